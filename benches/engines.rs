@@ -0,0 +1,79 @@
+//! Throughput of the engines on a 64 KiB buffer, `cargo +nightly bench [--features simd]`.
+//!
+//! The multi-lane engines only pay off with wide vectors, `RUSTFLAGS="-C target-cpu=native"` on AVX2 or AVX-512.
+
+#![feature(test)]
+
+extern crate test;
+
+use stuff::rng::engines::*;
+use stuff::rng::{RandomNumberEngine, UniformRandomBitGenerator};
+use test::{black_box, Bencher};
+
+const WORDS: usize = 8192;
+
+fn seeded<E: RandomNumberEngine>(mut engine: E) -> E
+where
+    E::ResultType: From<u8>,
+{
+    engine.seed_from_result(17u8.into());
+    engine
+}
+
+fn bench_generate<G: UniformRandomBitGenerator<ResultType = u64>>(b: &mut Bencher, mut generator: G) {
+    let mut buffer = vec![0u64; WORDS];
+    b.bytes = (WORDS * 8) as u64;
+
+    b.iter(|| {
+        for v in buffer.iter_mut() {
+            *v = generator.generate();
+        }
+
+        black_box(&buffer);
+    });
+}
+
+fn bench_fill<G: UniformRandomBitGenerator<ResultType = u64>>(b: &mut Bencher, mut generator: G) {
+    let mut buffer = vec![0u64; WORDS];
+    b.bytes = (WORDS * 8) as u64;
+
+    b.iter(|| {
+        generator.fill(&mut buffer);
+        black_box(&buffer);
+    });
+}
+
+#[bench]
+fn xoshiro256pp_generate(b: &mut Bencher) { bench_generate(b, seeded(Xoshiro256PP::new())) }
+
+#[bench]
+fn xoshiro256pp_fill(b: &mut Bencher) { bench_fill(b, seeded(Xoshiro256PP::new())) }
+
+#[bench]
+fn xoshiro256pp_fill_bytes(b: &mut Bencher) {
+    let mut generator = seeded(Xoshiro256PP::new());
+    let mut buffer = vec![0u8; WORDS * 8];
+    b.bytes = buffer.len() as u64;
+
+    b.iter(|| {
+        generator.fill_bytes(&mut buffer);
+        black_box(&buffer);
+    });
+}
+
+#[bench]
+fn xoshiro256ppx4_generate(b: &mut Bencher) { bench_generate(b, seeded(Xoshiro256PPx4::new())) }
+
+#[bench]
+fn xoshiro256ppx4_fill(b: &mut Bencher) { bench_fill(b, seeded(Xoshiro256PPx4::new())) }
+
+#[bench]
+fn xoshiro256ppx8_fill(b: &mut Bencher) { bench_fill(b, seeded(Xoshiro256PPx8::new())) }
+
+#[cfg(feature = "simd")]
+#[bench]
+fn xoshiro256pp_simd4_fill(b: &mut Bencher) { bench_fill(b, Xoshiro256PPSimd::<4>::jumped(seeded(Xoshiro256PP::new()), 128)) }
+
+#[cfg(feature = "simd")]
+#[bench]
+fn xoshiro256pp_simd8_fill(b: &mut Bencher) { bench_fill(b, Xoshiro256PPSimd::<8>::jumped(seeded(Xoshiro256PP::new()), 128)) }
@@ -1,9 +1,8 @@
-use stuff::rng::distributions::GenerateCanonical;
-
 use crate::*;
 
 pub trait Camera {
-    fn sample_pixel<Gen: stuff::rng::UniformRandomBitGenerator>(&self, screen_coords: (usize, usize), pos: Vec3, gen: &mut Gen) -> (Ray, Float);
+    /// The ray through the point `jitter` in `[0, 1)^2` of the filter footprint of the pixel.
+    fn sample_pixel(&self, screen_coords: (usize, usize), jitter: Vec2, pos: Vec3) -> (Ray, Float);
 }
 #[derive(Clone)]
 
@@ -17,12 +16,12 @@ impl PinholeCamera {
 }
 
 impl Camera for PinholeCamera {
-    fn sample_pixel<Gen: stuff::rng::UniformRandomBitGenerator>(&self, screen_coords: (usize, usize), pos: Vec3, gen: &mut Gen) -> (Ray, Float) {
+    fn sample_pixel(&self, screen_coords: (usize, usize), jitter: Vec2, pos: Vec3) -> (Ray, Float) {
         let half_θ = self.fov / 2.;
         //let d = screen_coords.0 as f64 * (half_θ.atan()) / 2f64 ;
         let d = (1. / (2. * half_θ.sin())) * ((self.screen_dims.0 as Float * (2. - self.screen_dims.0 as Float)).abs()).sqrt();
 
-        let offset = (Float::generate_canonical(gen) * 2. - 1., Float::generate_canonical(gen) * 2. - 1.);
+        let offset = (jitter[0] * 2. - 1., jitter[1] * 2. - 1.);
 
         let direction = Vec3::new([
             screen_coords.0 as Float - (self.screen_dims.0 as Float / 2.) + offset.0, //
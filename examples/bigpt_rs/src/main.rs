@@ -36,7 +36,9 @@ mod fun;
 
 use stuff::rng::distributions::sphere::NDSampler;
 use stuff::rng::distributions::GenerateCanonical;
-use stuff::rng::{RandomNumberEngine, UniformRandomBitGenerator};
+use stuff::rng::engines::Philox4x32;
+use stuff::rng::patterns::CorrelatedMultiJitteredPattern;
+use stuff::rng::UniformRandomBitGenerator;
 
 const MATERIALS: [EMaterial; 6] = [
     EMaterial::LambertianDiffuseIS(material::LambertianIS {
@@ -144,11 +146,18 @@ fn trace_iterative<T: Intersectable + 'static, Gen: stuff::rng::UniformRandomBit
     color::LinearRGB(light)
 }
 
-fn kernel_iterative<Cam: Camera, Gen: stuff::rng::UniformRandomBitGenerator>(pos: (usize, usize), samples: usize, cam: &Cam, gen: &mut Gen) -> color::LinearRGB {
+fn kernel_iterative<Cam: Camera>(pos: (usize, usize), samples: usize, cam: &Cam, key: [u32; 2]) -> color::LinearRGB {
+    // the samples of a pixel spread over its footprint as a correlated multi-jittered pattern of its own
+    let pattern = key[0] ^ key[1].rotate_left(16) ^ (pos.0 as u32).wrapping_mul(0x9e3779b9) ^ (pos.1 as u32).wrapping_mul(0x85ebca6b);
+
     let res = (0..samples)
-        .map(|_| {
-            let (ray, ray_pdf) = cam.sample_pixel(pos, Vec3::new_explode(0.), gen);
-            let res = trace_iterative(ray, &(&PLANES, &SPHERES), gen).0 / ray_pdf;
+        .map(|sample| {
+            // every sample gets its own counter so that the image does not depend on which thread rendered what
+            let mut gen = Philox4x32::new_with_key_and_counter(key, [0, sample as u32, pos.0 as u32, pos.1 as u32]);
+
+            let jitter = CorrelatedMultiJitteredPattern::point::<Float>(sample as u32, samples as u32, pattern);
+            let (ray, ray_pdf) = cam.sample_pixel(pos, jitter, Vec3::new_explode(0.));
+            let res = trace_iterative(ray, &(&PLANES, &SPHERES), &mut gen).0 / ray_pdf;
             res
         })
         .fold(Vec3::new_explode(0.), std::ops::Add::add)
@@ -169,8 +178,8 @@ pub fn main() {
     let mut image = stuff::qoi::Image::new(dims.0, dims.1);
 
     let mut rd = stuff::rng::engines::RandomDevice::new();
-    let mut gen = stuff::rng::engines::Xoshiro256P::new();
-    gen.seed_from_result(rd.generate());
+    let seed = rd.generate();
+    let key = [seed as u32, (seed >> 32) as u32];
 
     let cam = camera::PinholeCamera::new((image.width() as usize, image.height() as usize), 45_f64.to_radians());
 
@@ -186,9 +195,6 @@ pub fn main() {
                 let row = row.clone();
                 let sender = sender.clone();
 
-                gen.discard(192);
-                let mut gen = gen.clone();
-
                 let cam = &cam;
 
                 scope.spawn(move || loop {
@@ -203,7 +209,7 @@ pub fn main() {
                     }
 
                     let pixels = (0..dims.0) //
-                        .map(|col| kernel_iterative((col as usize, row as usize), samples, cam, &mut gen))
+                        .map(|col| kernel_iterative((col as usize, row as usize), samples, cam, key))
                         .collect();
 
                     sender.send(Product { for_row: row as usize, pixels }).unwrap();
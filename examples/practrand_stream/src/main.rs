@@ -0,0 +1,33 @@
+use std::io::ErrorKind;
+
+use stuff::rng::engines::*;
+use stuff::rng::quality::RawOutput;
+use stuff::rng::RandomNumberEngine;
+
+/// Writes the output of an engine to stdout until the reader goes away, e.g.
+/// `cargo run --release -- xoroshiro128+ | RNG_test stdin64`.
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let engine = args.next().unwrap_or_else(|| "xoshiro256++".to_string());
+    let seed = args.next().map(|seed| seed.parse::<u64>().expect("the seed must be an unsigned integer")).unwrap_or(0);
+
+    let result = match engine.as_str() {
+        "xoroshiro128+" => stream(Xoroshiro128P::new(), seed),
+        "xoroshiro128++" => stream(Xoroshiro128PP::new(), seed),
+        "xoshiro256++" => stream(Xoshiro256PP::new(), seed),
+        _ => panic!("unknown engine {}, expected one of xoroshiro128+, xoroshiro128++ and xoshiro256++", engine),
+    };
+
+    match result {
+        Err(error) if error.kind() != ErrorKind::BrokenPipe => panic!("{}", error),
+        _ => {}
+    }
+}
+
+fn stream<E: RandomNumberEngine>(mut engine: E, seed: u64) -> std::io::Result<u64>
+where
+    E::ResultType: From<u64>,
+{
+    engine.seed_from_result(seed.into());
+    std::io::copy(&mut RawOutput::new(engine), &mut std::io::stdout().lock())
+}
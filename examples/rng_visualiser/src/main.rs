@@ -110,6 +110,27 @@ fn quick_visualise_3d<Sampler: stuff::rng::distributions::sphere::NDSampler<f64,
     image_xz.encode_to_writer(&mut std::fs::File::create(format!("out/d3_xz_{}.qoi", sampler_name)).unwrap()).unwrap();
 }
 
+fn quick_visualise_pattern<Pattern: stuff::rng::patterns::SamplePattern<f64, 2>>(random_seed: u64, pattern_name: &str, pattern: &mut Pattern, count: usize) {
+    let mut generator = stuff::rng::engines::Xoshiro256PP::new();
+    generator.seed_from_result(random_seed);
+
+    // a single pattern, every point lights up a bin of its own
+    let mut points = pattern.generate(&mut generator, count).into_iter();
+
+    let image = visualise(
+        || points.next().unwrap().0.into(),
+        &VisualisationConfiguration {
+            image_dims: (258, 258),
+            vis_x_range: (0f64, 1f64),
+            vis_y_range: (0f64, 1f64),
+        },
+        count,
+    );
+
+    let mut file = std::fs::File::create(format!("out/pattern_{}.qoi", pattern_name)).unwrap();
+    image.encode_to_writer(&mut file).unwrap();
+}
+
 pub fn main() {
     let mut rd = stuff::rng::engines::RandomDevice::new();
     println!("here, have a random number: {}", rd.generate());
@@ -128,4 +149,14 @@ pub fn main() {
     quick_visualise_3d(rd.generate(), "cos_pow4_hemisphere", &mut stuff::rng::distributions::sphere::PowerCosineWeightedHemisphereSampler::<f64>::new(4f64));
     quick_visualise_3d(rd.generate(), "cos_pow8_hemisphere", &mut stuff::rng::distributions::sphere::PowerCosineWeightedHemisphereSampler::<f64>::new(8f64));
     quick_visualise_3d(rd.generate(), "uniform_ball", &mut stuff::rng::distributions::sphere::UniformBallSampler::<f64, 3>::new());
+    quick_visualise_2d(rd.generate(), "concentric_disk", &mut stuff::rng::distributions::sphere::ConcentricDiskSampler::<f64>::new());
+    quick_visualise_2d(rd.generate(), "triangle", &mut stuff::rng::distributions::sphere::UniformTriangleSampler::new([[-1f64, -1f64], [1f64, -0.5f64], [0f64, 1f64]]));
+    quick_visualise_3d(rd.generate(), "cone", &mut stuff::rng::distributions::sphere::UniformConeSampler::new([0f64, 0.6f64, 0.8f64], 0.5f64));
+    quick_visualise_3d(rd.generate(), "spherical_triangle", &mut stuff::rng::distributions::sphere::UniformSphericalTriangleSampler::new([[1f64, 0f64, 0.2f64], [0f64, 1f64, 0.5f64], [-0.5f64, -0.5f64, 1f64]]));
+
+    quick_visualise_pattern(rd.generate(), "jittered", &mut stuff::rng::patterns::JitteredPattern::new(), 1024);
+    quick_visualise_pattern(rd.generate(), "multi_jittered", &mut stuff::rng::patterns::MultiJitteredPattern::new(), 1024);
+    quick_visualise_pattern(rd.generate(), "correlated_multi_jittered", &mut stuff::rng::patterns::CorrelatedMultiJitteredPattern::new(), 1024);
+    quick_visualise_pattern(rd.generate(), "latin_hypercube", &mut stuff::rng::patterns::LatinHypercubePattern::new(), 1024);
+    quick_visualise_pattern(rd.generate(), "poisson_disk", &mut stuff::rng::patterns::PoissonDiskPattern::default(), 1024);
 }
@@ -14,6 +14,7 @@ use ray::*;
 
 use rng::distributions::sphere::NDSampler;
 use rng::distributions::GenerateCanonical;
+use rng::sequences::*;
 use rng::*;
 use smallvec::*;
 use stuff::*;
@@ -100,7 +101,7 @@ struct RenderConfiguration {
     samples: usize,
 }
 
-fn trace_iterative<T: Intersectable<f64>, Gen: stuff::rng::UniformRandomBitGenerator>(mut ray: Ray<f64>, scene: &T, gen: &mut Gen) -> Color<f64> {
+fn trace_iterative<T: Intersectable<f64>, S: SampleGenerator>(mut ray: Ray<f64>, scene: &T, samples: &mut S) -> Color<f64> {
     let mut attenuation = Vector::new_explode(1.);
     let mut light = Vector::new_explode(0.);
 
@@ -117,7 +118,7 @@ fn trace_iterative<T: Intersectable<f64>, Gen: stuff::rng::UniformRandomBitGener
 
         let (wi, cos_brdf_over_wi_pdf) = match intersection.material {
             ReflectanceType::Diffuse => {
-                let (wi, wi_pdf) = stuff::rng::distributions::sphere::UniformSphereSampler::new().sample(gen);
+                let (wi, wi_pdf) = stuff::rng::distributions::sphere::UniformSphereSampler::new().sample_from(samples);
                 let wi = Vector(wi);
                 let wi = if wi.dot(intersection.normal) >= 0. { wi } else { -wi };
                 let wi_pdf = wi_pdf * 2.;
@@ -132,7 +133,7 @@ fn trace_iterative<T: Intersectable<f64>, Gen: stuff::rng::UniformRandomBitGener
                 let (transmittant_index, incident_index) = if going_in { (intersection.index_of_refraction, 1.) } else { (1., intersection.index_of_refraction) };
 
                 if let Some((refraction, p_refraction)) = ray::refract(wo, oriented_normal, incident_index, transmittant_index) {
-                    let generated = f64::generate_canonical(gen).get();
+                    let generated = samples.next_1d::<f64>();
 
                     if generated < p_refraction {
                         // the brdf and the pdf are the same, they hence cancel eachother
@@ -162,7 +163,7 @@ fn trace_iterative<T: Intersectable<f64>, Gen: stuff::rng::UniformRandomBitGener
                 break;
             }
 
-            if f64::generate_canonical(gen).get() < p {
+            if samples.next_1d::<f64>() < p {
                 attenuation = attenuation / (1. - p);
             } else {
                 break;
@@ -174,10 +175,12 @@ fn trace_iterative<T: Intersectable<f64>, Gen: stuff::rng::UniformRandomBitGener
 }
 
 #[allow(dead_code)]
-fn kernel_preview<CameraType: Camera<f64>, Gen: stuff::rng::UniformRandomBitGenerator>(pixel_cooridnates: (usize, usize), gen: &mut Gen, camera: &CameraType, samples: usize) -> Color<f64> {
+fn kernel_preview<CameraType: Camera<f64>, S: SampleGenerator>(pixel_cooridnates: (usize, usize), sample_generator: &mut S, camera: &CameraType, samples: usize) -> Color<f64> {
     let res = (0..samples)
-        .map(|_| {
-            let (ray, _ray_pdf) = camera.generate_ray(pixel_cooridnates, gen);
+        .map(|index| {
+            sample_generator.start_pixel_sample([pixel_cooridnates.0 as u32, pixel_cooridnates.1 as u32], index as u64);
+
+            let (ray, _ray_pdf) = camera.generate_ray(pixel_cooridnates, sample_generator);
             if let Some(intersection) = SPHERES.intersect(&ray) {
                 intersection.albedo.0 * intersection.normal.dot(ray.direction).abs()
             } else {
@@ -191,11 +194,13 @@ fn kernel_preview<CameraType: Camera<f64>, Gen: stuff::rng::UniformRandomBitGene
 }
 
 #[allow(dead_code)]
-fn kernel<CameraType: Camera<f64>, Gen: stuff::rng::UniformRandomBitGenerator>(pixel_cooridnates: (usize, usize), gen: &mut Gen, camera: &CameraType, samples: usize) -> Color<f64> {
+fn kernel<CameraType: Camera<f64>, S: SampleGenerator>(pixel_cooridnates: (usize, usize), sample_generator: &mut S, camera: &CameraType, samples: usize) -> Color<f64> {
     let res = (0..samples)
-        .map(|_| {
-            let (ray, ray_pdf) = camera.generate_ray(pixel_cooridnates, gen);
-            trace_iterative(ray, &SPHERES, gen).0 / ray_pdf
+        .map(|index| {
+            sample_generator.start_pixel_sample([pixel_cooridnates.0 as u32, pixel_cooridnates.1 as u32], index as u64);
+
+            let (ray, ray_pdf) = camera.generate_ray(pixel_cooridnates, sample_generator);
+            trace_iterative(ray, &SPHERES, sample_generator).0 / ray_pdf
         })
         .fold(Vector::new_explode(0.), |acc, v| acc + v)
         / samples as f64;
@@ -206,8 +211,7 @@ fn kernel<CameraType: Camera<f64>, Gen: stuff::rng::UniformRandomBitGenerator>(p
 #[allow(dead_code)]
 fn single_threaded(config: &RenderConfiguration) {
     let mut rd = stuff::rng::engines::RandomDevice::new();
-    let mut generator = stuff::rng::engines::Xoshiro256PP::new();
-    generator.seed_from_result(rd.generate());
+    let mut generator = SobolSampleGenerator::new(rd.generate(), SobolScrambling::Owen);
 
     let pixels = (0..config.dimensions.1) //
         .map(|y| (0..config.dimensions.0).map(move |x| (x, y)))
@@ -231,9 +235,9 @@ fn multi_threaded(config: RenderConfiguration) {
 
     let mut workers = Vec::new();
 
+    // the points only depend on the pixel and the sample index, every thread can use a copy
     let mut rd = stuff::rng::engines::RandomDevice::new();
-    let mut base_generator = stuff::rng::engines::Xoshiro256PP::new();
-    base_generator.seed_from_result(rd.generate());
+    let sample_generator = SobolSampleGenerator::new(rd.generate(), SobolScrambling::Owen);
 
     let config = Arc::new(config);
 
@@ -242,8 +246,7 @@ fn multi_threaded(config: RenderConfiguration) {
         let sender = sender.clone();
         let config = config.clone();
 
-        base_generator.discard(192);
-        let mut generator = base_generator.clone();
+        let mut generator = sample_generator;
 
         workers.push(std::thread::spawn(move || loop {
             let row = {
@@ -36,8 +36,8 @@ macro_rules! impl_float_constants {
 
             const PI: Self::FloatType = core::$for_type::consts::PI;
             const TAU: Self::FloatType = core::$for_type::consts::TAU;
-            const PHI: Self::FloatType = core::$for_type::consts::PHI;
-            const EGAMMA: Self::FloatType = core::$for_type::consts::EGAMMA;
+            const PHI: Self::FloatType = core::$for_type::consts::GOLDEN_RATIO;
+            const EGAMMA: Self::FloatType = core::$for_type::consts::EULER_GAMMA;
             const FRAC_PI_2: Self::FloatType = core::$for_type::consts::FRAC_PI_2;
             const FRAC_PI_3: Self::FloatType = core::$for_type::consts::FRAC_PI_3;
             const FRAC_PI_4: Self::FloatType = core::$for_type::consts::FRAC_PI_4;
@@ -69,6 +69,7 @@ pub trait FloatingPoint:
     Copy
     + Clone
     + FloatConstants<FloatType = Self>
+    + NumericLimits
     + ZeroAndOne
     + FromScalar<u8>
     + ToScalar<u8>
@@ -115,6 +116,8 @@ pub trait FloatingPoint:
     fn abs(self) -> Self;
     fn signum(self) -> Self;
     fn copysign(self, sign: Self) -> Self;
+    fn next_up(self) -> Self;
+    fn next_down(self) -> Self;
 
     fn mul_add(self, a: Self, b: Self) -> Self;
     fn div_euclid(self, rhs: Self) -> Self;
@@ -197,6 +200,8 @@ macro_rules! impl_float_passthrough {
             fn abs(self) -> Self { Self::abs(self) }
             fn signum(self) -> Self { Self::signum(self) }
             fn copysign(self, sign: Self) -> Self { Self::copysign(self, sign) }
+            fn next_up(self) -> Self { Self::next_up(self) }
+            fn next_down(self) -> Self { Self::next_down(self) }
 
             fn mul_add(self, a: Self, b: Self) -> Self { Self::mul_add(self, a, b) }
             fn div_euclid(self, rhs: Self) -> Self { Self::div_euclid(self, rhs) }
@@ -1,7 +1,9 @@
 #![allow(incomplete_features)]
 #![allow(mixed_script_confusables)]
 #![feature(adt_const_params)]
+#![feature(const_param_ty_trait)]
 #![feature(allocator_api)]
+#![feature(float_gamma)]
 #![feature(float_next_up_down)]
 #![feature(generic_const_exprs)]
 #![feature(inherent_associated_types)]
@@ -10,6 +12,7 @@
 #![feature(more_float_constants)]
 #![feature(structural_match)]
 #![feature(stmt_expr_attributes)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
 pub mod integral;
 pub mod numeric_limits;
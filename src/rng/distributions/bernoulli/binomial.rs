@@ -0,0 +1,269 @@
+use crate::rng::distributions::detail::*;
+use crate::rng::*;
+use crate::*;
+
+/// The number of successes in `t` independent trials with probability `p` each, like `std::binomial_distribution`.
+///
+/// Small `t * min(p, 1 - p)` use inversion, larger ones the BTPE algorithm of Kachitvichyanukul and Schmeiser
+/// ("Binomial Random Variate Generation", 1988), which takes a constant expected time.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BinomialDistribution<T: Integral> {
+    param: BinomialDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BinomialDistributionParams<T: Integral> {
+    pub t: T,
+    pub p: f64,
+}
+
+impl<T: Integral> BinomialDistributionParams<T> {
+    pub fn new(t: T, p: f64) -> Self {
+        assert!(t >= T::zero(), "the number of trials must not be negative");
+        assert!((0f64..=1f64).contains(&p), "p must be a probability");
+        Self { t, p }
+    }
+}
+
+impl<T: Integral> Default for BinomialDistributionParams<T> {
+    fn default() -> Self { Self { t: T::one(), p: 0.5 } }
+}
+
+impl<T: Integral> BinomialDistribution<T> {
+    pub fn new(t: T, p: f64) -> Self { Self { param: BinomialDistributionParams::new(t, p) } }
+
+    pub fn t(&self) -> T { self.param.t }
+    pub fn p(&self) -> f64 { self.param.p }
+}
+
+impl<T: Integral> Default for BinomialDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: Integral> RandomNumberDistribution for BinomialDistribution<T> {
+    type ResultType = T;
+    type ParamType = BinomialDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { self.param.t }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let n = count_to_f64(param.t);
+        let p = param.p.min(1f64 - param.p);
+
+        let successes = if p == 0f64 {
+            0f64
+        } else if n * p < 30f64 {
+            detail::inversion(generator, n, p)
+        } else {
+            detail::btpe(generator, n, p)
+        };
+
+        // the algorithms assume p <= 1/2 and count failures otherwise
+        count_from_f64(if param.p > 0.5 { n - successes } else { successes })
+    }
+}
+
+mod detail {
+    use crate::rng::distributions::GenerateCanonical;
+    use crate::rng::*;
+
+    pub fn inversion<G: UniformRandomBitGenerator>(generator: &mut G, n: f64, p: f64) -> f64 {
+        let q = 1f64 - p;
+        let q_n = (n * q.ln()).exp();
+        let bound = n.min(n * p + 10f64 * (n * p * q + 1f64).sqrt());
+
+        let mut x = 0f64;
+        let mut p_x = q_n;
+        let mut u = f64::generate_canonical(generator).get();
+
+        while u > p_x {
+            x += 1f64;
+
+            if x > bound {
+                // the remaining mass is negligible, but the sum of the pmf might not reach u due to rounding
+                x = 0f64;
+                p_x = q_n;
+                u = f64::generate_canonical(generator).get();
+            } else {
+                u -= p_x;
+                p_x *= (n - x + 1f64) * p / (x * q);
+            }
+        }
+
+        x
+    }
+
+    /// The correction terms of Stirling's approximation of `ln(a!)` needed by BTPE, with `a_2 = a^2`.
+    fn stirling_correction(a: f64, a_2: f64) -> f64 { (13860f64 - (462f64 - (132f64 - (99f64 - 140f64 / a_2) / a_2) / a_2) / a_2) / a / 166320f64 }
+
+    pub fn btpe<G: UniformRandomBitGenerator>(generator: &mut G, n: f64, p: f64) -> f64 {
+        let q = 1f64 - p;
+        let npq = n * p * q;
+
+        let f_m = n * p + p;
+        let m = f_m.floor();
+
+        // the triangular centre, the parallelograms and the exponential tails of the majorizing function
+        let p_1 = (2.195f64 * npq.sqrt() - 4.6f64 * q).floor() + 0.5f64;
+        let x_m = m + 0.5f64;
+        let x_l = x_m - p_1;
+        let x_r = x_m + p_1;
+        let c = 0.134f64 + 20.5f64 / (15.3f64 + m);
+
+        let a = (f_m - x_l) / (f_m - x_l * p);
+        let lambda_l = a * (1f64 + a / 2f64);
+        let a = (x_r - f_m) / (x_r * q);
+        let lambda_r = a * (1f64 + a / 2f64);
+
+        let p_2 = p_1 * (1f64 + 2f64 * c);
+        let p_3 = p_2 + c / lambda_l;
+        let p_4 = p_3 + c / lambda_r;
+
+        loop {
+            let u = f64::generate_canonical(generator).get() * p_4;
+            let mut v = f64::generate_canonical_open(generator).get();
+
+            let y = if u <= p_1 {
+                // the triangle, always accepted
+                return (x_m - p_1 * v + u).floor();
+            } else if u <= p_2 {
+                let x = x_l + (u - p_1) / c;
+                v = v * c + 1f64 - (m - x + 0.5f64).abs() / p_1;
+
+                if v > 1f64 {
+                    continue;
+                }
+
+                x.floor()
+            } else if u <= p_3 {
+                let y = (x_l + v.ln() / lambda_l).floor();
+
+                if y < 0f64 {
+                    continue;
+                }
+
+                v *= (u - p_2) * lambda_l;
+                y
+            } else {
+                let y = (x_r - v.ln() / lambda_r).floor();
+
+                if y > n {
+                    continue;
+                }
+
+                v *= (u - p_3) * lambda_r;
+                y
+            };
+
+            let k = (y - m).abs();
+
+            if k <= 20f64 || k >= npq / 2f64 - 1f64 {
+                // evaluate f(y) / f(m) by the recurrence of the pmf
+                let s = p / q;
+                let a = s * (n + 1f64);
+                let mut f = 1f64;
+
+                let mut i = m + 1f64;
+                while i <= y {
+                    f *= a / i - s;
+                    i += 1f64;
+                }
+
+                let mut i = y + 1f64;
+                while i <= m {
+                    f /= a / i - s;
+                    i += 1f64;
+                }
+
+                if v <= f {
+                    return y;
+                }
+
+                continue;
+            }
+
+            // squeeze using the normal approximation, then the exact bound through Stirling's formula
+            let rho = (k / npq) * ((k * (k / 3f64 + 0.625f64) + 0.1666666666666f64) / npq + 0.5f64);
+            let t = -k * k / (2f64 * npq);
+            let log_v = v.ln();
+
+            if log_v < t - rho {
+                return y;
+            }
+
+            if log_v > t + rho {
+                continue;
+            }
+
+            let x_1 = y + 1f64;
+            let f_1 = m + 1f64;
+            let z = n + 1f64 - m;
+            let w = n - y + 1f64;
+
+            let bound = x_m * (f_1 / x_1).ln()
+                + (n - m + 0.5f64) * (z / w).ln()
+                + (y - m) * (w * p / (x_1 * q)).ln()
+                + stirling_correction(f_1, f_1 * f_1)
+                + stirling_correction(z, z * z)
+                + stirling_correction(x_1, x_1 * x_1)
+                + stirling_correction(w, w * w);
+
+            if log_v <= bound {
+                return y;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_binomial_bounds() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        for (t, p) in [(0u32, 0.5f64), (10, 0f64), (10, 1f64), (1, 0.5), (25, 0.3), (1000, 0.01), (1000, 0.5), (1000, 0.97), (4000000000, 0.5)] {
+            let mut distribution = BinomialDistribution::new(t, p);
+
+            for _ in 0..1000 {
+                let v = distribution.generate(&mut generator);
+                assert!(v <= t);
+
+                if p == 0f64 {
+                    assert_eq!(v, 0);
+                } else if p == 1f64 {
+                    assert_eq!(v, t);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binomial_mean() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        // BTPE, within four standard errors of the mean
+        for (t, p) in [(1000i64, 0.3f64), (100000, 0.5), (100000, 0.9)] {
+            let mut distribution = BinomialDistribution::new(t, p);
+            let mean = (0..100000).map(|_| distribution.generate(&mut generator) as f64).sum::<f64>() / 100000f64;
+            let standard_error = (t as f64 * p * (1f64 - p) / 100000f64).sqrt();
+
+            assert!((mean - t as f64 * p).abs() < 4f64 * standard_error, "mean {} for t = {}, p = {}", mean, t, p);
+        }
+    }
+}
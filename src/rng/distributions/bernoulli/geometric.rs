@@ -0,0 +1,90 @@
+use crate::rng::distributions::{detail::*, GenerateCanonical};
+use crate::rng::*;
+use crate::*;
+
+/// The number of failures before the first success in trials with probability `p`, like
+/// `std::geometric_distribution`.
+///
+/// Generated by inversion, `floor(ln(u) / ln(1 - p))`; results beyond the range of `T` saturate.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GeometricDistribution<T: Integral> {
+    param: GeometricDistributionParams,
+    phantom: std::marker::PhantomData<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GeometricDistributionParams {
+    pub p: f64,
+}
+
+impl GeometricDistributionParams {
+    pub fn new(p: f64) -> Self {
+        assert!(0f64 < p && p <= 1f64, "p must be a non-zero probability");
+        Self { p }
+    }
+}
+
+impl Default for GeometricDistributionParams {
+    fn default() -> Self { Self { p: 0.5 } }
+}
+
+impl<T: Integral> GeometricDistribution<T> {
+    pub fn new(p: f64) -> Self { Self { param: GeometricDistributionParams::new(p), phantom: std::marker::PhantomData } }
+
+    pub fn p(&self) -> f64 { self.param.p }
+}
+
+impl<T: Integral> Default for GeometricDistribution<T> {
+    fn default() -> Self { Self { param: Default::default(), phantom: std::marker::PhantomData } }
+}
+
+impl<T: Integral> RandomNumberDistribution for GeometricDistribution<T> {
+    type ResultType = T;
+    type ParamType = GeometricDistributionParams;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { NumericLimits::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        if param.p == 1f64 {
+            return T::zero();
+        }
+
+        // dense so that the tail is not cut off at -ln(2^-53) / -ln(1 - p)
+        let u = f64::generate_canonical_dense_open(generator).get();
+
+        count_from_f64((u.ln() / (-param.p).ln_1p()).floor())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_geometric() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        let mut distribution = GeometricDistribution::<u32>::new(1f64);
+        assert!((0..1000).all(|_| distribution.generate(&mut generator) == 0));
+
+        // the tail saturates instead of wrapping
+        distribution.set_param(&GeometricDistributionParams::new(1e-12));
+        assert!((0..1000).any(|_| distribution.generate(&mut generator) == u32::MAX));
+
+        let mut distribution = GeometricDistribution::<u8>::new(0.01);
+        assert!((0..1000).any(|_| distribution.generate(&mut generator) == u8::MAX));
+    }
+}
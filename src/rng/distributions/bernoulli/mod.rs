@@ -0,0 +1,83 @@
+pub mod binomial;
+pub mod geometric;
+pub mod negative_binomial;
+
+pub use binomial::*;
+pub use geometric::*;
+pub use negative_binomial::*;
+
+use crate::rng::distributions::GenerateCanonical;
+use crate::rng::*;
+
+/// `true` with probability `p`, like `std::bernoulli_distribution`.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct BernoulliDistribution {
+    param: BernoulliDistributionParams,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BernoulliDistributionParams {
+    pub p: f64,
+}
+
+impl BernoulliDistributionParams {
+    pub fn new(p: f64) -> Self {
+        assert!((0f64..=1f64).contains(&p), "p must be a probability");
+        Self { p }
+    }
+}
+
+impl Default for BernoulliDistributionParams {
+    fn default() -> Self { Self { p: 0.5 } }
+}
+
+impl BernoulliDistribution {
+    pub fn new(p: f64) -> Self { Self { param: BernoulliDistributionParams::new(p) } }
+
+    pub fn p(&self) -> f64 { self.param.p }
+}
+
+impl RandomNumberDistribution for BernoulliDistribution {
+    type ResultType = bool;
+    type ParamType = BernoulliDistributionParams;
+
+    fn min(&self) -> Self::ResultType { false }
+    fn max(&self) -> Self::ResultType { true }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        // the canonical float is below 1, so p = 1 always succeeds and p = 0 never does
+        f64::generate_canonical(generator).get() < param.p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_bernoulli() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        let mut distribution = BernoulliDistribution::new(1f64);
+        assert!((0..1000).all(|_| distribution.generate(&mut generator)));
+
+        distribution.set_param(&BernoulliDistributionParams::new(0f64));
+        assert!((0..1000).all(|_| !distribution.generate(&mut generator)));
+        assert_eq!(distribution.p(), 0f64);
+
+        let successes = (0..100000).filter(|_| distribution.generate_param(&mut generator, &BernoulliDistributionParams::new(0.25))).count();
+        assert!((24000..26000).contains(&successes), "{}", successes);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::rng::distributions::{detail::*, GammaDistribution, PoissonDistribution, PoissonDistributionParams};
+use crate::rng::*;
+use crate::*;
+
+/// The number of failures before the `k`-th success in trials with probability `p`, like
+/// `std::negative_binomial_distribution`.
+///
+/// Generated as a Poisson distribution whose mean is gamma distributed with shape `k` and scale `(1 - p) / p`, which
+/// takes a constant expected time for any `k`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NegativeBinomialDistribution<T: Integral> {
+    param: NegativeBinomialDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NegativeBinomialDistributionParams<T: Integral> {
+    pub k: T,
+    pub p: f64,
+}
+
+impl<T: Integral> NegativeBinomialDistributionParams<T> {
+    pub fn new(k: T, p: f64) -> Self {
+        assert!(k > T::zero(), "the number of successes must be positive");
+        assert!(0f64 < p && p <= 1f64, "p must be a non-zero probability");
+        Self { k, p }
+    }
+}
+
+impl<T: Integral> Default for NegativeBinomialDistributionParams<T> {
+    fn default() -> Self { Self { k: T::one(), p: 0.5 } }
+}
+
+impl<T: Integral> NegativeBinomialDistribution<T> {
+    pub fn new(k: T, p: f64) -> Self { Self { param: NegativeBinomialDistributionParams::new(k, p) } }
+
+    pub fn k(&self) -> T { self.param.k }
+    pub fn p(&self) -> f64 { self.param.p }
+}
+
+impl<T: Integral> Default for NegativeBinomialDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: Integral> RandomNumberDistribution for NegativeBinomialDistribution<T> {
+    type ResultType = T;
+    type ParamType = NegativeBinomialDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { NumericLimits::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        if param.p == 1f64 {
+            return T::zero();
+        }
+
+        let mean = GammaDistribution::<f64>::new(count_to_f64(param.k), (1f64 - param.p) / param.p).generate(generator);
+
+        PoissonDistribution::<T>::default().generate_param(generator, &PoissonDistributionParams { mean })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_negative_binomial_mean() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        for (k, p) in [(1u64, 0.5f64), (5, 0.1), (1000, 0.9)] {
+            let mut distribution = NegativeBinomialDistribution::new(k, p);
+            let mean = (0..100000).map(|_| distribution.generate(&mut generator) as f64).sum::<f64>() / 100000f64;
+
+            let expected_mean = k as f64 * (1f64 - p) / p;
+            let standard_error = (expected_mean / p / 100000f64).sqrt();
+
+            assert!((mean - expected_mean).abs() < 4f64 * standard_error, "mean {} for k = {}, p = {}", mean, k, p);
+        }
+
+        let mut distribution = NegativeBinomialDistribution::new(3i32, 1f64);
+        assert!((0..100).all(|_| distribution.generate(&mut generator) == 0));
+    }
+}
@@ -1,8 +1,16 @@
+pub mod bernoulli;
 pub mod normal;
+pub mod poisson;
+pub mod sampling;
 pub mod uniform;
 pub mod sphere;
 
+mod ziggurat;
+
+pub use bernoulli::*;
 pub use normal::*;
+pub use poisson::*;
+pub use sampling::*;
 pub use uniform::*;
 
 use super::*;
@@ -30,4 +38,19 @@ impl RandomNumberDistribution for CanonicalDistribution {
     }
 }
 
-mod detail {}
+mod detail {
+    use crate::*;
+
+    /// A non-negative count computed in floating point, saturated to the range of `T`.
+    pub fn count_from_f64<T: Integral>(v: f64) -> T {
+        debug_assert!(v >= 0f64);
+
+        let max: u128 = <T as NumericLimits>::max().as_to();
+        T::as_from(std::cmp::min(v as u128, max))
+    }
+
+    pub fn count_to_f64<T: Integral>(v: T) -> f64 {
+        let v: u128 = v.as_to();
+        v as f64
+    }
+}
@@ -0,0 +1,67 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// The Cauchy distribution with location `a` and scale `b`, like `std::cauchy_distribution`.
+///
+/// Generated by inversion, `a + b * tan(pi * (u - 1/2))`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CauchyDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: CauchyDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CauchyDistributionParams<T: FloatingPoint> {
+    pub a: T,
+    pub b: T,
+}
+
+impl<T: FloatingPoint> CauchyDistributionParams<T> {
+    pub fn new(a: T, b: T) -> Self {
+        assert!(b > T::zero(), "the scale of a Cauchy distribution must be positive");
+        Self { a, b }
+    }
+}
+
+impl<T: FloatingPoint> Default for CauchyDistributionParams<T> {
+    fn default() -> Self { Self { a: T::as_from(0f64), b: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> CauchyDistribution<T> {
+    pub fn new(a: T, b: T) -> Self { Self { param: CauchyDistributionParams::new(a, b) } }
+
+    pub fn a(&self) -> T { self.param.a }
+    pub fn b(&self) -> T { self.param.b }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for CauchyDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for CauchyDistribution<T> {
+    type ResultType = T;
+    type ParamType = CauchyDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { <T as NumericLimits>::lowest() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let a: f64 = param.a.as_to();
+        let b: f64 = param.b.as_to();
+
+        // open, tan(-pi / 2) would be infinite
+        let u = f64::generate_canonical_open(generator).get();
+
+        T::as_from(a + b * (std::f64::consts::PI * (u - 0.5f64)).tan())
+    }
+}
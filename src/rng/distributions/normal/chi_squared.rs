@@ -0,0 +1,61 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// The sum of the squares of `n` standard normal variates, like `std::chi_squared_distribution`.
+///
+/// Generated as a gamma distribution with shape `n / 2` and scale 2, `n` need not be an integer.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ChiSquaredDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: ChiSquaredDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ChiSquaredDistributionParams<T: FloatingPoint> {
+    pub n: T,
+}
+
+impl<T: FloatingPoint> ChiSquaredDistributionParams<T> {
+    pub fn new(n: T) -> Self {
+        assert!(n > T::zero(), "the degrees of freedom of a chi-squared distribution must be positive");
+        Self { n }
+    }
+}
+
+impl<T: FloatingPoint> Default for ChiSquaredDistributionParams<T> {
+    fn default() -> Self { Self { n: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> ChiSquaredDistribution<T> {
+    pub fn new(n: T) -> Self { Self { param: ChiSquaredDistributionParams::new(n) } }
+
+    pub fn n(&self) -> T { self.param.n }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for ChiSquaredDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for ChiSquaredDistribution<T> {
+    type ResultType = T;
+    type ParamType = ChiSquaredDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let n: f64 = param.n.as_to();
+
+        T::as_from(GammaDistribution::<f64>::new(n / 2f64, 2f64).generate(generator))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// The ratio of two chi-squared variates with `m` and `n` degrees of freedom, each divided by its degrees of freedom,
+/// like `std::fisher_f_distribution`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FisherFDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: FisherFDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FisherFDistributionParams<T: FloatingPoint> {
+    pub m: T,
+    pub n: T,
+}
+
+impl<T: FloatingPoint> FisherFDistributionParams<T> {
+    pub fn new(m: T, n: T) -> Self {
+        assert!(m > T::zero() && n > T::zero(), "the degrees of freedom of a Fisher F distribution must be positive");
+        Self { m, n }
+    }
+}
+
+impl<T: FloatingPoint> Default for FisherFDistributionParams<T> {
+    fn default() -> Self { Self { m: T::as_from(1f64), n: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> FisherFDistribution<T> {
+    pub fn new(m: T, n: T) -> Self { Self { param: FisherFDistributionParams::new(m, n) } }
+
+    pub fn m(&self) -> T { self.param.m }
+    pub fn n(&self) -> T { self.param.n }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for FisherFDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for FisherFDistribution<T> {
+    type ResultType = T;
+    type ParamType = FisherFDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let m: f64 = param.m.as_to();
+        let n: f64 = param.n.as_to();
+
+        let x = ChiSquaredDistribution::<f64>::new(m).generate(generator);
+        let y = ChiSquaredDistribution::<f64>::new(n).generate(generator);
+
+        T::as_from((x * n) / (y * m))
+    }
+}
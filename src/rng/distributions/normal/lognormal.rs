@@ -0,0 +1,65 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// `exp(x)` for a normally distributed `x` with mean `m` and standard deviation `s`, like
+/// `std::lognormal_distribution`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LognormalDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: LognormalDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LognormalDistributionParams<T: FloatingPoint> {
+    pub m: T,
+    pub s: T,
+}
+
+impl<T: FloatingPoint> LognormalDistributionParams<T> {
+    pub fn new(m: T, s: T) -> Self {
+        assert!(s > T::zero(), "the standard deviation of a lognormal distribution must be positive");
+        Self { m, s }
+    }
+}
+
+impl<T: FloatingPoint> Default for LognormalDistributionParams<T> {
+    fn default() -> Self { Self { m: T::as_from(0f64), s: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> LognormalDistribution<T> {
+    pub fn new(m: T, s: T) -> Self { Self { param: LognormalDistributionParams::new(m, s) } }
+
+    pub fn m(&self) -> T { self.param.m }
+    pub fn s(&self) -> T { self.param.s }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for LognormalDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for LognormalDistribution<T> {
+    type ResultType = T;
+    type ParamType = LognormalDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let m: f64 = param.m.as_to();
+        let s: f64 = param.s.as_to();
+
+        let z = NormalDistribution::<f64>::default().ziggurat().generate(generator);
+
+        T::as_from((m + s * z).exp())
+    }
+}
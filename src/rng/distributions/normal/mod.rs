@@ -1,11 +1,26 @@
+pub mod cauchy;
+pub mod chi_squared;
+pub mod fisher_f;
+pub mod lognormal;
+pub mod student_t;
+
+pub use cauchy::*;
+pub use chi_squared::*;
+pub use fisher_f::*;
+pub use lognormal::*;
+pub use student_t::*;
+
 use crate::{
     rng::{RandomNumberDistribution, UniformRandomBitGenerator},
-    FloatingPoint, FromScalar,
+    FloatingPoint, FromScalar, NumericLimits,
 };
 
 use super::GenerateCanonical;
 
 mod detail {
+    use std::sync::OnceLock;
+
+    use crate::rng::distributions::{ziggurat::Ziggurat, GenerateCanonical};
     use crate::rng::*;
     use crate::*;
 
@@ -49,6 +64,27 @@ mod detail {
         return Some([z_0.into(), z_1.into()]);
     }
 
+    fn ziggurat() -> &'static Ziggurat {
+        static ZIGGURAT: OnceLock<Ziggurat> = OnceLock::new();
+
+        // the density is not normalised, only its shape matters
+        ZIGGURAT.get_or_init(|| Ziggurat::new(3.654152885361009, 4.92867323399e-3, |x| (-0.5 * x * x).exp(), |y| (-2f64 * y.ln()).sqrt()))
+    }
+
+    pub fn standard_normal_ziggurat<G: UniformRandomBitGenerator>(generator: &mut G) -> f64 {
+        // Marsaglia's method for the tail beyond r
+        let tail = |generator: &mut G, r: f64| loop {
+            let x = -f64::generate_canonical_dense_open(generator).get().ln() / r;
+            let y = -f64::generate_canonical_dense_open(generator).get().ln();
+
+            if 2f64 * y > x * x {
+                return r + x;
+            }
+        };
+
+        ziggurat().sample(generator, true, tail)
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -68,20 +104,77 @@ mod detail {
     }
 }
 
+/// How `NormalDistribution` generates its standard normal variates.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum NormalMethod {
+    /// The polar form of the Box–Müller transform, producing two variates at a time; the second one is cached.
+    MarsagliaPolar,
+    /// A 256 layer ziggurat, several times faster, needing a single word of entropy most of the time.
+    Ziggurat,
+}
+
+/// The normal distribution with the given mean and standard deviation, like `std::normal_distribution`.
+#[derive(Copy, Clone, Debug)]
 pub struct NormalDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: NormalDistributionParams<T>,
+    method: NormalMethod,
+    /// A standard normal variate, independent of the parameters.
     cache: Option<T>,
 }
 
 impl<T: FloatingPoint + GenerateCanonical<T>> NormalDistribution<T> {
-    pub fn new() -> Self { Self { cache: None } }
+    pub fn new(mean: T, stdev: T) -> Self { Self::new_with(mean, stdev, NormalMethod::MarsagliaPolar) }
+
+    pub fn new_with(mean: T, stdev: T, method: NormalMethod) -> Self { Self { param: NormalDistributionParams::new(mean, stdev), method, cache: None } }
+
+    /// The same distribution but using `NormalMethod::Ziggurat`.
+    pub fn ziggurat(self) -> Self { Self { method: NormalMethod::Ziggurat, cache: None, ..self } }
+
+    pub fn mean(&self) -> T { self.param.mean }
+    pub fn stdev(&self) -> T { self.param.stdev }
+
+    pub fn method(&self) -> NormalMethod { self.method }
+
+    fn generate_standard<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> T {
+        match self.method {
+            NormalMethod::MarsagliaPolar => {
+                if let Some(cached_value) = self.cache.take() {
+                    return cached_value;
+                }
+
+                let normal_samples = loop {
+                    let samples = [T::generate_canonical(generator), T::generate_canonical(generator)];
+
+                    if let Some(normal_samples) = detail::box_müller_marsaglia_polar(samples) {
+                        break normal_samples;
+                    }
+                };
+
+                self.cache = Some(normal_samples[0].get());
+                normal_samples[1].get()
+            }
+            NormalMethod::Ziggurat => T::as_from(detail::standard_normal_ziggurat(generator)),
+        }
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for NormalDistribution<T> {
+    fn default() -> Self { Self { param: Default::default(), method: NormalMethod::MarsagliaPolar, cache: None } }
 }
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub struct NormalDistributionParams<T: FloatingPoint> {
     pub mean: T,
     pub stdev: T,
 }
 
+impl<T: FloatingPoint> NormalDistributionParams<T> {
+    pub fn new(mean: T, stdev: T) -> Self {
+        assert!(stdev > T::zero(), "the standard deviation of a normal distribution must be positive");
+        Self { mean, stdev }
+    }
+}
+
 impl<T: FloatingPoint> Default for NormalDistributionParams<T> {
     fn default() -> Self {
         NormalDistributionParams::<T> {
@@ -95,37 +188,106 @@ impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for Norma
     type ParamType = NormalDistributionParams<T>;
     type ResultType = T;
 
-    fn min(&self) -> Self::ResultType { todo!() }
-    fn max(&self) -> Self::ResultType { todo!() }
+    fn min(&self) -> Self::ResultType { <T as NumericLimits>::lowest() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
 
-    fn reset(&mut self) {}
+    /// Discards the cached variate, so that subsequent results only depend on the generator.
+    fn reset(&mut self) { self.cache = None; }
 
-    fn get_param(&self) -> Self::ParamType { Default::default() }
-    fn set_param(&mut self, _param: &Self::ParamType) { todo!() }
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
 
-    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType { self.generate_param(generator, &Default::default()) }
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
 
     fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
-        if let Some(cached_value) = self.cache {
-            self.cache = None;
+        self.generate_standard(generator) * param.stdev + param.mean
+    }
+}
 
-            return cached_value * param.stdev + param.mean;
-        }
+impl<T: FloatingPoint + GenerateCanonical<T>> PartialEq for NormalDistribution<T> {
+    fn eq(&self, other: &Self) -> bool { self.param == other.param && self.method == other.method && self.cache == other.cache }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use crate::rng::RandomNumberEngine;
+    use super::*;
+
+    /// The mean, variance, skewness and excess kurtosis of `count` samples.
+    fn moments(distribution: &mut NormalDistribution<f64>, count: usize) -> [f64; 4] {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        let samples = (0..count).map(|_| distribution.generate(&mut generator)).collect::<Vec<_>>();
+
+        let n = count as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let central = |k: i32| samples.iter().map(|x| (x - mean).powi(k)).sum::<f64>() / n;
+        let variance = central(2);
 
-        let normal_samples = loop {
-            let samples = [T::generate_canonical(generator), T::generate_canonical(generator)];
+        [mean, variance, central(3) / variance.powf(1.5), central(4) / variance.powi(2) - 3f64]
+    }
+
+    #[test]
+    fn test_normal_moments() {
+        for method in [NormalMethod::MarsagliaPolar, NormalMethod::Ziggurat] {
+            for (mean, stdev) in [(0f64, 1f64), (-3f64, 0.5f64), (1000f64, 20f64)] {
+                let [m, v, s, k] = moments(&mut NormalDistribution::new_with(mean, stdev, method), 200000);
 
-            if let Some(normal_samples) = detail::box_müller_marsaglia_polar(samples) {
-                break normal_samples;
+                // four standard errors each: sigma / sqrt(n), sigma^2 * sqrt(2 / n), sqrt(6 / n) and sqrt(24 / n)
+                assert!((m - mean).abs() < 4f64 * stdev / 447f64, "{:?}: mean {} instead of {}", method, m, mean);
+                assert!((v / (stdev * stdev) - 1f64).abs() < 4f64 * 0.00317, "{:?}: variance {} instead of {}", method, v, stdev * stdev);
+                assert!(s.abs() < 4f64 * 0.00548, "{:?}: skewness {}", method, s);
+                assert!(k.abs() < 4f64 * 0.011, "{:?}: excess kurtosis {}", method, k);
             }
-        };
+        }
+    }
+
+    #[test]
+    fn test_normal_cache() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        // the cached variate is scaled by the parameters it is returned with
+        let mut distribution = NormalDistribution::<f64>::default();
+        let first = distribution.generate(&mut generator);
+        let reference = generator;
+        let second = distribution.generate_param(&mut generator, &NormalDistributionParams::new(10f64, 2f64));
 
-        //self.cache = Some(normal_samples[0].get());
+        assert!(generator == reference, "the second variate of a pair must not consume entropy");
+        assert_ne!(first, second);
 
-        return normal_samples[1].get();
+        // the ziggurat does not cache, and reset discards the cache
+        let mut distribution = NormalDistribution::<f64>::default();
+        distribution.generate(&mut generator);
+        distribution.reset();
+        let reference = generator;
+        distribution.generate(&mut generator);
+        assert!(generator != reference);
+
+        let mut distribution = NormalDistribution::<f32>::default().ziggurat();
+        distribution.generate(&mut generator);
+        let reference = generator;
+        distribution.generate(&mut generator);
+        assert!(generator != reference);
     }
-}
 
-impl<T: FloatingPoint + GenerateCanonical<T>> PartialEq for NormalDistribution<T> {
-    fn eq(&self, other: &Self) -> bool { self.get_param() == other.get_param() }
+    #[test]
+    fn test_normal_param() {
+        let mut distribution = NormalDistribution::new(1f32, 2f32);
+        assert_eq!(distribution.get_param(), NormalDistributionParams { mean: 1f32, stdev: 2f32 });
+        assert_eq!((distribution.min(), distribution.max()), (f32::MIN, f32::MAX));
+        assert_eq!(distribution.method(), NormalMethod::MarsagliaPolar);
+
+        distribution.set_param(&NormalDistributionParams::new(-5f32, 0.5f32));
+        assert_eq!((distribution.mean(), distribution.stdev()), (-5f32, 0.5f32));
+
+        let distribution = distribution.ziggurat();
+        assert_eq!(distribution.method(), NormalMethod::Ziggurat);
+        assert_eq!(distribution.get_param(), NormalDistributionParams { mean: -5f32, stdev: 0.5f32 });
+    }
 }
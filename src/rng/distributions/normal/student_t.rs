@@ -0,0 +1,64 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// Student's t-distribution with `n` degrees of freedom, like `std::student_t_distribution`.
+///
+/// Generated as `z / sqrt(x / n)` from a standard normal `z` and a chi-squared `x` with `n` degrees of freedom.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct StudentTDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: StudentTDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct StudentTDistributionParams<T: FloatingPoint> {
+    pub n: T,
+}
+
+impl<T: FloatingPoint> StudentTDistributionParams<T> {
+    pub fn new(n: T) -> Self {
+        assert!(n > T::zero(), "the degrees of freedom of a Student t-distribution must be positive");
+        Self { n }
+    }
+}
+
+impl<T: FloatingPoint> Default for StudentTDistributionParams<T> {
+    fn default() -> Self { Self { n: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> StudentTDistribution<T> {
+    pub fn new(n: T) -> Self { Self { param: StudentTDistributionParams::new(n) } }
+
+    pub fn n(&self) -> T { self.param.n }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for StudentTDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for StudentTDistribution<T> {
+    type ResultType = T;
+    type ParamType = StudentTDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { <T as NumericLimits>::lowest() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let n: f64 = param.n.as_to();
+
+        let z = NormalDistribution::<f64>::default().ziggurat().generate(generator);
+        let x = ChiSquaredDistribution::<f64>::new(n).generate(generator);
+
+        T::as_from(z / (x / n).sqrt())
+    }
+}
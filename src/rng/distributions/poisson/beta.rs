@@ -0,0 +1,129 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// The beta distribution over `[0, 1]` with the shapes `alpha` and `beta`; not part of `<random>`.
+///
+/// Generated as `x / (x + y)` from two gamma variates, or by Jöhnk's algorithm if both shapes are at most 1, where
+/// the gamma variates would underflow.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BetaDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: BetaDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BetaDistributionParams<T: FloatingPoint> {
+    pub alpha: T,
+    pub beta: T,
+}
+
+impl<T: FloatingPoint> BetaDistributionParams<T> {
+    pub fn new(alpha: T, beta: T) -> Self {
+        assert!(alpha > T::zero() && beta > T::zero(), "the parameters of a beta distribution must be positive");
+        Self { alpha, beta }
+    }
+}
+
+impl<T: FloatingPoint> Default for BetaDistributionParams<T> {
+    fn default() -> Self { Self { alpha: T::as_from(1f64), beta: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> BetaDistribution<T> {
+    pub fn new(alpha: T, beta: T) -> Self { Self { param: BetaDistributionParams::new(alpha, beta) } }
+
+    pub fn alpha(&self) -> T { self.param.alpha }
+    pub fn beta(&self) -> T { self.param.beta }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for BetaDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for BetaDistribution<T> {
+    type ResultType = T;
+    type ParamType = BetaDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { T::one() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let alpha: f64 = param.alpha.as_to();
+        let beta: f64 = param.beta.as_to();
+
+        if alpha <= 1f64 && beta <= 1f64 {
+            return T::as_from(detail::johnk(generator, alpha, beta));
+        }
+
+        let x = GammaDistribution::<f64>::new(alpha, 1f64).generate(generator);
+        let y = GammaDistribution::<f64>::new(beta, 1f64).generate(generator);
+
+        T::as_from(x / (x + y))
+    }
+}
+
+mod detail {
+    use crate::rng::distributions::GenerateCanonical;
+    use crate::rng::*;
+
+    /// Jöhnk's algorithm: `u^(1/α) / (u^(1/α) + v^(1/β))` for the pairs where the denominator is at most 1.
+    pub fn johnk<G: UniformRandomBitGenerator>(generator: &mut G, alpha: f64, beta: f64) -> f64 {
+        loop {
+            let u = f64::generate_canonical_dense_open(generator).get();
+            let v = f64::generate_canonical_dense_open(generator).get();
+
+            let x = u.powf(1f64 / alpha);
+            let y = v.powf(1f64 / beta);
+
+            if x + y > 1f64 {
+                continue;
+            }
+
+            if x + y > 0f64 {
+                return x / (x + y);
+            }
+
+            // both underflowed, divide in log space
+            let log_x = u.ln() / alpha;
+            let log_y = v.ln() / beta;
+            let log_max = log_x.max(log_y);
+
+            let (log_x, log_y) = (log_x - log_max, log_y - log_max);
+
+            return (log_x - (log_x.exp() + log_y.exp()).ln()).exp();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_beta_mean() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        for (alpha, beta) in [(1e-3f64, 1e-3f64), (0.5, 0.5), (1.0, 1.0), (2.0, 5.0), (0.2, 30.0)] {
+            let mut distribution = BetaDistribution::new(alpha, beta);
+            let samples = (0..100000).map(|_| distribution.generate(&mut generator)).collect::<Vec<_>>();
+
+            assert!(samples.iter().all(|&x| (0f64..=1f64).contains(&x)), "out of range for alpha = {}, beta = {}", alpha, beta);
+
+            let variance = alpha * beta / ((alpha + beta).powi(2) * (alpha + beta + 1f64));
+            let mean = samples.iter().sum::<f64>() / 100000f64;
+            let standard_error = (variance / 100000f64).sqrt();
+            assert!((mean - alpha / (alpha + beta)).abs() < 4f64 * standard_error, "mean {} for alpha = {}, beta = {}", mean, alpha, beta);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// The waiting time between events occurring at a rate of `lambda`, like `std::exponential_distribution`.
+///
+/// Uses a 256 layer ziggurat, which needs a single word of entropy and no transcendental function in about 98.9% of
+/// the cases.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ExponentialDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: ExponentialDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ExponentialDistributionParams<T: FloatingPoint> {
+    pub lambda: T,
+}
+
+impl<T: FloatingPoint> ExponentialDistributionParams<T> {
+    pub fn new(lambda: T) -> Self {
+        assert!(lambda > T::zero(), "the rate of an exponential distribution must be positive");
+        Self { lambda }
+    }
+}
+
+impl<T: FloatingPoint> Default for ExponentialDistributionParams<T> {
+    fn default() -> Self { Self { lambda: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> ExponentialDistribution<T> {
+    pub fn new(lambda: T) -> Self { Self { param: ExponentialDistributionParams::new(lambda) } }
+
+    pub fn lambda(&self) -> T { self.param.lambda }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for ExponentialDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for ExponentialDistribution<T> {
+    type ResultType = T;
+    type ParamType = ExponentialDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let lambda: f64 = param.lambda.as_to();
+
+        T::as_from(detail::standard_exponential(generator) / lambda)
+    }
+}
+
+mod detail {
+    use std::sync::OnceLock;
+
+    use crate::rng::distributions::{ziggurat::Ziggurat, GenerateCanonical};
+    use crate::rng::*;
+
+    fn ziggurat() -> &'static Ziggurat {
+        static ZIGGURAT: OnceLock<Ziggurat> = OnceLock::new();
+
+        ZIGGURAT.get_or_init(|| Ziggurat::new(7.69711747013105, 3.949659822581572e-3, |x| (-x).exp(), |y| -y.ln()))
+    }
+
+    pub fn standard_exponential<G: UniformRandomBitGenerator>(generator: &mut G) -> f64 {
+        // the exponential distribution is memoryless, its tail beyond r is r plus another sample
+        ziggurat().sample(generator, false, |generator, r| r - f64::generate_canonical_dense_open(generator).get().ln())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_exponential_mean() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        let mut distribution = ExponentialDistribution::new(4f64);
+        let samples = (0..100000).map(|_| distribution.generate(&mut generator)).collect::<Vec<_>>();
+
+        assert!(samples.iter().all(|&x| x >= 0f64));
+
+        // the standard error of the mean is 0.25 / sqrt(100000)
+        let mean = samples.iter().sum::<f64>() / 100000f64;
+        assert!((mean - 0.25).abs() < 0.0032, "{}", mean);
+
+        // the tail beyond r = 7.697 is reached with a probability of 0.05%
+        let mut distribution = ExponentialDistribution::new(1f32);
+        assert!((0..100000).any(|_| distribution.generate(&mut generator) > 7.7f32));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// The type I extreme value (Gumbel) distribution with location `a` and scale `b`, like
+/// `std::extreme_value_distribution`.
+///
+/// Generated by inversion, `a - b * ln(-ln(u))`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ExtremeValueDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: ExtremeValueDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ExtremeValueDistributionParams<T: FloatingPoint> {
+    pub a: T,
+    pub b: T,
+}
+
+impl<T: FloatingPoint> ExtremeValueDistributionParams<T> {
+    pub fn new(a: T, b: T) -> Self {
+        assert!(b > T::zero(), "the scale of an extreme value distribution must be positive");
+        Self { a, b }
+    }
+}
+
+impl<T: FloatingPoint> Default for ExtremeValueDistributionParams<T> {
+    fn default() -> Self { Self { a: T::as_from(0f64), b: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> ExtremeValueDistribution<T> {
+    pub fn new(a: T, b: T) -> Self { Self { param: ExtremeValueDistributionParams::new(a, b) } }
+
+    pub fn a(&self) -> T { self.param.a }
+    pub fn b(&self) -> T { self.param.b }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for ExtremeValueDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for ExtremeValueDistribution<T> {
+    type ResultType = T;
+    type ParamType = ExtremeValueDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { <T as NumericLimits>::lowest() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let a: f64 = param.a.as_to();
+        let b: f64 = param.b.as_to();
+
+        // dense, as u close to 0 produces the lower tail
+        let u = f64::generate_canonical_dense_open(generator).get();
+
+        T::as_from(a - b * (-u.ln()).ln())
+    }
+}
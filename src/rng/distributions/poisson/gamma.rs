@@ -0,0 +1,122 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// The gamma distribution with shape `alpha` and scale `beta`, like `std::gamma_distribution`.
+///
+/// Uses Marsaglia and Tsang's method ("A Simple Method for Generating Gamma Variables", 2000), boosted by
+/// `u^(1 / alpha)` for `alpha < 1`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GammaDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: GammaDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GammaDistributionParams<T: FloatingPoint> {
+    pub alpha: T,
+    pub beta: T,
+}
+
+impl<T: FloatingPoint> GammaDistributionParams<T> {
+    pub fn new(alpha: T, beta: T) -> Self {
+        assert!(alpha > T::zero() && beta > T::zero(), "the parameters of a gamma distribution must be positive");
+        Self { alpha, beta }
+    }
+}
+
+impl<T: FloatingPoint> Default for GammaDistributionParams<T> {
+    fn default() -> Self { Self { alpha: T::as_from(1f64), beta: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> GammaDistribution<T> {
+    pub fn new(alpha: T, beta: T) -> Self { Self { param: GammaDistributionParams::new(alpha, beta) } }
+
+    pub fn alpha(&self) -> T { self.param.alpha }
+    pub fn beta(&self) -> T { self.param.beta }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for GammaDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for GammaDistribution<T> {
+    type ResultType = T;
+    type ParamType = GammaDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let alpha: f64 = param.alpha.as_to();
+        let beta: f64 = param.beta.as_to();
+
+        T::as_from(detail::standard_gamma(generator, alpha) * beta)
+    }
+}
+
+mod detail {
+    use crate::rng::distributions::{GenerateCanonical, NormalDistribution};
+    use crate::rng::*;
+
+    pub fn standard_gamma<G: UniformRandomBitGenerator>(generator: &mut G, alpha: f64) -> f64 {
+        if alpha < 1f64 {
+            // Gamma(alpha) = Gamma(alpha + 1) * u^(1 / alpha)
+            let u = f64::generate_canonical_dense_open(generator).get();
+            return standard_gamma(generator, alpha + 1f64) * u.powf(1f64 / alpha);
+        }
+
+        let mut normal = NormalDistribution::<f64>::default().ziggurat();
+
+        let d = alpha - 1f64 / 3f64;
+        let c = 1f64 / (9f64 * d).sqrt();
+
+        loop {
+            let x = normal.generate(generator);
+            let v = (1f64 + c * x).powi(3);
+
+            if v <= 0f64 {
+                continue;
+            }
+
+            let u = f64::generate_canonical_open(generator).get();
+
+            // the squeeze avoids the logarithms most of the time
+            if u < 1f64 - 0.0331f64 * x.powi(4) || u.ln() < 0.5f64 * x * x + d * (1f64 - v + v.ln()) {
+                return d * v;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_gamma_mean() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        for (alpha, beta) in [(0.1f64, 1f64), (0.5, 3.0), (1.0, 1.0), (7.5, 0.5), (1000.0, 2.0)] {
+            let mut distribution = GammaDistribution::new(alpha, beta);
+            let samples = (0..100000).map(|_| distribution.generate(&mut generator)).collect::<Vec<_>>();
+
+            assert!(samples.iter().all(|&x| x >= 0f64));
+
+            let mean = samples.iter().sum::<f64>() / 100000f64;
+            let standard_error = (alpha * beta * beta / 100000f64).sqrt();
+            assert!((mean - alpha * beta).abs() < 4f64 * standard_error, "mean {} for alpha = {}, beta = {}", mean, alpha, beta);
+        }
+    }
+}
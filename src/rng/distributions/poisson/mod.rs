@@ -0,0 +1,154 @@
+pub mod beta;
+pub mod exponential;
+pub mod extreme_value;
+pub mod gamma;
+pub mod weibull;
+
+pub use beta::*;
+pub use exponential::*;
+pub use extreme_value::*;
+pub use gamma::*;
+pub use weibull::*;
+
+use crate::rng::distributions::detail::*;
+use crate::rng::*;
+use crate::*;
+
+/// The number of events in an interval where `mean` are expected, like `std::poisson_distribution`.
+///
+/// Means below 10 use the multiplication method, larger ones Hörmann's PTRS ("The Transformed Rejection Method for
+/// Generating Poisson Random Variables", 1993), which takes a constant expected time.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PoissonDistribution<T: Integral> {
+    param: PoissonDistributionParams,
+    phantom: std::marker::PhantomData<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PoissonDistributionParams {
+    pub mean: f64,
+}
+
+impl PoissonDistributionParams {
+    pub fn new(mean: f64) -> Self {
+        assert!(mean > 0f64 && mean.is_finite(), "the mean of a Poisson distribution must be positive");
+        Self { mean }
+    }
+}
+
+impl Default for PoissonDistributionParams {
+    fn default() -> Self { Self { mean: 1f64 } }
+}
+
+impl<T: Integral> PoissonDistribution<T> {
+    pub fn new(mean: f64) -> Self { Self { param: PoissonDistributionParams::new(mean), phantom: std::marker::PhantomData } }
+
+    pub fn mean(&self) -> f64 { self.param.mean }
+}
+
+impl<T: Integral> Default for PoissonDistribution<T> {
+    fn default() -> Self { Self { param: Default::default(), phantom: std::marker::PhantomData } }
+}
+
+impl<T: Integral> RandomNumberDistribution for PoissonDistribution<T> {
+    type ResultType = T;
+    type ParamType = PoissonDistributionParams;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { NumericLimits::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let events = if param.mean < 10f64 {
+            detail::multiplication(generator, param.mean)
+        } else {
+            detail::ptrs(generator, param.mean)
+        };
+
+        count_from_f64(events)
+    }
+}
+
+mod detail {
+    use crate::rng::distributions::GenerateCanonical;
+    use crate::rng::*;
+
+    /// Counts the uniform numbers that can be multiplied before the product drops below `exp(-mean)`.
+    pub fn multiplication<G: UniformRandomBitGenerator>(generator: &mut G, mean: f64) -> f64 {
+        let limit = (-mean).exp();
+
+        let mut events = 0f64;
+        let mut product = f64::generate_canonical(generator).get();
+
+        while product > limit {
+            events += 1f64;
+            product *= f64::generate_canonical(generator).get();
+        }
+
+        events
+    }
+
+    pub fn ptrs<G: UniformRandomBitGenerator>(generator: &mut G, mean: f64) -> f64 {
+        let sqrt_mean = mean.sqrt();
+        let log_mean = mean.ln();
+
+        let b = 0.931f64 + 2.53f64 * sqrt_mean;
+        let a = -0.059f64 + 0.02483f64 * b;
+        let inverse_alpha = 1.1239f64 + 1.1328f64 / (b - 3.4f64);
+        let v_r = 0.9277f64 - 3.6224f64 / (b - 2f64);
+
+        loop {
+            let u = f64::generate_canonical(generator).get() - 0.5f64;
+            let v = f64::generate_canonical_open(generator).get();
+
+            let u_s = 0.5f64 - u.abs();
+            let k = ((2f64 * a / u_s + b) * u + mean + 0.43f64).floor();
+
+            // the region where the hat and the pmf agree closely enough to accept outright
+            if u_s >= 0.07f64 && v <= v_r {
+                return k;
+            }
+
+            if k < 0f64 || (u_s < 0.013f64 && v > u_s) {
+                continue;
+            }
+
+            if v.ln() + inverse_alpha.ln() - (a / (u_s * u_s) + b).ln() <= -mean + k * log_mean - (k + 1f64).ln_gamma().0 {
+                return k;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_poisson_mean() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        for mean in [0.01f64, 1f64, 9.99f64, 10f64, 1234.5f64, 1e12f64] {
+            let mut distribution = PoissonDistribution::<u64>::new(mean);
+            let sample_mean = (0..100000).map(|_| distribution.generate(&mut generator) as f64).sum::<f64>() / 100000f64;
+            let standard_error = (mean / 100000f64).sqrt();
+
+            assert!((sample_mean - mean).abs() < 4f64 * standard_error, "mean {} for {}", sample_mean, mean);
+        }
+
+        // the tail saturates instead of wrapping
+        let mut distribution = PoissonDistribution::<u8>::new(1000f64);
+        assert!((0..100).all(|_| distribution.generate(&mut generator) == u8::MAX));
+    }
+}
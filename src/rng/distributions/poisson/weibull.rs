@@ -0,0 +1,66 @@
+use crate::rng::distributions::*;
+use crate::rng::*;
+use crate::*;
+
+/// The Weibull distribution with shape `a` and scale `b`, like `std::weibull_distribution`.
+///
+/// Generated as `b * e^(1 / a)` from a standard exponential variate `e`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WeibullDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: WeibullDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WeibullDistributionParams<T: FloatingPoint> {
+    pub a: T,
+    pub b: T,
+}
+
+impl<T: FloatingPoint> WeibullDistributionParams<T> {
+    pub fn new(a: T, b: T) -> Self {
+        assert!(a > T::zero() && b > T::zero(), "the parameters of a Weibull distribution must be positive");
+        Self { a, b }
+    }
+}
+
+impl<T: FloatingPoint> Default for WeibullDistributionParams<T> {
+    fn default() -> Self { Self { a: T::as_from(1f64), b: T::as_from(1f64) } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> WeibullDistribution<T> {
+    pub fn new(a: T, b: T) -> Self { Self { param: WeibullDistributionParams::new(a, b) } }
+
+    pub fn a(&self) -> T { self.param.a }
+    pub fn b(&self) -> T { self.param.b }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for WeibullDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for WeibullDistribution<T> {
+    type ResultType = T;
+    type ParamType = WeibullDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { <T as NumericLimits>::max() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        let a: f64 = param.a.as_to();
+        let b: f64 = param.b.as_to();
+
+        let e = ExponentialDistribution::<f64>::default().generate(generator);
+
+        T::as_from(b * e.powf(1f64 / a))
+    }
+}
@@ -0,0 +1,182 @@
+pub mod piecewise_constant;
+pub mod piecewise_constant_2d;
+pub mod piecewise_linear;
+
+pub use piecewise_constant::*;
+pub use piecewise_constant_2d::*;
+pub use piecewise_linear::*;
+
+use std::marker::PhantomData;
+
+use crate::rng::distributions::{GenerateCanonical, UniformIntDistribution};
+use crate::rng::*;
+use crate::*;
+
+/// Indices in `0..n` with probabilities proportional to `n` weights, like `std::discrete_distribution`.
+///
+/// Samples take a constant time using Vose's alias method ("A Linear Algorithm for Generating Random Numbers with a
+/// Given Distribution", 1991): a uniformly chosen column holds the index itself with some probability and the index of
+/// one other, its alias, otherwise.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DiscreteDistribution<T: Integral> {
+    param: DiscreteDistributionParams,
+    data: PhantomData<T>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct DiscreteDistributionParams {
+    probabilities: Vec<f64>,
+    thresholds: Vec<f64>,
+    aliases: Vec<usize>,
+}
+
+impl DiscreteDistributionParams {
+    /// An empty list of weights is treated as a single weight, as in the standard.
+    pub fn new(weights: &[f64]) -> Self {
+        if weights.is_empty() {
+            return Self::new(&[1f64]);
+        }
+
+        assert!(weights.iter().all(|w| w.is_finite() && *w >= 0f64), "the weights must be finite and non-negative");
+
+        let sum = weights.iter().sum::<f64>();
+        assert!(sum > 0f64, "at least one weight must be positive");
+
+        let probabilities = weights.iter().map(|w| w / sum).collect::<Vec<_>>();
+        let (thresholds, aliases) = detail::alias_table(&probabilities);
+
+        Self { probabilities, thresholds, aliases }
+    }
+
+    /// `count` weights sampled from `fw` at the midpoints of equal subintervals of `[xmin, xmax]`.
+    pub fn from_fn(count: usize, xmin: f64, xmax: f64, fw: impl Fn(f64) -> f64) -> Self {
+        let count = std::cmp::max(count, 1);
+        let delta = (xmax - xmin) / count as f64;
+
+        Self::new(&(0..count).map(|i| fw(xmin + (i as f64 + 0.5f64) * delta)).collect::<Vec<_>>())
+    }
+
+    /// The normalised weights.
+    pub fn probabilities(&self) -> &[f64] { &self.probabilities }
+}
+
+impl Default for DiscreteDistributionParams {
+    fn default() -> Self { Self::new(&[]) }
+}
+
+impl<T: Integral> DiscreteDistribution<T> {
+    pub fn new(weights: &[f64]) -> Self { Self { param: DiscreteDistributionParams::new(weights), data: PhantomData } }
+
+    pub fn from_fn(count: usize, xmin: f64, xmax: f64, fw: impl Fn(f64) -> f64) -> Self {
+        Self { param: DiscreteDistributionParams::from_fn(count, xmin, xmax, fw), data: PhantomData }
+    }
+
+    pub fn probabilities(&self) -> &[f64] { self.param.probabilities() }
+}
+
+impl<T: Integral> Default for DiscreteDistribution<T> {
+    fn default() -> Self { Self { param: Default::default(), data: PhantomData } }
+}
+
+impl<T: Integral> RandomNumberDistribution for DiscreteDistribution<T> {
+    type ResultType = T;
+    type ParamType = DiscreteDistributionParams;
+
+    fn min(&self) -> Self::ResultType { T::zero() }
+    fn max(&self) -> Self::ResultType { T::as_from(self.param.probabilities.len() - 1) }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param.clone() }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = param.clone(); }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        T::as_from(detail::sample_alias(generator, &self.param))
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        T::as_from(detail::sample_alias(generator, param))
+    }
+}
+
+mod detail {
+    use super::*;
+
+    /// The probability of keeping each column and the index it falls back to otherwise.
+    pub fn alias_table(probabilities: &[f64]) -> (Vec<f64>, Vec<usize>) {
+        let n = probabilities.len();
+
+        let mut scaled = probabilities.iter().map(|p| p * n as f64).collect::<Vec<_>>();
+        let mut thresholds = vec![1f64; n];
+        let mut aliases = (0..n).collect::<Vec<_>>();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| scaled[i] < 1f64);
+
+        // every step fills up one column with the excess of a large one, which may become small itself
+        while let (Some(s), Some(&l)) = (small.pop(), large.last()) {
+            thresholds[s] = scaled[s];
+            aliases[s] = l;
+
+            scaled[l] = (scaled[l] + scaled[s]) - 1f64;
+
+            if scaled[l] < 1f64 {
+                large.pop();
+                small.push(l);
+            }
+        }
+
+        // whatever is left over is 1 up to rounding
+        (thresholds, aliases)
+    }
+
+    pub fn sample_alias<G: UniformRandomBitGenerator>(generator: &mut G, param: &DiscreteDistributionParams) -> usize {
+        let column = UniformIntDistribution::new(0, param.thresholds.len() - 1).generate(generator);
+
+        if f64::generate_canonical(generator).get() < param.thresholds[column] {
+            column
+        } else {
+            param.aliases[column]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    #[test]
+    fn test_alias_table() {
+        let weights = [1f64, 0f64, 7f64, 2f64, 0.5f64, 3f64, 0f64];
+        let param = DiscreteDistributionParams::new(&weights);
+
+        // the mass of an index is its own column's share and what the columns aliasing it give away
+        let n = weights.len() as f64;
+        let mut mass = param.thresholds.iter().map(|t| t / n).collect::<Vec<_>>();
+
+        for (column, &alias) in param.aliases.iter().enumerate() {
+            mass[alias] += (1f64 - param.thresholds[column]) / n;
+        }
+
+        for (i, w) in weights.iter().enumerate() {
+            assert!((mass[i] - w / 13.5f64).abs() < 1e-12, "index {} has a mass of {}", i, mass[i]);
+        }
+    }
+
+    #[test]
+    fn test_discrete_degenerate() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        let mut distribution = DiscreteDistribution::<u8>::default();
+        assert_eq!(distribution.probabilities(), &[1f64]);
+        assert!((0..100).all(|_| distribution.generate(&mut generator) == 0));
+
+        let mut distribution = DiscreteDistribution::<i32>::new(&[0f64, 0f64, 4f64, 0f64]);
+        assert_eq!((distribution.min(), distribution.max()), (0, 3));
+        assert!((0..1000).all(|_| distribution.generate(&mut generator) == 2));
+
+        distribution.set_param(&DiscreteDistributionParams::from_fn(4, 0f64, 4f64, |x| x));
+        assert_eq!(distribution.get_param().probabilities(), &[0.0625f64, 0.1875f64, 0.3125f64, 0.4375f64]);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::rng::distributions::GenerateCanonical;
+use crate::rng::*;
+use crate::*;
+
+/// Floats over `[b_0, b_n)` whose density is constant on each interval `[b_i, b_i+1)`, like
+/// `std::piecewise_constant_distribution`; the weight of an interval is its probability.
+///
+/// Samples invert the cumulative distribution, so that neighbouring canonical numbers map to neighbouring samples,
+/// which keeps stratified and low discrepancy inputs well distributed.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PiecewiseConstantDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: PiecewiseConstantDistributionParams<T>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct PiecewiseConstantDistributionParams<T: FloatingPoint> {
+    intervals: Vec<T>,
+    densities: Vec<T>,
+    cdf: Vec<f64>,
+}
+
+impl<T: FloatingPoint> PiecewiseConstantDistributionParams<T> {
+    /// `weights` needs one element less than `intervals`; fewer than two boundaries make up `[0, 1)`, as in the
+    /// standard.
+    pub fn new(intervals: &[T], weights: &[T]) -> Self {
+        if intervals.len() < 2 {
+            return Self::new(&[T::zero(), T::one()], &[T::one()]);
+        }
+
+        assert!(intervals.windows(2).all(|w| w[0] < w[1]), "the boundaries of the intervals must be increasing");
+        assert_eq!(weights.len() + 1, intervals.len(), "every interval needs exactly one weight");
+
+        let weights = weights.iter().map(|&w| -> f64 { w.as_to() }).collect::<Vec<_>>();
+        assert!(weights.iter().all(|w| w.is_finite() && *w >= 0f64), "the weights must be finite and non-negative");
+
+        let sum = weights.iter().sum::<f64>();
+        assert!(sum > 0f64, "at least one weight must be positive");
+
+        let mut cdf = Vec::with_capacity(intervals.len());
+        cdf.push(0f64);
+
+        for w in &weights {
+            cdf.push(cdf.last().unwrap() + w / sum);
+        }
+
+        // the last interval with any weight ends at exactly 1 so that no canonical number falls past it
+        let last = weights.iter().rposition(|&w| w > 0f64).unwrap();
+        cdf[last + 1..].fill(1f64);
+
+        let densities = weights
+            .iter()
+            .zip(intervals.windows(2))
+            .map(|(w, b)| {
+                let width: f64 = (b[1] - b[0]).as_to();
+                T::as_from(w / (sum * width))
+            })
+            .collect();
+
+        Self { intervals: intervals.to_vec(), densities, cdf }
+    }
+
+    /// `count` intervals of equal width over `[xmin, xmax)` weighted by `fw` at their midpoints.
+    pub fn from_fn(count: usize, xmin: T, xmax: T, fw: impl Fn(T) -> T) -> Self {
+        let count = std::cmp::max(count, 1);
+        let delta = (xmax - xmin) / T::as_from(count);
+
+        let intervals = (0..=count).map(|i| if i == count { xmax } else { xmin + T::as_from(i) * delta }).collect::<Vec<_>>();
+        let weights = intervals.windows(2).map(|b| fw((b[0] + b[1]) * T::as_from(0.5f64))).collect::<Vec<_>>();
+
+        Self::new(&intervals, &weights)
+    }
+
+    pub fn intervals(&self) -> &[T] { &self.intervals }
+
+    /// The normalised density on each interval.
+    pub fn densities(&self) -> &[T] { &self.densities }
+
+    /// The sample for the canonical number `u` in `[0, 1)` and its density.
+    pub fn sample_canonical(&self, u: T) -> (T, T) {
+        let (i, x) = self.locate(u.as_to());
+
+        (x, self.densities[i])
+    }
+
+    pub fn pdf(&self, x: T) -> T {
+        match self.interval_of(x) {
+            Some(i) => self.densities[i],
+            None => T::zero(),
+        }
+    }
+
+    pub fn cdf(&self, x: T) -> T {
+        match self.interval_of(x) {
+            Some(i) => {
+                let (b_0, b_1): (f64, f64) = (self.intervals[i].as_to(), self.intervals[i + 1].as_to());
+                let x: f64 = x.as_to();
+                let t = (x - b_0) / (b_1 - b_0);
+
+                T::as_from(self.cdf[i] + t * (self.cdf[i + 1] - self.cdf[i]))
+            }
+            None if x < self.intervals[0] => T::zero(),
+            None => T::one(),
+        }
+    }
+
+    /// The interval `u` falls into and the sample, clamped into that interval.
+    pub(crate) fn locate(&self, u: f64) -> (usize, T) {
+        // the first boundary of the cdf past u ends the interval, which skips those without weight
+        let n = self.densities.len();
+        let i = self.cdf.partition_point(|&c| c <= u).clamp(1, n) - 1;
+
+        let (c_0, c_1) = (self.cdf[i], self.cdf[i + 1]);
+        let (b_0, b_1): (f64, f64) = (self.intervals[i].as_to(), self.intervals[i + 1].as_to());
+        let x = T::as_from(b_0 + (u - c_0) / (c_1 - c_0) * (b_1 - b_0));
+
+        // rounding must not move the sample out of the interval of its density
+        let x = if x < self.intervals[i] { self.intervals[i] } else { x };
+        let x = if x < self.intervals[i + 1] { x } else { self.intervals[i + 1].next_down() };
+
+        (i, x)
+    }
+
+    fn interval_of(&self, x: T) -> Option<usize> {
+        if !(self.intervals[0] <= x && x < *self.intervals.last().unwrap()) {
+            return None;
+        }
+
+        Some(self.intervals.partition_point(|&b| b <= x) - 1)
+    }
+}
+
+impl<T: FloatingPoint> Default for PiecewiseConstantDistributionParams<T> {
+    fn default() -> Self { Self::new(&[], &[]) }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> PiecewiseConstantDistribution<T> {
+    pub fn new(intervals: &[T], weights: &[T]) -> Self { Self { param: PiecewiseConstantDistributionParams::new(intervals, weights) } }
+
+    pub fn from_fn(count: usize, xmin: T, xmax: T, fw: impl Fn(T) -> T) -> Self {
+        Self { param: PiecewiseConstantDistributionParams::from_fn(count, xmin, xmax, fw) }
+    }
+
+    pub fn intervals(&self) -> &[T] { self.param.intervals() }
+    pub fn densities(&self) -> &[T] { self.param.densities() }
+
+    pub fn sample_canonical(&self, u: T) -> (T, T) { self.param.sample_canonical(u) }
+    pub fn pdf(&self, x: T) -> T { self.param.pdf(x) }
+    pub fn cdf(&self, x: T) -> T { self.param.cdf(x) }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for PiecewiseConstantDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for PiecewiseConstantDistribution<T> {
+    type ResultType = T;
+    type ParamType = PiecewiseConstantDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { self.param.intervals[0] }
+    fn max(&self) -> Self::ResultType { self.param.intervals.last().unwrap().next_down() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param.clone() }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = param.clone(); }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        self.param.locate(f64::generate_canonical(generator).get()).1
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        param.locate(f64::generate_canonical(generator).get()).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piecewise_constant_params() {
+        let distribution = PiecewiseConstantDistribution::new(&[0f64, 1f64, 3f64, 4f64, 8f64], &[1f64, 0f64, 2f64, 1f64]);
+        assert_eq!(distribution.densities(), &[0.25f64, 0f64, 0.5f64, 0.0625f64]);
+
+        assert_eq!(distribution.sample_canonical(0f64), (0f64, 0.25f64));
+        assert_eq!(distribution.sample_canonical(0.125f64), (0.5f64, 0.25f64));
+        // the interval without weight is skipped
+        assert_eq!(distribution.sample_canonical(0.25f64), (3f64, 0.5f64));
+        assert_eq!(distribution.sample_canonical(0.875f64), (6f64, 0.0625f64));
+        let (x, _) = distribution.sample_canonical(1f64.next_down());
+        assert!(7.999f64 < x && x < 8f64);
+
+        assert_eq!(distribution.pdf(2f64), 0f64);
+        assert_eq!(distribution.pdf(3f64), 0.5f64);
+        assert_eq!(distribution.pdf(8f64), 0f64);
+        assert_eq!(distribution.cdf(3.5f64), 0.5f64);
+        assert_eq!(distribution.cdf(-1f64), 0f64);
+
+        let distribution = PiecewiseConstantDistribution::<f32>::default();
+        assert_eq!((distribution.min(), distribution.max()), (0f32, 1f32.next_down()));
+        assert_eq!(distribution.densities(), &[1f32]);
+
+        let distribution = PiecewiseConstantDistribution::from_fn(4, 0f64, 2f64, |x| x);
+        assert_eq!(distribution.intervals(), &[0f64, 0.5f64, 1f64, 1.5f64, 2f64]);
+        assert_eq!(distribution.densities(), &[0.125f64, 0.375f64, 0.625f64, 0.875f64]);
+    }
+
+    #[test]
+    fn test_piecewise_constant_trailing_zeros() {
+        // the last interval with weight may end before the last boundary
+        let distribution = PiecewiseConstantDistribution::new(&[0f32, 1f32, 2f32, 3f32], &[0f32, 1f32, 0f32]);
+
+        for u in [0f32, 0.5f32, 1f32.next_down()] {
+            let (x, pdf) = distribution.sample_canonical(u);
+            assert!((1f32..2f32).contains(&x));
+            assert_eq!(pdf, 1f32);
+        }
+    }
+}
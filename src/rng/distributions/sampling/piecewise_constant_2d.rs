@@ -0,0 +1,125 @@
+use crate::rng::distributions::sphere::NDSampler;
+use crate::rng::distributions::GenerateCanonical;
+use crate::rng::*;
+use crate::*;
+
+use super::PiecewiseConstantDistributionParams;
+
+/// Points in the unit square whose density is proportional to a grid of non-negative values, e.g. the luminance of an
+/// environment map.
+///
+/// The row is drawn from the marginal distribution of the row sums and the column from the conditional distribution
+/// of that row, both by inversion. `values` are in row-major order, columns along the first and rows along the second
+/// coordinate.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PiecewiseConstant2DDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    width: usize,
+    marginal: PiecewiseConstantDistributionParams<T>,
+    conditionals: Vec<PiecewiseConstantDistributionParams<T>>,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> PiecewiseConstant2DDistribution<T> {
+    pub fn new(values: &[T], width: usize) -> Self {
+        assert!(width > 0 && !values.is_empty() && values.len().is_multiple_of(width), "the values must form a non-empty grid");
+
+        let height = values.len() / width;
+        let boundaries = |n: usize| (0..=n).map(|i| T::as_from(i as f64 / n as f64)).collect::<Vec<_>>();
+        let (columns, rows) = (boundaries(width), boundaries(height));
+
+        let mut row_sums = Vec::with_capacity(height);
+
+        let conditionals = values
+            .chunks(width)
+            .map(|row| {
+                let sum = row.iter().fold(T::zero(), |sum, &v| sum + v);
+                row_sums.push(sum);
+
+                // never chosen, but the conditional still has to be a distribution
+                if sum > T::zero() {
+                    PiecewiseConstantDistributionParams::new(&columns, row)
+                } else {
+                    PiecewiseConstantDistributionParams::new(&columns, &vec![T::one(); width])
+                }
+            })
+            .collect();
+
+        Self { width, marginal: PiecewiseConstantDistributionParams::new(&rows, &row_sums), conditionals }
+    }
+
+    pub fn width(&self) -> usize { self.width }
+    pub fn height(&self) -> usize { self.conditionals.len() }
+
+    /// The sample for the canonical numbers `u` in `[0, 1)^2` and its density.
+    pub fn sample_canonical(&self, u: [T; 2]) -> ([T; 2], T) {
+        let (row, y) = self.marginal.locate(u[1].as_to());
+        let conditional = &self.conditionals[row];
+        let (column, x) = conditional.locate(u[0].as_to());
+
+        ([x, y], self.marginal.densities()[row] * conditional.densities()[column])
+    }
+
+    pub fn pdf(&self, point: [T; 2]) -> T {
+        let marginal = self.marginal.pdf(point[1]);
+
+        if marginal == T::zero() {
+            return T::zero();
+        }
+
+        let row = self.marginal.intervals().partition_point(|&b| b <= point[1]) - 1;
+
+        marginal * self.conditionals[row].pdf(point[0])
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 2> for PiecewiseConstant2DDistribution<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 2], T) {
+        let u = T::generate_canonical(gen).get();
+        let v = T::generate_canonical(gen).get();
+
+        PiecewiseConstant2DDistribution::sample_canonical(self, [u, v])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 2], T) { PiecewiseConstant2DDistribution::sample_canonical(self, [u[0], u[1]]) }
+
+    fn pdf(&self, point: &[T; 2]) -> T { PiecewiseConstant2DDistribution::pdf(self, *point) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piecewise_constant_2d() {
+        // 4 columns, 2 rows; the mean is 1
+        let values = [0f64, 2f64, 2f64, 0f64, 1f64, 0f64, 0f64, 3f64];
+        let distribution = PiecewiseConstant2DDistribution::new(&values, 4);
+        assert_eq!((distribution.width(), distribution.height()), (4, 2));
+
+        for (i, &value) in values.iter().enumerate() {
+            let center = [(i % 4) as f64 / 4f64 + 0.125f64, (i / 4) as f64 / 2f64 + 0.25f64];
+            assert_eq!(distribution.pdf(center), value);
+        }
+
+        assert_eq!(distribution.pdf([0.5f64, 1f64]), 0f64);
+
+        assert_eq!(distribution.sample_canonical([0f64, 0f64]), ([0.25f64, 0f64], 2f64));
+        assert_eq!(distribution.sample_canonical([0.25f64, 0.75f64]), ([0.75f64, 0.75f64], 3f64));
+
+        let (point, pdf) = distribution.sample_canonical([1f64.next_down(), 1f64.next_down()]);
+        assert!(point[0] < 1f64 && point[1] < 1f64);
+        assert_eq!(pdf, distribution.pdf(point));
+    }
+
+    #[test]
+    fn test_piecewise_constant_2d_empty_row() {
+        let distribution = PiecewiseConstant2DDistribution::new(&[0f32, 0f32, 1f32, 3f32], 2);
+
+        for u in [0f32, 0.3f32, 0.9f32] {
+            let (point, pdf) = distribution.sample_canonical([u, u]);
+            assert!(point[1] >= 0.5f32);
+            assert_eq!(pdf, distribution.pdf(point));
+        }
+    }
+}
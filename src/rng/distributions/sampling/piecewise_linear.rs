@@ -0,0 +1,222 @@
+use crate::rng::distributions::GenerateCanonical;
+use crate::rng::*;
+use crate::*;
+
+/// Floats over `[b_0, b_n)` whose density interpolates linearly between weights at the boundaries, like
+/// `std::piecewise_linear_distribution`.
+///
+/// Like `PiecewiseConstantDistribution`, samples invert the cumulative distribution, within an interval by solving the
+/// quadratic it is made of.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PiecewiseLinearDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: PiecewiseLinearDistributionParams<T>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct PiecewiseLinearDistributionParams<T: FloatingPoint> {
+    intervals: Vec<T>,
+    densities: Vec<T>,
+    cdf: Vec<f64>,
+}
+
+impl<T: FloatingPoint> PiecewiseLinearDistributionParams<T> {
+    /// `weights` needs as many elements as `intervals`; fewer than two boundaries make up `[0, 1)` with a constant
+    /// density, as in the standard.
+    pub fn new(intervals: &[T], weights: &[T]) -> Self {
+        if intervals.len() < 2 {
+            return Self::new(&[T::zero(), T::one()], &[T::one(), T::one()]);
+        }
+
+        assert!(intervals.windows(2).all(|w| w[0] < w[1]), "the boundaries of the intervals must be increasing");
+        assert_eq!(weights.len(), intervals.len(), "every boundary needs exactly one weight");
+
+        let weights = weights.iter().map(|&w| -> f64 { w.as_to() }).collect::<Vec<_>>();
+        assert!(weights.iter().all(|w| w.is_finite() && *w >= 0f64), "the weights must be finite and non-negative");
+
+        // the area of each trapezoid
+        let areas = weights
+            .windows(2)
+            .zip(intervals.windows(2))
+            .map(|(w, b)| {
+                let width: f64 = (b[1] - b[0]).as_to();
+                (w[0] + w[1]) * 0.5f64 * width
+            })
+            .collect::<Vec<_>>();
+
+        let sum = areas.iter().sum::<f64>();
+        assert!(sum > 0f64, "the density must not vanish everywhere");
+
+        let mut cdf = Vec::with_capacity(intervals.len());
+        cdf.push(0f64);
+
+        for a in &areas {
+            cdf.push(cdf.last().unwrap() + a / sum);
+        }
+
+        let last = areas.iter().rposition(|&a| a > 0f64).unwrap();
+        cdf[last + 1..].fill(1f64);
+
+        let densities = weights.iter().map(|w| T::as_from(w / sum)).collect();
+
+        Self { intervals: intervals.to_vec(), densities, cdf }
+    }
+
+    /// `count` intervals of equal width over `[xmin, xmax)` with the weights `fw` takes at their boundaries.
+    pub fn from_fn(count: usize, xmin: T, xmax: T, fw: impl Fn(T) -> T) -> Self {
+        let count = std::cmp::max(count, 1);
+        let delta = (xmax - xmin) / T::as_from(count);
+
+        let intervals = (0..=count).map(|i| if i == count { xmax } else { xmin + T::as_from(i) * delta }).collect::<Vec<_>>();
+        let weights = intervals.iter().map(|&b| fw(b)).collect::<Vec<_>>();
+
+        Self::new(&intervals, &weights)
+    }
+
+    pub fn intervals(&self) -> &[T] { &self.intervals }
+
+    /// The normalised density at each boundary.
+    pub fn densities(&self) -> &[T] { &self.densities }
+
+    /// The sample for the canonical number `u` in `[0, 1)` and its density.
+    pub fn sample_canonical(&self, u: T) -> (T, T) {
+        let x = self.locate(u.as_to());
+
+        (x, self.pdf(x))
+    }
+
+    pub fn pdf(&self, x: T) -> T {
+        match self.interval_of(x) {
+            Some(i) => {
+                let (t, _) = self.position(i, x);
+                let (rho_0, rho_1): (f64, f64) = (self.densities[i].as_to(), self.densities[i + 1].as_to());
+
+                T::as_from(rho_0 + t * (rho_1 - rho_0))
+            }
+            None => T::zero(),
+        }
+    }
+
+    pub fn cdf(&self, x: T) -> T {
+        match self.interval_of(x) {
+            Some(i) => {
+                let (t, width) = self.position(i, x);
+                let (rho_0, rho_1): (f64, f64) = (self.densities[i].as_to(), self.densities[i + 1].as_to());
+
+                T::as_from(self.cdf[i] + width * t * (rho_0 + 0.5f64 * t * (rho_1 - rho_0)))
+            }
+            None if x < self.intervals[0] => T::zero(),
+            None => T::one(),
+        }
+    }
+
+    fn locate(&self, u: f64) -> T {
+        let n = self.intervals.len() - 1;
+        let i = self.cdf.partition_point(|&c| c <= u).clamp(1, n) - 1;
+
+        let (c_0, c_1) = (self.cdf[i], self.cdf[i + 1]);
+        let (b_0, b_1): (f64, f64) = (self.intervals[i].as_to(), self.intervals[i + 1].as_to());
+        let (rho_0, rho_1): (f64, f64) = (self.densities[i].as_to(), self.densities[i + 1].as_to());
+
+        // v = t (rho_0 + (rho_1 - rho_0) t / 2) / ((rho_0 + rho_1) / 2), solved for t without cancellation
+        let v = (u - c_0) / (c_1 - c_0);
+        let denominator = rho_0 + (rho_0 * rho_0 + v * (rho_1 * rho_1 - rho_0 * rho_0)).max(0f64).sqrt();
+        let t = if denominator > 0f64 { v * (rho_0 + rho_1) / denominator } else { 0f64 };
+
+        let x = T::as_from(b_0 + t * (b_1 - b_0));
+
+        let x = if x < self.intervals[i] { self.intervals[i] } else { x };
+        if x < self.intervals[i + 1] { x } else { self.intervals[i + 1].next_down() }
+    }
+
+    fn interval_of(&self, x: T) -> Option<usize> {
+        if !(self.intervals[0] <= x && x < *self.intervals.last().unwrap()) {
+            return None;
+        }
+
+        Some(self.intervals.partition_point(|&b| b <= x) - 1)
+    }
+
+    /// The relative position of `x` in the interval `i` and the width of the interval.
+    fn position(&self, i: usize, x: T) -> (f64, f64) {
+        let (b_0, b_1): (f64, f64) = (self.intervals[i].as_to(), self.intervals[i + 1].as_to());
+        let x: f64 = x.as_to();
+
+        ((x - b_0) / (b_1 - b_0), b_1 - b_0)
+    }
+}
+
+impl<T: FloatingPoint> Default for PiecewiseLinearDistributionParams<T> {
+    fn default() -> Self { Self::new(&[], &[]) }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> PiecewiseLinearDistribution<T> {
+    pub fn new(intervals: &[T], weights: &[T]) -> Self { Self { param: PiecewiseLinearDistributionParams::new(intervals, weights) } }
+
+    pub fn from_fn(count: usize, xmin: T, xmax: T, fw: impl Fn(T) -> T) -> Self {
+        Self { param: PiecewiseLinearDistributionParams::from_fn(count, xmin, xmax, fw) }
+    }
+
+    pub fn intervals(&self) -> &[T] { self.param.intervals() }
+    pub fn densities(&self) -> &[T] { self.param.densities() }
+
+    pub fn sample_canonical(&self, u: T) -> (T, T) { self.param.sample_canonical(u) }
+    pub fn pdf(&self, x: T) -> T { self.param.pdf(x) }
+    pub fn cdf(&self, x: T) -> T { self.param.cdf(x) }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for PiecewiseLinearDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for PiecewiseLinearDistribution<T> {
+    type ResultType = T;
+    type ParamType = PiecewiseLinearDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { self.param.intervals[0] }
+    fn max(&self) -> Self::ResultType { self.param.intervals.last().unwrap().next_down() }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param.clone() }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = param.clone(); }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        self.param.locate(f64::generate_canonical(generator).get())
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        param.locate(f64::generate_canonical(generator).get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piecewise_linear_params() {
+        // a triangle over [0, 2) peaking at 1
+        let distribution = PiecewiseLinearDistribution::new(&[0f64, 1f64, 2f64], &[0f64, 3f64, 0f64]);
+        assert_eq!(distribution.densities(), &[0f64, 1f64, 0f64]);
+
+        assert_eq!(distribution.sample_canonical(0f64), (0f64, 0f64));
+        assert_eq!(distribution.sample_canonical(0.125f64), (0.5f64, 0.5f64));
+        assert_eq!(distribution.sample_canonical(0.5f64), (1f64, 1f64));
+        assert_eq!(distribution.sample_canonical(0.875f64), (1.5f64, 0.5f64));
+
+        for x in [0.1f64, 0.7f64, 1.3f64, 1.9f64] {
+            let (y, _) = distribution.sample_canonical(distribution.cdf(x));
+            assert!((x - y).abs() < 1e-12, "the cdf of {} is inverted to {}", x, y);
+        }
+
+        assert_eq!(distribution.cdf(2f64), 1f64);
+        assert_eq!(distribution.pdf(-1f64), 0f64);
+
+        let distribution = PiecewiseLinearDistribution::<f32>::default();
+        assert_eq!(distribution.densities(), &[1f32, 1f32]);
+        assert_eq!(distribution.sample_canonical(0.25f32), (0.25f32, 1f32));
+
+        let distribution = PiecewiseLinearDistribution::from_fn(2, 0f64, 1f64, |x| x);
+        assert_eq!(distribution.densities(), &[0f64, 1f64, 2f64]);
+    }
+}
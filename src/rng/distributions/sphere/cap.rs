@@ -0,0 +1,129 @@
+use crate::smallvec::Vector;
+use crate::{FloatConstants, FloatingPoint, ZeroAndOne};
+
+use super::*;
+
+/// Directions within an angle of an axis, uniform over the solid angle of the cone.
+///
+/// As on the sphere, the cosine of the angle to the axis is uniformly distributed.
+pub struct UniformConeSampler<T: FloatingPoint + GenerateCanonical<T>> {
+    axis: Vector<T, 3>,
+    tangent: Vector<T, 3>,
+    bitangent: Vector<T, 3>,
+    cos_θ_max: T,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> UniformConeSampler<T> {
+    /// `axis` is a unit vector and `cos_θ_max` the cosine of the half angle of the cone.
+    pub fn new(axis: [T; 3], cos_θ_max: T) -> Self {
+        let one = <T as ZeroAndOne>::one();
+        assert!(-one <= cos_θ_max && cos_θ_max < one, "the cone must have a positive half angle of at most π");
+
+        let axis = Vector(axis);
+        let (tangent, bitangent) = detail::orthonormal_basis(axis);
+
+        Self { axis, tangent, bitangent, cos_θ_max }
+    }
+
+    pub fn axis(&self) -> [T; 3] { self.axis.0 }
+    pub fn cos_θ_max(&self) -> T { self.cos_θ_max }
+
+    pub fn solid_angle(&self) -> T { T::from_scalar(2).unwrap() * <T as FloatConstants>::PI * (<T as ZeroAndOne>::one() - self.cos_θ_max) }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 3> for UniformConeSampler<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let one = <T as ZeroAndOne>::one();
+
+        let cosθ = (one - u[0]) + u[0] * self.cos_θ_max;
+        let sinθ = (one - cosθ * cosθ).max(<T as ZeroAndOne>::zero()).sqrt();
+        let φ = T::from_scalar(2).unwrap() * <T as FloatConstants>::PI * u[1];
+
+        let direction = self.tangent * (sinθ * φ.cos()) + self.bitangent * (sinθ * φ.sin()) + self.axis * cosθ;
+
+        (direction.0, one / self.solid_angle())
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T {
+        if Vector(*point).dot(self.axis) >= self.cos_θ_max {
+            <T as ZeroAndOne>::one() / self.solid_angle()
+        } else {
+            <T as ZeroAndOne>::zero()
+        }
+    }
+
+    fn invert(&self, point: &[T; 3], u: &mut [T]) -> bool {
+        let one = <T as ZeroAndOne>::one();
+        let point = Vector(*point);
+
+        u[0] = detail::below_one(((one - point.dot(self.axis)) / (one - self.cos_θ_max)).max(<T as ZeroAndOne>::zero()));
+        u[1] = detail::canonical_from_angle(point.dot(self.bitangent), point.dot(self.tangent));
+
+        true
+    }
+}
+
+/// Points on the cap of a sphere around an axis through its centre, uniform over the area of the cap; the density
+/// is per unit area.
+pub struct UniformSphericalCapSampler<T: FloatingPoint + GenerateCanonical<T>> {
+    center: Vector<T, 3>,
+    radius: T,
+    cone: UniformConeSampler<T>,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> UniformSphericalCapSampler<T> {
+    /// The points of the sphere whose direction from `center` is within the cone of `axis` and `cos_θ_max`.
+    pub fn new(center: [T; 3], radius: T, axis: [T; 3], cos_θ_max: T) -> Self {
+        assert!(radius > <T as ZeroAndOne>::zero(), "the radius must be positive");
+
+        Self { center: Vector(center), radius, cone: UniformConeSampler::new(axis, cos_θ_max) }
+    }
+
+    /// The cap of the sphere that can be seen from `point` outside of it, which wastes no samples of a spherical
+    /// light on its far side.
+    pub fn visible_from(center: [T; 3], radius: T, point: [T; 3]) -> Self {
+        let to_point = Vector(point) - Vector(center);
+        let distance = to_point.length();
+        assert!(distance > radius, "the point must be outside of the sphere");
+
+        Self::new(center, radius, (to_point / distance).0, radius / distance)
+    }
+
+    pub fn center(&self) -> [T; 3] { self.center.0 }
+    pub fn radius(&self) -> T { self.radius }
+    pub fn cone(&self) -> &UniformConeSampler<T> { &self.cone }
+
+    pub fn area(&self) -> T { self.cone.solid_angle() * self.radius * self.radius }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 3> for UniformSphericalCapSampler<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let (direction, _) = self.cone.sample_canonical(u);
+
+        ((self.center + Vector(direction) * self.radius).0, <T as ZeroAndOne>::one() / self.area())
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T {
+        let direction = (Vector(*point) - self.center) / self.radius;
+
+        if self.cone.pdf(&direction.0) > <T as ZeroAndOne>::zero() {
+            <T as ZeroAndOne>::one() / self.area()
+        } else {
+            <T as ZeroAndOne>::zero()
+        }
+    }
+
+    fn invert(&self, point: &[T; 3], u: &mut [T]) -> bool { self.cone.invert(&((Vector(*point) - self.center) / self.radius).0, u) }
+}
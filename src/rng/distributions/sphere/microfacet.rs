@@ -0,0 +1,397 @@
+use crate::rng::stats::erf;
+use crate::smallvec::Vector;
+use crate::{FloatConstants, FloatingPoint, ZeroAndOne};
+
+use super::*;
+
+/// The distribution of the normals of the microfacets of a rough surface, in the frame whose z axis is the normal of
+/// the surface, with Smith's masking and shadowing.
+///
+/// Directions are unit vectors; `wo` and `wi` point away from the surface.
+pub trait MicrofacetDistribution<T: FloatingPoint> {
+    /// The density of the microfacet normal `h` per unit solid angle, `D(h)`; `D(h) h_z` integrates to 1.
+    fn d(&self, h: &[T; 3]) -> T;
+
+    /// Smith's `Λ(w)`, the shadowed area per unit of visible area of the microfacets seen from `w`.
+    fn lambda(&self, w: &[T; 3]) -> T;
+
+    /// The share of the microfacets seen from `w` that are not masked, `G1(w)`.
+    fn g1(&self, w: &[T; 3]) -> T { <T as ZeroAndOne>::one() / (<T as ZeroAndOne>::one() + self.lambda(w)) }
+
+    /// The share of the microfacets that are neither masked from `wo` nor shadowed from `wi`, `G2(wo, wi)`, with the
+    /// heights of the two taken into account.
+    fn g2(&self, wo: &[T; 3], wi: &[T; 3]) -> T { <T as ZeroAndOne>::one() / (<T as ZeroAndOne>::one() + self.lambda(wo) + self.lambda(wi)) }
+
+    /// The normal of a microfacet that is visible from `wo`, with `wo` above the surface, for the canonical numbers
+    /// `u`; the normals are distributed by `visible_normal_pdf`.
+    fn sample_visible_normal(&self, wo: &[T; 3], u: [T; 2]) -> [T; 3];
+
+    /// The density of the normals visible from `wo` per unit solid angle, `G1(wo) max(0, wo h) D(h) / wo_z`.
+    fn visible_normal_pdf(&self, wo: &[T; 3], h: &[T; 3]) -> T {
+        let cosine = Vector(*wo).dot(Vector(*h)).max(<T as ZeroAndOne>::zero());
+
+        self.g1(wo) * cosine * self.d(h) / wo[2].abs()
+    }
+}
+
+/// The GGX or Trowbridge–Reitz distribution of normals (Walter et al., "Microfacet Models for Refraction through
+/// Rough Surfaces", 2007) with the roughnesses `alpha_x` and `alpha_y` along the axes; the normals of the ellipsoid
+/// that the hemisphere stretches to.
+///
+/// As a sampler, the normals are distributed by `D(h) h_z`, the visible normals from straight above.
+#[derive(Copy, Clone, Debug)]
+pub struct GGXDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    alpha_x: T,
+    alpha_y: T,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> GGXDistribution<T> {
+    pub fn new(alpha_x: T, alpha_y: T) -> Self {
+        assert!(alpha_x > <T as ZeroAndOne>::zero() && alpha_y > <T as ZeroAndOne>::zero(), "the roughnesses must be positive");
+
+        Self { alpha_x, alpha_y }
+    }
+
+    pub fn isotropic(alpha: T) -> Self { Self::new(alpha, alpha) }
+
+    pub fn alpha_x(&self) -> T { self.alpha_x }
+    pub fn alpha_y(&self) -> T { self.alpha_y }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> MicrofacetDistribution<T> for GGXDistribution<T> {
+    fn d(&self, h: &[T; 3]) -> T {
+        if h[2] <= <T as ZeroAndOne>::zero() {
+            return <T as ZeroAndOne>::zero();
+        }
+
+        let (x, y) = (h[0] / self.alpha_x, h[1] / self.alpha_y);
+        let denominator = x * x + y * y + h[2] * h[2];
+
+        <T as FloatConstants>::FRAC_1_PI / (self.alpha_x * self.alpha_y * denominator * denominator)
+    }
+
+    fn lambda(&self, w: &[T; 3]) -> T {
+        let one = <T as ZeroAndOne>::one();
+        let square = detail::stretched_tangent_squared(self.alpha_x, self.alpha_y, w);
+
+        ((one + square).sqrt() - one) * T::from_scalar(0.5f64).unwrap()
+    }
+
+    /// Heitz, "Sampling the GGX Distribution of Visible Normals", 2018: the visible part of the stretched hemisphere
+    /// is a disk seen from `wo` whose lower half is foreshortened, the normal of the point above a sample of it is
+    /// stretched back.
+    fn sample_visible_normal(&self, wo: &[T; 3], u: [T; 2]) -> [T; 3] {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+        let half = T::from_scalar(0.5f64).unwrap();
+
+        let v = Vector([self.alpha_x * wo[0], self.alpha_y * wo[1], wo[2]]).normalized();
+
+        let length_squared = v[0] * v[0] + v[1] * v[1];
+        let t_1 = if length_squared > zero { Vector([-v[1], v[0], zero]) / length_squared.sqrt() } else { Vector([one, zero, zero]) };
+        let t_2 = v.cross(t_1);
+
+        let r = u[0].sqrt();
+        let φ = T::from_scalar(2).unwrap() * <T as FloatConstants>::PI * u[1];
+        let (p_1, p_2) = (r * φ.cos(), r * φ.sin());
+
+        let s = half * (one + v[2]);
+        let p_2 = (one - s) * (one - p_1 * p_1).max(zero).sqrt() + s * p_2;
+
+        let n = t_1 * p_1 + t_2 * p_2 + v * (one - p_1 * p_1 - p_2 * p_2).max(zero).sqrt();
+
+        Vector([self.alpha_x * n[0], self.alpha_y * n[1], n[2].max(zero)]).normalized().0
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 3> for GGXDistribution<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let h = self.sample_visible_normal(&detail::up(), [u[0], u[1]]);
+
+        (h, NDSampler::pdf(self, &h))
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T { self.d(point) * point[2].max(<T as ZeroAndOne>::zero()) }
+}
+
+/// The Beckmann distribution of normals, whose slopes are normally distributed with the deviations `alpha_x / √2`
+/// and `alpha_y / √2` along the axes.
+///
+/// As a sampler, the normals are distributed by `D(h) h_z`, the visible normals from straight above.
+#[derive(Copy, Clone, Debug)]
+pub struct BeckmannDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    alpha_x: T,
+    alpha_y: T,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> BeckmannDistribution<T> {
+    pub fn new(alpha_x: T, alpha_y: T) -> Self {
+        assert!(alpha_x > <T as ZeroAndOne>::zero() && alpha_y > <T as ZeroAndOne>::zero(), "the roughnesses must be positive");
+
+        Self { alpha_x, alpha_y }
+    }
+
+    pub fn isotropic(alpha: T) -> Self { Self::new(alpha, alpha) }
+
+    pub fn alpha_x(&self) -> T { self.alpha_x }
+    pub fn alpha_y(&self) -> T { self.alpha_y }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> MicrofacetDistribution<T> for BeckmannDistribution<T> {
+    fn d(&self, h: &[T; 3]) -> T {
+        if h[2] <= <T as ZeroAndOne>::zero() {
+            return <T as ZeroAndOne>::zero();
+        }
+
+        let (x, y) = (h[0] / self.alpha_x, h[1] / self.alpha_y);
+        let cos_2 = h[2] * h[2];
+
+        (-(x * x + y * y) / cos_2).exp() * <T as FloatConstants>::FRAC_1_PI / (self.alpha_x * self.alpha_y * cos_2 * cos_2)
+    }
+
+    fn lambda(&self, w: &[T; 3]) -> T {
+        let square: f64 = detail::stretched_tangent_squared(self.alpha_x, self.alpha_y, w).as_to();
+
+        if square == 0f64 {
+            return <T as ZeroAndOne>::zero();
+        }
+
+        let a = 1f64 / square.sqrt();
+        let lambda = (erf(a) - 1f64) / 2f64 + (-a * a).exp() / (2f64 * a * std::f64::consts::PI.sqrt());
+
+        T::as_from(lambda.max(0f64))
+    }
+
+    /// Heitz and d'Eon, "Importance Sampling Microfacet-Based BSDFs using the Distribution of Visible Normals", 2014,
+    /// with the fit of Jakob for the inverse of the marginal of the slopes: the slopes are sampled for the isotropic
+    /// distribution of roughness 1, rotated to `wo` and stretched.
+    fn sample_visible_normal(&self, wo: &[T; 3], u: [T; 2]) -> [T; 3] {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+
+        let v = Vector([self.alpha_x * wo[0], self.alpha_y * wo[1], wo[2]]).normalized();
+        let (slope_x, slope_y) = detail::beckmann_standard_slopes(v[2].as_to(), u[0].as_to(), u[1].as_to());
+        let (slope_x, slope_y) = (T::as_from(slope_x), T::as_from(slope_y));
+
+        let sin_θ = (v[0] * v[0] + v[1] * v[1]).sqrt();
+        let (cos_φ, sin_φ) = if sin_θ > zero { (v[0] / sin_θ, v[1] / sin_θ) } else { (one, zero) };
+
+        let x = (cos_φ * slope_x - sin_φ * slope_y) * self.alpha_x;
+        let y = (sin_φ * slope_x + cos_φ * slope_y) * self.alpha_y;
+
+        Vector([-x, -y, one]).normalized().0
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 3> for BeckmannDistribution<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let h = self.sample_visible_normal(&detail::up(), [u[0], u[1]]);
+
+        (h, NDSampler::pdf(self, &h))
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T { self.d(point) * point[2].max(<T as ZeroAndOne>::zero()) }
+}
+
+/// The normals of a microfacet distribution that are visible from a direction, with the density per unit solid
+/// angle of `MicrofacetDistribution::visible_normal_pdf`.
+pub struct VisibleNormalSampler<T: FloatingPoint + GenerateCanonical<T>, Distribution: MicrofacetDistribution<T>> {
+    distribution: Distribution,
+    wo: [T; 3],
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, Distribution: MicrofacetDistribution<T>> VisibleNormalSampler<T, Distribution> {
+    /// `wo` is a unit vector above the surface.
+    pub fn new(distribution: Distribution, wo: [T; 3]) -> Self {
+        assert!(wo[2] > <T as ZeroAndOne>::zero(), "the direction must be above the surface");
+
+        Self { distribution, wo }
+    }
+
+    pub fn distribution(&self) -> &Distribution { &self.distribution }
+    pub fn wo(&self) -> [T; 3] { self.wo }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, Distribution: MicrofacetDistribution<T>> NDSampler<T, 3> for VisibleNormalSampler<T, Distribution> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let h = self.distribution.sample_visible_normal(&self.wo, [u[0], u[1]]);
+
+        (h, self.distribution.visible_normal_pdf(&self.wo, &h))
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T { self.distribution.visible_normal_pdf(&self.wo, point) }
+}
+
+mod detail {
+    use crate::rng::stats::erf;
+    use crate::{FloatingPoint, ZeroAndOne};
+
+    pub fn up<T: FloatingPoint>() -> [T; 3] { [<T as ZeroAndOne>::zero(), <T as ZeroAndOne>::zero(), <T as ZeroAndOne>::one()] }
+
+    /// `α² tan² θ` of `w` for the roughness `α` along the direction of `w`.
+    pub fn stretched_tangent_squared<T: FloatingPoint>(alpha_x: T, alpha_y: T, w: &[T; 3]) -> T {
+        let (x, y) = (alpha_x * w[0], alpha_y * w[1]);
+
+        (x * x + y * y) / (w[2] * w[2])
+    }
+
+    /// The slopes of a normal that is visible from the direction with the cosine `cos_θ` and the azimuth 0, for the
+    /// isotropic Beckmann distribution of roughness 1.
+    ///
+    /// The slope along x inverts its marginal, with a fit of the inverse as the first guess and Newton's method
+    /// guarded by bisection on the error function of the slope; the slope along y is normally distributed.
+    pub fn beckmann_standard_slopes(cos_θ: f64, u_0: f64, u_1: f64) -> (f64, f64) {
+        if cos_θ > 0.9999f64 {
+            let r = (-(1f64 - u_0).ln()).sqrt();
+            let φ = 2f64 * std::f64::consts::PI * u_1;
+
+            return (r * φ.cos(), r * φ.sin());
+        }
+
+        let sin_θ = (1f64 - cos_θ * cos_θ).max(0f64).sqrt();
+        let tan_θ = sin_θ / cos_θ;
+        let cot_θ = 1f64 / tan_θ;
+
+        let frac_1_sqrt_pi = 1f64 / std::f64::consts::PI.sqrt();
+        let u_0 = u_0.max(1e-6f64);
+
+        // the bracket and the guess for erf of the slope
+        let (mut a, mut c) = (-1f64, erf(cot_θ));
+        let θ = cos_θ.acos();
+        let fit = 1f64 + θ * (-0.876f64 + θ * (0.4265f64 - 0.0594f64 * θ));
+        let mut b = c - (1f64 + c) * (1f64 - u_0).powf(fit);
+
+        let normalization = 1f64 / (1f64 + c + frac_1_sqrt_pi * tan_θ * (-cot_θ * cot_θ).exp());
+
+        for _ in 0..10 {
+            if !(a <= b && b <= c) {
+                b = 0.5f64 * (a + c);
+            }
+
+            let slope = erf_inv(b);
+            let value = normalization * (1f64 + b + frac_1_sqrt_pi * tan_θ * (-slope * slope).exp()) - u_0;
+
+            if value.abs() < 1e-7f64 {
+                break;
+            }
+
+            if value > 0f64 {
+                c = b;
+            } else {
+                a = b;
+            }
+
+            b -= value / (normalization * (1f64 - slope * tan_θ));
+        }
+
+        (erf_inv(b), erf_inv(2f64 * u_1.max(1e-6f64) - 1f64))
+    }
+
+    /// The inverse of the error function to about single precision (Giles, "Approximating the erfinv function", 2010).
+    pub fn erf_inv(x: f64) -> f64 {
+        let x = x.clamp(-1f64 + 1e-15f64, 1f64 - 1e-15f64);
+        let w = -((1f64 - x) * (1f64 + x)).ln();
+
+        let p = if w < 5f64 {
+            let w = w - 2.5f64;
+            [3.43273939e-7f64, -3.5233877e-6f64, -4.39150654e-6f64, 2.1858087e-4f64, -1.25372503e-3f64, -4.17768164e-3f64, 2.46640727e-1f64, 1.50140941f64]
+                .iter()
+                .fold(2.81022636e-8f64, |p, &c| c + p * w)
+        } else {
+            let w = w.sqrt() - 3f64;
+            [1.00950558e-4f64, 1.34934322e-3f64, -3.67342844e-3f64, 5.73950773e-3f64, -7.6224613e-3f64, 9.43887047e-3f64, 1.00167406f64, 2.83297682f64]
+                .iter()
+                .fold(-2.00214257e-4f64, |p, &c| c + p * w)
+        };
+
+        p * x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The integral of `f` over the hemisphere above the surface by the midpoint rule in the polar angle and azimuth.
+    fn integrate_hemisphere<F: Fn(&[f64; 3]) -> f64>(f: F) -> f64 {
+        let (steps_θ, steps_φ) = (1024, 256);
+        let (d_θ, d_φ) = (std::f64::consts::FRAC_PI_2 / steps_θ as f64, 2f64 * std::f64::consts::PI / steps_φ as f64);
+
+        let mut sum = 0f64;
+
+        for i in 0..steps_θ {
+            let (sin_θ, cos_θ) = ((i as f64 + 0.5f64) * d_θ).sin_cos();
+
+            for j in 0..steps_φ {
+                let (sin_φ, cos_φ) = ((j as f64 + 0.5f64) * d_φ).sin_cos();
+                sum += f(&[sin_θ * cos_φ, sin_θ * sin_φ, cos_θ]) * sin_θ;
+            }
+        }
+
+        sum * d_θ * d_φ
+    }
+
+    fn check_normalized<D: MicrofacetDistribution<f64>>(distribution: &D) {
+        let projected = integrate_hemisphere(|h| distribution.d(h) * h[2]);
+        assert!((projected - 1f64).abs() < 1e-3, "the projected area is {}", projected);
+
+        // the visible normals of every direction are normalized only with the right Λ
+        for wo in [[0f64, 0f64, 1f64], [0.6f64, 0f64, 0.8f64], [-0.48f64, 0.64f64, 0.6f64], [0.2f64, -0.97f64, 0.14f64]] {
+            let wo = Vector(wo).normalized().0;
+            let visible = integrate_hemisphere(|h| distribution.visible_normal_pdf(&wo, h));
+            assert!((visible - 1f64).abs() < 2e-3, "the visible normals from {:?} integrate to {}", wo, visible);
+        }
+    }
+
+    #[test]
+    fn test_microfacet_normalization() {
+        check_normalized(&GGXDistribution::isotropic(0.5f64));
+        check_normalized(&GGXDistribution::new(0.3f64, 0.7f64));
+        check_normalized(&BeckmannDistribution::isotropic(0.5f64));
+        check_normalized(&BeckmannDistribution::new(0.3f64, 0.7f64));
+    }
+
+    #[test]
+    fn test_masking_shadowing() {
+        let ggx = GGXDistribution::isotropic(0.5f64);
+        let up = [0f64, 0f64, 1f64];
+        let grazing = Vector([0.999f64, 0f64, 0.001f64]).normalized().0;
+
+        assert_eq!(ggx.g1(&up), 1f64);
+        assert!(ggx.g1(&grazing) < 0.01f64);
+        assert!((ggx.g2(&up, &grazing) - ggx.g1(&grazing)).abs() < 1e-15);
+
+        // Λ of GGX for α tan θ = 1
+        let w = Vector([2f64, 0f64, 1f64]).normalized().0;
+        assert!((ggx.lambda(&w) - (2f64.sqrt() - 1f64) / 2f64).abs() < 1e-12);
+
+        let beckmann = BeckmannDistribution::isotropic(0.5f64);
+        assert_eq!(beckmann.lambda(&up), 0f64);
+        assert!(beckmann.g1(&grazing) < 0.01f64);
+    }
+
+    #[test]
+    fn test_inverse_error_function() {
+        for x in [-0.999f64, -0.5f64, 0f64, 0.3f64, 0.9f64, 0.99999f64] {
+            assert!((erf(detail::erf_inv(x)) - x).abs() < 5e-7, "erf(erf_inv({}))", x);
+        }
+    }
+}
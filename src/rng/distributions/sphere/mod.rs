@@ -0,0 +1,633 @@
+mod cap;
+mod microfacet;
+mod rectangle;
+mod triangle;
+
+pub use cap::*;
+pub use microfacet::*;
+pub use rectangle::*;
+pub use triangle::*;
+
+use std::marker::PhantomData;
+
+use crate::{FloatConstants, FloatingPoint, ZeroAndOne};
+
+use crate::rng::sequences::SampleGenerator;
+
+use super::*;
+
+/// The most canonical numbers `NDSampler::sample_from` keeps on the stack, samplers that take more get theirs from the
+/// heap.
+pub const MAX_CANONICAL_DIMENSIONS: usize = 8;
+
+pub trait NDSampler<T: FloatingPoint, const N: usize> {
+    /// The number of canonical numbers `sample_canonical` takes.
+    const CANONICAL_DIMENSIONS: usize;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; N], T);
+
+    /// The sample for `CANONICAL_DIMENSIONS` numbers in `[0, 1)`, so that stratified or low discrepancy points can
+    /// drive the sampler instead of a generator.
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; N], T);
+
+    /// The density of `point` in the measure of the densities of the samples, zero where no sample lands. Samplers of
+    /// a surface take `point` to be on the surface.
+    fn pdf(&self, point: &[T; N]) -> T;
+
+    /// Writes the canonical numbers that `sample_canonical` maps to `point` to `u`, which has `CANONICAL_DIMENSIONS`
+    /// elements; false for samplers that cannot invert their mapping.
+    fn invert(&self, _point: &[T; N], _u: &mut [T]) -> bool { false }
+
+    /// The sample for the next `CANONICAL_DIMENSIONS` coordinates of the current point of `samples`.
+    fn sample_from<S: SampleGenerator>(&mut self, samples: &mut S) -> ([T; N], T) {
+        let mut stack = [<T as ZeroAndOne>::zero(); MAX_CANONICAL_DIMENSIONS];
+        let mut heap = Vec::new();
+
+        let u = if Self::CANONICAL_DIMENSIONS <= MAX_CANONICAL_DIMENSIONS {
+            &mut stack[..Self::CANONICAL_DIMENSIONS]
+        } else {
+            heap.resize(Self::CANONICAL_DIMENSIONS, <T as ZeroAndOne>::zero());
+            &mut heap[..]
+        };
+
+        samples.fill(u);
+        self.sample_canonical(u)
+    }
+}
+
+pub struct UniformSphereSampler<T: FloatingPoint + GenerateCanonical<T>, const N: usize> {
+    data: PhantomData<[T; N]>,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const N: usize> UniformSphereSampler<T, N> {
+    pub fn new() -> Self { Self { data: Default::default() } }
+
+    /// The reciprocal of the area of the unit sphere in `N` dimensions, `2 π^(N/2) / Γ(N/2)`.
+    fn density() -> T {
+        let half_n = N as f64 / 2f64;
+
+        T::as_from(half_n.gamma() / (2f64 * std::f64::consts::PI.powf(half_n)))
+    }
+
+    #[allow(dead_code)]
+    fn strategy_normalised_normal<Gen: UniformRandomBitGenerator>(gen: &mut Gen) -> [T; N] {
+        let mut dist = NormalDistribution::<T>::default();
+
+        let mut ret = [<T as ZeroAndOne>::zero(); N];
+        let mut length = <T as ZeroAndOne>::zero();
+
+        for i in 0..N {
+            let t = dist.generate(gen);
+            length = length + t * t;
+            ret[i] = t;
+        }
+
+        length = length.sqrt();
+
+        for i in 0..N {
+            ret[i] = ret[i] / length;
+        }
+
+        ret
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const N: usize> NDSampler<T, N> for UniformSphereSampler<T, N> {
+    const CANONICAL_DIMENSIONS: usize = if N > 1 { N - 1 } else { 1 };
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; N], T) {
+        let sample = Self::strategy_normalised_normal(gen);
+
+        (sample, Self::density())
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; N], T) {
+        let mut ret = [<T as ZeroAndOne>::zero(); N];
+        detail::sphere_from_canonical(u, &mut ret);
+
+        (ret, Self::density())
+    }
+
+    fn pdf(&self, _point: &[T; N]) -> T { Self::density() }
+
+    fn invert(&self, point: &[T; N], u: &mut [T]) -> bool {
+        detail::canonical_from_sphere(point, <T as ZeroAndOne>::one(), u);
+
+        true
+    }
+}
+
+pub struct UniformBallSampler<T: FloatingPoint + GenerateCanonical<T>, const N: usize> {
+    data: PhantomData<[T; N]>,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const N: usize> UniformBallSampler<T, N> {
+    pub fn new() -> Self { Self { data: Default::default() } }
+
+    /// The reciprocal of the volume of the unit ball in `N` dimensions, `π^(N/2) / Γ(N/2 + 1)`.
+    fn density() -> T {
+        let half_n = N as f64 / 2f64;
+
+        T::as_from((half_n + 1f64).gamma() / std::f64::consts::PI.powf(half_n))
+    }
+
+    #[allow(dead_code)]
+    fn strategy_rejection<Gen: UniformRandomBitGenerator>(gen: &mut Gen) -> [T; N] {
+        loop {
+            let mut ret = [<T as ZeroAndOne>::zero(); N];
+            let mut length = <T as ZeroAndOne>::zero();
+
+            for i in 0..N {
+                let t = T::generate_canonical(gen).get() * T::from_scalar(2).unwrap() - <T as ZeroAndOne>::one();
+                ret[i] = t;
+                length = length + t * t;
+            }
+
+            length = length.sqrt();
+
+            if length < <T as ZeroAndOne>::one() {
+                break ret;
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    fn strategy_sphere_with_radius<Gen: UniformRandomBitGenerator>(gen: &mut Gen) -> [T; N] {
+        let mut res = UniformSphereSampler::new().sample(gen).0;
+        // the volume within a radius grows with its N-th power
+        let radius = T::generate_canonical(gen).get().powf(<T as ZeroAndOne>::one() / T::as_from(N));
+
+        for i in 0..N {
+            res[i] = res[i] * radius;
+        }
+
+        res
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const N: usize> NDSampler<T, N> for UniformBallSampler<T, N> {
+    const CANONICAL_DIMENSIONS: usize = <UniformSphereSampler<T, N> as NDSampler<T, N>>::CANONICAL_DIMENSIONS + 1;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; N], T) { (Self::strategy_sphere_with_radius(gen), Self::density()) }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; N], T) {
+        let (directions, radius) = u.split_at(Self::CANONICAL_DIMENSIONS - 1);
+
+        let mut res = UniformSphereSampler::new().sample_canonical(directions).0;
+        let radius = radius[0].powf(<T as ZeroAndOne>::one() / T::as_from(N));
+
+        for i in 0..N {
+            res[i] = res[i] * radius;
+        }
+
+        (res, Self::density())
+    }
+
+    fn pdf(&self, point: &[T; N]) -> T {
+        if detail::length(point) <= <T as ZeroAndOne>::one() {
+            Self::density()
+        } else {
+            <T as ZeroAndOne>::zero()
+        }
+    }
+
+    fn invert(&self, point: &[T; N], u: &mut [T]) -> bool {
+        let radius = detail::length(point);
+
+        // the direction of the centre does not matter, any numbers for the sphere do
+        let scale = if radius > <T as ZeroAndOne>::zero() { radius } else { <T as ZeroAndOne>::one() };
+        detail::canonical_from_sphere(point, scale, u);
+
+        u[Self::CANONICAL_DIMENSIONS - 1] = detail::below_one(radius.powi(N as i32));
+
+        true
+    }
+}
+
+pub struct CosineWeightedHemisphereSampler<T: FloatingPoint + GenerateCanonical<T>> {
+    data: PhantomData<T>,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> CosineWeightedHemisphereSampler<T> {
+    pub fn new() -> Self { Self { data: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 3> for CosineWeightedHemisphereSampler<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let cosθ = u[0].sqrt();
+        let sinθ = (<T as ZeroAndOne>::one() - cosθ * cosθ).sqrt();
+        let probability = cosθ * <T as FloatConstants>::FRAC_1_PI;
+
+        let φ = T::from_scalar(2).unwrap() * <T as FloatConstants>::PI * u[1];
+
+        let sinφ = φ.sin();
+        let cosφ = φ.cos();
+
+        ([cosφ * sinθ, sinφ * sinθ, cosθ], probability)
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T { point[2].max(<T as ZeroAndOne>::zero()) * <T as FloatConstants>::FRAC_1_PI }
+
+    fn invert(&self, point: &[T; 3], u: &mut [T]) -> bool {
+        u[0] = detail::below_one(point[2] * point[2]);
+        u[1] = detail::canonical_from_angle(point[1], point[0]);
+
+        true
+    }
+}
+
+pub struct PowerCosineWeightedHemisphereSampler<T: FloatingPoint + GenerateCanonical<T>> {
+    alpha: T,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> PowerCosineWeightedHemisphereSampler<T> {
+    pub fn new(alpha: T) -> Self { Self { alpha } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 3> for PowerCosineWeightedHemisphereSampler<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let one = <T as ZeroAndOne>::one();
+
+        let cosθ = u[0].powf(one / (self.alpha + one));
+        let sinθ = (one - cosθ * cosθ).sqrt();
+        let probability = (self.alpha + one) * cosθ.powf(self.alpha) * T::from_scalar(0.5f64).unwrap() * <T as FloatConstants>::FRAC_1_PI;
+
+        let φ = T::from_scalar(2).unwrap() * <T as FloatConstants>::PI * u[1];
+
+        let sinφ = φ.sin();
+        let cosφ = φ.cos();
+
+        ([cosφ * sinθ, sinφ * sinθ, cosθ], probability)
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T {
+        if point[2] <= <T as ZeroAndOne>::zero() {
+            return <T as ZeroAndOne>::zero();
+        }
+
+        (self.alpha + <T as ZeroAndOne>::one()) * point[2].powf(self.alpha) * T::from_scalar(0.5f64).unwrap() * <T as FloatConstants>::FRAC_1_PI
+    }
+
+    fn invert(&self, point: &[T; 3], u: &mut [T]) -> bool {
+        u[0] = detail::below_one(point[2].max(<T as ZeroAndOne>::zero()).powf(self.alpha + <T as ZeroAndOne>::one()));
+        u[1] = detail::canonical_from_angle(point[1], point[0]);
+
+        true
+    }
+}
+
+/// Uniform points on the unit disk by the concentric map of Shirley and Chiu ("A Low Distortion Map Between Disk and
+/// Square", 1997), which maps concentric squares to concentric circles and so keeps stratified points stratified.
+pub struct ConcentricDiskSampler<T: FloatingPoint + GenerateCanonical<T>> {
+    data: PhantomData<T>,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> ConcentricDiskSampler<T> {
+    pub fn new() -> Self { Self { data: Default::default() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 2> for ConcentricDiskSampler<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 2], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 2], T) {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+        let two = T::from_scalar(2).unwrap();
+
+        let a = two * u[0] - one;
+        let b = two * u[1] - one;
+
+        if a == zero && b == zero {
+            return ([zero, zero], <T as FloatConstants>::FRAC_1_PI);
+        }
+
+        let (r, φ) = if a.abs() > b.abs() {
+            (a, <T as FloatConstants>::FRAC_PI_4 * (b / a))
+        } else {
+            (b, <T as FloatConstants>::FRAC_PI_2 - <T as FloatConstants>::FRAC_PI_4 * (a / b))
+        };
+
+        ([r * φ.cos(), r * φ.sin()], <T as FloatConstants>::FRAC_1_PI)
+    }
+
+    fn pdf(&self, point: &[T; 2]) -> T {
+        if detail::length(point) <= <T as ZeroAndOne>::one() {
+            <T as FloatConstants>::FRAC_1_PI
+        } else {
+            <T as ZeroAndOne>::zero()
+        }
+    }
+
+    fn invert(&self, point: &[T; 2], u: &mut [T]) -> bool {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+        let half = T::from_scalar(0.5f64).unwrap();
+
+        let [x, y] = *point;
+        let r = detail::length(point);
+
+        // the square of the point is the one whose side it is closer to, a is its signed radius there
+        let (a, b) = if r == zero {
+            (zero, zero)
+        } else if x.abs() >= y.abs() {
+            let a = r.copysign(x);
+            (a, a * (y / x).atan() / <T as FloatConstants>::FRAC_PI_4)
+        } else {
+            let b = r.copysign(y);
+            (b * (<T as FloatConstants>::FRAC_PI_2 - (y / b).atan2(x / b)) / <T as FloatConstants>::FRAC_PI_4, b)
+        };
+
+        u[0] = detail::below_one((a + one) * half);
+        u[1] = detail::below_one((b + one) * half);
+
+        true
+    }
+}
+
+mod detail {
+    use crate::smallvec::Vector;
+    use crate::{FloatConstants, FloatingPoint, ZeroAndOne};
+
+    /// A uniform point on the unit sphere in `out.len()` dimensions from `max(out.len() - 1, 1)` canonical numbers.
+    ///
+    /// Past three dimensions the first two coordinates are split off: their share `a` of the squared length is
+    /// distributed as `Beta(1, (n - 2) / 2)`, whose inverse is closed, and the rest is a uniform point on the sphere
+    /// two dimensions lower, scaled to a squared length of `1 - a`.
+    pub fn sphere_from_canonical<T: FloatingPoint>(u: &[T], out: &mut [T]) {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+        let two = T::from_scalar(2).unwrap();
+
+        match out.len() {
+            0 => {}
+            1 => out[0] = if u[0] < T::from_scalar(0.5f64).unwrap() { -one } else { one },
+            2 => {
+                let φ = two * <T as FloatConstants>::PI * u[0];
+                out[0] = φ.cos();
+                out[1] = φ.sin();
+            }
+            3 => {
+                // Archimedes: the height of a point on the sphere is uniformly distributed
+                let z = one - two * u[0];
+                let r = (one - z * z).max(zero).sqrt();
+                let φ = two * <T as FloatConstants>::PI * u[1];
+
+                out[0] = r * φ.cos();
+                out[1] = r * φ.sin();
+                out[2] = z;
+            }
+            n => {
+                let a = one - (one - u[0]).powf(two / T::as_from(n - 2));
+                let φ = two * <T as FloatConstants>::PI * u[1];
+
+                out[0] = a.sqrt() * φ.cos();
+                out[1] = a.sqrt() * φ.sin();
+
+                let rest = (one - a).max(zero).sqrt();
+                sphere_from_canonical(&u[2..], &mut out[2..]);
+
+                for v in &mut out[2..] {
+                    *v = *v * rest;
+                }
+            }
+        }
+    }
+
+    /// The inverse of `sphere_from_canonical` for the point `point / scale` on the unit sphere.
+    pub fn canonical_from_sphere<T: FloatingPoint>(point: &[T], scale: T, u: &mut [T]) {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+        let two = T::from_scalar(2).unwrap();
+
+        match point.len() {
+            0 => {}
+            1 => u[0] = T::from_scalar(if point[0] < zero { 0.25f64 } else { 0.75f64 }).unwrap(),
+            2 => u[0] = canonical_from_angle(point[1], point[0]),
+            3 => {
+                u[0] = below_one(((one - point[2] / scale) / two).max(zero));
+                u[1] = canonical_from_angle(point[1], point[0]);
+            }
+            n => {
+                let (x, y) = (point[0] / scale, point[1] / scale);
+                let a = (x * x + y * y).min(one);
+
+                u[0] = below_one(one - (one - a).powf(T::as_from(n - 2) / two));
+                u[1] = canonical_from_angle(y, x);
+
+                let rest = (one - a).sqrt();
+                canonical_from_sphere(&point[2..], if rest > zero { scale * rest } else { scale }, &mut u[2..]);
+            }
+        }
+    }
+
+    /// The canonical number whose angle `2π u` is that of `(x, y)`.
+    pub fn canonical_from_angle<T: FloatingPoint>(y: T, x: T) -> T {
+        let u = y.atan2(x) / (T::from_scalar(2).unwrap() * <T as FloatConstants>::PI);
+
+        // just below zero wraps around to 1, which is the same angle as 0
+        let u = if u < <T as ZeroAndOne>::zero() { u + <T as ZeroAndOne>::one() } else { u };
+        if u < <T as ZeroAndOne>::one() { u } else { <T as ZeroAndOne>::zero() }
+    }
+
+    pub fn below_one<T: FloatingPoint>(u: T) -> T { u.min(<T as ZeroAndOne>::one().next_down()) }
+
+    pub fn length<T: FloatingPoint>(point: &[T]) -> T { point.iter().fold(<T as ZeroAndOne>::zero(), |sum, &v| sum + v * v).sqrt() }
+
+    /// Two unit vectors that complete `n` to a right-handed orthonormal basis (Duff et al., "Building an Orthonormal
+    /// Basis, Revisited", 2017).
+    pub fn orthonormal_basis<T: FloatingPoint>(n: Vector<T, 3>) -> (Vector<T, 3>, Vector<T, 3>) {
+        let one = <T as ZeroAndOne>::one();
+
+        let sign = one.copysign(n[2]);
+        let a = -one / (sign + n[2]);
+        let b = n[0] * n[1] * a;
+
+        (Vector([one + sign * n[0] * n[0] * a, sign * b, -sign * n[0]]), Vector([b, sign + n[1] * n[1] * a, -n[1]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::smallvec::Vector;
+
+    #[test]
+    fn test_sphere_and_ball_pdfs() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12 * b;
+
+        assert!(close(UniformSphereSampler::<f64, 1>::density(), 0.5f64));
+        assert!(close(UniformSphereSampler::<f64, 2>::density(), 1f64 / (2f64 * std::f64::consts::PI)));
+        assert!(close(UniformSphereSampler::<f64, 3>::density(), 1f64 / (4f64 * std::f64::consts::PI)));
+        assert!(close(UniformSphereSampler::<f64, 4>::density(), 1f64 / (2f64 * std::f64::consts::PI.powi(2))));
+
+        assert!(close(UniformBallSampler::<f64, 1>::density(), 0.5f64));
+        assert!(close(UniformBallSampler::<f64, 2>::density(), 1f64 / std::f64::consts::PI));
+        assert!(close(UniformBallSampler::<f64, 3>::density(), 3f64 / (4f64 * std::f64::consts::PI)));
+        assert!(close(UniformBallSampler::<f64, 4>::density(), 2f64 / std::f64::consts::PI.powi(2)));
+        assert!(close(UniformBallSampler::<f64, 5>::density(), 15f64 / (8f64 * std::f64::consts::PI.powi(2))));
+    }
+
+    #[test]
+    fn test_sphere_canonical_is_unit() {
+        let u = [0.1f64, 0.7f64, 0.35f64, 0.9f64, 0.05f64, 0.5f64];
+
+        fn length<const N: usize>(u: &[f64]) -> f64 {
+            let (v, _) = UniformSphereSampler::<f64, N>::new().sample_canonical(u);
+            v.iter().map(|v| v * v).sum::<f64>().sqrt()
+        }
+
+        for length in [length::<1>(&u), length::<2>(&u), length::<3>(&u), length::<4>(&u), length::<5>(&u), length::<7>(&u)] {
+            assert!((length - 1f64).abs() < 1e-14, "{}", length);
+        }
+
+        let (v, _) = UniformBallSampler::<f64, 6>::new().sample_canonical(&u);
+        assert!((v.iter().map(|v| v * v).sum::<f64>().sqrt() - 0.5f64.powf(1f64 / 6f64)).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_sample_from_many_dimensions() {
+        use crate::rng::sequences::IndependentSampleGenerator;
+
+        // more canonical numbers than fit on the stack
+        let mut samples = IndependentSampleGenerator::new(1);
+        samples.start_pixel_sample([3, 5], 0);
+
+        let (v, _) = UniformSphereSampler::<f64, 12>::new().sample_from(&mut samples);
+        assert!((v.iter().map(|v| v * v).sum::<f64>().sqrt() - 1f64).abs() < 1e-14);
+
+        let (v, pdf) = UniformBallSampler::<f64, 9>::new().sample_from(&mut samples);
+        assert!(v.iter().map(|v| v * v).sum::<f64>() <= 1f64 && pdf == UniformBallSampler::<f64, 9>::density());
+    }
+
+    #[test]
+    fn test_concentric_disk() {
+        let mut disk = ConcentricDiskSampler::<f64>::new();
+
+        assert_eq!(disk.sample_canonical(&[0.5f64, 0.5f64]).0, [0f64, 0f64]);
+
+        // the corners of the square go to the diagonals and the midpoints of the edges to the axes
+        let ([x, y], pdf) = disk.sample_canonical(&[1f64, 1f64]);
+        assert!((x - 0.5f64.sqrt()).abs() < 1e-15 && (y - 0.5f64.sqrt()).abs() < 1e-15);
+        assert_eq!(pdf, std::f64::consts::FRAC_1_PI);
+
+        let ([x, y], _) = disk.sample_canonical(&[0.5f64, 0f64]);
+        assert!(x.abs() < 1e-15 && (y + 1f64).abs() < 1e-15);
+    }
+
+    /// Canonical points spread over the cube, an additive recurrence on the powers of the generalised golden ratio.
+    fn canonical_points(count: usize, dimensions: usize) -> impl Iterator<Item = Vec<f64>> {
+        let phi = 1.0850702454914509f64;
+
+        (0..count).map(move |i| (0..dimensions).map(|d| (0.5f64 + (i + 1) as f64 * phi.powi(-(d as i32 + 1))).fract()).collect())
+    }
+
+    /// Checks that the samples have the densities `pdf` gives them and, if the sampler inverts, that the canonical
+    /// numbers come back out of `invert`.
+    fn check_pdf_and_invert<Sampler: NDSampler<f64, N>, const N: usize>(mut sampler: Sampler) {
+        let dimensions = Sampler::CANONICAL_DIMENSIONS;
+
+        for u in canonical_points(256, dimensions) {
+            let u = &u[..];
+            let (point, pdf) = sampler.sample_canonical(u);
+            assert!((sampler.pdf(&point) - pdf).abs() <= 1e-9 * pdf, "{} against {} at {:?}", sampler.pdf(&point), pdf, u);
+
+            let mut v = vec![0f64; dimensions];
+            if !sampler.invert(&point, &mut v) {
+                continue;
+            }
+
+            let v = &v[..];
+            assert!(u.iter().zip(v).all(|(u, v)| (u - v).abs() < 1e-9), "{:?} inverts to {:?}", u, v);
+
+            let (again, _) = sampler.sample_canonical(v);
+            assert!((Vector(again) - Vector(point)).length() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_pdf_and_invert() {
+        check_pdf_and_invert(UniformSphereSampler::<f64, 2>::new());
+        check_pdf_and_invert(UniformSphereSampler::<f64, 3>::new());
+        check_pdf_and_invert(UniformSphereSampler::<f64, 4>::new());
+        check_pdf_and_invert(UniformSphereSampler::<f64, 5>::new());
+        check_pdf_and_invert(UniformSphereSampler::<f64, 7>::new());
+        check_pdf_and_invert(UniformSphereSampler::<f64, 10>::new());
+        check_pdf_and_invert(UniformSphereSampler::<f64, 12>::new());
+        check_pdf_and_invert(UniformBallSampler::<f64, 2>::new());
+        check_pdf_and_invert(UniformBallSampler::<f64, 3>::new());
+        check_pdf_and_invert(UniformBallSampler::<f64, 6>::new());
+        check_pdf_and_invert(UniformBallSampler::<f64, 9>::new());
+        check_pdf_and_invert(CosineWeightedHemisphereSampler::<f64>::new());
+        check_pdf_and_invert(PowerCosineWeightedHemisphereSampler::new(0.5f64));
+        check_pdf_and_invert(PowerCosineWeightedHemisphereSampler::new(20f64));
+        check_pdf_and_invert(ConcentricDiskSampler::<f64>::new());
+        check_pdf_and_invert(UniformConeSampler::new([0.48f64, -0.6f64, 0.64f64], 0.3f64));
+        check_pdf_and_invert(UniformSphericalCapSampler::visible_from([1f64, 2f64, 3f64], 0.5f64, [-1f64, 0f64, 2f64]));
+        check_pdf_and_invert(UniformTriangleSampler::new([[0f64, 0f64, 0f64], [2f64, 0f64, 0f64], [0f64, 1f64, 3f64]]));
+        check_pdf_and_invert(UniformSphericalTriangleSampler::from_triangle([0.1f64, -0.2f64, 0.3f64], [[1f64, 0f64, 2f64], [-1f64, 1f64, 1.5f64], [0.5f64, 3f64, 1f64]]));
+        check_pdf_and_invert(UniformSphericalRectangleSampler::new([0f64, 0f64, -2f64], [1f64, 1f64, -1f64], [[0f64, -2f64, 0f64], [-1f64, 0f64, 0f64]]));
+        check_pdf_and_invert(GGXDistribution::new(0.2f64, 0.6f64));
+        check_pdf_and_invert(BeckmannDistribution::new(0.2f64, 0.6f64));
+        check_pdf_and_invert(VisibleNormalSampler::new(GGXDistribution::new(0.2f64, 0.6f64), [0.48f64, -0.6f64, 0.64f64]));
+        check_pdf_and_invert(VisibleNormalSampler::new(BeckmannDistribution::new(0.2f64, 0.6f64), [0.48f64, -0.6f64, 0.64f64]));
+
+        // the points of a sphere in one dimension are its two ends
+        let sphere = UniformSphereSampler::<f64, 1>::new();
+        for point in [[-1f64], [1f64]] {
+            let mut u = [0f64];
+            assert!(sphere.invert(&point, &mut u));
+            assert_eq!(UniformSphereSampler::<f64, 1>::new().sample_canonical(&u).0, point);
+        }
+
+        // no sample lands outside of the support
+        assert_eq!(UniformBallSampler::<f64, 3>::new().pdf(&[0.6f64, 0.6f64, 0.6f64]), 0f64);
+        assert_eq!(CosineWeightedHemisphereSampler::<f64>::new().pdf(&[0f64, 0.6f64, -0.8f64]), 0f64);
+        assert_eq!(PowerCosineWeightedHemisphereSampler::new(2f64).pdf(&[0f64, 0.6f64, -0.8f64]), 0f64);
+        assert_eq!(ConcentricDiskSampler::<f64>::new().pdf(&[0.8f64, -0.8f64]), 0f64);
+        assert_eq!(UniformConeSampler::new([0f64, 0f64, 1f64], 0.9f64).pdf(&[0f64, 0.6f64, 0.8f64]), 0f64);
+        assert_eq!(UniformSphericalTriangleSampler::new([[1f64, 0f64, 0f64], [0f64, 1f64, 0f64], [0f64, 0f64, 1f64]]).pdf(&[-1f64, 0f64, 0f64]), 0f64);
+
+        // beyond an edge, or off the plane
+        let triangle = UniformTriangleSampler::new([[0f64, 0f64, 0f64], [2f64, 0f64, 0f64], [0f64, 1f64, 3f64]]);
+        assert_eq!(triangle.pdf(&[1.5f64, 0.5f64, 1.5f64]), 0f64);
+        assert_eq!(triangle.pdf(&[-0.5f64, 0f64, 0f64]), 0f64);
+        assert_eq!(triangle.pdf(&[0.5f64, 0.25f64, 0.5f64]), 0f64);
+        assert!(triangle.pdf(&[0.5f64, 0.25f64, 0.75f64]) > 0f64);
+
+        let rectangle = UniformSphericalRectangleSampler::new([0f64, 0f64, 0f64], [-1f64, -1f64, -1f64], [[2f64, 0f64, 0f64], [0f64, 2f64, 0f64]]);
+        assert_eq!(rectangle.pdf(&[0f64, 0f64, 1f64]), 0f64);
+        assert_eq!(rectangle.pdf(&[0.8f64, 0f64, -0.6f64]), 0f64);
+        assert!(rectangle.pdf(&[0f64, 0f64, -1f64]) > 0f64);
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        for n in [[0f64, 0f64, 1f64], [0f64, 0f64, -1f64], [0.48f64, -0.6f64, 0.64f64]] {
+            let n = Vector(n);
+            let (t, b) = detail::orthonormal_basis(n);
+
+            for (v, w) in [(t, b), (t, n), (b, n)] {
+                assert!(v.dot(w).abs() < 1e-15);
+            }
+
+            assert!((t.length() - 1f64).abs() < 1e-15 && (b.length() - 1f64).abs() < 1e-15);
+            assert!((t.cross(b) - n).length() < 1e-15);
+        }
+    }
+}
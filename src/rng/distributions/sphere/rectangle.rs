@@ -0,0 +1,155 @@
+use crate::smallvec::Vector;
+use crate::{FloatConstants, FloatingPoint, NumericLimits, ZeroAndOne};
+
+use super::*;
+
+/// Directions towards a rectangle, uniform over the solid angle it subtends (Ureña, Fajardo and King, "An
+/// Area-Preserving Parametrization for Spherical Rectangles", 2013); the density is per unit solid angle.
+///
+/// The rectangle is seen in a frame with the origin at the viewpoint and the axes along its edges; the first
+/// canonical number picks the column of the rectangle by the solid angle up to it, the second the height within the
+/// column.
+pub struct UniformSphericalRectangleSampler<T: FloatingPoint + GenerateCanonical<T>> {
+    origin: Vector<T, 3>,
+    axes: [Vector<T, 3>; 3],
+
+    x_0: T,
+    x_1: T,
+    y_0: T,
+    y_1: T,
+    z_0: T,
+
+    b_0: T,
+    b_1: T,
+    k: T,
+    solid_angle: T,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> UniformSphericalRectangleSampler<T> {
+    /// The rectangle with the corner `corner` and the orthogonal edges `edges` as seen from `origin`.
+    pub fn new(origin: [T; 3], corner: [T; 3], edges: [[T; 3]; 2]) -> Self {
+        let zero = <T as ZeroAndOne>::zero();
+        let two = T::from_scalar(2).unwrap();
+
+        let origin = Vector(origin);
+        let [e_x, e_y] = edges.map(Vector);
+        let (length_x, length_y) = (e_x.length(), e_y.length());
+
+        let x = e_x / length_x;
+        let y = e_y / length_y;
+        let mut z = x.cross(y);
+
+        // the rectangle is below the viewpoint in the local frame
+        let d = Vector(corner) - origin;
+        let mut z_0 = d.dot(z);
+
+        if z_0 > zero {
+            z = -z;
+            z_0 = -z_0;
+        }
+
+        assert!(z_0 < zero, "the viewpoint must not be in the plane of the rectangle");
+
+        let (x_0, y_0) = (d.dot(x), d.dot(y));
+        let (x_1, y_1) = (x_0 + length_x, y_0 + length_y);
+
+        // the normals of the planes through the viewpoint and each edge
+        let n_0 = Vector([zero, z_0, -y_0]).normalized();
+        let n_1 = Vector([-z_0, zero, x_1]).normalized();
+        let n_2 = Vector([zero, -z_0, y_1]).normalized();
+        let n_3 = Vector([z_0, zero, -x_0]).normalized();
+
+        let angle = |a: Vector<T, 3>, b: Vector<T, 3>| (-a.dot(b)).clamp(-<T as ZeroAndOne>::one(), <T as ZeroAndOne>::one()).acos();
+        let (g_0, g_1, g_2, g_3) = (angle(n_0, n_1), angle(n_1, n_2), angle(n_2, n_3), angle(n_3, n_0));
+
+        let k = two * <T as FloatConstants>::PI - g_2 - g_3;
+        let solid_angle = g_0 + g_1 - k;
+
+        Self { origin, axes: [x, y, z], x_0, x_1, y_0, y_1, z_0, b_0: n_0[2], b_1: n_2[2], k, solid_angle }
+    }
+
+    pub fn solid_angle(&self) -> T { self.solid_angle }
+
+    /// The point on the rectangle for the canonical numbers `u` and its density per unit solid angle.
+    pub fn sample_point_canonical(&self, u: [T; 2]) -> ([T; 3], T) {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+
+        // the column whose part of the rectangle subtends u_0 of the solid angle
+        let a_u = u[0] * self.solid_angle + self.k;
+        let f_u = (a_u.cos() * self.b_0 - self.b_1) / a_u.sin();
+        let c_u = (one / (f_u * f_u + self.b_0 * self.b_0).sqrt()).copysign(f_u).clamp(-one, one);
+
+        let x_u = (-(c_u * self.z_0) / (one - c_u * c_u).max(zero).sqrt()).clamp(self.x_0, self.x_1);
+
+        // the height within the column, uniform in the sine of the elevation
+        let d = (x_u * x_u + self.z_0 * self.z_0).sqrt();
+        let h_0 = self.y_0 / (d * d + self.y_0 * self.y_0).sqrt();
+        let h_1 = self.y_1 / (d * d + self.y_1 * self.y_1).sqrt();
+        let h_v = h_0 + u[1] * (h_1 - h_0);
+
+        let y_v = if h_v * h_v < one - <T as NumericLimits>::epsilon() { (h_v * d) / (one - h_v * h_v).sqrt() } else { self.y_1 };
+        let y_v = y_v.clamp(self.y_0, self.y_1);
+
+        let [x, y, z] = self.axes;
+        let point = self.origin + x * x_u + y * y_v + z * self.z_0;
+
+        (point.0, one / self.solid_angle)
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 3> for UniformSphericalRectangleSampler<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let (point, pdf) = self.sample_point_canonical([u[0], u[1]]);
+
+        ((Vector(point) - self.origin).normalized().0, pdf)
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T {
+        let [x, y, z] = self.axes;
+        let direction = Vector(*point);
+
+        // where the ray along the direction crosses the plane of the rectangle, which is below in the local frame
+        let height = direction.dot(z);
+        if height >= <T as ZeroAndOne>::zero() {
+            return <T as ZeroAndOne>::zero();
+        }
+
+        let scale = self.z_0 / height;
+        let (x_p, y_p) = (direction.dot(x) * scale, direction.dot(y) * scale);
+
+        if self.x_0 <= x_p && x_p <= self.x_1 && self.y_0 <= y_p && y_p <= self.y_1 {
+            <T as ZeroAndOne>::one() / self.solid_angle
+        } else {
+            <T as ZeroAndOne>::zero()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spherical_rectangle_solid_angle() {
+        // a square of side 2 centred a distance 1 below the viewpoint covers a sixth of the sphere
+        let rectangle = UniformSphericalRectangleSampler::new([0f64, 0f64, 0f64], [-1f64, -1f64, -1f64], [[2f64, 0f64, 0f64], [0f64, 2f64, 0f64]]);
+        assert!((rectangle.solid_angle() - 4f64 * std::f64::consts::PI / 6f64).abs() < 1e-12);
+
+        // seen from the other side, with other edges
+        let rectangle = UniformSphericalRectangleSampler::new([0f64, 0f64, -2f64], [1f64, 1f64, -1f64], [[0f64, -2f64, 0f64], [-2f64, 0f64, 0f64]]);
+        assert!((rectangle.solid_angle() - 4f64 * std::f64::consts::PI / 6f64).abs() < 1e-12);
+
+        // the corners of the square go to the corners of the rectangle, every point is on it
+        for (u, corner) in [([0f64, 0f64], [1f64, 1f64]), ([1f64, 0f64], [1f64, -1f64]), ([1f64, 1f64], [-1f64, -1f64])] {
+            let (point, _) = rectangle.sample_point_canonical(u);
+            assert!((point[0] - corner[0]).abs() < 1e-9 && (point[1] - corner[1]).abs() < 1e-9 && point[2] == -1f64, "{:?} for {:?}", point, u);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::smallvec::Vector;
+use crate::{FloatingPoint, NumericLimits, ZeroAndOne};
+
+use super::*;
+
+/// Points on a triangle in any number of dimensions, uniform over its area; the density is per unit area.
+///
+/// The canonical numbers go through the low distortion map of Heitz ("A Low-Distortion Map Between Triangle and
+/// Square", 2019), which keeps stratified points stratified, to barycentric coordinates.
+pub struct UniformTriangleSampler<T: FloatingPoint + GenerateCanonical<T>, const N: usize> {
+    vertices: [Vector<T, N>; 3],
+    area: T,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const N: usize> UniformTriangleSampler<T, N> {
+    pub fn new(vertices: [[T; N]; 3]) -> Self {
+        let vertices = vertices.map(Vector);
+        let (e_1, e_2) = (vertices[1] - vertices[0], vertices[2] - vertices[0]);
+
+        // half the square root of the Gram determinant of the edges
+        let gram = e_1.dot(e_1) * e_2.dot(e_2) - e_1.dot(e_2) * e_1.dot(e_2);
+        let area = gram.max(<T as ZeroAndOne>::zero()).sqrt() * T::from_scalar(0.5f64).unwrap();
+        assert!(area > <T as ZeroAndOne>::zero(), "the triangle must not be degenerate");
+
+        Self { vertices, area }
+    }
+
+    pub fn vertices(&self) -> [[T; N]; 3] { self.vertices.map(|v| v.0) }
+    pub fn area(&self) -> T { self.area }
+
+    /// The barycentric coordinates of the point for the canonical numbers `u`.
+    pub fn barycentrics(u: [T; 2]) -> [T; 3] {
+        let half = T::from_scalar(0.5f64).unwrap();
+
+        let (b_0, b_1) = if u[0] < u[1] {
+            let b_0 = u[0] * half;
+            (b_0, u[1] - b_0)
+        } else {
+            let b_1 = u[1] * half;
+            (u[0] - b_1, b_1)
+        };
+
+        [b_0, b_1, <T as ZeroAndOne>::one() - b_0 - b_1]
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const N: usize> NDSampler<T, N> for UniformTriangleSampler<T, N> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; N], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; N], T) {
+        let [b_0, b_1, b_2] = Self::barycentrics([u[0], u[1]]);
+        let point = self.vertices[0] * b_0 + self.vertices[1] * b_1 + self.vertices[2] * b_2;
+
+        (point.0, <T as ZeroAndOne>::one() / self.area)
+    }
+
+    fn pdf(&self, point: &[T; N]) -> T {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+
+        let (e_1, e_2) = (self.vertices[1] - self.vertices[0], self.vertices[2] - self.vertices[0]);
+        let d = Vector(*point) - self.vertices[0];
+
+        // the barycentric coordinates of the projection onto the plane of the triangle, by the normal equations
+        let (d_11, d_12, d_22) = (e_1.dot(e_1), e_1.dot(e_2), e_2.dot(e_2));
+        let (d_1, d_2) = (d.dot(e_1), d.dot(e_2));
+        let gram = d_11 * d_22 - d_12 * d_12;
+        let b_1 = (d_22 * d_1 - d_12 * d_2) / gram;
+        let b_2 = (d_11 * d_2 - d_12 * d_1) / gram;
+
+        // a little slack for the rounding of the samples, and of points that were found by intersecting the triangle
+        let tolerance = <T as NumericLimits>::epsilon().sqrt();
+        let off_plane = (d - e_1 * b_1 - e_2 * b_2).length();
+        let inside = b_1 >= -tolerance && b_2 >= -tolerance && b_1 + b_2 <= one + tolerance;
+
+        if inside && off_plane <= tolerance * (e_1.length() + e_2.length()) {
+            one / self.area
+        } else {
+            zero
+        }
+    }
+}
+
+/// Directions towards a triangle, uniform over the solid angle it subtends (Arvo, "Stratified Sampling of Spherical
+/// Triangles", 1995); the density is per unit solid angle.
+///
+/// The first canonical number picks the sub-triangle of the wanted area that shares the edge from `A` to `B`, by
+/// solving for its third vertex `C'` on the arc from `A` to `C`, and the second a point on the arc from `B` to `C'`.
+pub struct UniformSphericalTriangleSampler<T: FloatingPoint + GenerateCanonical<T>> {
+    vertices: [Vector<T, 3>; 3],
+    solid_angle: T,
+    angle: T,
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> UniformSphericalTriangleSampler<T> {
+    /// The triangle with the directions `vertices` as corners, they need not be normalised.
+    pub fn new(vertices: [[T; 3]; 3]) -> Self {
+        let zero = <T as ZeroAndOne>::zero();
+        let one = <T as ZeroAndOne>::one();
+
+        let [a, b, c] = vertices.map(|v| Vector(v).normalized());
+
+        // Van Oosterom and Strackee, "The Solid Angle of a Plane Triangle", 1983
+        let triple = a.dot(b.cross(c)).abs();
+        let solid_angle = T::from_scalar(2).unwrap() * triple.atan2(one + a.dot(b) + b.dot(c) + c.dot(a));
+        assert!(solid_angle > zero, "the triangle must not be degenerate");
+
+        // the angle at a between the arcs towards b and c, α in the paper
+        let tangent = |towards: Vector<T, 3>| (towards - a * a.dot(towards)).normalized();
+        let angle = tangent(b).dot(tangent(c)).clamp(-one, one).acos();
+
+        Self { vertices: [a, b, c], solid_angle, angle }
+    }
+
+    /// The triangle `vertices` as seen from `origin`.
+    pub fn from_triangle(origin: [T; 3], vertices: [[T; 3]; 3]) -> Self { Self::new(vertices.map(|v| (Vector(v) - Vector(origin)).0)) }
+
+    pub fn vertices(&self) -> [[T; 3]; 3] { self.vertices.map(|v| v.0) }
+    pub fn solid_angle(&self) -> T { self.solid_angle }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> NDSampler<T, 3> for UniformSphericalTriangleSampler<T> {
+    const CANONICAL_DIMENSIONS: usize = 2;
+
+    fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([T; 3], T) {
+        self.sample_canonical(&[T::generate_canonical(gen).get(), T::generate_canonical(gen).get()])
+    }
+
+    fn sample_canonical(&mut self, u: &[T]) -> ([T; 3], T) {
+        let one = <T as ZeroAndOne>::one();
+        let [a, b, c] = self.vertices;
+
+        // the sub-triangle with the area u_0 Ω
+        let area = u[0] * self.solid_angle;
+        let (sin_angle, cos_angle) = self.angle.sin_cos();
+        let (s, t) = (area - self.angle).sin_cos();
+
+        // the u and v of the paper
+        let x = t - cos_angle;
+        let y = s + sin_angle * a.dot(b);
+
+        let q = (((y * t - x * s) * cos_angle - y) / ((y * s + x * t) * sin_angle)).clamp(-one, one);
+        let c_ = a * q + detail::orthogonal_direction(c, a) * (one - q * q).max(<T as ZeroAndOne>::zero()).sqrt();
+
+        // the point on the arc from b to c' with the wanted share of the sub-triangle
+        let z = one - u[1] * (one - c_.dot(b));
+        let direction = b * z + detail::orthogonal_direction(c_, b) * (one - z * z).max(<T as ZeroAndOne>::zero()).sqrt();
+
+        (direction.normalized().0, one / self.solid_angle)
+    }
+
+    fn pdf(&self, point: &[T; 3]) -> T {
+        let [a, b, c] = self.vertices;
+        let point = Vector(*point);
+
+        // inside if on the same side of the planes of all three edges as the opposite vertex
+        let orientation = a.dot(b.cross(c));
+        let inside = [(a, b), (b, c), (c, a)].iter().all(|&(p, q)| point.dot(p.cross(q)) * orientation >= <T as ZeroAndOne>::zero());
+
+        if inside {
+            <T as ZeroAndOne>::one() / self.solid_angle
+        } else {
+            <T as ZeroAndOne>::zero()
+        }
+    }
+}
+
+mod detail {
+    use crate::smallvec::Vector;
+    use crate::{FloatingPoint, ZeroAndOne};
+
+    /// The unit vector in the plane of `v` and `axis` orthogonal to `axis`, towards `v`; zero if they are parallel.
+    pub fn orthogonal_direction<T: FloatingPoint>(v: Vector<T, 3>, axis: Vector<T, 3>) -> Vector<T, 3> {
+        let orthogonal = v - axis * axis.dot(v);
+        let length = orthogonal.length();
+
+        if length > <T as ZeroAndOne>::zero() {
+            orthogonal / length
+        } else {
+            orthogonal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangle_area() {
+        let triangle = UniformTriangleSampler::new([[0f64, 0f64, 0f64], [2f64, 0f64, 0f64], [0f64, 0f64, 3f64]]);
+        assert_eq!(triangle.area(), 3f64);
+
+        let triangle = UniformTriangleSampler::new([[1f64, 1f64], [2f64, 1f64], [1f64, 3f64]]);
+        assert_eq!(triangle.area(), 1f64);
+
+        // the corners of the square go to the corners of the triangle
+        assert_eq!(UniformTriangleSampler::<f64, 2>::barycentrics([0f64, 0f64]), [0f64, 0f64, 1f64]);
+        assert_eq!(UniformTriangleSampler::<f64, 2>::barycentrics([1f64, 0f64]), [1f64, 0f64, 0f64]);
+        assert_eq!(UniformTriangleSampler::<f64, 2>::barycentrics([0f64, 1f64]), [0f64, 1f64, 0f64]);
+    }
+
+    #[test]
+    fn test_spherical_triangle_inside() {
+        let mut triangle = UniformSphericalTriangleSampler::from_triangle([0.1f64, -0.2f64, 0.3f64], [[1f64, 0f64, 2f64], [-1f64, 1f64, 1.5f64], [0.5f64, 3f64, 1f64]]);
+        let [a, b, c] = triangle.vertices;
+        let orientation = a.dot(b.cross(c)).signum();
+
+        let steps = 32;
+        for i in 0..steps {
+            for j in 0..steps {
+                let u = [(i as f64 + 0.5f64) / steps as f64, (j as f64 + 0.5f64) / steps as f64];
+                let (w, pdf) = triangle.sample_canonical(&u);
+                let w = Vector(w);
+
+                assert!((w.length() - 1f64).abs() < 1e-12);
+                assert_eq!(pdf, 1f64 / triangle.solid_angle());
+
+                for (p, q) in [(a, b), (b, c), (c, a)] {
+                    assert!(w.dot(p.cross(q)) * orientation > -1e-12, "{:?} at {:?} is outside", w, u);
+                }
+            }
+        }
+
+        // the corners of the square go to the corners of the triangle
+        let mut corner = |u: [f64; 2]| Vector(triangle.sample_canonical(&u).0);
+        assert!((corner([0f64, 0f64]) - b).length() < 1e-12);
+        assert!((corner([0f64, 1f64]) - a).length() < 1e-9);
+        assert!((corner([1f64, 1f64]) - c).length() < 1e-9);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::rng::*;
+use crate::*;
+
+/// Integers uniformly distributed over the closed range `[a, b]`, like `std::uniform_int_distribution`.
+///
+/// Uses Lemire's nearly divisionless method ("Fast Random Integer Generation in an Interval", 2019), which needs a
+/// division only when a sample lands in the small, possibly biased, region that is to be rejected.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct UniformIntDistribution<T: Integral> {
+    param: UniformIntDistributionParams<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct UniformIntDistributionParams<T: Integral> {
+    pub a: T,
+    pub b: T,
+}
+
+impl<T: Integral> UniformIntDistributionParams<T> {
+    pub fn new(a: T, b: T) -> Self {
+        assert!(a <= b, "the range of a uniform integer distribution must not be empty");
+        Self { a, b }
+    }
+}
+
+impl<T: Integral> Default for UniformIntDistributionParams<T> {
+    fn default() -> Self { Self { a: T::zero(), b: NumericLimits::max() } }
+}
+
+impl<T: Integral> UniformIntDistribution<T> {
+    pub fn new(a: T, b: T) -> Self { Self { param: UniformIntDistributionParams::new(a, b) } }
+
+    pub fn a(&self) -> T { self.param.a }
+    pub fn b(&self) -> T { self.param.b }
+}
+
+impl<T: Integral> Default for UniformIntDistribution<T> {
+    fn default() -> Self { Self { param: Default::default() } }
+}
+
+impl<T: Integral> RandomNumberDistribution for UniformIntDistribution<T> {
+    type ResultType = T;
+    type ParamType = UniformIntDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType { self.param.a }
+    fn max(&self) -> Self::ResultType { self.param.b }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        debug_assert!(param.a <= param.b);
+
+        let bits = T::DIGITS + if T::IS_SIGNED { 1 } else { 0 };
+
+        // the two's complement difference is correct for signed types as well, as long as it is truncated to `bits`
+        let a: u128 = param.a.as_to();
+        let b: u128 = param.b.as_to();
+        let range = detail::truncate(b.wrapping_sub(a), bits);
+
+        let offset = if range == detail::truncate(u128::MAX, bits) {
+            util::fill_with_entropy::<u128, _>(generator, bits)
+        } else {
+            detail::lemire(generator, range + 1, bits)
+        };
+
+        T::as_from(a.wrapping_add(offset))
+    }
+}
+
+mod detail {
+    use crate::rng::*;
+
+    pub fn truncate(v: u128, bits: u32) -> u128 { if bits >= 128 { v } else { v & ((1u128 << bits) - 1) } }
+
+    /// The full `x * s` product split at bit `bits`, for `x, s < 2^bits`.
+    fn widening_mul(x: u128, s: u128, bits: u32) -> (u128, u128) {
+        if bits <= 64 {
+            let product = x * s;
+            return (product >> bits, truncate(product, bits));
+        }
+
+        debug_assert_eq!(bits, 128);
+
+        let (x_hi, x_lo) = (x >> 64, x & (u64::MAX as u128));
+        let (s_hi, s_lo) = (s >> 64, s & (u64::MAX as u128));
+
+        let lo_lo = x_lo * s_lo;
+        let hi_lo = x_hi * s_lo;
+        let lo_hi = x_lo * s_hi;
+        let hi_hi = x_hi * s_hi;
+
+        let middle = (lo_lo >> 64) + (hi_lo & (u64::MAX as u128)) + (lo_hi & (u64::MAX as u128));
+
+        let lo = (middle << 64) | (lo_lo & (u64::MAX as u128));
+        let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64);
+
+        (hi, lo)
+    }
+
+    /// One round of Lemire's method: maps `x`, uniform in `[0, 2^bits)`, to `[0, s)` or rejects it.
+    pub fn lemire_step(x: u128, s: u128, bits: u32, threshold: &mut Option<u128>) -> Option<u128> {
+        let (hi, lo) = widening_mul(x, s, bits);
+
+        if lo < s {
+            // (2^bits - s) mod s, the number of values of `lo` that have to be rejected
+            let threshold = *threshold.get_or_insert_with(|| truncate(0u128.wrapping_sub(s), bits) % s);
+
+            if lo < threshold {
+                return None;
+            }
+        }
+
+        Some(hi)
+    }
+
+    pub fn lemire<G: UniformRandomBitGenerator>(generator: &mut G, s: u128, bits: u32) -> u128 {
+        debug_assert!(s != 0);
+
+        let mut threshold = None;
+
+        loop {
+            let x = util::fill_with_entropy::<u128, _>(generator, bits);
+
+            if let Some(res) = lemire_step(x, s, bits, &mut threshold) {
+                return res;
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_widening_mul() {
+            assert_eq!(widening_mul(0xFF, 0xFF, 8), (0xFE, 0x01));
+            assert_eq!(widening_mul(u64::MAX as u128, u64::MAX as u128, 64), (u64::MAX as u128 - 1, 1));
+            assert_eq!(widening_mul(u128::MAX, u128::MAX, 128), (u128::MAX - 1, 1));
+            assert_eq!(widening_mul(u128::MAX, 2, 128), (1, u128::MAX - 1));
+            assert_eq!(widening_mul(1u128 << 64, 1u128 << 64, 128), (1, 0));
+        }
+
+        #[test]
+        fn test_lemire_is_exact() {
+            // every accepted x in [0, 2^8) maps to each of the s outputs equally often
+            for s in 1..=256u128 {
+                let mut counts = vec![0usize; s as usize];
+                let mut threshold = None;
+
+                for x in 0..256u128 {
+                    if let Some(v) = lemire_step(x, s, 8, &mut threshold) {
+                        counts[v as usize] += 1;
+                    }
+                }
+
+                assert!(counts.iter().all(|&c| c == 256 / s as usize), "biased for s = {}: {:?}", s, counts);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use crate::rng::test::OddRangeGenerator;
+    use super::*;
+
+    fn test_bounds<T: Integral, G: UniformRandomBitGenerator>(generator: &mut G, a: T, b: T, iterations: usize) -> (T, T) {
+        let mut distribution = UniformIntDistribution::new(a, b);
+        let mut seen = (b, a);
+
+        for _ in 0..iterations {
+            let v = distribution.generate(generator);
+            assert!(a <= v && v <= b, "{:?} is out of [{:?}, {:?}]", v, a, b);
+
+            seen = (std::cmp::min(seen.0, v), std::cmp::max(seen.1, v));
+        }
+
+        seen
+    }
+
+    #[test]
+    fn test_uniform_int_bounds() {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        assert_eq!(test_bounds(&mut generator, 5u32, 5u32, 100), (5, 5));
+        assert_eq!(test_bounds(&mut generator, 0u8, 1u8, 100), (0, 1));
+        assert_eq!(test_bounds(&mut generator, -3i32, 3i32, 1000), (-3, 3));
+        assert_eq!(test_bounds(&mut generator, i8::MIN, i8::MAX, 10000), (i8::MIN, i8::MAX));
+        assert_eq!(test_bounds(&mut generator, u8::MIN, u8::MAX, 10000), (u8::MIN, u8::MAX));
+        assert_eq!(test_bounds(&mut generator, i64::MIN, i64::MIN + 2, 1000), (i64::MIN, i64::MIN + 2));
+        assert_eq!(test_bounds(&mut generator, u64::MAX - 2, u64::MAX, 1000), (u64::MAX - 2, u64::MAX));
+        assert_eq!(test_bounds(&mut generator, u128::MAX - 2, u128::MAX, 1000), (u128::MAX - 2, u128::MAX));
+        assert_eq!(test_bounds(&mut generator, i128::MIN, i128::MIN + 1, 1000), (i128::MIN, i128::MIN + 1));
+
+        test_bounds(&mut generator, u128::MIN, u128::MAX, 1000);
+        test_bounds(&mut generator, i128::MIN, i128::MAX, 1000);
+        test_bounds(&mut generator, 0u128, (u128::MAX / 3) * 2, 1000);
+
+        let mut generator = OddRangeGenerator(generator);
+        assert_eq!(test_bounds(&mut generator, 10u16, 20u16, 1000), (10, 20));
+        assert_eq!(test_bounds(&mut generator, -1000i64, 1000i64, 100000), (-1000, 1000));
+        test_bounds(&mut generator, u64::MIN, u64::MAX, 1000);
+    }
+
+    #[test]
+    fn test_uniform_int_param() {
+        let mut distribution = UniformIntDistribution::new(1i32, 6i32);
+        assert_eq!((distribution.min(), distribution.max()), (1, 6));
+
+        distribution.set_param(&UniformIntDistributionParams::new(-6, -1));
+        assert_eq!(distribution.get_param(), UniformIntDistributionParams { a: -6, b: -1 });
+
+        let mut generator = PCG32::new_with_stream(42, 54);
+        for _ in 0..100 {
+            let v = distribution.generate_param(&mut generator, &UniformIntDistributionParams::new(100, 101));
+            assert!(v == 100 || v == 101);
+        }
+    }
+}
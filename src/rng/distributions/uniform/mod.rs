@@ -1,7 +1,41 @@
+pub mod int;
+pub mod real;
+
+pub use int::*;
+pub use real::*;
+
 use crate::{rng::*, FloatingPoint};
 
 pub trait GenerateCanonical<T: FloatingPoint> {
+    /// A float in `[0, 1)` with a fixed granularity of `2^-DIGITS`.
     fn generate_canonical<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<T>;
+
+    /// A float in `[0, 1]` with a fixed granularity of `1 / (2^DIGITS - 1)`.
+    fn generate_canonical_closed<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<T>;
+
+    /// A float in `(0, 1)`, the midpoints of a grid with a spacing of `2^-(DIGITS - 1)`.
+    fn generate_canonical_open<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<T>;
+
+    /// A float in `[0, 1)` where every representable value, subnormals included, can occur.
+    ///
+    /// This is Downey's method ("Generating Pseudo-random Floating-Point Values", 2007): the exponent is drawn from a
+    /// geometric distribution and the mantissa uniformly, which is equivalent to rounding a real number uniform in
+    /// `[0, 1)` down to the next float. It consumes `DIGITS - 1` bits plus a single word of entropy almost always.
+    fn generate_canonical_dense<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<T>;
+
+    /// Like `generate_canonical_dense` but rounding to the nearest float, hence in `[0, 1]`.
+    fn generate_canonical_dense_closed<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<T>;
+
+    /// Like `generate_canonical_dense` but in `(0, 1)`.
+    fn generate_canonical_dense_open<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<T> {
+        loop {
+            let res = Self::generate_canonical_dense(generator);
+
+            if res != T::zero() {
+                return res;
+            }
+        }
+    }
 }
 
 impl GenerateCanonical<f32> for f32 {
@@ -11,13 +45,193 @@ impl GenerateCanonical<f32> for f32 {
 
         return Canonical::new((entropy as f32) * util::quick_exp2_f32(-24)).unwrap();
     }
+
+    fn generate_canonical_closed<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f32> {
+        let entropy = util::fill_with_entropy::<u32, _>(generator, 24);
+
+        Canonical::new((entropy as f32) / (0x00FF_FFFF_u32 as f32)).unwrap()
+    }
+
+    fn generate_canonical_open<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f32> {
+        let entropy = util::fill_with_entropy::<u32, _>(generator, 23);
+
+        Canonical::new(((2 * entropy + 1) as f32) * util::quick_exp2_f32(-24)).unwrap()
+    }
+
+    fn generate_canonical_dense<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f32> {
+        let bits = detail::dense_bits(generator, 23, 126, false);
+
+        Canonical::new(f32::from_bits(bits as u32)).unwrap()
+    }
+
+    fn generate_canonical_dense_closed<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f32> {
+        let bits = detail::dense_bits(generator, 23, 126, true);
+
+        Canonical::new(f32::from_bits(bits as u32)).unwrap()
+    }
 }
 
 impl GenerateCanonical<f64> for f64 {
     fn generate_canonical<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f64> {
-        let entropy = util::fill_with_entropy::<u64, _>(generator, 48);
-        debug_assert!(entropy <= 0xFFFF_FFFF_FFFF_u64);
+        let entropy = util::fill_with_entropy::<u64, _>(generator, 53);
+        debug_assert!(entropy <= 0x1F_FFFF_FFFF_FFFF_u64);
+
+        return Canonical::new((entropy as f64) * util::quick_exp2_f64(-53)).unwrap();
+    }
+
+    fn generate_canonical_closed<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f64> {
+        let entropy = util::fill_with_entropy::<u64, _>(generator, 53);
+
+        Canonical::new((entropy as f64) / (0x1F_FFFF_FFFF_FFFF_u64 as f64)).unwrap()
+    }
+
+    fn generate_canonical_open<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f64> {
+        let entropy = util::fill_with_entropy::<u64, _>(generator, 52);
+
+        Canonical::new(((2 * entropy + 1) as f64) * util::quick_exp2_f64(-53)).unwrap()
+    }
+
+    fn generate_canonical_dense<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f64> {
+        let bits = detail::dense_bits(generator, 52, 1022, false);
+
+        Canonical::new(f64::from_bits(bits)).unwrap()
+    }
+
+    fn generate_canonical_dense_closed<G: UniformRandomBitGenerator>(generator: &mut G) -> Canonical<f64> {
+        let bits = detail::dense_bits(generator, 52, 1022, true);
+
+        Canonical::new(f64::from_bits(bits)).unwrap()
+    }
+}
+
+mod detail {
+    use crate::rng::*;
+
+    /// The IEEE 754 bit pattern of a dense canonical float with `mantissa_bits` explicit mantissa bits, where
+    /// `top_exponent` is the biased exponent of `[0.5, 1)`.
+    pub fn dense_bits<G: UniformRandomBitGenerator>(generator: &mut G, mantissa_bits: u32, top_exponent: u64, round_to_nearest: bool) -> u64 {
+        let mut exponent = top_exponent;
+
+        // every leading zero halves the binade, the subnormals share the width of the smallest normal binade
+        while exponent > 0 {
+            let word = util::fill_with_entropy::<u64, _>(generator, 64);
+
+            exponent -= std::cmp::min(word.leading_zeros() as u64, exponent);
+
+            if word != 0 {
+                break;
+            }
+        }
+
+        let mantissa = util::fill_with_entropy::<u64, _>(generator, mantissa_bits);
+
+        // a power of two is the upper neighbour of half of its lower binade's interval when rounding to nearest
+        if round_to_nearest && mantissa == 0 && util::fill_with_entropy::<u8, _>(generator, 1) != 0 {
+            exponent += 1;
+        }
+
+        (exponent << mantissa_bits) | mantissa
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ConstantGenerator(u64);
+
+    impl UniformRandomBitGenerator for ConstantGenerator {
+        type ResultType = u64;
+        fn min() -> Self::ResultType { u64::MIN }
+        fn max() -> Self::ResultType { u64::MAX }
+        fn generate(&mut self) -> Self::ResultType { self.0 }
+    }
+
+    #[test]
+    fn test_canonical_extremes() {
+        let mut zeros = ConstantGenerator(0);
+        let mut ones = ConstantGenerator(u64::MAX);
+
+        assert!(f64::generate_canonical(&mut zeros) == 0f64);
+        assert!(f64::generate_canonical(&mut ones) == 1f64.next_down());
+        assert!(f64::generate_canonical_closed(&mut zeros) == 0f64);
+        assert!(f64::generate_canonical_closed(&mut ones) == 1f64);
+        assert!(f64::generate_canonical_open(&mut zeros) == util::quick_exp2_f64(-53));
+        assert!(f64::generate_canonical_open(&mut ones) == 1f64.next_down());
+        assert!(f64::generate_canonical_dense(&mut zeros) == 0f64);
+        assert!(f64::generate_canonical_dense(&mut ones) == 1f64.next_down());
+        assert!(f64::generate_canonical_dense_closed(&mut ones) == 1f64.next_down());
+
+        assert!(f32::generate_canonical(&mut ones) == 1f32.next_down());
+        assert!(f32::generate_canonical_closed(&mut ones) == 1f32);
+        assert!(f32::generate_canonical_open(&mut zeros) == util::quick_exp2_f32(-24));
+        assert!(f32::generate_canonical_dense(&mut zeros) == 0f32);
+        assert!(f32::generate_canonical_dense(&mut ones) == 1f32.next_down());
+    }
+
+    /// Yields one word with only the lowest bit set after `zero_words` zero words, then ones.
+    struct LeadingZerosGenerator {
+        zero_words: usize,
+    }
+
+    impl UniformRandomBitGenerator for LeadingZerosGenerator {
+        type ResultType = u64;
+        fn min() -> Self::ResultType { u64::MIN }
+        fn max() -> Self::ResultType { u64::MAX }
+
+        fn generate(&mut self) -> Self::ResultType {
+            match self.zero_words {
+                0 => {
+                    self.zero_words = usize::MAX;
+                    1
+                }
+                usize::MAX => u64::MAX,
+                _ => {
+                    self.zero_words -= 1;
+                    0
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dense_exponents() {
+        // 63 zero bits and the one: [2^-64, 2^-63)
+        let v = f64::generate_canonical_dense(&mut LeadingZerosGenerator { zero_words: 0 }).get();
+        assert_eq!(v, util::quick_exp2_f64(-63).next_down());
+
+        let v = f64::generate_canonical_dense(&mut LeadingZerosGenerator { zero_words: 2 }).get();
+        assert_eq!(v, util::quick_exp2_f64(-191).next_down());
+
+        // past the smallest normal binade, the largest subnormal
+        let v = f64::generate_canonical_dense(&mut LeadingZerosGenerator { zero_words: 15 }).get();
+        assert_eq!(v, f64::MIN_POSITIVE.next_down());
+
+        let v = f32::generate_canonical_dense(&mut LeadingZerosGenerator { zero_words: 1 }).get();
+        assert_eq!(v, f32::MIN_POSITIVE.next_down());
+    }
+
+    #[test]
+    fn test_dense_is_dense() {
+        use crate::rng::engines::Xoroshiro128PP;
+
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        // below 2^-8 the regular method leaves the 8 lowest mantissa bits zero
+        let mut small = 0;
+        let mut fine = 0;
+
+        while small < 64 {
+            let v = f32::generate_canonical_dense(&mut generator).get();
+            assert!((0f32..1f32).contains(&v));
+
+            if v < util::quick_exp2_f32(-8) {
+                small += 1;
+                fine += (v.to_bits() & 0xFF != 0) as usize;
+            }
+        }
 
-        return Canonical::new((entropy as f64) * util::quick_exp2_f64(-48)).unwrap();
+        assert!(fine > 48);
     }
 }
@@ -0,0 +1,233 @@
+use crate::rng::distributions::GenerateCanonical;
+use crate::rng::*;
+use crate::*;
+
+/// Which of the endpoints of `[a, b]` a `UniformRealDistribution` may return.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum UniformRealInterval {
+    /// `[a, b)`, like `std::uniform_real_distribution`.
+    HalfOpen,
+    Closed,
+    Open,
+}
+
+/// How the canonical float scaled to `[a, b]` is generated.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum CanonicalPrecision {
+    /// `DIGITS` bits of entropy per sample, values close to 0 share the granularity of the ones close to 1.
+    Fast,
+    /// Every representable float in the unit interval is reachable, see `GenerateCanonical::generate_canonical_dense`.
+    ///
+    /// The extra precision is only retained by the scaling if the interval starts at 0.
+    Dense,
+}
+
+/// Floats uniformly distributed over `[a, b)`, or `[a, b]` and `(a, b)` depending on the interval.
+///
+/// Results that round onto an excluded endpoint are rejected, `a + (b - a) * u` can round up to `b` even though
+/// `u < 1`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct UniformRealDistribution<T: FloatingPoint + GenerateCanonical<T>> {
+    param: UniformRealDistributionParams<T>,
+    interval: UniformRealInterval,
+    precision: CanonicalPrecision,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct UniformRealDistributionParams<T: FloatingPoint> {
+    pub a: T,
+    pub b: T,
+}
+
+impl<T: FloatingPoint> UniformRealDistributionParams<T> {
+    pub fn new(a: T, b: T) -> Self {
+        assert!(a <= b, "the range of a uniform real distribution must not be empty");
+        Self { a, b }
+    }
+}
+
+impl<T: FloatingPoint> Default for UniformRealDistributionParams<T> {
+    fn default() -> Self { Self { a: T::zero(), b: T::one() } }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> UniformRealDistribution<T> {
+    pub fn new(a: T, b: T) -> Self { Self::new_with(a, b, UniformRealInterval::HalfOpen, CanonicalPrecision::Fast) }
+
+    pub fn new_closed(a: T, b: T) -> Self { Self::new_with(a, b, UniformRealInterval::Closed, CanonicalPrecision::Fast) }
+
+    pub fn new_open(a: T, b: T) -> Self { Self::new_with(a, b, UniformRealInterval::Open, CanonicalPrecision::Fast) }
+
+    pub fn new_with(a: T, b: T, interval: UniformRealInterval, precision: CanonicalPrecision) -> Self {
+        let param = UniformRealDistributionParams::new(a, b);
+        Self::check_param(&param, interval);
+
+        Self { param, interval, precision }
+    }
+
+    /// The same distribution but using `CanonicalPrecision::Dense`.
+    pub fn dense(self) -> Self { Self { precision: CanonicalPrecision::Dense, ..self } }
+
+    pub fn a(&self) -> T { self.param.a }
+    pub fn b(&self) -> T { self.param.b }
+
+    pub fn interval(&self) -> UniformRealInterval { self.interval }
+    pub fn precision(&self) -> CanonicalPrecision { self.precision }
+
+    /// Panics unless the interval holds a float, otherwise `generate_param` would never find one.
+    fn check_param(param: &UniformRealDistributionParams<T>, interval: UniformRealInterval) {
+        let (a, b) = (param.a, param.b);
+
+        match interval {
+            UniformRealInterval::HalfOpen => assert!(a < b, "a half-open uniform real distribution needs a < b"),
+            UniformRealInterval::Closed => assert!(a <= b, "a closed uniform real distribution needs a <= b"),
+            UniformRealInterval::Open => assert!(a < b && a.next_up() < b, "an open uniform real distribution needs a float between a and b"),
+        }
+    }
+
+    fn generate_canonical<G: UniformRandomBitGenerator>(&self, generator: &mut G) -> T {
+        let canonical = match (self.precision, self.interval) {
+            (CanonicalPrecision::Fast, UniformRealInterval::HalfOpen) => T::generate_canonical(generator),
+            (CanonicalPrecision::Fast, UniformRealInterval::Closed) => T::generate_canonical_closed(generator),
+            (CanonicalPrecision::Fast, UniformRealInterval::Open) => T::generate_canonical_open(generator),
+            (CanonicalPrecision::Dense, UniformRealInterval::HalfOpen) => T::generate_canonical_dense(generator),
+            (CanonicalPrecision::Dense, UniformRealInterval::Closed) => T::generate_canonical_dense_closed(generator),
+            (CanonicalPrecision::Dense, UniformRealInterval::Open) => T::generate_canonical_dense_open(generator),
+        };
+
+        canonical.get()
+    }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> Default for UniformRealDistribution<T> {
+    fn default() -> Self { Self::new(T::zero(), T::one()) }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> RandomNumberDistribution for UniformRealDistribution<T> {
+    type ResultType = T;
+    type ParamType = UniformRealDistributionParams<T>;
+
+    fn min(&self) -> Self::ResultType {
+        match self.interval {
+            UniformRealInterval::Open => self.param.a.next_up(),
+            _ => self.param.a,
+        }
+    }
+
+    fn max(&self) -> Self::ResultType {
+        match self.interval {
+            UniformRealInterval::Closed => self.param.b,
+            _ => self.param.b.next_down(),
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn get_param(&self) -> Self::ParamType { self.param }
+    fn set_param(&mut self, param: &Self::ParamType) { self.param = *param; }
+
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) -> Self::ResultType {
+        let param = self.param;
+        self.generate_param(generator, &param)
+    }
+
+    fn generate_param<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, param: &Self::ParamType) -> Self::ResultType {
+        Self::check_param(param, self.interval);
+        let (a, b) = (param.a, param.b);
+
+        loop {
+            let res = a + (b - a) * self.generate_canonical(generator);
+
+            match self.interval {
+                UniformRealInterval::HalfOpen if res < b => return res,
+                UniformRealInterval::Closed => return res.min(b),
+                UniformRealInterval::Open if a < res && res < b => return res,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use super::*;
+
+    fn test_bounds<T: FloatingPoint + GenerateCanonical<T> + std::fmt::Debug>(mut distribution: UniformRealDistribution<T>, iterations: usize) -> (T, T) {
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        let mut seen = (distribution.b(), distribution.a());
+
+        for _ in 0..iterations {
+            let v = distribution.generate(&mut generator);
+            assert!(distribution.min() <= v && v <= distribution.max(), "{:?} is out of [{:?}, {:?}]", v, distribution.min(), distribution.max());
+
+            seen = (seen.0.min(v), seen.1.max(v));
+        }
+
+        seen
+    }
+
+    #[test]
+    fn test_uniform_real_bounds() {
+        let one = 1f64;
+        let two_ulps = one.next_up().next_up();
+
+        assert_eq!(test_bounds(UniformRealDistribution::new(one, one.next_up()), 100), (one, one));
+        assert_eq!(test_bounds(UniformRealDistribution::new(one, two_ulps), 1000), (one, one.next_up()));
+        assert_eq!(test_bounds(UniformRealDistribution::new_closed(one, one.next_up()), 1000), (one, one.next_up()));
+        assert_eq!(test_bounds(UniformRealDistribution::new_open(one, two_ulps), 100), (one.next_up(), one.next_up()));
+        assert_eq!(test_bounds(UniformRealDistribution::new_closed(one, one), 100), (one, one));
+
+        assert_eq!(test_bounds(UniformRealDistribution::new(one, one.next_up()).dense(), 100), (one, one));
+        assert_eq!(test_bounds(UniformRealDistribution::new_open(one, two_ulps).dense(), 100), (one.next_up(), one.next_up()));
+
+        // ranges where rounding onto `b` is likely
+        test_bounds(UniformRealDistribution::new(0.1f64, 0.3f64), 10000);
+        test_bounds(UniformRealDistribution::new(-1f32, 1f32.next_up()).dense(), 10000);
+        test_bounds(UniformRealDistribution::new_open(-1e30f64, 1e-30f64), 10000);
+        test_bounds(UniformRealDistribution::new_closed(0.1f32, 0.3f32).dense(), 10000);
+    }
+
+    #[test]
+    fn test_uniform_real_param() {
+        let mut distribution = UniformRealDistribution::new(1f32, 6f32);
+        assert_eq!((distribution.min(), distribution.max()), (1f32, 6f32.next_down()));
+        assert_eq!(distribution.interval(), UniformRealInterval::HalfOpen);
+        assert_eq!(distribution.precision(), CanonicalPrecision::Fast);
+
+        distribution.set_param(&UniformRealDistributionParams::new(-6f32, -1f32));
+        assert_eq!(distribution.get_param(), UniformRealDistributionParams { a: -6f32, b: -1f32 });
+
+        let distribution = distribution.dense();
+        assert_eq!(distribution.precision(), CanonicalPrecision::Dense);
+        assert_eq!((distribution.a(), distribution.b()), (-6f32, -1f32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_uniform_real_empty_half_open() { UniformRealDistribution::new_with(1f64, 1f64, UniformRealInterval::HalfOpen, CanonicalPrecision::Dense); }
+
+    #[test]
+    #[should_panic]
+    fn test_uniform_real_empty_open() { UniformRealDistribution::new_with(1f64, 1f64.next_up(), UniformRealInterval::Open, CanonicalPrecision::Fast); }
+
+    #[test]
+    #[should_panic]
+    fn test_uniform_real_empty_set_param() {
+        let mut generator = Xoroshiro128PP::new();
+        let mut distribution = UniformRealDistribution::new(0f32, 1f32);
+
+        distribution.set_param(&UniformRealDistributionParams::new(1f32, 1f32));
+        distribution.generate(&mut generator);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_uniform_real_empty_generate_param() {
+        let mut generator = Xoroshiro128PP::new();
+        let mut distribution = UniformRealDistribution::new_open(0f32, 1f32);
+
+        distribution.generate_param(&mut generator, &UniformRealDistributionParams::new(1f32, 1f32.next_up()));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::rng::distributions::GenerateCanonical;
+use crate::rng::*;
+
+/// Layers of a ziggurat covering the right half of a monotonically decreasing density, after Marsaglia and Tsang
+/// ("The Ziggurat Method for Generating Random Variables", 2000).
+///
+/// `x[0]` is the width of a rectangle with the area of a layer, so that the base layer (the rectangle up to `r` plus
+/// the tail) can be handled like the others; `x[LAYERS]` is 0.
+pub struct Ziggurat {
+    x: [f64; LAYERS + 1],
+    f: [f64; LAYERS + 1],
+    pdf: fn(f64) -> f64,
+}
+
+pub const LAYERS: usize = 256;
+
+impl Ziggurat {
+    /// `r` is the start of the tail and `v` the area of each layer, both depend on `pdf` and on `LAYERS`.
+    pub fn new(r: f64, v: f64, pdf: fn(f64) -> f64, inverse_pdf: fn(f64) -> f64) -> Self {
+        let mut x = [0f64; LAYERS + 1];
+        let mut f = [0f64; LAYERS + 1];
+
+        x[0] = v / pdf(r);
+        x[1] = r;
+
+        for i in 1..LAYERS - 1 {
+            x[i + 1] = inverse_pdf(v / x[i] + pdf(x[i]));
+        }
+
+        for i in 0..=LAYERS {
+            f[i] = pdf(x[i]);
+        }
+
+        Self { x, f, pdf }
+    }
+
+    /// A sample of the density over `[0, inf)`, or over the real line with a random sign if `symmetric` is set.
+    ///
+    /// `tail` has to return samples of the density conditioned on being beyond `r`.
+    pub fn sample<G: UniformRandomBitGenerator>(&self, generator: &mut G, symmetric: bool, mut tail: impl FnMut(&mut G, f64) -> f64) -> f64 {
+        loop {
+            // one word for the layer (bits 0..8), the sign (bit 8) and the position (bits 11..64)
+            let bits = util::fill_with_entropy::<u64, _>(generator, 64);
+            let layer = (bits & 0xFF) as usize;
+            // applied by setting the sign bit, a branch on a random bit is mispredicted half of the time
+            let sign = if symmetric { ((bits >> 8) & 1) << 63 } else { 0 };
+            let u = (bits >> 11) as f64 * util::quick_exp2_f64(-53);
+
+            let x = u * self.x[layer];
+
+            if x < self.x[layer + 1] {
+                return f64::from_bits(x.to_bits() | sign);
+            }
+
+            if let Some(x) = self.sample_slow(generator, layer, x, &mut tail) {
+                return f64::from_bits(x.to_bits() | sign);
+            }
+        }
+    }
+
+    /// The tail and the wedges, taken in about 1.5% of the cases; kept out of line so that the fast path can keep the
+    /// state of the generator in registers.
+    #[cold]
+    #[inline(never)]
+    fn sample_slow<G: UniformRandomBitGenerator>(&self, generator: &mut G, layer: usize, x: f64, tail: &mut impl FnMut(&mut G, f64) -> f64) -> Option<f64> {
+        if layer == 0 {
+            return Some(tail(generator, self.x[1]));
+        }
+
+        let y = self.f[layer] + f64::generate_canonical(generator).get() * (self.f[layer + 1] - self.f[layer]);
+
+        if y < (self.pdf)(x) {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_layers(ziggurat: &Ziggurat, v: f64) {
+        // the layers shrink towards the mode and the topmost one ends at its density
+        assert!(ziggurat.x.windows(2).all(|w| w[0] > w[1]));
+        assert_eq!(ziggurat.x[LAYERS], 0f64);
+        assert_eq!(ziggurat.f[LAYERS], 1f64);
+
+        // every layer has the same area, the topmost one only if r and v match
+        for i in 1..LAYERS {
+            let area = ziggurat.x[i] * (ziggurat.f[i + 1] - ziggurat.f[i]);
+            assert!((area - v).abs() < 1e-9, "layer {} has an area of {}", i, area);
+        }
+    }
+
+    #[test]
+    fn test_ziggurat_layers() {
+        check_layers(&Ziggurat::new(7.69711747013105, 3.949659822581572e-3, |x| (-x).exp(), |y| -y.ln()), 3.949659822581572e-3);
+        check_layers(&Ziggurat::new(3.654152885361009, 4.92867323399e-3, |x| (-0.5 * x * x).exp(), |y| (-2f64 * y.ln()).sqrt()), 4.92867323399e-3);
+    }
+}
@@ -0,0 +1,354 @@
+use std::io;
+use std::marker::PhantomData;
+
+use crate::rng::*;
+use super::*;
+
+use rng::util::fill_with_entropy;
+
+/// The result types of a `ChaChaEngine`, which are made of whole 32-bit keystream words.
+pub trait ChaChaResult: UnsignedIntegral {}
+
+impl ChaChaResult for u32 {}
+impl ChaChaResult for u64 {}
+
+/// A ChaCha keystream generator with `R` rounds, returning one or more 32-bit keystream words per result.
+///
+/// The block layout is Bernstein's original one: a 64-bit block counter in words 12 and 13 and a 64-bit stream id
+/// in words 14 and 15. The RFC 8439 layout (32-bit counter, 96-bit nonce) is the same state with the upper nonce
+/// bits taken from `stream` and word 13 from the high half of the block counter.
+///
+/// `u64` results combine two consecutive keystream words, the first one being the least significant.
+///
+/// Not `Copy`, as a copy that is used by value would silently repeat the keystream; `clone` where that is intended.
+#[derive(Clone)]
+pub struct ChaChaEngine<T: ChaChaResult, const R: usize> {
+    key: [u32; 8],
+    stream: u64,
+    block: u64,
+
+    /// Index of the next word of `buffer` to be returned. `buffer` is only valid when this is non-zero.
+    index: usize,
+    buffer: [u32; 16],
+
+    result: PhantomData<T>,
+}
+
+impl<T: ChaChaResult, const R: usize> ChaChaEngine<T, R> {
+    const WORDS_PER_RESULT: u128 = (T::DIGITS / 32) as u128;
+
+    pub fn new() -> Self { Self::new_with_key_and_stream([0; 8], 0) }
+
+    pub fn new_with_key_and_stream(key: [u32; 8], stream: u64) -> Self {
+        Self {
+            key,
+            stream,
+            block: 0,
+            index: 0,
+            buffer: [0; 16],
+            result: Default::default(),
+        }
+    }
+
+    /// Interprets the 256-bit key as in RFC 8439, i.e. as eight little-endian words.
+    pub fn new_with_key_bytes(key: [u8; 32], stream: u64) -> Self {
+        let mut words = [0u32; 8];
+        for (word, bytes) in words.iter_mut().zip(key.chunks_exact(4)) {
+            *word = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+
+        Self::new_with_key_and_stream(words, stream)
+    }
+
+    /// Draws a fresh key from the operating system or the CPU.
+    pub fn from_random_device(device: &mut RandomDevice) -> io::Result<Self> {
+        let mut key = [0u8; 32];
+        device.try_fill_bytes(&mut key)?;
+
+        Ok(Self::new_with_key_bytes(key, 0))
+    }
+
+    pub fn key(&self) -> [u32; 8] { self.key }
+
+    pub fn stream(&self) -> u64 { self.stream }
+
+    /// Selects another stream and moves to its beginning.
+    pub fn set_stream(&mut self, stream: u64) {
+        self.stream = stream;
+        self.seek(0);
+    }
+
+    /// The position of the next keystream word, counting 32-bit words from the start of the stream.
+    pub fn word_position(&self) -> u128 { ((self.block as u128) << 4) + self.index as u128 }
+
+    /// Moves to the given 32-bit keystream word; positions wrap around at `2^68`.
+    pub fn seek(&mut self, word_position: u128) {
+        self.block = (word_position >> 4) as u64;
+        self.index = (word_position & 15) as usize;
+
+        if self.index != 0 {
+            self.buffer = detail::block::<R>(&self.key, self.block, self.stream);
+        }
+    }
+
+    fn next_word(&mut self) -> u32 {
+        if self.index == 0 {
+            self.buffer = detail::block::<R>(&self.key, self.block, self.stream);
+        }
+
+        let res = self.buffer[self.index];
+        self.index += 1;
+
+        if self.index == 16 {
+            self.index = 0;
+            self.block = self.block.wrapping_add(1);
+        }
+
+        res
+    }
+}
+
+impl<T: ChaChaResult, const R: usize> PartialEq<Self> for ChaChaEngine<T, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.stream == other.stream && self.block == other.block && self.index == other.index
+    }
+}
+
+impl<T: ChaChaResult, const R: usize> Eq for ChaChaEngine<T, R> {}
+
+impl<T: ChaChaResult, const R: usize> UniformRandomBitGenerator for ChaChaEngine<T, R> {
+    type ResultType = T;
+
+    fn min() -> Self::ResultType { NumericLimits::min() }
+
+    fn max() -> Self::ResultType { NumericLimits::max() }
+
+    fn generate(&mut self) -> Self::ResultType {
+        let mut res = T::zero();
+
+        for i in 0..T::DIGITS / 32 {
+            res = res | (T::as_from(self.next_word()) << (i * 32));
+        }
+
+        res
+    }
+}
+
+impl<T: ChaChaResult, const R: usize> RandomNumberEngine for ChaChaEngine<T, R> {
+    fn discard(&mut self, z: u128) {
+        let words = z.wrapping_mul(Self::WORDS_PER_RESULT);
+        self.seek(self.word_position().wrapping_add(words) & ((1u128 << 68) - 1));
+    }
+
+    fn reset(&mut self) {
+        self.key = [0; 8];
+        self.stream = 0;
+        self.seek(0);
+    }
+
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) {
+        for word in &mut self.key {
+            *word = fill_with_entropy(generator, 32);
+        }
+
+        self.seek(0);
+    }
+
+    /// Sets the low bits of the key to `v` and the rest to zero. This is for reproducibility, not security.
+    fn seed_from_result(&mut self, v: Self::ResultType) {
+        let mut key = [0u32; 8];
+        for (i, word) in key.iter_mut().take((T::DIGITS / 32) as usize).enumerate() {
+            *word = <T as ToScalar<u32>>::as_to(v >> (i as u32 * 32));
+        }
+
+        self.key = key;
+        self.seek(0);
+    }
+}
+
+impl<T: ChaChaResult, const R: usize> SerializableEngine for ChaChaEngine<T, R> {
+    fn state_tag() -> String { format!("chacha{}-u{}", R, T::DIGITS) }
+
+    fn state_words(&self) -> Vec<u128> {
+        self.key.iter().map(|&v| v as u128).chain([self.stream as u128, self.word_position()]).collect()
+    }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        let mut words = StateWords::new(words, 10)?;
+
+        let mut ret = Self::new_with_key_and_stream(words.next_array()?, words.next()?);
+        let word_position: u128 = words.next()?;
+
+        if word_position >= 1u128 << 68 {
+            return Err(StateError::BadValue(format!("word position {} is out of range", word_position)));
+        }
+
+        ret.seek(word_position);
+        Ok(ret)
+    }
+}
+
+pub type ChaCha8 = ChaChaEngine<u32, 8>;
+pub type ChaCha12 = ChaChaEngine<u32, 12>;
+pub type ChaCha20 = ChaChaEngine<u32, 20>;
+
+pub type ChaCha8U64 = ChaChaEngine<u64, 8>;
+pub type ChaCha12U64 = ChaChaEngine<u64, 12>;
+pub type ChaCha20U64 = ChaChaEngine<u64, 20>;
+
+mod detail {
+    const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+    fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] = (state[d] ^ state[a]).rotate_left(16);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_left(12);
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] = (state[d] ^ state[a]).rotate_left(8);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] = (state[b] ^ state[c]).rotate_left(7);
+    }
+
+    pub fn block<const R: usize>(key: &[u32; 8], block: u64, stream: u64) -> [u32; 16] {
+        let mut input = [0u32; 16];
+        input[..4].copy_from_slice(&CONSTANTS);
+        input[4..12].copy_from_slice(key);
+        input[12] = block as u32;
+        input[13] = (block >> 32) as u32;
+        input[14] = stream as u32;
+        input[15] = (stream >> 32) as u32;
+
+        let mut state = input;
+
+        for _ in 0..R / 2 {
+            quarter_round(&mut state, 0, 4, 8, 12);
+            quarter_round(&mut state, 1, 5, 9, 13);
+            quarter_round(&mut state, 2, 6, 10, 14);
+            quarter_round(&mut state, 3, 7, 11, 15);
+
+            quarter_round(&mut state, 0, 5, 10, 15);
+            quarter_round(&mut state, 1, 6, 11, 12);
+            quarter_round(&mut state, 2, 7, 8, 13);
+            quarter_round(&mut state, 3, 4, 9, 14);
+        }
+
+        for (word, input) in state.iter_mut().zip(input) {
+            *word = word.wrapping_add(input);
+        }
+
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::*;
+    use super::*;
+
+    fn keystream_bytes<const R: usize>(generator: &mut ChaChaEngine<u32, R>, count: usize) -> Vec<u8> {
+        (0..count / 4).flat_map(|_| generator.generate().to_le_bytes()).collect()
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    // RFC 8439, section 2.3.2
+    #[test]
+    fn test_chacha20_block_function() {
+        let mut key = [0u8; 32];
+        for (i, v) in key.iter_mut().enumerate() {
+            *v = i as u8;
+        }
+
+        // nonce 00:00:00:09:00:00:00:4a:00:00:00:00 and block count 1
+        let mut generator = ChaCha20::new_with_key_bytes(key, 0x4a000000);
+        generator.seek(((0x09000000_u128 << 32) | 1) << 4);
+
+        let expected = [
+            0xe4e7f110_u32, 0x15593bd1, 0x1fdd0f50, 0xc47120a3, 0xc7f4d1c7, 0x0368c033, 0x9aaa2204, 0x4e6cd4c3,
+            0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9, 0xd19c12b5, 0xb94e16de, 0xe883d0cb, 0x4e3c50a2,
+        ];
+
+        for expected in expected {
+            assert_eq!(generator.generate(), expected);
+        }
+    }
+
+    // RFC 8439, appendix A.1, test vectors #1 and #2
+    #[test]
+    fn test_chacha20_zero_key() {
+        let mut generator = ChaCha20::new();
+
+        assert_eq!(keystream_bytes(&mut generator, 64), from_hex("76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586"));
+        assert_eq!(keystream_bytes(&mut generator, 64), from_hex("9f07e7be5551387a98ba977c732d080dcb0f29a048e3656912c6533e32ee7aed29b721769ce64e43d57133b074d839d531ed1f28510afb45ace10a1f4b794d6f"));
+    }
+
+    // draft-strombergson-chacha-test-vectors, TC1
+    #[test]
+    fn test_chacha8_chacha12_zero_key() {
+        assert_eq!(keystream_bytes(&mut ChaCha8::new(), 64), from_hex("3e00ef2f895f40d67f5bb8e81f09a5a12c840ec3ce9a7f3b181be188ef711a1e984ce172b9216f419f445367456d5619314a42a3da86b001387bfdb80e0cfe42"));
+        assert_eq!(keystream_bytes(&mut ChaCha12::new(), 64), from_hex("9bf49a6a0755f953811fce125f2683d50429c3bb49e074147e0089a52eae155f0564f879d27ae3c02ce82834acfa8c793a629f2ca0de6919610be82f411326be"));
+    }
+
+    #[test]
+    fn test_chacha_u64() {
+        let mut narrow = ChaCha20::new_with_key_and_stream([1, 2, 3, 4, 5, 6, 7, 8], 42);
+        let mut wide = ChaCha20U64::new_with_key_and_stream([1, 2, 3, 4, 5, 6, 7, 8], 42);
+
+        for _ in 0..40 {
+            let lo = narrow.generate() as u64;
+            let hi = narrow.generate() as u64;
+            assert_eq!(wide.generate(), lo | (hi << 32));
+        }
+    }
+
+    #[test]
+    fn test_chacha_discard() {
+        let mut generator = ChaCha8U64::new_with_key_and_stream([0xDEADBEEF; 8], 0xCAFEBABE);
+
+        for z in [0u128, 1, 2, 7, 8, 9, 15, 16, 17, 1000] {
+            let mut stepped = generator.clone();
+            for _ in 0..z {
+                stepped.generate();
+            }
+
+            let mut discarded = generator.clone();
+            discarded.discard(z);
+
+            assert!(stepped == discarded, "discard({}) disagrees with stepping", z);
+            assert_eq!(stepped.generate(), discarded.generate());
+
+            generator.generate();
+        }
+
+        // the position wraps around at 2^68 words
+        let mut generator = ChaCha20::new();
+        let first = generator.generate();
+        generator.discard((1u128 << 68) - 1);
+        assert_eq!(generator.generate(), first);
+    }
+
+    #[test]
+    fn test_chacha_streams() {
+        let mut a = ChaCha20::new_with_key_and_stream([7; 8], 0);
+        let mut b = ChaCha20::new_with_key_and_stream([7; 8], 1);
+        assert_ne!(a.generate(), b.generate());
+
+        b.set_stream(0);
+        a.seek(0);
+        assert!(a == b);
+        assert_eq!(a.generate(), b.generate());
+    }
+
+    #[test]
+    fn test_chacha_from_random_device() {
+        let mut device = RandomDevice::new();
+        let mut a = ChaCha20::from_random_device(&mut device).unwrap();
+        let mut b = ChaCha20::from_random_device(&mut device).unwrap();
+
+        assert_ne!(a.key(), b.key());
+        assert_ne!(a.generate(), b.generate());
+    }
+}
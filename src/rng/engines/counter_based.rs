@@ -0,0 +1,203 @@
+use std::marker::PhantomData;
+
+use crate::rng::*;
+use super::*;
+
+use rng::util::fill_with_entropy;
+
+/// A keyed bijection over `N`-word counters, e.g. the Philox and Threefry families of Random123.
+///
+/// The bijection is stateless; the output for a given `(counter, key)` pair never changes.
+pub trait Bijection<T: UnsignedIntegral, const N: usize, const K: usize>: Copy + Clone + Eq + PartialEq {
+    fn apply(counter: [T; N], key: [T; K]) -> [T; N];
+
+    /// The name used in the `SerializableEngine` tag, e.g. `philox4x32-10`.
+    fn name() -> String;
+}
+
+/// Turns a `Bijection` into a `UniformRandomBitGenerator` by encrypting successive counter values.
+///
+/// The first word of the counter is the least significant one, matching Random123's `incr`.
+#[derive(Copy, Clone)]
+pub struct CounterBasedEngine<T: UnsignedIntegral, const N: usize, const K: usize, B: Bijection<T, N, K>> {
+    key: [T; K],
+    counter: [T; N],
+
+    /// Index of the next word of `buffer` to be returned. `buffer` is only valid when this is non-zero.
+    index: usize,
+    buffer: [T; N],
+
+    bijection: PhantomData<B>,
+}
+
+impl<T: UnsignedIntegral, const N: usize, const K: usize, B: Bijection<T, N, K>> CounterBasedEngine<T, N, K, B> {
+    pub fn new() -> Self { Self::new_with_key([T::zero(); K]) }
+
+    pub fn new_with_key(key: [T; K]) -> Self { Self::new_with_key_and_counter(key, [T::zero(); N]) }
+
+    pub fn new_with_key_and_counter(key: [T; K], counter: [T; N]) -> Self {
+        Self {
+            key,
+            counter,
+            index: 0,
+            buffer: [T::zero(); N],
+            bijection: Default::default(),
+        }
+    }
+
+    pub fn key(&self) -> [T; K] { self.key }
+
+    /// The counter of the block the next result will be taken from.
+    pub fn counter(&self) -> [T; N] { self.counter }
+
+    pub fn set_key(&mut self, key: [T; K]) {
+        self.key = key;
+        self.refresh_buffer();
+    }
+
+    /// Moves to the start of the block with the given counter.
+    pub fn seek(&mut self, counter: [T; N]) {
+        self.counter = counter;
+        self.index = 0;
+    }
+
+    fn refresh_buffer(&mut self) {
+        if self.index != 0 {
+            self.buffer = B::apply(self.counter, self.key);
+        }
+    }
+}
+
+impl<T: UnsignedIntegral, const N: usize, const K: usize, B: Bijection<T, N, K>> PartialEq<Self> for CounterBasedEngine<T, N, K, B> {
+    fn eq(&self, other: &Self) -> bool { self.key == other.key && self.counter == other.counter && self.index == other.index }
+}
+
+impl<T: UnsignedIntegral, const N: usize, const K: usize, B: Bijection<T, N, K>> Eq for CounterBasedEngine<T, N, K, B> {}
+
+impl<T: UnsignedIntegral, const N: usize, const K: usize, B: Bijection<T, N, K>> UniformRandomBitGenerator for CounterBasedEngine<T, N, K, B> {
+    type ResultType = T;
+
+    fn min() -> Self::ResultType { NumericLimits::min() }
+
+    fn max() -> Self::ResultType { NumericLimits::max() }
+
+    fn generate(&mut self) -> Self::ResultType {
+        if self.index == 0 {
+            self.buffer = B::apply(self.counter, self.key);
+        }
+
+        let res = self.buffer[self.index];
+        self.index += 1;
+
+        if self.index == N {
+            self.index = 0;
+            self.counter = detail::add_to_counter(self.counter, 1);
+        }
+
+        return res;
+    }
+}
+
+impl<T: UnsignedIntegral, const N: usize, const K: usize, B: Bijection<T, N, K>> RandomNumberEngine for CounterBasedEngine<T, N, K, B> {
+    fn discard(&mut self, z: u128) {
+        let position = self.index as u128 + z % N as u128;
+
+        self.counter = detail::add_to_counter(self.counter, z / N as u128 + position / N as u128);
+        self.index = (position % N as u128) as usize;
+        self.refresh_buffer();
+    }
+
+    fn reset(&mut self) {
+        self.key = [T::zero(); K];
+        self.seek([T::zero(); N]);
+    }
+
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) {
+        for word in &mut self.key {
+            *word = fill_with_entropy(generator, T::DIGITS);
+        }
+
+        self.seek([T::zero(); N]);
+    }
+
+    fn seed_from_result(&mut self, v: Self::ResultType) {
+        let mut key = [T::zero(); K];
+        key[0] = v;
+
+        self.key = key;
+        self.seek([T::zero(); N]);
+    }
+}
+
+impl<T: UnsignedIntegral, const N: usize, const K: usize, B: Bijection<T, N, K>> SerializableEngine for CounterBasedEngine<T, N, K, B> {
+    fn state_tag() -> String { B::name() }
+
+    fn state_words(&self) -> Vec<u128> {
+        self.key.iter().chain(self.counter.iter()).map(|&v| v.as_to()).chain(std::iter::once(self.index as u128)).collect()
+    }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        let mut words = StateWords::new(words, K + N + 1)?;
+
+        let mut ret = Self::new_with_key_and_counter(words.next_array()?, words.next_array()?);
+        ret.index = words.next()?;
+
+        if ret.index >= N {
+            return Err(StateError::BadValue(format!("word index {} is out of range", ret.index)));
+        }
+
+        ret.refresh_buffer();
+        Ok(ret)
+    }
+}
+
+mod detail {
+    use crate::*;
+
+    pub fn add_to_counter<T: UnsignedIntegral, const N: usize>(mut counter: [T; N], mut z: u128) -> [T; N] {
+        let mut carry = false;
+
+        for word in &mut counter {
+            if z == 0 && !carry {
+                break;
+            }
+
+            let addend = if T::DIGITS >= u128::DIGITS { T::as_from(z) } else { T::as_from(z & ((1u128 << T::DIGITS) - 1)) };
+            z = z.checked_shr(T::DIGITS).unwrap_or(0);
+
+            let (sum, overflow_0) = match word.checked_add(addend) {
+                Some(sum) => (sum, false),
+                None => (word.wrapping_add(addend), true),
+            };
+
+            let (sum, overflow_1) = if carry {
+                match sum.checked_add(T::one()) {
+                    Some(sum) => (sum, false),
+                    None => (T::zero(), true),
+                }
+            } else {
+                (sum, false)
+            };
+
+            *word = sum;
+            carry = overflow_0 || overflow_1;
+        }
+
+        counter
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_add_to_counter() {
+            assert_eq!(add_to_counter([0u32, 0, 0, 0], 1), [1, 0, 0, 0]);
+            assert_eq!(add_to_counter([u32::MAX, 0, 0, 0], 1), [0, 1, 0, 0]);
+            assert_eq!(add_to_counter([u32::MAX, u32::MAX, 0, 0], 1), [0, 0, 1, 0]);
+            assert_eq!(add_to_counter([u32::MAX; 4], 1), [0; 4]);
+            assert_eq!(add_to_counter([1u32, 2, 3, 4], 0x00000008_00000007_00000006_00000005_u128), [6, 8, 10, 12]);
+            assert_eq!(add_to_counter([u64::MAX, 0], 0x1_0000000000000001_u128), [0, 2]);
+        }
+    }
+}
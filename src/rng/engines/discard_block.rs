@@ -0,0 +1,125 @@
+use crate::rng::*;
+use super::*;
+
+/// Uses only the first `R` values of every block of `P` values of `E` and throws the rest away, like
+/// `std::discard_block_engine`; this is how `ranlux24` and `ranlux48` get rid of the correlations of their base.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DiscardBlockEngine<E: RandomNumberEngine, const P: usize, const R: usize> {
+    engine: E,
+    /// The number of values of the current block that were used.
+    n: usize,
+}
+
+impl<E: RandomNumberEngine, const P: usize, const R: usize> DiscardBlockEngine<E, P, R> {
+    pub fn new(engine: E) -> Self {
+        assert!(0 < R && R <= P, "a discard block engine uses between 1 and all of the values of a block");
+
+        Self { engine, n: 0 }
+    }
+
+    pub fn base(&self) -> &E { &self.engine }
+
+    pub fn into_inner(self) -> E { self.engine }
+}
+
+impl<E: RandomNumberEngine, const P: usize, const R: usize> UniformRandomBitGenerator for DiscardBlockEngine<E, P, R> {
+    type ResultType = E::ResultType;
+
+    fn min() -> Self::ResultType { E::min() }
+
+    fn max() -> Self::ResultType { E::max() }
+
+    fn generate(&mut self) -> Self::ResultType {
+        if self.n >= R {
+            self.engine.discard((P - R) as u128);
+            self.n = 0;
+        }
+
+        self.n += 1;
+        self.engine.generate()
+    }
+}
+
+impl<E: RandomNumberEngine, const P: usize, const R: usize> RandomNumberEngine for DiscardBlockEngine<E, P, R> {
+    fn discard(&mut self, z: u128) {
+        let first = std::cmp::min(z, (R - self.n) as u128);
+        self.engine.discard(first);
+        self.n += first as usize;
+
+        let z = z - first;
+
+        if z == 0 {
+            return;
+        }
+
+        // every block that is started throws away its tail first
+        let blocks = (z - 1) / R as u128 + 1;
+        self.engine.discard(blocks * (P - R) as u128 + z);
+        self.n = (z - (blocks - 1) * R as u128) as usize;
+    }
+
+    fn reset(&mut self) {
+        self.engine.reset();
+        self.n = 0;
+    }
+
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) {
+        self.engine.seed_from(generator);
+        self.n = 0;
+    }
+
+    fn seed_from_result(&mut self, v: Self::ResultType) {
+        self.engine.seed_from_result(v);
+        self.n = 0;
+    }
+}
+
+impl<E: SerializableEngine, const P: usize, const R: usize> SerializableEngine for DiscardBlockEngine<E, P, R> {
+    fn state_tag() -> String { format!("discard-block-{}-{}-{}", P, R, E::state_tag()) }
+
+    /// The number of used values of the block, then the state of the base engine.
+    fn state_words(&self) -> Vec<u128> { [vec![self.n as u128], self.engine.state_words()].concat() }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        let (&n, rest) = words.split_first().ok_or(StateError::InsufficientData)?;
+
+        if n > R as u128 {
+            return Err(StateError::BadValue(format!("{} values of a block of {} used", n, R)));
+        }
+
+        Ok(Self { engine: E::from_state_words(rest)?, n: n as usize })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discard_block() {
+        let mut base = Xoshiro256PP::new();
+        base.seed_from_result(17);
+
+        let mut generator = DiscardBlockEngine::<_, 7, 3>::new(base);
+
+        for _ in 0..10 {
+            for _ in 0..3 {
+                assert_eq!(generator.generate(), base.generate());
+            }
+
+            base.discard(4);
+        }
+
+        let mut reference = generator;
+        for z in [0u128, 1, 2, 3, 4, 5, 6, 100, 1001] {
+            generator.discard(z);
+
+            for _ in 0..z {
+                reference.generate();
+            }
+
+            assert!(generator == reference, "after discarding {}", z);
+            assert_eq!(generator.generate(), reference.generate());
+        }
+    }
+}
@@ -22,6 +22,35 @@ impl<T: UnsignedIntegral, const N: usize, P: Permuter<T, N>, S: Scrambler<T, N>,
             discarder: Default::default(),
         }
     }
+
+    /// Advances the engine by `2^log2` steps, e.g. `discard_pow2(128)` is the `jump()` of xoshiro256.
+    pub fn discard_pow2(&mut self, log2: usize) {
+        self.state = D::discard_pow2(self.state, log2);
+    }
+
+    pub(crate) fn state(&self) -> [T; N] { self.state }
+
+    /// An all zero state never leaves zero, so it is drawn again; with small words that is not that unlikely.
+    fn fill_state<G: UniformRandomBitGenerator>(&mut self, generator: &mut G)
+    where
+        T: FromScalar<G::ResultType>,
+    {
+        loop {
+            for v in &mut self.state {
+                *v = fill_with_entropy(generator, T::DIGITS);
+            }
+
+            if self.state.iter().any(|&v| v != T::zero()) {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn from_state(state: [T; N]) -> Self {
+        let mut ret = Self::new();
+        ret.state = state;
+        ret
+    }
 }
 
 impl<T: UnsignedIntegral, const N: usize, P: Permuter<T, N>, S: Scrambler<T, N>, D: Discarder<T, N>> UniformRandomBitGenerator for GenericLinearGenerator<T, N, P, S, D> {
@@ -43,7 +72,7 @@ impl<T: UnsignedIntegral, const N: usize, P: Permuter<T, N>, S: Scrambler<T, N>,
 }
 
 impl<T: UnsignedIntegral, const N: usize, P: Permuter<T, N>, S: Scrambler<T, N>, D: Discarder<T, N>> RandomNumberEngine for GenericLinearGenerator<T, N, P, S, D> {
-    fn discard(&mut self, z: usize) {
+    fn discard(&mut self, z: u128) {
         self.state = D::discard(self.state, z);
     }
 
@@ -51,28 +80,49 @@ impl<T: UnsignedIntegral, const N: usize, P: Permuter<T, N>, S: Scrambler<T, N>,
         self.state = [T::zero(); N];
     }
 
-    fn seed_from<G: UniformRandomBitGenerator<ResultType=u32>>(&mut self, _generator: &mut G) {
-        todo!()
+    fn seed_from<G: UniformRandomBitGenerator<ResultType=u32>>(&mut self, generator: &mut G) {
+        self.fill_state(generator);
     }
 
+    /// Fills the state from a SplitMix32 for words of up to 32 bits and from a SplitMix64 otherwise. A `u128` seed
+    /// seeds the SplitMix64 with its lower half, which then is reseeded with its first value plus the upper half.
     fn seed_from_result(&mut self, v: Self::ResultType) {
-        if T::DIGITS == 64 {
-            let mut generator = SplitMix64::new();
-            generator.seed_from_result(<T as ToScalar<u64>>::to_scalar(v).unwrap());
+        let v: u128 = v.as_to();
 
-            for v in &mut self.state {
-                *v = fill_with_entropy(&mut generator, T::DIGITS);
-            }
-        } else if T::DIGITS == 32 {
+        if T::DIGITS <= 32 {
             let mut generator = SplitMix32::new();
-            generator.seed_from_result(<T as ToScalar<u32>>::to_scalar(v).unwrap());
+            generator.seed_from_result(v as u32);
+            self.fill_state(&mut generator);
+        } else {
+            let mut generator = SplitMix64::new();
+            generator.seed_from_result(v as u64);
 
-            for v in &mut self.state {
-                *v = fill_with_entropy(&mut generator, T::DIGITS);
+            if T::DIGITS > 64 {
+                let mixed = generator.generate().wrapping_add((v >> 64) as u64);
+                generator.seed_from_result(mixed);
             }
-        } else {
-            todo!()
+
+            self.fill_state(&mut generator);
+        }
+    }
+}
+
+impl<T: UnsignedIntegral, const N: usize, P: Permuter<T, N> + StateTag, S: Scrambler<T, N> + StateTag, D: Discarder<T, N>> SerializableEngine for GenericLinearGenerator<T, N, P, S, D> {
+    /// The permuter and the scrambler, e.g. `xoshiro256++`, so that engines with the same state size but another
+    /// sequence do not take each other's states.
+    fn state_tag() -> String { format!("{}{}", P::tag(), S::tag()) }
+
+    fn state_words(&self) -> Vec<u128> { self.state.iter().map(|&v| v.as_to()).collect() }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        let mut ret = Self::new();
+        ret.state = StateWords::new(words, N)?.next_array()?;
+
+        if ret.state.iter().all(|&v| v == T::zero()) {
+            return Err(StateError::BadValue("an all zero state never leaves zero".into()));
         }
+
+        Ok(ret)
     }
 }
 
@@ -120,4 +170,93 @@ mod tests {
 
         generator.discard(123);
     }
+
+    /// Xorshift on a single 16-bit word with the shifts (7, 9, 8), which has the full period of `2^16 - 1`.
+    #[derive(Eq, PartialEq, Copy, Clone)]
+    struct Xorshift16;
+
+    impl Scrambler<u16, 1> for Xorshift16 {
+        fn scramble(state: &[u16; 1]) -> u16 { state[0] }
+    }
+
+    impl Permuter<u16, 1> for Xorshift16 {
+        fn permute(mut state: [u16; 1]) -> [u16; 1] {
+            state[0] ^= state[0] << 7;
+            state[0] ^= state[0] >> 9;
+            state[0] ^= state[0] << 8;
+
+            return state;
+        }
+    }
+
+    impl PolyDiscarder<u16, 1> for Xorshift16 {
+        fn polynomials() -> &'static [(usize, [u16; 1])] { &[] }
+    }
+
+    /// Xorshift128+ with the state words widened to 128 bits.
+    #[derive(Eq, PartialEq, Copy, Clone)]
+    struct Xorshift256P;
+
+    impl Scrambler<u128, 2> for Xorshift256P {
+        fn scramble(state: &[u128; 2]) -> u128 { state[0].wrapping_add(state[1]) }
+    }
+
+    impl Permuter<u128, 2> for Xorshift256P {
+        fn permute(state: [u128; 2]) -> [u128; 2] {
+            let [mut s1, s0] = state;
+
+            s1 ^= s1 << 23;
+            [s0, s1 ^ s0 ^ (s1 >> 17) ^ (s0 >> 26)]
+        }
+    }
+
+    impl PolyDiscarder<u128, 2> for Xorshift256P {
+        fn polynomials() -> &'static [(usize, [u128; 2])] { &[] }
+    }
+
+    #[test]
+    fn test_narrow_and_wide_words() {
+        let mut generator = GenericLinearGenerator::<u16, 1, Xorshift16, Xorshift16, Xorshift16>::new();
+
+        for seed in 0..1000 {
+            generator.seed_from_result(seed);
+            assert_ne!(generator.state(), [0]);
+        }
+
+        let mut discarded = generator;
+        discarded.discard(65535);
+        assert!(discarded == generator);
+
+        let mut values = std::collections::HashSet::new();
+        for _ in 0..65535 {
+            assert!(values.insert(generator.generate()));
+        }
+
+        let mut generator = GenericLinearGenerator::<u128, 2, Xorshift256P, Xorshift256P, Xorshift256P>::new();
+        generator.seed_from_result(17);
+
+        // the words are filled with all 128 bits of the seeding generator
+        let mut splitmix = SplitMix64::new();
+        splitmix.seed_from_result(17);
+        let mixed = splitmix.generate();
+        splitmix.seed_from_result(mixed);
+        let word = ((splitmix.generate() as u128) << 64) | splitmix.generate() as u128;
+        assert_eq!(generator.state()[0], word);
+
+        // and the upper half of the seed is not folded into the lower one
+        let mut other = generator;
+        other.seed_from_result(17 | (1 << 64));
+        assert!(other != generator);
+
+        for (v, h) in [(17u128, 1u128), (0xDEADBEEF_u128 << 64 | 0xCAFEBABE, 0xFFFFFFFFFFFFFFFF), (u128::MAX, 12345)] {
+            generator.seed_from_result(v);
+            other.seed_from_result(v ^ (h << 64 | h));
+            assert!(other != generator, "{:X} and {:X} seed the same state", v, v ^ (h << 64 | h));
+        }
+
+        let mut pcg = PCG32::new_with_stream(42, 54);
+        generator.seed_from(&mut pcg);
+        let mut pcg = PCG32::new_with_stream(42, 54);
+        assert_eq!(generator.state()[0] >> 96, pcg.generate() as u128);
+    }
 }
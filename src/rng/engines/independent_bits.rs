@@ -0,0 +1,165 @@
+use std::marker::PhantomData;
+
+use crate::rng::*;
+use super::*;
+
+/// Values of exactly `W` bits in a `T` made from as many values of `E` as needed, like
+/// `std::independent_bits_engine`.
+///
+/// Unlike `util::fill_with_entropy`, which truncates the range of the generator to a power of two, values of `E`
+/// above the largest multiple of the power of two that is used are rejected as the standard specifies, so that the
+/// results are the same as those of the standard library for the same base engine.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IndependentBitsEngine<E: RandomNumberEngine, const W: u32, T: UnsignedIntegral> {
+    engine: E,
+    result: PhantomData<T>,
+}
+
+impl<E: RandomNumberEngine, const W: u32, T: UnsignedIntegral> IndependentBitsEngine<E, W, T> {
+    pub fn new(engine: E) -> Self {
+        assert!(0 < W && W <= T::DIGITS, "an independent bits engine produces between 1 and all of the bits of its result type");
+
+        Self { engine, result: PhantomData }
+    }
+
+    pub fn base(&self) -> &E { &self.engine }
+
+    pub fn into_inner(self) -> E { self.engine }
+}
+
+impl<E: RandomNumberEngine, const W: u32, T: UnsignedIntegral> UniformRandomBitGenerator for IndependentBitsEngine<E, W, T> {
+    type ResultType = T;
+
+    fn min() -> Self::ResultType { T::zero() }
+
+    fn max() -> Self::ResultType { <T as NumericLimits>::max() >> (T::DIGITS - W) }
+
+    fn generate(&mut self) -> Self::ResultType {
+        let parameters = detail::Parameters::of::<E>(W);
+        let min: u128 = E::min().as_to();
+
+        let mut ret = 0u128;
+
+        for k in 0..parameters.n {
+            let (bits, limit) = if k < parameters.n0 { (parameters.w0, parameters.y0) } else { (parameters.w0 + 1, parameters.y1) };
+
+            let u = loop {
+                let u = <E::ResultType as ToScalar<u128>>::as_to(self.engine.generate()).wrapping_sub(min);
+
+                if limit.is_none_or(|limit| u < limit) {
+                    break u;
+                }
+            };
+
+            ret = if bits >= 128 { u } else { (ret << bits) | (u & ((1u128 << bits) - 1)) };
+        }
+
+        T::as_from(ret)
+    }
+}
+
+impl<E: RandomNumberEngine, const W: u32, T: UnsignedIntegral> RandomNumberEngine for IndependentBitsEngine<E, W, T> {
+    fn discard(&mut self, z: u128) {
+        // the number of values of the base engine for each result depends on the rejections
+        for _ in 0..z {
+            self.generate();
+        }
+    }
+
+    fn reset(&mut self) { self.engine.reset(); }
+
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) { self.engine.seed_from(generator); }
+
+    /// Seeds the base engine with `v` converted to its result type, like the standard does.
+    fn seed_from_result(&mut self, v: Self::ResultType) {
+        let v: u128 = v.as_to();
+        self.engine.seed_from_result(E::ResultType::as_from(v));
+    }
+}
+
+impl<E: SerializableEngine, const W: u32, T: UnsignedIntegral> SerializableEngine for IndependentBitsEngine<E, W, T> {
+    fn state_tag() -> String { format!("independent-bits-{}-u{}-{}", W, T::DIGITS, E::state_tag()) }
+
+    fn state_words(&self) -> Vec<u128> { self.engine.state_words() }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> { Ok(Self::new(E::from_state_words(words)?)) }
+}
+
+mod detail {
+    use crate::rng::*;
+    use crate::*;
+
+    /// The constants of [rand.adapt.ibits]: `n` values of the base engine, of which the first `n0` give `w0` bits
+    /// each when below `y0` and the rest `w0 + 1` bits each when below `y1`; `None` is a limit of `2^128`.
+    pub struct Parameters {
+        pub n: u32,
+        pub n0: u32,
+        pub w0: u32,
+        pub y0: Option<u128>,
+        pub y1: Option<u128>,
+    }
+
+    impl Parameters {
+        pub fn of<E: UniformRandomBitGenerator>(w: u32) -> Self {
+            let span: u128 = (E::max() - E::min()).as_to();
+
+            // a base engine that covers all of `u128` gives any `w` bits with a single value
+            let Some(range) = span.checked_add(1) else {
+                return Self { n: 1, n0: 1, w0: w, y0: None, y1: None };
+            };
+
+            let m = 127 - range.leading_zeros();
+            assert!(m > 0, "the base engine has to produce at least one bit");
+
+            let largest_multiple = |bits: u32| if bits >= 128 { 0 } else { (range >> bits) << bits };
+
+            let mut n = w.div_ceil(m);
+            let mut w0 = w / n;
+            let mut y0 = largest_multiple(w0);
+
+            if range - y0 > y0 / n as u128 {
+                n += 1;
+                w0 = w / n;
+                y0 = largest_multiple(w0);
+            }
+
+            Self { n, n0: n - w % n, w0, y0: Some(y0), y1: Some(largest_multiple(w0 + 1)) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_independent_bits() {
+        // values of `std::independent_bits_engine` over a default constructed `std::minstd_rand0`, whose range of
+        // `2^31 - 2` values gives 30 bits per value with rejections
+        let mut generator = IndependentBitsEngine::<_, 48, u64>::new(MinStdRand0::new());
+        assert_eq!(IndependentBitsEngine::<MinStdRand0, 48, u64>::max(), (1 << 48) - 1);
+        assert_eq!([0; 5].map(|_| generator.generate()), [281971931888, 201952998001705, 54664213486279, 6111065606653, 267384307057740]);
+
+        generator.discard(9994);
+        assert_eq!(generator.generate(), 126581834170079);
+
+        let mut generator = IndependentBitsEngine::<_, 64, u64>::new(MinStdRand0::new());
+        assert_eq!([0; 5].map(|_| generator.generate()), [147833249932487896, 12133062905910713031, 3203951880177921858, 2315524922698120276, 16759120405005325490]);
+
+        let mut generator = IndependentBitsEngine::<_, 31, u32>::new(MinStdRand0::new());
+        assert_eq!([0; 5].map(|_| generator.generate()), [1101413104, 752356393, 931257031, 248973821, 792855628]);
+
+        let mut generator = IndependentBitsEngine::<_, 7, u8>::new(MinStdRand0::new());
+        assert_eq!([0; 8].map(|_| generator.generate()), [38, 112, 88, 41, 1, 71, 87, 125]);
+
+        // a base engine with the full range is only truncated
+        let mut base = Xoshiro256PP::new();
+        base.seed_from_result(17);
+
+        let mut generator = IndependentBitsEngine::<_, 128, u128>::new(base);
+        assert_eq!(generator.generate(), ((base.generate() as u128) << 64) | base.generate() as u128);
+
+        let mut generator = IndependentBitsEngine::<_, 20, u32>::new(base);
+        assert_eq!(generator.generate() as u64, base.generate() & 0xFFFFF);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::rng::*;
+use super::*;
+
+/// `x = (A * x + C) mod M` like `std::linear_congruential_engine`, where an `M` of zero is `2^T::DIGITS`.
+///
+/// Kept for reproducing results of programs that use `minstd_rand0` or `minstd_rand`; the low bits of a power of
+/// two modulus and the small period of the others make these poor generators for anything new.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct LinearCongruentialEngine<T: UnsignedIntegral, const A: u64, const C: u64, const M: u64> {
+    state: T,
+}
+
+impl<T: UnsignedIntegral, const A: u64, const C: u64, const M: u64> LinearCongruentialEngine<T, A, C, M> {
+    pub const DEFAULT_SEED: u64 = 1;
+
+    pub fn new() -> Self {
+        assert!(T::DIGITS <= 64, "the modulus of a linear congruential engine fits into 64 bits");
+        assert!(M == 0 || ((A as u128) < M as u128 && (C as u128) < M as u128), "the multiplier and the increment are below the modulus");
+
+        let mut ret = Self { state: T::zero() };
+        ret.seed_from_result(T::as_from(Self::DEFAULT_SEED as u128));
+        ret
+    }
+
+    fn modulus() -> u128 { if M == 0 { 1u128 << T::DIGITS } else { M as u128 } }
+
+    /// The seed rule of the standard: everything modulo `M`, except that a multiplicative engine never starts at zero.
+    fn seed_state(&mut self, s: u128) {
+        let s = s % Self::modulus();
+        self.state = T::as_from(if (C as u128).is_multiple_of(Self::modulus()) && s == 0 { 1 } else { s });
+    }
+}
+
+impl<T: UnsignedIntegral, const A: u64, const C: u64, const M: u64> UniformRandomBitGenerator for LinearCongruentialEngine<T, A, C, M> {
+    type ResultType = T;
+
+    fn min() -> Self::ResultType { if C == 0 { T::one() } else { T::zero() } }
+
+    fn max() -> Self::ResultType { T::as_from(Self::modulus() - 1) }
+
+    fn generate(&mut self) -> Self::ResultType {
+        let x: u128 = self.state.as_to();
+        self.state = T::as_from((A as u128 * x % Self::modulus() + C as u128) % Self::modulus());
+        self.state
+    }
+}
+
+impl<T: UnsignedIntegral, const A: u64, const C: u64, const M: u64> RandomNumberEngine for LinearCongruentialEngine<T, A, C, M> {
+    /// O(log z), with the same doubling as `PCGEngine::advance` but modulo `M`.
+    fn discard(&mut self, z: u128) {
+        let m = Self::modulus();
+
+        let (mut cur_mult, mut cur_plus) = (A as u128, C as u128);
+        let (mut acc_mult, mut acc_plus) = (1u128, 0u128);
+        let mut z = z;
+
+        while z != 0 {
+            if z & 1 != 0 {
+                acc_mult = acc_mult * cur_mult % m;
+                acc_plus = (acc_plus * cur_mult % m + cur_plus) % m;
+            }
+
+            cur_plus = (cur_mult + 1) % m * cur_plus % m;
+            cur_mult = cur_mult * cur_mult % m;
+            z >>= 1;
+        }
+
+        let x: u128 = self.state.as_to();
+        self.state = T::as_from((acc_mult * x % m + acc_plus) % m);
+    }
+
+    fn reset(&mut self) { *self = Self::new(); }
+
+    /// Takes the words that `seed(seed_seq&)` would take from the sequence: three are skipped, then as many as `M`
+    /// needs make up the seed, least significant first.
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) {
+        let words = (128 - (Self::modulus() - 1).leading_zeros()).div_ceil(32);
+
+        for _ in 0..3 {
+            generator.generate();
+        }
+
+        let s = (0..words).fold(0u128, |s, j| s | (generator.generate() as u128) << (32 * j));
+        self.seed_state(s);
+    }
+
+    fn seed_from_result(&mut self, v: Self::ResultType) { self.seed_state(v.as_to()); }
+}
+
+impl<T: UnsignedIntegral, const A: u64, const C: u64, const M: u64> SerializableEngine for LinearCongruentialEngine<T, A, C, M> {
+    fn state_tag() -> String { format!("lcg-u{}-{}-{}-{}", T::DIGITS, A, C, M) }
+
+    fn state_words(&self) -> Vec<u128> { vec![self.state.as_to()] }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        let mut words = StateWords::new(words, 1)?;
+        let state: T = words.next()?;
+
+        if state < Self::min() || state > Self::max() {
+            return Err(StateError::BadValue("the state of a linear congruential engine must be within its range".into()));
+        }
+
+        Ok(Self { state })
+    }
+}
+
+/// `std::minstd_rand0`, Lewis, Goodman and Miller (1969).
+pub type MinStdRand0 = LinearCongruentialEngine<u32, 16807, 0, 2147483647>;
+
+/// `std::minstd_rand`, Park and Miller (1993).
+pub type MinStdRand = LinearCongruentialEngine<u32, 48271, 0, 2147483647>;
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_tables;
+    use crate::rng::engines::util::WordSequence;
+    use super::*;
+
+    #[test]
+    fn test_minstd_rand() {
+        // [rand.predef]: the 10000th value of a default constructed engine
+        let mut generator = MinStdRand0::new();
+        assert_eq!([0; 3].map(|_| generator.generate()), [16807, 282475249, 1622650073]);
+        generator.discard(9996);
+        assert_eq!(generator.generate(), 1043618065);
+
+        let mut generator = MinStdRand::new();
+        generator.discard(9999);
+        assert_eq!(generator.generate(), 399268537);
+
+        // a seed of zero is replaced by one
+        generator.seed_from_result(0);
+        assert_eq!(generator.generate(), 48271);
+
+        generator.seed_from(&mut WordSequence::new());
+        assert_eq!([0; 3].map(|_| generator.generate()), [1806864978, 1278513780, 753626894]);
+
+        generator.reset();
+        assert!(generator == MinStdRand::new());
+    }
+
+    #[test]
+    fn test_minstd_rand_consistency() {
+        let mut generator = MinStdRand0::new();
+        test_tables::TABLE_MINSTD_RAND0_SEED_0.test(&mut generator);
+        test_tables::TABLE_MINSTD_RAND0_SEED_1.test(&mut generator);
+        test_tables::TABLE_MINSTD_RAND0_SEED_2.test(&mut generator);
+
+        let mut generator = MinStdRand::new();
+        test_tables::TABLE_MINSTD_RAND_SEED_0.test(&mut generator);
+        test_tables::TABLE_MINSTD_RAND_SEED_1.test(&mut generator);
+        test_tables::TABLE_MINSTD_RAND_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_lcg_discard() {
+        let mut generator = MinStdRand::new();
+        generator.seed_from_result(0xDEADBEEF);
+
+        // a power of two modulus and an increment
+        let mut knuth = LinearCongruentialEngine::<u64, 6364136223846793005, 1442695040888963407, 0>::new();
+        knuth.seed_from_result(0xDEADBEEFCAFEBABE);
+
+        for z in [0u128, 1, 2, 3, 17, 1000, 4097] {
+            let mut stepped = generator;
+            let mut stepped_knuth = knuth;
+            for _ in 0..z {
+                stepped.generate();
+                stepped_knuth.generate();
+            }
+
+            generator.discard(z);
+            knuth.discard(z);
+
+            assert!(stepped == generator && stepped_knuth == knuth, "discard({}) disagrees with stepping", z);
+        }
+
+        // the period of minstd is 2^31 - 2
+        let mut discarded = generator;
+        discarded.discard(2147483646);
+        assert!(discarded == generator);
+    }
+}
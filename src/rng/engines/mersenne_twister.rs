@@ -0,0 +1,243 @@
+use crate::rng::*;
+use super::*;
+
+/// `std::mersenne_twister_engine` with the parameters in the same order: `W` bits per word, a state of `N` words,
+/// the middle word `M` and the separation point `R`, the twist matrix `A`, the tempering `U`, `D`, `S`, `B`, `TT`,
+/// `C`, `L` and the initialisation multiplier `F` (Matsumoto and Nishimura, 1998).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct MersenneTwisterEngine<
+    T: UnsignedIntegral,
+    const W: u32, const N: usize, const M: usize, const R: u32,
+    const A: u64, const U: u32, const D: u64, const S: u32, const B: u64, const TT: u32, const C: u64, const L: u32,
+    const F: u64,
+> {
+    state: [T; N],
+    /// The word that is tempered next, `N` once all of them were used.
+    index: usize,
+}
+
+impl<
+    T: UnsignedIntegral,
+    const W: u32, const N: usize, const M: usize, const R: u32,
+    const A: u64, const U: u32, const D: u64, const S: u32, const B: u64, const TT: u32, const C: u64, const L: u32,
+    const F: u64,
+> MersenneTwisterEngine<T, W, N, M, R, A, U, D, S, B, TT, C, L, F> {
+    pub const DEFAULT_SEED: u64 = 5489;
+
+    pub fn new() -> Self {
+        assert!(0 < M && M <= N, "the middle word lies within the state");
+        assert!(R <= W && 2 < W && W <= T::DIGITS && W <= 64, "a word has between 3 and 64 bits of the result type");
+
+        let mut ret = Self { state: [T::zero(); N], index: N };
+        ret.seed_from_result(T::as_from(Self::DEFAULT_SEED as u128));
+        ret
+    }
+
+    fn mask() -> u128 { (1u128 << W) - 1 }
+
+    fn twist(&mut self) {
+        let upper = (Self::mask() << R) & Self::mask();
+        let lower = !upper & Self::mask();
+
+        for k in 0..N {
+            let x: u128 = self.state[k].as_to();
+            let next: u128 = self.state[(k + 1) % N].as_to();
+            let middle: u128 = self.state[(k + M) % N].as_to();
+
+            let y = (x & upper) | (next & lower);
+            let a = if y & 1 != 0 { A as u128 } else { 0 };
+
+            self.state[k] = T::as_from(middle ^ (y >> 1) ^ a);
+        }
+
+        self.index = 0;
+    }
+}
+
+impl<
+    T: UnsignedIntegral,
+    const W: u32, const N: usize, const M: usize, const R: u32,
+    const A: u64, const U: u32, const D: u64, const S: u32, const B: u64, const TT: u32, const C: u64, const L: u32,
+    const F: u64,
+> UniformRandomBitGenerator for MersenneTwisterEngine<T, W, N, M, R, A, U, D, S, B, TT, C, L, F> {
+    type ResultType = T;
+
+    fn min() -> Self::ResultType { T::zero() }
+
+    fn max() -> Self::ResultType { T::as_from(Self::mask()) }
+
+    fn generate(&mut self) -> Self::ResultType {
+        if self.index >= N {
+            self.twist();
+        }
+
+        let mut z: u128 = self.state[self.index].as_to();
+        self.index += 1;
+
+        z ^= (z >> U) & D as u128;
+        z ^= (z << S) & B as u128;
+        z ^= (z << TT) & C as u128;
+        z ^= z >> L;
+
+        T::as_from(z & Self::mask())
+    }
+}
+
+impl<
+    T: UnsignedIntegral,
+    const W: u32, const N: usize, const M: usize, const R: u32,
+    const A: u64, const U: u32, const D: u64, const S: u32, const B: u64, const TT: u32, const C: u64, const L: u32,
+    const F: u64,
+> RandomNumberEngine for MersenneTwisterEngine<T, W, N, M, R, A, U, D, S, B, TT, C, L, F> {
+    /// Skips whole twists without tempering, but is still linear in `z`.
+    fn discard(&mut self, z: u128) {
+        let mut z = z;
+
+        while z > 0 {
+            if self.index >= N {
+                self.twist();
+            }
+
+            let skipped = std::cmp::min(z, (N - self.index) as u128);
+            self.index += skipped as usize;
+            z -= skipped;
+        }
+    }
+
+    fn reset(&mut self) { *self = Self::new(); }
+
+    /// Takes the words that `seed(seed_seq&)` would take from the sequence, `ceil(W / 32)` per word of the state,
+    /// least significant first.
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) {
+        let words = W.div_ceil(32);
+
+        for v in &mut self.state {
+            let x = (0..words).fold(0u128, |x, j| x | (generator.generate() as u128) << (32 * j));
+            *v = T::as_from(x & Self::mask());
+        }
+
+        // an all zero state, apart from the bits below `R` of the first word, would never leave zero
+        let first: u128 = self.state[0].as_to();
+        if (first >> R) == 0 && self.state[1..].iter().all(|&v| v == T::zero()) {
+            self.state[0] = T::as_from(1u128 << (W - 1));
+        }
+
+        self.index = N;
+    }
+
+    fn seed_from_result(&mut self, v: Self::ResultType) {
+        let mut x: u128 = v.as_to();
+        x &= Self::mask();
+        self.state[0] = T::as_from(x);
+
+        for i in 1..N {
+            x = (F as u128 * (x ^ (x >> (W - 2))) + i as u128) & Self::mask();
+            self.state[i] = T::as_from(x);
+        }
+
+        self.index = N;
+    }
+}
+
+impl<
+    T: UnsignedIntegral,
+    const W: u32, const N: usize, const M: usize, const R: u32,
+    const A: u64, const U: u32, const D: u64, const S: u32, const B: u64, const TT: u32, const C: u64, const L: u32,
+    const F: u64,
+> SerializableEngine for MersenneTwisterEngine<T, W, N, M, R, A, U, D, S, B, TT, C, L, F> {
+    fn state_tag() -> String { format!("mersenne-twister-{}-{}-{}-{}-{:X}", W, N, M, R, A) }
+
+    /// The words of the state, then the index of the next one.
+    fn state_words(&self) -> Vec<u128> {
+        self.state.iter().map(|&v| v.as_to()).chain(std::iter::once(self.index as u128)).collect()
+    }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        let mut words = StateWords::new(words, N + 1)?;
+
+        let state: [T; N] = words.next_array()?;
+        let index: u128 = words.next()?;
+
+        if state.iter().any(|&v| v > Self::max()) {
+            return Err(StateError::BadValue(format!("the words of a Mersenne twister have {} bits", W)));
+        }
+
+        if index > N as u128 {
+            return Err(StateError::BadValue(format!("index {} into a state of {} words", index, N)));
+        }
+
+        Ok(Self { state, index: index as usize })
+    }
+}
+
+pub type MT19937 = MersenneTwisterEngine<u32, 32, 624, 397, 31, 0x9908B0DF, 11, 0xFFFFFFFF, 7, 0x9D2C5680, 15, 0xEFC60000, 18, 1812433253>;
+
+#[allow(non_camel_case_types)]
+pub type MT19937_64 = MersenneTwisterEngine<
+    u64, 64, 312, 156, 31,
+    0xB5026F5AA96619E9, 29, 0x5555555555555555, 17, 0x71D67FFFEDA60000, 37, 0xFFF7EEE000000000, 43,
+    6364136223846793005,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_tables;
+    use crate::rng::engines::util::WordSequence;
+    use super::*;
+
+    #[test]
+    fn test_mt19937() {
+        // [rand.predef]: the 10000th value of a default constructed engine
+        let mut generator = MT19937::new();
+        generator.discard(9999);
+        assert_eq!(generator.generate(), 4123659995);
+
+        let mut generator = MT19937_64::new();
+        generator.discard(9999);
+        assert_eq!(generator.generate(), 9981545732273789042);
+
+        generator.reset();
+        assert!(generator == MT19937_64::new());
+    }
+
+    #[test]
+    fn test_mt19937_consistency() {
+        let mut generator = MT19937::new();
+        test_tables::TABLE_MT19937_SEED_0.test(&mut generator);
+        test_tables::TABLE_MT19937_SEED_1.test(&mut generator);
+        test_tables::TABLE_MT19937_SEED_2.test(&mut generator);
+
+        let mut generator = MT19937_64::new();
+        test_tables::TABLE_MT19937_64_SEED_0.test(&mut generator);
+        test_tables::TABLE_MT19937_64_SEED_1.test(&mut generator);
+        test_tables::TABLE_MT19937_64_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_mt19937_seed_from() {
+        // seeded like with a seed sequence that gives these words
+        let mut generator = MT19937::new();
+        generator.seed_from(&mut WordSequence::new());
+        assert_eq!([0; 3].map(|_| generator.generate()), [4226463028, 4028416005, 2664531347]);
+
+        let mut generator = MT19937_64::new();
+        generator.seed_from(&mut WordSequence::new());
+        assert_eq!([0; 3].map(|_| generator.generate()), [16650483429136065548, 16010615547696398464, 17644602608522499060]);
+    }
+
+    #[test]
+    fn test_mt19937_discard() {
+        let mut generator = MT19937::new();
+        generator.seed_from_result(0xDEADBEEF);
+
+        for z in [0u128, 1, 623, 624, 625, 2000] {
+            let mut stepped = generator;
+            for _ in 0..z {
+                stepped.generate();
+            }
+
+            generator.discard(z);
+            assert!(stepped == generator, "discard({}) disagrees with stepping", z);
+        }
+    }
+}
@@ -1,16 +1,47 @@
 use crate::*;
 
+pub mod chacha;
+pub mod counter_based;
+pub mod discard_block;
 pub mod generic;
+pub mod independent_bits;
+pub mod linear_congruential;
+pub mod mersenne_twister;
+pub mod multi_lane;
+pub mod pcg;
+pub mod philox;
+mod polynomial;
 pub mod random_device;
+pub mod shuffle_order;
+#[cfg(feature = "simd")] pub mod simd;
 pub mod splitmix;
+pub mod state;
+pub mod subtract_with_carry;
+pub mod threefry;
 pub mod xoroshiro;
 pub mod xoshiro;
 
 #[cfg(test)]
 mod test_tables;
 
+pub(crate) use polynomial::F2Polynomial;
+
+pub use chacha::*;
+pub use counter_based::*;
+pub use discard_block::*;
+pub use independent_bits::*;
+pub use linear_congruential::*;
+pub use mersenne_twister::*;
+pub use multi_lane::*;
+pub use pcg::*;
+pub use philox::*;
 pub use random_device::*;
+pub use shuffle_order::*;
+#[cfg(feature = "simd")] pub use simd::*;
 pub use splitmix::*;
+pub use state::*;
+pub use subtract_with_carry::*;
+pub use threefry::*;
 pub use xoroshiro::*;
 pub use xoshiro::*;
 
@@ -28,66 +59,63 @@ pub trait Scrambler<T: Integral, const N: usize>: Copy + Clone + Eq + PartialEq
     fn scramble(state: &[T; N]) -> T;
 }
 
+/// The part of the `SerializableEngine` tag of a `GenericLinearGenerator` that a permuter or a scrambler contributes,
+/// e.g. `xoshiro256` and `++`; only generators that are serialized need it.
+pub trait StateTag {
+    fn tag() -> String;
+}
+
 pub trait Discarder<T: Integral, const N: usize>: Copy + Clone + Eq + PartialEq {
-    fn discard(state: [T; N], z: usize) -> [T; N];
+    fn discard(state: [T; N], z: u128) -> [T; N];
+
+    /// Advances the state by `2^log2` steps, for jumps that do not fit into a `u128`.
+    fn discard_pow2(state: [T; N], log2: usize) -> [T; N];
 }
 
 pub trait PolyDiscarder<T: Integral, const N: usize>: Copy + Clone + Eq + PartialEq {
-    /// `polynomials().windows(2).map(|v| v[0] > v[1]).reduce(|a, b| a && b).unwrap_or(true)`
+    /// Precomputed `(log2, x^(2^log2) mod p)` pairs, where `p` is the characteristic polynomial of the permuter.
     ///
-    /// must hold true.
+    /// These are only a shortcut for the usual jump and long jump; any other distance is handled by computing
+    /// `p` and exponentiating modulo it.
     fn polynomials() -> &'static [(usize, [T; N])];
 }
 
-impl<T: Integral, const N: usize, P: PolyDiscarder<T, N> + Permuter<T, N>> Discarder<T, N> for P {
-    fn discard(mut state: [T; N], mut z: usize) -> [T; N] {
-        debug_assert!(Self::polynomials().windows(2).map(|v| v[0] > v[1]).reduce(|a, b| a && b).unwrap_or(true));
-        //debug_assert!(Self::polynomials().iter().map(|v| v.0.count_ones() == 1).reduce(|a, b| a && b).unwrap_or(true));
-
-        // this does not work for arbitrarily descending divisors
-        /*let placeholder = (usize::MAX, [T::zero(); N]);
-        let polys_and_counts = std::iter::once(&placeholder)
-            .chain(Self::polynomials().iter())
-            .map_windows(|w: &[_; 2]| {
-                let to_sub = (z / w[0].0) * w[0].0;
-                let left_z = z - to_sub;
-                (left_z / w[1].0, w[1].1)
-            });
-
-        for (count, poly) in polys_and_counts {
-            for _ in 0..count {
-                state = util::jump_new(state, &poly, &P::permute);
+impl<T: Integral, const N: usize, P: PolyDiscarder<T, N> + Permuter<T, N> + 'static> Discarder<T, N> for P {
+    fn discard(mut state: [T; N], z: u128) -> [T; N] {
+        // applying a jump polynomial costs as much as stepping through every bit of the state
+        if z <= (N * T::DIGITS as usize) as u128 {
+            for _ in 0..z {
+                state = P::permute(state);
             }
-        }
 
-        for _ in 0..z - Self::polynomials().last().map(|v| z / v.0 * v.0).unwrap_or(0) {
-            state = P::permute(state);
+            return state;
         }
 
-        return state;*/
+        let jump = F2Polynomial::x_pow_mod(z, &util::characteristic_polynomial::<T, N, P>());
 
-        // this is terrible
-
-        for (jump_length, poly) in Self::polynomials() {
-            let jump_count = z / jump_length;
-            z -= jump_count * jump_length;
+        util::jump_new(state, &jump.to_words(), &P::permute)
+    }
 
-            for _ in 0..jump_count {
-                state = util::jump_new(state, poly, &P::permute);
-            }
+    fn discard_pow2(state: [T; N], log2: usize) -> [T; N] {
+        if let Some((_, poly)) = Self::polynomials().iter().find(|(l, _)| *l == log2) {
+            return util::jump_new(state, poly, &P::permute);
         }
 
-        for _ in 0..z {
-            state = P::permute(state);
-        }
+        let jump = F2Polynomial::x_pow2_mod(log2, &util::characteristic_polynomial::<T, N, P>());
 
-        return state;
+        util::jump_new(state, &jump.to_words(), &P::permute)
     }
 }
 
 mod util {
+    use std::any::TypeId;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, OnceLock};
+
     #[cfg(test)]
     use super::super::RandomNumberEngine;
+    use super::Permuter;
+    use super::polynomial::F2Polynomial;
     use crate::integral::*;
 
     // see stuff/libs/random/test/consistency.cpp
@@ -110,6 +138,30 @@ mod util {
         }
     }
 
+    /// Stands in for a `std::seed_seq` whose `generate` gives these words, to compare `seed_from` with the
+    /// `seed(seed_seq&)` of the C++ engines.
+    #[cfg(test)]
+    pub struct WordSequence(u32);
+
+    #[cfg(test)]
+    impl WordSequence {
+        pub fn new() -> Self { Self(0x9E3779B9) }
+    }
+
+    #[cfg(test)]
+    impl super::super::UniformRandomBitGenerator for WordSequence {
+        type ResultType = u32;
+
+        fn min() -> u32 { 0 }
+        fn max() -> u32 { u32::MAX }
+
+        fn generate(&mut self) -> u32 {
+            let ret = self.0;
+            self.0 = self.0.wrapping_mul(2654435761).wrapping_add(12345);
+            ret
+        }
+    }
+
     #[cfg(test)]
     pub struct JumpConsistencyTestTable<T: Integral, const N: usize> {
         pub date: &'static str,
@@ -171,4 +223,132 @@ mod util {
 
         return res;
     }
+
+    /// The characteristic polynomial of the transition `P::permute`, recovered with Berlekamp–Massey from the
+    /// lowest bit of the first state word. Computed once per permuter.
+    ///
+    /// This assumes the polynomial is irreducible (as it is for every full-period engine), so that any nonzero
+    /// state and output bit have it as their minimal polynomial.
+    pub fn characteristic_polynomial<T: Integral, const N: usize, P: Permuter<T, N> + 'static>() -> Arc<F2Polynomial> {
+        static CACHE: OnceLock<Mutex<HashMap<TypeId, Arc<F2Polynomial>>>> = OnceLock::new();
+
+        let key = TypeId::of::<(P, [T; N])>();
+
+        if let Some(poly) = CACHE.get_or_init(Default::default).lock().unwrap().get(&key) {
+            return poly.clone();
+        }
+
+        let bits = N * T::DIGITS as usize;
+
+        let mut state = [T::zero(); N];
+        state[0] = T::one();
+
+        let sequence: Vec<bool> = (0..2 * bits).map(|_| {
+            let bit = (state[0] & T::one()) != T::zero();
+            state = P::permute(state);
+            bit
+        }).collect();
+
+        let poly = Arc::new(F2Polynomial::berlekamp_massey(&sequence));
+        debug_assert_eq!(poly.degree(), Some(bits));
+
+        CACHE.get_or_init(Default::default).lock().unwrap().insert(key, poly.clone());
+
+        poly
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::*;
+    use super::*;
+    use super::util::characteristic_polynomial;
+
+    fn test_polynomials_against_characteristic<T: Integral, const N: usize, P: PolyDiscarder<T, N> + Permuter<T, N> + 'static>() {
+        let characteristic = characteristic_polynomial::<T, N, P>();
+
+        for (log2, poly) in P::polynomials() {
+            assert_eq!(&F2Polynomial::x_pow2_mod(*log2, &characteristic).to_words::<T, N>(), poly, "jump polynomial for 2^{} disagrees", log2);
+        }
+    }
+
+    #[test]
+    fn test_jump_polynomials() {
+        test_polynomials_against_characteristic::<u32, 2, Xoroshiro64Permuter>();
+        test_polynomials_against_characteristic::<u64, 2, Xoroshiro128PSPermuter>();
+        test_polynomials_against_characteristic::<u64, 2, Xoroshiro128PPPermuter>();
+        test_polynomials_against_characteristic::<u64, 16, Xoroshiro1024Permuter>();
+        test_polynomials_against_characteristic::<u32, 4, Xoshiro128Permuter>();
+        test_polynomials_against_characteristic::<u64, 4, Xoshiro256Permuter>();
+        test_polynomials_against_characteristic::<u64, 8, Xoshiro512Permuter>();
+    }
+
+    fn test_discard_against_stepping<E: RandomNumberEngine>(mut generator: E) {
+        for z in [0u128, 1, 63, 64, 65, 127, 128, 129, 255, 256, 257, 1000, 1023, 1024, 1025, 4097] {
+            let mut stepped = generator.clone();
+            for _ in 0..z {
+                stepped.generate();
+            }
+
+            let mut discarded = generator.clone();
+            discarded.discard(z);
+
+            assert!(stepped == discarded, "discard({}) disagrees with stepping", z);
+
+            generator.generate();
+        }
+    }
+
+    #[test]
+    fn test_linear_discard() {
+        let mut generator = Xoroshiro64SS::new();
+        generator.seed_from_result(0xDEADBEEF);
+        test_discard_against_stepping(generator);
+
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+        test_discard_against_stepping(generator);
+
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+        test_discard_against_stepping(generator);
+
+        let mut generator = Xoroshiro1024PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+        test_discard_against_stepping(generator);
+    }
+
+    #[test]
+    fn test_long_discard() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(0xABADC0DECAFED00D);
+
+        let mut twice = generator.clone();
+        twice.discard(1_000_000_000_000);
+        twice.discard(1_000_000_000_000);
+
+        let mut once = generator.clone();
+        once.discard(2_000_000_000_000);
+
+        assert!(once == twice);
+
+        // (2^128 - 1) + 1 steps is the precomputed jump
+        let mut jumped = generator.clone();
+        jumped.discard_pow2(128);
+
+        let mut discarded = generator.clone();
+        discarded.discard(u128::MAX);
+        discarded.discard(1);
+
+        assert!(jumped == discarded);
+
+        // 2^128 - 1 is the full period
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(0xABADC0DECAFED00D);
+
+        let mut discarded = generator.clone();
+        discarded.discard(u128::MAX);
+
+        assert!(generator == discarded);
+    }
 }
@@ -0,0 +1,323 @@
+use std::marker::PhantomData;
+
+use crate::rng::*;
+use super::*;
+
+use rng::util::fill_with_entropy;
+
+/// A PCG family member: the LCG constants of the underlying state and the output permutation applied to it.
+pub trait PCGVariant<T: UnsignedIntegral>: Copy + Clone + Eq + PartialEq {
+    type ResultType: UnsignedIntegral;
+
+    /// Whether the output permutation is applied to the state before (true) or after (false) it is advanced.
+    const OUTPUT_PREVIOUS: bool;
+
+    /// The name used in the `SerializableEngine` tag, e.g. `xsh-rr-64-32`.
+    const NAME: &'static str;
+
+    fn multiplier() -> T;
+    fn default_increment() -> T;
+
+    fn output(state: T) -> Self::ResultType;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct PCGEngine<T: UnsignedIntegral, V: PCGVariant<T>> {
+    state: T,
+    increment: T,
+
+    variant: PhantomData<V>,
+}
+
+impl<T: UnsignedIntegral, V: PCGVariant<T>> PCGEngine<T, V> {
+    /// Seeded with zero on the default stream, like `new_with_stream(0, default_increment >> 1)`.
+    pub fn new() -> Self {
+        let mut ret = Self {
+            state: T::zero(),
+            increment: V::default_increment(),
+            variant: Default::default(),
+        };
+
+        ret.seed_state(T::zero());
+        ret
+    }
+
+    /// Equivalent to `pcg_srandom_r(state, stream)` of the reference implementation.
+    pub fn new_with_stream(state: T, stream: T) -> Self {
+        let mut ret = Self::new();
+        ret.seed_with_stream(state, stream);
+        ret
+    }
+
+    /// Selects the stream (the increment) without touching the state.
+    pub fn set_stream(&mut self, stream: T) { self.increment = (stream << 1) | T::one(); }
+
+    pub fn stream(&self) -> T { self.increment >> 1 }
+
+    pub fn seed_with_stream(&mut self, state: T, stream: T) {
+        self.set_stream(stream);
+        self.seed_state(state);
+    }
+
+    /// Advances the state by `delta` steps in O(log delta) time.
+    ///
+    /// As the LCG wraps around, `advance(0u128.wrapping_sub(n))` steps the generator backwards by `n`.
+    pub fn advance(&mut self, delta: T) { self.state = detail::lcg_advance(self.state, delta, V::multiplier(), self.increment); }
+
+    fn seed_state(&mut self, state: T) {
+        self.state = state.wrapping_add(self.increment);
+        self.bump();
+    }
+
+    fn bump(&mut self) { self.state = self.state.wrapping_mul(V::multiplier()).wrapping_add(self.increment); }
+}
+
+impl<T: UnsignedIntegral, V: PCGVariant<T>> UniformRandomBitGenerator for PCGEngine<T, V> {
+    type ResultType = V::ResultType;
+
+    fn min() -> Self::ResultType { NumericLimits::min() }
+
+    fn max() -> Self::ResultType { NumericLimits::max() }
+
+    fn generate(&mut self) -> Self::ResultType {
+        if V::OUTPUT_PREVIOUS {
+            let old_state = self.state;
+            self.bump();
+            V::output(old_state)
+        } else {
+            self.bump();
+            V::output(self.state)
+        }
+    }
+}
+
+impl<T: UnsignedIntegral + FromScalar<V::ResultType>, V: PCGVariant<T>> RandomNumberEngine for PCGEngine<T, V> {
+    /// The period is at most `2^128`, so `z` only matters modulo the width of the state.
+    fn discard(&mut self, z: u128) { self.advance(T::as_from(z)); }
+
+    fn reset(&mut self) { *self = Self::new(); }
+
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) {
+        let state = fill_with_entropy(generator, T::DIGITS);
+        let stream = fill_with_entropy(generator, T::DIGITS);
+        self.seed_with_stream(state, stream);
+    }
+
+    /// Like `engine::seed` from pcg-cpp, this keeps the currently selected stream.
+    fn seed_from_result(&mut self, v: Self::ResultType) { self.seed_state(FromScalar::from_scalar(v).unwrap()); }
+}
+
+impl<T: UnsignedIntegral + FromScalar<V::ResultType>, V: PCGVariant<T>> SerializableEngine for PCGEngine<T, V> {
+    fn state_tag() -> String { format!("pcg-{}", V::NAME) }
+
+    fn state_words(&self) -> Vec<u128> { vec![self.state.as_to(), self.increment.as_to()] }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        let mut words = StateWords::new(words, 2)?;
+
+        let mut ret = Self::new();
+        ret.state = words.next()?;
+        ret.increment = words.next()?;
+
+        if (ret.increment & T::one()) == T::zero() {
+            return Err(StateError::BadValue("the increment of a PCG engine must be odd".into()));
+        }
+
+        Ok(ret)
+    }
+}
+
+/// XSH RR 64/32
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct PCG32Output;
+
+impl PCGVariant<u64> for PCG32Output {
+    type ResultType = u32;
+
+    const OUTPUT_PREVIOUS: bool = true;
+    const NAME: &'static str = "xsh-rr-64-32";
+
+    fn multiplier() -> u64 { 6364136223846793005_u64 }
+    fn default_increment() -> u64 { 1442695040888963407_u64 }
+
+    fn output(state: u64) -> u32 {
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rot = (state >> 59) as u32;
+
+        xorshifted.rotate_right(rot)
+    }
+}
+
+/// XSL RR 128/64
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct PCG64Output;
+
+impl PCGVariant<u128> for PCG64Output {
+    type ResultType = u64;
+
+    const OUTPUT_PREVIOUS: bool = false;
+    const NAME: &'static str = "xsl-rr-128-64";
+
+    fn multiplier() -> u128 { 0x2360ED051FC65DA44385DF649FCCF645_u128 }
+    fn default_increment() -> u128 { 0x5851F42D4C957F2D14057B7EF767814F_u128 }
+
+    fn output(state: u128) -> u64 {
+        let folded = ((state >> 64) as u64) ^ (state as u64);
+        let rot = (state >> 122) as u32;
+
+        folded.rotate_right(rot)
+    }
+}
+
+/// DXSM 128/64 with the 64-bit "cheap multiplier", as used by NumPy's `PCG64DXSM`
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct PCG64DXSMOutput;
+
+impl PCGVariant<u128> for PCG64DXSMOutput {
+    type ResultType = u64;
+
+    const OUTPUT_PREVIOUS: bool = true;
+    const NAME: &'static str = "dxsm-128-64";
+
+    fn multiplier() -> u128 { detail::CHEAP_MULTIPLIER_128 as u128 }
+    fn default_increment() -> u128 { 0x5851F42D4C957F2D14057B7EF767814F_u128 }
+
+    fn output(state: u128) -> u64 {
+        let mut hi = (state >> 64) as u64;
+        let lo = (state as u64) | 1;
+
+        hi ^= hi >> 32;
+        hi = hi.wrapping_mul(detail::CHEAP_MULTIPLIER_128);
+        hi ^= hi >> 48;
+        hi.wrapping_mul(lo)
+    }
+}
+
+pub type PCG32 = PCGEngine<u64, PCG32Output>;
+pub type PCG64 = PCGEngine<u128, PCG64Output>;
+pub type PCG64DXSM = PCGEngine<u128, PCG64DXSMOutput>;
+
+mod detail {
+    use crate::*;
+
+    pub const CHEAP_MULTIPLIER_128: u64 = 0xDA942042E4DD58B5_u64;
+
+    /// Brown, "Random Number Generation with Arbitrary Stride"
+    pub fn lcg_advance<T: UnsignedIntegral>(state: T, mut delta: T, mut cur_mult: T, mut cur_plus: T) -> T {
+        let mut acc_mult = T::one();
+        let mut acc_plus = T::zero();
+
+        while delta != T::zero() {
+            if (delta & T::one()) != T::zero() {
+                acc_mult = acc_mult.wrapping_mul(cur_mult);
+                acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+            }
+
+            cur_plus = cur_mult.wrapping_add(T::one()).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+            delta = delta >> 1;
+        }
+
+        acc_mult.wrapping_mul(state).wrapping_add(acc_plus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_tables;
+    use crate::rng::*;
+    use super::*;
+
+    #[test]
+    fn test_pcg32_reference() {
+        // pcg32-demo from pcg-c-basic, `pcg32_srandom_r(&rng, 42u, 54u)`
+        let mut generator = PCG32::new_with_stream(42, 54);
+
+        for expected in [0xa15c02b7_u32, 0x7b47f409_u32, 0xba1d3330_u32, 0x83d2f293_u32, 0xbfa4784b_u32, 0xcbed606e_u32] {
+            assert_eq!(generator.generate(), expected);
+        }
+    }
+
+    #[test]
+    fn test_pcg32_consistency() {
+        let mut generator = PCG32::new();
+        test_tables::TABLE_PCG32_SEED_0.test(&mut generator);
+        test_tables::TABLE_PCG32_SEED_1.test(&mut generator);
+        test_tables::TABLE_PCG32_SEED_2.test(&mut generator);
+        test_tables::TABLE_PCG32_SEED_0.test(&mut generator);
+
+        generator.set_stream(0x0123456789ABCDEF_u64);
+        test_tables::TABLE_PCG32_STREAM_0123456789ABCDEF.test(&mut generator);
+
+        // a fresh and a reset engine agree, and start seeded
+        generator.reset();
+        assert!(generator == PCG32::new());
+        assert!(generator == PCG32::new_with_stream(0, 1442695040888963407_u64 >> 1));
+    }
+
+    #[test]
+    fn test_pcg64_consistency() {
+        let mut generator = PCG64::new();
+        test_tables::TABLE_PCG64_SEED_0.test(&mut generator);
+        test_tables::TABLE_PCG64_SEED_1.test(&mut generator);
+        test_tables::TABLE_PCG64_SEED_2.test(&mut generator);
+        test_tables::TABLE_PCG64_SEED_0.test(&mut generator);
+
+        generator.set_stream(0x0123456789ABCDEF_u128);
+        test_tables::TABLE_PCG64_STREAM_0123456789ABCDEF.test(&mut generator);
+    }
+
+    #[test]
+    fn test_pcg64dxsm_consistency() {
+        let mut generator = PCG64DXSM::new();
+        test_tables::TABLE_PCG64DXSM_SEED_0.test(&mut generator);
+        test_tables::TABLE_PCG64DXSM_SEED_1.test(&mut generator);
+        test_tables::TABLE_PCG64DXSM_SEED_2.test(&mut generator);
+        test_tables::TABLE_PCG64DXSM_SEED_0.test(&mut generator);
+
+        generator.set_stream(0x0123456789ABCDEF_u128);
+        test_tables::TABLE_PCG64DXSM_STREAM_0123456789ABCDEF.test(&mut generator);
+    }
+
+    fn test_discard_against_stepping<E: RandomNumberEngine>(mut generator: E) {
+        for z in [0u128, 1, 2, 3, 17, 128, 1000, 4097] {
+            let mut stepped = generator.clone();
+            for _ in 0..z {
+                stepped.generate();
+            }
+
+            let mut discarded = generator.clone();
+            discarded.discard(z);
+
+            assert!(stepped == discarded, "discard({}) disagrees with stepping", z);
+
+            generator.generate();
+        }
+    }
+
+    #[test]
+    fn test_pcg_discard() {
+        let mut generator = PCG32::new();
+        generator.seed_from_result(0xDEADBEEF_u32);
+        test_discard_against_stepping(generator);
+
+        let mut generator = PCG64::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE_u64);
+        test_discard_against_stepping(generator);
+
+        let mut generator = PCG64DXSM::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE_u64);
+        test_discard_against_stepping(generator);
+    }
+
+    #[test]
+    fn test_pcg_backstep() {
+        let mut generator = PCG64::new_with_stream(0xCAFEBABE_u128, 0xDEADBEEF_u128);
+        let first = generator.generate();
+
+        generator.discard(123456);
+        generator.advance(0u128.wrapping_sub(123457));
+
+        assert_eq!(generator.generate(), first);
+    }
+}
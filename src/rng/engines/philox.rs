@@ -0,0 +1,99 @@
+use crate::rng::engines::counter_based::CounterBasedEngine;
+
+use super::*;
+
+/// Philox4x32 with `R` rounds, see Salmon et al., "Parallel Random Numbers: As Easy as 1, 2, 3"
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Philox4x32Bijection<const R: usize>;
+
+impl<const R: usize> Bijection<u32, 4, 2> for Philox4x32Bijection<R> {
+    fn apply(mut counter: [u32; 4], mut key: [u32; 2]) -> [u32; 4] {
+        for round in 0..R {
+            if round != 0 {
+                key = detail::bump_key(key);
+            }
+
+            counter = detail::round(counter, key);
+        }
+
+        counter
+    }
+
+    fn name() -> String { format!("philox4x32-{}", R) }
+}
+
+/// Philox4x32-10, the stateless form.
+pub fn philox4x32_10(counter: [u32; 4], key: [u32; 2]) -> [u32; 4] { Philox4x32Bijection::<10>::apply(counter, key) }
+
+pub type Philox4x32 = CounterBasedEngine<u32, 4, 2, Philox4x32Bijection<10>>;
+
+mod detail {
+    const M0: u32 = 0xD2511F53_u32;
+    const M1: u32 = 0xCD9E8D57_u32;
+    const W0: u32 = 0x9E3779B9_u32;
+    const W1: u32 = 0xBB67AE85_u32;
+
+    fn mulhilo(a: u32, b: u32) -> (u32, u32) {
+        let product = (a as u64) * (b as u64);
+        ((product >> 32) as u32, product as u32)
+    }
+
+    pub fn round(counter: [u32; 4], key: [u32; 2]) -> [u32; 4] {
+        let (hi_0, lo_0) = mulhilo(M0, counter[0]);
+        let (hi_1, lo_1) = mulhilo(M1, counter[2]);
+
+        [hi_1 ^ counter[1] ^ key[0], lo_1, hi_0 ^ counter[3] ^ key[1], lo_0]
+    }
+
+    pub fn bump_key(key: [u32; 2]) -> [u32; 2] { [key[0].wrapping_add(W0), key[1].wrapping_add(W1)] }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::*;
+    use super::*;
+
+    // Random123 kat_vectors
+    #[test]
+    fn test_philox4x32_10_known_answers() {
+        assert_eq!(philox4x32_10([0, 0, 0, 0], [0, 0]), [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]);
+        assert_eq!(philox4x32_10([0xffffffff; 4], [0xffffffff; 2]), [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]);
+        assert_eq!(
+            philox4x32_10([0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344], [0xa4093822, 0x299f31d0]),
+            [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+        );
+    }
+
+    #[test]
+    fn test_philox4x32_engine() {
+        let key = [0xa4093822, 0x299f31d0];
+        let mut generator = Philox4x32::new_with_key_and_counter(key, [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344]);
+
+        for expected in [0xd16cfe09_u32, 0x94fdcceb_u32, 0x5001e420_u32, 0x24126ea1_u32] {
+            assert_eq!(generator.generate(), expected);
+        }
+
+        assert_eq!(generator.counter(), [0x243f6a89, 0x85a308d3, 0x13198a2e, 0x03707344]);
+        assert_eq!(generator.generate(), philox4x32_10([0x243f6a89, 0x85a308d3, 0x13198a2e, 0x03707344], key)[0]);
+    }
+
+    #[test]
+    fn test_philox4x32_discard() {
+        let mut generator = Philox4x32::new_with_key([0xDEADBEEF, 0xCAFEBABE]);
+
+        for z in [0u128, 1, 2, 3, 4, 5, 17, 1000] {
+            let mut stepped = generator.clone();
+            for _ in 0..z {
+                stepped.generate();
+            }
+
+            let mut discarded = generator.clone();
+            discarded.discard(z);
+
+            assert!(stepped == discarded, "discard({}) disagrees with stepping", z);
+            assert_eq!(stepped.generate(), discarded.generate());
+
+            generator.generate();
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::*;
+
+/// A polynomial over GF(2); the coefficient of `x^i` is bit `i % 64` of `words[i / 64]`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(crate) struct F2Polynomial {
+    words: Vec<u64>,
+}
+
+impl F2Polynomial {
+    pub fn zero() -> Self { Self { words: Vec::new() } }
+
+    pub fn one() -> Self { Self { words: vec![1] } }
+
+    pub fn x() -> Self { Self { words: vec![2] } }
+
+    pub fn degree(&self) -> Option<usize> {
+        self.words.iter().enumerate().rev()
+            .find(|(_, &w)| w != 0)
+            .map(|(i, &w)| i * 64 + 63 - w.leading_zeros() as usize)
+    }
+
+    pub fn coefficient(&self, i: usize) -> bool { self.words.get(i / 64).map(|w| (w >> (i % 64)) & 1 != 0).unwrap_or(false) }
+
+    /// Adds `other * x^shift` to `self`.
+    pub fn add_shifted(&mut self, other: &Self, shift: usize) {
+        let word_shift = shift / 64;
+        let bit_shift = shift % 64;
+
+        let required = other.words.len() + word_shift + 1;
+        if self.words.len() < required {
+            self.words.resize(required, 0);
+        }
+
+        for (i, &w) in other.words.iter().enumerate() {
+            self.words[i + word_shift] ^= w << bit_shift;
+            if bit_shift != 0 {
+                self.words[i + word_shift + 1] ^= w >> (64 - bit_shift);
+            }
+        }
+
+        self.trim();
+    }
+
+    /// Squaring is linear over GF(2): `(sum a_i x^i)^2 = sum a_i x^(2i)`.
+    pub fn square(&self) -> Self {
+        let mut words = Vec::with_capacity(self.words.len() * 2);
+
+        for &w in &self.words {
+            words.push(detail::spread_bits(w as u32));
+            words.push(detail::spread_bits((w >> 32) as u32));
+        }
+
+        let mut ret = Self { words };
+        ret.trim();
+        ret
+    }
+
+    pub fn rem(mut self, modulus: &Self) -> Self {
+        let modulus_degree = modulus.degree().expect("division by the zero polynomial");
+
+        while let Some(degree) = self.degree() {
+            if degree < modulus_degree {
+                break;
+            }
+
+            self.add_shifted(modulus, degree - modulus_degree);
+        }
+
+        self
+    }
+
+    /// `x^exponent mod modulus` by left-to-right binary exponentiation.
+    pub fn x_pow_mod(exponent: u128, modulus: &Self) -> Self {
+        let mut ret = Self::one().rem(modulus);
+
+        for bit in (0..(128 - exponent.leading_zeros())).rev() {
+            ret = ret.square().rem(modulus);
+
+            if (exponent >> bit) & 1 != 0 {
+                let mut shifted = Self::zero();
+                shifted.add_shifted(&ret, 1);
+                ret = shifted.rem(modulus);
+            }
+        }
+
+        ret
+    }
+
+    /// `x^(2^log2) mod modulus`, for jumps too long to be expressed as a `u128`.
+    pub fn x_pow2_mod(log2: usize, modulus: &Self) -> Self {
+        let mut ret = Self::x().rem(modulus);
+
+        for _ in 0..log2 {
+            ret = ret.square().rem(modulus);
+        }
+
+        ret
+    }
+
+    /// Finds the minimal polynomial of the linear recurrence generating `sequence`.
+    ///
+    /// The result is the characteristic polynomial of the recurrence (monic, highest coefficient first in the
+    /// recurrence relation), not the connection polynomial Berlekamp–Massey works with internally.
+    pub fn berlekamp_massey(sequence: &[bool]) -> Self {
+        let mut connection = Self::one();
+        let mut previous = Self::one();
+        let mut length = 0usize;
+        let mut gap = 1usize;
+
+        for i in 0..sequence.len() {
+            let mut discrepancy = sequence[i];
+            for j in 1..=length {
+                discrepancy ^= connection.coefficient(j) & sequence[i - j];
+            }
+
+            if !discrepancy {
+                gap += 1;
+            } else if 2 * length <= i {
+                let old_connection = connection.clone();
+                connection.add_shifted(&previous, gap);
+                length = i + 1 - length;
+                previous = old_connection;
+                gap = 1;
+            } else {
+                connection.add_shifted(&previous, gap);
+                gap += 1;
+            }
+        }
+
+        let mut ret = Self::zero();
+        for i in 0..=length {
+            if connection.coefficient(length - i) {
+                ret.add_shifted(&Self::one(), i);
+            }
+        }
+
+        ret
+    }
+
+    /// Packs the coefficients into the word layout `util::jump_new` expects, the lowest coefficient first.
+    pub fn to_words<T: Integral, const N: usize>(&self) -> [T; N] {
+        debug_assert!(self.degree().map(|d| d < N * T::DIGITS as usize).unwrap_or(true));
+
+        let mut ret = [T::zero(); N];
+
+        for i in 0..N * T::DIGITS as usize {
+            if self.coefficient(i) {
+                let word = &mut ret[i / T::DIGITS as usize];
+                *word = *word | (T::one() << (i % T::DIGITS as usize) as u32);
+            }
+        }
+
+        ret
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+mod detail {
+    /// Moves bit `i` of `v` to bit `2i`.
+    pub fn spread_bits(v: u32) -> u64 {
+        let mut v = v as u64;
+
+        v = (v | (v << 16)) & 0x0000FFFF0000FFFF;
+        v = (v | (v << 8)) & 0x00FF00FF00FF00FF;
+        v = (v | (v << 4)) & 0x0F0F0F0F0F0F0F0F;
+        v = (v | (v << 2)) & 0x3333333333333333;
+        v = (v | (v << 1)) & 0x5555555555555555;
+
+        v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_bits(bits: &[usize]) -> F2Polynomial {
+        let mut ret = F2Polynomial::zero();
+        for &bit in bits {
+            ret.add_shifted(&F2Polynomial::one(), bit);
+        }
+        ret
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(F2Polynomial::zero().degree(), None);
+        assert_eq!(from_bits(&[0, 3, 130]).degree(), Some(130));
+
+        // (x^70 + x + 1)^2 = x^140 + x^2 + 1
+        assert_eq!(from_bits(&[70, 1, 0]).square(), from_bits(&[140, 2, 0]));
+
+        // x^4 = x + 1 mod x^4 + x + 1
+        assert_eq!(from_bits(&[4]).rem(&from_bits(&[4, 1, 0])), from_bits(&[1, 0]));
+    }
+
+    #[test]
+    fn test_x_pow_mod() {
+        // x^4 + x + 1 is primitive, x has order 15
+        let modulus = from_bits(&[4, 1, 0]);
+
+        assert_eq!(F2Polynomial::x_pow_mod(0, &modulus), F2Polynomial::one());
+        assert_eq!(F2Polynomial::x_pow_mod(15, &modulus), F2Polynomial::one());
+        assert_eq!(F2Polynomial::x_pow_mod(15 * 12345 + 4, &modulus), from_bits(&[1, 0]));
+        assert_eq!(F2Polynomial::x_pow2_mod(4, &modulus), F2Polynomial::x_pow_mod(16, &modulus));
+
+        let mut naive = F2Polynomial::one();
+        for exponent in 0..100u128 {
+            assert_eq!(F2Polynomial::x_pow_mod(exponent, &modulus), naive);
+
+            let mut shifted = F2Polynomial::zero();
+            shifted.add_shifted(&naive, 1);
+            naive = shifted.rem(&modulus);
+        }
+    }
+
+    #[test]
+    fn test_berlekamp_massey() {
+        // s_{i+4} = s_{i+1} + s_i, i.e. x^4 + x + 1
+        let mut sequence = vec![true, false, false, false];
+        for i in 0..60 {
+            sequence.push(sequence[i + 1] ^ sequence[i]);
+        }
+
+        assert_eq!(F2Polynomial::berlekamp_massey(&sequence), from_bits(&[4, 1, 0]));
+        assert_eq!(F2Polynomial::berlekamp_massey(&[false; 16]), F2Polynomial::one());
+    }
+}
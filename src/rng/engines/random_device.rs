@@ -1,84 +1,103 @@
-// this is terrible
-// TODO: rewrite
-
+use std::fs::File;
+use std::io;
+#[cfg(unix)]
 use std::io::Read;
 
 use crate::rng::UniformRandomBitGenerator;
 
+/// A source of entropy `RandomDevice` can draw from, in the order they are tried.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RandomDeviceStrategy {
+    /// The `getrandom(2)` system call, Linux only.
+    GetRandom,
+    /// Reading `/dev/urandom`, on any Unix.
+    File,
+    /// The RDSEED instruction, if CPUID reports it.
+    RdSeed,
+    /// The RDRAND instruction, if CPUID reports it.
+    RdRand,
+}
+
+impl RandomDeviceStrategy {
+    pub const ALL: [RandomDeviceStrategy; 4] = [Self::GetRandom, Self::File, Self::RdSeed, Self::RdRand];
+}
+
+/// Non-deterministic random numbers from the operating system or the CPU, like `std::random_device`.
+///
+/// The first strategy that succeeds is remembered and tried first on subsequent calls.
 pub struct RandomDevice {
-    rand_file: Option<std::fs::File>,
-    #[allow(dead_code)]
-    last_entropy: u64,
+    rand_file: Option<File>,
+    strategy: Option<RandomDeviceStrategy>,
 }
 
 impl RandomDevice {
-    pub fn new() -> Self { Self { rand_file: None, last_entropy: 0 } }
-
-    fn strat_file(&mut self) -> Option<u64> {
-        if let Some(rand_file) = &mut self.rand_file {
-            let mut buf = [0; 8];
-            if let Ok(_) = rand_file.read_exact(&mut buf) {
-                Some(u64::from_le_bytes(buf))
-            } else {
-                self.rand_file = None;
-                None
+    pub fn new() -> Self { Self { rand_file: None, strategy: None } }
+
+    /// The strategy that produced the most recent output, if any.
+    pub fn strategy(&self) -> Option<RandomDeviceStrategy> { self.strategy }
+
+    pub fn try_fill_bytes(&mut self, dest: &mut [u8]) -> io::Result<()> {
+        if let Some(strategy) = self.strategy {
+            if self.fill_bytes_with(strategy, dest).is_ok() {
+                return Ok(());
             }
-        } else {
-            None
         }
-    }
 
-    fn rdx_impl<const N: usize, T: Sized + Default + Copy, Fun: Fn(&mut T) -> i32>(fun: Fun) -> Option<[T; N]> {
-        let mut ret = [Default::default(); N];
+        let mut last_error = None;
 
-        for i in 0..N {
-            let res = fun(&mut ret[i]);
-            if res != 1 {
-                return None;
+        for strategy in RandomDeviceStrategy::ALL {
+            match self.fill_bytes_with(strategy, dest) {
+                Ok(()) => {
+                    self.strategy = Some(strategy);
+                    return Ok(());
+                }
+                Err(err) => last_error = Some(err),
             }
         }
 
-        Some(ret)
+        self.strategy = None;
+        Err(last_error.unwrap())
     }
 
-    fn strat_rdx_impl<Fun16, Fun32, Fun64>(fun_16: Fun16, fun_32: Fun32, fun_64: Fun64) -> Option<u64>
-    where
-        Fun16: Fn(&mut u16) -> i32,
-        Fun32: Fn(&mut u32) -> i32,
-        Fun64: Fn(&mut u64) -> i32,
-    {
-        if let Some(v) = Self::rdx_impl::<1, _, _>(fun_64) {
-            return Some(v[0]);
+    /// Fills `dest` using only the given strategy.
+    pub fn fill_bytes_with(&mut self, strategy: RandomDeviceStrategy, dest: &mut [u8]) -> io::Result<()> {
+        match strategy {
+            RandomDeviceStrategy::GetRandom => detail::fill_getrandom(dest),
+            RandomDeviceStrategy::File => self.fill_file(dest),
+            RandomDeviceStrategy::RdSeed => detail::fill_rdseed(dest),
+            RandomDeviceStrategy::RdRand => detail::fill_rdrand(dest),
         }
+    }
 
-        if let Some(v) = Self::rdx_impl::<2, _, _>(fun_32) {
-            return Some(((v[0] as u64) << 32) | (v[1] as u64));
-        }
+    pub fn try_generate(&mut self) -> io::Result<u64> {
+        let mut buf = [0; 8];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    #[cfg(unix)]
+    fn fill_file(&mut self, dest: &mut [u8]) -> io::Result<()> {
+        if self.rand_file.is_none() {
+            // sandboxes and containers may not have the device at all, which is no different from a missing syscall
+            let file = File::open("/dev/urandom").map_err(|err| match err.kind() {
+                io::ErrorKind::NotFound | io::ErrorKind::PermissionDenied => detail::unsupported("/dev/urandom"),
+                _ => err,
+            })?;
 
-        if let Some(v) = Self::rdx_impl::<4, _, _>(fun_16) {
-            return Some(((v[0] as u64) << 48) | ((v[1] as u64) << 32) | ((v[2] as u64) << 16) | (v[3] as u64));
+            self.rand_file = Some(file);
         }
 
-        None
-    }
+        let res = self.rand_file.as_mut().unwrap().read_exact(dest);
 
-    fn strat_rdseed() -> Option<u64> {
-        Self::strat_rdx_impl(
-            |v| unsafe { core::arch::x86_64::_rdseed16_step(v) },
-            |v| unsafe { core::arch::x86_64::_rdseed32_step(v) },
-            |v| unsafe { core::arch::x86_64::_rdseed64_step(v) },
-        )
-    }
+        if res.is_err() {
+            self.rand_file = None;
+        }
 
-    fn strat_rdrand() -> Option<u64> {
-        Self::strat_rdx_impl(
-            |v| unsafe { core::arch::x86_64::_rdrand16_step(v) },
-            |v| unsafe { core::arch::x86_64::_rdrand32_step(v) },
-            |v| unsafe { core::arch::x86_64::_rdrand64_step(v) },
-        )
+        res
     }
 
-    fn strat_fallback(&mut self) -> Option<u64> { todo!() }
+    #[cfg(not(unix))]
+    fn fill_file(&mut self, _dest: &mut [u8]) -> io::Result<()> { Err(detail::unsupported("/dev/urandom")) }
 }
 
 impl UniformRandomBitGenerator for RandomDevice {
@@ -87,17 +106,174 @@ impl UniformRandomBitGenerator for RandomDevice {
     fn min() -> Self::ResultType { u64::MIN }
     fn max() -> Self::ResultType { u64::MAX }
 
-    fn generate(&mut self) -> Self::ResultType {
-        if let Some(res) = self.strat_file() {
-            res
-        } else if let Some(res) = Self::strat_rdseed() {
-            res
-        } else if let Some(res) = Self::strat_rdrand() {
-            res
-        } else if let Some(res) = self.strat_fallback() {
-            res
-        } else {
-            panic!("failed to generate a random number through any of the strategies")
+    /// Panics if no strategy is available; use `try_generate` or `try_fill_bytes` to handle that case.
+    fn generate(&mut self) -> Self::ResultType { detail::or_panic(self.try_generate()) }
+
+    /// Takes the bytes for many values at once rather than one call to the strategy per value.
+    fn fill(&mut self, dest: &mut [Self::ResultType]) {
+        let mut buf = [0u8; 256];
+
+        for chunk in dest.chunks_mut(buf.len() / 8) {
+            let bytes = &mut buf[..chunk.len() * 8];
+            detail::or_panic(self.try_fill_bytes(bytes));
+
+            for (v, b) in chunk.iter_mut().zip(bytes.chunks_exact(8)) {
+                *v = u64::from_le_bytes(b.try_into().unwrap());
+            }
+        }
+    }
+
+    /// Panics like `generate`, `try_fill_bytes` returns the error instead.
+    fn fill_bytes(&mut self, dest: &mut [u8]) { detail::or_panic(self.try_fill_bytes(dest)) }
+}
+
+mod detail {
+    use std::io;
+
+    pub fn or_panic<T>(res: io::Result<T>) -> T {
+        match res {
+            Ok(res) => res,
+            Err(err) => panic!("failed to generate a random number through any of the strategies: {}", err),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn unsupported(what: &str) -> io::Error { io::Error::new(io::ErrorKind::Unsupported, format!("{} is not available", what)) }
+
+    #[cfg(target_os = "linux")]
+    pub fn fill_getrandom(mut dest: &mut [u8]) -> io::Result<()> {
+        extern "C" {
+            fn getrandom(buf: *mut u8, buflen: usize, flags: u32) -> isize;
+        }
+
+        while !dest.is_empty() {
+            let res = unsafe { getrandom(dest.as_mut_ptr(), dest.len(), 0) };
+
+            if res < 0 {
+                let err = io::Error::last_os_error();
+                if err.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+
+                return Err(err);
+            }
+
+            dest = &mut dest[res as usize..];
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn fill_getrandom(_dest: &mut [u8]) -> io::Result<()> { Err(unsupported("getrandom")) }
+
+    /// Intel recommends retrying RDRAND 10 times before giving up; RDSEED may fail more often under contention.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    const RDRAND_RETRIES: usize = 10;
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    const RDSEED_RETRIES: usize = 100;
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn fill_with_steps(dest: &mut [u8], retries: usize, step: impl Fn(&mut u32) -> i32, name: &str) -> io::Result<()> {
+        for chunk in dest.chunks_mut(4) {
+            let mut v = 0u32;
+
+            if !(0..retries).any(|_| step(&mut v) == 1) {
+                return Err(io::Error::new(io::ErrorKind::WouldBlock, format!("{} did not return a value after {} tries", name, retries)));
+            }
+
+            chunk.copy_from_slice(&v.to_le_bytes()[..chunk.len()]);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub fn fill_rdseed(dest: &mut [u8]) -> io::Result<()> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_rdseed32_step;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_rdseed32_step;
+
+        #[target_feature(enable = "rdseed")]
+        unsafe fn step(v: &mut u32) -> i32 { _rdseed32_step(v) }
+
+        if !std::arch::is_x86_feature_detected!("rdseed") {
+            return Err(unsupported("RDSEED"));
+        }
+
+        // SAFETY: the feature was detected above
+        fill_with_steps(dest, RDSEED_RETRIES, |v| unsafe { step(v) }, "RDSEED")
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub fn fill_rdrand(dest: &mut [u8]) -> io::Result<()> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_rdrand32_step;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_rdrand32_step;
+
+        #[target_feature(enable = "rdrand")]
+        unsafe fn step(v: &mut u32) -> i32 { _rdrand32_step(v) }
+
+        if !std::arch::is_x86_feature_detected!("rdrand") {
+            return Err(unsupported("RDRAND"));
+        }
+
+        // SAFETY: the feature was detected above
+        fill_with_steps(dest, RDRAND_RETRIES, |v| unsafe { step(v) }, "RDRAND")
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    pub fn fill_rdseed(_dest: &mut [u8]) -> io::Result<()> { Err(unsupported("RDSEED")) }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    pub fn fill_rdrand(_dest: &mut [u8]) -> io::Result<()> { Err(unsupported("RDRAND")) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_device() {
+        let mut device = RandomDevice::new();
+
+        let mut a = [0u8; 37];
+        let mut b = [0u8; 37];
+        device.try_fill_bytes(&mut a).unwrap();
+        device.try_fill_bytes(&mut b).unwrap();
+
+        assert_ne!(a, b);
+        assert!(device.strategy().is_some());
+
+        assert_ne!(device.generate(), device.generate());
+
+        // the trait methods take the bulk path too
+        fn fill<G: UniformRandomBitGenerator<ResultType = u64>>(generator: &mut G) -> ([u8; 37], [u64; 45]) {
+            let (mut bytes, mut values) = ([0u8; 37], [0u64; 45]);
+            generator.fill_bytes(&mut bytes);
+            generator.fill(&mut values);
+            (bytes, values)
+        }
+
+        let (bytes, values) = fill(&mut device);
+        assert_ne!(bytes, a);
+        assert!(values[..44].iter().zip(&values[1..]).all(|(v, w)| v != w));
+    }
+
+    #[test]
+    fn test_random_device_strategies() {
+        let mut device = RandomDevice::new();
+
+        for strategy in RandomDeviceStrategy::ALL {
+            let mut a = [0u8; 13];
+            let mut b = [0u8; 13];
+
+            match (device.fill_bytes_with(strategy, &mut a), device.fill_bytes_with(strategy, &mut b)) {
+                (Ok(()), Ok(())) => assert_ne!(a, b, "{:?} returned the same bytes twice", strategy),
+                (Err(err), _) | (_, Err(err)) => assert!(matches!(err.kind(), io::ErrorKind::Unsupported | io::ErrorKind::WouldBlock), "{:?}: {}", strategy, err),
+            }
         }
     }
 }
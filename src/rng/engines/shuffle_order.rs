@@ -0,0 +1,141 @@
+use crate::rng::*;
+use super::*;
+
+/// Returns the values of `E` in a different order through a table of `K` of them, like `std::shuffle_order_engine`
+/// (Bays and Durham, "Improving a Poor Random Number Generator", 1976); `knuth_b` is one over `minstd_rand0`.
+///
+/// The last result picks the entry of the table that is returned next and replaced by a new value of `E`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ShuffleOrderEngine<E: RandomNumberEngine, const K: usize> {
+    engine: E,
+    table: [E::ResultType; K],
+    y: E::ResultType,
+}
+
+impl<E: RandomNumberEngine, const K: usize> ShuffleOrderEngine<E, K> {
+    /// Fills the table with the next `K` values of `engine` and takes the one after as the first selector.
+    pub fn new(engine: E) -> Self {
+        assert!(K > 0, "the table of a shuffle order engine needs at least one entry");
+
+        let mut ret = Self { engine, table: [E::min(); K], y: E::min() };
+        ret.fill_table();
+        ret
+    }
+
+    pub fn base(&self) -> &E { &self.engine }
+
+    pub fn into_inner(self) -> E { self.engine }
+
+    fn fill_table(&mut self) {
+        for v in &mut self.table {
+            *v = self.engine.generate();
+        }
+
+        self.y = self.engine.generate();
+    }
+
+    /// `floor(K * (y - min) / (max - min + 1))`, exactly when the range of `E` fits into 64 bits.
+    fn index(&self) -> usize {
+        let span: u128 = (E::max() - E::min()).as_to();
+        let offset: u128 = (self.y - E::min()).as_to();
+
+        let index = if span < u64::MAX as u128 {
+            K as u128 * offset / (span + 1)
+        } else {
+            // like the long double of the standard libraries for the widest engines
+            (K as f64 * (offset as f64 / (span as f64 + 1f64))) as u128
+        };
+
+        std::cmp::min(index as usize, K - 1)
+    }
+}
+
+impl<E: RandomNumberEngine, const K: usize> UniformRandomBitGenerator for ShuffleOrderEngine<E, K> {
+    type ResultType = E::ResultType;
+
+    fn min() -> Self::ResultType { E::min() }
+
+    fn max() -> Self::ResultType { E::max() }
+
+    fn generate(&mut self) -> Self::ResultType {
+        let j = self.index();
+
+        self.y = self.table[j];
+        self.table[j] = self.engine.generate();
+
+        self.y
+    }
+}
+
+impl<E: RandomNumberEngine, const K: usize> RandomNumberEngine for ShuffleOrderEngine<E, K> {
+    fn discard(&mut self, z: u128) {
+        for _ in 0..z {
+            self.generate();
+        }
+    }
+
+    fn reset(&mut self) {
+        self.engine.reset();
+        self.fill_table();
+    }
+
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) {
+        self.engine.seed_from(generator);
+        self.fill_table();
+    }
+
+    fn seed_from_result(&mut self, v: Self::ResultType) {
+        self.engine.seed_from_result(v);
+        self.fill_table();
+    }
+}
+
+impl<E: SerializableEngine, const K: usize> SerializableEngine for ShuffleOrderEngine<E, K> {
+    fn state_tag() -> String { format!("shuffle-order-{}-{}", K, E::state_tag()) }
+
+    /// The table, the selector and then the state of the base engine.
+    fn state_words(&self) -> Vec<u128> {
+        let own = self.table.iter().chain(std::iter::once(&self.y)).map(|&v| v.as_to());
+        own.chain(self.engine.state_words()).collect()
+    }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        if words.len() < K + 1 {
+            return Err(StateError::InsufficientData);
+        }
+
+        let (own, rest) = words.split_at(K + 1);
+        let mut own = StateWords::new(own, K + 1)?;
+
+        let table = own.next_array()?;
+        let y = own.next()?;
+
+        if table.iter().chain(std::iter::once(&y)).any(|&v| v < E::min() || v > E::max()) {
+            return Err(StateError::BadValue("the table holds values out of the range of the base engine".into()));
+        }
+
+        Ok(Self { engine: E::from_state_words(rest)?, table, y })
+    }
+}
+
+/// `std::knuth_b`
+pub type KnuthB = ShuffleOrderEngine<MinStdRand0, 256>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knuth_b() {
+        // the 10000th value of a default constructed `std::knuth_b` that the standard requires
+        let mut generator = KnuthB::new(MinStdRand0::new());
+        assert_eq!([0; 5].map(|_| generator.generate()), [152607844, 823378840, 578354438, 2035308228, 1004016855]);
+
+        generator.discard(9994);
+        assert_eq!(generator.generate(), 1112339016);
+
+        generator.seed_from_result(42);
+        generator.discard(9999);
+        assert_eq!(generator.generate(), 1060807721);
+    }
+}
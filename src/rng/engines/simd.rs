@@ -0,0 +1,112 @@
+use std::simd::Simd;
+
+use crate::rng::*;
+use super::*;
+
+/// `L` lanes of xoshiro256++ in `std::simd` vectors, the same streams as `MultiLaneLinearGenerator` with the same
+/// lanes but without relying on the auto-vectoriser.
+///
+/// Only available with the `simd` feature, which needs a nightly compiler for `portable_simd`.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro256PPSimd<const L: usize> {
+    state: [Simd<u64, L>; 4],
+
+    /// The results of the last step, of which those from `position` on were not returned yet.
+    buffer: [u64; L],
+    position: usize,
+}
+
+impl<const L: usize> Xoshiro256PPSimd<L> {
+    pub fn from_lanes(lanes: [Xoshiro256PP; L]) -> Self {
+        let states = lanes.map(|lane| lane.state());
+
+        Self { state: std::array::from_fn(|i| Simd::from_array(std::array::from_fn(|lane| states[lane][i]))), buffer: [0; L], position: L }
+    }
+
+    /// Lane `k` is `generator` advanced by `k * 2^log2` steps, like `MultiLaneLinearGenerator::jumped`.
+    pub fn jumped(generator: Xoshiro256PP, log2: usize) -> Self { Self::from_lanes(MultiLaneLinearGenerator::jumped(generator, log2).lanes()) }
+
+    #[inline(always)]
+    fn rotate_left(x: Simd<u64, L>, k: u64) -> Simd<u64, L> { (x << Simd::splat(k)) | (x >> Simd::splat(64 - k)) }
+
+    /// One result of every lane, the scrambler and the permuter of `Xoshiro256PP` on whole vectors.
+    #[inline(always)]
+    fn step(&mut self) -> Simd<u64, L> {
+        let [mut s0, mut s1, mut s2, mut s3] = self.state;
+
+        let res = Self::rotate_left(s0 + s3, 23) + s0;
+
+        let t = s1 << Simd::splat(17);
+
+        s2 ^= s0;
+        s3 ^= s1;
+        s1 ^= s2;
+        s0 ^= s3;
+
+        s2 ^= t;
+
+        s3 = Self::rotate_left(s3, 45);
+
+        self.state = [s0, s1, s2, s3];
+        res
+    }
+}
+
+impl<const L: usize> UniformRandomBitGenerator for Xoshiro256PPSimd<L> {
+    type ResultType = u64;
+
+    fn min() -> Self::ResultType { u64::MIN }
+    fn max() -> Self::ResultType { u64::MAX }
+
+    fn generate(&mut self) -> Self::ResultType {
+        if self.position == L {
+            self.buffer = self.step().to_array();
+            self.position = 0;
+        }
+
+        let res = self.buffer[self.position];
+        self.position += 1;
+        res
+    }
+
+    fn fill(&mut self, dest: &mut [Self::ResultType]) {
+        let buffered = std::cmp::min(L - self.position, dest.len());
+        let (head, dest) = dest.split_at_mut(buffered);
+
+        head.copy_from_slice(&self.buffer[self.position..self.position + buffered]);
+        self.position += buffered;
+
+        let mut chunks = dest.chunks_exact_mut(L);
+
+        for chunk in &mut chunks {
+            self.step().copy_to_slice(chunk);
+        }
+
+        for v in chunks.into_remainder() {
+            *v = self.generate();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simd_matches_multi_lane() {
+        let mut scalar = Xoshiro256PP::new();
+        scalar.seed_from_result(17);
+
+        let mut generator = Xoshiro256PPSimd::<4>::jumped(scalar, 128);
+        let mut reference = Xoshiro256PPx4::jumped(scalar, 128);
+
+        let (mut values, mut expected) = ([0u64; 101], [0u64; 101]);
+        generator.fill(&mut values);
+        reference.fill(&mut expected);
+        assert_eq!(values, expected);
+
+        for _ in 0..10 {
+            assert_eq!(generator.generate(), reference.generate());
+        }
+    }
+}
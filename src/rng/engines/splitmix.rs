@@ -52,7 +52,7 @@ impl PartialEq for SplitMixParameters {
     }
 }
 
-impl ConstParamTy for SplitMixParameters {}
+impl std::marker::ConstParamTy_ for SplitMixParameters {}
 
 #[derive(Copy, Clone)]
 pub struct SplitMixEngine<T: UnsignedIntegral, const PARAMS: SplitMixParameters> {
@@ -92,7 +92,7 @@ impl<T: UnsignedIntegral, const PARAMS: SplitMixParameters> UniformRandomBitGene
 }
 
 impl<T: UnsignedIntegral, const PARAMS: SplitMixParameters> RandomNumberEngine for SplitMixEngine<T, PARAMS> {
-    fn discard(&mut self, _z: usize) { todo!() }
+    fn discard(&mut self, _z: u128) { todo!() }
 
     fn reset(&mut self) { self.seed_from_result(T::zero()); }
 
@@ -104,6 +104,16 @@ impl<T: UnsignedIntegral, const PARAMS: SplitMixParameters> RandomNumberEngine f
     fn seed_from_result(&mut self, v: Self::ResultType) { self.state = v; }
 }
 
+impl<T: UnsignedIntegral, const PARAMS: SplitMixParameters> SerializableEngine for SplitMixEngine<T, PARAMS> {
+    fn state_tag() -> String { format!("splitmix-u{}", T::DIGITS) }
+
+    fn state_words(&self) -> Vec<u128> { vec![self.state.as_to()] }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        Ok(Self { state: StateWords::new(words, 1)?.next()? })
+    }
+}
+
 pub type SplitMix32 = SplitMixEngine<u32, SPLITMIX32_PARAMS>;
 pub type SplitMix64 = SplitMixEngine<u64, SPLITMIX64_PARAMS>;
 
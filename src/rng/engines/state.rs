@@ -0,0 +1,334 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::rng::RandomNumberEngine;
+use crate::*;
+
+const MAGIC: [u8; 4] = *b"SRNG";
+const VERSION: u8 = 1;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum StateError {
+    BadMagic,
+    UnsupportedVersion(u8),
+    /// The state was saved from a different engine; holds the tags of both.
+    WrongEngine { expected: String, found: String },
+    InsufficientData,
+    TooMuchData,
+    BadValue(String),
+    /// The tag or the number of words is too long for the length fields of the binary encoding.
+    TooLong(String),
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            StateError::BadMagic => f.write_str("bad magic value"),
+            StateError::UnsupportedVersion(version) => f.write_fmt(format_args!("unsupported encoding version {}", version)),
+            StateError::WrongEngine { expected, found } => f.write_fmt(format_args!("expected the state of \"{}\", found \"{}\"", expected, found)),
+            StateError::InsufficientData => f.write_str("insufficient data"),
+            StateError::TooMuchData => f.write_str("too much data"),
+            StateError::BadValue(desc) => f.write_fmt(format_args!("bad value: {}", desc)),
+            StateError::TooLong(desc) => f.write_fmt(format_args!("too long to encode: {}", desc)),
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+/// Engines whose complete state can be saved and restored with bit-identical continuation.
+///
+/// There are two encodings, both starting with `state_tag()`:
+/// - `to_state_bytes`: the magic `SRNG`, a version byte, the tag prefixed with its length as a byte, the number of
+///   words as a little-endian `u16` and the words as unsigned LEB128. Longer tags or more words cannot be encoded.
+/// - `to_state_string`: the tag and the words in decimal, separated by single spaces, akin to `operator<<` of the
+///   standard library engines.
+pub trait SerializableEngine: RandomNumberEngine {
+    /// Identifies the engine and its word sizes, e.g. `pcg-xsh-rr-64-32`.
+    ///
+    /// Engines whose states have the same layout but continue differently have different tags, e.g. `xoshiro256+` and
+    /// `xoshiro256++`.
+    fn state_tag() -> String;
+
+    /// The complete state, in a fixed order.
+    fn state_words(&self) -> Vec<u128>;
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError>;
+
+    fn to_state_bytes(&self) -> Result<Vec<u8>, StateError> {
+        let tag = Self::state_tag();
+        let words = self.state_words();
+
+        let tag_length = u8::try_from(tag.len()).map_err(|_| StateError::TooLong(format!("a tag of {} bytes", tag.len())))?;
+        let count = u16::try_from(words.len()).map_err(|_| StateError::TooLong(format!("{} words", words.len())))?;
+
+        let mut ret = Vec::new();
+        ret.extend_from_slice(&MAGIC);
+        ret.push(VERSION);
+        ret.push(tag_length);
+        ret.extend_from_slice(tag.as_bytes());
+        ret.extend_from_slice(&count.to_le_bytes());
+
+        for word in words {
+            detail::write_leb128(&mut ret, word);
+        }
+
+        Ok(ret)
+    }
+
+    fn from_state_bytes(bytes: &[u8]) -> Result<Self, StateError> {
+        let mut reader = detail::ByteReader { bytes };
+
+        if reader.take(4)? != MAGIC {
+            return Err(StateError::BadMagic);
+        }
+
+        let version = reader.take(1)?[0];
+        if version != VERSION {
+            return Err(StateError::UnsupportedVersion(version));
+        }
+
+        let tag_length = reader.take(1)?[0] as usize;
+        let tag = String::from_utf8_lossy(reader.take(tag_length)?).into_owned();
+        detail::check_tag::<Self>(&tag)?;
+
+        let count = u16::from_le_bytes(reader.take(2)?.try_into().unwrap());
+        let words = (0..count).map(|_| reader.read_leb128()).collect::<Result<Vec<_>, _>>()?;
+
+        if !reader.bytes.is_empty() {
+            return Err(StateError::TooMuchData);
+        }
+
+        Self::from_state_words(&words)
+    }
+
+    fn to_state_string(&self) -> String {
+        std::iter::once(Self::state_tag())
+            .chain(self.state_words().into_iter().map(|word| word.to_string()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn from_state_str(s: &str) -> Result<Self, StateError> {
+        let mut tokens = s.split_whitespace();
+
+        detail::check_tag::<Self>(tokens.next().ok_or(StateError::InsufficientData)?)?;
+
+        let words = tokens
+            .map(|token| token.parse::<u128>().map_err(|err| StateError::BadValue(format!("\"{}\": {}", token, err))))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_state_words(&words)
+    }
+}
+
+/// Hands out the words of a state in order, checking ranges; used by the `from_state_words` implementations.
+pub(crate) struct StateWords<'a> {
+    words: &'a [u128],
+}
+
+impl<'a> StateWords<'a> {
+    pub fn new(words: &'a [u128], expected_count: usize) -> Result<Self, StateError> {
+        if words.len() < expected_count {
+            Err(StateError::InsufficientData)
+        } else if words.len() > expected_count {
+            Err(StateError::TooMuchData)
+        } else {
+            Ok(Self { words })
+        }
+    }
+
+    pub fn next<T: Integral>(&mut self) -> Result<T, StateError> {
+        let (&first, rest) = self.words.split_first().ok_or(StateError::InsufficientData)?;
+        self.words = rest;
+
+        T::from_scalar(first).ok_or_else(|| StateError::BadValue(format!("{} does not fit into {} bits", first, T::DIGITS)))
+    }
+
+    pub fn next_array<T: Integral, const N: usize>(&mut self) -> Result<[T; N], StateError> {
+        let mut ret = [T::zero(); N];
+        for v in &mut ret {
+            *v = self.next()?;
+        }
+
+        Ok(ret)
+    }
+}
+
+mod detail {
+    use super::*;
+
+    pub fn check_tag<E: SerializableEngine>(found: &str) -> Result<(), StateError> {
+        let expected = E::state_tag();
+
+        if found == expected {
+            Ok(())
+        } else {
+            Err(StateError::WrongEngine { expected, found: found.into() })
+        }
+    }
+
+    pub fn write_leb128(out: &mut Vec<u8>, mut v: u128) {
+        loop {
+            let byte = (v & 0x7F) as u8;
+            v >>= 7;
+
+            if v == 0 {
+                out.push(byte);
+                break;
+            }
+
+            out.push(byte | 0x80);
+        }
+    }
+
+    pub struct ByteReader<'a> {
+        pub bytes: &'a [u8],
+    }
+
+    impl<'a> ByteReader<'a> {
+        pub fn take(&mut self, count: usize) -> Result<&'a [u8], StateError> {
+            if self.bytes.len() < count {
+                return Err(StateError::InsufficientData);
+            }
+
+            let (ret, rest) = self.bytes.split_at(count);
+            self.bytes = rest;
+            Ok(ret)
+        }
+
+        pub fn read_leb128(&mut self) -> Result<u128, StateError> {
+            let mut ret = 0u128;
+
+            for shift in (0..128).step_by(7) {
+                let byte = self.take(1)?[0];
+                let bits = (byte & 0x7F) as u128;
+
+                if shift == 126 && bits > 3 {
+                    return Err(StateError::BadValue("LEB128 value does not fit into 128 bits".into()));
+                }
+
+                ret |= bits << shift;
+
+                if byte & 0x80 == 0 {
+                    return Ok(ret);
+                }
+            }
+
+            Err(StateError::BadValue("LEB128 value does not fit into 128 bits".into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::engines::*;
+    use crate::rng::*;
+    use super::*;
+
+    fn test_round_trip<E: SerializableEngine>(mut generator: E) {
+        for _ in 0..7 {
+            generator.generate();
+        }
+
+        let from_bytes = E::from_state_bytes(&generator.to_state_bytes().unwrap()).unwrap();
+        let from_string = E::from_state_str(&generator.to_state_string()).unwrap();
+
+        assert!(from_bytes == generator);
+        assert!(from_string == generator);
+
+        for (mut restored, mut original) in [(from_bytes, generator.clone()), (from_string, generator.clone())] {
+            for _ in 0..100 {
+                assert!(restored.generate() == original.generate());
+            }
+        }
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+        test_round_trip(generator);
+
+        let mut generator = Xoroshiro64SS::new();
+        generator.seed_from_result(0xDEADBEEF);
+        test_round_trip(generator);
+
+        let mut generator = SplitMix64::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE);
+        test_round_trip(generator);
+
+        test_round_trip(PCG32::new_with_stream(42, 54));
+        test_round_trip(PCG64::new_with_stream(0xCAFEBABE, 0xDEADBEEF));
+        test_round_trip(PCG64DXSM::new_with_stream(0xCAFEBABE, 0xDEADBEEF));
+
+        test_round_trip(Philox4x32::new_with_key([0xDEADBEEF, 0xCAFEBABE]));
+        test_round_trip(Threefry2x64::new_with_key([0xDEADBEEFCAFEBABE, 0xABADC0DECAFED00D]));
+
+        test_round_trip(ChaCha20::new_with_key_and_stream([1, 2, 3, 4, 5, 6, 7, 8], 9));
+        test_round_trip(ChaCha8U64::new_with_key_and_stream([1, 2, 3, 4, 5, 6, 7, 8], 9));
+
+        test_round_trip(DiscardBlockEngine::<_, 7, 3>::new(PCG32::new_with_stream(42, 54)));
+        test_round_trip(IndependentBitsEngine::<_, 48, u64>::new(PCG32::new_with_stream(42, 54)));
+        test_round_trip(ShuffleOrderEngine::<_, 16>::new(PCG32::new_with_stream(42, 54)));
+
+        test_round_trip(MinStdRand::new());
+        test_round_trip(MT19937_64::new());
+        test_round_trip(Ranlux24::new(Ranlux24Base::new()));
+        test_round_trip(KnuthB::new(MinStdRand0::new()));
+    }
+
+    #[test]
+    fn test_state_encoding_is_stable() {
+        let generator = PCG32::new_with_stream(42, 54);
+
+        assert_eq!(generator.to_state_string(), "pcg-xsh-rr-64-32 1753877967969059832 109");
+        assert_eq!(
+            generator.to_state_bytes().unwrap(),
+            [
+                b'S', b'R', b'N', b'G', 1, 16,
+                b'p', b'c', b'g', b'-', b'x', b's', b'h', b'-', b'r', b'r', b'-', b'6', b'4', b'-', b'3', b'2',
+                2, 0,
+                0xF8, 0x87, 0xB8, 0xE1, 0x82, 0xD7, 0xC1, 0xAB, 0x18,
+                0x6D,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_state_errors() {
+        let generator = PCG32::new_with_stream(42, 54);
+        let bytes = generator.to_state_bytes().unwrap();
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert_eq!(PCG32::from_state_bytes(&bad_magic).err(), Some(StateError::BadMagic));
+
+        let mut bad_version = bytes.clone();
+        bad_version[4] = 2;
+        assert_eq!(PCG32::from_state_bytes(&bad_version).err(), Some(StateError::UnsupportedVersion(2)));
+
+        assert!(matches!(PCG64::from_state_bytes(&bytes), Err(StateError::WrongEngine { .. })));
+        assert!(matches!(PCG64::from_state_str(&generator.to_state_string()), Err(StateError::WrongEngine { .. })));
+
+        assert_eq!(PCG32::from_state_bytes(&bytes[..bytes.len() - 1]).err(), Some(StateError::InsufficientData));
+        assert_eq!(PCG32::from_state_bytes(&[bytes.as_slice(), &[0]].concat()).err(), Some(StateError::TooMuchData));
+
+        assert_eq!(PCG32::from_state_str("pcg-xsh-rr-64-32 1").err(), Some(StateError::InsufficientData));
+        assert!(matches!(PCG32::from_state_str("pcg-xsh-rr-64-32 1 x"), Err(StateError::BadValue(_))));
+        assert!(matches!(PCG32::from_state_str("pcg-xsh-rr-64-32 1 2"), Err(StateError::BadValue(_))));
+        assert!(matches!(PCG32::from_state_str("pcg-xsh-rr-64-32 18446744073709551616 1"), Err(StateError::BadValue(_))));
+
+        // the same layout, but another engine
+        let mut generator = Xoroshiro128P::new();
+        generator.seed_from_result(17);
+        assert_eq!(Xoroshiro128P::state_tag(), "xoroshiro128-24-16-37+");
+        assert!(matches!(Xoroshiro128PP::from_state_str(&generator.to_state_string()), Err(StateError::WrongEngine { .. })));
+        assert!(matches!(Xoroshiro128SS::from_state_bytes(&generator.to_state_bytes().unwrap()), Err(StateError::WrongEngine { .. })));
+        assert!(matches!(Xoroshiro128P::from_state_str("xoroshiro128-24-16-37+ 0 0"), Err(StateError::BadValue(_))));
+
+        // more words than the binary encoding can count
+        let generator = Box::new(ShuffleOrderEngine::<_, 65536>::new(PCG32::new_with_stream(42, 54)));
+        assert!(matches!(generator.to_state_bytes(), Err(StateError::TooLong(_))));
+    }
+}
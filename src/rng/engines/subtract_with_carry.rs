@@ -0,0 +1,193 @@
+use crate::rng::*;
+use super::*;
+
+/// `x(i) = (x(i - S) - x(i - R) - carry) mod 2^W` like `std::subtract_with_carry_engine` (Marsaglia and Zaman, "A New
+/// Class of Random Number Generators", 1991), the base of `ranlux24` and `ranlux48`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SubtractWithCarryEngine<T: UnsignedIntegral, const W: u32, const S: usize, const R: usize> {
+    /// The last `R` values, `state[index]` is the oldest one.
+    state: [T; R],
+    carry: bool,
+    index: usize,
+}
+
+impl<T: UnsignedIntegral, const W: u32, const S: usize, const R: usize> SubtractWithCarryEngine<T, W, S, R> {
+    pub const DEFAULT_SEED: u64 = 19780503;
+
+    pub fn new() -> Self {
+        assert!(0 < S && S < R, "the short lag is below the long lag");
+        assert!(0 < W && W <= T::DIGITS && W <= 64, "a word has between 1 and 64 bits of the result type");
+
+        let mut ret = Self { state: [T::zero(); R], carry: false, index: 0 };
+        ret.seed_from_result(T::as_from(Self::DEFAULT_SEED as u128));
+        ret
+    }
+
+    fn mask() -> u128 { (1u128 << W) - 1 }
+
+    /// Starts over from the words that `next_word` gives for each value, least significant first.
+    fn seed_words<F: FnMut() -> u32>(&mut self, mut next_word: F) {
+        let words = W.div_ceil(32);
+
+        for v in &mut self.state {
+            let x = (0..words).fold(0u128, |x, j| x | (next_word() as u128) << (32 * j));
+            *v = T::as_from(x & Self::mask());
+        }
+
+        self.carry = self.state[R - 1] == T::zero();
+        self.index = 0;
+    }
+}
+
+impl<T: UnsignedIntegral, const W: u32, const S: usize, const R: usize> UniformRandomBitGenerator for SubtractWithCarryEngine<T, W, S, R> {
+    type ResultType = T;
+
+    fn min() -> Self::ResultType { T::zero() }
+
+    fn max() -> Self::ResultType { T::as_from(Self::mask()) }
+
+    fn generate(&mut self) -> Self::ResultType {
+        let short: u128 = self.state[(self.index + R - S) % R].as_to();
+        let long: u128 = self.state[self.index].as_to();
+        let subtrahend = long + self.carry as u128;
+
+        let x = if short >= subtrahend { short - subtrahend } else { (Self::mask() + 1) - subtrahend + short };
+        self.carry = short < subtrahend;
+
+        self.state[self.index] = T::as_from(x);
+        self.index = (self.index + 1) % R;
+
+        T::as_from(x)
+    }
+}
+
+impl<T: UnsignedIntegral, const W: u32, const S: usize, const R: usize> RandomNumberEngine for SubtractWithCarryEngine<T, W, S, R> {
+    fn discard(&mut self, z: u128) {
+        for _ in 0..z {
+            self.generate();
+        }
+    }
+
+    fn reset(&mut self) { *self = Self::new(); }
+
+    /// Takes the words that `seed(seed_seq&)` would take from the sequence, `ceil(W / 32)` per value.
+    fn seed_from<G: UniformRandomBitGenerator<ResultType = u32>>(&mut self, generator: &mut G) {
+        self.seed_words(|| generator.generate());
+    }
+
+    /// Like the standard, the values come from a `LinearCongruentialEngine<_, 40014, 0, 2147483563>` seeded with
+    /// `v`, or with the default seed if `v` is zero.
+    fn seed_from_result(&mut self, v: Self::ResultType) {
+        let v: u128 = v.as_to();
+        let seed = if v == 0 { Self::DEFAULT_SEED } else { v as u64 };
+
+        let mut lcg = LinearCongruentialEngine::<u64, 40014, 0, 2147483563>::new();
+        lcg.seed_from_result(seed);
+
+        self.seed_words(|| lcg.generate() as u32);
+    }
+}
+
+impl<T: UnsignedIntegral, const W: u32, const S: usize, const R: usize> SerializableEngine for SubtractWithCarryEngine<T, W, S, R> {
+    fn state_tag() -> String { format!("subtract-with-carry-{}-{}-{}", W, S, R) }
+
+    /// The last values, the carry and the index of the oldest value.
+    fn state_words(&self) -> Vec<u128> {
+        let values = self.state.iter().map(|&v| v.as_to());
+        values.chain([self.carry as u128, self.index as u128]).collect()
+    }
+
+    fn from_state_words(words: &[u128]) -> Result<Self, StateError> {
+        let mut words = StateWords::new(words, R + 2)?;
+
+        let state: [T; R] = words.next_array()?;
+        let carry: u128 = words.next()?;
+        let index: u128 = words.next()?;
+
+        if state.iter().any(|&v| v > Self::max()) {
+            return Err(StateError::BadValue(format!("the values of a subtract with carry engine have {} bits", W)));
+        }
+
+        if carry > 1 {
+            return Err(StateError::BadValue("the carry is either 0 or 1".into()));
+        }
+
+        if index >= R as u128 {
+            return Err(StateError::BadValue(format!("index {} into {} values", index, R)));
+        }
+
+        Ok(Self { state, carry: carry == 1, index: index as usize })
+    }
+}
+
+/// `std::ranlux24_base`
+pub type Ranlux24Base = SubtractWithCarryEngine<u32, 24, 10, 24>;
+
+/// `std::ranlux48_base`
+pub type Ranlux48Base = SubtractWithCarryEngine<u64, 48, 5, 12>;
+
+/// `std::ranlux24`, luxury level 3 of Lüscher's RANLUX.
+pub type Ranlux24 = DiscardBlockEngine<Ranlux24Base, 223, 23>;
+
+/// `std::ranlux48`, luxury level 4 of Lüscher's RANLUX.
+pub type Ranlux48 = DiscardBlockEngine<Ranlux48Base, 389, 11>;
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_tables;
+    use crate::rng::engines::util::WordSequence;
+    use super::*;
+
+    #[test]
+    fn test_ranlux() {
+        // [rand.predef]: the 10000th value of a default constructed engine
+        let mut generator = Ranlux24Base::new();
+        generator.discard(9999);
+        assert_eq!(generator.generate(), 7937952);
+
+        let mut generator = Ranlux48Base::new();
+        generator.discard(9999);
+        assert_eq!(generator.generate(), 61839128582725);
+
+        let mut generator = Ranlux24::new(Ranlux24Base::new());
+        generator.discard(9999);
+        assert_eq!(generator.generate(), 9901578);
+
+        let mut generator = Ranlux48::new(Ranlux48Base::new());
+        generator.discard(9999);
+        assert_eq!(generator.generate(), 249142670248501);
+
+        generator.seed_from(&mut WordSequence::new());
+        assert_eq!([0; 3].map(|_| generator.generate()), [71938590565838, 126090700733197, 30707338872910]);
+
+        let mut generator = Ranlux24Base::new();
+        generator.seed_from(&mut WordSequence::new());
+        assert_eq!([0; 3].map(|_| generator.generate()), [2287054, 82286, 7158542]);
+
+        generator.reset();
+        assert!(generator == Ranlux24Base::new());
+    }
+
+    #[test]
+    fn test_ranlux_consistency() {
+        let mut generator = Ranlux24Base::new();
+        test_tables::TABLE_RANLUX24_BASE_SEED_0.test(&mut generator);
+        test_tables::TABLE_RANLUX24_BASE_SEED_1.test(&mut generator);
+        test_tables::TABLE_RANLUX24_BASE_SEED_2.test(&mut generator);
+
+        let mut generator = Ranlux48Base::new();
+        test_tables::TABLE_RANLUX48_BASE_SEED_0.test(&mut generator);
+        test_tables::TABLE_RANLUX48_BASE_SEED_1.test(&mut generator);
+        test_tables::TABLE_RANLUX48_BASE_SEED_2.test(&mut generator);
+
+        let mut generator = Ranlux24::new(Ranlux24Base::new());
+        test_tables::TABLE_RANLUX24_SEED_0.test(&mut generator);
+        test_tables::TABLE_RANLUX24_SEED_1.test(&mut generator);
+        test_tables::TABLE_RANLUX24_SEED_2.test(&mut generator);
+
+        let mut generator = Ranlux48::new(Ranlux48Base::new());
+        test_tables::TABLE_RANLUX48_SEED_0.test(&mut generator);
+        test_tables::TABLE_RANLUX48_SEED_1.test(&mut generator);
+        test_tables::TABLE_RANLUX48_SEED_2.test(&mut generator);
+    }
+}
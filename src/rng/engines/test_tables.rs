@@ -947,4 +947,1905 @@ pub(crate) const JUMP_TABLE_XOSHIRO256SS_192: JumpConsistencyTestTable<u64, 128u
         (0xF69946F97D475623_u64, 0x6DDC18992AF07B15_u64), (0x632B5B12E2460020_u64, 0xDC4B2850D51A7B13_u64), (0xD7B10D74B0CE6588_u64, 0x89A8886FA15FD8CD_u64), (0x1E3EB0715DA54C71_u64, 0x8D38EC8AF25EF2D5_u64),
         (0x0E8A6C0E966FAF92_u64, 0x704F94D729F073E8_u64), (0x096ACA7A527382C0_u64, 0xE156269863DD15A8_u64), (0x1E6AFCFDA68F08C1_u64, 0xF1CA64D756D7CBAB_u64), (0x90A94AD9F12D50FE_u64, 0x23853896C27767FC_u64),
     ],
-};
\ No newline at end of file
+};
+pub(crate) const TABLE_PCG32_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0x00000000_u32,
+    table: [
+        0xE823A24E_u32, 0x7A7ECBD9_u32, 0x89FD6C06_u32, 0xAE646AA8_u32, 0xCD3CF945_u32, 0x6204B303_u32, 0x198C8585_u32, 0x49FCE611_u32, 0xD1E9297A_u32, 0x142D9440_u32, 0xEE75F56B_u32, 0x473A9117_u32, 0xE3A45903_u32, 0xBCE807A1_u32, 0xE54E5F4D_u32, 0x497D6C51_u32,
+        0x61829166_u32, 0xA740474B_u32, 0x031912A8_u32, 0x9DE3DEFA_u32, 0xD266DBF1_u32, 0x0F38BEBB_u32, 0xEC3C4F65_u32, 0x07C5057D_u32, 0xBBCE03C8_u32, 0xFD2AC7A8_u32, 0xFFCF4773_u32, 0x5B10AFFB_u32, 0xEDE1C842_u32, 0xE22B01B7_u32, 0xDA133C8C_u32, 0xAF89B0F4_u32,
+        0x25D1B8BC_u32, 0x9F625482_u32, 0x7BFD6882_u32, 0x2E2210C0_u32, 0x2C8FB9A6_u32, 0x42CB3B83_u32, 0x40CE0DAB_u32, 0x644A3510_u32, 0x36230EF2_u32, 0xE2CB6D43_u32, 0x1012B343_u32, 0x746C6C9F_u32, 0x36714CF8_u32, 0xED1F5026_u32, 0x8BBBF83E_u32, 0xE98710F4_u32,
+        0x8A2AFA36_u32, 0x09035349_u32, 0x6DC1A487_u32, 0x682B634B_u32, 0xC106794F_u32, 0x7DD78BEB_u32, 0x628C262B_u32, 0x852FB232_u32, 0xB153AC4C_u32, 0x4F169D1B_u32, 0xA69AB774_u32, 0x4BD4B6F2_u32, 0xDC351DD3_u32, 0x93FF3C8C_u32, 0xA30819AB_u32, 0xFF07758C_u32,
+        0x5AB13C62_u32, 0xD16D7FB5_u32, 0xC4950FFA_u32, 0xD309AE49_u32, 0xB9677A87_u32, 0x4464E317_u32, 0x90DC44F1_u32, 0xC694C1D4_u32, 0x1D5E1168_u32, 0xADF37A2D_u32, 0xDA38990D_u32, 0x1EC4BD33_u32, 0x36CA25CE_u32, 0xFA0DC76A_u32, 0x968A9D43_u32, 0x6950AC39_u32,
+        0xDD3276BC_u32, 0x06D5A71E_u32, 0x1F6F282D_u32, 0x5C626C62_u32, 0xDDE3FC31_u32, 0x152194CE_u32, 0xC35ED14C_u32, 0xB1F7224E_u32, 0x47F76BB8_u32, 0xB34FDD08_u32, 0x7011395E_u32, 0x162D2A49_u32, 0x0D1BF09F_u32, 0x9428A952_u32, 0x03C5C344_u32, 0xD3525616_u32,
+        0x7816FFF3_u32, 0x6BCEB8A8_u32, 0x8345A081_u32, 0x366420FD_u32, 0x182ABEDA_u32, 0x70F82745_u32, 0xAF15DED8_u32, 0xC7F52CA2_u32, 0xA98DB9C5_u32, 0x919D99BA_u32, 0x9C376C1C_u32, 0xED8D34C2_u32, 0x716AE9F5_u32, 0xEF062FA5_u32, 0xEE3B6C56_u32, 0x52325658_u32,
+        0x61AFA9C3_u32, 0xFDAF02F0_u32, 0x961CF3AB_u32, 0x9F291565_u32, 0x4FBF3045_u32, 0x0590C899_u32, 0xDE901385_u32, 0x45005FFB_u32, 0x509DB162_u32, 0x262FA941_u32, 0x4C421653_u32, 0x4B17C21E_u32, 0xEA0D1530_u32, 0xDE803845_u32, 0x61BFD515_u32, 0x438523EF_u32,
+    ],
+};
+pub(crate) const TABLE_PCG32_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0xC3B00CCB_u32, 0xE7CC54A7_u32, 0x20D2F15A_u32, 0x968EE6DD_u32, 0xD1D281FB_u32, 0x17F8C47F_u32, 0x9E9AA07B_u32, 0x50F95B42_u32, 0x2BA43A66_u32, 0x825F44BE_u32, 0x1AAF4368_u32, 0x8FDB04E2_u32, 0x281734A7_u32, 0xA1993E8F_u32, 0x17FC63D4_u32, 0x09D0D2FD_u32,
+        0x45008EEF_u32, 0x760E71C8_u32, 0x1670EA40_u32, 0x38C653CD_u32, 0x37D4AC81_u32, 0x4769A3E0_u32, 0x8F8303A7_u32, 0x73B9D50F_u32, 0x3AAF295D_u32, 0xE07B5F49_u32, 0x9A46D588_u32, 0x975EB33F_u32, 0xE980FBB7_u32, 0xF857563D_u32, 0x0E30F108_u32, 0xA0054538_u32,
+        0xC559DBDF_u32, 0x666CF8A8_u32, 0x4F3D4A48_u32, 0x6AC34FCF_u32, 0xB71C23E4_u32, 0xDD436DF1_u32, 0x985B09C7_u32, 0x7B045804_u32, 0x89E0F4B8_u32, 0xD22DBB3E_u32, 0x63A07221_u32, 0x779485D8_u32, 0x4FE49D45_u32, 0xE6635ACC_u32, 0xC9C2780D_u32, 0x65E1FD79_u32,
+        0x0E17C56F_u32, 0x3B63F826_u32, 0x911590CC_u32, 0x8183E5C7_u32, 0x8AAF8552_u32, 0xDADD7A48_u32, 0x0C0F7A82_u32, 0x6C6BF22A_u32, 0x8BD92817_u32, 0x56F661E2_u32, 0xFEB57D6D_u32, 0xF7095507_u32, 0xD66894E9_u32, 0x68488674_u32, 0x41CC23A3_u32, 0xBCEADB51_u32,
+        0xBFB2AB91_u32, 0xA8824706_u32, 0x33DC137A_u32, 0xDBCFBF74_u32, 0xB0E4501E_u32, 0xE0FAE0B0_u32, 0x9DEFB396_u32, 0x22FCD8CA_u32, 0xD8848EE8_u32, 0x576FBA00_u32, 0x72CB3126_u32, 0xD56A6746_u32, 0x7C464754_u32, 0x4F65DD96_u32, 0xA60DB280_u32, 0x92EE6215_u32,
+        0xD056E584_u32, 0xFE673D3D_u32, 0x0E23D253_u32, 0x20AC39D4_u32, 0xF3D6D538_u32, 0x6498C951_u32, 0xFAE8DA0C_u32, 0x03FF4611_u32, 0x23E2713C_u32, 0xCF40A315_u32, 0x17A0C5E5_u32, 0xC6FD745A_u32, 0xC8FB6A65_u32, 0x5FFA256A_u32, 0x1FCD5852_u32, 0x997EB3F1_u32,
+        0x7DA8E0AA_u32, 0x7FEC43D4_u32, 0xC1867ADF_u32, 0xF975D586_u32, 0x9A79E02F_u32, 0xB8D8CCA5_u32, 0x238643B7_u32, 0xB1DC068F_u32, 0x42F526B9_u32, 0x7A5AC413_u32, 0x66B624AF_u32, 0xD34D0A58_u32, 0xA3D03921_u32, 0xB352C1E8_u32, 0x2D7F757C_u32, 0x60095837_u32,
+        0xF89907B8_u32, 0x7E0DA332_u32, 0x676F386A_u32, 0xA31B4176_u32, 0x70FDE5ED_u32, 0x31ACFEA3_u32, 0x7AB032F9_u32, 0xA35424E1_u32, 0x4FC70FC6_u32, 0xEA4E7F39_u32, 0x2D159153_u32, 0xFDE65907_u32, 0x012643C2_u32, 0xF6B70C28_u32, 0xA47AAEA5_u32, 0xC0AF088C_u32,
+    ],
+};
+pub(crate) const TABLE_PCG32_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0xF6D284B5_u32, 0x852783CC_u32, 0x96F64E37_u32, 0x3546321F_u32, 0xC7B5E13D_u32, 0x384B5143_u32, 0x816D9DDB_u32, 0xDB2A4088_u32, 0x4D173696_u32, 0xBB6B92DB_u32, 0xE73E41E0_u32, 0x51D5F52C_u32, 0x0E3E7EE8_u32, 0xDA240B55_u32, 0x0A0B1C07_u32, 0x8C339994_u32,
+        0x7F68ED07_u32, 0xA64CAC25_u32, 0xC1F7F8AA_u32, 0x864BA5E3_u32, 0x62851271_u32, 0x4A1E42B4_u32, 0xE1A4BA27_u32, 0x8B7C25D7_u32, 0x48441DC4_u32, 0x7E33A5E9_u32, 0x3B824432_u32, 0x7A410C09_u32, 0x64C0FA6B_u32, 0xF7C60CB9_u32, 0x142792B9_u32, 0x28F25B56_u32,
+        0x75F4F683_u32, 0xAAC68ABF_u32, 0x39C5FF16_u32, 0x117A0FAB_u32, 0xB7CFE749_u32, 0x43D85E66_u32, 0x966CB727_u32, 0xA529E27D_u32, 0x77B0115C_u32, 0x8BD1B063_u32, 0xB485A732_u32, 0x9370E7D9_u32, 0x18FDCE6B_u32, 0xC9D03E2B_u32, 0x444A47F5_u32, 0x09125345_u32,
+        0x746E191A_u32, 0x523FB026_u32, 0xD708C83A_u32, 0xD0E40431_u32, 0x653C44C4_u32, 0xC4EA5126_u32, 0x90316979_u32, 0x544F2B7A_u32, 0x824B735E_u32, 0x4873AECA_u32, 0x3B3420DF_u32, 0x8218AEEC_u32, 0x2C3F54B6_u32, 0x9E27462D_u32, 0x52C31C85_u32, 0x9CA71FD0_u32,
+        0x94DA7E19_u32, 0x77ADB983_u32, 0x1678F2BB_u32, 0x6E419904_u32, 0xEF5F200D_u32, 0x66886D6A_u32, 0x60C90D3E_u32, 0xC81F0EC5_u32, 0x7ABDE7A8_u32, 0x73D7045A_u32, 0x09EC99E1_u32, 0xD9BD35D6_u32, 0xE6F674B9_u32, 0xBE69F52F_u32, 0xB72E58FA_u32, 0x9097CD4D_u32,
+        0xB9EDF668_u32, 0x0A565F4E_u32, 0x2943C628_u32, 0x65069184_u32, 0xEB1408BD_u32, 0xA5ABA918_u32, 0x8C31B028_u32, 0xEA9E8143_u32, 0x222DFEC2_u32, 0x19AEB142_u32, 0x37814FAB_u32, 0xA2F35437_u32, 0x856E35BD_u32, 0x01954396_u32, 0x3F7EBA82_u32, 0x187A9F9E_u32,
+        0xB6473328_u32, 0x1FB83FBE_u32, 0xD8B9F753_u32, 0x83C82431_u32, 0xD6814C0B_u32, 0x1E366759_u32, 0x031F5754_u32, 0xCC58FD17_u32, 0x2FADBCA2_u32, 0x53DC1284_u32, 0xD150FAB2_u32, 0x21F811A9_u32, 0x7EDCEBA3_u32, 0x6A00CC40_u32, 0x7B989177_u32, 0x1C198F34_u32,
+        0x30077907_u32, 0xBC19642D_u32, 0xCA41F19B_u32, 0xA62F1437_u32, 0x89BAD0E3_u32, 0xC44E5ADF_u32, 0xE43D4993_u32, 0x8362FF71_u32, 0x10958D55_u32, 0x491C644A_u32, 0x98044898_u32, 0x85708905_u32, 0x64CD6E02_u32, 0xD65CCC0B_u32, 0x6DB68154_u32, 0x21ACE459_u32,
+    ],
+};
+pub(crate) const TABLE_PCG64_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x01070196E695F8F1_u64, 0x703EC840C59F4493_u64, 0xE54954914B3A44FA_u64, 0x96130FF204B9285E_u64, 0x7D9FDEF535CEB21A_u64, 0x666FEED42E1219A0_u64, 0x981F685721C8326F_u64, 0xAD80710D6EAB4DDA_u64,
+        0xE202C480B037A029_u64, 0x5D3390EAEDD907E2_u64, 0x0756BEFB39C6B8AA_u64, 0x1FB44BA6634D62A3_u64, 0x8D20423662426642_u64, 0x34EA910167A39FB4_u64, 0x93010B43A80D0AB6_u64, 0x663DB08A98FC568A_u64,
+        0x720B0A1335956FAE_u64, 0x2C35483E31E1D3BA_u64, 0x429F39776337409D_u64, 0xB46D99E638687344_u64, 0x105370B96AEDCAEE_u64, 0x3999E92F811CFF71_u64, 0xD230F8BCB591CFC9_u64, 0x0DCE3DB2BA7BDEA5_u64,
+        0xCF2F52C91EEC99AF_u64, 0x2BC7C24A8B998A39_u64, 0xBD8AF1B0D599A19C_u64, 0x56BC45ABC66059F5_u64, 0x170A46DC170F7F1E_u64, 0xC25DAF5277B85FAD_u64, 0xE629C2E0C948EADB_u64, 0x1720A796915542ED_u64,
+        0x22FB0CAA4F909951_u64, 0x7E0C0F4175ACD83D_u64, 0xD9FCAB37FF2A860C_u64, 0xAB2280FB2054BAD1_u64, 0x58E8A06F37FA9E99_u64, 0xC3A52A30B06528C7_u64, 0x0175F773A13FC1BD_u64, 0x731CFC584B00E840_u64,
+        0x404CC7B2648069CB_u64, 0x5BC29153B0B7F783_u64, 0x771310A38CC999D1_u64, 0x766A572F0A71A916_u64, 0x90F450FB4FC48348_u64, 0xF080EA3E1C7B1A0D_u64, 0x15471A4507D66A44_u64, 0x7D58E55A78F3DF69_u64,
+        0x0130A094576AC99C_u64, 0x46669CB2D04B1D87_u64, 0x17AB5BED20191840_u64, 0x95B177D260ADFF3E_u64, 0x025FB624B6EE4C07_u64, 0xB35DE4330154A95F_u64, 0xE8510FFF67E24C79_u64, 0x132C3CBCD76ED2D3_u64,
+        0x35E7CC145A093904_u64, 0x9F5B5B5F81583B79_u64, 0x3EE749A533966233_u64, 0x4AF85886CDEDA8CD_u64, 0x0CA5380ECB3EF3AA_u64, 0x4F674EB7661D3192_u64, 0x88A29AAD00CD7733_u64, 0x70B627CA045FFAC6_u64,
+        0x5912B43EA887623D_u64, 0x95DC9FC6F62CF221_u64, 0x926081A12A5C905B_u64, 0x9C57D4CD7DFCE651_u64, 0x85AB2CBF23E3BB5D_u64, 0xC5CD669F63023152_u64, 0x3067BE0FAD5D898E_u64, 0x12B56F444CB53D05_u64,
+        0xBC2E5A640C3434FC_u64, 0x9280BFF0E4613FE1_u64, 0x98819094C528743E_u64, 0x999D1C98D829DF33_u64, 0x9FF82A012DC89242_u64, 0xF99183ED39C8BE94_u64, 0xF0F59161CD421C55_u64, 0x3C705730C2F6C48D_u64,
+        0x66AD85C6E9278A61_u64, 0x2A3428E4A428D5D0_u64, 0x79207D68FD04940D_u64, 0xEA7F2B402EDC8430_u64, 0xA06B419AC857F63B_u64, 0xCB1DD0E6FBC47E1C_u64, 0x4F55229200ADA6A4_u64, 0x9647B5E6359C927F_u64,
+        0x30BF8F9197C7EFE5_u64, 0xA79519529CC384D0_u64, 0xBB22C4F339AD6497_u64, 0xD7B9782F59D14175_u64, 0x0DFF12FFF2EC0118_u64, 0xA331AD8305343A7C_u64, 0x48DAD7E3F17E0862_u64, 0x324C6FB3FD3C9665_u64,
+        0xF0E4350E7933DFC4_u64, 0x7CCDA2F30B8B03B6_u64, 0xA0AFC6179005DE40_u64, 0xEE65DA6D063B3A30_u64, 0xB9506F42F2BFE87A_u64, 0xC9A2E26B0EF5BAA0_u64, 0x39FA9D4F495011D6_u64, 0xBECC21A45D023948_u64,
+        0x6BF484C6593F737F_u64, 0x8065E0070CADC3B7_u64, 0x9EF617ED8D419799_u64, 0xAC692CF8C233DD15_u64, 0xD2ED87583C4EBB98_u64, 0xAD95BA1BEBFEDC62_u64, 0x9B60B160A8264E43_u64, 0x0BC8C45F71FCF25B_u64,
+        0x4A78035CDF1C9931_u64, 0x4602DC106667E029_u64, 0xB335A3C250498AC8_u64, 0x0256EBC4DF20CAB8_u64, 0x0C61EFD153F0C8D9_u64, 0xE5D0150A4F806F88_u64, 0x99D6521D351E7D87_u64, 0x8D4888C9F80F4325_u64,
+        0x106C5735C1BA868D_u64, 0x73414881B880A878_u64, 0x808A9A58A3064751_u64, 0x339A29F3746DE3D5_u64, 0x5410D7FA4F873896_u64, 0xD84623C81D7B8A03_u64, 0x1F7C7E7A7F47F462_u64, 0xDA624BCB7CAD608C_u64,
+    ],
+};
+pub(crate) const TABLE_PCG64_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x772FC80A8529021F_u64, 0x5DA44E42895D2D94_u64, 0x8AAA32BF88B0AF9A_u64, 0xC0181B4F403062BC_u64, 0x71A37BAA8EF7CF1C_u64, 0xB5E4955EFF088B0F_u64, 0xB210F0B04884B3A1_u64, 0xACF51BD19B06A339_u64,
+        0xD0D663B702453310_u64, 0x5C049F3199AF0A4C_u64, 0xF9D4CA82BC7920C1_u64, 0xE1D0BC19335726DD_u64, 0x414AD6DCB33FC444_u64, 0xC7A4D377AC836997_u64, 0x76062AEE53F0260A_u64, 0x406C82B7B4F30D63_u64,
+        0x34229258AD94193C_u64, 0x60BFF3201C79763B_u64, 0x09AA2155FCD106CE_u64, 0x8E4B3CEB3C9EAF04_u64, 0x8FBC3F6822DCED24_u64, 0x9602C32E296B29D8_u64, 0xD33EFC84D88E2ACF_u64, 0x5A28C4F0A8FC6C73_u64,
+        0xA6F0E42A66C076C8_u64, 0x59A956DD873651B7_u64, 0x246512005919F335_u64, 0xC21605E8D3BE7318_u64, 0x9ADF9507DD704E5B_u64, 0x131D0F8577E11AFB_u64, 0x07BB3FEA03278E1F_u64, 0x99A2938EF25CCDFA_u64,
+        0x9DF57F7C6B141968_u64, 0xF97ECA1AD39BA256_u64, 0xB6C7F5892B748F05_u64, 0x7F65953E673BAB08_u64, 0xEC1E246D987B174D_u64, 0x2DDE6256CB054750_u64, 0xA33BABDB2E28D284_u64, 0x72235E75FBBB7211_u64,
+        0xE9B6E9675E0676E4_u64, 0xBF28C53BAE382112_u64, 0x276CCE77DC6A4488_u64, 0x6E0F59997B41442E_u64, 0xB84C44D2E3781B54_u64, 0xBA3393BADC6EAD8A_u64, 0x4FCDB59CC990F190_u64, 0xAF96152C23755C16_u64,
+        0x6D214311D6B5224A_u64, 0xD09C6E9CDA2F2452_u64, 0x8F4E7264794AD91F_u64, 0xD069988520ED6074_u64, 0xB809DD050CF1BBE5_u64, 0xA4147210150F005F_u64, 0x4DFF9C4C6A252C03_u64, 0xD119A3F859CCA14E_u64,
+        0x1A03C56CD3DA5C0E_u64, 0xF46287D3A7795E8D_u64, 0x4638F1E7908BFC25_u64, 0x30379DA34417399E_u64, 0x8BD98E5859EF10EE_u64, 0x767C88A3BDADA498_u64, 0xF52952D87CD56A12_u64, 0x1E61A193D5626029_u64,
+        0x0255F8DCEBB8E2EC_u64, 0x13E5F8D069FD7305_u64, 0xDB0E69CFC6B4F880_u64, 0xE1B4C4EF1E5D24DD_u64, 0xDF9E8F5E92C3AB2E_u64, 0xC7059426F67C0631_u64, 0x50CC14D8D9CFBE20_u64, 0xA07CB07D664AEB7B_u64,
+        0x241480E3F0CC5AF5_u64, 0xFFDADABF4D8E6A45_u64, 0x07699FE687334D8C_u64, 0x42E8CF96993E1B6A_u64, 0x71D8AE2AE458D325_u64, 0xFA0956B03ABDE8F8_u64, 0x2C1F64F57F9E8803_u64, 0xF40D9A2DD83D5891_u64,
+        0xA836328894AA766D_u64, 0xFF78864A21B2F195_u64, 0x05806F74F8EC7D09_u64, 0xFA64ED38610E1A73_u64, 0xB47E0490509F092F_u64, 0x0873CE623699DA2F_u64, 0xCC48C578383F4414_u64, 0x250B7882B3BC7DC0_u64,
+        0x20D1098188DC4F1C_u64, 0xD7AB9DA3B3ECE539_u64, 0xDC0B9A2F19D98C66_u64, 0x330EB2F188DA2E67_u64, 0x40F6DC95FC0259CA_u64, 0x10F80F98346802A4_u64, 0xB93301E07449FE5A_u64, 0x780F02FD17754402_u64,
+        0x20F1D3CA9BBB2856_u64, 0xDCF5501C4A489157_u64, 0x02C2D1229196D40C_u64, 0xFBA54A4F24F6EB71_u64, 0x9421C47854044695_u64, 0x26976D1E779A9806_u64, 0x8B0C2825AD12D3AB_u64, 0x465C533B8C80B19D_u64,
+        0xCC6F41430EF6E3DD_u64, 0x333270972C1CA049_u64, 0xB26C536D669AED93_u64, 0x740E155684679DDF_u64, 0x5E3C9A0438427F6E_u64, 0x3B05934661275873_u64, 0xFE5A55B1836DDC37_u64, 0xD03385A9E08AA59C_u64,
+        0x124C87F4CF69FA79_u64, 0x7E7E88F218E650C2_u64, 0x81D095BE97636A43_u64, 0xD7424A409FB0A89E_u64, 0xDE584567332B48CE_u64, 0x447AD7657955D999_u64, 0xDCBF35B8FB34E64A_u64, 0x43699C45C78D315F_u64,
+        0x514F7BC125AEABBD_u64, 0xFD6BE49EE49DB105_u64, 0xDE2BF7F3B98A6B89_u64, 0xBFAAF3325C486565_u64, 0x74ADA2BDE73A5EF7_u64, 0x432881E565864EC6_u64, 0x7CE1857B0E3125F6_u64, 0x4904B27403A11E45_u64,
+    ],
+};
+pub(crate) const TABLE_PCG64_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0xD827E00E815AF951_u64, 0x18FDCA01199357A7_u64, 0x9CA64409648C73EB_u64, 0xB73B2FBFBEC7EC94_u64, 0x743E6B478AE8CBD8_u64, 0x2A14B27E962D6B03_u64, 0x4DBCB37369280805_u64, 0x28E5EE39A52B2955_u64,
+        0xB46FF7B653E94E83_u64, 0xBF5A88660DB48B4A_u64, 0x2D797D53C1AF7B1F_u64, 0x254F0924296437F0_u64, 0x7037A3702C54560C_u64, 0xAA1039DC969DB83F_u64, 0x5AD69503FC1C774F_u64, 0x95423888B460681F_u64,
+        0x254A7EA125DDC249_u64, 0x3C6CE60C42ECAE0A_u64, 0xF55132B9B4DC2880_u64, 0xA61FD60C817A6112_u64, 0x6B2D48BE84872A0D_u64, 0xB8CAAF2035B5BCF9_u64, 0xF4F0F7AD0C9B9AD5_u64, 0x93859AC04F6CE7C7_u64,
+        0x340ED9640099AE8F_u64, 0x2E54E928E0A4F8D5_u64, 0xD1E88FC6E31B72CB_u64, 0x80A2A093367C5398_u64, 0xEA6328AF28EDB9BF_u64, 0x05CB668CF2E1D298_u64, 0x7884B9B524C01348_u64, 0xBF978580D096CE97_u64,
+        0x926E9A39FB0EE2FE_u64, 0x801C30DB187638C1_u64, 0xE63B9333D9F26806_u64, 0x9101E5387616286D_u64, 0x26C30C27A9C45F11_u64, 0xCAC8F133D097C70F_u64, 0xD988E6232B79D9EB_u64, 0xA9728D5653F25DE2_u64,
+        0x06F63F523940F0EA_u64, 0x8D3CF4F31BDB2E3C_u64, 0x3231087C8361E7AA_u64, 0xD6538F594CD711B2_u64, 0xF01ED1F5B425DAA3_u64, 0x5CF3D3B3A5151B1B_u64, 0x800E867BB8308361_u64, 0x791D2C8292A0CD59_u64,
+        0x0902F15D03139486_u64, 0xEC21FE6E1B7FCA59_u64, 0x5493B12641C595A4_u64, 0x5FD0FAE30235BDFA_u64, 0xCC351E91638BD2E1_u64, 0xBFCBC49BF9749BF6_u64, 0x116C391FE893E3BD_u64, 0x793B2E7433F02A5C_u64,
+        0xCCA5A0C46B9ECCAC_u64, 0x820BAA4E50679FD9_u64, 0x9CC82750947F84EA_u64, 0x0C5E7500EBF78B56_u64, 0x893CA09354440DE6_u64, 0xF42D8952CFD714A5_u64, 0xC68A0641E25CD1EE_u64, 0xC86CC0FD61B46AE6_u64,
+        0x3DBDF0BBE73A5234_u64, 0x4EFB98CAC93BF80A_u64, 0x9F22DFC9B95080EE_u64, 0xA8EE8E9602EEC833_u64, 0xFC23F46E4184CC12_u64, 0x0F284B80EEA0669C_u64, 0x1AAE8206DB2EFA52_u64, 0xFE63557A328F9689_u64,
+        0x4D500E25E3F9846A_u64, 0x6B7CEE0ACA45DCBE_u64, 0x91AED6C6B880DFBB_u64, 0xE282D0A4832D004B_u64, 0xE57C4788044E60C5_u64, 0x6C0A1C03298D1546_u64, 0x81F1A46F272AE80F_u64, 0x346E0004CFEB2CB8_u64,
+        0xC7762CD1AB506233_u64, 0xBF424C3BDE279B75_u64, 0x551862465CFB3C76_u64, 0x191614F2AC5ADF9E_u64, 0xC6135BC49D725D96_u64, 0x99E998452359D635_u64, 0x8D8EAC1BD5CA19E8_u64, 0xB592D5216CD535CE_u64,
+        0x33309A7B3E071B29_u64, 0x37613F4CB35D3C85_u64, 0x4A9658CC666C0D7A_u64, 0x8ADFCC27231A8696_u64, 0x0BA9D9FCCCAFDBE8_u64, 0x28FB879FA8E09BAC_u64, 0xF99D3645F52AB858_u64, 0xF6F5BC3E6EA9C752_u64,
+        0x1C63BC7D981791A2_u64, 0x924B50900CD061E3_u64, 0xC1413454FFFB5808_u64, 0x852DCA812C0AB82C_u64, 0xC5181B1EB81CC9DA_u64, 0xCE5184DF3867C5AD_u64, 0x9EC9E8080A14DC5C_u64, 0x52B95C15640217F9_u64,
+        0x4874D1A422C56E42_u64, 0x7B64D01ED1CD6F97_u64, 0x947C08BF64A28E75_u64, 0x8511B5D2DA18CD57_u64, 0x58BF0B286E48CA4E_u64, 0x2A2381E5277DDCAB_u64, 0xDFB78C527F6B731E_u64, 0xC7FBFBC949973890_u64,
+        0xD945DA1FAF57B8AA_u64, 0x18817A80B25AA867_u64, 0x4BE8B153AE882C48_u64, 0xE42F00DB2B5E9283_u64, 0x9B5486B1D4EE7D7A_u64, 0x0957E03632C74E44_u64, 0xD4BBEDAA82687BCC_u64, 0x74A1AA33460431FE_u64,
+        0xFA3378999E167CEC_u64, 0xA0046202A07E176C_u64, 0x9470C88C74C3B46A_u64, 0x8B63D4E199A51A21_u64, 0xD3B00F81056A3A81_u64, 0xC5ADFEAE0E1CEB3C_u64, 0xCBF754ABB04CB9BE_u64, 0x97FEDE62F41A7E31_u64,
+    ],
+};
+pub(crate) const TABLE_PCG64DXSM_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0xACFD7CAEFDA3B179_u64, 0x6768E02E8B4FF8FE_u64, 0x58739EBB39BF3C1E_u64, 0xE994A30226BEF6D3_u64, 0x074E9583BD476C80_u64, 0xBD2AC4441BB00443_u64, 0x413FF4D70B1FE6D6_u64, 0x8B3CC598E2EF50F7_u64,
+        0x9E3E8EA425FE698C_u64, 0x63B6AE205ED82B49_u64, 0x2B96C8B84FC808D8_u64, 0x17D5468C370177B3_u64, 0xB8C9D16289BB40B6_u64, 0x5C4E6D186F7A9A28_u64, 0x8AC65A8177E975EB_u64, 0x6AE7CAE117C6844F_u64,
+        0x662717D7CD72B0BC_u64, 0x48F36EDB9F874277_u64, 0xDB631FDC311283F8_u64, 0xE167764D07192ADB_u64, 0x6A9A83079423BD5C_u64, 0x8EE191EEBA066283_u64, 0x9D9FE8CD247DC5EA_u64, 0x1D23CA4BA8892274_u64,
+        0x44A304A6BF24E78D_u64, 0xCAB5CD2E5668AA0E_u64, 0xCDF54A91E2F5484F_u64, 0x01482C384EEE7B9E_u64, 0xCFC1080B0E39E7DE_u64, 0x9CBA5B9D81F91815_u64, 0xD302C25A6BFE4525_u64, 0x292D673B32CA3B33_u64,
+        0x3302AAC92390F9C5_u64, 0x2EF6D1763DAAD34A_u64, 0x56080AB03658C8C3_u64, 0xA7B82EA34E684997_u64, 0x0EB4EB2A135F231B_u64, 0x763485E4CD94097C_u64, 0x3DAB3638E3C7A765_u64, 0x6A73E329BE4CF470_u64,
+        0x9796E7B30301ABBB_u64, 0xF147030FC72A5C8D_u64, 0x9C30527E5281BA9B_u64, 0x842056FB4205718B_u64, 0xAEC894760C157CA0_u64, 0xCBB6097560FF8CCF_u64, 0xDF2338B4573CE45F_u64, 0xC9F87C58D4AFAA26_u64,
+        0xDDB987C329A00609_u64, 0x6EC537200801A8CB_u64, 0x0D748DAA1B3C3B74_u64, 0x788DFB0DDCA79994_u64, 0x1DE740BA55FED0DD_u64, 0xC522F920B62D54E4_u64, 0x0C9279F48D5E048A_u64, 0xE093B3F758DEB06B_u64,
+        0x46FCB95948C013A2_u64, 0x4DB34121C8AC13C9_u64, 0x36CC48A473D3B639_u64, 0x998B1E6DD2759C39_u64, 0xC82355A5088C6DCB_u64, 0xC867FDA3857601B6_u64, 0x203F0D1C3ED8FDA2_u64, 0x37A3BB95B3B5BEAF_u64,
+        0xCAA1CF52818E4036_u64, 0x1C94BBE855547F3A_u64, 0xC9E26BDCC8CC9665_u64, 0x0952AD0FF4688532_u64, 0xD3FC83F4F504EAC0_u64, 0xDA9DCF18920CB36B_u64, 0xE2EB9A4AF91C3E38_u64, 0x11E583AED75A5094_u64,
+        0xF19545F28FFDFEBF_u64, 0x5DADA2A661BC0B15_u64, 0xE8F30CD381C1350D_u64, 0x3F7EDB2269FFF4F2_u64, 0x3129E9ACFCA29C17_u64, 0xB0BC29BECB0D88A1_u64, 0x47796C4F96FD0F6A_u64, 0x69AA0D5DD94563B9_u64,
+        0xDB17A724DED34471_u64, 0xEDB6E280BA9992AA_u64, 0x9B5821C4907B532C_u64, 0xFAF9C6383C4688B4_u64, 0x465D6FA2D3E3C4E2_u64, 0x398D86D6E447C0C5_u64, 0xD9AE1DA9E728DD93_u64, 0xFE5A593974DCC3FC_u64,
+        0x2ADD343F0111DDC4_u64, 0xF33A06585B82E447_u64, 0xE413F27E78F39700_u64, 0xE34F335101F954AF_u64, 0x0FE5EF697286CCE7_u64, 0xDA3A91BAF646F463_u64, 0xBB34A7568939C00A_u64, 0xD0BB446C1C5BBCBB_u64,
+        0x9C120E03C2263319_u64, 0x3E73E27CAA9C514C_u64, 0xA0C780C8CEABD55B_u64, 0x9B4715B61E84A9CB_u64, 0x44BC7020D73D1191_u64, 0x9B65ECBEDB27FF63_u64, 0x94B071442AD491FD_u64, 0xC18790E2356B9AA0_u64,
+        0xAE764462AE149753_u64, 0x77F8861E74C7A79B_u64, 0x03EC2C1AE49BFC69_u64, 0x0E94B1DDB6E17F17_u64, 0x93C624BB7746CE84_u64, 0x49E879FB3D1A1CDB_u64, 0x27D6550A59E2FBE1_u64, 0x916026FC8235E065_u64,
+        0xF68B818EE4F68334_u64, 0x27FEBC40E69F6997_u64, 0x78E666640E65F22D_u64, 0x6D95509719D29A58_u64, 0xF4747AF9507BD7D2_u64, 0x6EC61203635A8610_u64, 0x8485DB9759A046CE_u64, 0x57B1694D06871699_u64,
+        0x22CB78257C5BBDD3_u64, 0x791C4B680010CEC2_u64, 0xD315FEB034322686_u64, 0x89E1325F569B59A8_u64, 0x58E51C1E17CA0B3A_u64, 0x756A95F76109E582_u64, 0xE4C0EEBBD42F6101_u64, 0x34609557D51F9F88_u64,
+    ],
+};
+pub(crate) const TABLE_PCG64DXSM_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0xD21FC01D608FEDB4_u64, 0xDFD0C1914FFCEA15_u64, 0xB84C2F7A56818D3E_u64, 0x033F09B858DF00AE_u64, 0x41620C1008C12A15_u64, 0x6C90058D5FFEA8AC_u64, 0x208590F2957D8B8D_u64, 0x466452A24F6BE6F5_u64,
+        0x64775382F7495897_u64, 0x31741072BB803C66_u64, 0x91F611BD072A58F6_u64, 0x1129A90DAF1018F0_u64, 0x80E946DAB88A43F0_u64, 0xD816258FFF331434_u64, 0x620FFFD402858305_u64, 0x7AE90845889E7277_u64,
+        0xB3BD0291CB1BB133_u64, 0xF8ED4F4FE6F90277_u64, 0x5F264E48FDF961AF_u64, 0x9F4C5FE304ACEB16_u64, 0xF94A81E9BDADE773_u64, 0x67C08D31A462A246_u64, 0x3015ADFAEADA9941_u64, 0x5EAA40F358B877A5_u64,
+        0xEA30E4786150E2E7_u64, 0xB57E79766976F7F7_u64, 0x37C3F4392CA9028C_u64, 0xE646618995785345_u64, 0x88D64F48DEE61E42_u64, 0xA26CE37D0C178BA0_u64, 0xF15EF6FF5BC4DECA_u64, 0xF5D91E0E4148870E_u64,
+        0xBC20A4635D699767_u64, 0x1A479D49A77BD8CC_u64, 0xEA1A7D3535EBC800_u64, 0x44E001D388E62CA3_u64, 0x910E994527E0DB53_u64, 0x34F888B3A4D1E88B_u64, 0xA9633859C9CF7953_u64, 0xC42D145007215400_u64,
+        0x9F906DD27DCE1037_u64, 0x8EF91A57CE8F8987_u64, 0x279C8F2F32982EB4_u64, 0x5DC8F869775E1FEB_u64, 0x94E7D66921B4475E_u64, 0x547A5F14ED45801A_u64, 0xA6CA0379B8A02AFF_u64, 0x3C2E8FE7474AB6E7_u64,
+        0xA7B2307DC8AEB136_u64, 0x0FDB83650F184FB6_u64, 0xC460A6FBA348C925_u64, 0xB26012C38092794C_u64, 0x2664485DF3FC7E3D_u64, 0xAB6A8D5A40A538DC_u64, 0xB8CC174EE4CE0D09_u64, 0x2E14ACAC6EA4A6BA_u64,
+        0xEBAADB1540F275D8_u64, 0xC64DC67D2CE49D6B_u64, 0x400ABF8C9663539E_u64, 0x380CBB49473918E2_u64, 0x5D439963B36C29A0_u64, 0xC774304340EC7577_u64, 0xF7BB017CA44CCAB4_u64, 0x891D3C6AC48DC936_u64,
+        0xBD85E81B94DD4A9C_u64, 0xF1CE82913B9476EF_u64, 0xA9377DFB2344009F_u64, 0x0A6858348CECB895_u64, 0xCC96E2B6716E7E4A_u64, 0xEAA2346FF9E66D18_u64, 0x96C28D780CD40975_u64, 0xD7A823E4C84A67CB_u64,
+        0xA53A668CA58CEEC6_u64, 0x4B44326AC14281F8_u64, 0xA220F9D7E61DA53D_u64, 0xD077F4D73EBFB827_u64, 0x0B15E5C51BFAD5A7_u64, 0x1217A6E5CEC82084_u64, 0x6AE3CA7BC098DD44_u64, 0x46AAA4846E9A69F2_u64,
+        0x26B72447474C2637_u64, 0x27A4FFA3377D44D5_u64, 0x0C4057690153BFDE_u64, 0x2702DCC0BB7DFFA9_u64, 0xBD033315B444C012_u64, 0x2807EA9FD5592D2E_u64, 0x57D9CF9F88DAE708_u64, 0xC1934A30BDA003D4_u64,
+        0xFB02A383957D0EB7_u64, 0x0D908018D06F55FA_u64, 0xEC58D0F04AD867EA_u64, 0x85190E7D9E39E319_u64, 0x84851BE349B02691_u64, 0x922C3EC05EB9D230_u64, 0x6824A52903929A44_u64, 0x2C5FBEE74314CFE1_u64,
+        0x85F5D41EB569A2DC_u64, 0x2BB8408F6852AD89_u64, 0x6B461BF909002537_u64, 0x26FBDEEC5BF7D084_u64, 0x548E1C8C3451AD32_u64, 0xD17A4A04BF53113A_u64, 0x6A90B96E80EB7426_u64, 0x1297D4381A3F934A_u64,
+        0x81A2AC47965255B6_u64, 0xD84E3F715C081D9A_u64, 0x95DFC2F879CF21DC_u64, 0x881B1089DC46F7AC_u64, 0x8C873F6ADA801D7C_u64, 0x97DD99D03F574302_u64, 0x04433E8506AF36C2_u64, 0x282B015F147F79F8_u64,
+        0xF25C4582AA4432B9_u64, 0x52745F2D60CDAE93_u64, 0xBEDE4967CDC76D07_u64, 0x2EB4572C66AC467F_u64, 0xA07D791F46B58CD7_u64, 0x5E60BB1CD1BEADF1_u64, 0x564947445BCB675B_u64, 0xB8623C8D3C6D78AC_u64,
+        0xF624ED1264771A37_u64, 0xACE47996931FA55C_u64, 0x0E59FD17645B4ED0_u64, 0x9D90E613E5DD6D24_u64, 0x986F0905CDFB896C_u64, 0x6CB890A823208135_u64, 0x0CF435B110D22F27_u64, 0x99A7DE9C5A37B7D6_u64,
+    ],
+};
+pub(crate) const TABLE_PCG64DXSM_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0x0631DDBC54B0FC0A_u64, 0x9D3D3C523D5D7709_u64, 0x6FD97CDECB9D7F77_u64, 0x519091A4F1A72154_u64, 0xE39671FD7D301731_u64, 0x2FE7C9FB26D92CD6_u64, 0x5D7484791F52B09F_u64, 0x202555E60F547F27_u64,
+        0xEC7E2335043F53DF_u64, 0x8BF20831DBBAA656_u64, 0x1FC44E710A2FBE8F_u64, 0xDDEC99A2F3FBDBE5_u64, 0x43FAE7AAB485EAEC_u64, 0xBDA890C322321A19_u64, 0xACBDFC0C36959FCE_u64, 0x5EA789EC24EDD6A7_u64,
+        0xD1B4EE39034B3652_u64, 0x0BB2AD77CD21C37C_u64, 0xAE7B510AC26ED867_u64, 0x092AB124D0A4DBA5_u64, 0x82FA8882961478C2_u64, 0xCBAB5557094B3D7D_u64, 0xD55E66B7251DFE52_u64, 0x42C4AE121B11E5BB_u64,
+        0x05F36CFAD0D563E6_u64, 0x9EC5EF08321AB1A0_u64, 0xF12D51BA15B50101_u64, 0x81B55CA0FEE0822D_u64, 0xBCE70F1FB5CEDD12_u64, 0x18992B7368830BEC_u64, 0x67E86A186EFA436D_u64, 0xA268F6F0D5B9317F_u64,
+        0xC34DD92170D4FFB5_u64, 0xE6AA5368A6592D35_u64, 0x5BD2040DEB23DE3B_u64, 0x391F78391A24D8B6_u64, 0xA0EAD9B1CF2E7D26_u64, 0x4F90BC18C635828E_u64, 0xFE871FEBE94C415F_u64, 0xD7DC8906D12C3928_u64,
+        0xD4894363A39477AA_u64, 0xB0FEA34777DEED7B_u64, 0x1CFCF27CF249F418_u64, 0xFEAD6E8538D24F7D_u64, 0x5095D054997E7F4E_u64, 0x8C69E113BC75713A_u64, 0x9E2982AD71325612_u64, 0xB50A1284214E4D0A_u64,
+        0x2E4D078EB6DC6CBF_u64, 0x42BC759B98EB1E05_u64, 0xF436885F25875558_u64, 0xF8634B4A8BE7794E_u64, 0xCBCF81B89C4E496C_u64, 0xA080D01FD0188872_u64, 0x9122A5219D3F6848_u64, 0xE52DDD03739A7F57_u64,
+        0x7C6E951096CC069C_u64, 0xA2F857EE7A065589_u64, 0x61DA5D74455C221F_u64, 0x086D5AE11173BAF5_u64, 0xA8F5A64E1E7650B2_u64, 0xEE3666A46679941F_u64, 0xAA97DB07F91675E1_u64, 0x8527A511D817CD7E_u64,
+        0x381D345391586D82_u64, 0x600605E942F4703D_u64, 0xF6956F011CBFC3F6_u64, 0x73EDC9DD2B79FEA5_u64, 0xF4667E0E99A59FCE_u64, 0x1681BE4E6DF63292_u64, 0x684A62942272580F_u64, 0x37994817F1A0D940_u64,
+        0x74261B3A4F9317EB_u64, 0xA9EE16073FAEE09A_u64, 0xA53818A04C3A874E_u64, 0xC9231BFE2F8AEAEC_u64, 0x218F86F9C69E5443_u64, 0xCB7F8A8F940E82A2_u64, 0xADF218DDAB6BF5A5_u64, 0xFDABE3E0861651AB_u64,
+        0xE4D50B2A1EEF8E47_u64, 0x2A514E211FCC738A_u64, 0x10EE4358AC72EFBB_u64, 0xE0C8BC18D831C701_u64, 0x5D3BFF89D022C484_u64, 0x13FD626165E3FA46_u64, 0xFFD8285E5C54F897_u64, 0x22A9EFB886172C3D_u64,
+        0x77152FA67FA68648_u64, 0x20695310C6A75ED1_u64, 0x73EC9BB5B17C78BE_u64, 0x8A741585216D32D8_u64, 0xD148F6AF7C327FCA_u64, 0x811A2FBBCF05268D_u64, 0x7B3CFE4A1437358D_u64, 0x14A5F4DCBC6DB3A5_u64,
+        0xD36BD9DAE3CCD7D3_u64, 0x0C03E846916E164D_u64, 0xD71259E0D91175AA_u64, 0x58365439B9848D71_u64, 0x5C1CA24143BA3121_u64, 0xEAB5AE71ECDCCD02_u64, 0x76E671AC3037B3B8_u64, 0xC568BA5C484776C2_u64,
+        0xC61CBC1D21E02B49_u64, 0x7A222C1C59B170EE_u64, 0x2A5209E8EDAD13A9_u64, 0x6E21C86E1B11B924_u64, 0xB582A1FD76E89784_u64, 0x50D0ACD691AB6798_u64, 0x946BB9DA4521FCDE_u64, 0xC5FE716622E478C6_u64,
+        0xDD7E640E5FD237FD_u64, 0x032B63F04F205B10_u64, 0x4DC5EA96003EB1C1_u64, 0xFDBAE9CC41C46A82_u64, 0x11BC25E17AD9A359_u64, 0x5DB236BED832C50A_u64, 0x63557435EF1DC32F_u64, 0xCEABEBB2090FB08A_u64,
+        0xC110DC482CC86CD0_u64, 0xB76ADD48DFAE91A9_u64, 0x13A09586D8A8581E_u64, 0x3C4ACA890D5DB032_u64, 0xE89AAD1FD63146A5_u64, 0x4D0C59918AAE37A1_u64, 0x9E8C404C40AA7561_u64, 0x33C054933142E22A_u64,
+    ],
+};
+pub(crate) const TABLE_PCG32_STREAM_0123456789ABCDEF: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0xFC5B6DCF_u32, 0x1990C2AF_u32, 0x74660FF7_u32, 0x06EA0155_u32, 0x68F294A3_u32, 0xC159C29E_u32, 0x2CC01203_u32, 0x939DE9B7_u32, 0xFC4C1280_u32, 0x8C332613_u32, 0x7D955D5B_u32, 0x6719F951_u32, 0xEC9EE169_u32, 0xC786E15E_u32, 0x6D47BBC5_u32, 0xC89E79A8_u32,
+        0xCD5E038C_u32, 0x783DAAA0_u32, 0x0A09BACA_u32, 0xB7265486_u32, 0x81BD1BE5_u32, 0xDDA09AA1_u32, 0xDCF0F586_u32, 0x6A2582FD_u32, 0xC8681DC8_u32, 0x390D8CC6_u32, 0x12C29788_u32, 0xFB2C236B_u32, 0x35967029_u32, 0x223C3FCE_u32, 0x0564DB3C_u32, 0x6C42D9B2_u32,
+        0xB546F1E6_u32, 0x660AFCBC_u32, 0x516C89C9_u32, 0xB30C4A4C_u32, 0xAFBBB559_u32, 0x77C85425_u32, 0x0AB9F029_u32, 0x88EC6956_u32, 0xEBE21D49_u32, 0x2CF01A62_u32, 0xEBCA96E9_u32, 0x29948B68_u32, 0x23643E71_u32, 0x4D023CA2_u32, 0x866D69D6_u32, 0xB2DBAC45_u32,
+        0xFA56F353_u32, 0x52DE8A84_u32, 0x445682AD_u32, 0x8CBE28AC_u32, 0x03D5672C_u32, 0x1004727D_u32, 0xCC5E0C8C_u32, 0xF73CFE5F_u32, 0xB6AE1703_u32, 0xAAED1922_u32, 0x1846EBD9_u32, 0x8C2625EE_u32, 0x6C91E772_u32, 0xC6A53DAC_u32, 0x2A2DC1E5_u32, 0x98E11F57_u32,
+        0x2EA0282D_u32, 0x18FF586A_u32, 0x65D3543F_u32, 0xA2CE2D7D_u32, 0x424973AE_u32, 0x482EF2CD_u32, 0xB74729B3_u32, 0xA0C8377D_u32, 0x0A6E4D98_u32, 0xD0DAD1D3_u32, 0xA7B87600_u32, 0xDA112DDF_u32, 0xF98D8F6A_u32, 0x5E3C6746_u32, 0xE324B3FE_u32, 0x56741086_u32,
+        0x59060527_u32, 0x3E26ABB5_u32, 0x2DA468EC_u32, 0xF4D5A354_u32, 0x2E9C9B53_u32, 0x89441743_u32, 0xC4A8AE3E_u32, 0xFB6D0F56_u32, 0x197D4CF7_u32, 0xF05E666F_u32, 0x10E34B99_u32, 0x8454758E_u32, 0x4B8AD934_u32, 0xA20C97F3_u32, 0x48307333_u32, 0x1B576267_u32,
+        0x738E84AC_u32, 0xDA3E0BB6_u32, 0x80FBA639_u32, 0x7B83C468_u32, 0x736AB78D_u32, 0xCFC7CCC6_u32, 0xB5CACCFA_u32, 0xD39F906A_u32, 0xA9B52D8F_u32, 0x2F0CE1F0_u32, 0x2C1EF813_u32, 0x1C9146D7_u32, 0xA9C9FC34_u32, 0xCF9AF368_u32, 0x04D7612F_u32, 0x8EA686B9_u32,
+        0xC2D11F56_u32, 0x59A6FD07_u32, 0x45580F96_u32, 0x35BC3817_u32, 0x11571115_u32, 0x37855B98_u32, 0x5252C7C2_u32, 0x331A1D4C_u32, 0xDB574C2C_u32, 0x577FC6DB_u32, 0x4DCE2180_u32, 0x56AE3131_u32, 0xAABE5E66_u32, 0xBDFB0A75_u32, 0x107E34BD_u32, 0xED6F3798_u32,
+    ],
+};
+pub(crate) const TABLE_PCG64_STREAM_0123456789ABCDEF: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x003DF525B37A206B_u64, 0x7B4DAB9028A6E911_u64, 0xEED005DD252A34BB_u64, 0xE6D9CB6064CD3FB2_u64, 0xEF7278A3802D4878_u64, 0x2F2F45050E137D4E_u64, 0xCF7D49FE5707CD26_u64, 0x926E5F610728D7A8_u64,
+        0xEECE04A526FBC5BB_u64, 0x0B75BDA990D01C6D_u64, 0x37DF4EE166502F6A_u64, 0x835A7FD9C67DF9DF_u64, 0x490AA92146FC8D78_u64, 0x54326351ED829678_u64, 0x2442FB3D6AD90730_u64, 0xB53294EB41676C5B_u64,
+        0x6479DB57AE741031_u64, 0x6CDE47C83FF9763E_u64, 0xD448FC12E16EB347_u64, 0xE808B4B342751264_u64, 0x175308BC05AC5DEA_u64, 0xE7106E5FAA4071D7_u64, 0xC8B0969CFF729549_u64, 0x829ECE573A555932_u64,
+        0x54D44665E4347CE8_u64, 0xBFEC02CB9DC96166_u64, 0x355E009D99CEBF82_u64, 0x5D993395E5FA998E_u64, 0xE6CA580B1C951A75_u64, 0x1CBD8330A6E51546_u64, 0xD42D5C0F6720F4A0_u64, 0x52CDF99B583571B1_u64,
+        0x84AF8FA652490568_u64, 0xF929B2989C2C6861_u64, 0x16897198040D2B22_u64, 0xD5F9EF3382B79917_u64, 0x6160A67329C36FC3_u64, 0x2C64343B2E55B7D0_u64, 0x59ABDEAB745004E9_u64, 0xE7A766FDFD69BB3F_u64,
+        0x73AB0A35AEB15668_u64, 0x87A82B03791F4D9D_u64, 0x22A86E894B8175CD_u64, 0x5091871C7D8BEB7A_u64, 0xD85D6CE04D781B6D_u64, 0x91261912BCD9E2B9_u64, 0xE05F05AAF1E8C3F9_u64, 0x67A8B4DE0D41C341_u64,
+        0x39BB1D34751D2C7E_u64, 0x1143F5C0E1C13B6F_u64, 0xB9D15AA6BB8F9F8E_u64, 0x33CDE4216C9BBD81_u64, 0x5524C6E9850D2CE2_u64, 0xA3D059977F8A14B6_u64, 0xB31DBB4B75124408_u64, 0x262196F531DABDD4_u64,
+        0x1FC08AA4DA04F3BA_u64, 0x489032BDF49674F4_u64, 0x7D87085536BFC3E3_u64, 0xB79D2421D76340F3_u64, 0xE048C1F3FD8E388F_u64, 0x7645C14AA768AF6C_u64, 0xE3EF69F810A34650_u64, 0x565AEB8733C40D88_u64,
+        0x9C54CFD1BD73B437_u64, 0xB506F49D23D550CC_u64, 0x284E9A1A926CE703_u64, 0x1E12A059A1233BE0_u64, 0x6F697908581DBBFC_u64, 0x585033354569D54C_u64, 0x3B6BF11F090DF573_u64, 0xE993713A0A42A354_u64,
+        0xED0FB93253E18402_u64, 0x9E68E6F24385CC8E_u64, 0x60BDA84385671083_u64, 0x914B2180D8B34D72_u64, 0x242EE708E63A0E81_u64, 0x8ED3B67FDD85F075_u64, 0xDE24CB5C29BFFCE2_u64, 0xFBF1A0271520183C_u64,
+        0x9A915A92362FC41A_u64, 0x7762EAADCB0C9098_u64, 0x51ACEAE0362D5575_u64, 0x6CC5A4D9F97FE96C_u64, 0x130BC044E5308C8D_u64, 0x4C5DBD9A7CDE54E9_u64, 0x6CB65B7ACBAA2AC8_u64, 0x73FF45052245CE4C_u64,
+        0x4311EBB9B8A2BA57_u64, 0x3FC23CE025865F28_u64, 0xF6EA3EE04CE53B94_u64, 0x06BF509859D69B7B_u64, 0xC6DACFA5CF44C71F_u64, 0x02AA5CBD629244D2_u64, 0x97EA624DBA4A97D3_u64, 0xDD3BF2B5666B065C_u64,
+        0xAE9FF18A378160A2_u64, 0x1C2D47BF9D321FE9_u64, 0xC331051DD2AB169A_u64, 0x76A9FFBAF66086DC_u64, 0x3F3C5C10F3F9068B_u64, 0x191E3D4F94D3E2CC_u64, 0xB0D555E5A02B0B54_u64, 0xD277480B1C7FDEC2_u64,
+        0xBCD7F15A785D5DCD_u64, 0x2D45C64A5B18244A_u64, 0x25749DEE1C7E9171_u64, 0xD1CF6D7DF9B81D0B_u64, 0xD9CCB14511376FAA_u64, 0xBF424469DEA3B435_u64, 0x75646357355FD88A_u64, 0x05297E9CE88D6A87_u64,
+        0xB033C870BC2EE68F_u64, 0xFE85DFE086AD73CB_u64, 0x514EBD5F7E3624DF_u64, 0x6120D082BD092BD7_u64, 0xC863469FEDC6BCB5_u64, 0x2B726947E65E0252_u64, 0xD98CD8CF81644D94_u64, 0x05EA526F5089CFF0_u64,
+        0x30703995518FB653_u64, 0x04E918E8657A0A9D_u64, 0xC809E9EC4B511264_u64, 0xB9E8C83A86D2ED81_u64, 0x13FE6D9028568A2D_u64, 0x16E46B683EFB06C4_u64, 0x4870565CFBEB6B74_u64, 0x7E239A2A49EF15CA_u64,
+    ],
+};
+pub(crate) const TABLE_PCG64DXSM_STREAM_0123456789ABCDEF: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:22:25",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0xEBACF5CFFBCCFB8A_u64, 0xC5F0E04841D25828_u64, 0x80A0F5E281E0F1F0_u64, 0x999B181E102D160C_u64, 0x919447433890609D_u64, 0xA73DA9FF8EEBA237_u64, 0xE84231C12742BCFC_u64, 0x9752CF120068E785_u64,
+        0x006FFBDEECE56845_u64, 0xDAA04523C8C55658_u64, 0xB85C05F8B766EBBC_u64, 0xD291B25216740CB0_u64, 0x35A5C596EEBF0063_u64, 0xA99169E37ADAAA9D_u64, 0xC1DBCA139D9F04D3_u64, 0xB7DE5A694BE2ABAD_u64,
+        0x070E5D3826634961_u64, 0x922E7250851F9C08_u64, 0x02B89558ADBD0DED_u64, 0x1CB574C0883B58A1_u64, 0x53BD8C768FDDA228_u64, 0x58E936930DA15436_u64, 0x261990A2FA227FFE_u64, 0x635C5D259DAD16CD_u64,
+        0xA73BC009F3BA1F29_u64, 0x210018318229191E_u64, 0x688483730C2B8FA9_u64, 0xA8DD7BA60CE0F298_u64, 0xD43A8931861387D8_u64, 0xE1B630E0C2C32A57_u64, 0x6C3948EFE305A326_u64, 0xD12405F8310F4273_u64,
+        0xFAB70094AFEB2624_u64, 0x89FB0423EA58B161_u64, 0x6DEA08010A5B6FC5_u64, 0xE622FACB2C0A1967_u64, 0x77D638EE9887EDA2_u64, 0xCC976CE894506C50_u64, 0x07E69E084EAE39F9_u64, 0xE86FE7CCD3641369_u64,
+        0x98C2A9CD201AF729_u64, 0x2F2B49AA59625456_u64, 0x42C8EE3907CDC451_u64, 0x9A41060571233F9B_u64, 0xD4916338885C6D3A_u64, 0x88D7C9DA99D5A9B3_u64, 0x21ADB9FC5E247146_u64, 0x60F4EB46BF0741E1_u64,
+        0x5FDEB75A7A99D878_u64, 0x19B7AD085BCC9853_u64, 0x07CA19424449C091_u64, 0xF1B1DD7DA46BE21B_u64, 0x4D0F293D7A9D7884_u64, 0xA0E8E9963E2D2D0C_u64, 0x6C4A0EE9A4D2A4E6_u64, 0x9619504CF7F86F47_u64,
+        0x3422C0EEC753AAFB_u64, 0x402DA8647F5B7748_u64, 0xE89D43F97925C33D_u64, 0xBC371DE3DDB2813B_u64, 0x3BB16430114629DB_u64, 0x47AAD432472BB1A7_u64, 0xC51F33F700FCB9C1_u64, 0x48E04110598CB564_u64,
+        0x900B8F01FF286246_u64, 0x06A26FD0EE934348_u64, 0xEAC8E293FB77CB51_u64, 0xD68B3EDEEF8BF67B_u64, 0x5F397F36EB3F4EF7_u64, 0xAB67495D2903DFA2_u64, 0x91321529382A8344_u64, 0xC465B59945D69BA2_u64,
+        0x6E2119C3F29449DD_u64, 0x86C9E806AED935AA_u64, 0x3C90D23DF3879EC3_u64, 0x7E855F232F487A3F_u64, 0x20DC4877575A27C8_u64, 0x441844DA65BA2B94_u64, 0xE3E336F5340A235F_u64, 0x83294BA4D9870006_u64,
+        0x530D611E5A000EDE_u64, 0xE7839764DF8ABFCB_u64, 0xF27DD714A3420007_u64, 0x902A9223DB2031AF_u64, 0xD4B8FE8EA05F75E9_u64, 0xC2454839F4F40366_u64, 0x7FB0BA0B712EC1EC_u64, 0x447745B878A9B385_u64,
+        0x51A8A95609FECBB3_u64, 0x905993881EE44956_u64, 0x37D2389E8DE3F127_u64, 0x1392344CBD226FA8_u64, 0xAA616685823A7D87_u64, 0xC8450F1EEFD5016E_u64, 0xAF4133AD03E60F4E_u64, 0x3FC1CBB7359DE1A9_u64,
+        0xFF33D419DB4EC744_u64, 0xF05FE231715E0925_u64, 0xE1F971AE0BA2ED64_u64, 0x72E1303002F43B95_u64, 0xA70E6C20C627FC77_u64, 0xD79F4A02CBE7D75E_u64, 0x0152A44227224238_u64, 0x25E7C1F6A6BA539B_u64,
+        0x7066998BF9D63242_u64, 0x80F177F00E72E37C_u64, 0xF9933C638AB544DC_u64, 0xFFD030F6DEE06965_u64, 0xED5342A8681C318D_u64, 0x883A25829D5B5E95_u64, 0xAB98F4137D82A3CC_u64, 0xEFE42C3C4DD93ED7_u64,
+        0xF08E8EA1932099C8_u64, 0xA5B91F98E4021834_u64, 0x216E938DAE030C31_u64, 0xD63D9ADA6C434856_u64, 0xFCA46C60670F192D_u64, 0x92BCBC8FEF961A23_u64, 0xA19E26698AB630B3_u64, 0xB7362BCCF107E008_u64,
+        0x6877A950845D61D5_u64, 0x9F0282318A66243A_u64, 0x4E274C581D7AC841_u64, 0x8229745B94AA93DE_u64, 0x504612DB9A61C8EF_u64, 0xE5E0DFD3647807DA_u64, 0xC11B2180434C1336_u64, 0x0598218E8E20381E_u64,
+    ],
+};
+
+pub(crate) const TABLE_XOROSHIRO64S_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x00000000_u32,
+    table: [
+        0x841BF27B_u32, 0x6E113B5D_u32, 0x151D7AB1_u32, 0x969F350A_u32, 0xDD5D7527_u32, 0xF857A7A5_u32, 0x8DB584E1_u32, 0x65E532E6_u32, 0xD0AB1B02_u32, 0x3911FFDB_u32, 0xA9C21E7F_u32, 0x5A50A405_u32, 0xDC2E8540_u32, 0xD1F788D6_u32, 0x76D0B1F3_u32, 0x1E56B221_u32,
+        0xEA4EFAAC_u32, 0x35FEA25C_u32, 0xC620FE77_u32, 0x76FA00E0_u32, 0xB2029ECA_u32, 0x26CA79C1_u32, 0x944BBF8B_u32, 0x6D950E3E_u32, 0xB2BC1558_u32, 0x2B3F7601_u32, 0x83023FC3_u32, 0x520D3E93_u32, 0x5AE42957_u32, 0x691A2280_u32, 0x9CFED00D_u32, 0xCB95D409_u32,
+        0xDD850D89_u32, 0xE98A0AE7_u32, 0x291B5BA6_u32, 0x3EB7CD98_u32, 0x1908765B_u32, 0xCEFCD55F_u32, 0x8AAA310C_u32, 0xB1955326_u32, 0x4502F7A1_u32, 0x046C7A33_u32, 0x8ED73C0E_u32, 0xA3197658_u32, 0x0B6FD718_u32, 0x1D2E7B68_u32, 0xDB4CEFE4_u32, 0x452A2AD1_u32,
+        0x40BB977D_u32, 0x4A4F0B4A_u32, 0xB254BD2A_u32, 0xBBEE886C_u32, 0x8829E3E0_u32, 0xB0963A06_u32, 0xD1AB5F14_u32, 0xEA0C4BFF_u32, 0xCE547FB3_u32, 0x922A1FA9_u32, 0xAA15BB2B_u32, 0x819D7AB0_u32, 0xD4ADC132_u32, 0x69712A0C_u32, 0x603905CB_u32, 0xBE695842_u32,
+        0x9AC7915F_u32, 0x9087B099_u32, 0x21203AAC_u32, 0xF13881A0_u32, 0x5966BE64_u32, 0xC40AB0C7_u32, 0xA562AFB8_u32, 0xE434499F_u32, 0x82035D1F_u32, 0x29BED126_u32, 0x2BD67F09_u32, 0xA18C7EA1_u32, 0x40FA70CC_u32, 0xE6F382A6_u32, 0xE4D5711C_u32, 0x81BDDB7D_u32,
+        0x133EA85B_u32, 0xE241070A_u32, 0xAB79B58D_u32, 0x263DE71D_u32, 0x2EECA22B_u32, 0xA043E579_u32, 0xE22F4D4B_u32, 0x0B693946_u32, 0x18414E4B_u32, 0x361EF5D6_u32, 0xBDEC346B_u32, 0x85858680_u32, 0x5992A0A5_u32, 0x1663352C_u32, 0x465F6B8A_u32, 0xDA02EDE7_u32,
+        0x531CF66B_u32, 0xC08FFC1E_u32, 0xCE7C8851_u32, 0xB1BBC402_u32, 0x0A2C4CD4_u32, 0xF4179C0E_u32, 0x5172D863_u32, 0x9000169F_u32, 0xE24D2C0A_u32, 0xCE484CE5_u32, 0x85DF8F97_u32, 0xE6A8728F_u32, 0x4B65A23A_u32, 0xFC254606_u32, 0x9A34C766_u32, 0xFBB93E65_u32,
+        0xD13E715F_u32, 0xC9CA3F78_u32, 0x0EF8232E_u32, 0x82779554_u32, 0xA15DDDDB_u32, 0x746C0977_u32, 0x08DD16F6_u32, 0x7A23BD74_u32, 0x799B4360_u32, 0x803632B2_u32, 0x36EC2B49_u32, 0x650662C4_u32, 0xD516CA0F_u32, 0x499A74E6_u32, 0xBEAFCBB8_u32, 0x5F12906D_u32,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO64S_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x472790F6_u32, 0x3ACF1C2B_u32, 0x3560F0E0_u32, 0x5FDEA768_u32, 0xF9874786_u32, 0x70438DB6_u32, 0xAC390CF0_u32, 0xFA48E5D2_u32, 0x72248B26_u32, 0xCEBD86C0_u32, 0x5991BF0D_u32, 0x1A07E8AF_u32, 0x1123654A_u32, 0x18B696DB_u32, 0x3308A0E7_u32, 0x3CEE978F_u32,
+        0x0DF2D04E_u32, 0x49DDA375_u32, 0xCCD7667E_u32, 0x2B1D38A1_u32, 0x4D854CB3_u32, 0xA490737A_u32, 0x2ACAA453_u32, 0x335F006A_u32, 0x8866D4EE_u32, 0x0B92CF3D_u32, 0x3E5F6857_u32, 0xF1A29784_u32, 0x5B496EDA_u32, 0xFEDF68DF_u32, 0x0254377A_u32, 0x31709AFA_u32,
+        0xF31EEC25_u32, 0x6690FB51_u32, 0x5D74F7F6_u32, 0x0CFEE00F_u32, 0xEF2756A0_u32, 0x257BC137_u32, 0xAECC5BD2_u32, 0x42F8281F_u32, 0x2FBE3652_u32, 0x300678CC_u32, 0xC6582C08_u32, 0x38002E13_u32, 0xF61620F4_u32, 0x10BFC404_u32, 0xF8A5630C_u32, 0x81EC4445_u32,
+        0x1C5ACE65_u32, 0xB911EA66_u32, 0x4D49D0E2_u32, 0x546B90D9_u32, 0xBECA7363_u32, 0x5ACB44E0_u32, 0x9F43CFB7_u32, 0x8073CE14_u32, 0x8C8C2723_u32, 0xD659FE61_u32, 0x7C37CB63_u32, 0xE4870130_u32, 0x6CC7CE53_u32, 0x8322EBAF_u32, 0xAFDEB52F_u32, 0x097D5673_u32,
+        0xD81CA370_u32, 0xDB7781FE_u32, 0x99624AD1_u32, 0x386F5C82_u32, 0xE9A47B16_u32, 0x74997F48_u32, 0xEFFDF060_u32, 0xD0866F0F_u32, 0x97BFDF9A_u32, 0x3879AF1D_u32, 0x273FCEF1_u32, 0xC251FDED_u32, 0x7BA568F9_u32, 0xDDE7FD04_u32, 0x5E4ED76D_u32, 0x4140E051_u32,
+        0x26056418_u32, 0x38A8C618_u32, 0xC516D58C_u32, 0x15C4772F_u32, 0xCFA52D7F_u32, 0x4D7053AE_u32, 0x66370B46_u32, 0x125E8308_u32, 0xE276C0B4_u32, 0x924F4B80_u32, 0xFB51338B_u32, 0x108D23CE_u32, 0x9B5522E0_u32, 0x5CDFCB09_u32, 0xBD10EC7B_u32, 0x6E8D40DA_u32,
+        0x82AB9F9E_u32, 0x43303A58_u32, 0x32D6614A_u32, 0xAAA09764_u32, 0xD39B8A37_u32, 0xECC9DE2E_u32, 0x4F095404_u32, 0xC67F721C_u32, 0x269A7125_u32, 0xCD48C65F_u32, 0x0389C620_u32, 0x59AE1235_u32, 0x19763434_u32, 0x6CD9CF67_u32, 0x001B7875_u32, 0xBEC06C06_u32,
+        0x96F3CF1B_u32, 0x1130E251_u32, 0x7B483DF4_u32, 0xEDD1AAF5_u32, 0x161796F2_u32, 0x27544668_u32, 0x17331CF7_u32, 0x690A533D_u32, 0x9F2881A1_u32, 0xFCF168F8_u32, 0x58F230FD_u32, 0xDF201863_u32, 0x5629F106_u32, 0x78CDB902_u32, 0x0CFB7981_u32, 0x3ECB7583_u32,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO64S_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0xF63A987A_u32, 0xAF8194B6_u32, 0xC839952A_u32, 0x40A32A71_u32, 0xCC00509C_u32, 0xD46F48B5_u32, 0x4BE2DBCA_u32, 0x181E8C2C_u32, 0x1C408869_u32, 0x2FC3D9AD_u32, 0xA10DD2D1_u32, 0x00C92969_u32, 0x0BF93027_u32, 0xE6F16D36_u32, 0x2B14035C_u32, 0x7AB7A0AF_u32,
+        0x3204813A_u32, 0x9685A98E_u32, 0x3139D653_u32, 0x2F668EF0_u32, 0x615BB251_u32, 0xEF3D906E_u32, 0xB48BE5E1_u32, 0xF7A52E2B_u32, 0x632260C8_u32, 0x2518678B_u32, 0x1595F3E4_u32, 0xB0A34811_u32, 0x815FECE9_u32, 0xA50A9595_u32, 0xD620BDA3_u32, 0x3F216715_u32,
+        0x8696E3A9_u32, 0x52C95AA1_u32, 0x0E04D668_u32, 0xEDD4F2F4_u32, 0xA4F8AD8A_u32, 0x9492CADC_u32, 0x409CDB1B_u32, 0x8145DEB0_u32, 0x2C3B4497_u32, 0xBE9C242F_u32, 0x6F5ADB30_u32, 0xD1D1CED2_u32, 0xB1C0533D_u32, 0x26D1B0FB_u32, 0xBC6B62F7_u32, 0x905DF0F2_u32,
+        0x5B169744_u32, 0xD9069EDF_u32, 0xA475E46C_u32, 0x22FCBEA1_u32, 0xD2ACC63C_u32, 0xE2B5D51A_u32, 0x17CEFADA_u32, 0x35357FF5_u32, 0xB9CB02CB_u32, 0x12B890A4_u32, 0x22E2E6FD_u32, 0x3FEF688D_u32, 0xF1938B4D_u32, 0xAFFC77F5_u32, 0x65B7D98F_u32, 0x66883AA1_u32,
+        0x71EB95CE_u32, 0x0E634D9D_u32, 0xF69C1789_u32, 0xA41D8B44_u32, 0x53704ABF_u32, 0xE0D4478F_u32, 0xEB315B7D_u32, 0x666417D0_u32, 0xEDF0E080_u32, 0xDCFA28D9_u32, 0x24020AB2_u32, 0x6CD6BCAB_u32, 0xE9AE2037_u32, 0xDAD3D353_u32, 0xCC935EE0_u32, 0xD32493EB_u32,
+        0xB3F5CE8F_u32, 0xDD77CFE6_u32, 0x12F852E9_u32, 0xF994729C_u32, 0xBA61CCFA_u32, 0x6B7CFC70_u32, 0x9D7C1E8F_u32, 0xF39D42BA_u32, 0xC0E2968F_u32, 0x31A8D4B5_u32, 0xCCCCA161_u32, 0x5B10D6B5_u32, 0x6CCD91E7_u32, 0x8C2B707D_u32, 0x9B8A4656_u32, 0x25AB73A4_u32,
+        0xC21AFDC5_u32, 0x1B506824_u32, 0xB54BEE00_u32, 0xBC7BC2C0_u32, 0x63208892_u32, 0xCEC84CB6_u32, 0x791C3470_u32, 0xF88EEFA2_u32, 0x2671153F_u32, 0xC9568C76_u32, 0x221E1F3B_u32, 0x74930C45_u32, 0x58D517FE_u32, 0xCC0DA2C7_u32, 0x180A98A8_u32, 0xE1745761_u32,
+        0x2936ED2B_u32, 0xBAA537FA_u32, 0x4B8DE464_u32, 0x2A16E68F_u32, 0x666B4328_u32, 0x223299FA_u32, 0x84058F0A_u32, 0xE8B2FD62_u32, 0x45A513F1_u32, 0x35B7CD66_u32, 0xB9021765_u32, 0xC033D9EB_u32, 0x6730EFA6_u32, 0x599B5925_u32, 0x37952018_u32, 0xA9070F90_u32,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO64SS_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x00000000_u32,
+    table: [
+        0x91778D30_u32, 0xCAC51A61_u32, 0x326CAEAA_u32, 0x2381269A_u32, 0x5A6938E7_u32, 0x36C8C7BB_u32, 0x91730CF5_u32, 0xAF3FCFFC_u32, 0x6AF0E1C2_u32, 0xAB3FE903_u32, 0x19530FC9_u32, 0x72668357_u32, 0x9D134887_u32, 0x3AB58642_u32, 0x426F3826_u32, 0xF62F54AF_u32,
+        0x715CAC11_u32, 0xBF25799E_u32, 0xD49F0AD8_u32, 0x5C408C46_u32, 0x41A33EAE_u32, 0x3E8C18B4_u32, 0xAF57B73A_u32, 0x7D28E701_u32, 0xB58D576E_u32, 0x07A9C0B9_u32, 0xE167DA30_u32, 0x48471C12_u32, 0xCE99D697_u32, 0xB0559041_u32, 0x1F42087F_u32, 0x3DA4861D_u32,
+        0x73287627_u32, 0xF646D0F1_u32, 0xB11947D9_u32, 0x32E07F23_u32, 0xA549F8EF_u32, 0x5E055BDD_u32, 0xAA5EA7D5_u32, 0xFD53F82E_u32, 0x21DAC4C8_u32, 0xC3CC5FE0_u32, 0x46858915_u32, 0xEFE9F764_u32, 0x25E66F05_u32, 0x3D0D210F_u32, 0x1015EF07_u32, 0x3A5AC2C8_u32,
+        0x753EAE48_u32, 0x71670E6D_u32, 0x74F63AAE_u32, 0x751543F3_u32, 0x1A2E6C55_u32, 0x5DE4442E_u32, 0x0B1B6D02_u32, 0x47AF7FF1_u32, 0xF4CFD05D_u32, 0x5A53C9FA_u32, 0x4D94FB49_u32, 0x026CAE50_u32, 0xEC98BFC2_u32, 0xE6BA47C1_u32, 0x23A39F1C_u32, 0x01D729B3_u32,
+        0xBCBADBBF_u32, 0x54CE5FFA_u32, 0xB424AB94_u32, 0xC3510496_u32, 0xE036FEB7_u32, 0x86AE7CD8_u32, 0x5DADD364_u32, 0xA0AE03EC_u32, 0x421A33B0_u32, 0x1742B7D9_u32, 0x660F65B9_u32, 0xF7CF2504_u32, 0x9C867FA8_u32, 0x5831A84C_u32, 0x0566B20C_u32, 0x16A92E70_u32,
+        0x072938EA_u32, 0x68A466CC_u32, 0x2C117889_u32, 0xE6B07234_u32, 0x53E55AF9_u32, 0x2A6F6C04_u32, 0x5D904F6C_u32, 0x21C3CBC5_u32, 0x28D0EEEF_u32, 0xD359A5DE_u32, 0xB3A0C353_u32, 0x73741050_u32, 0xFBA46757_u32, 0xFE013B8A_u32, 0xFBA33668_u32, 0x41D4B0E7_u32,
+        0xF21A0312_u32, 0x59FD9338_u32, 0x0DD5331D_u32, 0x155A81AE_u32, 0x5BB00485_u32, 0x8EC18956_u32, 0xE7C73E12_u32, 0x000E23BA_u32, 0x703B86CC_u32, 0xED300F9D_u32, 0xABB9BEB0_u32, 0x294799EC_u32, 0x1F85646D_u32, 0x974BC45B_u32, 0x60FCA01F_u32, 0x53C6FFBB_u32,
+        0xC706DBE2_u32, 0x1E67AB7D_u32, 0x5B15FCC5_u32, 0x8ABD54D0_u32, 0xDAAAA944_u32, 0xC385EAA6_u32, 0x8A2E59C5_u32, 0x565668CB_u32, 0x010A1C4B_u32, 0x21DFAF90_u32, 0x539B0DBE_u32, 0x23FDBABC_u32, 0x2E3E49E2_u32, 0x00890FED_u32, 0x2DDF5373_u32, 0x6B9A4457_u32,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO64SS_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x78BA99E8_u32, 0xC1719B03_u32, 0x5C968C1E_u32, 0xEB28A137_u32, 0xF48CB45B_u32, 0x2A389206_u32, 0xA3A81669_u32, 0x6D8FA3DB_u32, 0x56D6F806_u32, 0x3674387D_u32, 0xFB176857_u32, 0x44F16D6F_u32, 0xB61F4E4A_u32, 0x721E48EF_u32, 0xE564907E_u32, 0x151EB983_u32,
+        0xB7C230C5_u32, 0x2A86294D_u32, 0x06A00F3D_u32, 0xF24364B9_u32, 0x734FF00D_u32, 0xDA482CA4_u32, 0xBEA6B3F9_u32, 0x1B60425E_u32, 0x40451515_u32, 0x3BC18625_u32, 0xFBA13683_u32, 0x059EB316_u32, 0x0DE54877_u32, 0x4BA18BFB_u32, 0x74A2AC40_u32, 0xE660DC5E_u32,
+        0xF35397B6_u32, 0x1A9D12DC_u32, 0x691AF9F7_u32, 0x1F4C0965_u32, 0x78962491_u32, 0x6D58C274_u32, 0x3FB963A9_u32, 0xDB191388_u32, 0xD6E1F359_u32, 0x040B7F9E_u32, 0xF71B8578_u32, 0x001CCC03_u32, 0xCDD49916_u32, 0x77DA828A_u32, 0x675DE81B_u32, 0x33AAAB70_u32,
+        0xB8C0FF2F_u32, 0xAB328033_u32, 0x4E228D6D_u32, 0xC33A87D2_u32, 0x3E881E53_u32, 0xBF0B0C37_u32, 0x8A61D2BF_u32, 0x4860CCD0_u32, 0xD7987635_u32, 0xF83EFD22_u32, 0xA2DF1E2B_u32, 0xD460BE8C_u32, 0xFCE0F421_u32, 0xF5D34DB0_u32, 0xEB313DC9_u32, 0xEE5607E5_u32,
+        0x11E62687_u32, 0x2AB13F47_u32, 0xDD6EC2FF_u32, 0x4599D163_u32, 0x06CCEE51_u32, 0xDFEF8D46_u32, 0xFEB63C91_u32, 0x540569E2_u32, 0xD7EBC09A_u32, 0x4C0D7243_u32, 0x87E156B4_u32, 0x733EB498_u32, 0x47619BEB_u32, 0xB0FE2307_u32, 0xF146A457_u32, 0xC88C32C8_u32,
+        0xC35E8F14_u32, 0x697BCF23_u32, 0x2E4577F8_u32, 0x9ACA7D6A_u32, 0xC73C6FDD_u32, 0x66344CED_u32, 0xE2670BFC_u32, 0x7B11E50A_u32, 0x8A38710C_u32, 0x718F305A_u32, 0x12C0377B_u32, 0x583660CA_u32, 0x1535CC5F_u32, 0x0BDEE5D7_u32, 0x2A93CD53_u32, 0x18488881_u32,
+        0xAB43C310_u32, 0xFE247728_u32, 0xC5FCCE5E_u32, 0xA45E9EE9_u32, 0x413662E2_u32, 0xFE2ADD51_u32, 0x65D482AD_u32, 0x0FA751F8_u32, 0x2086B734_u32, 0x4D7BFBDD_u32, 0x361BD400_u32, 0x0CCB6157_u32, 0xE9E0A08F_u32, 0x0821A0A1_u32, 0x112B4920_u32, 0x38438433_u32,
+        0x5861713A_u32, 0xBE8D72AA_u32, 0x0D26B8CB_u32, 0xA30AD9B1_u32, 0xCEBE574A_u32, 0x94AC0114_u32, 0x7FF21A6A_u32, 0xA6740661_u32, 0x795104FF_u32, 0x16E19B9B_u32, 0x975E9E57_u32, 0x740F3E67_u32, 0xDA36A3F2_u32, 0x8093A18B_u32, 0x1D2BF0A5_u32, 0x3F297203_u32,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO64SS_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0xE49F4CD6_u32, 0xB0FCF229_u32, 0x23FD3ABD_u32, 0x65FA86C8_u32, 0x803261FD_u32, 0xC58D71A2_u32, 0x6DC95E6D_u32, 0x13179B8F_u32, 0xA85541AF_u32, 0xDA680C39_u32, 0xA8A3C304_u32, 0x7DB9E1A0_u32, 0x7BBE1865_u32, 0x56E4424C_u32, 0xEC821999_u32, 0xB2C46DAB_u32,
+        0x42D0C45E_u32, 0x1389F91A_u32, 0xC425F3FE_u32, 0xA0195619_u32, 0xD94F72DC_u32, 0x867A4551_u32, 0xD76FAD0E_u32, 0xC73CDB76_u32, 0xF57C7D3C_u32, 0x2F40B6F4_u32, 0x7DB86E8A_u32, 0x660D0B0E_u32, 0xDBF411F0_u32, 0x269D7D84_u32, 0xD4768662_u32, 0x74E06D43_u32,
+        0x1E4E49F0_u32, 0xBDD8A4D2_u32, 0xC3060105_u32, 0xA517D911_u32, 0x1B6C76A4_u32, 0xDBBEC9DA_u32, 0x6208F108_u32, 0xCBAB2E50_u32, 0xA50ADE79_u32, 0x21969DD3_u32, 0x98C8FE41_u32, 0x232143C2_u32, 0x1834068E_u32, 0x430E9CF4_u32, 0xC31DDAD3_u32, 0x3AB6979A_u32,
+        0xEE1E8AB7_u32, 0xA4234BE7_u32, 0xC9AEC3E4_u32, 0xDDF724B4_u32, 0xABFBE602_u32, 0xB1A530CC_u32, 0xE15CC84A_u32, 0x416FF93E_u32, 0x1EE1BF53_u32, 0xB35A668A_u32, 0xCDD05E34_u32, 0xF5A15843_u32, 0xFC3710B6_u32, 0xFDCAF989_u32, 0x92E7F99C_u32, 0x1524A4DC_u32,
+        0x333DA106_u32, 0xFE108225_u32, 0x218EB636_u32, 0x92770AE4_u32, 0x262EB792_u32, 0x84ACB9EC_u32, 0xFED92EB1_u32, 0xFE8EE23C_u32, 0xB68C5091_u32, 0x1C598827_u32, 0x8146AF54_u32, 0x0635EB21_u32, 0x0CD422F1_u32, 0xC4641467_u32, 0xDC1B4C7D_u32, 0xF6DC7362_u32,
+        0x79A119CE_u32, 0x6AE1F047_u32, 0xDB33D1AA_u32, 0xFCC7A21B_u32, 0x7D201CB3_u32, 0x2E1DC641_u32, 0x6D9319BF_u32, 0x4249B4D6_u32, 0x8D9E19D8_u32, 0x0984F13E_u32, 0xFFE4DD1D_u32, 0xEA863157_u32, 0x007B30A1_u32, 0x9B264E75_u32, 0x366BF61F_u32, 0x8B284694_u32,
+        0x50DE9B98_u32, 0x1241168F_u32, 0x4F74C06E_u32, 0xCD59B873_u32, 0xF4555B7C_u32, 0x3D2FF23D_u32, 0xB1A0C64B_u32, 0x5955C5DB_u32, 0x06AD4774_u32, 0xD617CA3D_u32, 0x52D384F4_u32, 0xDBE7AB66_u32, 0x852EFEF7_u32, 0x8885BCDD_u32, 0x069F690F_u32, 0xE8B69D2C_u32,
+        0xC2543AF9_u32, 0xA742FCB3_u32, 0x38AEBEAD_u32, 0x4E501979_u32, 0x0309F93C_u32, 0x5FA03C54_u32, 0x83796690_u32, 0x6FDE5DD1_u32, 0x872C76C8_u32, 0x92E05FDE_u32, 0xA14E9F93_u32, 0x20683358_u32, 0x7E95C7FC_u32, 0x0117B757_u32, 0xBD340F1E_u32, 0xA469BA69_u32,
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO64S_32: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x54C92FC3_u32, 0xD4DA9A67_u32), (0xDC084560_u32, 0x7F117040_u32), (0x7D75D7A7_u32, 0xE4BC7042_u32), (0xC49D2E43_u32, 0xA13818B8_u32), (0x9EA50E4F_u32, 0xFB0CC87A_u32), (0xA0D06383_u32, 0x033C56F2_u32), (0x7BB1B760_u32, 0x4D741E6B_u32), (0xD0EE397D_u32, 0xD7F14BED_u32),
+        (0x4857344E_u32, 0x4928CB2F_u32), (0x7C1069AF_u32, 0x6802B5DA_u32), (0x641B98A7_u32, 0xB7667975_u32), (0xA4E3F2C6_u32, 0xECEC2F0E_u32), (0x87793BEF_u32, 0x1F52FD04_u32), (0x2FA99911_u32, 0xB8691F3C_u32), (0x604592BD_u32, 0x535FC714_u32), (0x103DCF86_u32, 0x662333CB_u32),
+        (0x5DFF1148_u32, 0xFED79B08_u32), (0xE51C0A06_u32, 0xD8EE36B1_u32), (0xBB005082_u32, 0x0083C42A_u32), (0x29A31948_u32, 0x9317752E_u32), (0xCE5D2E22_u32, 0x3744A39B_u32), (0xC3CE73E0_u32, 0xD5DAB778_u32), (0xF7E0D58D_u32, 0xF6E7C791_u32), (0xEB020A1F_u32, 0xE4CC3B37_u32),
+        (0x6BDF2B70_u32, 0xEFEFFCDE_u32), (0xC87EF19E_u32, 0xE4168C8B_u32), (0x95377641_u32, 0x932F7FDC_u32), (0xB749C3C9_u32, 0xE903CA0D_u32), (0xCE254C10_u32, 0x4DEEF330_u32), (0xBD95A823_u32, 0x0F554DD4_u32), (0xC513DAA1_u32, 0x1699CBE4_u32), (0x5869DDB7_u32, 0x932FAB10_u32),
+        (0xC2582297_u32, 0x14628E4E_u32), (0x0136AB5A_u32, 0x046A8E62_u32), (0x02F3A7CC_u32, 0x7A9D9DEE_u32), (0xB5BCB4FC_u32, 0xC5A96716_u32), (0x575618CB_u32, 0xEE5DB8F7_u32), (0xD5455F23_u32, 0xE860E8F0_u32), (0x678B21E8_u32, 0xC0B31F7F_u32), (0xEB5FF7F7_u32, 0x23AA2AEC_u32),
+        (0x491BCB46_u32, 0x9FE7D98D_u32), (0x437FB109_u32, 0xDAFF2B61_u32), (0x64F8256F_u32, 0xDAC591E5_u32), (0x30495BE3_u32, 0x3DDB26AC_u32), (0x824C85C5_u32, 0x5C99A6E7_u32), (0x677A6C4E_u32, 0x1F0C2E69_u32), (0xAF1E4DA3_u32, 0xDBD6D36F_u32), (0x4C835E41_u32, 0x1575CF5F_u32),
+        (0x0566D1B4_u32, 0x7E72D7B5_u32), (0x98F45C95_u32, 0xE3D7F016_u32), (0x725CBA15_u32, 0xB0E41957_u32), (0x2D8D5E1D_u32, 0x4E4F0F26_u32), (0x6741F1D5_u32, 0x3475CE12_u32), (0x74E1B84D_u32, 0xF283F9E8_u32), (0x5FEA4FB1_u32, 0x6196DC49_u32), (0x4922B677_u32, 0x249C897C_u32),
+        (0x6299C329_u32, 0x5A8E53CE_u32), (0x58694110_u32, 0xA4DC89DB_u32), (0x0EAC8C01_u32, 0xD714226A_u32), (0x72E5AA9E_u32, 0x4214191D_u32), (0x0B8CDE76_u32, 0xBA44DCD8_u32), (0x0A39A7AD_u32, 0x73857CB6_u32), (0x87061B4A_u32, 0xB968B545_u32), (0x0F9F31EB_u32, 0x6B237984_u32),
+        (0x416687BA_u32, 0xED45B47A_u32), (0x0F873BAC_u32, 0x27E92FE1_u32), (0x5017FD6C_u32, 0x43511644_u32), (0x17DE9E33_u32, 0x5E8E2E2B_u32), (0x50F3A608_u32, 0xB6745377_u32), (0x8DC04226_u32, 0x14502DFA_u32), (0xA463FFCA_u32, 0xB78C00D5_u32), (0xBF523181_u32, 0xE231F4D8_u32),
+        (0x4EF81AAF_u32, 0xC76EDAA3_u32), (0x0C02A438_u32, 0xEDA82EFF_u32), (0x2B648DE5_u32, 0xFB389FEB_u32), (0xAC8DF1E6_u32, 0x330617B8_u32), (0x4FADADDE_u32, 0x74E4F28E_u32), (0x53434B92_u32, 0xD2F796F9_u32), (0x610C354E_u32, 0xC2F6EDB2_u32), (0xDC38482C_u32, 0xA9EFA65F_u32),
+        (0xEE280578_u32, 0x07354454_u32), (0xB3DD1395_u32, 0x066D87FE_u32), (0x0C92FEC5_u32, 0xA8F06379_u32), (0x9ACAB1F6_u32, 0xE983DA29_u32), (0x3B3F53A2_u32, 0x854A4A3B_u32), (0x1144FD83_u32, 0x11179EC9_u32), (0x37C9F65B_u32, 0xF845858B_u32), (0x661FB1BB_u32, 0x3583629E_u32),
+        (0xE3B282AB_u32, 0x7B78F7BC_u32), (0x3BEFB8F0_u32, 0x97F6FEE4_u32), (0x301B7453_u32, 0x6BBC64A7_u32), (0x1B9F7E32_u32, 0x7B6ECFCA_u32), (0xFD0C2C73_u32, 0xBCF874D9_u32), (0x32C22EF9_u32, 0x77F56F45_u32), (0xE2A528E3_u32, 0x7DA45F5E_u32), (0x304C45F3_u32, 0xD5937753_u32),
+        (0x2800CA8F_u32, 0xE002FFFD_u32), (0xA097FAEA_u32, 0xDBBE19DE_u32), (0x2171B245_u32, 0x21ED646F_u32), (0x54D390A5_u32, 0x4495C563_u32), (0xAD77A367_u32, 0xA1EC1B36_u32), (0x7384B4A8_u32, 0x70524B06_u32), (0x93BEB494_u32, 0x9AB10412_u32), (0x74077065_u32, 0xE57317B8_u32),
+        (0x92B0AC9A_u32, 0xFFC9C6F8_u32), (0x4FBCFDB3_u32, 0x6A4B2134_u32), (0x056EAFEA_u32, 0x842E91CD_u32), (0xA02DB1D2_u32, 0xD38C64C6_u32), (0x06C68F5B_u32, 0x6241691A_u32), (0x025057BC_u32, 0xF8A36A54_u32), (0xF034FC42_u32, 0xA9BC157F_u32), (0x31C0EAD4_u32, 0x294B21CF_u32),
+        (0xC6A375DC_u32, 0x86B30DB4_u32), (0x692574FB_u32, 0x6A3724BF_u32), (0x9CFA955D_u32, 0xCC4AE781_u32), (0xD4123EA7_u32, 0xA6D0919B_u32), (0xA690ECF1_u32, 0x48425591_u32), (0x3C4AA142_u32, 0xC42B7D4A_u32), (0x7FEDEB30_u32, 0x7E2BE80F_u32), (0x5EB76792_u32, 0x493670B7_u32),
+        (0x2728269F_u32, 0x5BFAB43C_u32), (0xB8419A3D_u32, 0xF9D9F680_u32), (0xF67BD517_u32, 0x72CC99B3_u32), (0x9297F16C_u32, 0xBC6C6AEC_u32), (0xCA1D394F_u32, 0xDC1545A1_u32), (0x7163EBBD_u32, 0xC983E6FB_u32), (0x1FFB31C6_u32, 0x647C2C32_u32), (0x4BA4086A_u32, 0xA5F9F14B_u32),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO64S_48: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x32D7511F_u32, 0xC530FA4E_u32), (0x34EF3425_u32, 0xFA4F03D5_u32), (0x4AE49CE4_u32, 0x0469C44D_u32), (0x17FACEFF_u32, 0x98BC489E_u32), (0xF707F20E_u32, 0x9900071A_u32), (0xB88FD6BC_u32, 0xF0BFAF3F_u32), (0x989D2FE5_u32, 0x202CF702_u32), (0xC7CC1258_u32, 0x2E187E69_u32),
+        (0x32EFC201_u32, 0xAB20C036_u32), (0x749FC135_u32, 0xA8F9D7EA_u32), (0xFC9F78DD_u32, 0xEDC4C872_u32), (0x94A94698_u32, 0xA32854DD_u32), (0x8B1B5A01_u32, 0xCCCA18F8_u32), (0x3CDE13F6_u32, 0x38FA42C8_u32), (0x08E0D7D7_u32, 0xCD080C5F_u32), (0xACD4788C_u32, 0xB4C45C83_u32),
+        (0x08AADE53_u32, 0x794AF176_u32), (0x4BC615C1_u32, 0xCA58C18B_u32), (0xD5396BCB_u32, 0x4AE75D02_u32), (0x5D48B712_u32, 0x139F2244_u32), (0x2FE44769_u32, 0x214D8E93_u32), (0x67913591_u32, 0xAA1D658B_u32), (0x18B08ADC_u32, 0xAFABC059_u32), (0x9E03CD33_u32, 0x37C0914C_u32),
+        (0xB4BF373D_u32, 0xA7DBA83D_u32), (0x9C9B6275_u32, 0x2C50C0C9_u32), (0x9A1DD8BF_u32, 0xCCD22F97_u32), (0x53C737E1_u32, 0x72E45443_u32), (0x28637CA0_u32, 0x4D37F3BB_u32), (0x1B04DFD4_u32, 0xEDB989D5_u32), (0x06866421_u32, 0xFC301D4E_u32), (0x9765158D_u32, 0x894FD4BA_u32),
+        (0xBB1F0CCF_u32, 0xA2550EA1_u32), (0x5D3BB5F1_u32, 0xE2FD30A4_u32), (0x2BF3478C_u32, 0xE6D28638_u32), (0xF5142F4B_u32, 0xFE59844E_u32), (0x3C6D8CB6_u32, 0x364DAD05_u32), (0x46B68B36_u32, 0xAB9D0580_u32), (0x9E51C868_u32, 0x439F6ADA_u32), (0xFD72DC56_u32, 0x8595B44C_u32),
+        (0x76BE3D4C_u32, 0x574AE522_u32), (0xBBA1FB07_u32, 0xE45DFF67_u32), (0xFC948402_u32, 0x8DCF65F9_u32), (0xE297C1E7_u32, 0x1AD2C567_u32), (0x538F5C5B_u32, 0x7CE79A2A_u32), (0xD06AA7EB_u32, 0xD5E8CF8C_u32), (0x7F696A48_u32, 0x09BD04EC_u32), (0x88C5FB7C_u32, 0x0AE187A6_u32),
+        (0x6A91FC3D_u32, 0xD0B23624_u32), (0xF5DBDCA9_u32, 0x54371535_u32), (0xD7A766BC_u32, 0x51F14F4F_u32), (0x1BB73896_u32, 0x1DDE9851_u32), (0x02A10F42_u32, 0x693617D0_u32), (0x2596435F_u32, 0x183B888D_u32), (0x46E3C713_u32, 0x4E6A12A5_u32), (0x49730F77_u32, 0x960C8C15_u32),
+        (0x28A4472A_u32, 0x77ACE9EC_u32), (0x1AA07819_u32, 0xD51D6194_u32), (0x9B743FD6_u32, 0xC16C6B01_u32), (0x561429F9_u32, 0x0FC1E880_u32), (0x809BD5D3_u32, 0xDD510A01_u32), (0xFE467294_u32, 0x8C785675_u32), (0xBA4A91FB_u32, 0xD09578D4_u32), (0xFF4E2BDC_u32, 0xF7982146_u32),
+        (0xD0AF6736_u32, 0x053F0857_u32), (0xE29FA2A9_u32, 0x4919D800_u32), (0x20FD7C0D_u32, 0x0BDCB4FF_u32), (0x7D37BE50_u32, 0x87C0656F_u32), (0x8F0183B6_u32, 0xD680A3D2_u32), (0xF89CE64E_u32, 0xD7FF2791_u32), (0xE0284BDD_u32, 0x64C3C794_u32), (0x59750E7E_u32, 0xCFEDCB25_u32),
+        (0xE49F1CBD_u32, 0xD36513CA_u32), (0xA93887D0_u32, 0x888A509D_u32), (0x95C90FAA_u32, 0x8F8D4861_u32), (0x97B6C242_u32, 0xCDD3095B_u32), (0xB0F9F471_u32, 0xF4F1AF6B_u32), (0x3179A5A5_u32, 0x2B8A9269_u32), (0xA267CBFF_u32, 0xEA058079_u32), (0x6EAC8154_u32, 0x4876CF23_u32),
+        (0x060ACA55_u32, 0x36677EBC_u32), (0xEA3D05CC_u32, 0x4329AA1A_u32), (0x2569543D_u32, 0xBACD7E2E_u32), (0xF06864E2_u32, 0x5E897FD9_u32), (0xEE026779_u32, 0x62D10BB8_u32), (0x906F6EA6_u32, 0x39FFF41F_u32), (0xA00E8BD7_u32, 0x41BCFF3B_u32), (0xF787EE9F_u32, 0xF2E6FF0F_u32),
+        (0xDCF2E4E7_u32, 0x46F6F052_u32), (0x1086CA2D_u32, 0x2BD4EB02_u32), (0xA66D017C_u32, 0x331E48BF_u32), (0xACEC7543_u32, 0xE75A863A_u32), (0x12CB21C9_u32, 0x8D7F1F1D_u32), (0xBEFA08E6_u32, 0x32006751_u32), (0x8AFD8A5B_u32, 0x81366001_u32), (0x5F8A5EA6_u32, 0xE09CBDD5_u32),
+        (0xEEEDCA1F_u32, 0x623DECF7_u32), (0x946C7C4A_u32, 0x235634DC_u32), (0x65EFAD16_u32, 0x1448B3A7_u32), (0x0338DE05_u32, 0xBA3E25BC_u32), (0x0CA6E140_u32, 0x5D7D7DF9_u32), (0x831D8803_u32, 0x372A0A92_u32), (0x5EF422C1_u32, 0x066B5E62_u32), (0x06E347EA_u32, 0x01585112_u32),
+        (0x19B2B82B_u32, 0x6377A51E_u32), (0xC43B1F7A_u32, 0x37E757B1_u32), (0xF7497069_u32, 0x79557049_u32), (0x4CFFC22E_u32, 0x5E83FCE7_u32), (0xDA69B1F4_u32, 0x20CD6D63_u32), (0x7FF1867F_u32, 0x8E4F4D55_u32), (0x903EF6C5_u32, 0xEF4D5CEA_u32), (0x5D02B50D_u32, 0x2A11522E_u32),
+        (0x24470407_u32, 0x32012BCE_u32), (0xD56E3EC8_u32, 0x2981F266_u32), (0xFD651490_u32, 0x8D01C242_u32), (0xF0EF4FC9_u32, 0xC19C2768_u32), (0xB04BA0B3_u32, 0x859A5F1A_u32), (0x9EFDCE95_u32, 0x790BE84A_u32), (0xC5600C44_u32, 0xCFA1B471_u32), (0x517A8569_u32, 0x8775DDC2_u32),
+        (0xDE7EC5F0_u32, 0x4E9E8003_u32), (0x0813697E_u32, 0xE02B440E_u32), (0xDCDD0EAB_u32, 0xED8FF10E_u32), (0x5D20F8FA_u32, 0xB663F0B2_u32), (0x26E7EC98_u32, 0x432B727B_u32), (0xCE7A4FD5_u32, 0x42F5DEBD_u32), (0xA180CA0B_u32, 0xCC654460_u32), (0x037422F6_u32, 0xE09EAFCA_u32),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO64SS_32: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x1D7DEEF9_u32, 0x83CE7691_u32), (0xE0A95040_u32, 0x04201ABF_u32), (0x61906FBA_u32, 0x00D2BEB5_u32), (0x4E4A7310_u32, 0x5E998C9F_u32), (0x5D24E187_u32, 0x25263DD7_u32), (0xFCA64F68_u32, 0x5D9E56E7_u32), (0x4626044E_u32, 0x54BAA046_u32), (0x9DDFE10D_u32, 0xA2F4DD02_u32),
+        (0x8EEA3F0F_u32, 0x60CC9729_u32), (0xF965E7BD_u32, 0xD1E7EBD1_u32), (0x15F7B51A_u32, 0xBAEBA825_u32), (0x25727A6E_u32, 0x8A0439AB_u32), (0x83C8A1E9_u32, 0x444E999C_u32), (0xE96FD12F_u32, 0x4D02E458_u32), (0x397CEDDE_u32, 0xD88C71B3_u32), (0x2EDE6F7A_u32, 0x5BC83B8C_u32),
+        (0xB40396D5_u32, 0xC8DBAF74_u32), (0x9CAEFB14_u32, 0x24D2B3E2_u32), (0xA9787D54_u32, 0x3FDD5119_u32), (0x725BEBE2_u32, 0xCC3C1E27_u32), (0x78CF0E3C_u32, 0x6F119DAB_u32), (0x66D74DB8_u32, 0xC035D9E3_u32), (0x59F15823_u32, 0xF9D7C6D7_u32), (0x7FC5277C_u32, 0x31D954F6_u32),
+        (0xC0B0A3C8_u32, 0x154EAC35_u32), (0xF855C08E_u32, 0x8368BBD9_u32), (0xD596711E_u32, 0xE01A8CB9_u32), (0xD4041DED_u32, 0x47F07DAD_u32), (0xED4E1BC6_u32, 0x5DB2ED7B_u32), (0xBAF03D2A_u32, 0xA3FBCEAD_u32), (0xDF8FC72E_u32, 0x4AB10686_u32), (0xD697F4A1_u32, 0x6A7197BE_u32),
+        (0x3F1CBC8E_u32, 0x19C5E3F5_u32), (0xF41CBAA1_u32, 0x40A64F1E_u32), (0x272FC37C_u32, 0xEE4C2EED_u32), (0x4417ADA8_u32, 0x4C84F6E9_u32), (0x7A420CDD_u32, 0xAEB8B572_u32), (0x87E9A9C2_u32, 0xC6982EC5_u32), (0xA2E635D3_u32, 0x0888BEE8_u32), (0x89422D72_u32, 0x0CE7345F_u32),
+        (0x466EF7D3_u32, 0xA96F97A4_u32), (0x3BE218F9_u32, 0xF6C30991_u32), (0xE38BF2A3_u32, 0xD42A3128_u32), (0xF7F32E1A_u32, 0x2E21665A_u32), (0x4C4A535E_u32, 0x6103B531_u32), (0x5547C9E8_u32, 0x6718D1DF_u32), (0xADA40A12_u32, 0x553364DB_u32), (0x39CC5A49_u32, 0x19C53CAA_u32),
+        (0xBEB7B8F6_u32, 0x423A3175_u32), (0xA164F8E9_u32, 0x1422DDF0_u32), (0x4ADDB065_u32, 0x2E9616E2_u32), (0x08563A2C_u32, 0x28F7DF08_u32), (0x1B4E871E_u32, 0xEF6E8DC1_u32), (0x98C12D19_u32, 0xB51A4CA3_u32), (0x7CBC0B47_u32, 0x5BBBF02E_u32), (0x36B50546_u32, 0x2DA3C24A_u32),
+        (0xB86CF8B8_u32, 0xB21F8A80_u32), (0x15B4FE35_u32, 0x0BFD9F3D_u32), (0xAAD72D6F_u32, 0x1ECB832F_u32), (0x2BB6BCA9_u32, 0x1B914060_u32), (0xBC1C99A2_u32, 0xEF44F99C_u32), (0x258543CC_u32, 0x820DB904_u32), (0xA9D298CE_u32, 0xA1AD6BCE_u32), (0x12CDE45E_u32, 0x49490FE4_u32),
+        (0xD3F5C518_u32, 0x7703A617_u32), (0x1C476F11_u32, 0x671BFA05_u32), (0xA08730E6_u32, 0xA9335DA6_u32), (0x3E6B3731_u32, 0xEE8864C4_u32), (0xBCA39BF3_u32, 0xC2812E39_u32), (0x672A4DC0_u32, 0x096580C9_u32), (0x3B074A56_u32, 0x9A4AF07E_u32), (0xDF08B443_u32, 0xE59C4417_u32),
+        (0xB9F935E3_u32, 0x452BBF9A_u32), (0x8BB35285_u32, 0x87139848_u32), (0xC22EC522_u32, 0xC418E051_u32), (0x6F01D6D7_u32, 0x84BEEE42_u32), (0xC7D7554A_u32, 0xA664E099_u32), (0x463ADDFF_u32, 0xDB58963A_u32), (0x7749838B_u32, 0x78EF976F_u32), (0x25852BFF_u32, 0x456D43A2_u32),
+        (0xA51AAA85_u32, 0xD7AFF291_u32), (0x36BFAED3_u32, 0xDED95C8A_u32), (0x66A7BA63_u32, 0xD356C898_u32), (0xF23C986B_u32, 0x553FA50F_u32), (0x0A32CA41_u32, 0x7BC16C91_u32), (0x7CA45F5F_u32, 0x82204B46_u32), (0x545E27AC_u32, 0xCF876862_u32), (0x7CCFA21C_u32, 0x34AF820B_u32),
+        (0x3211F885_u32, 0x00ECA317_u32), (0x9605C07E_u32, 0x18733BF3_u32), (0xC58A3ED0_u32, 0xC08DE5A9_u32), (0xA69306CB_u32, 0xE22061E6_u32), (0x71F2E8CB_u32, 0x500154F8_u32), (0x0AC43E26_u32, 0x97B3DF19_u32), (0xC5270E60_u32, 0x83D4F0EE_u32), (0xF1064FE5_u32, 0x2C1B0679_u32),
+        (0x74AEBEBA_u32, 0x2DFEB1BE_u32), (0x86CD6133_u32, 0xF505A2AD_u32), (0x71A8B7B4_u32, 0x3B2A40E2_u32), (0x8A029B39_u32, 0xF62284CB_u32), (0x99A2E844_u32, 0xD912F9AE_u32), (0x8BFFF0DB_u32, 0x67EA5709_u32), (0x6A85EAD5_u32, 0x9CB3F300_u32), (0x38838C97_u32, 0xE32E14CA_u32),
+        (0x4571023F_u32, 0x83C66DE6_u32), (0xF80E1D0A_u32, 0x3CCF455E_u32), (0x59A2D4F8_u32, 0x596305DA_u32), (0x9A8676B7_u32, 0xCBAA7403_u32), (0xEE229974_u32, 0x786835AB_u32), (0x901920E8_u32, 0x43208C94_u32), (0xBE47A2CD_u32, 0x9E834996_u32), (0xB44C9DCB_u32, 0x1BA506C3_u32),
+        (0x2B7B67BB_u32, 0xD8067A8A_u32), (0xE6ED92CE_u32, 0xE539D2D5_u32), (0x459C2754_u32, 0x3B942ABB_u32), (0xBDB8C6E3_u32, 0x68A2DF47_u32), (0x10E3A851_u32, 0xBEC9742B_u32), (0xFA5302E7_u32, 0xEEB3A3A9_u32), (0x33C5F220_u32, 0x0A09178A_u32), (0xBE1AF2CC_u32, 0x83A0D4A3_u32),
+        (0xDD7B98CF_u32, 0xAC13B5A1_u32), (0xE0015ABE_u32, 0xC9C867DD_u32), (0x64133086_u32, 0x7E04F610_u32), (0x11FEE551_u32, 0xBFDBE27F_u32), (0x8A1084AE_u32, 0x2B1AE80F_u32), (0xB78C78EF_u32, 0x2C5B2E30_u32), (0xF4ED1577_u32, 0x7BF7DE45_u32), (0x8F30F858_u32, 0xC0C5BD85_u32),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO64SS_48: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x1E4A9E4B_u32, 0xB9F0EA26_u32), (0x2779B885_u32, 0xDC35D1E3_u32), (0x66D3764F_u32, 0x01116C87_u32), (0x25B00E6F_u32, 0x26898D42_u32), (0xE9FDC9A8_u32, 0xA53B607F_u32), (0xD930A03C_u32, 0xD2E197EE_u32), (0xD6351A46_u32, 0x57AB924B_u32), (0xB17404E0_u32, 0x12D0F30E_u32),
+        (0x92E875D8_u32, 0xD9A66EEB_u32), (0x28160BDE_u32, 0x5C4C6807_u32), (0xE34395CA_u32, 0xD48C60D7_u32), (0x1E3E81A3_u32, 0x205F074A_u32), (0x063930A2_u32, 0xE5AA5F23_u32), (0xD982400B_u32, 0x3CA9B218_u32), (0x66B5930F_u32, 0x32E3F40E_u32), (0x720ECFC2_u32, 0xB46900B4_u32),
+        (0xA8A1555E_u32, 0x68E330BD_u32), (0x0498D415_u32, 0x10D81A3D_u32), (0xC82A3BF6_u32, 0xAEE07B89_u32), (0xDB055170_u32, 0x327CDEFE_u32), (0x31EC89F7_u32, 0x838C03E8_u32), (0xC186EFFC_u32, 0x8330DDEC_u32), (0x59CAD5DD_u32, 0xAA6DE9CE_u32), (0xF98051E7_u32, 0xFEBA6DEE_u32),
+        (0xFF57D7AF_u32, 0x52E4EB7F_u32), (0x2A75DAAC_u32, 0xA1604ABF_u32), (0xC46BE341_u32, 0xDAF9D44B_u32), (0xCAF9FF43_u32, 0xB3A47639_u32), (0x8094288B_u32, 0x580F9CA4_u32), (0x14272CE8_u32, 0x22B22236_u32), (0x26EDEE24_u32, 0x679F6C21_u32), (0xF8E26949_u32, 0xD6F1B0F0_u32),
+        (0xEF87CDBF_u32, 0x45A5C48D_u32), (0xE3B2A7E4_u32, 0xBBE5A862_u32), (0xA0004A48_u32, 0x933F7903_u32), (0xB2A778B7_u32, 0x2C4750A6_u32), (0x7D588EC8_u32, 0x49E91AE5_u32), (0x3359FD69_u32, 0x356357F4_u32), (0xCAAF7EDE_u32, 0x19299CAD_u32), (0x3F28E0C0_u32, 0xBD429D45_u32),
+        (0xFCF2258C_u32, 0x5FAD8CBE_u32), (0xE72B75A3_u32, 0xAC72F147_u32), (0x603F6AA6_u32, 0x93F78FF0_u32), (0xABB7B303_u32, 0x6E90D2F5_u32), (0xAA084775_u32, 0x92F8DD0F_u32), (0xB704D35C_u32, 0x45276A2F_u32), (0xCFF4E223_u32, 0xC9936C60_u32), (0x9EE230D8_u32, 0xB2C87A6F_u32),
+        (0x36C38D34_u32, 0x23377423_u32), (0xE6F25557_u32, 0xC36DB338_u32), (0x31931905_u32, 0xCB710FD5_u32), (0x5A238729_u32, 0xA0B345A0_u32), (0x91AA6062_u32, 0x51ACE501_u32), (0x1C63C9DD_u32, 0x65A80D7F_u32), (0xD5536F2B_u32, 0xA9CE60FF_u32), (0xA71AA279_u32, 0x14895028_u32),
+        (0x6FE6D184_u32, 0x6BAE380A_u32), (0x4786DFBC_u32, 0x376C6A5F_u32), (0xA9C781D5_u32, 0x0C118B7E_u32), (0x153D060B_u32, 0x58BC6003_u32), (0xCFB07AB7_u32, 0x47C632F1_u32), (0x7BFDFEB8_u32, 0x0DF9967B_u32), (0x97E7FCC9_u32, 0x5C37C27A_u32), (0x575738FC_u32, 0xA83090B0_u32),
+        (0x7363DD06_u32, 0x5F2D4D77_u32), (0xF2CFED33_u32, 0x0C1A6B3F_u32), (0x58EA6931_u32, 0x2CC67AFE_u32), (0x9CED8B04_u32, 0xB9437A6A_u32), (0x3DFA8026_u32, 0x36778F48_u32), (0x0A6CDB7F_u32, 0x1B58E851_u32), (0x81C649B0_u32, 0x9AC3B94D_u32), (0xB5267900_u32, 0xEFB3731F_u32),
+        (0x1B6B0E0E_u32, 0xAEE0B405_u32), (0x8732F3E6_u32, 0x7FC724C2_u32), (0x14540535_u32, 0x2A372B41_u32), (0x158FB5DC_u32, 0x455291F8_u32), (0x623778AC_u32, 0x2A7376EA_u32), (0x6D1D3F2A_u32, 0xEEE0503B_u32), (0xC0A271F4_u32, 0x380C6E52_u32), (0xDECEB7CF_u32, 0x631A4E7E_u32),
+        (0x1482D7FD_u32, 0x1336AFB5_u32), (0xA3610D63_u32, 0x1792807C_u32), (0x18DF43DF_u32, 0x6076AE62_u32), (0x4E99F2D4_u32, 0xA63CE246_u32), (0x0A08E0AD_u32, 0x347487BC_u32), (0x3F9F5FA7_u32, 0xD2160277_u32), (0xA7B8C347_u32, 0xDE028DEF_u32), (0x822CD9A4_u32, 0x4A8B8D50_u32),
+        (0x74ED9513_u32, 0x35CA5BD3_u32), (0xFDB65617_u32, 0x4D99FB7C_u32), (0xD08BD2CC_u32, 0xB16F469E_u32), (0xBFB103B3_u32, 0x49F2F70E_u32), (0x979D3102_u32, 0x0ACD945C_u32), (0x502EEC16_u32, 0xE36040E3_u32), (0x825124BA_u32, 0x6E0593DE_u32), (0xDB742DE3_u32, 0xCF26B124_u32),
+        (0x49F9FACE_u32, 0xFF72857A_u32), (0x6E5764C7_u32, 0xFE8A74BA_u32), (0x319D5C26_u32, 0x04C97652_u32), (0xE96E7967_u32, 0xDD594974_u32), (0xF9EC753F_u32, 0x4757A9CC_u32), (0x041568F5_u32, 0xA9FC4F33_u32), (0x7D63FB22_u32, 0x532C440D_u32), (0xD0E30E14_u32, 0x57DA5B9C_u32),
+        (0xC880B5C3_u32, 0x0A2B11EE_u32), (0x6C230542_u32, 0x817F7544_u32), (0x9CD6EBFC_u32, 0xD4F597C9_u32), (0xFCCD4301_u32, 0x8A9A7861_u32), (0x36B16052_u32, 0x58CDA2DD_u32), (0x67AADCB1_u32, 0xF3FACDDD_u32), (0x3775F276_u32, 0xEA33E3FB_u32), (0x058BCFD6_u32, 0x71588EBE_u32),
+        (0xF8BBFD8A_u32, 0x57C640DF_u32), (0x82C5EEC1_u32, 0x901FE609_u32), (0x67533AB5_u32, 0xEFAAF566_u32), (0xF13F8BA6_u32, 0x6D45B2E7_u32), (0x1B57F7B6_u32, 0x0176C05B_u32), (0x306D9BA8_u32, 0x804D8536_u32), (0x863ED7A5_u32, 0x6D9BE16E_u32), (0xF724865D_u32, 0x6A4175FF_u32),
+        (0xA60FF94F_u32, 0x5E95F87C_u32), (0x8A784CEF_u32, 0x6D4F86AA_u32), (0x38FA47F3_u32, 0x795C3C34_u32), (0x0E79C989_u32, 0x9F058D47_u32), (0xBE150C2C_u32, 0xA738FFDC_u32), (0xF852639C_u32, 0x09E7E644_u32), (0x6F2890F4_u32, 0xF63BF954_u32), (0x64408CF0_u32, 0x51A8B576_u32),
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024S_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x505422BF52ACC11C_u64, 0x3CB2CFFD381868DD_u64, 0x659BB9FB2CD3F484_u64, 0xB43965FF41C9BB81_u64, 0x7755AAE129F36F5E_u64, 0xB7830F192AD1C2B3_u64, 0x351360A02AF7C574_u64, 0x32E444965A89C079_u64,
+        0x343CFB179BFD8452_u64, 0x891392D57651E3AB_u64, 0x228AEB2962ECB442_u64, 0x84FDBCAB22CEDE21_u64, 0x41E2BFF0EEE3527D_u64, 0xAD4EE463B4BBD4DB_u64, 0x775C456187F160B1_u64, 0xC9AC1264A30286A5_u64,
+        0x5969272052A266D8_u64, 0x6C82028098063B5B_u64, 0x4653E3A32A187106_u64, 0x37BC076999A6DC83_u64, 0x24C70C96E9DA5906_u64, 0xDDF5B582F6C64481_u64, 0xDCEAEE752D888C54_u64, 0xB7D096CBD909E9E3_u64,
+        0xFC00622C3A8283D9_u64, 0xA68A358EACC1BF4C_u64, 0xCF1F5B7AABFB0197_u64, 0xEA91C99A469CC9F8_u64, 0x86FCB17CF2C1ACE1_u64, 0x60696597DBA9B9C1_u64, 0x5C63094853577767_u64, 0xC1CF6B473E38D08D_u64,
+        0xFCA339F02D303322_u64, 0x362F00709F51655A_u64, 0xEB2983DE0745AE38_u64, 0x6652DDE74015054F_u64, 0xBBC5C8B313A71DAD_u64, 0x73FC04D0127F6DD1_u64, 0x2F8CB7C71B093FC2_u64, 0x1A0200D942D88998_u64,
+        0x2582B2FB9BA5C49A_u64, 0x4B8516E4572120D9_u64, 0xF9095BE02D10FF9C_u64, 0xEB498E8525D6E4D9_u64, 0x0B23CE75766B75A0_u64, 0xD2A8B70E76D2ACF3_u64, 0x9CE129AB065FDEAC_u64, 0xE108D249CD3BFBF2_u64,
+        0xDCFF01ADFA720D29_u64, 0x46D8EE3D46279992_u64, 0x0FE794CB0E58B78F_u64, 0x88AD5BE860B65528_u64, 0x382384F6E103D3AD_u64, 0xF4CD8D657366815E_u64, 0x63F3B27E6000AC82_u64, 0x5E60F20CF6D97262_u64,
+        0xEF5EDBD4D5128E52_u64, 0xE4FF62869BB9AE91_u64, 0xA99B245530A14DD0_u64, 0xDFDED5D01870FDEE_u64, 0xDBEDE8FEA29E5C18_u64, 0xB12E782FFCAE11D1_u64, 0x18A18A13EDF433EA_u64, 0x56C7BD69CE607741_u64,
+        0xD0C97504CA3FB596_u64, 0x0DDE0F9F45FCAA91_u64, 0xFB58BC61CF45712C_u64, 0x729B378200558BA7_u64, 0xBA1F0B560CFEFDE9_u64, 0x9F792A12A7B6FC64_u64, 0x298AB286A9ED6DC7_u64, 0x5FCEC8FC92A00CFB_u64,
+        0x604C22C44107AE6A_u64, 0x678C87016E2DC097_u64, 0x1DA978AD9583A445_u64, 0x3AF90E7E8648672C_u64, 0x8C8919D2B798AD64_u64, 0xF1313C8EE0B1F416_u64, 0x04725D7DD4ECE551_u64, 0x7165EAA6701773FE_u64,
+        0x83FE5DA7EBEC8919_u64, 0xF7E622409CE20F36_u64, 0x0A12F9F9A845CD9D_u64, 0xF505BA255C33F009_u64, 0x56DA772CEED509B5_u64, 0x2F0601975E50E582_u64, 0x50E0F55AEC470933_u64, 0xF1428359BF415BD3_u64,
+        0x846115232EEE316E_u64, 0x88325182F080DE9A_u64, 0x0D6661A508DC5304_u64, 0x732745ABF5DB9841_u64, 0x02D2C43C8046DF1F_u64, 0x649388ED7DF8362E_u64, 0x3CF826077DDEF396_u64, 0x1E288F69AE869B27_u64,
+        0xB3C2CB064639C8DA_u64, 0x53376DA4E2408BBD_u64, 0x1D43013CE3472B05_u64, 0xBC2BB6C6B662FA0D_u64, 0x6AFBEB575FDCFDFB_u64, 0xD6D67DCB2AB77D1A_u64, 0x58075B3CC1EDAFAE_u64, 0x35DED2FBDAD232BC_u64,
+        0x2750B631D6822FA7_u64, 0xD64F3C38AFE49A6E_u64, 0x58119566B22466DE_u64, 0xF17C374972025E53_u64, 0xBD6F6612EB0372C1_u64, 0x9D0D62424E1BE200_u64, 0x95BDD52EA47F5503_u64, 0x21BF6981586D1350_u64,
+        0x20C3A7939767B6E2_u64, 0xCE02C463E13A2939_u64, 0x78303172794FAD28_u64, 0x69C376604CC1D094_u64, 0x71468462D1BD1C8F_u64, 0xDF59C1CA6B5B8874_u64, 0xBBBDF8530AB89AAB_u64, 0x3455DCB67309F3F8_u64,
+        0xCD7DEE46858D72E7_u64, 0xD8F7B9568E9662CB_u64, 0xF14EDB98E6DCE950_u64, 0x6EA34922EA17F2B9_u64, 0xE3C1D785D2A02020_u64, 0x3C768ADDAE09C31C_u64, 0xF18D785563D0AB11_u64, 0x6FE5BD28E276F92F_u64,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024S_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x3DF045BBD20444AD_u64, 0x894E7BDFD2878F47_u64, 0x430FBBCDE823251F_u64, 0x1E4E53AD59D94991_u64, 0xC1F73019331CA452_u64, 0x80F31DB5AADD19C6_u64, 0xBD6AF7AE969BE093_u64, 0x5AC03BF1476172CD_u64,
+        0x5DA69CD05929C773_u64, 0x3DEACFB98FC47AA8_u64, 0x223D8F96A2D436E5_u64, 0xA3BBE02CCF98C938_u64, 0x943DE414E06350D5_u64, 0x2EAD4C6668D65333_u64, 0x0B9FAFD0F7322A59_u64, 0x8A073568BC12C801_u64,
+        0x36484559CB0100BE_u64, 0x97227CCEAEA0A303_u64, 0xA887A8BCC0137756_u64, 0x976635EEEC6BBE44_u64, 0x4BBBFA7032A8FC8D_u64, 0xDD6B90E67EEE0F3C_u64, 0x0833596AAD0352B7_u64, 0x2592D0DBEFE14002_u64,
+        0x6B12B962F5CDB333_u64, 0xA313B9A5563F30D6_u64, 0x021E00B3E7311D8E_u64, 0x4014FEAB05ED56C7_u64, 0xEB6A778DA6BA796B_u64, 0xEE895AE147C44A8A_u64, 0xB855FAD9A37BCE1D_u64, 0xBA00120185A37F42_u64,
+        0xC86060DAEF343087_u64, 0x72B8D21BE899AFB6_u64, 0xF4B7EFBA764D7028_u64, 0x6C42BFDC0E8A37A4_u64, 0x2A89CE5C856E3004_u64, 0x2AAB03504C70C915_u64, 0x580EC30B6CFE7C37_u64, 0x30C2476EDC0F30C4_u64,
+        0x46209AAB0795790B_u64, 0x93C963DCB4E95596_u64, 0x74C73ED8771A9491_u64, 0xD339D6D0EF742448_u64, 0x37B19257D04A3056_u64, 0x02ABC9BAB48ED2A5_u64, 0x9BEE07EBD59A31DA_u64, 0xC7E8E700E92BD29C_u64,
+        0x476A98A471D1EAA4_u64, 0x2327E60EF294CC17_u64, 0x0A8A638A844C8407_u64, 0x6DD37A2EEEBC825D_u64, 0x182F10718800A622_u64, 0x441D59E5D740A0F6_u64, 0xA977F378DE9C53B9_u64, 0x4D9528606A6713C9_u64,
+        0x8CD1F0F7DD3BAB6C_u64, 0x28A95A74FDE72096_u64, 0x2AACD7E9B398690C_u64, 0x021F4FF3C87A05B3_u64, 0x95D16F76A351B9AD_u64, 0x81CABF10486B23C0_u64, 0xC6D8CD6CCA0989A4_u64, 0x31D45DB309423295_u64,
+        0x77D722837134F1D4_u64, 0x09FBD91D1CDA261D_u64, 0xDD28C5161F84B14E_u64, 0x907CABC34BC8E0C1_u64, 0xB53182EF3445D10C_u64, 0xA48B2C071D51E63B_u64, 0x6C3C34FCB90B110B_u64, 0x3CFA24F6099DCBAE_u64,
+        0xF4CDD74311ACEF03_u64, 0xF4B93F67C460BA8C_u64, 0x43BB94B67524C755_u64, 0xEDE202A456EE04D4_u64, 0xA3D7E7CBDEBC69E6_u64, 0x822017BBC70C65E9_u64, 0x440494F35EF742D9_u64, 0x14F6FD876A13BC18_u64,
+        0xA55D24B7991DD798_u64, 0x4EC16B899DB8F8FB_u64, 0x61C0B641D500E935_u64, 0x2DFDC219EED3EA2B_u64, 0xE112961E63083420_u64, 0x2EA82AA0F32A120C_u64, 0x704BC981C204AB76_u64, 0xEC447BA753A86299_u64,
+        0x5B7EA1EDF04B65BF_u64, 0xDB9AF57A9F4D7895_u64, 0x1A2A703295F64908_u64, 0x08C8D30C6DE703AD_u64, 0xB8FC8953258F67F0_u64, 0x7BD0DA0D0B9C4824_u64, 0xE5D2AA6A21FAE9F5_u64, 0xDC4F09A5F95AF788_u64,
+        0xD0CA399D6A0E0E09_u64, 0xF65E58C31A703B3C_u64, 0x6AF3AB643ADD995F_u64, 0x3C34F0A385DEC534_u64, 0x8272F6E2785A6469_u64, 0xEFCEB0F17050B250_u64, 0x32D2D8A1207DE8F1_u64, 0xAD73162404FB85C2_u64,
+        0xBB116D73F21856D1_u64, 0x95D3DBAC8858678E_u64, 0xB58E97CEF9CFC09A_u64, 0xCC3665C12F8B8FE4_u64, 0xC1A6BB7722E149CB_u64, 0xDDCCDB8AED2AA82A_u64, 0xF6E5EDB890326B13_u64, 0x98D64B16526DDA84_u64,
+        0x1B8C5833C2EAD96E_u64, 0x22B5CAA6022B6C18_u64, 0x562C953F96DE459F_u64, 0xBEE633F67884FB0C_u64, 0x060AB27C31C5D345_u64, 0xB08733FBBC55AA51_u64, 0x0D1B7C74FEE5C409_u64, 0x3C60F983F8EB8BDD_u64,
+        0x123824FAF386CE42_u64, 0x8E64DC14236326D1_u64, 0xF802245650F5A507_u64, 0x44E6C5E2207E57CB_u64, 0x3627CA4AA9192614_u64, 0x35FBE034C4E96FCC_u64, 0x502954F85DD20B57_u64, 0x1BEB1CDFE2A0724D_u64,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024S_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0xC8954D29BCE42EA9_u64, 0x6BF9CA549601A93A_u64, 0x441E7EBD5DCB862E_u64, 0x50FDAC072756277A_u64, 0xE147710915A93243_u64, 0xF33BFDF01C5DAF65_u64, 0x9EA844A358B6C1AF_u64, 0x0E55BCBAFAA707BE_u64,
+        0x6B3F26B6F1F13544_u64, 0x6C9434451E0D56C3_u64, 0xC198703EE07081C5_u64, 0xA089663DEBF90602_u64, 0x09A17AD55EA5810A_u64, 0x18DDF57AE0D76AEE_u64, 0x64A9C88FDBE23951_u64, 0x50418D52D20BFCAC_u64,
+        0x1737CAA514AF554F_u64, 0x6A65EE03A1B9A4C3_u64, 0xBB1E683C625BB171_u64, 0x07A731229AD16C08_u64, 0x9E4D25186C30317D_u64, 0x2D7C1E5103A71122_u64, 0x01BE16DD351D9FD7_u64, 0xF653E33AF02E7758_u64,
+        0xFFA2090340F4B75A_u64, 0xCD9D47669C74CD94_u64, 0x7F604484BB01A0E7_u64, 0xF56842F8ED608323_u64, 0x77C2D791CA7D4E27_u64, 0x0C5DAF934035BEAE_u64, 0x853CC9BD8158B0B4_u64, 0xA62493C2ACFC6EDF_u64,
+        0x28D0C502488531A3_u64, 0xD4485071B7B41903_u64, 0x7ABC2198A05AD9D3_u64, 0x05CF03FF538EACB5_u64, 0xD223A3924E58ED22_u64, 0x9B1399C2F06C72E1_u64, 0x493ABF3AB8A070FF_u64, 0xB01F0E02812CEA4F_u64,
+        0x4E0E5AEC6C1D6AB8_u64, 0x85F1FF1AE4AB3FE8_u64, 0x5182FF5149741C6F_u64, 0x6543056FE38741C1_u64, 0xDC4C27153D846C79_u64, 0xA0D26F3000E8835D_u64, 0x8F7F62641738A482_u64, 0x66CD3CE255315E1E_u64,
+        0x457FC6AA9497CA9B_u64, 0x4E41035B45B46DD7_u64, 0x9D8EF517448A5C7C_u64, 0x0410FCCB9CFDDDAA_u64, 0xAFD3513AED138862_u64, 0x38913902E1CE70E8_u64, 0x7E7158FCC6A9E285_u64, 0xA58C77E5F6B6B971_u64,
+        0xB7D9049E5FA0A801_u64, 0x8B0534FD134A24E0_u64, 0x26768A2D5C38B173_u64, 0xCB806E0957FE93FE_u64, 0x17F43D33129CBDEE_u64, 0xFA154F8C2B59F607_u64, 0x65B700CC7DAA875A_u64, 0x89E28ABDC07D5C73_u64,
+        0x34BF3C8EE4AB57E9_u64, 0x6B37D7689B69C5A2_u64, 0x4FF0AF0786CBCF4F_u64, 0xED8D2EF4816B144B_u64, 0x97DB016AACE78C43_u64, 0x73E2971D196B7A7D_u64, 0xE74677E31D21B198_u64, 0xFB51B4D3352CE155_u64,
+        0x2CD73B900861C6A8_u64, 0xADFBFFC81FEF9F3E_u64, 0x7F79F56E67718F3D_u64, 0xDF4E0FAA274CB3A0_u64, 0x395335F7573E204F_u64, 0x089E4A6499BFDC09_u64, 0xC3BDA4BBC0228AC4_u64, 0xBC1FE3270C1A5F73_u64,
+        0x3BEEAB53B01C965A_u64, 0xFD23CFF996832B91_u64, 0x92F10C6A975EC831_u64, 0xDAB53AC83557951E_u64, 0x8BA6B4D633D9E3E7_u64, 0x4B2AD5456871D7FD_u64, 0xC93E2FE5ABA54948_u64, 0x07633EBF15FB4C52_u64,
+        0x64755AB64F920745_u64, 0xA58D0F40E658CCBD_u64, 0x9BC3636D529F4611_u64, 0xC0072A690B5317A2_u64, 0x49D8E4AAB5D22900_u64, 0x431843686F86FDD8_u64, 0xCA69CFFEB6B60F34_u64, 0x3B1AE9205418D2EC_u64,
+        0x03B8353176C8403F_u64, 0x132B1ECA2CF59F10_u64, 0x4A6BABB9963CEAC1_u64, 0x9AF6C8D00499507C_u64, 0x527E885F17A48567_u64, 0x9070DA9E4ECE359C_u64, 0x9C2EA6E02B807951_u64, 0x2BDF6D00F657F59F_u64,
+        0x8CB66398EC9BB023_u64, 0xF71E93E6AF56F1AD_u64, 0x36106AA6DE6C12F9_u64, 0xD42AA67AFF9CD765_u64, 0xADF3C8E6B346D40E_u64, 0x6706F21C187C561E_u64, 0x48E903FF3D2D2B46_u64, 0x98C24501EC301A53_u64,
+        0x6A00F2EFCD1EEC12_u64, 0x13CD4773CED17CF2_u64, 0x318D687902A816F9_u64, 0x55B04122E2EDC6F1_u64, 0x02CD061DA684F8EE_u64, 0x4579943218C8DFFC_u64, 0x888382F17932DE17_u64, 0xA8A7F8CC4167B6A6_u64,
+        0x9712E2EF9D493299_u64, 0xCEE3B940FD6F5A16_u64, 0xE70851E19F1292C3_u64, 0x582858C97C04A6F9_u64, 0xCCFCC1BCDDEEF99E_u64, 0xCECB5F1E0F3ACBE0_u64, 0x54724EA4A87FE1B4_u64, 0x0667A6EBE1FA9995_u64,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024PP_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x342F13D34CC61A52_u64, 0x12ED4C0E0BDBECAE_u64, 0x423FA430C11620E3_u64, 0xA43AE0C065FE9D4E_u64, 0x3EFC541B8A57003A_u64, 0x48F50C2FBB02F0AA_u64, 0x5E7EAF6C04DB8A1F_u64, 0x0473FA7400BA3728_u64,
+        0x55450551C401E25B_u64, 0x254773F7DFB708CC_u64, 0x186DE6F3BE34F414_u64, 0xCBA2E7A95F9EF140_u64, 0x7B0ECCD861E0E725_u64, 0x57B6DED1DB2E3EE1_u64, 0xFF1A6AAA244B36DB_u64, 0x13DFF1A3EF6B3943_u64,
+        0xE2B7405D5750FA97_u64, 0x5A7CDD00F9EDCCF8_u64, 0x89FF1939698273AE_u64, 0x74DBA565D161CBF6_u64, 0x921A97271BB58D2E_u64, 0x84BC9D3252E4EBE7_u64, 0x75066998B272CF0F_u64, 0xBD89463697912918_u64,
+        0xBFC8B5300BAF7297_u64, 0x3513088154D22BBD_u64, 0xE98E020E186340E0_u64, 0xD73E2ABFA84FFD1B_u64, 0xCFB84136BF89EC89_u64, 0xF03ECF84F1D9218A_u64, 0xE1FAF56013677CD9_u64, 0x9D584721E183F299_u64,
+        0x8F40B6B232FD4FCA_u64, 0xD59E1E33E2D56F93_u64, 0x68F85768EBFD2F19_u64, 0x95331CB39B2E7D37_u64, 0xDFDFC2F24E90F394_u64, 0xE863C3025FC2A244_u64, 0x22DA2848D5CDED3E_u64, 0xEC17CF5C791EC69F_u64,
+        0xE353286855AE8575_u64, 0x1588242E239CC8C8_u64, 0x957EFB2939EEC8BA_u64, 0x8C9D0CACC6A1F8B8_u64, 0xC97A2536EFCA472F_u64, 0x2F154F69C572B87D_u64, 0x181B1895110BBD0F_u64, 0x48CF354A56CC5B84_u64,
+        0x96F5EC1CEC9D7821_u64, 0xF3F71E26963506AA_u64, 0xCF541F835AC58D47_u64, 0x24CF80335945F2F5_u64, 0x0330BC14898E9EC0_u64, 0xB145A27F1603AEFA_u64, 0x38C0D4AF8A2D9C82_u64, 0x96D423349ADB676E_u64,
+        0x2F5C7458369DE785_u64, 0xADFFAFE30C5F3B49_u64, 0xB8FE31B67B67E0F6_u64, 0x70CBF1A6879D4A03_u64, 0x240B3394DE061D1A_u64, 0x36EB5B2D7D8F46C0_u64, 0x5BD9D1DE8EF538CE_u64, 0x33585B604B18114E_u64,
+        0xBE2426894932998F_u64, 0x148386113DF1542E_u64, 0xC7BFB60E653918A9_u64, 0x2C07B1001DEE8783_u64, 0xB163B0F0B14FA28D_u64, 0xE7DCDA0E156F7B6F_u64, 0x0899DB9CC22764C9_u64, 0xAA4A1582B07C8F77_u64,
+        0x6CF92D957960E3F5_u64, 0xBF4E487ABC29636E_u64, 0x077C608C2E74B12D_u64, 0x4BAA17B25B386FA9_u64, 0x7EA1C4D7914AA5F8_u64, 0x8062045D5F2F7C32_u64, 0xF784C69FF0B8D60B_u64, 0x593E67674C95E1DC_u64,
+        0xB9EF72242AFC490F_u64, 0xBAE301DFAECF3365_u64, 0xAF075BFA1E2A75AA_u64, 0x44C189F318880DAB_u64, 0x073DD4324CF8562B_u64, 0x8AF29BF8C5D8D8D4_u64, 0x1F58C9E371840085_u64, 0x6A90964F00BEDD59_u64,
+        0x8B453D8B3FC1E95B_u64, 0xD36D4D1A7AC729D8_u64, 0x5627F2E82B078ADC_u64, 0xB33B05BB2B271C9D_u64, 0xC1C4F45AF55BCFD0_u64, 0xAAD567331A90AC22_u64, 0xFD33745A5B7A31D1_u64, 0xA45C1CF736A2EF56_u64,
+        0x3E7D6A7DD71146B2_u64, 0x365CFC5EA22BC152_u64, 0x0A66EC457D339A48_u64, 0x25D9942E854EE188_u64, 0x855FDB8228A7D5B4_u64, 0xFB567A781A458E95_u64, 0xB5C7FDE050244C47_u64, 0x3803646611526B97_u64,
+        0x6608F36FBF6C84B8_u64, 0xBDF057715A652C8C_u64, 0x77B1061251FA90EF_u64, 0xA7CC4E7392464216_u64, 0xCA8653823C788F32_u64, 0x78D035EFA1473008_u64, 0x0CEBDA19DC19B945_u64, 0x49BC1E0D6D3CB40A_u64,
+        0x1F99B494C06596D8_u64, 0x166C6B5D85A7FC7D_u64, 0xF5C0769C33532342_u64, 0x20D2B062741F458C_u64, 0x16EEE12413302304_u64, 0xFEC800182FB995A9_u64, 0x7288C926E047B6EC_u64, 0x0FE3F7132775E661_u64,
+        0xE7585D0EA58B7511_u64, 0x69E4AFA4100487C2_u64, 0xF7BB8C66F28FB35E_u64, 0x4387DEECBFE85AEB_u64, 0xE42612653824AA31_u64, 0x09899F014D0E8D3F_u64, 0x45EC070246504022_u64, 0xC521F9F983A082EA_u64,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024PP_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x728B485515C47799_u64, 0x98E6814494C20B25_u64, 0x030DB57E91D47E27_u64, 0x905B8C8301BF6C56_u64, 0xABA8E94841361E3E_u64, 0xC657F92795EBF4E9_u64, 0x22A149C6B372ACCF_u64, 0x5794844D4E8D695A_u64,
+        0xC573DFDD6F035A10_u64, 0x26EAD4AF8CE39ADC_u64, 0x57FBF4CFBC25E219_u64, 0x2961D385FF0530EE_u64, 0x1C3B165FB52E04F4_u64, 0xF212E2650CF81912_u64, 0x5E84D725ACD8C8FA_u64, 0xF7ECC5724E818F09_u64,
+        0xF132CD3030765679_u64, 0x8BAC0AA0740ECC7B_u64, 0x63914EA1166D3E66_u64, 0xAB0E76F29457C561_u64, 0x9B09238CAD838B28_u64, 0x4610AE78C3DA7ABB_u64, 0xA442E98D96E40568_u64, 0xA3FC45B88F2E2887_u64,
+        0xB6BDCF43AADDF455_u64, 0xF7C100133A0F6860_u64, 0x7FF6578D595A5126_u64, 0x531E455133911088_u64, 0x51C027CB51F30760_u64, 0x79BBF34880125C99_u64, 0x180353DFB2E66858_u64, 0x98A0040E32C09E87_u64,
+        0xF1691E7E0DBDCE6C_u64, 0x4BC3796C7F9C94F1_u64, 0xCF13770EF9B9EC45_u64, 0xB9148DECA15DFE40_u64, 0x200091A003C2EB51_u64, 0xDB4FCF79550855C2_u64, 0x987DB16C3E22CFCE_u64, 0xC0217F7AD8C99084_u64,
+        0x86FFCF22E5F422F3_u64, 0xB7236F23722EDA18_u64, 0x739C8C4EF5A9D8DC_u64, 0x80994B3E7E6F692B_u64, 0x03BE0C111706DCBE_u64, 0xF5B4C8F2105B114C_u64, 0xC26EF5063028DD64_u64, 0x6E6E714456EF0F80_u64,
+        0xC5344DE8F12F2CFB_u64, 0xB00F1584BCA6359A_u64, 0x91048DF6D9D5F941_u64, 0xABE2CC67502C319C_u64, 0x0C41A32AE25B78B7_u64, 0x1BCA8DE0BB4DB8B5_u64, 0x229C3FBFF9996255_u64, 0x453275A272939B43_u64,
+        0x6C4E8E25E3105710_u64, 0xB79E676A012FAD40_u64, 0x7DA0C35CEAC01C9A_u64, 0xF168BAFE42B74BE6_u64, 0xC761E9939C081475_u64, 0xE5FE3CC193A9A71F_u64, 0xCA7E42411367084B_u64, 0x2AAD359D9438CCCF_u64,
+        0x697C3C13A0AE7FFE_u64, 0x817811FBF511398A_u64, 0xA227D72640F2B9A9_u64, 0x68AC4878A41DFF97_u64, 0x3A1129A0FC7D981F_u64, 0xBDA1F4B6F00661C0_u64, 0x89BA9FA65F30AEBC_u64, 0x70D63D910F274BBD_u64,
+        0x0818FD1B86934982_u64, 0x73FD728AB73D8772_u64, 0xDC503D2D85AC6480_u64, 0x7A114855F8A9554A_u64, 0x9698AD7B2DCAD41D_u64, 0xDC3D20B21D4F9EAA_u64, 0xB53C78BA29CA0FDD_u64, 0xE0B368DCE21E814C_u64,
+        0x41FE0C784CAFBEF4_u64, 0x9072DA345B4F35A2_u64, 0xDA75B4849C12710A_u64, 0x4D23C1313C2A311B_u64, 0x8DCDACD97E3E9785_u64, 0x49EFD9648BC80AC8_u64, 0xA6E32E6F57A3B2CB_u64, 0x6EF204469F1612C3_u64,
+        0x769A843443C2FF6E_u64, 0x4AD49A9DD4483AA0_u64, 0x4EAD6EC0FD516983_u64, 0x163B4496897E9D47_u64, 0x5655A1731D39FE41_u64, 0xC78EA11058B6B96A_u64, 0x03003BFD5DA46073_u64, 0x4636F52CFD83490B_u64,
+        0xC9E85F7CA9B7E7D9_u64, 0x5F621D019722936E_u64, 0x0E1918955D061041_u64, 0xE454CF7E68971C2E_u64, 0xF23722AAC311608E_u64, 0x368D22DDD4F81052_u64, 0x436345AE55B3A72F_u64, 0x10D7362DD2C88A1F_u64,
+        0x0538C3F070F9B84F_u64, 0x4B8D0477FB4D2A45_u64, 0x9D8A73AAEF53C71F_u64, 0x1C1BD24EF4C2F198_u64, 0x5F16C49AB943ABAC_u64, 0xC0520A592E7CF25E_u64, 0xB1C888DE07861C88_u64, 0x19224634005D2D1F_u64,
+        0xD626BDA0055653BC_u64, 0xADE3408BF4DEBCAE_u64, 0x1BF3FEE1FC802495_u64, 0x5DB0BD3935997051_u64, 0x66E197638FA3EFED_u64, 0xC070F88DD55CC99D_u64, 0x403C46466D3EBA84_u64, 0xA97B19683E35B663_u64,
+        0x8ED59D012718FA78_u64, 0x404D43F5DE483F5B_u64, 0x5706626B87BDDA05_u64, 0x503160E6BA19930A_u64, 0x4D68F473635CCFA9_u64, 0x07C578567DE11EED_u64, 0x2F32871375163010_u64, 0x6A63B9F32ACCC094_u64,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024PP_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0x5964BB317C6EC1E8_u64, 0x1B3C5F84BE1059A0_u64, 0xD2BF241EF4FAE5DC_u64, 0x637B70747BA1A83E_u64, 0xE7ED80FC88249400_u64, 0x26A38A97E8337DBA_u64, 0x0112AF51AF052602_u64, 0x20451BF0D406EF45_u64,
+        0x986976D169DF8348_u64, 0x5F0726A42B6EFC94_u64, 0x6F3E6129C11BB0DF_u64, 0x17821D427DAF4E2D_u64, 0xFED3CA06EB9B862D_u64, 0x370A158AE1573D0E_u64, 0x0901538E26507F86_u64, 0xDDE190BD82E16B35_u64,
+        0x2432D978A5D06098_u64, 0x7E08CC27ED32D303_u64, 0x098B1CDA48579B94_u64, 0x9577844476B2BB05_u64, 0x6AA215FEAD93F024_u64, 0xBC18EA8FA4E60495_u64, 0x62080605C8805F06_u64, 0x4D40DCDE2EB01B92_u64,
+        0x1A6BF4E5ACDD19D0_u64, 0x4644CC7A71FEB9B7_u64, 0x028ADD2C42291DBF_u64, 0xBE4E89E8558BB0B4_u64, 0x02C0F349DF9540B1_u64, 0x70CCF7B550BCB9AA_u64, 0xD9E62A03C3CD08C5_u64, 0x43B0A515530FBF1E_u64,
+        0x736B74B11DF94621_u64, 0x0B176031DF67191F_u64, 0x54E679C72B373C94_u64, 0x1E8484B868B70527_u64, 0x60E0B7C0A7A697FF_u64, 0xC03344A434C55EB5_u64, 0x29C0F285236C356E_u64, 0x68D39FB91896C211_u64,
+        0xFA77961A03E0B695_u64, 0x9A9D2C7E7E7F8506_u64, 0x654DD3167BAD8782_u64, 0xE49D83211F86E690_u64, 0x8BCCC528766F3591_u64, 0xD7AD262713AE6E88_u64, 0x69C62969CCBC443B_u64, 0xB226AC8F60C52A93_u64,
+        0xDFE4EE65FB9F7E6A_u64, 0x4B3BFFB653F09934_u64, 0x7929A84DC4BDB8E2_u64, 0x65117CE23341E42A_u64, 0xC7714D2B95814F68_u64, 0x80C473C3B6E83B41_u64, 0x4D0155D71CD31817_u64, 0x9461E19D1A5C0CA4_u64,
+        0xCE9545A4617ED3A2_u64, 0xE252FC814DAC513D_u64, 0x8D549BDF0B527827_u64, 0x71A1989500B5EB9F_u64, 0xDC1394F9670D8D06_u64, 0xB0C1B4126EC6FCDB_u64, 0xAED0281834CD5DE7_u64, 0x9A9A44CE083C17E7_u64,
+        0x9344AF31FF2CA25B_u64, 0x939D527D6D763DC2_u64, 0x8DA2FC87F9021962_u64, 0xBBEF16745FCA0D8F_u64, 0x9D640407FC79F1BB_u64, 0xCB9B1C2581E9C7CF_u64, 0x5441FC78C9020987_u64, 0x59A66D857B92E726_u64,
+        0x6A25400744DDADFB_u64, 0x7B412BEAD316A7C9_u64, 0xBE06928D20E38863_u64, 0xEDA7D5804D97611E_u64, 0x508350EE553935F7_u64, 0xD03E1349B44BFBB9_u64, 0xD99B6E1C7E0C36EC_u64, 0x53166C0A1B8F67EB_u64,
+        0x158CDAA03BA1A3BF_u64, 0x8846DC139A909E63_u64, 0x006E9FF2AEC540B5_u64, 0x1FE392E1BDDD0DE9_u64, 0x15DD25FFFB98A5F3_u64, 0xC5A2EA5A0434BED8_u64, 0xFD8F11F729F3D3A4_u64, 0xE41F9BF5D343B6E4_u64,
+        0xAB44E8CBA81FC3D3_u64, 0xCC53BCB9D8522382_u64, 0x9CF213A71DDDBAAA_u64, 0x569946E4095B6F3E_u64, 0x233337716BA51E16_u64, 0x68F8CC305AC16616_u64, 0x780CE26308E8E21B_u64, 0x84808EFDFD0B751A_u64,
+        0x6ECA683CAED60322_u64, 0xC63DB825D5CE6CC2_u64, 0x6DA895252E7B2D69_u64, 0xCBE8633372082EA1_u64, 0xFC2C6352CE5812F4_u64, 0x76D14A5561B70AF0_u64, 0xB16F4915A1C1FFBD_u64, 0xDCC8A3B19832FF68_u64,
+        0x60B15F8E1631C5EA_u64, 0xD97154A188E402F6_u64, 0x6B935A0E835FF59E_u64, 0xEDF4E9D6F05EB72B_u64, 0x3F224C7FCB75359B_u64, 0x2A672E8EEE4D2BC8_u64, 0x4852CC58A34223F1_u64, 0xD1575899D5BA7FA6_u64,
+        0xC332366D103A6487_u64, 0x694E9FEBE1F729FE_u64, 0x13A70B587E8C64D8_u64, 0x6CD776197CFD4D41_u64, 0x741E8A7B5C2D5CD9_u64, 0x92AC54355E9F4EAC_u64, 0xE6BCD3709CC61C7E_u64, 0x4161F849532A2F5B_u64,
+        0xFD56B3DF01D9C2CF_u64, 0x5F15B20016A91831_u64, 0xA832F6077B7A6380_u64, 0x05CE8BE2E81E3723_u64, 0x262044DFE81AC9E6_u64, 0xB6DE7A2868DE36BE_u64, 0xE00F886A74766DA2_u64, 0x67EAC6126E0FF308_u64,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024SS_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x99EC5F36CB75F2B4_u64, 0x422EA740D0977210_u64, 0x47BACE0BB96B41D5_u64, 0x8E93D82D4E3FA1E4_u64, 0x657A983D215193D9_u64, 0x7A99B5BF94F8CA67_u64, 0x1BB0AAAC7E0CC9D5_u64, 0x2B18ADC836B22485_u64,
+        0xB26052CB5D869A69_u64, 0xA7D171D593DD4EE5_u64, 0x8EEAB0789318A29F_u64, 0xF4959D4082CA5247_u64, 0xC3D005E127DEA4FB_u64, 0x5C536F11F4C6B4ED_u64, 0x7516D2C817CF0A23_u64, 0xA399BD9B93DB918B_u64,
+        0xCAA321236C559487_u64, 0xA01E861A8A83749C_u64, 0x4199122106F7BD09_u64, 0x6F8C124787A9DFDF_u64, 0xE53CCBD4A503BF25_u64, 0x5FBCEB85B85B22E9_u64, 0x967A0FE9719D5837_u64, 0xCD0708F1A1073162_u64,
+        0x2F4A759BE77475C9_u64, 0x2BFBE23364B8DCAC_u64, 0x474A66ED1E82D73E_u64, 0x9D72092104F00652_u64, 0xF33C663B06AAF235_u64, 0x520FEDCF96D87FB6_u64, 0x704C37C63C05EE78_u64, 0x26A0F4ACE7359BC9_u64,
+        0x385ECE7E0A4D2F63_u64, 0x03AD54B00F611495_u64, 0x0B8D08326D55E67F_u64, 0xE64D824CF93DFC31_u64, 0x6DB96DC89F9B0D04_u64, 0xCC2E7F41E8547A23_u64, 0x6187243DAEA2E0DD_u64, 0xEC0E92B3520C36B5_u64,
+        0x7B880254EF29751C_u64, 0x50B54C694C05F6AA_u64, 0x2A20FF9B7D46B2FC_u64, 0xC514190E0EA3F392_u64, 0xDA26D43AD78130D8_u64, 0xC9C29DB0219BA81E_u64, 0x7E0B3A28F14D2DF0_u64, 0x76D5AE7D8646C87A_u64,
+        0x03820995403DCEB3_u64, 0x3322FF4B34B3F751_u64, 0xFF134263977CD8DA_u64, 0x875DE218B9CC6C2D_u64, 0x80AE908173800A73_u64, 0xBCF9A1FDF8889148_u64, 0x4E6573D4F174811C_u64, 0x514A00E373A5920F_u64,
+        0xCB7F3670B8C19A8D_u64, 0x5787F935DD3C17AD_u64, 0xC007F32B441719D4_u64, 0xA1514A38C950495A_u64, 0xE7FCF8BF6E92F520_u64, 0xAA9DB421A2827954_u64, 0xA80E887DDCBFCD49_u64, 0xD3959C704209C33A_u64,
+        0x70BE4B293C5B779A_u64, 0xA28B0543A63E1A11_u64, 0xAE6233B925F3EB8C_u64, 0xAB67DC70DBD3CDCD_u64, 0xD57C1CF27DCD6BBF_u64, 0x1C56FC4FE93B501C_u64, 0x0AF8BD66E84EBD3D_u64, 0x0148B89A5956A322_u64,
+        0x6FF73B40F9728B36_u64, 0x54838C3B7FC35723_u64, 0x612E09BC0788BF81_u64, 0x11C87C5CBEB8EE1D_u64, 0xCE230795D7C2D202_u64, 0x2EDF5FAB272C38A8_u64, 0x02A2032B2FE8B9D2_u64, 0x55DFAE41552B4148_u64,
+        0x01E1DD9B48A9D7B8_u64, 0xE141BA0921EAA902_u64, 0x6FD4587A4D6D155E_u64, 0x88D29C5E71C05F3A_u64, 0x2D81E5F5779006B2_u64, 0xA1881E0403B801EB_u64, 0x305AD8AE894586D1_u64, 0x2A64881425383998_u64,
+        0x231E775D7B868924_u64, 0x64969A597D5C737E_u64, 0x34C799CAC52C0052_u64, 0x3C0020BA9DD940B2_u64, 0x0C9C2514C58F117C_u64, 0x5C60E9F6660C2BE2_u64, 0xDBEC6D82C9FC756C_u64, 0x4C9B5DCA529C0DCD_u64,
+        0x2ADBF3422DFF55BE_u64, 0x6720378D66FA030C_u64, 0x7BEDE2F5400D5FC9_u64, 0x2FB07DC19D30DC7D_u64, 0x63DBF8B6123E2643_u64, 0x63AFF9E409FD3429_u64, 0x76A424E8845F6B1C_u64, 0xEB6D0EE350792476_u64,
+        0xEA440F3FD701CE03_u64, 0x2E804995FCB20A17_u64, 0xC83D1EBA5725D4F0_u64, 0xFAC879F26626F9C5_u64, 0x43D0FFB5345C022C_u64, 0x01F532F460CF03E7_u64, 0x2BD67F8B071D9FA9_u64, 0xF4409824AFC45A76_u64,
+        0xE8657542A0B351AC_u64, 0xCD39ABEBC785C767_u64, 0x3A7CB4CD66206C7E_u64, 0xCAE21ECE95D336B4_u64, 0x27C4D6DCEC2A58BF_u64, 0xB047CBE85C4B48E2_u64, 0xB2E62AF7F021CBD6_u64, 0xA3CD86EF601B07BA_u64,
+        0x86DFBACE84D82E78_u64, 0x65E7366B3DE9BD2C_u64, 0xD29F4604C6195A49_u64, 0x072A9EE29FB10389_u64, 0xFD9D7FB1459BF06C_u64, 0xCA4F219397B4F212_u64, 0x77564C7BE2EDDB0D_u64, 0xA9D4C76DFE180A4B_u64,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024SS_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x2299B3421AA78B66_u64, 0x08DCF842FB4DFDD6_u64, 0xCD21AA3E7FAC1158_u64, 0x9A8786C1468E99B7_u64, 0x1256ACE819F6999A_u64, 0x8972396665AB5DCF_u64, 0xEBC42C3D977DD827_u64, 0xA460F7281CFC7B1E_u64,
+        0x81EB121C187E6AD3_u64, 0xF47212DE4849AC63_u64, 0x8F6DE996105B6DE3_u64, 0xA6F6AF898CE86613_u64, 0x40D9A5434FB87916_u64, 0x64DBFA053F608A3A_u64, 0xF93B2A7202913392_u64, 0x02CFE26BD0E9628F_u64,
+        0x57FC94BC0AECE16C_u64, 0xD5707138E9B6A0C9_u64, 0xBC273E7341E29701_u64, 0x2D31C048C7EE5F4D_u64, 0x64BD9E4DFB9F9B1E_u64, 0xC2C9A25487BAE2CF_u64, 0xC317543BFF6644EC_u64, 0xCFF6DFC615FABFC6_u64,
+        0xAAF976A367B0873D_u64, 0x35A4C9A1DD15D8DE_u64, 0x6796FCC4583C7B1C_u64, 0x76DEAEB0F4BA404C_u64, 0x07508009E27A6AAD_u64, 0x761FD4582B507D76_u64, 0x85ADC242991CD1BF_u64, 0x3ACE8CD3D2D32029_u64,
+        0xF6010F92A1B5603A_u64, 0xCEF82AB6E8CA6509_u64, 0xAAF29E36B75EED5F_u64, 0x76552C5B4815AF3B_u64, 0x4AF2BFCDA81D7E90_u64, 0x19E9029141545955_u64, 0x552438782CE185FA_u64, 0x608EC80931A51E36_u64,
+        0x10D7A6CBD7F69DC5_u64, 0xA3F89398A38B7869_u64, 0x73080CBBB109199C_u64, 0x86C7450DB530DF0F_u64, 0x57A42E2450E617BE_u64, 0x3676D5EE463591E5_u64, 0x3BC45EBD3A2AD6D5_u64, 0xC955757DF5FD349A_u64,
+        0x048CBBBA5E5C322F_u64, 0x809FC09EE60D97B3_u64, 0x2A29047AD25B9DD6_u64, 0xFE35C4E1339AB4E9_u64, 0x939487966433B125_u64, 0x439B1BD58009C7D0_u64, 0x074E3AB22EE084B2_u64, 0xEF49CCDE86567FC1_u64,
+        0x0D41B1FDC5EECD06_u64, 0xF21C72965FC5F791_u64, 0x3B684D8DC7CBFA24_u64, 0xB1A8DEE100F74719_u64, 0xA33FD3F5B0CD0A2B_u64, 0x301BC68FF89621E6_u64, 0xA940A616F12CB21D_u64, 0xEF3014D09BAF98BC_u64,
+        0xE0D8793948FA9609_u64, 0xCA245AD1C2F0D39C_u64, 0x3CA45E4D369A9900_u64, 0xBAA5A9F2F0E50097_u64, 0xB5604E0EC017FCF4_u64, 0xF728F08ECA2204E4_u64, 0x04989705EE2A9B07_u64, 0x9A6F256322D16B64_u64,
+        0xBCF05C72DC10A108_u64, 0x30FA043B85B33E0B_u64, 0x849988BF9FED36A9_u64, 0x4C363EFB8A911720_u64, 0xB5ECCDB02DAF4F49_u64, 0x96A664BAC6196BC8_u64, 0x4F76BB6BE7B4F731_u64, 0x98290F1597E2F760_u64,
+        0xF87A249CA2A53772_u64, 0xC9BF1EB12160A60D_u64, 0xD8B679D18EF045C8_u64, 0xDA54782389CA0E4C_u64, 0x54C98DEA1D11F3C3_u64, 0x22E391D373577D96_u64, 0x98B5BFBA4A748845_u64, 0x59D7F930C7CD17EE_u64,
+        0x29132A0D53E7C347_u64, 0xD0025D57DE4C9778_u64, 0xE048D8C98FA67FB1_u64, 0x9245EF35A3E80CA1_u64, 0x6F321B32D2760B84_u64, 0x7686ABF5FBBD6ECF_u64, 0x3B905B384BF7E793_u64, 0xDA4E817B9B3F4053_u64,
+        0x8C3591B655F15F0D_u64, 0x2D9B4F32D324E212_u64, 0xED989B05C549F428_u64, 0x751D6A66D734E6EA_u64, 0xC29D535A6B0A2E62_u64, 0xD617F13DEC94DC77_u64, 0xBCF444573FE2E87C_u64, 0xE0100F0C9DBFDFB4_u64,
+        0x8DED6628883FF9ED_u64, 0x8F623EF9C2577BC7_u64, 0xD1D501FA64AB76CC_u64, 0x7342DD2493C19076_u64, 0x011650F5AA163C81_u64, 0xB4118EE9645DAC0E_u64, 0xE864A57A68A897DF_u64, 0xBBA67AE6D0B840EB_u64,
+        0x08611B28C4328D2F_u64, 0xA30DEBC25E0AF86E_u64, 0x37ACC56994CBD40D_u64, 0x506D2769F242FCD0_u64, 0x99E36597DB11407D_u64, 0x878BA20506663A2C_u64, 0x10BF12588C565B92_u64, 0xDE8913861B25F3C0_u64,
+        0xBE910B39E1CBA2F0_u64, 0xBAEE09BC80D7FB16_u64, 0x064A92B9D72B20DC_u64, 0x4BD2EEF0F6CF3DB3_u64, 0xEA97D14C61B8767E_u64, 0xE83FD249C9909DF9_u64, 0xE6006ECF0565F8CA_u64, 0xDA01B2B176CCB9AD_u64,
+    ],
+};
+pub(crate) const TABLE_XOROSHIRO1024SS_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0xB2165ACD673D0F4C_u64, 0x1771DA025B13243B_u64, 0xAEB461B4CA642D4A_u64, 0x6B650CFF196C06A8_u64, 0x58A172AE716700C9_u64, 0x395E7861974F2E10_u64, 0xE9EF558597E3C8C8_u64, 0x2840F5839589629E_u64,
+        0x3D3ED41DDCD2DE1B_u64, 0x187A53BE4784C003_u64, 0x3CC0F0EC83A9FF81_u64, 0x08A91AA18257C08C_u64, 0x9A11D92C13A53E18_u64, 0x4D27EBE4C7F9CAB0_u64, 0x8D612E59F8BEB861_u64, 0xBDC614FAAC7E2B7A_u64,
+        0x467B2FB69A15F8FE_u64, 0xE856DF92849DBFA9_u64, 0x18805EEA4AAEA84F_u64, 0xC549B857D634FEB5_u64, 0x9EDB72B7680F227C_u64, 0xD0D43312281E316D_u64, 0x6CC43EBAE1CBB5FA_u64, 0x515822CDA2FD5664_u64,
+        0xF7FFB89A26781606_u64, 0x1D51496AF434B600_u64, 0x8CDB45B3320130A6_u64, 0x7188830CC7729198_u64, 0xB73FD831B1628DCD_u64, 0x6F238279E8F7EB9A_u64, 0x5D15E603280F28D0_u64, 0x10DD4D24FD6F34DC_u64,
+        0x618BE26AF30B4FB2_u64, 0xB4E9C7F02A3BA18F_u64, 0x6E880AEA0DB93A5E_u64, 0x70FCB3B3BEDE8607_u64, 0x93327C72ADB032A8_u64, 0x99220DE3C987F247_u64, 0x34601FB42FDA22AE_u64, 0x955B0BA8442B7AED_u64,
+        0x797179AC1AA7A70F_u64, 0xEBA93320731F0DC2_u64, 0x65636AFBC6FE6AF6_u64, 0xE5640618A31482B3_u64, 0x70BAFF0E75DC2779_u64, 0x5356FB0BEAFA339C_u64, 0x0E10244BEA788284_u64, 0x3AE1833ECBA2348D_u64,
+        0x2FC1C10DADB3D466_u64, 0x7B939BA919A4B693_u64, 0xE616977452CC4476_u64, 0x5DF960EE4652EC17_u64, 0xA07DB210BCDA90F8_u64, 0x559D3A9BD0E68CBD_u64, 0x11F990AE45019EBB_u64, 0xB6DE435BA82AA846_u64,
+        0x70A3A3BB9DF963C1_u64, 0xDB30038D60219438_u64, 0x78F4D2E28AC967BB_u64, 0x7E2B1A28AD9B429E_u64, 0x06017FF4DB704CCC_u64, 0x576CAEB5A0629CF5_u64, 0x4482681F899014A7_u64, 0xFA73B07E9793E9E0_u64,
+        0xA3EBC6F45B606F5E_u64, 0xE5218B7F14F3EF09_u64, 0xF687F76EEB98F96A_u64, 0x1D48624579A0FDE0_u64, 0x31D2AD1145F9FFBB_u64, 0xE6723CCAFACAA2D6_u64, 0x70CCC0BAA478373C_u64, 0x762EF5C877A0393A_u64,
+        0x1EE428C0D223AEEB_u64, 0x93613C963B8DA40F_u64, 0x29756BC3BD48442C_u64, 0x7226D7688422346E_u64, 0x06CC16EAEDD078A4_u64, 0x47232DDE204A5FDC_u64, 0x188AD8A67BB81F0E_u64, 0xC81C8F629132692C_u64,
+        0x40BE87889928943B_u64, 0xC1C6D0E9A05D9AF2_u64, 0x34844476096B4B0D_u64, 0x9907D9BC9826B3B5_u64, 0x031B830905FFB00D_u64, 0x3F89582E7A2BE594_u64, 0x50825F2D2B7E5F2A_u64, 0xD9B1AB9AB1A29B5C_u64,
+        0x2A5F900D2C773D80_u64, 0xD4CA1CC70039841A_u64, 0x837D215394C05A7D_u64, 0x81A69AEA490AF22A_u64, 0x17DE3BC93B4B8195_u64, 0xDBD1132D1DAA145A_u64, 0x712C7926694FE65A_u64, 0x5C255D9EA7AB0D21_u64,
+        0xD8577AD01FEA8230_u64, 0x52F26AA9E8A67944_u64, 0x4AC58A263C9FFFEC_u64, 0x6D1FD8C54AD242C6_u64, 0x50167D5030BEEFE9_u64, 0xF98B1954D0EBB5CC_u64, 0xAE295266C19EB966_u64, 0x355841F169F3D21E_u64,
+        0x2B2BE5EAE3BC12D3_u64, 0x232E858B7B310BC0_u64, 0x2D6ABFDF74F8E623_u64, 0x62D4753EAEBB302C_u64, 0x9140267BD6513A0E_u64, 0x005D14EDDB263220_u64, 0x8E1D5EFB07B29FBE_u64, 0x168DD0EC01C8F77C_u64,
+        0x76B421D9C87ABAC3_u64, 0xA199FF3398E64B1D_u64, 0x55DB410D0C76E623_u64, 0xD98E59D7C133EA59_u64, 0x56524978BC72C9AB_u64, 0xFE0B3637B25A840A_u64, 0x3870652047C4957C_u64, 0xB6852A166A90EFD0_u64,
+        0x5922BDB6D5651755_u64, 0x80CC5BE57CB935B4_u64, 0x8287DE96C766C093_u64, 0xD7C5691A642EE392_u64, 0x0CA2B8B9CCA473FD_u64, 0xD55691DCE16E115F_u64, 0x3BBC5CDF03D6A957_u64, 0x3E3AC051611C15FE_u64,
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO1024S_512: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x17313F0D707FB167_u64, 0x8BA9D946E3D748BA_u64), (0x30D471D21256A1F8_u64, 0x7701EAFC6A2E1E76_u64), (0xEAA7D5D48F04A168_u64, 0xE02E7EF6D530AA81_u64), (0xE0EF50E8874EDD71_u64, 0xE8087F5E29525D94_u64),
+        (0x34FC006DADE60432_u64, 0x47DDBE71F41C2251_u64), (0x122BA1F8D24FE2A8_u64, 0xB341A34BC01FA49F_u64), (0x65950DA9C93D3A72_u64, 0x4F71E7DFA08406EE_u64), (0xA6BDB441E4321A06_u64, 0xE384FC7782BF51FE_u64),
+        (0x04F57F33DD1553C7_u64, 0x9518A1D433B9A3D9_u64), (0x35A790497A3C5657_u64, 0xC8DF152BC3D2BD72_u64), (0x6CE608895698048D_u64, 0xB426A9679FBCA50A_u64), (0x9B3ABD5C59641840_u64, 0x798FF86C3C32F90A_u64),
+        (0xC3BB381E7E06B21A_u64, 0x92BE1888D464B735_u64), (0x67E73510A436A28F_u64, 0xAE7400AC7F40E146_u64), (0x70A3FF668A2B1D50_u64, 0xE4AC9D2E6AF617C6_u64), (0x00D68BD9721A4347_u64, 0xE18D9BF804BE81C2_u64),
+        (0xC901EEDEC43B1ECB_u64, 0x168BC5D44B0B8B0A_u64), (0x96B258FA9BA98F0A_u64, 0x7A88CFA2924B055C_u64), (0xB43E786D24AEBAD8_u64, 0x4F3691B70880F3E2_u64), (0x7B6040718F97E77A_u64, 0x2F07E9812D2C8BF3_u64),
+        (0xC114BD29ED2C4B2E_u64, 0x2EB030FB1A5231A4_u64), (0x597BB9F727B9A965_u64, 0x013AE52F279F23ED_u64), (0x40CF003DC00946F3_u64, 0x5DB9BD65E071F555_u64), (0xD164822AD5C537F0_u64, 0x191E172B56FAFCB6_u64),
+        (0x32204284AC158E24_u64, 0x46ABB95A2F6C8D97_u64), (0xCCB1A6FC3AD93A4E_u64, 0xF77630A213498999_u64), (0x19AD218070BC6C71_u64, 0x0502D816D779B0E9_u64), (0x9CA90A8DBC9F7A5F_u64, 0x7C20081A39F3F511_u64),
+        (0xC63299451605915F_u64, 0xC62A8C52E9606BBB_u64), (0xD72EAC76BFF1A5BC_u64, 0x6FBF8D572311FF25_u64), (0x986F12B97A935367_u64, 0xF2490CEF24FCA710_u64), (0xD3305F84A528E736_u64, 0x99D9BF45A0E9161B_u64),
+        (0xAFEFD9A8F43B3ACE_u64, 0x50B763A5D6285CAF_u64), (0xA62604B9DE32532C_u64, 0xEE4D5A2BE6B2DAF9_u64), (0xC61EAE90E67441A5_u64, 0xF6C6149865CC5FB6_u64), (0x74DFA24182AA5AF4_u64, 0xED92CDC0DC38D6C4_u64),
+        (0x2E10010B0B6C7A98_u64, 0xA167DA4E69DF2899_u64), (0x57971BB582567BFC_u64, 0xB1AEF370BD6C16FC_u64), (0x2704A222B8EDBAA7_u64, 0xADECB7498689E0F9_u64), (0x35D6F6701A9935D9_u64, 0xA98324D78840FBDD_u64),
+        (0x2E7EDD7CE6BAD2E9_u64, 0x9A77D26B76A611D8_u64), (0x33C50DF1B7BA1443_u64, 0x12FA8D0A374022EB_u64), (0x7806F14046F8DD7D_u64, 0x9A928B5D650FA6EB_u64), (0xCF2DE04B162F24BE_u64, 0x0062EC809BCE8B19_u64),
+        (0xF86FC195A93EA55C_u64, 0x08132DF1BAA56629_u64), (0x2E387EEBFB8F33DB_u64, 0x79C173B9471C9E9C_u64), (0xB363FA743B2FD78B_u64, 0x7E4457CB8833A2FC_u64), (0x136F360419908A3F_u64, 0xD02B518D60322CEE_u64),
+        (0x7E65DA566D37371E_u64, 0x9715532C6E80CA11_u64), (0x4862BD1E8A0C2DDD_u64, 0x7E5FF471F9657B95_u64), (0xC2E2A559A5D8ADCD_u64, 0xD5194BD459FE5E07_u64), (0x65B65D0E8F24A911_u64, 0xCD57AAE4B6994C8B_u64),
+        (0xB3D9BA47813BE08F_u64, 0xAF661A6205C60062_u64), (0x7AA16A4225AC05E3_u64, 0xC167650E7BCA3608_u64), (0xA041C1A19DCF1779_u64, 0x1B7202A6F55362E6_u64), (0xB9B2BF3B4E192041_u64, 0xD5B41645C2B88D39_u64),
+        (0x56962A50983F5EB7_u64, 0x7F16B932C3C67688_u64), (0x47DEB7A99C5F5804_u64, 0x8F9C8DD446C2B552_u64), (0x12F79237C0B20151_u64, 0x7B876D7756C3996F_u64), (0x788F6867CEE6A4A6_u64, 0x5BCB214BE04DCFA9_u64),
+        (0x1C853B529C773185_u64, 0x060B76A8CACCE0D2_u64), (0x536DC75D4EEEEDB4_u64, 0xDD30AE47FB3FB550_u64), (0xC50B5640DE710C29_u64, 0x883862E075525ED1_u64), (0xF77FD5DA4A9AFE7D_u64, 0x2D53F768D8AFFA19_u64),
+        (0x7CB8DE7B2BCA8EE1_u64, 0xFE44B6CB9862B304_u64), (0x811C70E5523B2863_u64, 0x717257C49BC0286C_u64), (0x396EB21A7394A9E0_u64, 0x53DED3E5551C4A0E_u64), (0x7B7E6AA5008FA19E_u64, 0x13D3C83627749509_u64),
+        (0xB097913A2C6CE0E0_u64, 0xFC95B2F32F9BDBC2_u64), (0x479548A13AA9D403_u64, 0xBA21E89987801FA5_u64), (0x93439B621BFB8C0A_u64, 0x29B3CAA96C79E7B0_u64), (0x7F7438F35B08AA28_u64, 0xA659A1211ECA0F46_u64),
+        (0x2C69772FF3357B9D_u64, 0x78300F50E5367F51_u64), (0xE1EA0E1F5BFF59FB_u64, 0x17B57A11A550FF5F_u64), (0x5E88A737E88E0F3C_u64, 0xE633EAED1A55FA45_u64), (0x08EDB2B8DA787DE7_u64, 0x5F3C2EB25B9F5E78_u64),
+        (0x02564A73C3E96B90_u64, 0x5BD7FBCA70FF2351_u64), (0xC193D4B2EB7DEBB2_u64, 0x6D9FCB481A9A7879_u64), (0x457D767C5BED5924_u64, 0xCD2D586F451309C1_u64), (0x36F25DF0EB44A59F_u64, 0x3189D1B3B8242401_u64),
+        (0xBC87D67591EED45D_u64, 0xCFBC3E9F428C8160_u64), (0x8462A392070CAEAC_u64, 0x794918D3B74C37CD_u64), (0x1C931AC49008D240_u64, 0x1000BBEF6F5EDDEA_u64), (0x1CA2094E3AA61724_u64, 0x62893CCE8611EA56_u64),
+        (0xDA5869737934CFAC_u64, 0x6774B2A438EE16AC_u64), (0xB5082428D76A126C_u64, 0xBD63060237E98E08_u64), (0xA8E67808DCC87F78_u64, 0x32671634F6C52A5A_u64), (0x8447867B036CE51E_u64, 0x9F87354C2642F5D8_u64),
+        (0x4A0706BCA888F9E3_u64, 0x3C06E7E3C6DC0D5A_u64), (0x00E17475B942DCAB_u64, 0xEE5E13CF3F5399ED_u64), (0x79B2536ED21CF441_u64, 0x2BE20873D9804675_u64), (0x663FDAD9DFD41857_u64, 0xD9F20E6E647AFED9_u64),
+        (0x8328A27E5D1000AE_u64, 0x5CD02148A9775939_u64), (0x45C5E570588EE14A_u64, 0x476ABDBC78CA1F8A_u64), (0x7FA09CEFA4B4BF24_u64, 0x2A8867A1D52851E7_u64), (0x4E96E802D7AD1477_u64, 0x8994F2C078C50EB9_u64),
+        (0x05C4440FE6BC3DB3_u64, 0xC56ACCFC4A8FD155_u64), (0x8C72BBE4CE93E8AD_u64, 0x64BBD6E56FFB4005_u64), (0xAF33DC1226507F3E_u64, 0x9A2B7127E52F7604_u64), (0x4253B0D35B4DDA36_u64, 0x5B9397C89DC350FA_u64),
+        (0x5A4B723F3F60F54B_u64, 0xE4C78BE60D3BC272_u64), (0x5DD6149166F412C4_u64, 0x555257A309D4FCE1_u64), (0x56645FA545F97EF7_u64, 0x8A8E9119DB613973_u64), (0xA99B4AC251FDBCB7_u64, 0x437AFD3BF750A650_u64),
+        (0x69C587B6239FDFF0_u64, 0x87E28C232DE5A7B6_u64), (0xFE1A02B5BA431072_u64, 0x6F0199AF4FE17F77_u64), (0x233713A1D6128116_u64, 0x42C06A47816A4F6E_u64), (0x89E233FC7C901B1E_u64, 0xC0CF857674592517_u64),
+        (0xE3CBB6F900890C6C_u64, 0x07C9609BC39409FC_u64), (0xBD08AAE14EADD6FA_u64, 0x174A62319208E960_u64), (0x36FF8B54EA5DFE84_u64, 0x68E3E0296851B4DE_u64), (0xA8A588DE2B390F2B_u64, 0x9360364B74A95C45_u64),
+        (0xCF7A8FA63305B42A_u64, 0x3EA000FF2868C42E_u64), (0x931AD43FE0AA2498_u64, 0x0CD6054FD79F0EBE_u64), (0x7ABB56BCDC67D286_u64, 0x10DAC450C07E7342_u64), (0xDDF7CF643EC0E3CF_u64, 0x9C0970489A963FA8_u64),
+        (0x99056A9501ECB7D7_u64, 0xE7E6C1705EEB37FB_u64), (0x7218E284808A2707_u64, 0x7468C2011F2F1034_u64), (0x02214F67B3AD607A_u64, 0x2BD153B7BE9015DF_u64), (0xA8B33646911F178E_u64, 0x9E5BAB2AA9D4E2A7_u64),
+        (0xBF95AD866E3A20C8_u64, 0xFB116F7484B26DA9_u64), (0xBA74D04953E9E0B3_u64, 0x37C75D2DB0BDF6CE_u64), (0x8030DE8A93FC0513_u64, 0x2AF260BB1B1354C5_u64), (0x0F94D75DBF06D0C0_u64, 0x91A046749AC32913_u64),
+        (0xA34B135A3555584B_u64, 0x434D0DB98B3F1549_u64), (0xDB1E568207C5EF33_u64, 0x752588580696EF34_u64), (0xC09D9A2E3B057A98_u64, 0x10B2EF051A2E4A9D_u64), (0x0B46DE7154FF5F90_u64, 0x47F863318129554B_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO1024S_768: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x4C158916E76030FA_u64, 0x8FE9E583F4AE65EA_u64), (0x1CFE886F648C7B9E_u64, 0x645E43599C19728A_u64), (0x65AF16DA100BA6D0_u64, 0x61F320090CAB1EA9_u64), (0x82BB349B24B0EC22_u64, 0x836F35D2617FA89F_u64),
+        (0x31B741979E84E2D7_u64, 0xDBB26207A52CA7FD_u64), (0x053D33504E432220_u64, 0x148730611F9ACA53_u64), (0x3E6B30AB57217B52_u64, 0x2059E58BB530E34C_u64), (0xD0B1289CCFA84BD0_u64, 0x49C06ACC369E9D2C_u64),
+        (0x6D8B68391C16FAAC_u64, 0xC82D37C4B5385F78_u64), (0xE5B5D0CE0C1D863D_u64, 0x1DC119423286E5B1_u64), (0x1C7FE7063886B35E_u64, 0x0BDC83A4E6BBBB03_u64), (0x4E55E4A08EF4328E_u64, 0x90F799802E613CE6_u64),
+        (0x94930AAEB72D792E_u64, 0x5E68321D272FCED9_u64), (0x93A9FA658EF62B8A_u64, 0x4F176BF51B7745E4_u64), (0xC782BB6843917B5F_u64, 0x3DB39F4514F19A48_u64), (0xC30AE24842E70176_u64, 0x1994D18A9D677DAA_u64),
+        (0x32499B70C548991E_u64, 0xD00649D081CF7245_u64), (0xAAEED296F3370F48_u64, 0xD380269670738EF0_u64), (0x5F683BBA5962E449_u64, 0x90549C4E27A40F90_u64), (0xD190320756E07815_u64, 0xAC075CA63863B382_u64),
+        (0xB08608D4426E7A3D_u64, 0x2CBF07812044FAAB_u64), (0x7BBBDA5BBFB4A524_u64, 0x80A22389F6B0E20C_u64), (0x19FF22D0395BB57D_u64, 0xEA7119ECB89296AD_u64), (0x7A6F3F9AB7A32545_u64, 0xC6D1FD98A3B970D8_u64),
+        (0x462E66224C6BFA4E_u64, 0x982A1B0B175DB240_u64), (0x02A785445C18A965_u64, 0x66D636EC7F1B5882_u64), (0xF957E7E6B852ABF4_u64, 0x5D881E59ECADBFBD_u64), (0x60562AF71556D035_u64, 0x5427F9202AA8DAAF_u64),
+        (0x8637CFCB72D20E26_u64, 0xA76D998A3CDD483D_u64), (0x1325EC043ADF5A1F_u64, 0xDE778E3D908C43DB_u64), (0x22B9F54B19C4E158_u64, 0x0EB83C2BA12DF141_u64), (0x5D2E94945589C8B1_u64, 0xE086DDA448C3A38E_u64),
+        (0x2C4BC317932E9E2C_u64, 0xF1B72F45596F773D_u64), (0x26CF4C09BF8E8569_u64, 0xED60CD993C4AD6F7_u64), (0xE036E7A33C549025_u64, 0x9D3782D182ABBAC2_u64), (0x0B4C07F683B6DD52_u64, 0xF4FD4E71EEE48536_u64),
+        (0xCD985B9FFAD29658_u64, 0xD9F504DBD331E35A_u64), (0x33A0B4AA460F0574_u64, 0xC1DE10BD8B5565B8_u64), (0x6D58D10F38789E42_u64, 0x9C7514D494D9D693_u64), (0x137A96AAED289A58_u64, 0xFED7F6DE538F3687_u64),
+        (0xB5C8C75776D23F6D_u64, 0xF79ED531BFA0E6C6_u64), (0xFAB1B9D8A4F1D1A3_u64, 0x72B3A9AA8C1A6908_u64), (0x568B2FA22C8B1615_u64, 0x10F2569B57CB1D66_u64), (0x9F1C920DFBDCA7D8_u64, 0x48A959D0A2FB8BDA_u64),
+        (0x25C74B7105419921_u64, 0x75A913A532598660_u64), (0x2C08D0C619700CA2_u64, 0x94A849A8CB91817F_u64), (0x2644FF96C4046229_u64, 0xFD7FEE3D1A46822F_u64), (0xB5DEC4E92F7D2000_u64, 0x94ECB360AE54CC34_u64),
+        (0xEE4E69CA7B1F72C1_u64, 0x2A42F8790C70EF5E_u64), (0x189E2351C1E52FDA_u64, 0x123ED4E9010C4671_u64), (0x08F054D054989912_u64, 0xCAD9FE27240DDB1A_u64), (0x77C7C0E24C0FF592_u64, 0x226AAD9F40245D4B_u64),
+        (0x9BB507208DA87443_u64, 0x4506F8462CFBB2AC_u64), (0x4C135923D6666F60_u64, 0x541618D309B9BEA9_u64), (0xAC2A98313BFAC465_u64, 0x9F12EA51B1EE9CF4_u64), (0x6DE8CA5923820DBD_u64, 0x51F01DF38CDF65EB_u64),
+        (0x5BB4BC6BC10CAAD5_u64, 0xDC5006E8D61E3FD6_u64), (0x9BB881A89B868442_u64, 0xC0221C75654D69C6_u64), (0x0AC55157DEE92E8D_u64, 0xFA4A323F17D41422_u64), (0x2ADB822E3B14ED6C_u64, 0x2C0A06C983F10408_u64),
+        (0x70E278EA2E80C2A2_u64, 0x6A2572DCF7BF4D64_u64), (0xB8A775A5B31CDD15_u64, 0x00101B631AB9E149_u64), (0x9E4E090A77B6A4C7_u64, 0x72676FF520B2503A_u64), (0x172C7C57981A40BE_u64, 0x5289440B53356397_u64),
+        (0x28A0B992BD2C0C84_u64, 0x5FDFCB78BECEEB62_u64), (0x9493FDC8BA91B6CC_u64, 0xBC9F93DB9E5F34FD_u64), (0x18F13C024241D2D3_u64, 0x0400F0D7DE9B91DA_u64), (0x5D2AB0279D8C1927_u64, 0x91F22A6D00D82DFB_u64),
+        (0x084967125764D248_u64, 0x63E92A9A4B2B44A3_u64), (0x22A655CCC1F457B0_u64, 0xF55E64FEEC0D1C5F_u64), (0x3257BCA926686B7C_u64, 0x61C0C68B1B33BE54_u64), (0x031BB5245B4EEF0C_u64, 0xC0C0B7B332418AD3_u64),
+        (0x00E58307A5A22480_u64, 0xF68DD09F1C8650C0_u64), (0x87426658FA5C1826_u64, 0x7F2403A1850A1F3B_u64), (0x275BE8FC7B3D842B_u64, 0x9A2039C646163E7D_u64), (0x0D3C456B6116FC84_u64, 0xD37419E9C594142B_u64),
+        (0x6282E06E964BA255_u64, 0xEB994EB48BEFF7A8_u64), (0x41A7E2880E037C9A_u64, 0x9E7D5CD8ACAD71A4_u64), (0xD6279028E3E7B148_u64, 0x4627E2E9C504DCA2_u64), (0x527A73068623065E_u64, 0x00C60E31556ABBA5_u64),
+        (0x3E2980747172CA7E_u64, 0x684B86ABEDFB830C_u64), (0x122029438983FC75_u64, 0xC8D1A4726D8C874E_u64), (0x7D24E646AD778199_u64, 0xBC565090C80C2A44_u64), (0x5B717ECFDD5AB6C5_u64, 0x5D2641B048528B77_u64),
+        (0xCFBC01D0ACD8AB07_u64, 0x68163EA653C8BE6A_u64), (0x5671AD413DC3121F_u64, 0x1642A9AD67AF7495_u64), (0x91F8CBB7961A01F1_u64, 0x062455486DE888A8_u64), (0xF670E2671C163B68_u64, 0x009147ECD4938579_u64),
+        (0xA812B5F0297C70F2_u64, 0xE34D84AB52D627D6_u64), (0xFFA620835976F690_u64, 0x839430D59C134766_u64), (0x7152148EFED4ECB3_u64, 0x2A56DB62ECEB20F8_u64), (0x6DBC634008A49747_u64, 0x06779F83C5217630_u64),
+        (0x33ABB66836D5E42E_u64, 0x4ED0B72F0E3A246A_u64), (0xD05335C74B2DDAFE_u64, 0xF30A289177BD871A_u64), (0x6A716857D50F7AC6_u64, 0x2A6EC3066B53700E_u64), (0x30A38B2F8F2FBBCA_u64, 0x02CB7E6257406C3A_u64),
+        (0x883138DD6A607C7D_u64, 0x75F666E4B914AB92_u64), (0x7239048E21A31AD7_u64, 0xC016CF588FA58E1E_u64), (0xF53E34DB251C36AA_u64, 0xBA26373F64259636_u64), (0xC4EA674FB4BB81ED_u64, 0xB6C05FA280E42C82_u64),
+        (0x1AC9AB74F755FF1B_u64, 0x9F763AB302A82952_u64), (0xECA64CED495083ED_u64, 0xCF6A1159DA23DEF0_u64), (0x8D47B88AA7CF4B65_u64, 0x81D8324049FD3AB1_u64), (0xA0F6236C69D1E627_u64, 0x6E445026DEAA672F_u64),
+        (0x035BC0FD0F9A60A3_u64, 0x96DF3E25612A310B_u64), (0x0F11215B9D736F3A_u64, 0x893A564D47ED145C_u64), (0xCED9928ABDDC5798_u64, 0x5563A72A74374D06_u64), (0x8D50B0CBC69D8BD2_u64, 0xD4EF0165CC67B1B4_u64),
+        (0xA93B146AE7D77400_u64, 0x8B02BF2C1D87B8A9_u64), (0xCEE1343B5CE6E1B8_u64, 0x408E2F9C5C788BA1_u64), (0x9C2E7325085E6627_u64, 0xEDF0CF0B880967A1_u64), (0x338BCD214FD4429B_u64, 0x2C74A1F3AB925A3D_u64),
+        (0x48CEF54319C48812_u64, 0x46080E7FDAB8B906_u64), (0xDFEDE89C5941BAAF_u64, 0x5BDCC00AB1ABC582_u64), (0x63A7557723FB62F3_u64, 0xA5FD2C21B4DFD4A8_u64), (0x65FB421DD23CF8D9_u64, 0x8BE6458EC86729A0_u64),
+        (0xA85E9E8DD9D910D2_u64, 0x41B55F58CE692C30_u64), (0x2FF1987FBAD95CD4_u64, 0x3301971B741538A0_u64), (0x4EDA2EAD2EEACF9B_u64, 0x429DBABE848389E8_u64), (0x5C7F4AABAD1C8906_u64, 0x1FF75ACDD6BE4012_u64),
+        (0x84DCFFBE72082311_u64, 0xFF5E0B1094E749FB_u64), (0xAF17D7CFC502843B_u64, 0xFAD356697C0343A2_u64), (0xDF44F49B923326ED_u64, 0x6A225412B51F4825_u64), (0x1E1439B7CA30CEEF_u64, 0x6AF8A4BE5D330C50_u64),
+        (0xF437C62B50A3B801_u64, 0xE33ABB9869CED7E4_u64), (0x13257C7A6FEA3E42_u64, 0xBC0521F9A8E9BE7C_u64), (0x3A128EB9ED0AF7AB_u64, 0xF5C874BF5BE49899_u64), (0x3AEF845EB4F63EF0_u64, 0xB16A0299ECAD2672_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO1024PP_512: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x3EB7D5FC8373A0EE_u64, 0xB645BB31CEC5CFC1_u64), (0xBED9A5416B47A11D_u64, 0xE93C914B1DD6E377_u64), (0xFFB341232438DF74_u64, 0x396C7862F1ED684D_u64), (0x49D465C562E3439C_u64, 0x7412E8EF63E797A4_u64),
+        (0xED323A86FD1E746C_u64, 0xC479C44850CD1CA4_u64), (0xE6A5285022F8A925_u64, 0x1DE1DF66F76E1828_u64), (0xA68DBE9277112998_u64, 0xA08BB0109B8A88EA_u64), (0xD4AE020F969149BB_u64, 0xA2D0257FD806C5E8_u64),
+        (0x6BE0D3448A877546_u64, 0xEF86C858F040D697_u64), (0xD7AC516321CBAB72_u64, 0xCF7F2C477E35BB4E_u64), (0xCC8AAA571F958241_u64, 0xF51FB1A0E6E3B9E3_u64), (0x390E76479645A0A6_u64, 0x7F49ED87153AD6B4_u64),
+        (0xBD6733D4536F632C_u64, 0x20DB2D6944F5A3CF_u64), (0x53BB9914802A066A_u64, 0x96DE3645F9EDA7D0_u64), (0x880614A09F3441B9_u64, 0xC247EA0F10C98B8F_u64), (0x408EB75728A4DDE3_u64, 0x460A8FD06CBB3098_u64),
+        (0x7F5E48A9EA4AE2C5_u64, 0xA241F5A80A6F4661_u64), (0x85057B928B70E48B_u64, 0x4CDF5E32DAB287C6_u64), (0x22F158CB4B8BD092_u64, 0x8FC9EDBE53C192AE_u64), (0xA6A2A620EBD2C937_u64, 0xC0CFD38CFE144662_u64),
+        (0xD91520D502612BAD_u64, 0x01579741C9F384FE_u64), (0x8A62163B377412F7_u64, 0xBC809F6D8CE92C88_u64), (0xBFAFB7CCC4EA72E2_u64, 0x5CAC35E296C658FE_u64), (0xA94F4E35019D0629_u64, 0xB3DCE36C0AB498ED_u64),
+        (0x3753DD4550E32B61_u64, 0x5C5A15C580586E55_u64), (0xE869B324F905BEFE_u64, 0x17E963B5FA1C9193_u64), (0xA7D3F92A16D8D0F9_u64, 0x88BE52696E1811BC_u64), (0xBE2215DCE9E0A4ED_u64, 0x3B7900178706191F_u64),
+        (0x185A39EC2C6C939D_u64, 0x0823BF59772A634D_u64), (0x98CF20D07A86B07E_u64, 0x59C22C87C4CDD059_u64), (0xC732FABC52404B2E_u64, 0xCAE696C2D8DD9F41_u64), (0xA2865ED0C6983014_u64, 0x92ED77A45C00D03E_u64),
+        (0xAA5B97D5DF4A3A3E_u64, 0xB3FDAA468B70D2FC_u64), (0x3D4FC457F442069F_u64, 0x622BF518A2373973_u64), (0x7DCF738E02EF5D27_u64, 0x73F7B34780E57817_u64), (0x2D2FBC057ED5B9E9_u64, 0x83B15207C7D27BB7_u64),
+        (0x5D61055B484DACB4_u64, 0x84C6567AC384C02C_u64), (0x366F2F3E9D6C0331_u64, 0x22282600A0E7B2DF_u64), (0x7FDD65F0D511417B_u64, 0x86454C4D813779B4_u64), (0x27D0988F9097139E_u64, 0x9A0F65BB1B1B27FB_u64),
+        (0x24E9A1DA9FBE7362_u64, 0x62301D506FC59F61_u64), (0x5E3B382DD3A960A5_u64, 0xC368D6EDEE0653A5_u64), (0x43A4A1D032162CF2_u64, 0x2D1F94D0EF08182E_u64), (0x617B7DB72208A207_u64, 0x0A9A4EF65D5A9137_u64),
+        (0x9AA6E143E671A71D_u64, 0xC1741BCA458A9D1D_u64), (0x00E012D477A30FD0_u64, 0xD9EFAFD74251D6AB_u64), (0xF4C9DDD27DFE6BFE_u64, 0x8C3B94BF874A347C_u64), (0x970E6DA7C86F6570_u64, 0x2C503DE32599799D_u64),
+        (0x2414A3FDBE097658_u64, 0xBF31E403DFE82A26_u64), (0x05A49B026A841226_u64, 0x61C58E10EA0BEDFF_u64), (0x60357B70F6BF9A3F_u64, 0x51C7FCA934B1934E_u64), (0x0509E7F86B0E7E50_u64, 0x8EA4193AC8038A26_u64),
+        (0xBD05507538C98D51_u64, 0xAB1D9988A9CACFAC_u64), (0x8E1455FD4B306E2B_u64, 0xA349495CA3C88350_u64), (0xAFA2AF046C222066_u64, 0xC1C6DCE5AD6F4D28_u64), (0xFF37697F9A3D7C37_u64, 0x88B17E6B4A322182_u64),
+        (0x780CC73F003346BC_u64, 0x2E545523715896A7_u64), (0x692A446EE5C64373_u64, 0x464893DEB1CEDA7C_u64), (0xA5EA8C1052AD4F57_u64, 0xA287E4D988C2D9A6_u64), (0x7722BEDB1EB71F6E_u64, 0xF09EB7EFBA27F2B1_u64),
+        (0x013E97607A6A35E7_u64, 0xEB98134C0D15CEDD_u64), (0xA5077A3497C32B31_u64, 0xF1B0BBEC69E485F4_u64), (0x3A956106C4B99A04_u64, 0x0C5E70813F4A8AD1_u64), (0x811684C2ED3CD183_u64, 0x8F7CF1F2A4E25B93_u64),
+        (0xDE574B1F9A97749A_u64, 0x7150879F81994704_u64), (0x1CF1DA8B71D7CA0F_u64, 0xA4010A34C8F5D7D4_u64), (0xC7B19027FD82ECDF_u64, 0xBD4ABF1A3DF0CFE9_u64), (0xE3DDDB77E86C1244_u64, 0x63944674F8F8DFD4_u64),
+        (0xAAFE6693DB4D5A21_u64, 0xEDF1B6B4901BF27B_u64), (0x6C8738FE06BCF14E_u64, 0x30EC319F4D21E206_u64), (0xA7080BEAF981373D_u64, 0xD71B7E4E002C8F36_u64), (0xFB7461306698E11E_u64, 0x732EC21D36ED3EFE_u64),
+        (0x81C02F430B48FB89_u64, 0x3D17DEA6E8AD4268_u64), (0x6B1B97A297EAA368_u64, 0xC76FDFA531210A27_u64), (0x891D78B923EE68C3_u64, 0x5C1CB7F6CC72F241_u64), (0x494FCAC45AE7CF69_u64, 0xAA1C540C98E6B8F5_u64),
+        (0x73006525B35D778C_u64, 0x8573E6C9D97B24EF_u64), (0x6EB7164508A6FD28_u64, 0x37FFF6FF14AFD852_u64), (0x0FD7DBE3E2CEDBAD_u64, 0xE5B16CB9BCCB9E51_u64), (0x97D095F31DD5430E_u64, 0x7DEC144979A9032E_u64),
+        (0xF12900DC111D48D7_u64, 0x955B48FC5331B22F_u64), (0xB7E6E7C4469945EF_u64, 0xFC147F3EA8F00B89_u64), (0xC764EE80FFF097C8_u64, 0x54219C43C13FAB39_u64), (0xB2CCAFCB227839F1_u64, 0x7636B7C82AADB58B_u64),
+        (0x11D21BA94B097960_u64, 0xDC398A82133BDBBB_u64), (0x72BD072E5116323E_u64, 0x85A2E15F2366C50A_u64), (0x79D983F8E416D1CB_u64, 0xE74A06862247262D_u64), (0x852F7820B7A31C7D_u64, 0x77F05C756171DA4F_u64),
+        (0x675F40FB9BF30B8C_u64, 0xE8D9C15CEC7AC898_u64), (0x1D8C97C6A8CFE59B_u64, 0x5CBAAB9BFCF87CD8_u64), (0x1F85D55F5B8EE118_u64, 0xFB649E36BBDA4D30_u64), (0xE62691338829EA36_u64, 0xB33376D97FD2BABD_u64),
+        (0x1B4A03AF509D8A27_u64, 0x58282C2FC8E1B71B_u64), (0xC774FD53C71158AC_u64, 0xB7BC2E9F9BD14E09_u64), (0x446CCC8F0D22308A_u64, 0x746D9DE7231EFA01_u64), (0xCEF84BC4C8D121CF_u64, 0x704737CF716F3168_u64),
+        (0xE98E3183CEBC277A_u64, 0x9A908C7D90CFF878_u64), (0x7731578D8A0B749A_u64, 0x8133B48B453DDB55_u64), (0xB258627598A3ABBD_u64, 0x650397F93E46494B_u64), (0xF74ED45CE9B8E954_u64, 0x4ECF99626C024B15_u64),
+        (0xD2FF48915851485E_u64, 0x95A4F33783FB21EB_u64), (0x68EC98A86E40D25E_u64, 0x8B4162C6394E1624_u64), (0xA49310B903064263_u64, 0x4739566C67A6A302_u64), (0x19C32D6CA41BAC18_u64, 0x95948295BF9194A8_u64),
+        (0xA9ABB0F8132E6F8C_u64, 0x396443E2CC821C6A_u64), (0x13CF49D183DD2F0F_u64, 0xA74DB8F63A7F5422_u64), (0x1244018F735AE335_u64, 0xC7250A6398CA2C43_u64), (0x84F6268C7FA312D2_u64, 0x148C5E3C0D568759_u64),
+        (0xBE76DCEF6F391215_u64, 0x9DD729B9205FD432_u64), (0x10BF0295E0EC09BB_u64, 0xC67F810FBCC6F163_u64), (0x77A45D17182B35D5_u64, 0x178E963DF2EC18CB_u64), (0x55F43F4D5487E4CA_u64, 0x1A8BB4036ABDF88C_u64),
+        (0x6CB97906E89DA62F_u64, 0xF139052A10471B6F_u64), (0xECBF74413176D4BC_u64, 0xB907D70D400C00D1_u64), (0xD8CC52609C00CBA1_u64, 0x944103CA3E6736FC_u64), (0xBAA124A02CAF6A1E_u64, 0xAB3043FCBFBD78E5_u64),
+        (0xE42A9D4249558AA5_u64, 0xB095F5FAF77A8481_u64), (0x9FAE277BA5292262_u64, 0x8DEFCA2F39F4B4B8_u64), (0x7CA0AFF40C9397DE_u64, 0x7111F1E8664B06E6_u64), (0xD8F95650978631F8_u64, 0xFD9E9083290959D8_u64),
+        (0x750BA5B689D5652B_u64, 0xA6FE88713CAC0224_u64), (0xFF3834606E66F2F9_u64, 0x08EFF48F6E7C76E7_u64), (0x082C27ACD218B98D_u64, 0x14607025D19E9D7B_u64), (0x23FA2DE9E65AE3FD_u64, 0x3CFD61CBD70EC82C_u64),
+        (0x00020E154BEB2F4B_u64, 0xBFC9DEEF0D1DE803_u64), (0x97386C4B23FC8F7C_u64, 0x07A6BE0C40D396BA_u64), (0x23F1789F572D61EE_u64, 0xAA5C70AA08B41E2E_u64), (0xB6673194C833826B_u64, 0xB3620BC2C1FC2F49_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO1024PP_768: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x02F4950E96F7FDE2_u64, 0xFAEC493F304B3C88_u64), (0x443463F2363FC348_u64, 0x57990AF07735C0CA_u64), (0xF0413030F87D32D8_u64, 0xA3C33FE6FF07BDBA_u64), (0x2FA846C933C04AE8_u64, 0xEFBDB5A4CDC09DB2_u64),
+        (0xBFC4746DB194AE01_u64, 0xAC658CEC5994BB11_u64), (0x193994327211372E_u64, 0xEB5791D630897921_u64), (0xAB5362DCF45234F9_u64, 0x55995F7AEF526BCA_u64), (0xD0EE5996BA6C5F18_u64, 0x2CDD7CE624635F33_u64),
+        (0x8BB134DF26C09385_u64, 0x77FB443D458A6999_u64), (0xA7AE48618B538A57_u64, 0x3D6C5FA56A0BE201_u64), (0xEADFDE94C3D10B75_u64, 0x205BB796BBF83602_u64), (0x7435201954881336_u64, 0xBC7B949920498102_u64),
+        (0xC27507A8591A7173_u64, 0xDDDE3812134A6103_u64), (0x24A663EFB88A9ED5_u64, 0x04E22503763C4710_u64), (0xD6C91380AEC345B2_u64, 0x239D587AD4993497_u64), (0x21C2335D74DE35D4_u64, 0x65916F148EC0E056_u64),
+        (0x9A9154E489297C28_u64, 0x527A17AFA878A71F_u64), (0xC94B09D8B27E9AEB_u64, 0x2D53B26251510C40_u64), (0x5C6B39D7B9F7C167_u64, 0x739AFEB772A71A53_u64), (0xF777ACFA906C3F59_u64, 0x37B34CDD665B2D30_u64),
+        (0x817A6102CDC44152_u64, 0x91D7A7848C90BFA1_u64), (0xF6A1C55DA6D9FAB7_u64, 0xDA31B52DAD4E9666_u64), (0x8CD5C18B5B4F53D2_u64, 0x95AE6C24D1B6498E_u64), (0x43678BEB3A70CE3B_u64, 0x4ED8A161779E5894_u64),
+        (0xF7745DB37BB83CFA_u64, 0xF93534DB3BF18192_u64), (0x1CE6468FDBC0A69F_u64, 0xCE3C28ED0A0793BE_u64), (0xBFAE63D54DCCADDE_u64, 0x4E005947A1A5B732_u64), (0x17475684B8512921_u64, 0x364D1B065B43C496_u64),
+        (0x9E025D40C9D24868_u64, 0xF290AA11519E19F4_u64), (0x6BCF9FA89A98DD55_u64, 0x5F7E4BA9CDAD42E0_u64), (0xF498128B4DC89BC8_u64, 0xBB3CA7AED2404CDD_u64), (0xEA4F0F5822D5C8B7_u64, 0xE78F29A2ADCAC47F_u64),
+        (0x7CA812FEF9E64AF0_u64, 0xF9BA45FE741B49A6_u64), (0x6AE25CB01E1D3A11_u64, 0x44755EA613C33E55_u64), (0x9AA53E18C01CD535_u64, 0x8AF85AA2B2685A58_u64), (0xDC91A6C1E1D3FB01_u64, 0x4B7CD0D79FFBE694_u64),
+        (0x8D2CC4DCFC6082C0_u64, 0xA661973E0E4A7544_u64), (0x363211727773894B_u64, 0xEB31984B5BFF395F_u64), (0x7D33DB63A1F8729B_u64, 0x5ADB5BC945F8A357_u64), (0x53A2820FAB05EBB6_u64, 0x549D2EC5974138DB_u64),
+        (0xB52DECE9BCF29877_u64, 0x40B956D8A96F921A_u64), (0x0517C43A928583B0_u64, 0x688A7505F17A4574_u64), (0x51EDBE80C8379779_u64, 0xF71F9F42081816E6_u64), (0xBC3FDCA6AA68F007_u64, 0xC083F9F3649238BF_u64),
+        (0x69E0E2427D0B9D30_u64, 0x9667468592DA2310_u64), (0x2E28E2BE15BFDF12_u64, 0x2AE1E1A1A2F78473_u64), (0xA7D77E903E6498ED_u64, 0xEDC476D8CB7759A7_u64), (0x261CB9990284B380_u64, 0x782FD9D994B8E302_u64),
+        (0xDA3F72AFEEBDAA0B_u64, 0xDCCAE73F1ED1E0E2_u64), (0xBB665FF145A437A1_u64, 0xF2AE98A92CE792D6_u64), (0x5AB368A1070CE492_u64, 0xD8215164A101374A_u64), (0x4B88668A5846AC14_u64, 0x0DC8FB36A445B0F4_u64),
+        (0xBDAE261A9BED2E62_u64, 0x2FAB1809D6BA998D_u64), (0x61C7931CC7DA0E4C_u64, 0x8DBDC839D3211EA6_u64), (0x8C91A15EAF4AA8C3_u64, 0x470E1A7A6A2A1835_u64), (0x7162471690FCC7C8_u64, 0x4D7D2C7B1D96C928_u64),
+        (0xE1D6B6E9DB3A6218_u64, 0x6B0AE80F6055B81D_u64), (0xC37269EC1F086596_u64, 0xB5D954B1C020CB96_u64), (0xF5CD8F7B4204281B_u64, 0xF41563D797E6955C_u64), (0x673A0DBE6AB7146D_u64, 0x37D28C26151F6BDD_u64),
+        (0x8BE509B3DD85EF42_u64, 0xB3D4550207F3CCEA_u64), (0x45E6BF40B3D955DF_u64, 0x2F7DD6C263A2EBAA_u64), (0xBCB3087277977473_u64, 0xB4FA7EBEE9788833_u64), (0x705A0E00E9C273FC_u64, 0x1E63928B6E89C3A7_u64),
+        (0x848FDFBF452B65B9_u64, 0xF0E4E034A7449636_u64), (0x2C571BC66C69DA62_u64, 0x2FAAE1B9B220B4BC_u64), (0x0322B3BD73E6C23D_u64, 0x53FDBA1218ECF661_u64), (0xFB22A57CE4642D8F_u64, 0x301981DE45C0E032_u64),
+        (0x7ED7B902C4A0A78A_u64, 0x7E1E51E7C4F18D30_u64), (0xD0177F26ACBD2500_u64, 0x3DDA68EF11E63EAB_u64), (0xF005462B07463324_u64, 0xFBDC7E598B7FC93C_u64), (0xA213BF01E471A269_u64, 0x0444B6B243B3EC01_u64),
+        (0xF6B5CA8C0D7AFB09_u64, 0xA3A47A5BD2269E66_u64), (0xE9E8C3D72B8C79B8_u64, 0xA9B1BE05068392B4_u64), (0x0E3CB897DCD25BC9_u64, 0x44B7A66DE2E3CE79_u64), (0xAF1BFE0A339B256F_u64, 0xEB29AD5B89CA25F3_u64),
+        (0xA826A52FA4E2DF8E_u64, 0x178C1F39CA4E7E95_u64), (0x64E0E1F383B14FF5_u64, 0xBCE78B9DF8E89B9A_u64), (0x13F5813BA288D5F1_u64, 0x85881BA02EC3E224_u64), (0xC1942A8F62216921_u64, 0x949EC16635650DA0_u64),
+        (0x220A0280AD0A2073_u64, 0xAC83566FF5000CE8_u64), (0xE08FDF8C572BA894_u64, 0x825E561C1A8BC960_u64), (0x387A093B9468AAF9_u64, 0xD6C86A7859691C66_u64), (0xC8648B2428A56CBE_u64, 0x07AD395F17BBB610_u64),
+        (0xDAC9A1253E418ED9_u64, 0x84A2EA47A3380A0C_u64), (0x90A8BAF154127CFB_u64, 0x0DF95555620860C7_u64), (0x87AF643E76F53640_u64, 0x27AE751B79786BFE_u64), (0x3BA87664D8424766_u64, 0x4BE9AFBD37E6CB30_u64),
+        (0xD8FEF2F4D79BF38B_u64, 0x9C3FE6CCEBAF71AE_u64), (0x20698BE18D82A862_u64, 0x40748CC68AE19E51_u64), (0x98D37EAADF776244_u64, 0x02F113ACB7524F3B_u64), (0x1DAA33508A74907A_u64, 0x0F5190E3965CD6AF_u64),
+        (0x9D28CAE775F2BCE5_u64, 0x222E1EB61BF70FC5_u64), (0xCBB051679EB4C743_u64, 0xF57293DBA9AED769_u64), (0xF7FAAB8917C87DA1_u64, 0x408E8C52A81E3619_u64), (0x060EBFC805C71BFE_u64, 0x7564D0331B3B054B_u64),
+        (0x55D9F7C3D07BC2E7_u64, 0x1B0A19ACD61BCC8C_u64), (0x8E11B081E7450A09_u64, 0x118FDE697F8C12C8_u64), (0xA7273ABA3DBF7E7F_u64, 0x85A38359ACF1F0EF_u64), (0xF8721699775BEAC8_u64, 0x08E6E4604267A934_u64),
+        (0x15BF5F92578DBB4F_u64, 0x4509366315AD0C24_u64), (0x099FA38FD6F95F8F_u64, 0x27507F2CD43AD208_u64), (0x490AECB3D7274B30_u64, 0x16CDA5BBCB479126_u64), (0xC55750C0DFBDDC81_u64, 0x4B0D56F9DA516F60_u64),
+        (0xC1C28CAF1BC1A560_u64, 0xD38233FDF51DE9B0_u64), (0xCAFDEB69DF01FD27_u64, 0xA59C8EDB996D9B70_u64), (0x5850C9842A1E3B4F_u64, 0x9A883A137F0A1FC5_u64), (0xBB0D784BAA4C2CC1_u64, 0x3EF8E84B7F06ECBB_u64),
+        (0xC63885A74575539E_u64, 0xA38E62543767ACCA_u64), (0xD3E62FB25A96180B_u64, 0xA15F680F0BB70317_u64), (0x9DB46E09D4233A86_u64, 0x07C7E98B7F069803_u64), (0xAFF6A229C786B49D_u64, 0xE1B9E9BF2C8D136D_u64),
+        (0x5B9D6671759A96A6_u64, 0x3465D46A75C1FC63_u64), (0x81408B630D3BA220_u64, 0x4A2544F96ADFA3B4_u64), (0xBA3343C5DB4F9CAD_u64, 0xAD9166364BAF331F_u64), (0x5D1714C4B536A4A4_u64, 0xE1044A4480B9EDAA_u64),
+        (0x769107C569FB3DC4_u64, 0x10990B49C6ADB6CD_u64), (0x295F77CFFD64DABF_u64, 0x86B1EB938A81D2DC_u64), (0x9E1A3CBE5B1531CA_u64, 0x5AE5CEF9AE2526F8_u64), (0x305D477BD3BCAA1E_u64, 0x9C46CE009B82D5B7_u64),
+        (0x6BF5382651292812_u64, 0x46B0130A3FD4D831_u64), (0x1A9FFDFE1AB752A9_u64, 0x102FE5D7BBA76A58_u64), (0xB9E8CBA9139B4B04_u64, 0x34F25026F5E5A177_u64), (0xBBCA207EA5EDD2AF_u64, 0xC1156F9B111AAD0E_u64),
+        (0xE75C290AFF186D56_u64, 0x78CF32D279124693_u64), (0x58C7D072AB0AD6BB_u64, 0x07ED6BD000609221_u64), (0x47CA28FA06EF3D1D_u64, 0x186B5692081C7C57_u64), (0x56BC1863C549B35A_u64, 0x404395EBB2644DE4_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO1024SS_512: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0xEF2B21EAAB2AB0D7_u64, 0x9D983C0113C6F527_u64), (0xC35CD6908D57AE5A_u64, 0x7175E80569EABC84_u64), (0x14EA536E2DDA5EDF_u64, 0xA951C487A4731CD0_u64), (0xDCBE7DF66ADFEDA6_u64, 0xBEF152CD1DA425A9_u64),
+        (0xFB577080791A0D2D_u64, 0xBC059F49328722E8_u64), (0xBB18DEA38E85404D_u64, 0x67776591F6BDC0AA_u64), (0x1C8AAC10F983BB3C_u64, 0x7F835EAF559B3CCC_u64), (0xAA0DA84E59E85FF5_u64, 0x1D0D3F540145FEC2_u64),
+        (0xD9909B859B6CBE0E_u64, 0x7D2C5248ABC72E71_u64), (0xADF20CE45791C693_u64, 0xF5E38C9601F5BAB8_u64), (0x1785B849E702ACF1_u64, 0xFB895967BDAC674D_u64), (0x62F2349EF199CD58_u64, 0x6D1BE67EF0485DD2_u64),
+        (0x229C574D13FA86E4_u64, 0xDF76DC5D044B9910_u64), (0x92269637DE1CD397_u64, 0xDC1F9D959B8BF2AB_u64), (0x8C949811E2C6B125_u64, 0x8D2CAF51B8F4B959_u64), (0xF6A7BBB79712E540_u64, 0x6A8AF9A85DA433D5_u64),
+        (0xDFE664BB70D17401_u64, 0x303A2AF04F28A0A0_u64), (0xC20AC0EFFE8D11FE_u64, 0x4BD13BF4EFE66416_u64), (0x726BC30721206686_u64, 0xDA98F422CFA9943F_u64), (0x1988D04BB23E101D_u64, 0xB212A9D0BD1E9005_u64),
+        (0xCDBF539C588C18E2_u64, 0xDD6096DB4F57267E_u64), (0x25612ADDA07C8610_u64, 0xA287654C420FD1EA_u64), (0x2E9DD0D79F640591_u64, 0x422ED4C06B61173C_u64), (0x538721D59CC9FCAB_u64, 0x646C5E08AC70ECEF_u64),
+        (0xECCF5AC1DAEDED40_u64, 0x3C191C2F066E30C8_u64), (0x246D31F5B72FE40D_u64, 0x2A26629F7EBD29E2_u64), (0x70F93DB563CEA2FC_u64, 0x361A6B67843E5678_u64), (0x21BF9F9AD8B003C7_u64, 0x797DC2E22A165198_u64),
+        (0x9358CBA4C6CCC49A_u64, 0x17FF00FBC55D8059_u64), (0xC63159C614069277_u64, 0x4836EEF60BDEAC6C_u64), (0x0A62CE25610C3D15_u64, 0xB1E596E303E93FED_u64), (0x5CA5A84FBD539994_u64, 0x73833EEA93DCDAC8_u64),
+        (0xB29ED4EE527EB254_u64, 0x22A0A130A231E5F6_u64), (0xB01BB44B0AAD30E5_u64, 0xF8BBC9012E9CFBF4_u64), (0xB08F94428F1E028A_u64, 0x8CA20B5482AA8244_u64), (0x62E53C392E99CC0C_u64, 0x67DDA6251BF20A3E_u64),
+        (0xA7D96FA95B8E39C9_u64, 0xB4C3747214643A2C_u64), (0x8A41A42BCE19D8D9_u64, 0x6B606A058C15CC3E_u64), (0x4D4AEDC9CB833BEF_u64, 0x2D528D5D7A4D2C2A_u64), (0x740856AE0A8A60E2_u64, 0x796A39BA31BBC050_u64),
+        (0xC2A5C3DB9891DD25_u64, 0xB31B5832F8C033F3_u64), (0x0B74A705650E8488_u64, 0x3A40C741A22E8292_u64), (0x3082E29136F5CFDD_u64, 0xDAFB946B96423F50_u64), (0x11C7252305977831_u64, 0x91610EFBDC8C9DB8_u64),
+        (0x9C3F4009FB524466_u64, 0xCEC1E8A66964F322_u64), (0xA235E63728B8D5D5_u64, 0xE3C34CBBF8C90DCE_u64), (0x0A415ED001478EEB_u64, 0x1CB41BC06A9693C4_u64), (0xCAF244DF4074E6BB_u64, 0x9561AF7A7EB61A81_u64),
+        (0x6F5A790C3CD3108C_u64, 0xCAFF1C8DB84BB4D8_u64), (0xB74FB5889385F261_u64, 0x46B4875E05252C71_u64), (0x36BD6B943CEB0FF1_u64, 0x2E658C8802D0EE7E_u64), (0xF12EB6A58695D1A0_u64, 0xF9D0A9A1E0899519_u64),
+        (0x326DCC63132B3B6C_u64, 0xAE352DD11E5A2E12_u64), (0x6DF82FE2E3FEED00_u64, 0x882CF280BD60035A_u64), (0xF231FC3772A2E0A0_u64, 0x5E845537D43A4311_u64), (0x966E655539FE48F4_u64, 0xB4DE5FE65AEDB907_u64),
+        (0xF92BADC7C6C376AC_u64, 0x72159C7CE233C5BA_u64), (0xCD11811D398D2404_u64, 0x96B80145FB42B340_u64), (0xCA3807F72D9E7F50_u64, 0xFDF77383862D8E98_u64), (0x22D3C6FE11F65863_u64, 0xCA486530781D2425_u64),
+        (0x8187A03B2D64EAF0_u64, 0x971BBF6F1A5C4EAC_u64), (0x4DAF0A2832AF32C2_u64, 0x69F7AE11BFC1246D_u64), (0x8748806BE1E9576F_u64, 0x96E6A4307D627AC7_u64), (0x525DE77F97A2C470_u64, 0x83916021145DA329_u64),
+        (0xDE58544A7C535D69_u64, 0x373716E4E3DC448B_u64), (0xB75174315FFA5B28_u64, 0x2926EAEDC515EA5C_u64), (0xE90B72D615F76B24_u64, 0x62FB85DB75F0E600_u64), (0x693EBA57EB1B0267_u64, 0x1AC10B16F54765B2_u64),
+        (0xCA2FCA98064DF93A_u64, 0x50B83AAA961B3CB3_u64), (0x7F1898AACD616D17_u64, 0x71DA48B97B55598E_u64), (0x8B4E943DAF4332B8_u64, 0x9A854C5A9D9AF870_u64), (0x6949C2CCE0A07071_u64, 0x8CF574D8B45867B2_u64),
+        (0x002E191F2ED674F0_u64, 0xE6AA445FFD6FA82C_u64), (0xB6BA536757A80C75_u64, 0xD49B01F25050CAF2_u64), (0x10D536D81DA359EF_u64, 0xEEE7C3EDD0523805_u64), (0x0C5DBE2B5FC6A788_u64, 0x9ED528BE3E1D3016_u64),
+        (0x2546ADEC7C2C33D0_u64, 0xC3F7AA359326B098_u64), (0x3521E94C925C2E06_u64, 0xF0CBCF20E9EFBBBD_u64), (0xE2FC0A1D0858434E_u64, 0x6581EFBA6E2496EE_u64), (0xF21D7CAE7FBD0430_u64, 0x448802D9E902CCEA_u64),
+        (0x2BEBA84EBDA26724_u64, 0xA7E19BC45861EB71_u64), (0xB3CECB82687D72C8_u64, 0xFD321FED37C5AE3A_u64), (0xB15480636F1BF358_u64, 0xF11C4639487F3F16_u64), (0x70DCC5C358F29D9D_u64, 0x8937A2EA51BCF6C6_u64),
+        (0x8DCABF9EFD6CFE35_u64, 0xD340DAD86A2E7672_u64), (0xEDDF775F18747AC0_u64, 0x5BAADD7A72D807BB_u64), (0x3047BB0DDB25CD1A_u64, 0x9C32312B1908779D_u64), (0x35FF6DF5BC8AFE63_u64, 0xECE2D42C84BF30F9_u64),
+        (0x96C610FEAE68BD14_u64, 0x5BAE8B341BF36E6F_u64), (0x27C829AEC776CF2E_u64, 0x5CEB57C8C8E2A714_u64), (0xBD17D58875545346_u64, 0x5841830A455E9B2C_u64), (0x7747E9A3922FD407_u64, 0x5AC9DAC528A451D4_u64),
+        (0x91EE5EF354DCFA1A_u64, 0x1FE38539E67B0D5C_u64), (0xFA41A9AB5AAE7D48_u64, 0x4E7CB0C657B27BD6_u64), (0x97C45748294EDAAE_u64, 0xB3BC175B470E3A34_u64), (0xA21E605CF63819A5_u64, 0x2C3C1AE80EB1C358_u64),
+        (0x91BE36A3AAC1ADDD_u64, 0x5040B37B23D7EB76_u64), (0x93011C330C455B79_u64, 0x7651C6826CBE800B_u64), (0xE8E73670B0EE22DA_u64, 0x834C5C4C63577ED6_u64), (0xBDEF96ABC327576C_u64, 0xBE0BC81C44DBB976_u64),
+        (0x8E989B6B57AE4563_u64, 0xE9B2D0FA8DB806A2_u64), (0xD8D7E40D7CBDBC59_u64, 0x15C975B6A83CC730_u64), (0x2C35F9FFDBC027F5_u64, 0x669A2547B2999308_u64), (0xC54D108AB0EF910D_u64, 0x90293B3DCD3C4926_u64),
+        (0x6734A149A1147538_u64, 0x7D48F2DF77AFEF61_u64), (0x41E76B52FBC86857_u64, 0x3E2F84EBE4E48E96_u64), (0xC040497E7CD6DD3D_u64, 0x559DFF74803EDA1C_u64), (0xFD1AC5D04A50DD90_u64, 0xCEAA0162B7EC6B85_u64),
+        (0x300E8246706EAC22_u64, 0xC4066A3D9194A0C5_u64), (0x2193EEB917B7FCED_u64, 0x6DFEFDD134426286_u64), (0xB445684E0F990C5F_u64, 0x4870BCDAB320FB69_u64), (0xAB51D970E31CD3DD_u64, 0x05E6413DA262CB4E_u64),
+        (0x37DC315F1082D862_u64, 0x5CE4E6B0850F53C7_u64), (0x6A0F2D43C5BD5F58_u64, 0x1A5BE8D6A0866846_u64), (0x36CF2697E8AA41E7_u64, 0xECAEE077C80394B3_u64), (0xBB0A2A5511B3A68F_u64, 0x4528DE9C60B2A4EF_u64),
+        (0xCAD2E50C0471E6AD_u64, 0x5FB42A301D880D6B_u64), (0x99703C47B12217E8_u64, 0x8DB825A5FF4F7B9C_u64), (0xACAA3998210A6EA1_u64, 0x68E1B4BEF3F218EF_u64), (0x76C82D670BF957FE_u64, 0xD3CACCF31C34BCA8_u64),
+        (0xF6D65A064ED647B6_u64, 0xE6DBCE5E6861106D_u64), (0x91E8C6B1E9917F10_u64, 0xDA775C4BF43BAC1C_u64), (0xD72318333D58CA91_u64, 0xB0063FAE644B9C72_u64), (0x441D8CF97345A889_u64, 0xD52F8C5A7BBC90E2_u64),
+        (0x05C745523C9226FF_u64, 0xA9250E57F0DB43A4_u64), (0xE5388BC786F24861_u64, 0xBB11A2D438019457_u64), (0xA59AAF1D72D0B724_u64, 0xA676F29058D95CF4_u64), (0xECE28AF0BD5241C2_u64, 0x1219F97ABC39D237_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOROSHIRO1024SS_768: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x77E0DAAC1D31FD62_u64, 0xEC1929ABC1BD10F0_u64), (0xE34DBD287C8082CC_u64, 0x343124159C39CAAC_u64), (0x3A20B01E6D2B9415_u64, 0x22AB8525097732C0_u64), (0x1B263CD3090F2E23_u64, 0xDC9984249D5E7F8D_u64),
+        (0x04D2E7C1F5DC56A0_u64, 0x8CD4A39A6CFA18B9_u64), (0x1CF89319AD68775D_u64, 0x5942408D44D70AC8_u64), (0x6E3C60F445D61418_u64, 0x43601E8508BCD36F_u64), (0x6F663FEF71246631_u64, 0x080AF9269B51297B_u64),
+        (0x98399A02DC8238E8_u64, 0xC21182ED4FD0951F_u64), (0x9C185C6E4E150802_u64, 0xA7977E82AF29B86A_u64), (0x54F1DAD045B4A1E4_u64, 0x132310F6B0C0D319_u64), (0x2970CE3FBD721E36_u64, 0x56092B369AED07E5_u64),
+        (0x5F33E3E2167536F3_u64, 0x140AAB663129EE95_u64), (0x0CBB55EAB00B96FB_u64, 0x38CD8FEA48A5557C_u64), (0x5386E23377B5EFFB_u64, 0xDD7D5A67DEAA67ED_u64), (0xE5F7DFAF3323B79B_u64, 0xD40FA3B6B25F8427_u64),
+        (0xB0F3EB2302DEDA9D_u64, 0x6E8D223677FDCBCF_u64), (0x7086486EF09C7191_u64, 0x1F0A92BC16078CF9_u64), (0xE897338461176D2A_u64, 0xD71E672FF5C666F7_u64), (0x3353A367550AAA32_u64, 0xA75499756A97CBCC_u64),
+        (0x4D83EAC5C2358823_u64, 0x62A590238F3837A5_u64), (0x4961A9163AF2E477_u64, 0xBC7D9F3F2F3E4402_u64), (0x0CF1F0A12A8CED98_u64, 0x923907B57096C017_u64), (0xB5B239FC03A45B08_u64, 0x24A6186B458E2E50_u64),
+        (0xF32817EF1863093B_u64, 0xADCECBC9E881B9E6_u64), (0x5AE2B8FDB6A1364F_u64, 0x015D9847DF426F24_u64), (0xB8B7A680AEBDCC24_u64, 0x89A20E35E1227D67_u64), (0x2F36FF75BD8C1E25_u64, 0xED1B009F1DA0367A_u64),
+        (0xEFCD0C08B242D1EB_u64, 0x8C954C4EC7654F5F_u64), (0x7BE7B4003A088FD0_u64, 0x89773403381D57E1_u64), (0xEAD79B059C026013_u64, 0xC7204FD4184938D7_u64), (0x5B28358BFBF5B1F5_u64, 0x55C15EEF805377A4_u64),
+        (0x1435C99E3A636538_u64, 0x7AF47B13CA3079CF_u64), (0xF1EE26E117322575_u64, 0xB99F5B709D8B7570_u64), (0x2D783FB6A68B2714_u64, 0x9A88BA291288B544_u64), (0x302C30FED6A9500B_u64, 0x49F3EDE89B0B557F_u64),
+        (0xDBAC63FEF776FAC6_u64, 0x37713E3DA585F9CE_u64), (0x0D1C265540B670EB_u64, 0x47700F6C3AB4DD63_u64), (0x908F21AF30E6FB5B_u64, 0x50873A4BEDA98D16_u64), (0x41AB604C439FCB29_u64, 0xAF9B60353B597992_u64),
+        (0x2E14998AB7087DD6_u64, 0xF3883042CCC528EB_u64), (0x72B6ED2CC00BF464_u64, 0xEB4599A2AB1DED44_u64), (0x4D820EA72FB9168E_u64, 0xDF0CE9FD743F26AF_u64), (0x044BA8A44225925D_u64, 0xAD960DCE9A4939CC_u64),
+        (0xCEFBB8A5C12F7734_u64, 0x12F6C16DA6BA031C_u64), (0xCDC52E213335E7CB_u64, 0xE6077D5A04271F7F_u64), (0x37F9EBCAF2B1B730_u64, 0xB530825BDEFE39B9_u64), (0x4031E7B6B7382F0D_u64, 0x59BBA8C5F8C9AB51_u64),
+        (0x646979B8AB8BF5D9_u64, 0x9FF3695AC9A563F8_u64), (0x8F6006DDD5F046CA_u64, 0x8FAB23B8CB7FF9A0_u64), (0x0FBA77DADD4CAB7B_u64, 0xA275DC1674AA1968_u64), (0x78A3913F7E3DD9D1_u64, 0xFD60AEB879E4E856_u64),
+        (0x3E83FCD3F7981666_u64, 0xABD229BC6656020E_u64), (0x77054388EE149512_u64, 0xA86BFCBAF54D1521_u64), (0xDA0B6722D0B2AFF0_u64, 0x5A3D579BAAF30956_u64), (0x8D753CA5BD4124F9_u64, 0xD809C612E5344A68_u64),
+        (0xF40C74F643EF1161_u64, 0x1F679821E8CC576F_u64), (0x0C30FB3C70441FD3_u64, 0x0EB87782EBFA5E70_u64), (0x183BC2AA5065B489_u64, 0x20CCA4677062B1A1_u64), (0x984E1117C51C9515_u64, 0xBDBE0CE00A30F6C4_u64),
+        (0x0ABE7508ADDE2042_u64, 0x5F7EC54B69E9136E_u64), (0xBA93BA380CB9CCCF_u64, 0x41DC5C43D71616BA_u64), (0x277393383B3C3C3A_u64, 0xE2F8730BCDCB5656_u64), (0x7C7123808E56D51D_u64, 0xB59D0D91B56CDAE1_u64),
+        (0x0878C9ED6AC4A746_u64, 0x295800BCAB427E37_u64), (0x38537ACD6D8FC07C_u64, 0x2F18654E77558BE0_u64), (0xF364AD87134C1877_u64, 0x9EBB6EDCA07CDE5A_u64), (0x1E808918E8086850_u64, 0x62CD188E5A0B799D_u64),
+        (0x65FC56F46E370105_u64, 0x95758FC0FDF59E8C_u64), (0xB666AD19DA64805E_u64, 0x5947FAA385A2E622_u64), (0x84CC13E2F41BF2B6_u64, 0x09CF19C27C7D41FC_u64), (0xF36659006A35102D_u64, 0xBD820DBED1472546_u64),
+        (0xAF797DB48079AC1F_u64, 0x4CE5DF8C1E0B58B4_u64), (0x35A43E99E32475BE_u64, 0x0182DA69F1D34533_u64), (0xA07E1C52D0E10254_u64, 0xEB05102529BF9494_u64), (0x0C0F0D7D07FF8ED4_u64, 0x42E1B39885F239F8_u64),
+        (0xF7547665C8EE8EA4_u64, 0xA72EDC06824FE46F_u64), (0x05117B8B6CB9B5F2_u64, 0x9B6AD6BB88DC883F_u64), (0xE48609527C91B10C_u64, 0xDD69B94E48863235_u64), (0x0F6351E17E25CBF7_u64, 0x6EF737E883898D93_u64),
+        (0x71A08F790B7B413D_u64, 0xEB6C6E6BED6CB2A5_u64), (0xAE284CE5A1479A06_u64, 0xCEA1110B8D8E0A9A_u64), (0x0DAC15DE5D1392D1_u64, 0x68FCDF76278FDC64_u64), (0xD2FAECCC4CC26877_u64, 0x49CFBCE7B8DB3422_u64),
+        (0xB9FD7A483C141E0F_u64, 0x50A672414FE902E8_u64), (0xCB44A533591AB369_u64, 0x8D4AAEE36CE97465_u64), (0x46DEAD150FFC6B86_u64, 0x11897CF9272898A1_u64), (0x004E60FEB823E1BF_u64, 0x3B6C6E957DC919B4_u64),
+        (0x371875E3E3C1D015_u64, 0xBB7BCD7AE91D2C4C_u64), (0x85B7CE1C365D9D69_u64, 0xABB2D765C57BC716_u64), (0xB90EE2D87A31F1B9_u64, 0x712AC8D6B01E9106_u64), (0x7D667364D06DD5F8_u64, 0x25E62E91C55F5D47_u64),
+        (0xDE7748F40617E0FC_u64, 0x2868F2CCDF30B92B_u64), (0xB9D42393BCE47F92_u64, 0x08361DC6FA1223DE_u64), (0xB9A7E931BC7F2F76_u64, 0x07DE3C4B54024995_u64), (0x537A75F1000FF9FD_u64, 0x2C5BB5D71F8605B2_u64),
+        (0x4F2584DE6892F954_u64, 0x64EB982DFD66FAF2_u64), (0x4BD5EF757FD68114_u64, 0x2A8FFCCDBF5CF3B1_u64), (0xFE5233F85FF59CB7_u64, 0x83721BFB6C14D8D9_u64), (0xE7B8F1C8B86D4104_u64, 0xF61A766BEA01A3F4_u64),
+        (0x8DEBAC61D6E1D9F4_u64, 0x491AED50D78182AB_u64), (0x345D3AFB0CEE0497_u64, 0x40A8EE375FA9C1F0_u64), (0xF294E1AC814B8119_u64, 0xEE82E5A1AC5143C8_u64), (0x90815123F8AA1ABA_u64, 0xECB5257CB94ACD09_u64),
+        (0x1DE739981AF900D5_u64, 0x234B2B19146DC882_u64), (0x50DEC768F34385C4_u64, 0x6E34243B348D59DC_u64), (0xC76885EE16BDD2F8_u64, 0xF78BC5FBA8415A87_u64), (0xAE761C326B7034ED_u64, 0x486F81D937CB56E0_u64),
+        (0xBF60B814257AF006_u64, 0x805F057E50B4DE6F_u64), (0x0701A5CD008C97B1_u64, 0xFB8F88C5BA372C92_u64), (0xFBA91D11569ABAB3_u64, 0x11C2E636B25EB2EF_u64), (0x4363A47437B8B64B_u64, 0x3820819B0D78ED9B_u64),
+        (0xA18D38B60B7AB510_u64, 0xBBCFFAA9EABFD94B_u64), (0x5A06AA5C6A74719B_u64, 0xA8C0B82A66DCC240_u64), (0xA00FC1ED8727A9A5_u64, 0x9A19293DF4B7CA97_u64), (0x0311269007FA39D5_u64, 0x2F10DCDA9B72CC41_u64),
+        (0xC5FCC8E8933B90AF_u64, 0x40F56A436F60C29B_u64), (0x9F05982067350F82_u64, 0x31076CCAE6AE261D_u64), (0xFBA91442E93EC499_u64, 0x5882247FE45B8419_u64), (0xAC51E01A4AAE620D_u64, 0xF5008FC11683C30E_u64),
+        (0xE9C08484B8F18E92_u64, 0xC334EA7945217D1D_u64), (0x1C15A34C070EE089_u64, 0x55FB02A519C78786_u64), (0xD3B55E2E8A92F6BF_u64, 0x43275199A6D74DA4_u64), (0x8AA9574914A88B48_u64, 0x441B18955EA0E7AB_u64),
+        (0xEE68D34B50D5D2A1_u64, 0x1B8D8A5EF76ED759_u64), (0xA6B9595241D97037_u64, 0x3D65E6E7E024A5F9_u64), (0x856EE9F386CFAA1F_u64, 0xF0172B721D73F9D4_u64), (0x0B4E33445C364080_u64, 0x6F899E89B99B1614_u64),
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128P_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x00000000_u32,
+    table: [
+        0x1E8EDEA9_u32, 0xEE72A551_u32, 0x64909FAB_u32, 0xFC9DD576_u32, 0xAD7770CF_u32, 0x0B3C6050_u32, 0x9A2941E3_u32, 0xB2E7D5C5_u32, 0x971F7299_u32, 0xF5FC47AF_u32, 0x77B5F905_u32, 0xDDE982F9_u32, 0x4CC23502_u32, 0x82CD2F09_u32, 0xFEB306E2_u32, 0x722A86A4_u32,
+        0x51BAA4B9_u32, 0x4BF124A5_u32, 0xF2EAE53F_u32, 0x31B1D794_u32, 0xEBB4682A_u32, 0xA5757165_u32, 0xDC339D6E_u32, 0xD87E607B_u32, 0x4EAEF555_u32, 0x3D4B35BA_u32, 0xE3846050_u32, 0xDA15D78E_u32, 0xC2363158_u32, 0xFFA5863A_u32, 0xA172D497_u32, 0x5DDE72DF_u32,
+        0xA9F10761_u32, 0xC76BF649_u32, 0x2B99F1A3_u32, 0x6BB63D52_u32, 0x06183185_u32, 0xD3B25C2B_u32, 0x7121E872_u32, 0x173E5D46_u32, 0x05F87A1B_u32, 0x8DB837A4_u32, 0xF92B677A_u32, 0xB64EDF77_u32, 0x61296A10_u32, 0xE216FE20_u32, 0x57AEC13B_u32, 0x4A164F5E_u32,
+        0xCF928BB6_u32, 0xDD2FA03A_u32, 0x93ED4DD0_u32, 0x6A380CA7_u32, 0xF8C19731_u32, 0xDA5A3666_u32, 0x10706BB8_u32, 0x2EA2616C_u32, 0x8AB475C0_u32, 0xA65E5E77_u32, 0x7DC3C45F_u32, 0x5E182E43_u32, 0x01478B4E_u32, 0x7AEF6DE3_u32, 0xA955ACFB_u32, 0x3C6889C5_u32,
+        0x30ADFECB_u32, 0x89A268C8_u32, 0x34291DF4_u32, 0x0F59F71A_u32, 0xFDEB0A14_u32, 0xAE90A118_u32, 0x3C640E3D_u32, 0xACED7F60_u32, 0x12AE2757_u32, 0x629457B3_u32, 0x29803D9A_u32, 0x4E158482_u32, 0xF5DCD823_u32, 0xA3FE7E2D_u32, 0xD78176E0_u32, 0xC414E3B3_u32,
+        0x074B9F52_u32, 0x98E510BC_u32, 0x1DF8763D_u32, 0xED46CC78_u32, 0xAF8F8259_u32, 0x20BB0803_u32, 0x4E58EC61_u32, 0xFE204E72_u32, 0xE036B50A_u32, 0xF94475CD_u32, 0x81BF7F8A_u32, 0xE8134096_u32, 0x8688959C_u32, 0x23237955_u32, 0x87191184_u32, 0x17D55D63_u32,
+        0x1321C1DB_u32, 0x44C2FC7F_u32, 0x5CEBF945_u32, 0xED4BCA4D_u32, 0xF262A5C1_u32, 0x04E72706_u32, 0x3A421D6E_u32, 0x21F40572_u32, 0x078173FB_u32, 0xDC77C17F_u32, 0x7EDECB2C_u32, 0xEB88B9E6_u32, 0x13C0EC65_u32, 0x80258688_u32, 0x7D5BD8C7_u32, 0xFC532A9D_u32,
+        0xA383F5E5_u32, 0x501FD342_u32, 0x36FECFD6_u32, 0x5B6C74BD_u32, 0x792A9087_u32, 0x6111D426_u32, 0x1290E88B_u32, 0xC19FBA02_u32, 0x63E43EFA_u32, 0x94D8D596_u32, 0x7829FA24_u32, 0x5F9D22D4_u32, 0x244AB743_u32, 0x5B957F9B_u32, 0x7A95AC4C_u32, 0x99878062_u32,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128P_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x862654C7_u32, 0x588C4A4E_u32, 0xEC67899D_u32, 0xF8F49640_u32, 0xF752714B_u32, 0x6092AB82_u32, 0x348F1296_u32, 0x98FA3368_u32, 0xF92EA202_u32, 0xE506C840_u32, 0xF60C9B4C_u32, 0x922E6B57_u32, 0x5BBFAB0A_u32, 0x07A0A044_u32, 0x9FBF3F0A_u32, 0x8E158E2E_u32,
+        0x22F7D330_u32, 0xE0E00C7A_u32, 0x21BDF08A_u32, 0x610B9F95_u32, 0x9EE255CC_u32, 0x600E0C2F_u32, 0xF55C735D_u32, 0x579A232C_u32, 0x530D19E2_u32, 0xB4CC5B2C_u32, 0xBA705B88_u32, 0xC4802FBC_u32, 0xF2EB5999_u32, 0xB9F1CB6C_u32, 0x4BB1EF1B_u32, 0x2224086E_u32,
+        0xEAD32758_u32, 0x63D86424_u32, 0x92E5397D_u32, 0xF9794FE9_u32, 0x500EB9A3_u32, 0xAAC528C2_u32, 0x2FDC8B69_u32, 0x80B1A3A5_u32, 0x6DC4D251_u32, 0xB569526C_u32, 0x77C7ED67_u32, 0xCE913E4C_u32, 0x23E0968A_u32, 0x6783ADA3_u32, 0x55A23D4D_u32, 0xE87FA933_u32,
+        0x1517BBFB_u32, 0x9F8F100B_u32, 0xB181CF31_u32, 0xAFAB92A0_u32, 0xDAD22EB2_u32, 0xEF734775_u32, 0xEA0FC3B3_u32, 0xDDCB073D_u32, 0xBF9CD8AE_u32, 0xB75A4FD7_u32, 0xFFBEC0A5_u32, 0x4A932A28_u32, 0xC195CDBA_u32, 0x59F0CE98_u32, 0x8D965C8E_u32, 0xDDC276C3_u32,
+        0xA4051578_u32, 0x1D2AECF7_u32, 0x9CB0D1A0_u32, 0x8FD5B82F_u32, 0x8C73DB01_u32, 0x2E03D31D_u32, 0x1B038E20_u32, 0xB7D7BC88_u32, 0xA41AA73F_u32, 0xD6641A19_u32, 0x70CE157A_u32, 0x3F67B7C2_u32, 0x0BD36295_u32, 0xF413F73E_u32, 0x2108F4B2_u32, 0x5A091527_u32,
+        0x64C7C8D5_u32, 0xC19EAE23_u32, 0xDA837C99_u32, 0xA6700754_u32, 0x59D3A2CD_u32, 0x8434543C_u32, 0x38D0A871_u32, 0x15D5A276_u32, 0x24D562B7_u32, 0x15DB8BB8_u32, 0x47D4429E_u32, 0x685160E0_u32, 0x90A490DC_u32, 0x3098893E_u32, 0x1427A1BC_u32, 0x58203788_u32,
+        0x75DA207D_u32, 0xCAC20737_u32, 0x5AF391F6_u32, 0xB7DDFF1C_u32, 0xB7BE2A6A_u32, 0xA70B6A71_u32, 0x02823993_u32, 0xB34241D0_u32, 0x01F44738_u32, 0x3B8A76A7_u32, 0xBD3230EE_u32, 0xA3250E48_u32, 0xF0CEAB7C_u32, 0x4C0D2442_u32, 0xC31926EF_u32, 0x6FC54923_u32,
+        0xE904A937_u32, 0xE502C59F_u32, 0x3B2EDD42_u32, 0x794EB1FE_u32, 0xE9529280_u32, 0x514861B5_u32, 0x24A4799C_u32, 0xFC63A978_u32, 0x6AF3DAC0_u32, 0xB14065B4_u32, 0xA2B619EB_u32, 0xE584B149_u32, 0xF4B2616E_u32, 0xEC4589C0_u32, 0x89412F01_u32, 0x48ACAC6C_u32,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128P_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0x2D71E4F2_u32, 0x90838617_u32, 0x06C54985_u32, 0xAC80C391_u32, 0x5482C769_u32, 0xF058F7E3_u32, 0x6990D9FD_u32, 0xF87026F7_u32, 0x6206B77D_u32, 0xE5DFD3CD_u32, 0xBC99C658_u32, 0x4ABEECB3_u32, 0xF9D0A6E8_u32, 0xCAFCFC50_u32, 0xD51A8661_u32, 0xF1B741E5_u32,
+        0x8FF0A904_u32, 0x582091A9_u32, 0x07F7AE1D_u32, 0x63519075_u32, 0xB571022B_u32, 0x1BAD5FB6_u32, 0xD50687CD_u32, 0x2A2AA9C6_u32, 0x9731EE9E_u32, 0x19571AEA_u32, 0x5EB8E838_u32, 0xEF547D1E_u32, 0x079B9AA4_u32, 0xFE31B598_u32, 0x4A77701A_u32, 0xE17F98BC_u32,
+        0x8B1A789B_u32, 0xDF25A072_u32, 0xD7E67322_u32, 0xABCAA767_u32, 0x6CB5644B_u32, 0x2C8D8944_u32, 0x10D5A359_u32, 0xC93AB767_u32, 0x0B243EF7_u32, 0x4D6C3ABE_u32, 0xE0CA37F6_u32, 0xC8BA2E26_u32, 0x98F62720_u32, 0x3FEF3019_u32, 0x373942C0_u32, 0xD045C826_u32,
+        0x5FF464D4_u32, 0x58407E05_u32, 0xBA7200D7_u32, 0x3674075F_u32, 0xA51F242E_u32, 0xF4A59883_u32, 0x9978FA6A_u32, 0x4C371A2F_u32, 0xD699450F_u32, 0x657047AF_u32, 0x596AB242_u32, 0xE21EB550_u32, 0xD49663FF_u32, 0x7FD48342_u32, 0xB4362B91_u32, 0xA05898AB_u32,
+        0xA62642A7_u32, 0xE4331C11_u32, 0x41AC1D5B_u32, 0x3BFFD440_u32, 0xCD533452_u32, 0x9F36ED3B_u32, 0x8C5605B4_u32, 0xC0A93D3A_u32, 0x206F9CA5_u32, 0xCFB96ACE_u32, 0x032E37F5_u32, 0x7F48CA72_u32, 0xAE0ECD6E_u32, 0x1527EA01_u32, 0x60534887_u32, 0x46CCDC37_u32,
+        0x86FAC7B2_u32, 0x16241CD0_u32, 0x242D9E1D_u32, 0x2D1CE417_u32, 0xC9DAC48E_u32, 0xF9E9AE63_u32, 0x183E5589_u32, 0x1CDB5158_u32, 0x5832EFB2_u32, 0x148CE721_u32, 0xDF93D492_u32, 0xC438E6B3_u32, 0x5BB23EDB_u32, 0x74B333CF_u32, 0xBF3B69BA_u32, 0x33A89693_u32,
+        0x0F80E2B4_u32, 0xA132C278_u32, 0x686E2AF2_u32, 0x1D04DC0F_u32, 0xF0E1375A_u32, 0x6440FDFB_u32, 0xE95A1D46_u32, 0xE83E8637_u32, 0x7E1092C4_u32, 0x0F20E558_u32, 0xADA11493_u32, 0x2DE047AF_u32, 0x429C4DBF_u32, 0x88A0A1CF_u32, 0x5BD4569B_u32, 0xBB86E135_u32,
+        0x6F3E8DF3_u32, 0x63A798BE_u32, 0x07E9B498_u32, 0xAD58C4C8_u32, 0xCEAEC940_u32, 0xC5622F36_u32, 0x9755C96E_u32, 0x50600F8E_u32, 0xBBC2DF06_u32, 0xDF015D22_u32, 0x14170441_u32, 0x870B13FF_u32, 0x07FA76A4_u32, 0xCF39D9A4_u32, 0xAE7E45BB_u32, 0x0C2BC41C_u32,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128PP_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x00000000_u32,
+    table: [
+        0xA603D2D0_u32, 0x7B34954D_u32, 0x6CD9C232_u32, 0xE8F07B00_u32, 0xA0550CB1_u32, 0xFB398A89_u32, 0x1E5C610B_u32, 0x9F76678D_u32, 0xEDC5F3B8_u32, 0x42DE66D4_u32, 0xCAC6F665_u32, 0x3BBA2A9E_u32, 0xA892861C_u32, 0xFF3CC4CC_u32, 0x9BF6398B_u32, 0xA82C7259_u32,
+        0xBD3976F9_u32, 0xE03DE588_u32, 0xC6724F7E_u32, 0x3A11881E_u32, 0xEB53021E_u32, 0x139031E9_u32, 0xE544203F_u32, 0xE8C0F350_u32, 0x44772DD1_u32, 0x86F2DA6B_u32, 0x011391C4_u32, 0x3001BE1C_u32, 0xBF2EE1B1_u32, 0x5F5B7275_u32, 0x4FA37F92_u32, 0xF36B75FD_u32,
+        0xF32BA041_u32, 0x63C00071_u32, 0x67EFA17F_u32, 0xE71BE1FF_u32, 0xD65305FA_u32, 0x834D8713_u32, 0xBC2C05A1_u32, 0x0DB17E24_u32, 0x2D5554A1_u32, 0x114F29C9_u32, 0x7F667612_u32, 0xBCE65F6C_u32, 0x66BC5005_u32, 0xCE03C60D_u32, 0x1267BF1A_u32, 0x444CEE72_u32,
+        0xA0C017AB_u32, 0x9B912F03_u32, 0xA60468B5_u32, 0x3E7BCBF3_u32, 0xBBF26463_u32, 0xF01F5512_u32, 0xCBE1953B_u32, 0xAAC1D132_u32, 0x0605A673_u32, 0x959C73DD_u32, 0x220CBAEB_u32, 0xA04AD952_u32, 0x0D26F264_u32, 0xFAF6B04E_u32, 0xEF299C94_u32, 0xE287BD13_u32,
+        0x29E8C87E_u32, 0x5A1DDA3C_u32, 0xC8A86E27_u32, 0xDF1E5FF0_u32, 0xEC27466E_u32, 0xF632BA95_u32, 0x5FCB18DA_u32, 0x39FE803F_u32, 0xEFA53803_u32, 0xC5D92A4B_u32, 0x7422CC31_u32, 0x6E40EEEE_u32, 0x765F22F9_u32, 0x28FB6F8C_u32, 0xAD7C7120_u32, 0xAB773B27_u32,
+        0x54DE0FE5_u32, 0x6BA5FC58_u32, 0xEA16BC25_u32, 0x1ADB6419_u32, 0x2E5D66A1_u32, 0xF4F11204_u32, 0x9A674D34_u32, 0xB762F99B_u32, 0x6763CB21_u32, 0xB1CF5AAD_u32, 0x9BAC8F27_u32, 0xD0493020_u32, 0xD4E1292E_u32, 0xC82876AF_u32, 0x215E46B2_u32, 0x075992AB_u32,
+        0xBF775DD3_u32, 0x36D9DC43_u32, 0xE662AECA_u32, 0x81695BE4_u32, 0xF73485C7_u32, 0x2DBC760A_u32, 0x9FEE5664_u32, 0xB675E86D_u32, 0x7C945941_u32, 0xE43D98D1_u32, 0x8655BB76_u32, 0x86B1F6B6_u32, 0xFB804824_u32, 0x186889D3_u32, 0xFAEFB240_u32, 0xD77FD294_u32,
+        0x922BDCC8_u32, 0x2CA02606_u32, 0x292F0D46_u32, 0x43994246_u32, 0xF2A5DDC3_u32, 0xC7A3E437_u32, 0x8AD81A2E_u32, 0x5581AD24_u32, 0x98D28B13_u32, 0xC980C103_u32, 0x22D5D1DA_u32, 0x222AAA11_u32, 0x4FC1A506_u32, 0x603E8F50_u32, 0x40248F88_u32, 0x1F29DC3D_u32,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128PP_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x0981A445_u32, 0xD774CE42_u32, 0x9603B0F8_u32, 0xB8B4D3DA_u32, 0xF294B38D_u32, 0x3CA766E2_u32, 0xABD4F8F8_u32, 0x244F4999_u32, 0x164BD4B0_u32, 0x4DDDA30E_u32, 0x9802AEE9_u32, 0xA74AF713_u32, 0xA13B85AC_u32, 0x11CEE647_u32, 0xA1248852_u32, 0xD1250D37_u32,
+        0xAE6D939B_u32, 0x88E13898_u32, 0xE11154C4_u32, 0x10B395FE_u32, 0x72FB4FC2_u32, 0x443A49F8_u32, 0x19FEAFA0_u32, 0x630EC966_u32, 0xA0E7168E_u32, 0x04668D63_u32, 0xE366D63D_u32, 0x66C289B2_u32, 0x8C7DF50F_u32, 0x20092839_u32, 0x2B93E113_u32, 0xC3967867_u32,
+        0xFFAC8291_u32, 0x4A6A7814_u32, 0x1EA178B5_u32, 0x7A2BBC59_u32, 0xA2F21A1B_u32, 0x0D9F2093_u32, 0x030F9202_u32, 0x460D8696_u32, 0xBB525159_u32, 0x3718ADF2_u32, 0x64283910_u32, 0xC9176CB1_u32, 0x20F16615_u32, 0xCD447578_u32, 0xE54432FD_u32, 0xB500801F_u32,
+        0x792D1CC2_u32, 0x41F3C121_u32, 0xF82C95A0_u32, 0x35584FB1_u32, 0xB2141F64_u32, 0x339630E4_u32, 0xE694F86E_u32, 0xA0FAF2FD_u32, 0xE48ED2A3_u32, 0x0890F148_u32, 0x73E4B525_u32, 0x6C35B09C_u32, 0x0876FA21_u32, 0x73D3188D_u32, 0xEFD518D6_u32, 0xEA57A744_u32,
+        0xEAF5F2BF_u32, 0x4AFE3D96_u32, 0x0583012A_u32, 0xE604BD45_u32, 0xF9FEA9EE_u32, 0x087F5380_u32, 0xE90AC31F_u32, 0x00160548_u32, 0xF3F40F7D_u32, 0x4A84DF0E_u32, 0x69A4D1DB_u32, 0x203C556A_u32, 0x03AF996E_u32, 0x2240D6AB_u32, 0xD299C310_u32, 0xAC93E9A4_u32,
+        0x572BD0AD_u32, 0xB0B27F73_u32, 0xBDAF0CB1_u32, 0xD7740A83_u32, 0x3383CAC3_u32, 0x726139F2_u32, 0xF9581AC4_u32, 0x12AF97CA_u32, 0x95126DDC_u32, 0xB1F10050_u32, 0xB9C57165_u32, 0x43FDF26D_u32, 0x0F6061F2_u32, 0xB653C19E_u32, 0x5D674EAA_u32, 0xD51FD750_u32,
+        0xC8078E38_u32, 0x26BB8A22_u32, 0xC21EB8B4_u32, 0x4D3E1CC4_u32, 0xF69FBD78_u32, 0x86737A8B_u32, 0x38DBE35C_u32, 0xE533E48C_u32, 0x0F5300AF_u32, 0x9E9E73E2_u32, 0x4EDC44E7_u32, 0xEC92131B_u32, 0x0E799A0B_u32, 0x193717BC_u32, 0xEB1BDC6F_u32, 0x71F8FC4C_u32,
+        0x4FEBA315_u32, 0xF6DDC7CA_u32, 0xAA2E9B21_u32, 0x93E76149_u32, 0xA87C6257_u32, 0x353C00EC_u32, 0x1D9CDCDB_u32, 0xFBBAFDE2_u32, 0x80782C7A_u32, 0x14C0347C_u32, 0xEB7C0895_u32, 0x005488CF_u32, 0xC9FC567F_u32, 0xF9AE1505_u32, 0xC93CA7D3_u32, 0x8A1DF1B5_u32,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128PP_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0x090A25E4_u32, 0x4A09B71D_u32, 0x03EEC6C0_u32, 0x9F657475_u32, 0x34534EA4_u32, 0x5F1413D8_u32, 0x87DA6442_u32, 0x872BCB70_u32, 0x058F73C5_u32, 0xD3FD738E_u32, 0x48AEC1B8_u32, 0xE0092A7E_u32, 0x7030632A_u32, 0x623E3195_u32, 0x174C9BFD_u32, 0xC52961C1_u32,
+        0x06CCC40C_u32, 0x7E6E5353_u32, 0xD757FEF3_u32, 0xDB0F0BD8_u32, 0xF22ABFA6_u32, 0xAB51235B_u32, 0xDEB26239_u32, 0x15401FB7_u32, 0x59292BEB_u32, 0xBADE117B_u32, 0x780D1BC1_u32, 0x375347E1_u32, 0x4413E4CD_u32, 0xB1A50AA3_u32, 0x05122AB3_u32, 0xDBCF1082_u32,
+        0x57B6EFAD_u32, 0x5C2851C8_u32, 0xFF3AF663_u32, 0x9D32CD5E_u32, 0x0BFA99CD_u32, 0x01EAFB07_u32, 0xE287297D_u32, 0x7E642E96_u32, 0xBD72FC2A_u32, 0xD673818C_u32, 0x3D1333A9_u32, 0xDEB6EED7_u32, 0x20D54E4A_u32, 0xF58209F7_u32, 0x8DA29A74_u32, 0xFC84024A_u32,
+        0x456FE26E_u32, 0xC54A2140_u32, 0x709BB020_u32, 0x25D5C018_u32, 0x8CD902CC_u32, 0x22CEC113_u32, 0xFC468038_u32, 0x212EB002_u32, 0xB46EC9E7_u32, 0x99C0132F_u32, 0x8725E9EC_u32, 0x74FDF01E_u32, 0xBDA1032B_u32, 0x8D163DFC_u32, 0x24B79710_u32, 0xB6CFCA62_u32,
+        0x07368A86_u32, 0xA28C591C_u32, 0xA05F68E6_u32, 0x0FBF5E31_u32, 0x6E0E4F5B_u32, 0x67E51ACA_u32, 0x554518C9_u32, 0x62F3117A_u32, 0xA2911411_u32, 0x78DA1A7E_u32, 0x2E65CA1B_u32, 0x5AF612A3_u32, 0x29681021_u32, 0xBC51C239_u32, 0x1FF5C547_u32, 0xF9F604AC_u32,
+        0xE5E8011D_u32, 0x580C8B70_u32, 0x523248BB_u32, 0x4ADF3B75_u32, 0x163B574D_u32, 0xC775188D_u32, 0x2D676B30_u32, 0x7506B51B_u32, 0x4C728731_u32, 0x1C711073_u32, 0x147A2906_u32, 0xD3E1A0A6_u32, 0x8477BBA7_u32, 0x59F0AE31_u32, 0xD43CAD63_u32, 0xE00E7642_u32,
+        0x034C1273_u32, 0x77E429E6_u32, 0x01069B6B_u32, 0xA77DE436_u32, 0xE77C4A33_u32, 0x7CFF625A_u32, 0x6BD3D9A8_u32, 0x77B7EAF6_u32, 0x8FB37E0B_u32, 0x4014A019_u32, 0x5A2FD539_u32, 0xF0C3FAFD_u32, 0x764A4C1C_u32, 0xB498CF30_u32, 0xD5553FCD_u32, 0x547D5841_u32,
+        0x357E9771_u32, 0x9EB0E509_u32, 0x6031638E_u32, 0xBF86B55B_u32, 0x919B805F_u32, 0x4B4B5598_u32, 0x20DC493B_u32, 0x4FF96366_u32, 0x2BAF2FB5_u32, 0x600EE9E7_u32, 0x5B5B0046_u32, 0x8F617992_u32, 0x645EB530_u32, 0xCC0439CA_u32, 0xB3F330B4_u32, 0x6289B05F_u32,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128SS_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x00000000_u32,
+    table: [
+        0xA6D02A19_u32, 0x5C2FF8B8_u32, 0x34B39EDC_u32, 0x17CA36F6_u32, 0x72B38873_u32, 0x55226302_u32, 0x3186A3A5_u32, 0x49A1D757_u32, 0xFE0122A1_u32, 0x549F02C9_u32, 0x1A853A9D_u32, 0x62E755CA_u32, 0x4FC72FB2_u32, 0x210CE6CA_u32, 0x5264F990_u32, 0xF9AB8C55_u32,
+        0x79C02AF8_u32, 0x0DAC6A87_u32, 0x51AF0628_u32, 0x1C1A6719_u32, 0xAA698D70_u32, 0x1DAC759E_u32, 0x13FE0505_u32, 0xE45452EC_u32, 0x3394AE7E_u32, 0x4CA81E1A_u32, 0x183A99CE_u32, 0xA9165036_u32, 0xCC5BCB48_u32, 0xA598D848_u32, 0xF220406E_u32, 0x8E402632_u32,
+        0xB415894C_u32, 0x19CE9D94_u32, 0x56DC72A4_u32, 0x1E2E28EC_u32, 0x5F5D4F0D_u32, 0x6094CE4D_u32, 0x5A822280_u32, 0x6926CB31_u32, 0xDA857051_u32, 0x71D6149A_u32, 0x15FC74A9_u32, 0x6E27BD1B_u32, 0xB8D20587_u32, 0x91E077B7_u32, 0xBBEB6DAC_u32, 0x4CADA05C_u32,
+        0x5C2B5767_u32, 0xC7C19756_u32, 0x322D3F48_u32, 0xC1F1B8FC_u32, 0xAEE7347E_u32, 0x6ED5A74B_u32, 0x54773711_u32, 0x0EA84D72_u32, 0x622E80B0_u32, 0x8C1F0340_u32, 0xC081087F_u32, 0x0B2A0D9B_u32, 0xBDC3BB8A_u32, 0x24D1C7C1_u32, 0x320D7E8D_u32, 0x7D16A6CE_u32,
+        0xC664102B_u32, 0x695EC146_u32, 0x9351C407_u32, 0xA7880640_u32, 0xC7541496_u32, 0xA029FE49_u32, 0x2F7923B0_u32, 0x2B520C25_u32, 0x562C60EE_u32, 0xF951E69F_u32, 0x200CAE3D_u32, 0x68F304BB_u32, 0x2399F3B1_u32, 0x1487694E_u32, 0xDC18C0A9_u32, 0xE5C22E70_u32,
+        0x1BEDE43B_u32, 0xA0724AB3_u32, 0xADA9D67A_u32, 0xFC698A4A_u32, 0xB58AD13E_u32, 0xF499E029_u32, 0x3631943A_u32, 0x500D92AA_u32, 0xFFDA5373_u32, 0x243284BA_u32, 0x3C7066B5_u32, 0xF62FB5FA_u32, 0xE0A3FCC7_u32, 0xDEA6A95B_u32, 0x5A6BEAB9_u32, 0x83D81EA6_u32,
+        0xB7A73BBE_u32, 0xFB3A37C1_u32, 0xA04BC142_u32, 0xBD7BAADF_u32, 0xDBAA2B50_u32, 0x72E911FB_u32, 0xAD205E30_u32, 0xA8D2A31D_u32, 0xF427F33C_u32, 0x00DED72A_u32, 0x230F05C0_u32, 0x8C1B2CA1_u32, 0x3E580F37_u32, 0x5DAB8E65_u32, 0x94C49ED1_u32, 0x370D7709_u32,
+        0x41888047_u32, 0x3070C147_u32, 0xD48033E8_u32, 0x4F745BA3_u32, 0x94A7C18C_u32, 0x6D97A04C_u32, 0x1A89DF93_u32, 0xCCC61E2E_u32, 0x57306530_u32, 0xDEAE7909_u32, 0x786EF912_u32, 0xB98BCB56_u32, 0x2FF09DB9_u32, 0x58D9E917_u32, 0xFB50734C_u32, 0x551E023E_u32,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128SS_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0xFAEDE0F6_u32, 0xD8BADE12_u32, 0x3F307E58_u32, 0x519A0B12_u32, 0xA164F493_u32, 0x00B509F9_u32, 0xE499C930_u32, 0x0A84A084_u32, 0x86027724_u32, 0x48B9103B_u32, 0x47F47D6E_u32, 0xDA6C6ED0_u32, 0xC03A7A49_u32, 0x2B65BF39_u32, 0x6ED67320_u32, 0xC7B4FA00_u32,
+        0xC3E5DB29_u32, 0x7B329B7E_u32, 0xC62DE24A_u32, 0x4C69BCA8_u32, 0x189E1DB4_u32, 0x98A84BB2_u32, 0xE73A3283_u32, 0x4571E3DB_u32, 0x4B588198_u32, 0x607EC418_u32, 0x7F5D9E76_u32, 0xCBA132C2_u32, 0xED2A56E2_u32, 0x7954C852_u32, 0x6B979AC9_u32, 0xE68F3821_u32,
+        0x9CEE23BF_u32, 0xD683DEC3_u32, 0x8D9953BA_u32, 0x742BBF9A_u32, 0x42D1FF2C_u32, 0xA389DFA9_u32, 0xC129656F_u32, 0xB7C25A20_u32, 0x422C189B_u32, 0xB10636C1_u32, 0x2888A843_u32, 0x7500325C_u32, 0x443BC1A6_u32, 0xC65D5568_u32, 0x18346FD9_u32, 0x5A9B622C_u32,
+        0xC2FADC8A_u32, 0x0FAE93A4_u32, 0xB1749127_u32, 0x39EF2942_u32, 0x528A2722_u32, 0x06DB767E_u32, 0x71082EB4_u32, 0x14BEE40D_u32, 0x5199658A_u32, 0x79F76A82_u32, 0x31140C68_u32, 0x0E251DF5_u32, 0x5B77D61F_u32, 0xD6CAE95B_u32, 0xFB27ED95_u32, 0xE4548F36_u32,
+        0x4C512D40_u32, 0x060131AE_u32, 0xEEB179D0_u32, 0x8FF74F66_u32, 0x3F181CF3_u32, 0x36B4B330_u32, 0xE65931E9_u32, 0x3B756F1A_u32, 0xC0A37771_u32, 0xA1E0E029_u32, 0x23758992_u32, 0x7D3539A0_u32, 0xB45B3521_u32, 0x8FD1C969_u32, 0x796E90A4_u32, 0x9B16C89A_u32,
+        0x3F60A941_u32, 0x70E67F4B_u32, 0x9DB66959_u32, 0x6AD3C3C8_u32, 0xEFB7FEA4_u32, 0xBB7843E6_u32, 0x091A27FA_u32, 0x54FCC7B0_u32, 0x7BFF8842_u32, 0x9210E269_u32, 0x1DC36F30_u32, 0x61E614AC_u32, 0x08B0A363_u32, 0xEC9FE51C_u32, 0x4AC25DA7_u32, 0x0C117660_u32,
+        0xC6FF08BE_u32, 0x6A9D2306_u32, 0xB358D6EC_u32, 0xD1F07EE8_u32, 0x26FBE691_u32, 0x37EB2BA3_u32, 0x41000AC7_u32, 0xA86C9773_u32, 0x029FB659_u32, 0xEDFECC44_u32, 0xB323596D_u32, 0x7F346FD2_u32, 0x0871C226_u32, 0x8AD9534D_u32, 0x3FF8BB6D_u32, 0xCB4099C6_u32,
+        0xC74A0258_u32, 0xDDE26034_u32, 0x6454564F_u32, 0x7D90B9FE_u32, 0x77FA9B9A_u32, 0x90C1A609_u32, 0x904B968D_u32, 0x12A52CB8_u32, 0xB85E4AF8_u32, 0x246D58FC_u32, 0x21247657_u32, 0x3B9E549A_u32, 0xC34E76CE_u32, 0xEE369123_u32, 0xE0A8FFDD_u32, 0x6C5434F8_u32,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO128SS_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0x7D1A4C2C_u32, 0xC25773E2_u32, 0xF9A5911B_u32, 0xC1CDC739_u32, 0x9722C934_u32, 0xB55E98B8_u32, 0x97501BE9_u32, 0xEA087593_u32, 0xE0C34950_u32, 0xCA58B823_u32, 0xCFD37F74_u32, 0x204FB489_u32, 0x89E7EBE6_u32, 0x953EC70D_u32, 0xAAB0058D_u32, 0x19276B0F_u32,
+        0xC8442E42_u32, 0x50DF3C16_u32, 0x05CBFCDE_u32, 0x320C012B_u32, 0x41D5F0C1_u32, 0x26B33E9D_u32, 0x13246CE8_u32, 0xACE7D8E6_u32, 0x8DA2D09C_u32, 0x1D9849B5_u32, 0x7AE9C3BD_u32, 0x296CC677_u32, 0xA18C1437_u32, 0xBCF58324_u32, 0x26BDEAE2_u32, 0xEF8F89A7_u32,
+        0x2641B0B1_u32, 0x17822C51_u32, 0x39A38058_u32, 0x79B5A38D_u32, 0xC068770A_u32, 0xF3C9A8AA_u32, 0x4F5314DF_u32, 0x4BF54006_u32, 0xBB02BF34_u32, 0x15AD9EB2_u32, 0x5B8BB94C_u32, 0x7E977738_u32, 0xF9A61B83_u32, 0xEA19626D_u32, 0xAA6956B4_u32, 0xA47EF6CA_u32,
+        0x4C267702_u32, 0x00FF19F4_u32, 0x0DF2F4CC_u32, 0xA9B8A416_u32, 0xC530E8AA_u32, 0xA7EBFDDC_u32, 0x088E9F41_u32, 0xD3C61EBC_u32, 0xD72F576D_u32, 0x307613C0_u32, 0xCE0C0189_u32, 0x992171F5_u32, 0xCC6F4F2E_u32, 0xB0D33C7A_u32, 0x9363ED28_u32, 0x6C7BEC6C_u32,
+        0x029E14AD_u32, 0x0BDC39C0_u32, 0x9753BFB7_u32, 0xC3F686D2_u32, 0x582CCB89_u32, 0x0E69EC24_u32, 0x7CF345A7_u32, 0x17F67058_u32, 0x0FEA2668_u32, 0xF45C997E_u32, 0x67A18281_u32, 0x60E61396_u32, 0x1CE57947_u32, 0x702D556E_u32, 0x74D4AC5F_u32, 0x42F2BE54_u32,
+        0x9BA891F7_u32, 0x3FB36F54_u32, 0xC45F2DCF_u32, 0x6C833D0A_u32, 0x98BFF004_u32, 0x1CF1D19D_u32, 0x339D2E48_u32, 0x27876662_u32, 0x962DBA0D_u32, 0xCE03A7B4_u32, 0xE6A34436_u32, 0x33145FFA_u32, 0x30590064_u32, 0x6F5348D6_u32, 0x3E62E1F6_u32, 0x3C40A23D_u32,
+        0x5E39279A_u32, 0xD8656446_u32, 0x99222B0E_u32, 0x7053B2EA_u32, 0x78A95088_u32, 0x4269B3A5_u32, 0x43262E6E_u32, 0xEE49921A_u32, 0xA1F5D88C_u32, 0x457FD727_u32, 0x55EB1383_u32, 0xACEEE27E_u32, 0x7F584E8F_u32, 0x4E65A30C_u32, 0x954D0A6A_u32, 0x36C4D579_u32,
+        0x4CEC023E_u32, 0x634FE907_u32, 0xD9CB059C_u32, 0xED41F537_u32, 0x1159C71B_u32, 0x99546ACC_u32, 0x41287A1C_u32, 0x95E8436E_u32, 0x67737D1C_u32, 0xBE0D2BBD_u32, 0x5A02A16D_u32, 0x08D276B9_u32, 0xF58A84F6_u32, 0xA6D3ED9D_u32, 0xA934E924_u32, 0xEDAA7E9E_u32,
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO128P_64: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x674C67D6_u32, 0xD73F9884_u32), (0x27630AB5_u32, 0x926761F2_u32), (0x5492F86D_u32, 0x00FC1B0E_u32), (0xAC446F1F_u32, 0x4A4D2078_u32), (0xC3B445CE_u32, 0x48B8E8FD_u32), (0x23622F2A_u32, 0x9A8E7728_u32), (0x7733D5A9_u32, 0xF1A5DD28_u32), (0x835D0CFE_u32, 0xD110CA19_u32),
+        (0xEA81AC7B_u32, 0x246CB6AD_u32), (0x6AB97485_u32, 0x8A377B08_u32), (0x8D748A18_u32, 0x065BE04C_u32), (0x34880965_u32, 0x6F9D4F7D_u32), (0x77B9ED63_u32, 0x7C3553EC_u32), (0xD71A701C_u32, 0xD9260488_u32), (0x5C649979_u32, 0x8472EC65_u32), (0x71A5372C_u32, 0x55EBF774_u32),
+        (0xCE8AE365_u32, 0x5F4F91E6_u32), (0x8AC29935_u32, 0xA6324099_u32), (0xDF16A606_u32, 0xAAA07A17_u32), (0x63B9BD5B_u32, 0x3804EFC5_u32), (0x99FF601D_u32, 0xBFAEA312_u32), (0x7F6C7799_u32, 0xC8232CB1_u32), (0x951E4818_u32, 0xF97409D0_u32), (0x3AE4A216_u32, 0xCE5E40D4_u32),
+        (0x247AB0EB_u32, 0x3502EDB2_u32), (0x9B061DAB_u32, 0x2374D811_u32), (0x6D93B8D3_u32, 0x8D217732_u32), (0xDF482BE8_u32, 0x9EB84ADF_u32), (0xEF49C83F_u32, 0xCE457A2B_u32), (0xCDE19E32_u32, 0x19D7441D_u32), (0x6C7129F2_u32, 0x04A10138_u32), (0x743B3065_u32, 0x3A9B70FF_u32),
+        (0x9055CEB5_u32, 0x7397D8BB_u32), (0x2F32CEB0_u32, 0xD0E1DBBF_u32), (0x5001E457_u32, 0xDA6BBC52_u32), (0xCC7F67B3_u32, 0xD4BB3502_u32), (0x53BC74A8_u32, 0x8441CBE0_u32), (0xA6FA5A8F_u32, 0x85A83778_u32), (0x435215EB_u32, 0xD38D4B31_u32), (0xB94C83DF_u32, 0x256DF1AC_u32),
+        (0x5CE9DB5A_u32, 0xB6550856_u32), (0xB9A8FF06_u32, 0xC23F6CF2_u32), (0x4E0AFE7B_u32, 0x1D8F0CFE_u32), (0x28B68D4F_u32, 0x1EEF043F_u32), (0x8BB52A82_u32, 0xB56B0E9B_u32), (0x11567E33_u32, 0x48E650CE_u32), (0xD440E557_u32, 0x269C5B8D_u32), (0x73029BC2_u32, 0x8AF8E2C7_u32),
+        (0x9F076CF6_u32, 0xB40BE2E5_u32), (0x5B2BFB8B_u32, 0xD8F2EDA6_u32), (0x17B7E3F5_u32, 0xF86AF915_u32), (0x003020EE_u32, 0xCD069716_u32), (0x134C0193_u32, 0xF680252F_u32), (0x77D95C07_u32, 0xD3E55668_u32), (0x6FE375F3_u32, 0x2D1C2CF9_u32), (0xEB397AA6_u32, 0x2E7D5268_u32),
+        (0x5777A299_u32, 0x793AF68F_u32), (0xEC204B18_u32, 0xE92772B8_u32), (0xCD58F875_u32, 0x739B27A1_u32), (0x910C1905_u32, 0x6BD08299_u32), (0x1611B0D3_u32, 0xB20C4D47_u32), (0x079B3842_u32, 0x9E4A3DC3_u32), (0x6E60291C_u32, 0x6288B735_u32), (0x26532F25_u32, 0x80C9EB93_u32),
+        (0x3263CA63_u32, 0x55DEF0D6_u32), (0x351FE814_u32, 0xE80AA552_u32), (0xE2035E5F_u32, 0xF3ED83B0_u32), (0x388209A3_u32, 0x241C2A9C_u32), (0x3900AA6F_u32, 0xBE2351CF_u32), (0x9ABFDCEC_u32, 0x758CAB37_u32), (0xA550354D_u32, 0xF31C7728_u32), (0x209916CD_u32, 0x95AECA20_u32),
+        (0x98C785A9_u32, 0x8CEEAC3F_u32), (0x8449321E_u32, 0xFDFF3028_u32), (0x34909E16_u32, 0x0D90DC41_u32), (0xC9BD08C5_u32, 0xCDA0BEA9_u32), (0x0E868237_u32, 0x27AD0778_u32), (0x649E3790_u32, 0xD65F5C55_u32), (0x5D5ED2D6_u32, 0x84E97083_u32), (0x03D511B0_u32, 0x8695DC1D_u32),
+        (0xEA5F2026_u32, 0xE9DD8484_u32), (0x841A3022_u32, 0xAD506C33_u32), (0x84F2712A_u32, 0x4AD061A8_u32), (0x898617E3_u32, 0x85BCCEEA_u32), (0xD3C06D93_u32, 0x1E483A29_u32), (0x2A121542_u32, 0xE0C211A3_u32), (0x238035A8_u32, 0xDE7107E2_u32), (0x30F9534F_u32, 0x5C765332_u32),
+        (0xE2B1F0E8_u32, 0x457F4F1F_u32), (0x2FD609BB_u32, 0x565D64DB_u32), (0xFB966912_u32, 0x421EF9B9_u32), (0xCB428741_u32, 0x4A3D3C9A_u32), (0x896DF4D4_u32, 0x93184200_u32), (0xBDAEFC8A_u32, 0xDA397A26_u32), (0x24B5EE17_u32, 0x9E66C5C4_u32), (0x7B738460_u32, 0x7F867E19_u32),
+        (0x97F8BDC5_u32, 0x9DBAB865_u32), (0xF63EFEE4_u32, 0x89C55A24_u32), (0x68A35B58_u32, 0x3330675E_u32), (0x96BC1AB0_u32, 0x5CFDBDA6_u32), (0x97B33BFE_u32, 0x64BE86A0_u32), (0xBDBC203A_u32, 0x7D29CFCD_u32), (0x09066FC7_u32, 0x7F953B69_u32), (0xB2D9594A_u32, 0x2019944F_u32),
+        (0x86557D54_u32, 0xEC392881_u32), (0xD288FB9C_u32, 0xDD2523A2_u32), (0x8E83F1B5_u32, 0xD3412CDF_u32), (0xC9461835_u32, 0xF465BA3D_u32), (0x961A1B51_u32, 0xD897BF3B_u32), (0x5EDADE65_u32, 0xADC0A015_u32), (0x5D11A0AF_u32, 0xBC260F90_u32), (0xB741E2B3_u32, 0x1F5875EF_u32),
+        (0xEF78F7EA_u32, 0x883FB0D9_u32), (0xC92314AD_u32, 0xD5DCB53B_u32), (0x22D06936_u32, 0x78A5BBC5_u32), (0x6770C5D6_u32, 0x1E2C6756_u32), (0x8DC065C9_u32, 0xAB8BC3C2_u32), (0x583DD977_u32, 0x623A475F_u32), (0x17464BB5_u32, 0xEFF997BC_u32), (0x810D1864_u32, 0x8415DFF9_u32),
+        (0x63048362_u32, 0x98E19C95_u32), (0xF666C2E9_u32, 0x1A559D6B_u32), (0x9A658A38_u32, 0xC07331E5_u32), (0x5F4B3F8C_u32, 0x812E4FB5_u32), (0x61AA1D47_u32, 0x6A32BA78_u32), (0xB1BDB70D_u32, 0xD8E63EE5_u32), (0x1180BA77_u32, 0x95511BA8_u32), (0x6C5F028D_u32, 0xFE53231A_u32),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO128P_96: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0xA6726394_u32, 0x948BEF8A_u32), (0x6E027089_u32, 0xF56F72FB_u32), (0x38FE0E55_u32, 0xFE728978_u32), (0x4DC28898_u32, 0x6545BFE4_u32), (0xF6850E5A_u32, 0x58AFD4AC_u32), (0xD96BD79D_u32, 0x6AA7C707_u32), (0x2875084C_u32, 0x2ED84585_u32), (0x75FB527F_u32, 0xF86EC747_u32),
+        (0x24F27C95_u32, 0xE359180A_u32), (0x366B8F34_u32, 0xF812357B_u32), (0x9450BE68_u32, 0xE6ABD12A_u32), (0x9A778597_u32, 0xCDC4CB33_u32), (0x46B3CE55_u32, 0xBC969DDC_u32), (0x605A3996_u32, 0x1D152192_u32), (0xA4A1BBBD_u32, 0xE552ED9F_u32), (0x6F8DE33F_u32, 0x3B3AE316_u32),
+        (0xA999598B_u32, 0x10CC71F6_u32), (0xC7234BC3_u32, 0x17E507DB_u32), (0x06F9A399_u32, 0x4552BEBB_u32), (0xAB38BC5F_u32, 0x3F0C2C0B_u32), (0xC548B263_u32, 0x2899133A_u32), (0x428EEDC7_u32, 0x0E3BB942_u32), (0x10256BE8_u32, 0x8DE62A95_u32), (0x05EB30C7_u32, 0xD68A8966_u32),
+        (0x4F0AC71D_u32, 0x367DFD64_u32), (0xE22E8150_u32, 0x6893C65B_u32), (0xDD72641F_u32, 0xD32173CE_u32), (0xEA1782D0_u32, 0xFB3C240C_u32), (0x318CFF0C_u32, 0x1F1A47BB_u32), (0x95E4B724_u32, 0x4826F1CD_u32), (0x6BDE6EC5_u32, 0x225E9BA4_u32), (0x401ED3A1_u32, 0x7490C7BD_u32),
+        (0xF72831A4_u32, 0x46D60F8D_u32), (0x6EEE91AD_u32, 0x18C65B5E_u32), (0x85EC0228_u32, 0xD229B626_u32), (0xEF66141F_u32, 0x188FA30A_u32), (0x5D640D19_u32, 0x6B7BA81C_u32), (0xF443B345_u32, 0x9A338FC0_u32), (0xDC8F4909_u32, 0xBD864CD1_u32), (0xF5E4C725_u32, 0xA04B9C1E_u32),
+        (0x109D80C5_u32, 0x93DD2525_u32), (0x8A385670_u32, 0x4B38B384_u32), (0x1797ED68_u32, 0x22C4F8CF_u32), (0xF02C6B20_u32, 0xCEBF90F2_u32), (0x59F5D5E0_u32, 0xCB5D8072_u32), (0x333D1B8C_u32, 0x0CCAC8B8_u32), (0xA96A71EC_u32, 0x3BCA9D15_u32), (0xD9C71ACC_u32, 0xBE44AC70_u32),
+        (0xD4CE49F1_u32, 0x9F1B0225_u32), (0x07E42D9E_u32, 0x6B5B324D_u32), (0xD48A8582_u32, 0xAEE9FD39_u32), (0xAFBE15D3_u32, 0x070DD1F2_u32), (0x3C743219_u32, 0x5E51945C_u32), (0x4CE42DD6_u32, 0x0451AC8B_u32), (0xF9902DF6_u32, 0x50864690_u32), (0x29A4941D_u32, 0x408234BD_u32),
+        (0x34C3058F_u32, 0xA421E705_u32), (0x600B11EE_u32, 0xF64BEEB9_u32), (0x9F20006E_u32, 0x71BF8F0E_u32), (0xCBB32132_u32, 0x9D94ED39_u32), (0x957E7F32_u32, 0x62D7D3C4_u32), (0x37827CA6_u32, 0x58C5EC52_u32), (0x3A8CF026_u32, 0xDD525D96_u32), (0x91AE1DC8_u32, 0x315BA4AB_u32),
+        (0xF7FCBCDB_u32, 0xC62352C7_u32), (0x06C8D23C_u32, 0x302977FC_u32), (0xEAF235DA_u32, 0xF8F3B51B_u32), (0xF2656376_u32, 0x4BE2663A_u32), (0x8FE3DC06_u32, 0x1280F624_u32), (0x4D1D0D94_u32, 0xBD309C6C_u32), (0xE4C9DE5E_u32, 0xD0968C10_u32), (0x6ACDF5E1_u32, 0xC866A967_u32),
+        (0x1853D90B_u32, 0x3FACFA7B_u32), (0xD9C73066_u32, 0x09D3FAB6_u32), (0x746BA4E3_u32, 0xE052954C_u32), (0x4F80C077_u32, 0x22C1ACEA_u32), (0x86C8B158_u32, 0x0D0A2D13_u32), (0xC346ECEE_u32, 0xAD3A9458_u32), (0x2232BC71_u32, 0x289830FE_u32), (0xA1C568BD_u32, 0xFCF96028_u32),
+        (0x1E6FDE66_u32, 0x6636E3CD_u32), (0x2522F7E3_u32, 0xF342C6B1_u32), (0x93FB2BE8_u32, 0xE83B5E64_u32), (0xCCF17B6D_u32, 0x4E0063D1_u32), (0x2A9A79FF_u32, 0x4187681F_u32), (0x91E20105_u32, 0x3359559C_u32), (0x2DA04113_u32, 0x17F7C36D_u32), (0x95B2673D_u32, 0xE22E7519_u32),
+        (0xEAC5447C_u32, 0xABAA5579_u32), (0x0F9E9C34_u32, 0xAEC6BF3B_u32), (0x42191D6B_u32, 0x6C9BC7D8_u32), (0x08CEAB12_u32, 0x7FE09E12_u32), (0x9CD4823F_u32, 0xF5743C10_u32), (0x89CE0E8D_u32, 0x33B8E80E_u32), (0x22989ED5_u32, 0xCB0FF281_u32), (0x9AED87BE_u32, 0x0BBD0837_u32),
+        (0x4CE87035_u32, 0x416D536B_u32), (0x2C259E56_u32, 0xAD090DAA_u32), (0x77A96BA2_u32, 0xAF2F23DF_u32), (0x81D57F5D_u32, 0xAD0C3605_u32), (0x72247058_u32, 0xE88F5FBA_u32), (0x999276AC_u32, 0xF34C82EF_u32), (0x8FF7977C_u32, 0xCB5C7A8D_u32), (0x3AC69945_u32, 0xEDE97DD3_u32),
+        (0xBDD7C0CE_u32, 0xE6993D61_u32), (0xBADBCF91_u32, 0x4F245940_u32), (0x1DA65D3C_u32, 0xED9E55BC_u32), (0x464643EF_u32, 0x0E4F402A_u32), (0x904008CA_u32, 0xD89A6170_u32), (0x97701DBF_u32, 0xB6CB58FB_u32), (0xC53B143D_u32, 0xFD0897DE_u32), (0xAED40A1B_u32, 0xDB247919_u32),
+        (0x94008CD0_u32, 0x038B27D0_u32), (0x02463E7B_u32, 0x0690B074_u32), (0x47E94DF9_u32, 0x17853C9B_u32), (0xF55A8DAD_u32, 0x2E3104FF_u32), (0xDB4B0530_u32, 0x0D8B6C16_u32), (0x9EA2AEB8_u32, 0x8AA04E03_u32), (0x4CD7904B_u32, 0x9A5CA89C_u32), (0xAF9F5D4A_u32, 0x7C69C7B8_u32),
+        (0x7FEF35AE_u32, 0x9177CC99_u32), (0xE7026603_u32, 0x4FDBB859_u32), (0x2E459528_u32, 0x3F2C491C_u32), (0x6306BCB3_u32, 0x24727594_u32), (0xBF57CA35_u32, 0x7EEE11CA_u32), (0xA047DEE3_u32, 0x3937A3FE_u32), (0x346389C3_u32, 0x38525FF3_u32), (0x4ADDDC77_u32, 0xCF057EC9_u32),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO128PP_64: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0xA883A766_u32, 0x2B47FF70_u32), (0xFE252B74_u32, 0x2F6DDD32_u32), (0x997A46B4_u32, 0x0EDE50FA_u32), (0xD4878E18_u32, 0xEBA3ADBC_u32), (0x8A838ABC_u32, 0x6EBEC38C_u32), (0x628E3B7C_u32, 0x431129D3_u32), (0x037C48FE_u32, 0x7BBD79CA_u32), (0x70C05390_u32, 0xF1909078_u32),
+        (0x0CDDE1EA_u32, 0x97360C9C_u32), (0x83B0AB88_u32, 0xDB534325_u32), (0xD23A6DC8_u32, 0x3DACA456_u32), (0xECF92856_u32, 0x2D200BA5_u32), (0xD220218D_u32, 0xA81E5EFA_u32), (0xF855E8C7_u32, 0x45BD261A_u32), (0x3FA4DA72_u32, 0x20723A88_u32), (0xF9912CBC_u32, 0xE64CD482_u32),
+        (0x46EE716D_u32, 0x9CF8E716_u32), (0xAA5B5A26_u32, 0xAD036B44_u32), (0x136F3D0A_u32, 0xB5D092EF_u32), (0x278697EC_u32, 0xD764C76A_u32), (0x6CB6163A_u32, 0xD7593C1D_u32), (0xCC259570_u32, 0x5262EBB8_u32), (0x180F4861_u32, 0xBF628F4F_u32), (0x18C9420C_u32, 0xA52B6BDD_u32),
+        (0xFAF86D1A_u32, 0xF084700A_u32), (0x636CAA59_u32, 0xA60BAEEC_u32), (0xF0892E26_u32, 0x24A8D371_u32), (0x2DE481B6_u32, 0xF4964CC4_u32), (0x803E1A20_u32, 0xA7972A26_u32), (0x344665EB_u32, 0xA62725E4_u32), (0xE1D62018_u32, 0x3FB7EE43_u32), (0x2DFCF37D_u32, 0x0E8708AB_u32),
+        (0xF7F0F398_u32, 0x03B2F43E_u32), (0xE2F04AC8_u32, 0x6395C868_u32), (0xE4C90B3B_u32, 0x6B1897D1_u32), (0xB8ABEB12_u32, 0xC8DB2606_u32), (0x7AFA02E5_u32, 0x71AE3EBF_u32), (0x4D84F59A_u32, 0xF157B738_u32), (0xFCE00DB8_u32, 0x90FE23E4_u32), (0x693980DA_u32, 0x089CEC5F_u32),
+        (0xB6012CE1_u32, 0xCEC7F2CE_u32), (0xB431A60A_u32, 0xE6E963C4_u32), (0xEC1C0943_u32, 0x2A94DDAD_u32), (0x9F632FC8_u32, 0x324E6A98_u32), (0xFE7431F3_u32, 0x90375703_u32), (0x24E61CCB_u32, 0x5530F447_u32), (0x7E495495_u32, 0x31DBE87B_u32), (0x69369E30_u32, 0xBF7CFD4E_u32),
+        (0xF2FFCD57_u32, 0x81DA259F_u32), (0x13A4D233_u32, 0xC75276BD_u32), (0x04A8D77A_u32, 0x91D72150_u32), (0x24C177B5_u32, 0xD4D09F23_u32), (0xF44EC920_u32, 0xA19D17A4_u32), (0x1C430DBF_u32, 0x469B8EB5_u32), (0x67B9F203_u32, 0xFEB4034A_u32), (0xC9010E7E_u32, 0x2AB0AFD8_u32),
+        (0x0B011127_u32, 0x3C0C662D_u32), (0x20C87206_u32, 0x64EB4669_u32), (0x896E5E38_u32, 0x15C4526F_u32), (0x94DA2659_u32, 0xFAA9FBF2_u32), (0x36CCB84E_u32, 0xB549EC5C_u32), (0xBF77D644_u32, 0x3FC27055_u32), (0xEC428EB0_u32, 0x84E1C1F5_u32), (0x0ACF98EF_u32, 0xBE9C2E15_u32),
+        (0x915BFD96_u32, 0x725602FA_u32), (0x81688F0E_u32, 0x7E469F02_u32), (0x5D71F580_u32, 0x5F2F412C_u32), (0x94D62A1F_u32, 0xD9AC2818_u32), (0xAB8EAA47_u32, 0x0A48EF95_u32), (0x95B32428_u32, 0x2F2C03B4_u32), (0xD28FA0CA_u32, 0xDA40EA89_u32), (0x242DF168_u32, 0x06BF1831_u32),
+        (0x80F5683E_u32, 0xA5C4D094_u32), (0xB725E6B3_u32, 0x18EDCA7A_u32), (0xC71047BD_u32, 0xDA2E865C_u32), (0x25142DEB_u32, 0x13670F71_u32), (0x8C1CBA62_u32, 0x4465685A_u32), (0x3AFCA9ED_u32, 0xA97EA965_u32), (0xB851E41F_u32, 0xC754F742_u32), (0xBAACFF51_u32, 0x903C5B20_u32),
+        (0x37AD2630_u32, 0x15AF3824_u32), (0xE0E60DEC_u32, 0x7D599AA1_u32), (0x0E2D8D6A_u32, 0x09DF8C78_u32), (0x4D9A9D29_u32, 0xCE81FD09_u32), (0x575538D3_u32, 0x4E8810DB_u32), (0x10018C42_u32, 0x5E36F400_u32), (0x40C13678_u32, 0xF395F61C_u32), (0x0378B5C6_u32, 0x1FDA7FD1_u32),
+        (0xAB3B960D_u32, 0x1C8E3C3E_u32), (0xAEBAC02D_u32, 0x80AC4F09_u32), (0x8ADF3CD7_u32, 0xA5CE82F7_u32), (0xB95EF9BC_u32, 0xCF3644AD_u32), (0x156E3CEC_u32, 0xD867AB39_u32), (0x0EAFCB90_u32, 0x1086B60D_u32), (0x2110A2D3_u32, 0x16BBEF1F_u32), (0x0F25C5B5_u32, 0xE5003C2E_u32),
+        (0xAADC586B_u32, 0xB2FBD833_u32), (0xBE62039F_u32, 0x386523C4_u32), (0x9ADADC9E_u32, 0xA3E907FB_u32), (0x5B8C12C7_u32, 0xE2A670DC_u32), (0xDC5689A4_u32, 0x3FC68DE0_u32), (0xD0DE2DE2_u32, 0x8332F5FF_u32), (0x0B2849B5_u32, 0xA802B4BF_u32), (0x614745AA_u32, 0xC476755B_u32),
+        (0xACD8818E_u32, 0xBC14D31F_u32), (0x36650B57_u32, 0x49D74AC3_u32), (0x969674A6_u32, 0xD7ABBCDA_u32), (0x34322C24_u32, 0xFDA69360_u32), (0x8098F004_u32, 0x7F871642_u32), (0xA4C22C24_u32, 0xFBB5E100_u32), (0x0208B7AC_u32, 0xB0470C4A_u32), (0xD6573DE1_u32, 0xE0FA6364_u32),
+        (0xB09BD7E3_u32, 0x5F06A289_u32), (0x2BD0856B_u32, 0xFE8314D4_u32), (0x94244575_u32, 0x988FAD98_u32), (0x3B21394F_u32, 0x78BB70FF_u32), (0x113276C4_u32, 0x82C2FC8F_u32), (0xFB47F78F_u32, 0xF9AF0402_u32), (0x3281D356_u32, 0x320FAD55_u32), (0x967EA7E0_u32, 0xD83F405F_u32),
+        (0xB8029E37_u32, 0xBC93BAAE_u32), (0xD8C951B2_u32, 0x4688EC6A_u32), (0x65C5D47B_u32, 0xDBCB9BE3_u32), (0xBCD7049D_u32, 0x4628D668_u32), (0x3917364C_u32, 0xD0BD2E12_u32), (0x925F5052_u32, 0x13F9EC89_u32), (0xB48FBD08_u32, 0x17792B8C_u32), (0x5A0300D4_u32, 0x9D078A46_u32),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO128PP_96: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0xB0614861_u32, 0xC86F3577_u32), (0xF0946C57_u32, 0xE6BED701_u32), (0xACFC9423_u32, 0xB9E43C84_u32), (0x15A12953_u32, 0xCA97374B_u32), (0x936F330C_u32, 0x5DB96FC2_u32), (0x782AD3BB_u32, 0x6991085C_u32), (0x65A94C81_u32, 0x5DE898DE_u32), (0xA6588B7B_u32, 0x0452BFB8_u32),
+        (0x44246F65_u32, 0x04BF98F1_u32), (0x4C0628D7_u32, 0x6265B35F_u32), (0x6BE40EAC_u32, 0x8E2DF094_u32), (0x0A6FC1E8_u32, 0xF82D4BBE_u32), (0x02B45BA7_u32, 0xB817D52B_u32), (0xA65E56B5_u32, 0x78996771_u32), (0x06BB046B_u32, 0x2D66726F_u32), (0xC2ED24D9_u32, 0x2BC2600C_u32),
+        (0xA921AECD_u32, 0x6883C6F6_u32), (0x08417746_u32, 0x1A0CC8A7_u32), (0xB995F450_u32, 0x059785BD_u32), (0x2D1B827D_u32, 0x79E8C25A_u32), (0x56ED7F7E_u32, 0x25E62E33_u32), (0x6EFE8190_u32, 0xA51517F2_u32), (0x67B11CF8_u32, 0x371ABA68_u32), (0x4254A03B_u32, 0xD958031A_u32),
+        (0xCCBDCAFF_u32, 0x79E7CF70_u32), (0xEF07785C_u32, 0xB0A5D478_u32), (0xA27A372E_u32, 0x4C6C66D4_u32), (0x1446F67A_u32, 0x8FF32367_u32), (0x441B4BA2_u32, 0xBC77F6FC_u32), (0xD97DB2A3_u32, 0xB9427747_u32), (0x8BF27493_u32, 0x3C03434B_u32), (0x8ACC45B8_u32, 0x1FA579F1_u32),
+        (0xB17F0BFA_u32, 0x93B93FBB_u32), (0xB4C7F1FE_u32, 0xB14D5306_u32), (0x5C8EC516_u32, 0xA05D5A5D_u32), (0xE1C1C8C4_u32, 0xDEEB6223_u32), (0x20DB6237_u32, 0x7710CB00_u32), (0x3691DCF4_u32, 0x265A4425_u32), (0xD5595D26_u32, 0xD9D49BC0_u32), (0xEE8907C2_u32, 0xE53D1261_u32),
+        (0x9457E09D_u32, 0xD1A81D78_u32), (0xDD52F9B2_u32, 0x6266ADA8_u32), (0xB1B523A1_u32, 0xB1E0828B_u32), (0x00F33F66_u32, 0x7A97BF8C_u32), (0x536348F8_u32, 0x2819D738_u32), (0xF3528F9E_u32, 0x0C864D36_u32), (0xC3106E8A_u32, 0xCBDBFF11_u32), (0x76CFA434_u32, 0xF9837C11_u32),
+        (0x131AD4A0_u32, 0xAA46907E_u32), (0x6351E848_u32, 0xF3F5BB34_u32), (0x783495A6_u32, 0xB1632495_u32), (0xD361B866_u32, 0xDA5D49CA_u32), (0x8FCB3246_u32, 0xD430B495_u32), (0x6D1A4EE2_u32, 0x80B3E18D_u32), (0x7F5E5DD7_u32, 0xB531F3CC_u32), (0x18EA7BCE_u32, 0x54673F8C_u32),
+        (0xE84C55F4_u32, 0x221997F5_u32), (0x6FCF5A6F_u32, 0x63B16FDA_u32), (0xEB62B42B_u32, 0x1B8364DD_u32), (0x78EDB5E9_u32, 0xDC89635C_u32), (0x33D585AC_u32, 0x58A164C9_u32), (0xBC0F73B2_u32, 0x56B8D7CD_u32), (0xF63EEAE8_u32, 0xA13DA192_u32), (0xCF6EB871_u32, 0x388CFEE1_u32),
+        (0x4E837228_u32, 0x3D9EEFD8_u32), (0x56E3578C_u32, 0x1518667D_u32), (0x0C006B78_u32, 0xA6E20903_u32), (0xD45A3A30_u32, 0x7FC2C8FE_u32), (0xAAE63E38_u32, 0x63B95746_u32), (0x6EB7B17B_u32, 0x527A1E42_u32), (0x24835A81_u32, 0x4457A67F_u32), (0x09C34902_u32, 0x9EFE3E7A_u32),
+        (0x5C7FD76F_u32, 0x40DA0E08_u32), (0x54853D5E_u32, 0x4A548AB0_u32), (0xA9DDBC32_u32, 0x96EC673B_u32), (0xEE8C5248_u32, 0x19366B31_u32), (0x43A3A8C2_u32, 0x27242CAC_u32), (0x8FBA0E87_u32, 0x05EEF3BC_u32), (0x7B363C52_u32, 0x88244BE0_u32), (0x2F3C0669_u32, 0x0A967ADD_u32),
+        (0x7813EBE6_u32, 0x45065E19_u32), (0x63F6E639_u32, 0x5966A9A2_u32), (0x028CD8F8_u32, 0x52447936_u32), (0x3630664E_u32, 0x3D7F2FEB_u32), (0xCF5CC3A4_u32, 0x9F541598_u32), (0xB47D85F0_u32, 0x3D7DAB81_u32), (0x9783F0DA_u32, 0x105E7344_u32), (0xD9FF70CC_u32, 0xAD12D32F_u32),
+        (0xB2EE5A4A_u32, 0x4ABB314C_u32), (0x677BEDE8_u32, 0xB94FA526_u32), (0x2F1C7FF6_u32, 0xBC7F6180_u32), (0x9D5BD0DE_u32, 0xEBB8B524_u32), (0xC4D0A419_u32, 0xE7E1BA2F_u32), (0x219E1DEE_u32, 0xF4BB01CC_u32), (0x8D179933_u32, 0xE2B72F5A_u32), (0x0F305596_u32, 0x2DFA3F92_u32),
+        (0xB22ED263_u32, 0xE251BC9E_u32), (0x1D4B5E33_u32, 0xF2E5D9B4_u32), (0xE9EAFE22_u32, 0x2B7790F9_u32), (0x5C98DA75_u32, 0x0DBF7AA7_u32), (0x61AEDAF8_u32, 0x072D6A3E_u32), (0xA778CEDE_u32, 0xC11B27E5_u32), (0x81E235D4_u32, 0xD5DE3038_u32), (0xCFAA1A48_u32, 0xD8FB32B3_u32),
+        (0x042DF089_u32, 0x4861B4A7_u32), (0x762A1AA5_u32, 0x9867B934_u32), (0x6CFFEE2C_u32, 0xA9FCC2B3_u32), (0xA5AFB235_u32, 0x67EF69FC_u32), (0x7F785DD3_u32, 0x8CCE673A_u32), (0xA90A31F1_u32, 0x7FA615EF_u32), (0x0E824193_u32, 0xB7C6EFF4_u32), (0x1E6DECBC_u32, 0x89A4C835_u32),
+        (0x67FC2AE4_u32, 0xC050D1F4_u32), (0x274F9AE4_u32, 0x4E08AC94_u32), (0x981624B9_u32, 0xAB8B61B3_u32), (0x55CE36B9_u32, 0xE647DA1F_u32), (0x4D29A886_u32, 0x3D49529A_u32), (0x5B1AF124_u32, 0x6FD5A9F2_u32), (0x7B8856D1_u32, 0x8AC7DAFD_u32), (0x577916ED_u32, 0x83CBEA60_u32),
+        (0x7CEC0BCE_u32, 0x9B9C868D_u32), (0x712B18EA_u32, 0xEAE25769_u32), (0x1F73E1F8_u32, 0x4B79DF29_u32), (0x0A7362BB_u32, 0x97AB3813_u32), (0x098E14E6_u32, 0xFAC4CBA2_u32), (0x58EFD686_u32, 0xE6FABA40_u32), (0xABA2677C_u32, 0x47AB05CC_u32), (0x1B10A5A7_u32, 0x511042FC_u32),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO128SS_64: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x029F3998_u32, 0xF031D7A8_u32), (0x2C7810D0_u32, 0x66A34D28_u32), (0xC066D0D0_u32, 0x83158741_u32), (0x258EF17A_u32, 0x4C79B861_u32), (0xE567A585_u32, 0xA09FAFDB_u32), (0xC05B0E0E_u32, 0xB37E68A2_u32), (0xD2E2FF6F_u32, 0xF7A1865E_u32), (0xC19056C8_u32, 0x9D121C23_u32),
+        (0x24DF2C14_u32, 0x0EE2D175_u32), (0xBF035E03_u32, 0x7CCF7936_u32), (0x6ED7EC62_u32, 0x263B5ACD_u32), (0x069BF40A_u32, 0x21DDBD84_u32), (0x3D8D2E8C_u32, 0xC0DD91FA_u32), (0xB1BF16A9_u32, 0x313F51B5_u32), (0xA639B5C3_u32, 0x51D032F9_u32), (0x3D3875E7_u32, 0x5588E25E_u32),
+        (0x47CC14B3_u32, 0x3E707983_u32), (0x50889332_u32, 0x6C85FB29_u32), (0x70BF3EDD_u32, 0xD54CC27C_u32), (0x79E7700D_u32, 0x787CC831_u32), (0x3CE024D3_u32, 0x4AC98545_u32), (0x5E7652FE_u32, 0x1CE7B46C_u32), (0x44E8A1DB_u32, 0x3CD1E8F9_u32), (0xBB603E92_u32, 0x582F2E3B_u32),
+        (0x61FF7A1A_u32, 0xAD4FE79D_u32), (0xC8990E2D_u32, 0xE6FB9376_u32), (0x170D2F28_u32, 0xC0616781_u32), (0xAF3507CF_u32, 0x98FDD042_u32), (0x5A46B629_u32, 0x4BA3E470_u32), (0xDC8830F9_u32, 0x2AB200B5_u32), (0x98A720FA_u32, 0x06D9E263_u32), (0x527C2C80_u32, 0x99D1E575_u32),
+        (0x09BC28D3_u32, 0x4B1E3AF8_u32), (0xFB1909A3_u32, 0x69005F3B_u32), (0xDEBAD8B8_u32, 0x192C58F1_u32), (0x5216551C_u32, 0x14B7379F_u32), (0x7E88C85A_u32, 0x6F6E8F49_u32), (0x6DDCC93D_u32, 0x621368EB_u32), (0xE874C314_u32, 0x06B113B7_u32), (0xF3F7DEC4_u32, 0x77170BB9_u32),
+        (0x2C70F07B_u32, 0x7DADF83A_u32), (0xA546F7B1_u32, 0x6F7FAFA0_u32), (0x1793DD6D_u32, 0x24833FC7_u32), (0x2028BBA9_u32, 0xE3CDE8CE_u32), (0x88F360F5_u32, 0x9A3BE514_u32), (0xE0208464_u32, 0xC76DD825_u32), (0x636635E6_u32, 0x318F36A1_u32), (0xC43715FA_u32, 0x7D0EF00F_u32),
+        (0xEDBA4D30_u32, 0xB9B96E19_u32), (0xACB5B358_u32, 0xE309892A_u32), (0x374F74AF_u32, 0x31FF64C5_u32), (0x9FF18EE2_u32, 0xEBCA157A_u32), (0xDA782A02_u32, 0x89223F5A_u32), (0x6C3606B7_u32, 0xD812EC8E_u32), (0x38C1644E_u32, 0x73C57196_u32), (0x97467894_u32, 0xA1B248E8_u32),
+        (0x1F995147_u32, 0x4322DCFE_u32), (0x5DF5D6B3_u32, 0x85863A6D_u32), (0x01B0844A_u32, 0x14AD00F9_u32), (0x90D4A007_u32, 0x722AF649_u32), (0xC9259549_u32, 0xE0510DB1_u32), (0x37D10965_u32, 0x36001B6B_u32), (0x1B9C5E0E_u32, 0x35B67E5D_u32), (0x8310658F_u32, 0xB377A5E5_u32),
+        (0x68C09AAB_u32, 0xDB35A3BC_u32), (0x5ABE938E_u32, 0x2E8957FF_u32), (0x6044055C_u32, 0xD539EDC6_u32), (0x8E6ADBB6_u32, 0x9A862FF5_u32), (0x28B7ADC0_u32, 0x67BADDEA_u32), (0x9F1948D3_u32, 0x71DF1449_u32), (0x2F803D06_u32, 0x4A094BE3_u32), (0x18454B5E_u32, 0xC06DD55D_u32),
+        (0x7C7506B9_u32, 0x4A1BBBF0_u32), (0xB2B7AC5D_u32, 0xF05A676B_u32), (0x49C882A8_u32, 0x742A2315_u32), (0x8FA32F15_u32, 0x72788340_u32), (0xD2EED3E2_u32, 0xFB453C55_u32), (0x7A42FF88_u32, 0xF51A5071_u32), (0xD4320D35_u32, 0xE576F187_u32), (0x4B54C1A7_u32, 0x58D7B6EF_u32),
+        (0x4F5DCDEE_u32, 0x13CC2A3C_u32), (0xA75F941C_u32, 0x9ABF2FE7_u32), (0xEADEF734_u32, 0xCCA51A3B_u32), (0x9CF37DC1_u32, 0xD35C77C7_u32), (0x1DAD4F6B_u32, 0x309D6290_u32), (0x0FC95A8C_u32, 0x641FBA62_u32), (0x6CEC3603_u32, 0x65F0EF68_u32), (0x175EFF63_u32, 0xBBF6A79F_u32),
+        (0x3084135C_u32, 0x4F06F195_u32), (0x6C8867DB_u32, 0x459A876D_u32), (0xD6F9D789_u32, 0xAA45C0C6_u32), (0xD7D5A5AD_u32, 0x9F56FC47_u32), (0x990946F9_u32, 0x486ECFC1_u32), (0x75121C69_u32, 0x0D46BF1A_u32), (0xB1DA464D_u32, 0x8285F73D_u32), (0xBFFA4C57_u32, 0xC7FFA458_u32),
+        (0x2A67B219_u32, 0x6AA2BB00_u32), (0x356818F6_u32, 0xCA187579_u32), (0xF266C816_u32, 0x05089A12_u32), (0x550CB262_u32, 0xE373E31D_u32), (0x15C8434D_u32, 0x9ED38AD3_u32), (0x82C43C4F_u32, 0xA652FC2E_u32), (0x635C5158_u32, 0xB83F7BE6_u32), (0x8809EF6B_u32, 0x9A1494CE_u32),
+        (0x603DE10F_u32, 0xFED67AAC_u32), (0x6CDAC557_u32, 0x4B00D018_u32), (0xEBB03FB4_u32, 0xCC01CAB2_u32), (0x4A892624_u32, 0x7F07F2EA_u32), (0xAFBD73E4_u32, 0xE7792684_u32), (0xDB752BED_u32, 0x53DD7556_u32), (0x10CA00CC_u32, 0xC9B3F802_u32), (0x5D5249A5_u32, 0x2E36470F_u32),
+        (0x267921C6_u32, 0x5FD2A810_u32), (0x838DA933_u32, 0x792CF8FA_u32), (0xC7C1191A_u32, 0xBDDCDDAD_u32), (0xF772BF73_u32, 0x1412BCD4_u32), (0xA0A89254_u32, 0x13CE3480_u32), (0xC18AC688_u32, 0xBEC989A5_u32), (0x67EA728D_u32, 0xB0EE7E37_u32), (0x2BD9378E_u32, 0x6D8977B9_u32),
+        (0xDF389D08_u32, 0x0172B957_u32), (0xD4855CDE_u32, 0x78AAAE74_u32), (0x569C772A_u32, 0x33E30A68_u32), (0x69ECEDFA_u32, 0xC865C0A7_u32), (0x13B3CEB5_u32, 0x0B3C8188_u32), (0x93287EE0_u32, 0x8E5C1B9C_u32), (0x2B8F897F_u32, 0x027FF5C6_u32), (0x3EC5C426_u32, 0x0DB9BDB3_u32),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO128SS_96: JumpConsistencyTestTable<u32, 128usize> = JumpConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0xA7BA6FFD_u32, 0xDA395440_u32), (0xB58AE677_u32, 0xC0099EE1_u32), (0x9242B93A_u32, 0x27CE7B39_u32), (0x11CDCF76_u32, 0xD053F73B_u32), (0x46E7465A_u32, 0xE7998BDE_u32), (0xC3320C67_u32, 0xEC8FE543_u32), (0xD489ECD3_u32, 0xEE606551_u32), (0xCC02F23D_u32, 0x43115D9F_u32),
+        (0x9919D123_u32, 0xB430F80D_u32), (0x86B01F00_u32, 0x0DCE5AA2_u32), (0x1E3E5319_u32, 0x2A42EA35_u32), (0xA7A2B51B_u32, 0xF8FFD1AB_u32), (0x471EF49A_u32, 0xFA52E31A_u32), (0x5E0D342C_u32, 0x5B8679AE_u32), (0xB5FAA039_u32, 0xC4D622AA_u32), (0xA7F7C13C_u32, 0x936BE84F_u32),
+        (0xAC991B9D_u32, 0xA57DBD29_u32), (0xB64DEA2E_u32, 0xB3407457_u32), (0xA744F578_u32, 0x2CDA6954_u32), (0xC18118A5_u32, 0x0E9EDEC9_u32), (0xE5D091C1_u32, 0xE6DB2D62_u32), (0xE5D794FA_u32, 0xF497DF30_u32), (0xC3038EB0_u32, 0x7DA949DD_u32), (0x88521EE7_u32, 0x561CECB5_u32),
+        (0xB3DCFFCF_u32, 0x4806405E_u32), (0x149C8158_u32, 0x73431E7E_u32), (0x3113ED95_u32, 0xD636424A_u32), (0x37F7034B_u32, 0x9D60A90B_u32), (0xE3DF941D_u32, 0x56B6B7CF_u32), (0xC16D02AB_u32, 0xA43B503D_u32), (0xE9058655_u32, 0x5408BC06_u32), (0xF503F582_u32, 0x334B769D_u32),
+        (0xEB9AC7E1_u32, 0x3DD389CB_u32), (0xE9AF0741_u32, 0x8C751E72_u32), (0x2B623AD5_u32, 0x203E7DAE_u32), (0x2F9E4092_u32, 0x1CAEE2F3_u32), (0x33AD937B_u32, 0xDE0CA746_u32), (0x548B5A32_u32, 0x3C9B87AD_u32), (0x71FAE903_u32, 0xBBCE48C4_u32), (0x5E532CC0_u32, 0x13DE965B_u32),
+        (0xC181BAC0_u32, 0xBA97049D_u32), (0xF60820ED_u32, 0x9017A31F_u32), (0xA2B95B0A_u32, 0x685CB936_u32), (0xA86D6B92_u32, 0x7F60DCDD_u32), (0x2194E9A1_u32, 0x49B8769E_u32), (0x6E741043_u32, 0x50F26844_u32), (0xB91FDAB0_u32, 0x16A6343C_u32), (0xED7F9802_u32, 0x28F5AD8A_u32),
+        (0x7BAD5A04_u32, 0x3B1F427E_u32), (0x8B626E8D_u32, 0x25BCE7D2_u32), (0x93C51A79_u32, 0xB91BBFC3_u32), (0x45CC9ACE_u32, 0x3C0B7C9D_u32), (0xBC2EEAE9_u32, 0xA8CB9C15_u32), (0x93F17DAF_u32, 0xEB378AEA_u32), (0xB67BCA57_u32, 0xF08DB493_u32), (0x14C2DEFB_u32, 0xA8EF17C2_u32),
+        (0xDDA85D22_u32, 0x56CF2584_u32), (0xD524D1B9_u32, 0x387BDF1F_u32), (0xA8AB4C98_u32, 0x48CF8EF3_u32), (0x0022A9FA_u32, 0x6DE39E8F_u32), (0x51286800_u32, 0xEC469024_u32), (0x9C24159F_u32, 0xF1E7DDA8_u32), (0xE0C125BB_u32, 0xF3DAB158_u32), (0x089C782D_u32, 0x7D33DEEC_u32),
+        (0x90A62274_u32, 0x7F674EFE_u32), (0x6CF3A4D8_u32, 0x6C75F6CD_u32), (0xEB7B4CAF_u32, 0x4B133A81_u32), (0x913D6946_u32, 0x24C90FBF_u32), (0x92C8C89E_u32, 0x7263039F_u32), (0x5478B806_u32, 0x4859D9A1_u32), (0x045FC511_u32, 0x740AAD02_u32), (0x0D307B8F_u32, 0x01EBA490_u32),
+        (0xE5487BCA_u32, 0x4525ECF8_u32), (0x447DF770_u32, 0x856E1285_u32), (0x61E60C93_u32, 0xE70FEA07_u32), (0xA45FF825_u32, 0x94D137A9_u32), (0xF0175D57_u32, 0xEB12E030_u32), (0xE32A72AE_u32, 0xFAE8B765_u32), (0x9F8E1AEE_u32, 0xCD623FDB_u32), (0xD05E95CB_u32, 0xDF4B941E_u32),
+        (0xA8D0D51E_u32, 0xFFE28BE5_u32), (0x8F2C2DFC_u32, 0x227A7679_u32), (0x9A971FF1_u32, 0xBAD7665D_u32), (0x7B989136_u32, 0xF3C9164F_u32), (0x78E46D3D_u32, 0x809BE807_u32), (0xFF97D0E5_u32, 0xA94E7998_u32), (0x889F5B60_u32, 0xA8EB6BA2_u32), (0x33B7E01C_u32, 0x9B6A5F27_u32),
+        (0x5910384F_u32, 0x1C10F7A6_u32), (0x91E22544_u32, 0x6BE90B84_u32), (0xF8511C77_u32, 0xED4FEF54_u32), (0x34CB39B4_u32, 0x57456591_u32), (0x6B33CB70_u32, 0x8435DE12_u32), (0x893712B2_u32, 0x1C6EBDD1_u32), (0x1C1BFDCC_u32, 0xA96FEBFD_u32), (0x1A6C6BD0_u32, 0xCDB7830E_u32),
+        (0x2E9ADDCF_u32, 0xDDCE62D4_u32), (0xDDC0D3C7_u32, 0x85DD819C_u32), (0xBFA6C88B_u32, 0xD9E5D87E_u32), (0xB32B707C_u32, 0x8EDB61CB_u32), (0x99A71C4C_u32, 0xA01BAA20_u32), (0x48B48324_u32, 0x01AC5AE3_u32), (0x4A4E0E95_u32, 0x5E539726_u32), (0x3DE25EB4_u32, 0x9511EB64_u32),
+        (0x91F1F170_u32, 0x8F58B32E_u32), (0x7C11F6EE_u32, 0x5255F79F_u32), (0x6AC56326_u32, 0xB2276FF3_u32), (0x94E05B5B_u32, 0x9EBAB94A_u32), (0x0C5CF498_u32, 0x3034F4F4_u32), (0x70C46216_u32, 0xE624448E_u32), (0x9F0C6BD4_u32, 0x76945F23_u32), (0x9191B61C_u32, 0x605F7FF9_u32),
+        (0x69D9748C_u32, 0x7F5666FD_u32), (0xE831B8D8_u32, 0xF4485693_u32), (0xAC87CB67_u32, 0xA19A5C8B_u32), (0x50C0D3A7_u32, 0x33628328_u32), (0xE46D6734_u32, 0xB42D6C01_u32), (0x9DD8B289_u32, 0xCFF7E45D_u32), (0xAF893748_u32, 0xB5256AE0_u32), (0x99709EC3_u32, 0xB708546D_u32),
+        (0xF3511D1D_u32, 0xF808397E_u32), (0x2DFD4FE4_u32, 0x2A035ABF_u32), (0x03D2BC97_u32, 0x360731D5_u32), (0xAF61B625_u32, 0x668882DE_u32), (0x871C829C_u32, 0xC83227E5_u32), (0xE5E3421F_u32, 0x18809139_u32), (0xFE74F21A_u32, 0x2FB54688_u32), (0x20134D8C_u32, 0xD081D6A8_u32),
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512P_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0xE8E50551FB2712FE_u64, 0xB38727A95F6D882E_u64, 0x46231E4CAAB4BF2D_u64, 0xEDB7C3D606F73C9D_u64, 0xE8F46103175C2843_u64, 0x34D517948ED00C7C_u64, 0x384FD67A6703888A_u64, 0x21DAE3403A1C0A40_u64,
+        0x5B07AC0574D1AB55_u64, 0xFFF0598D8A59EF5C_u64, 0x83DDA6D45E84C2B9_u64, 0x8228B3709DDED2D9_u64, 0x62D63DD5B794C4ED_u64, 0xAC67E8F875AA5231_u64, 0xD4F8E18DF51C55CD_u64, 0xBAF11CCE2C2EFEEC_u64,
+        0xF11C33E8A10E2EBD_u64, 0x4ECBD61A76CCE595_u64, 0xDFB7580DB34EA373_u64, 0xA860E25B9014413E_u64, 0x3BF9A0F4CE35F9F5_u64, 0xA9C1EEDFCB426E24_u64, 0x5410BBEF117DCCC2_u64, 0xECBFAF4036E5B4B8_u64,
+        0x51F759D7EB0223DE_u64, 0x370E3611414EBF7E_u64, 0x54B2421E3A53FF93_u64, 0xBECF27BE546F617D_u64, 0x7A83E4FB22287DD7_u64, 0x1065D01EE8052DA6_u64, 0x81FB80C65DEC0F91_u64, 0xDEEE916396461CAA_u64,
+        0x4E534F10A1C098A3_u64, 0xE83AEE37B242A444_u64, 0x39BD7F0C3E9C08B6_u64, 0x15B33C4DDB8706C6_u64, 0x410ACF37A749F1CA_u64, 0x3CF0F7C60C57BBBC_u64, 0x1213DDA329AEE243_u64, 0x26559D345D16F0AB_u64,
+        0x139855A17CA9A3CA_u64, 0x986A2C6E31999A4D_u64, 0x443C7B35A759CA1A_u64, 0x12E92D0F833D7454_u64, 0x6E28EE8C8BDAC66D_u64, 0xCDE3321107F3D587_u64, 0x966CFAC7A8246DE5_u64, 0x965EA0B9B34DDBB7_u64,
+        0xE86DB1F5BEDE5417_u64, 0x7D44D9E8696195DE_u64, 0x2E69216AA1C00B3B_u64, 0x7C53C4797A8B999F_u64, 0x29FFFFD42D8451C8_u64, 0x4682CEB3BFBC670E_u64, 0x1E80C0E1ACDB3F7F_u64, 0x6448428D7B840D1C_u64,
+        0xDF84481DD56BDD9E_u64, 0x3502DECFD2E440E3_u64, 0xA72ABFD5385001E2_u64, 0x56B64408AB0EE417_u64, 0x529B91F371ADA359_u64, 0xD545E7F64BE4F58B_u64, 0xDA7F538AB8F296B1_u64, 0x1E843C3F662F038D_u64,
+        0x03ED0EBDE23F00A7_u64, 0x278C5A3B028D7738_u64, 0xED9E560D074D8A25_u64, 0xC363DA8B7BAB3D82_u64, 0x37161EE937D492AA_u64, 0xD1EF8E79E3CBF7AE_u64, 0xFD974BBF2064CB77_u64, 0x05AA0E56F3A31261_u64,
+        0xD0CE9467AA69F8BC_u64, 0x0E6D100B8A845D78_u64, 0xDB37A8D162F6A597_u64, 0xB99050F10B780553_u64, 0x9AAF7A22894DC499_u64, 0xBEC2838C27FF4C4E_u64, 0xAE51E57C1459AE95_u64, 0xFABB66AEE63A9C5E_u64,
+        0x3659EA9FD1445F04_u64, 0x344E660A5B23893E_u64, 0xEE0846F7812FC169_u64, 0xC00DDE34CD55E668_u64, 0x04316988A9783F8C_u64, 0x5DF5682B9C05D944_u64, 0x0492683559E11F6B_u64, 0x442D433EFD006689_u64,
+        0xC300A96C95480657_u64, 0x09CD3BE23D6277A6_u64, 0xFED354A2051DE50C_u64, 0x025851E231B3DEBB_u64, 0x8CAE39DB4DC2A9FB_u64, 0xA704A68E8107BCB8_u64, 0x014DCB0552F92F44_u64, 0x071061A56A849A10_u64,
+        0x83E52F1034FE1AA8_u64, 0x4006E5488EABFE0C_u64, 0x5BAA01CE1CD814C4_u64, 0x659651FA2F93D9CA_u64, 0x130118E19C3B0FA4_u64, 0x191D3CDA2355C76E_u64, 0xA218ED2FFAB6EEA3_u64, 0x8ECCA1526B352DF1_u64,
+        0xB03FBF00CDD14551_u64, 0x7DA658800384DCFD_u64, 0x0EF404E81F7EAA2F_u64, 0x27958305E84F6C03_u64, 0xDE1AC65FCAE51529_u64, 0x70C56B1ED72AE4B4_u64, 0x9AD1E4E27A468200_u64, 0x039BF9F6971E9440_u64,
+        0xCCA1A53871AEC583_u64, 0xA5AFEF8583CD7371_u64, 0x9F702C1B43F4A91B_u64, 0x7B4B034542535BB9_u64, 0x0D714CF226479C8E_u64, 0x408A6CDB4C2B38F3_u64, 0x60F027A1C47F1AD6_u64, 0x3C247E7AC8A707B4_u64,
+        0xD2A47D76E05DFC0C_u64, 0x54D8ADB2EA98D16E_u64, 0x8AFBF39899BAEECD_u64, 0x24A1FCDC9126DF66_u64, 0x747834AC337C5497_u64, 0x616F70F01B5DCE11_u64, 0xCAE452F62A732583_u64, 0x2FE6EDE699337406_u64,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512P_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x4FEFBF4A5457884F_u64, 0x53F98090DF96D9DF_u64, 0x98EE08F643EBC9B5_u64, 0xAA35AE8C08AE5B92_u64, 0x2EC0E11845385BD2_u64, 0xF296F000758B5888_u64, 0xBAD85F6D43642A27_u64, 0xF553D4B38E9CFB96_u64,
+        0xFC425A4365DE592C_u64, 0x5EF4A9C336A2443B_u64, 0x6DD5AF80AF5D6909_u64, 0x36F3F2A6E13A2542_u64, 0xC20F6996ADBE21D3_u64, 0x9961C95F6818B492_u64, 0x12828B3DD5F462B0_u64, 0x9B4868A2A03E4DCB_u64,
+        0x4D5F92152F92D044_u64, 0x03D82E3DB9B94D9E_u64, 0xDAD6C030E76A881B_u64, 0x56962FA59EA4E7CD_u64, 0xB16BFED1561E527B_u64, 0xFC610054830CF43C_u64, 0xCBCB036340291194_u64, 0xA009A1A3E200A1F9_u64,
+        0xFAB38DAC40673416_u64, 0xFDFA9FEECCEF70DD_u64, 0xD677270AED8634FA_u64, 0x9812661CD746908D_u64, 0x98A0B59C6BEEA807_u64, 0x38E9A302E6440D00_u64, 0xBBA38FF28CE86589_u64, 0x61B43DD6D618BE6D_u64,
+        0x04784461D3BE9CF8_u64, 0x9FB52EF56608947E_u64, 0x6AEFB9631ECC0358_u64, 0x31CACFA74C367AFE_u64, 0xBCD3F5244BF3880A_u64, 0x3F4BDB4EA82344D4_u64, 0x4BB8233DE70B73CE_u64, 0xE89CF19AAA700E22_u64,
+        0xA9F41D40C61CE0E4_u64, 0xBF56CCD4A1191C2E_u64, 0x1E1164A9B3672C45_u64, 0xE3BFD8DADA8EF834_u64, 0xB1073099841D6B0F_u64, 0xB59731874E350D8B_u64, 0x62A18B9B20128624_u64, 0xB19992282466604C_u64,
+        0x5A1AE99BE6164EB9_u64, 0x16B724E8D5841F8E_u64, 0x1FE41DE5195163C3_u64, 0x84BD25C7352CA291_u64, 0x50C7D5AA42D100A8_u64, 0xF572B4A8A6776D65_u64, 0x36761A2CD51DE580_u64, 0x09C0E1C7062E2443_u64,
+        0x19A735BB3B9F2BAE_u64, 0xD1376AE4E2413A77_u64, 0x73D27BECD2B04BF2_u64, 0x6730DA2CF6FF0A16_u64, 0x8D71220296F67AC9_u64, 0xD48C4DFC09033BEF_u64, 0x8FB2FA864615059C_u64, 0xA05F5857DB72BB91_u64,
+        0x4A3E62B9D045C43A_u64, 0x500D8106367584E4_u64, 0xD4476C1FF9C776C0_u64, 0x29BD000C66AD15EE_u64, 0xE7D91365B75458E1_u64, 0xD9E5D49660C68EE4_u64, 0x101D8151F6A537E1_u64, 0xE25D34F3D13DBF38_u64,
+        0xD546CC92FABB4634_u64, 0x71926CFC7916B744_u64, 0x038B557F9A123133_u64, 0x385188474BB3989E_u64, 0x8CDB41C29A1FE414_u64, 0x86C9BFFFDFDF2DE0_u64, 0x4A9E5C5395D1B850_u64, 0x6BB154DD72186229_u64,
+        0x17167B67FFA2D77E_u64, 0x50102FFAD2C0897B_u64, 0xB5A05C0D636CC8B6_u64, 0x9FD253D1792049FD_u64, 0xFD8B198677ACFE4C_u64, 0x56E0D9F764762D54_u64, 0x59323E1A09778332_u64, 0x627D36522FD2E261_u64,
+        0x77E1F674ACB94F92_u64, 0xB98BF8954DCDFFFE_u64, 0x1C9DDF0B83B88551_u64, 0xB21605AA992A2626_u64, 0x62EA819855C8F0BC_u64, 0x8AF5F48CCFEF79BC_u64, 0xCB221C875A3707BF_u64, 0x45AA5F57A3BB00BF_u64,
+        0x2C8102477FD1DF68_u64, 0xD95DCE090B592568_u64, 0xE1D4D481C3EA0703_u64, 0xB0E7848377ACFEF8_u64, 0x208929E816372081_u64, 0xB7DC93A2C2A037F7_u64, 0x41C7F1B49D890116_u64, 0x9C0BF41FE73D01EA_u64,
+        0x7D121906634EB1D0_u64, 0x6FD050289D576214_u64, 0x4A94D58F4FB1769A_u64, 0xD1EA72870CBF89C9_u64, 0x2F37689FAE8D2C5C_u64, 0xFDFD7AC656FF14DD_u64, 0xBF8CB290DF8CF353_u64, 0x27F0ADCB08D519A6_u64,
+        0x6E95A7DB1A35ED68_u64, 0x7EE7E1C3C3FA3489_u64, 0x699F991EBAE1624A_u64, 0x8B594D2A169579D0_u64, 0x4B4E07AFA5384969_u64, 0xC4C1679563303E19_u64, 0x82E6F3DAD2C4FEC4_u64, 0x9152816B44088221_u64,
+        0x86432C6BCEFD9E6A_u64, 0xECE7F7EAC41F178F_u64, 0xCCFBF4C400BE830B_u64, 0x98FD7B27329FE47E_u64, 0xD39A114D6C6F461E_u64, 0x38969CEDA3E99765_u64, 0x5D2B4DA0CD2B1C11_u64, 0x27D623D4F70B5B94_u64,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512P_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0x91E20094815CAC30_u64, 0x21356ED0A75A64C1_u64, 0x0261BF406A102B76_u64, 0x234C15AAEE2C2239_u64, 0x106F64C68BA840D7_u64, 0xC04EF81A47679AA5_u64, 0xC5D8CB91040FD8E9_u64, 0xC9012010B18C504B_u64,
+        0x3F9E5899112662AD_u64, 0x14005D6D13E29911_u64, 0xE6DAD9C0A57040EF_u64, 0x7A312E6505AE0E16_u64, 0xC6549CDAACB63A4E_u64, 0x2DBBD0791B87A8DB_u64, 0x44EBFD520F447AEA_u64, 0xD303DAEC5BDA3295_u64,
+        0xC0AF047B556AC468_u64, 0xFBA57ED54AC3ED41_u64, 0x15B55F650B1CE75C_u64, 0x23D5254F3AD6CB54_u64, 0x2A8DFF3DBE1E9028_u64, 0x39733C10116CE09E_u64, 0x0E55106382A328A3_u64, 0xDAB12EF7B71439FD_u64,
+        0x0DF563D2DAE4D5D7_u64, 0x0980A3BC42820350_u64, 0x1ACF768F00CC367C_u64, 0x5D580D72F0158BE7_u64, 0xA67983E528BB557A_u64, 0xE232782EC31B644B_u64, 0x94E25F27815422D6_u64, 0xDB2A2239B6165192_u64,
+        0xE709BD119C75E5CB_u64, 0x94B87D1AD780BED1_u64, 0x8376BCEF44520515_u64, 0xDDDC88193FB044EF_u64, 0xF6C055BE11B9BEDA_u64, 0x00ECD95A863119B3_u64, 0x767DCF31EE1F5D26_u64, 0x38AF5EE27B6F1BC0_u64,
+        0x32FF27E863D17EBD_u64, 0xBD1A5E6A02E6927E_u64, 0xB0334CB5E913B3E0_u64, 0xF10598F40309DF02_u64, 0xB4439342180F87E7_u64, 0x7CF941B144923DF4_u64, 0x306DE118B903B309_u64, 0xE7254221CD67173D_u64,
+        0x8D5D0498EC98A32E_u64, 0xC08F82925C384376_u64, 0x88198A773982F7C2_u64, 0x5180DA55AA9A4637_u64, 0x0C9407540BBAEA6A_u64, 0x826AECBD45611551_u64, 0x19288BA29042B698_u64, 0xDCCB8B5458B6D738_u64,
+        0x6FFC698941425D97_u64, 0xBE693750F96A9A62_u64, 0xCB6399AFA174940F_u64, 0x347F1B110BA50F19_u64, 0x01A6A88815DC615A_u64, 0x3ED2BA279FC3727E_u64, 0x84DAA1F45A800442_u64, 0xECA55AEDFC2A79A1_u64,
+        0x90FBB7A9EA948954_u64, 0x903194933AB590CF_u64, 0xD2E6DDFC5B5B52E2_u64, 0x636732ED3CECDCE9_u64, 0x69250C186CB99C56_u64, 0xB7967EBCC745EB11_u64, 0x4589C9A456AB8BBA_u64, 0x98AA80F4E49AEA17_u64,
+        0xA0751155879F5D9B_u64, 0x6A35D121C805F1D1_u64, 0x48A0CFF69D5AA3F3_u64, 0x2481820757EEE989_u64, 0x8DA01CF72B0D5D2B_u64, 0x90BD382F03830E95_u64, 0xBD61BADB372EC48A_u64, 0x5E876F2323BDC9B5_u64,
+        0xBDF9BC58D370F725_u64, 0xB899DFABFB802AFE_u64, 0x882391E6A1ACE93E_u64, 0x578905F9A6F59CAC_u64, 0x299EFB09566C497D_u64, 0x144EF04655633780_u64, 0x2F209550C0C83119_u64, 0x869CB31FF7D023A3_u64,
+        0x302F7E27250D5253_u64, 0xEDF9C9B1F4343262_u64, 0xB0E6E181E6D14D14_u64, 0xC5C4A47046162C7A_u64, 0xA882DFD814CE89C0_u64, 0xBE7A6AD10E567447_u64, 0xD734F52621DA5F80_u64, 0x22BC0EC676D00160_u64,
+        0x1A39D9BCD4D0F5FB_u64, 0xDE90790CD2BBA895_u64, 0x9EA5CBB8013181D1_u64, 0x7BA91C3888CED5E6_u64, 0xBACAB719098A91AA_u64, 0xADE3679A4C7B4CB2_u64, 0x9C444B4DF85F1D1D_u64, 0xB5C8A02D5A88E213_u64,
+        0x913C8453AAFDCAE0_u64, 0x520C6597850A24DD_u64, 0x1C7B28B40214A6B7_u64, 0xD08059D4F7261D90_u64, 0xE7890F1774CF1190_u64, 0x911C97E0E2F1B508_u64, 0xCEBAF294324ECB24_u64, 0xBCDF36ACCD2D5691_u64,
+        0xCF8EB7BAE4074F16_u64, 0xEC653920CD5A28FB_u64, 0xA78EBD2D8241C562_u64, 0xD5BB1CFCE696191B_u64, 0x17E50A5923F522E9_u64, 0x61B23869095060FE_u64, 0x34DF77AE33B19C71_u64, 0x4591CB6FD51672B5_u64,
+        0x279F0D210E6517D2_u64, 0xDAFCEF748486DC10_u64, 0x3BD93613C40991B3_u64, 0x595ED3D40109C228_u64, 0x38CFD7BF825AB2C1_u64, 0x016AF0416E775762_u64, 0xD4E0458A7732F92B_u64, 0xC04CA870288A9CE6_u64,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512PP_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x11685366A6071719_u64, 0x3437B3FD0B71EFEE_u64, 0x66E01D101DA703F4_u64, 0xB9469EEF0D600C40_u64, 0x4F8DD28E377F0D05_u64, 0x06143C98EF8C3FE5_u64, 0x37F7B46A7FC45119_u64, 0xECCE8AADAB7C8C1F_u64,
+        0x35CDC4628C8E3FCB_u64, 0x53A120ACE9C7A805_u64, 0x4D950376F9B4F6CD_u64, 0xE2FE61C943B64106_u64, 0xF5C21A98EBB3703D_u64, 0x649A245DD8C8099C_u64, 0x4E33811F20BCBB9A_u64, 0xFC93349F08B3CB6F_u64,
+        0x9CDDDEE8890ADF0A_u64, 0x3537F96A2A1C6AD0_u64, 0xFCE72837901294D3_u64, 0xA2D77811A5866C2C_u64, 0x564A7A0741ECB6AB_u64, 0x11B7B547AA7AD908_u64, 0xBE0FF1D9CCA6153B_u64, 0xAA6F919A56EF0C31_u64,
+        0x9EEF7EC3EBD254A2_u64, 0xF9AA9C4561F631BA_u64, 0xA84A7A76BBD3E1E2_u64, 0x6426E26084077D09_u64, 0x88851C0E4E179280_u64, 0xA5B9AA8AF9239DF2_u64, 0x111EEAF6F71DD14F_u64, 0xA0C2AB458B614D3E_u64,
+        0xBB29AF9447779ECE_u64, 0x08B1F373E64864C8_u64, 0x95D34EDF9AA70F1C_u64, 0xAE5433D149E8221C_u64, 0x88B211DD870568BB_u64, 0xAC16E15718226763_u64, 0xF8333B178F8A47DF_u64, 0x2432CC7E7704E9DE_u64,
+        0x808492069A5AF57B_u64, 0x43F38790AFBF4C08_u64, 0x3CAF7B016185ECA5_u64, 0x15DB692500012792_u64, 0xC9A9C08509984FA5_u64, 0xD12AFD5A1EAFBC13_u64, 0x0361FA35C3BEC250_u64, 0xC6BC5FD1DF3279D6_u64,
+        0xF848DC7254799462_u64, 0x741DE0B8EA9A4DA0_u64, 0xBF9009872ACF6EA2_u64, 0x5607927BCC7EE162_u64, 0x61D415791C04AC5E_u64, 0x43677E8C9B982E3B_u64, 0x87408A42BE39A1EF_u64, 0xA19997E16CDD870F_u64,
+        0xEBF2AC4535B9AFEA_u64, 0x9619EDA5A25A8663_u64, 0x049D41CF96898730_u64, 0x2ED695A7FF7E9F48_u64, 0x2D1B1F4F8BA3A91E_u64, 0x104005D553654720_u64, 0x7BB689C6B93E7961_u64, 0x49FDF8A6ADE75335_u64,
+        0xB9F5E83C1AFB0327_u64, 0xAF7ECE5CBFAF4D2F_u64, 0x83A56853F4BC6272_u64, 0x76B09D4341B30CA0_u64, 0xFE26011B97A4A09C_u64, 0xD384E39CA731F629_u64, 0x454DAF3B3313F25C_u64, 0xFEB69A823D252EBB_u64,
+        0xEA793CFAB4D66F3A_u64, 0xEEA4596EDF42035C_u64, 0x42F555B08D9346E3_u64, 0xBA99ADBD6D97F877_u64, 0xACB43F8A53AB3009_u64, 0x6147B1DC0D3FB0CA_u64, 0x09B46927252A86EF_u64, 0x1E87B1F0C463A37B_u64,
+        0xCDF806D18F39AD0F_u64, 0x912E9866E39EC790_u64, 0x381C6454DDF750CE_u64, 0xA6611FA34A00B830_u64, 0x12F330BAAC219EA8_u64, 0xCC058E8003EFFAEA_u64, 0x6A53FB855ACFAE68_u64, 0x79BE61B1F33167BD_u64,
+        0xF685E6CCFDAEDE46_u64, 0x34C3CBD8440409F1_u64, 0x9976C619863774BE_u64, 0xA2568681B2931F9C_u64, 0x710AA9571D82F27F_u64, 0xBF2B23E7C72E5804_u64, 0xDD034B60DD7FBDE1_u64, 0xBFF85601DF16D6D8_u64,
+        0x54EFCA504ACC21AA_u64, 0x466BCC4007109935_u64, 0xC392D238B2D4B520_u64, 0xC83A50F4CE5CB660_u64, 0x96D8CA562D542BA4_u64, 0x42B25D8D0EFF41DA_u64, 0x734128870058FC9F_u64, 0xD47B5F7A5097ABF4_u64,
+        0xEB222CF00CD7724C_u64, 0xDF3FC40883A41D95_u64, 0x6B29667D44D0B6E5_u64, 0xD2CFCAB6B784D8FA_u64, 0x22D208C402022830_u64, 0xB457CFF1EE01A45F_u64, 0x16765CAB9A0C18AD_u64, 0xF67E431A9AB18533_u64,
+        0x4E0CD521E1E404FB_u64, 0xA9A94A4B32F17DD2_u64, 0x69C6764ECDE8B16D_u64, 0xA2FA58776B653A99_u64, 0xDC99493376AF6E97_u64, 0x61BF4540470D9C80_u64, 0x7FCC9C16D7CFC8CB_u64, 0xFDE398DF8FE78D48_u64,
+        0x36C63234C0728CFC_u64, 0x087A4FB8733A9D9D_u64, 0xF2017D20A8024465_u64, 0x84B50294C4013774_u64, 0x7CB663CB37F407F4_u64, 0x542801C6C694ED75_u64, 0x434CC3D625A11FB5_u64, 0x8CB0BD507D4C7248_u64,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512PP_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0xC106D4A357DCFE5C_u64, 0x503177CFFDE61FA2_u64, 0x5DD3072472B3477B_u64, 0x63FA08427310F5F4_u64, 0x67E2CBB4AECD7901_u64, 0x0CBDC9AE6A3840FF_u64, 0xA84156C759921D14_u64, 0xE17E5DCB3291104E_u64,
+        0x39B2A0701AB4E8CC_u64, 0x45C3BE811E505695_u64, 0xFD8B687507240ECE_u64, 0x370F6EF099E174AC_u64, 0x882145D322279CD6_u64, 0x4CA7659A7AE34466_u64, 0x7D0D52880D44D851_u64, 0x9EA6827E658252B8_u64,
+        0x24B0C3C6C040C84A_u64, 0xA8DABD47AB9E96E2_u64, 0x7B897C92C96BE70B_u64, 0x83D3FD18669D150F_u64, 0x14287C5635977AE0_u64, 0x8D0D04C83E581B3D_u64, 0xEA657F5A9C1A8B50_u64, 0x4EFABF5403BC2E73_u64,
+        0xBB3CDDD2062D5360_u64, 0x0509078A215487D9_u64, 0x4BFA3AF51CC41C0B_u64, 0xF1B04757A994DAE4_u64, 0xC3262D7616C4804E_u64, 0x5D6402237D8C89CA_u64, 0x56BA72CDAC4F5A45_u64, 0x2DC91A3AC77223DD_u64,
+        0xA6474941FB06467D_u64, 0x5961CF6AFCBBA250_u64, 0xD20F665A48A5295D_u64, 0xF43E50CF93206039_u64, 0x727D470D4246FCA5_u64, 0x73493B1EB59C0689_u64, 0x84AFE8C53F78D280_u64, 0x16E56701F538D4E7_u64,
+        0xC1DE5992CB575DC2_u64, 0x3F74C4F0ED5E5D7D_u64, 0x85942F79B1A31FB0_u64, 0x8F460872F3C072B8_u64, 0x3CF2DF0BAA8E49D0_u64, 0x72072B8296D7CFBD_u64, 0xBF9C6D9BB5FD91B6_u64, 0x36ADBC1FA082D8F5_u64,
+        0x609939B43908537D_u64, 0x8BA9BC9C10315DA7_u64, 0xD0D135699D021F34_u64, 0x6B688431DBD164B5_u64, 0x268D97D80A20BDFC_u64, 0x18091E310D9CE33B_u64, 0x1C66DC600C76FA45_u64, 0x69F3E6891B57E8FF_u64,
+        0x30B554F538E9CF09_u64, 0x67204A35308BB6B6_u64, 0xA69005E1351E1A0B_u64, 0x200469EABC7AFA4D_u64, 0xD4315399DC9210A8_u64, 0x0960EBFFC0E919DD_u64, 0xFF583A260229DB54_u64, 0x3FDC985730F83300_u64,
+        0x6392382320BDCF89_u64, 0x340DC9A0B97E523B_u64, 0x044D6C74168C0972_u64, 0x844D593A2593C8B2_u64, 0x48886E9C4706A540_u64, 0x90CDAE0ED51D0AA8_u64, 0x1887F1DF8DE28F15_u64, 0x59C51AA545166C97_u64,
+        0xB6C8BA5A58A75B13_u64, 0x83FFB57A50C9084C_u64, 0x188C9F07D6FCBE4A_u64, 0x08FF17E6832A3F6E_u64, 0x4B16BBF8705120CE_u64, 0x82D7FD6FB5A0E977_u64, 0x39D0EBA34CC11F54_u64, 0xF21807DD29E47B82_u64,
+        0x61D911E318134855_u64, 0x26FA1FD91293479C_u64, 0x062A96CEBE2CB1C3_u64, 0xD142362DAE0E0454_u64, 0x92B93B6341B93D13_u64, 0x76614A60D23BAB73_u64, 0xD250ECE5A1D68474_u64, 0xC1AE1D7BBA37282C_u64,
+        0x45641F1C6E4F0BE0_u64, 0x384890FF12A2A27F_u64, 0xF38AFDC234303939_u64, 0xDDAF513FBFF4DED9_u64, 0x1112A3E6379E97A9_u64, 0x420310F69CFFE527_u64, 0xA3F4A6D09F6DFC00_u64, 0xC9891FBC47255313_u64,
+        0x34985EFADA8357C1_u64, 0x68911459CA7DD0D1_u64, 0x69A3B59B0208DCED_u64, 0xEA9B7AD639DB56CA_u64, 0x82969EE9412D3E18_u64, 0x064A4A5686164E36_u64, 0xEB3C46ACBC8C0A96_u64, 0x2667BBFD5D4C351F_u64,
+        0x95D0B1BD3851F40E_u64, 0x1ADF01A81E562DAC_u64, 0x3AEDEF39063BEF2D_u64, 0x1A18E3EE4340EA66_u64, 0x7B14BF934B776413_u64, 0x2244125C732D1F0C_u64, 0x631331502BA451F2_u64, 0x9800441711B94284_u64,
+        0x27A27D9ECED5B2CB_u64, 0x04CC9F8F3B48C037_u64, 0xAF255374E8868388_u64, 0x2AA49409A8BF18FA_u64, 0x7AB7CB06673C0C5C_u64, 0x83D7CDEC80DB2FEB_u64, 0x8C6F0D0F57B837A6_u64, 0x7D6B73E126FE1FD7_u64,
+        0xC5011C4640CC8663_u64, 0x660658A9F811371F_u64, 0xEA0FF89239F48166_u64, 0xC3426FFBC83AAEED_u64, 0x239853AF589CC2AC_u64, 0x0C3D347F9B19A30B_u64, 0x4F96F74393F80EAF_u64, 0x642A9A74E1A8E38D_u64,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512PP_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0xA8E5C934CFF368E2_u64, 0x2B3B4B1747DA6476_u64, 0x1C83622CAE46657C_u64, 0x25B39B908A61F09C_u64, 0x9C41009B6F81F30F_u64, 0xDF3D2100A5533D34_u64, 0xD570FFF9592BEE4A_u64, 0xF9E887879C86A6CB_u64,
+        0x6831021C13CCAE87_u64, 0xF93BCECBBEE84429_u64, 0x9F805C408DBA2E76_u64, 0x6DEEE45CAE7C7551_u64, 0xB1DDE5D155AE9A71_u64, 0xD6F6D3207C6A7CC5_u64, 0xBC57C6FFD03C309A_u64, 0xF864B46253E718EB_u64,
+        0xDBF2CD690C774EB3_u64, 0x3CF958FC2CE53190_u64, 0x41E28E4E78BBB427_u64, 0x5C2314F261C31DCC_u64, 0x024F958BC4F1782B_u64, 0x9D8E1F7B7F18C0EE_u64, 0x522FC9163F92F948_u64, 0x4B73FD84EE1645FD_u64,
+        0xC84DC690F29155E3_u64, 0x555DC8D0D982B528_u64, 0xE39C1DBC2A75F8AC_u64, 0xED15267416026B10_u64, 0x60D1D2D7BAC7B8DA_u64, 0x04D30A1BDFD246D8_u64, 0x98187A5502878D67_u64, 0xA744FD031BC992E4_u64,
+        0x94AC3AA1116977A5_u64, 0xD03F69EA9118BF1B_u64, 0xE286017CE1A9C37A_u64, 0x829ABC2F458DAFBE_u64, 0xC4989AF7BD65926F_u64, 0x7774B620210BBEDD_u64, 0x96F662602F7BCEA6_u64, 0x433EC60F455F0C2E_u64,
+        0x861D08235DC8801E_u64, 0x87D2ADB588CAF8F1_u64, 0xD250E3916495CDE2_u64, 0x81933035CEF10D23_u64, 0x15757512F2C50179_u64, 0xAF0593E253D56FF9_u64, 0x3D26AE56215C12C5_u64, 0x53D13355756F7F3F_u64,
+        0xE14C0A4F06DDF177_u64, 0x727D9AD773849BEB_u64, 0x535CB55372BB4299_u64, 0x2C705F98C1E99A3B_u64, 0xBD26DD0B1539D2EF_u64, 0xC9E60EEDB5D38E39_u64, 0x78DA0D3F9E3034DA_u64, 0xECB023C01CB4701D_u64,
+        0xA3D5EDD83F65762C_u64, 0xBE9D883B6E02CE29_u64, 0x54F57776212FAF23_u64, 0xBE7D68F86FA6CCED_u64, 0x7588C485ADF8CC12_u64, 0x6DA5D6FEA6CFCDF5_u64, 0x00136AD824A87C33_u64, 0x2A61521915C3BD04_u64,
+        0x7BF92E170E7E9854_u64, 0xB2197BC13709850D_u64, 0x4BC63F21D5E6EE5C_u64, 0x32AF57D3D4EE09AA_u64, 0x7277627C7377AB1B_u64, 0x52119891E1478A80_u64, 0xC9AF2C07CE7A5FFC_u64, 0x3B2EFD78FCCF938D_u64,
+        0x88CB88314E9226D1_u64, 0xFEB8709C4AC166BE_u64, 0xF1A14AB64FE05E6E_u64, 0xAB675FD265AB64EE_u64, 0x145ED4011223F1B5_u64, 0xD9BDE9FCA1B7723D_u64, 0xC3B9262C839067D4_u64, 0xFFA3FC3E5A33F776_u64,
+        0x9525B58488893118_u64, 0x7D499A14F90C6858_u64, 0x6B26E2DDCDFCD543_u64, 0x1383BBCC96AB8FD0_u64, 0x3E7BA2D1A7EAAE8D_u64, 0x89EB9BAFC48BDE1A_u64, 0xEE5291460C8E95BF_u64, 0x0F1E79CF037EDC1E_u64,
+        0x71AEECFA2C55FAB9_u64, 0x631262007FB80996_u64, 0x94E8F7245DD98AE9_u64, 0x57C51DAE3986966D_u64, 0x77B4AD0C98957C77_u64, 0x1E1CFBB3F33DF230_u64, 0x27D19881C80B38A0_u64, 0xC1B7E2341499A4F6_u64,
+        0x8E3598486126330F_u64, 0xD7DFAB277BBAC6E3_u64, 0xB47C794F8646D45C_u64, 0xD50633C5A7FB7523_u64, 0xB1B8EB4C93E39B59_u64, 0x03F99FCD81D7A9E8_u64, 0xE47756D3C6B5E93A_u64, 0x440E383FABC6486A_u64,
+        0xBA4DF5CE2B35FC5C_u64, 0x39628567CAB6CD36_u64, 0xDF539540D2600B97_u64, 0xB70DF4D834F4A7B7_u64, 0xECA73F6127A1DF80_u64, 0x072AB27ADEE03385_u64, 0x5403AC7BB1375065_u64, 0x9E5E87C2BAB02482_u64,
+        0x2A546C3B5059A026_u64, 0x20B05256D5EF27CE_u64, 0x0DF33B60555AE610_u64, 0xBA687DB8AF716412_u64, 0xE9D524E65A3808AD_u64, 0x08B30441DDE75649_u64, 0x4D8E1ECA2F71C6BA_u64, 0x1F7C214EB117EEAC_u64,
+        0x4EAB4039F268BBE3_u64, 0xA645D3EC41867D81_u64, 0x4124765E95AD4AB2_u64, 0x59C8AB96A7D52070_u64, 0xC49D88896383AB65_u64, 0x1732AD27291C4412_u64, 0x8729DE96809F00D8_u64, 0x75BFF67EA07A75A4_u64,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512SS_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x99EC5F36CB75F2B4_u64, 0xBF6E1F784956452A_u64, 0x3832E5E4541959A2_u64, 0x25715BAFDF7A6B43_u64, 0x8B67FC19F1C8E17F_u64, 0xE950E894ACC448C1_u64, 0x940554D2C9B256B2_u64, 0xF9EADC87DA09AC92_u64,
+        0x4BFA499F878AC962_u64, 0x383336B0082C3C49_u64, 0x2863CF13AF79512E_u64, 0x498C25CCC5953380_u64, 0xACDE7669D72AC701_u64, 0xD8582A2FDDA30A73_u64, 0x0DDAE8A5A57C3302_u64, 0x39B75E8FB865DFF5_u64,
+        0x6946963B565B2BA9_u64, 0xA67EC91E011A1AA3_u64, 0xF88116863CBA9C94_u64, 0xC3A3C692211C54F8_u64, 0x62E1A84548F3272D_u64, 0x48BFAC565FAEA7DC_u64, 0xE8EC2B5F2EE41403_u64, 0x9F4081DAB8C56846_u64,
+        0x7CF256729DF79A61_u64, 0xEEDE53016ACD39F2_u64, 0xB37E90DF69310B9C_u64, 0x2975A99504C56251_u64, 0xDB9416723DF2752B_u64, 0xC189E2089A8EE16C_u64, 0x39FB60F3D17DB683_u64, 0x2B60DDA991DF7C61_u64,
+        0x5749B739403062BC_u64, 0xBE7379E4398257E7_u64, 0x21ADCE8625781175_u64, 0xF9CA19CB6FF09A4A_u64, 0x27689FD07F617A32_u64, 0x656290D3433D9077_u64, 0xC6FBA3291A5D6372_u64, 0x8794D1A1BB955DB7_u64,
+        0xC8B037815AAB3077_u64, 0x23A02093B144D169_u64, 0x267AD8C80F9CAAD3_u64, 0x5970A90562008761_u64, 0x7419AA839B2828B6_u64, 0x52DC309A0DFE0F33_u64, 0x10564D319FE055B8_u64, 0xAD925EBE373170B0_u64,
+        0xC38F00EF7258C162_u64, 0x19E6805CBF134B02_u64, 0x0A3368CB52E0919A_u64, 0xA543DED14E49D4B6_u64, 0x02CEBE96C5F0A953_u64, 0xFD2F810919931D8D_u64, 0x8181B29C373AD4CC_u64, 0x5A03097378F059D6_u64,
+        0x8E9BE6B21FEC37B7_u64, 0x37BD0C6E705B5DF2_u64, 0x8F1E22E2EB0A869E_u64, 0x1ABDED820C714CF8_u64, 0x0DFC917D8B2BED11_u64, 0xC8D119482CEAD771_u64, 0xEE4B670743624BA5_u64, 0x55FBC8A194BF56E6_u64,
+        0xB21BC8CE5C709989_u64, 0x77ABBB291A079826_u64, 0xABB7E9F7BD35D5BE_u64, 0x479866E6B1A66F15_u64, 0xEC82D785EAE5A82C_u64, 0x2E5D3B0AA0158DC4_u64, 0x2FEC4F8B5A8EFBFE_u64, 0x885293AA43F8645B_u64,
+        0x816C016DC41F2DD7_u64, 0x832F06EAE4DD19ED_u64, 0xAABACAF4C7840BB7_u64, 0x84E0FD247B0B2412_u64, 0x4ECF02BBF93C2647_u64, 0x1ECF4E8FBAB848F8_u64, 0x0A7618244187427A_u64, 0xC51C7D4D76A4B4E0_u64,
+        0x044B7B83127F6CB2_u64, 0x13E717CE99868F93_u64, 0x571466622B4E94E4_u64, 0x6086C22CA249714E_u64, 0x15D182D77B18A109_u64, 0x2B5CBBF2E8092148_u64, 0x33B31EE2E36F9CDD_u64, 0x5BA30DC0C04B381F_u64,
+        0xF855A989B1146877_u64, 0x8F85AEF1E22FCAE8_u64, 0x7BB6F96D4B652FB7_u64, 0xF3A2E2B45A2619C9_u64, 0x049BCF25F4D6260A_u64, 0x26640D4F8E246F16_u64, 0x3B3CA907AB2ABB1A_u64, 0x2F7A9A3D75F59615_u64,
+        0xDB63EB1C6172E7FB_u64, 0x1569AFEE9F7A840D_u64, 0x4FD1B0162E59BC8A_u64, 0x4649428B04932D2B_u64, 0xC504F1AA5E5DEA73_u64, 0xC2CCB667A4325D31_u64, 0x9AFBFDD5AD3A5204_u64, 0xCC07392E231E5553_u64,
+        0x6F4D6D195FD22EBB_u64, 0xEB292B5127FB6E18_u64, 0xFE1F960A70442DED_u64, 0x4447C330B74B5933_u64, 0xD21D4046F002EFAE_u64, 0x8BC90CC78187C57E_u64, 0x4F67494BF7CECEE2_u64, 0xBC33CE353C1DDCFD_u64,
+        0xB891C0A04B0E88F4_u64, 0x8F689F4D0ED5342C_u64, 0xDA219CD14BE3D102_u64, 0x7C1DDBB38629B680_u64, 0x6C70AA3B93C4F52A_u64, 0x29EC9E21B1DD5D9E_u64, 0xCAEEDAC16BE11BBF_u64, 0x35B5110345859BBF_u64,
+        0x439D41EA67B37EBB_u64, 0xC6D8D0B7B4F1486A_u64, 0xC52385DB44D09080_u64, 0x5F99C2E9CA57B52A_u64, 0xE3254276E855D800_u64, 0xEFBD9049DA09502C_u64, 0xE6FF8AC6AD006271_u64, 0x2C930E30CB747267_u64,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512SS_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x2299B3421AA78B66_u64, 0x9AF6550483E02887_u64, 0xF75396A5198053D2_u64, 0x6A4C2C62A4114913_u64, 0xB9BCD8FBBD8E89F4_u64, 0x6707913E4738258D_u64, 0x8A106B7507A77BCC_u64, 0xE43A579E0BBA8730_u64,
+        0xB7C563FA1348DDAF_u64, 0xEE60103F3331DFD3_u64, 0xA6A42565308D4C0B_u64, 0x6B8560EA3475DA0C_u64, 0xBB7B29DA75373747_u64, 0xE9C03D9D02D7BC17_u64, 0x9F97666E85EAD069_u64, 0xDE8A81AF5E145232_u64,
+        0x697CDA1B065747A9_u64, 0xF735E0060F608CB2_u64, 0x10DA8CE4A1A8E759_u64, 0xF946C129F732AB6C_u64, 0x2DFF7EF3E5161074_u64, 0xB7E5D69242B2765A_u64, 0x019E9969F8BA195B_u64, 0x7A438D77E14DE9BE_u64,
+        0x1DABBD8B2C958AA0_u64, 0x6783C3972E5AC208_u64, 0x19EB6C01238B691C_u64, 0x680A487FA7EB87AB_u64, 0xDAE1CD3965A77810_u64, 0x8EFA66FA4D952A14_u64, 0x0FFF2E456D622F1C_u64, 0xB2C8F2A82C1C76F1_u64,
+        0xADBD3D733D607BA3_u64, 0xDC78DB546CE9F837_u64, 0xC4ED93E81A749EB9_u64, 0x29595BE1934B68B9_u64, 0xDD6223A806ABEC2D_u64, 0xF2C7A179A0084220_u64, 0xD2BEDD5FB70C2B52_u64, 0xD986C05A386CB413_u64,
+        0x019CD51ACAA74EE7_u64, 0xF721F9933996169F_u64, 0x8704FD78139999C2_u64, 0x6627F8DCCB88AB61_u64, 0x3D49797D70D779C9_u64, 0xCDF0DB6719AC1C64_u64, 0xC26815ADD73036A9_u64, 0x6FA2B300891AAB35_u64,
+        0xCD4DC42D2AAE7E1E_u64, 0x37F2704CD6617A6C_u64, 0xCEAFDB4F126184FC_u64, 0x91A3BAC743F72D42_u64, 0x42937922BAF293C6_u64, 0x8F777078466F0784_u64, 0x4D8940C83D2CF228_u64, 0xFCB2C61A3DD8D51F_u64,
+        0x8F750D752F97D40A_u64, 0x859E68C1E090274D_u64, 0x2C399B20326CA0CD_u64, 0x4461824B0B36F82C_u64, 0x6B5B01975ED3920D_u64, 0xCC276DADAD8633F8_u64, 0x5AFD37881B412208_u64, 0x5EF82CE8B1519289_u64,
+        0xE9A5F7618903A5DE_u64, 0xCD384EAB30B17653_u64, 0x92C8E45E9A0D6A49_u64, 0x0CE64BD4AF280826_u64, 0x7C9A0633CE0A9868_u64, 0x816369D33905D07C_u64, 0x96FFD583103E2893_u64, 0x16C062F218C869D5_u64,
+        0xAE8E2F6D1FCB7A68_u64, 0x2D3F60CA7C87777F_u64, 0x1AADD6626DF4B9AC_u64, 0xA7696210288B766F_u64, 0x741D75CE44A2AB6B_u64, 0x036B2DD18E87E150_u64, 0x7734CF08CA4E2AFE_u64, 0x47F3EAB5FA66FB61_u64,
+        0x90E7CEA280F46264_u64, 0x68F50D55291A875F_u64, 0x83427020C2C28D5E_u64, 0x1D4258295283946B_u64, 0xA3D3BFD613778713_u64, 0x2DAE1A31F839E360_u64, 0xA87445CBE44648EA_u64, 0x511AA9AF5EFB604E_u64,
+        0xB07CD309970BEB8A_u64, 0x91F29AD233C94622_u64, 0x9F67ECE13736BBDF_u64, 0xEE3CC6E7E87DBB71_u64, 0xA768242C168F6851_u64, 0xAE383312DFA760FD_u64, 0x58602C06A823A9E6_u64, 0x2792585A2B0AA3ED_u64,
+        0x850B0081E5862905_u64, 0x519D084298E248D4_u64, 0xE385F330846D0DAB_u64, 0x8AB346D49B0302B6_u64, 0x1CDBE1568D440BDB_u64, 0x082A71728A7750BA_u64, 0xE5A9F3F9679A1431_u64, 0x979595CF75AF48CA_u64,
+        0x5B867C30DAFCADE3_u64, 0x4747988C60FC0993_u64, 0x44140A294B0B61EC_u64, 0x41CA54DA5A686043_u64, 0x72F1DCC48AACDFBA_u64, 0xC553935E5BB8223B_u64, 0x0AADB7AC6AD84EE0_u64, 0x1A7B899730F11A52_u64,
+        0xCB749A65844B8809_u64, 0x3911C29E51966F4E_u64, 0x27F5C042C91371AA_u64, 0xECEA741091E18679_u64, 0x64341A863869E385_u64, 0x2FEC00E8A077AB4E_u64, 0x750348DD399FECED_u64, 0x198C93702A07D38B_u64,
+        0x8042913D168E1829_u64, 0xBF4CBEC7209CA0D4_u64, 0xE3F645A3C9D3F45D_u64, 0x26E73461B455D391_u64, 0x7A6BE7D7DEC897A3_u64, 0x35B140531EA7726E_u64, 0xA9199C44DE0098EC_u64, 0x5C12859913E23209_u64,
+    ],
+};
+pub(crate) const TABLE_XOSHIRO512SS_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0xB2165ACD673D0F4C_u64, 0x5709AE81563B1B27_u64, 0x778F0F9AB4C07A60_u64, 0x1FD66DC5ABEF1E7A_u64, 0xA82227F2DFE2CC8E_u64, 0xE5CE6F9B4202BC13_u64, 0x57FFD19B12FCADBB_u64, 0x4C0DEC8C478F8209_u64,
+        0x1AA0B540D9829B5D_u64, 0x994EB14A2576A09E_u64, 0x6528A0916ED5D74B_u64, 0x5EC32A90F0A3FF45_u64, 0x37D45DA91ECEEBF9_u64, 0x9E2109D96DEF462A_u64, 0xCC4F155CF17DD0C1_u64, 0xA5FB46F9C81FA385_u64,
+        0xA5086DEF8D9D5E99_u64, 0x473DD8B7D4A9D17E_u64, 0xEDC80820E0A767FF_u64, 0xDD359373568509A0_u64, 0x31342FB1D9F021CD_u64, 0xD38012BE4963D855_u64, 0xE8B5B6B0A56B1DD2_u64, 0x783AF138931D67BA_u64,
+        0xD59D62B300550243_u64, 0x2FC6EEBA6F1B42F9_u64, 0xE653F8AD7891ED17_u64, 0x51A5E8B6C60CBD83_u64, 0xF346E27EA3434C62_u64, 0x6CC8020A85B15B67_u64, 0x684B43EE66D52613_u64, 0x10912B12ADBFCF84_u64,
+        0x8636AD53A86DD56E_u64, 0x44E6A0FF96ACF893_u64, 0xABBF9C5939F0B14E_u64, 0x675FC768BA0E6EDC_u64, 0xD4952A7314009952_u64, 0x43E1A14CD878C021_u64, 0xBE25EC7317C4CBC4_u64, 0x849DBE2EFE781643_u64,
+        0x1108FB59E499E2AD_u64, 0x52221A240514FAC6_u64, 0x3FFCC4C230B46C12_u64, 0x8EC8C93E4D6AD0C6_u64, 0x0298C5E6B08675B4_u64, 0xAFE94393DCE5B9F1_u64, 0xE97DD4FE4AF709D1_u64, 0xFA64D74495FB420E_u64,
+        0xD7446AEFC324F78A_u64, 0xF50420E5328BF0F1_u64, 0x43D7AD3FB929F094_u64, 0x9F6DE50D95B350CE_u64, 0xB79127F6677453BA_u64, 0x452D662589ECFA99_u64, 0x51A1C3D409CC0480_u64, 0xEDEB305D8F8B9981_u64,
+        0x0C6C56F87A952D70_u64, 0x4F90165D85EC082F_u64, 0xA46285969D3EB33B_u64, 0x07F9A6C19E28A250_u64, 0xF14459A9C68F43BD_u64, 0x9A13AF0309103E84_u64, 0xECAFEB59F133DA52_u64, 0xEC982854CD0F44F9_u64,
+        0x1C95EC53869BC5FE_u64, 0x0E4752F720BDDE93_u64, 0x25D14EE2025C2965_u64, 0x97B1E6DA52A32DEB_u64, 0xFEA82DBAA6AE4ACD_u64, 0xA3890019FE8AAAE9_u64, 0x5EA6B721FE142D7E_u64, 0x368ED17D8B761BFD_u64,
+        0x1113E9E47484EC80_u64, 0x65EDCA9995F8FBBD_u64, 0x3285E0E1E176547C_u64, 0x65F31F53D37DD400_u64, 0x927C21EEABF1DDE8_u64, 0xDF302549BEFAD6C3_u64, 0xA36558E73C0505DC_u64, 0x9AF2959478F4DF15_u64,
+        0xCC73E2F1DA8FC060_u64, 0x0FFC1C9874A7DF8A_u64, 0x048C81CCCA60D058_u64, 0x649FC183D7575173_u64, 0x35FFC8BC031A9B47_u64, 0xEF5ADF3CC409659E_u64, 0xE1F23DC07FADB1C7_u64, 0xBED4F9A7EDD19F94_u64,
+        0xB7755B3D0712078D_u64, 0x45CEB562F0423B82_u64, 0xA2BF645CFA75C71E_u64, 0xCC22B8198CD39D9C_u64, 0x3121761BFB0219E0_u64, 0x7C86E073B0CBDF6B_u64, 0xC55F7756F0E863D0_u64, 0x831076AEA99C3506_u64,
+        0x0467A9915E5567CC_u64, 0xEAEA84DC26274A1A_u64, 0x648C1BD715E71912_u64, 0xFD0CF8737D2F27B6_u64, 0x3DC9BEAEFF922327_u64, 0xCE0DC5FA394E2723_u64, 0x692924A5C7D5CAA9_u64, 0x4FAABE35E193F805_u64,
+        0xBB4365A3F1413254_u64, 0x09AC15CFE510A14B_u64, 0xFC6E6BFED4131CB1_u64, 0xA68FB231F56D67F2_u64, 0x2964CE6336DCF419_u64, 0xCD6EC2CE9E06E459_u64, 0x4B5275E3A1CADC11_u64, 0x5ECEB247C53921AE_u64,
+        0x3782F9F6A321D5B4_u64, 0x85192BDC0439B017_u64, 0xFD2765CED66060B6_u64, 0x0F2530BEBC3164F6_u64, 0x2DA19BC40D89CD02_u64, 0x04B793531498ED31_u64, 0x75E9C96C3AE0C4D6_u64, 0x1792368C931323F4_u64,
+        0x8201F12F15D52366_u64, 0xA686ACACB1C32DFE_u64, 0x9482401C94A1AE10_u64, 0xCDB139C0F12D4452_u64, 0xAD8F045FDCC375AB_u64, 0x0817ADAA667C85D3_u64, 0xC5DE522005DBB5F5_u64, 0x8F47E77B5C40A86D_u64,
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO512P_256: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x53CF65E8109C8041_u64, 0x2ADEEC0839B63835_u64), (0x4CEF0E228EBA4D85_u64, 0xB5C74C79DDA21491_u64), (0xE2E0E3813B80710E_u64, 0x8C0D6920924AF414_u64), (0xBDEC2039415A75FC_u64, 0xA8AED75029263FAA_u64),
+        (0xCAAE22653A16D9C3_u64, 0xE000387B49472FB8_u64), (0x297F605661189DE8_u64, 0x48794127B0DE3F17_u64), (0xCE9FC70662752440_u64, 0x356CE20DAE41FFAC_u64), (0xD5BBEFA398100C69_u64, 0x3C17C24434860A42_u64),
+        (0x020E911D55980B4F_u64, 0xC797880FDDA16A6F_u64), (0x9D9B1690F5CD1C30_u64, 0x4FE55BBAB19D1F27_u64), (0x28088C3BC78C69B7_u64, 0xEA006F3AE2F0AD25_u64), (0x132A36CCF962A66C_u64, 0xE188E90F44D4CB48_u64),
+        (0xFD2C3FAA500149A2_u64, 0xD50D409CA1296414_u64), (0xE69E048F4772AA31_u64, 0xA80142CA8B7AEB06_u64), (0x190E9723AE6D942A_u64, 0x8B8F20F65DECA3C4_u64), (0xA04E09039DC02988_u64, 0xC33DA550465BDCCA_u64),
+        (0xCD78CA2F1FEF93C0_u64, 0x39CA798463B4C218_u64), (0xBC865A9CD015182A_u64, 0x98A77820AF30B498_u64), (0xB01A47559CDA0A99_u64, 0x95F1133894844847_u64), (0x6D6B43DCA02B9FA5_u64, 0x3BAE78B03D489386_u64),
+        (0x04F93359E81FCA5B_u64, 0x79432D160BC03612_u64), (0xADE846673151C472_u64, 0x401E10A2C01A10CE_u64), (0xC09A0578D6698A63_u64, 0xC1FEA89FBA89B887_u64), (0xCF59A13867A5E4A3_u64, 0x1915986AEB430558_u64),
+        (0xB4699C0667CE5A97_u64, 0xC1BE68BC3F996618_u64), (0xDC76E23BD9E08CDD_u64, 0x0BDBDAFE7F65578A_u64), (0x55B69AA293BDDF62_u64, 0xD0EDC43B33274340_u64), (0x1F4087E1583BFD9D_u64, 0xD9E54772DB5C7F5A_u64),
+        (0xBB1364662CCE456B_u64, 0x46D71B580955A00C_u64), (0xF0050A55BA80D83C_u64, 0xBE51943E552A770A_u64), (0xC857C3F9BE0D9E62_u64, 0xFCA346A6564DA508_u64), (0xFC73981E50C4A8D9_u64, 0xA0109A32E3B64FFF_u64),
+        (0x4BFF1A45E63E1CD1_u64, 0x214223A41C999550_u64), (0x117DEBBC6D0C90C6_u64, 0x2E73F60DB49B5FEE_u64), (0x7593DC685CC45C28_u64, 0x99BD45775E2AE67A_u64), (0xC734CDE951BEEA03_u64, 0x1C17F2010464CB93_u64),
+        (0x94417CE72CACB377_u64, 0xA109AA7F63F326B6_u64), (0x22186D600AA5A4B2_u64, 0x4DCDB88E91E792C7_u64), (0xB96AE539C0C0F59F_u64, 0x2FEDF19EF17A0AF3_u64), (0x68CD745278CD92E3_u64, 0x66E012E029883BA8_u64),
+        (0x50B3C12A85C8F1E6_u64, 0x85179794AEFC1CD2_u64), (0xC08720819F1E2A1D_u64, 0x5EEA07D185E1108A_u64), (0x61FEB7118CCD5F4C_u64, 0x483DD70FEDEC51CB_u64), (0x6AD275E4FB60E1CE_u64, 0x18CDD6F01C44F02C_u64),
+        (0xF63F936F98BF5B59_u64, 0x8408F2EF0AFC989E_u64), (0xEC8E3F0CA2198B32_u64, 0x7CA7524253FAE941_u64), (0x2E19AD487492B48E_u64, 0x3E17553A25AE6332_u64), (0xE48D171DB484CBA9_u64, 0x12A1DB1DD51B37EE_u64),
+        (0x2CB30E255B396561_u64, 0xC345AB830CB356D6_u64), (0x8F0212905824BBD9_u64, 0x69D17517DD44AC0A_u64), (0x31FE85BD9F387D07_u64, 0xDC8350752740A29D_u64), (0x5FF02E2159167613_u64, 0xFEB1E274561F6139_u64),
+        (0x5FD8E6022F68268A_u64, 0x4391D47C05A33E76_u64), (0xC8EFC221AB7E5253_u64, 0x675B3E862BF47517_u64), (0x77BCF894266992D7_u64, 0xCEBE4FF832292096_u64), (0x519CEE5B42F8BC4D_u64, 0xB35A8984C1DE684F_u64),
+        (0xB776147C19603EE1_u64, 0xB0BBC004A38B6BFC_u64), (0xA02C595F55B31626_u64, 0x4510742FA1C17A09_u64), (0x796E1D8899C93AD1_u64, 0x0915BCDE39EE0C1C_u64), (0x3FF5C189E45DD253_u64, 0x9122E1A427F8AA8B_u64),
+        (0x636163D60E4D3F03_u64, 0x2EA36ECBA1A3FA15_u64), (0x030855502228C334_u64, 0xC510E4377D5982C2_u64), (0xA7C310109454E122_u64, 0x392273CDC03842C8_u64), (0x5D9516072F45C5D1_u64, 0x4A7A85A2BFDFCF7A_u64),
+        (0x43B9CD5C134BA160_u64, 0xBC5E87B1362100A4_u64), (0xD4654B0146528F22_u64, 0x7276896F1A4CE262_u64), (0x5D6B30A280CC32EA_u64, 0x7E9526045E3F4EF2_u64), (0x5F9736E7BEBE5D5F_u64, 0xE66EDA2489031FE3_u64),
+        (0xBFE3DC6BE849CA06_u64, 0x478CDD789127AEC3_u64), (0xB9C3FF81EF511C04_u64, 0xFBB8A5F4E653C759_u64), (0xEAEC52B822A413C6_u64, 0xBDE406FBF19587E5_u64), (0x8D366570A2EA44C7_u64, 0x115F946C589981DE_u64),
+        (0x986DA37BA50F738E_u64, 0xEA6B6EE04F70B673_u64), (0x7F61D82EEA994157_u64, 0x7AF5D82BD4EEBE24_u64), (0xB41083E84C437217_u64, 0xC4048C1DF90A8B15_u64), (0x0CBD6B0563CB305F_u64, 0x18B727C2008A37E0_u64),
+        (0x15F3595290F15FC7_u64, 0x433ACE338C7735FC_u64), (0x242D901D16C5B21F_u64, 0x710429D89B35C52E_u64), (0xD4812B46F2C03126_u64, 0xF7CD029C74C9D702_u64), (0x1071CD8588746435_u64, 0xE973DAC5CA22F8EA_u64),
+        (0x961092CC07CC42A5_u64, 0x787BA70A103581B2_u64), (0x682B1A6CEE858BFD_u64, 0x7DC4BD60FB7A3FC4_u64), (0x47D4E7B9E6B869BA_u64, 0xC6E9139B06F09A53_u64), (0x5D76E38846CF4C63_u64, 0x98A7362D9983577E_u64),
+        (0x75B38725DF45D5B9_u64, 0xE79469DABE43C5FA_u64), (0xBCFD7AD32CF1B90F_u64, 0x6D1736AD9640A576_u64), (0x1CC10BE0E84A5EAE_u64, 0xAB2F006034E99971_u64), (0xA58BBEEB12F0A5A2_u64, 0x49300E95C77A0A69_u64),
+        (0x5AD5F36DC3F9E071_u64, 0x69A6D9DA16CAB79A_u64), (0x4F6426C75DB267FE_u64, 0x153294808711DB67_u64), (0x4AAAF92493152EFD_u64, 0x168A1D107F63CC0D_u64), (0x315BF59269038083_u64, 0x4BC7D2249589BF8F_u64),
+        (0xE0CD5B03FC3D326C_u64, 0xD7E33066FE443206_u64), (0x187D5B0C06DF8CB9_u64, 0xA8BA16375DADFC90_u64), (0x5E0F3CA1E6F67F86_u64, 0xB388520A2BE460F9_u64), (0xB252D398CA484EC7_u64, 0x24D400F870F2A02A_u64),
+        (0xFC3AFAC215AC1A13_u64, 0x7354D23E25175EBA_u64), (0x05D8E455926430CC_u64, 0x2159ED4BF01EB5F3_u64), (0xDDCA9C47350B060F_u64, 0x4C33B8A9FF1A8F23_u64), (0x5DC0F3A0ACBB23C0_u64, 0x630A347D30BCC09C_u64),
+        (0xD6F813ED26CB1617_u64, 0x00D5F4ABDE04C13E_u64), (0x4EF27F13844BF09A_u64, 0x33955BEDB2E59C19_u64), (0x9C31F5FAF269FA63_u64, 0xFDC21A59D507F1B0_u64), (0xCEC55A8F907DFBE0_u64, 0x00517E014B733FA9_u64),
+        (0x17E342CC3ED3987E_u64, 0xA692C37ED4C91A17_u64), (0x254C63B75409020E_u64, 0x335166BD5404E1F9_u64), (0x126F2FBDFE15A6DB_u64, 0x89723DFFA21CEAE7_u64), (0xA9C3B819C515F455_u64, 0xBCDAD65347775ACB_u64),
+        (0x29318C9E55698B41_u64, 0x47DDFF559FA2E027_u64), (0xF97BEE8AAF395998_u64, 0xD888C0E4DE73AC92_u64), (0xE80513CE84916830_u64, 0x53884F76177E3180_u64), (0x9FA104FBCBC51C6D_u64, 0xDA2921DAFC8D572D_u64),
+        (0x894F294F4A70B5BE_u64, 0xA8A14884C659B80B_u64), (0x35C82469B64A446D_u64, 0x7DE1AC366E7C6704_u64), (0x8F4E929FAA4C907B_u64, 0x51BAF848B2C064B3_u64), (0x1412BEA85EDECBC0_u64, 0xE22B728EECF3BB89_u64),
+        (0xA9D178864411E9D3_u64, 0xAAAAD5D679FEE5E7_u64), (0x566683B51A152BC9_u64, 0x0A3AF673D2E65F5A_u64), (0x312F30BAB8BD020F_u64, 0x9DB85DA923E09DE5_u64), (0x3F479788D1984AD0_u64, 0x41C7F1CAB00AF670_u64),
+        (0x96F29984E1E98E68_u64, 0x3CEF4309BE9FCF75_u64), (0x4F0666DD7C0E1E30_u64, 0x70D607E748C608DB_u64), (0x47E8FA7F05C6DEF2_u64, 0x06F8645669F8E5C6_u64), (0xA41B416DAEB39FF7_u64, 0x613A3559745ED5DB_u64),
+        (0x3B75E32C601D8F4F_u64, 0xB63BA10664B4139C_u64), (0x7BD6B7046B41443D_u64, 0xB42FE71E83D9AAFA_u64), (0x189089D0089AA661_u64, 0xF658DE43F2A0189B_u64), (0x75144F64727BF95F_u64, 0xD5824429179616C7_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO512P_384: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x4E6483F7434A58D8_u64, 0x56CBBBD571269FDA_u64), (0x5F152F9EE1687B3D_u64, 0xBEEE3CD7EB1A4CF6_u64), (0x1F8B07707B001580_u64, 0x8EC0129530D51847_u64), (0x837ECA9A797CBFF4_u64, 0x48DF49B43D6CD44E_u64),
+        (0x81AF19A6B33C36D0_u64, 0xE9B2D8D1CE46774B_u64), (0x44DE0A80EC8E3F62_u64, 0x2D6AB083A7DB5CE2_u64), (0xAF2B1D331271ED45_u64, 0xA5D425A38FE22538_u64), (0x5305DC88618000C0_u64, 0x49DC4A0CD03266DB_u64),
+        (0x8C29C4A4C6E2D92B_u64, 0xA504478833B3CCAD_u64), (0x97368DFF210BABAE_u64, 0x2744A9FD0E20C745_u64), (0xD650736B2E8FA0A9_u64, 0xEC1A379F91CC5965_u64), (0xA51547BF0DA96FE7_u64, 0xBDC0529E107CE556_u64),
+        (0xDC5C3F503A52E4B4_u64, 0x4F2F5CB5CF47DF14_u64), (0xBD33E1247EBF53CF_u64, 0x7776F73BD8A4E874_u64), (0x4AEE14CA60A074D7_u64, 0xB0366FD62C8D3BCC_u64), (0x9395974C24974363_u64, 0x82F2C93954FCAF01_u64),
+        (0x24ECAC5BF5D23B37_u64, 0x7901F38D9255A49C_u64), (0xE2423523BC4E88EE_u64, 0xD45A24360778F52E_u64), (0xB68E362F9DDE50F1_u64, 0x87026DFA2AD9D88A_u64), (0xA697D54DF9D3C327_u64, 0x217515080D0181E5_u64),
+        (0xE7A3F69284EC4033_u64, 0xB46C717A60B14213_u64), (0xED724EEE70AECC11_u64, 0x05BEBEEDF119E0A2_u64), (0x9662A930F3F2BBC9_u64, 0x3CE214042B6CBFE3_u64), (0xE182BD3ADDBE82A4_u64, 0x1479BEDF20C96EA1_u64),
+        (0x00B537A77E65C4CA_u64, 0x3A1C3C7700960531_u64), (0xC9B74C674C33BC0F_u64, 0x6E7E2C0564EB932E_u64), (0x81D1994666A3E77E_u64, 0xC3599CCCCC423834_u64), (0x0888E9272D92DA86_u64, 0x65C054B27D10108D_u64),
+        (0x820F6D0134FA268B_u64, 0xAA2C0C2121DD675A_u64), (0xB266B647631BDCDA_u64, 0x1F308A2F810BCEB0_u64), (0x16C3C2AD3E131D9B_u64, 0x0A8C98E9FDD34CB5_u64), (0x39C7EC2238231AB3_u64, 0x46AF8A04C77564CD_u64),
+        (0x98B2859ECDF71A96_u64, 0xD536800957777804_u64), (0xE1CE2FA77EFCD8D2_u64, 0x5293C52B7B51B4FE_u64), (0xBF21A8CB7DCDA5BC_u64, 0x25AC0D1C43493ED9_u64), (0x8FBEA303AB1CBC38_u64, 0x3DAB8F5CC86589D2_u64),
+        (0x13F4CB7D99BB6F17_u64, 0x408B396E1215722F_u64), (0x946B275999D35F83_u64, 0x425CD90A10B713BB_u64), (0x835AB05E4026D4E8_u64, 0xB18C75BBA15B9C2E_u64), (0xD401C45745A65468_u64, 0x9C993892E70DAABF_u64),
+        (0xE7CF3BD460F59235_u64, 0x9EE732A1EACE35A5_u64), (0x6AB7CCD39FD64D63_u64, 0x093D3F602C63C81F_u64), (0x922DADC40300A797_u64, 0x61C19A1098048BA1_u64), (0xD3497BCAC92DE309_u64, 0x89BF110BB89CB617_u64),
+        (0x5661FF6D37FACB23_u64, 0x565C2B618E2C49E7_u64), (0xEF07F1FF53B7F777_u64, 0x7D8032DA6076D869_u64), (0xF7095885E9A67B25_u64, 0x4E3A54F629A11F25_u64), (0x25A63CB64BCE4E6C_u64, 0x1FBE5212A56D5104_u64),
+        (0xA831D14027346BA1_u64, 0x3B6F5B2B810E50F2_u64), (0xFD4D7F1328D02DF0_u64, 0xB82673406B6650DF_u64), (0x7E2EFA47ED273AD6_u64, 0x665371148A73CAAF_u64), (0x9D16917CD983C05A_u64, 0x863801D46AFCD70D_u64),
+        (0x5AD92DEB4C0BE2C1_u64, 0x0C2BC1E00C2E51B1_u64), (0xA22228A82439BAE3_u64, 0xB8AEF8C2C9DEB6C8_u64), (0x3C486C8A53F7CD38_u64, 0x9BBF75A36EF46F3A_u64), (0x17C969787D4E466E_u64, 0x0CAE8EBDF082EA07_u64),
+        (0x2CC6A88E88B60AEE_u64, 0x6661A194C508D199_u64), (0xD5B534E568FFDD9B_u64, 0x7A62AD970D0B83BC_u64), (0x10AEF96073084340_u64, 0x80197EDF273A3AC2_u64), (0x6ACFD39053DA802E_u64, 0x47FDD86F04D7C277_u64),
+        (0x95096AE18B043F4B_u64, 0x36A34B08404A2E4E_u64), (0xD375812666E2EADD_u64, 0xE5BAFC58BA2309F7_u64), (0x5432B4FD3DD8DF0D_u64, 0x4341CC7E3BCF4053_u64), (0x05C8B42B44C0A9A1_u64, 0x8A18AB4A3116D01A_u64),
+        (0xC64287768F0EC679_u64, 0x2C511A53D642C270_u64), (0xEA47B5FF508315FA_u64, 0x165F3793BBDFDA86_u64), (0x54988599BDAA49DA_u64, 0x111E944887DAC7F9_u64), (0x1E112CE6897A6EC7_u64, 0x9324C2AB6350C719_u64),
+        (0xC537A80EC1E92A51_u64, 0xCB8036E650DDA29B_u64), (0x9EF21FA6B38C8047_u64, 0xC0E1F5BF387C8989_u64), (0x62FBCE41CFD9FDA5_u64, 0xBC02395BFFF7CA39_u64), (0x4CD4485954562151_u64, 0xABCE98634E95D2F7_u64),
+        (0xEA2BC3EFD509ABF3_u64, 0x85D88983D27772A7_u64), (0xC19326CCA91F4D35_u64, 0x77377AA676066FAC_u64), (0x339497956B8BD002_u64, 0xDDE8DC237E7B84E7_u64), (0x7B9B5685F3D8147F_u64, 0x1BFB352D6B826107_u64),
+        (0x5BC2FC46FB2E4F03_u64, 0x98740030FDFDDF95_u64), (0x6DEF7ACD15B5FF7E_u64, 0x53843E5FCE56FDFD_u64), (0x36F06CB75E1921A1_u64, 0x1D07F098C77139CF_u64), (0x155B9362856FB0C3_u64, 0x1C999A6A7E53495F_u64),
+        (0xC6CFC5FEEAD55196_u64, 0x8E6C94A8C9025F68_u64), (0x6EE1FA22DD9E1CE2_u64, 0xF1E3CC53C299C620_u64), (0x5857589F91EA9EF8_u64, 0xB4672A630A841D32_u64), (0xB192604FCC05CBF9_u64, 0xCD3574D3440BCDDC_u64),
+        (0x0C4016F73B08389F_u64, 0x32609F61F9A61B55_u64), (0xC9281AE993FF1AE8_u64, 0xB596269911AE5A6C_u64), (0x020FF1B1990B43C3_u64, 0xCB000D15E7A8FECA_u64), (0x41C33195C5C390C4_u64, 0x45A5376C7DD45DC7_u64),
+        (0x27ADF574616327B6_u64, 0x271B640FB4D59398_u64), (0x3F31A0EA6DAC97C9_u64, 0x905F53CAA4675BB0_u64), (0xCCA524BCE2F609D4_u64, 0xBE986656BDE4E87D_u64), (0xB938E4DA43627DFF_u64, 0xAE0DD8401AB4CD9E_u64),
+        (0xD40EE12FE01C70B4_u64, 0x74731F61B8660FBD_u64), (0xA6D133B655481AF7_u64, 0x53BFE86912223EB2_u64), (0x267B69524D182488_u64, 0x3ED24157AAC1C946_u64), (0x7747459AC43E37AA_u64, 0xF86CB83DD06C0CB6_u64),
+        (0xA3AD1E2E60DF67D3_u64, 0xA811DE6C1F15C4FF_u64), (0x1088F50CF9802C8D_u64, 0x9A5C667146D11F1E_u64), (0x9D83280867FDA539_u64, 0xB4AB635929049267_u64), (0x2A5E53F8F35BEB80_u64, 0x6BB97CB05A81274B_u64),
+        (0x1236882BDBC4EF6C_u64, 0x2B0AFA27616D1581_u64), (0xAB2D6DA8753492CF_u64, 0xFEECC959440C4B03_u64), (0x7871403CCDB2CFC6_u64, 0x617919E06691F83E_u64), (0xA69FF9D4159B2381_u64, 0xEEF54C2CA5174A76_u64),
+        (0x41C806739EBD0466_u64, 0xC8FF7151F1E75B83_u64), (0xDDF47AD04A4A4DCD_u64, 0x2CD8CCFFDA50E556_u64), (0x82D6CA81127E85F2_u64, 0xA84EA623CA43CC3D_u64), (0x0246FB7840BDF5DB_u64, 0x2B833966CFCB53A1_u64),
+        (0xB3D0ADC51322F5FD_u64, 0xE74BB0807579BB27_u64), (0x6C11E39761D77EC8_u64, 0x4DB6488B2DDA7170_u64), (0x9C61695D3B4FEC37_u64, 0x01123201E7111D83_u64), (0x6F162B245ACFFA10_u64, 0x532547E80FCA9469_u64),
+        (0x82BF736A3C2C7BCC_u64, 0x9C147B222B45CF59_u64), (0x8F8B0B9D7BD1034F_u64, 0xD1F7450F6B633AF9_u64), (0xED442206D400EA57_u64, 0xB7E5D6C9B5D72836_u64), (0x4D4B9DBAF09E2187_u64, 0xE6E20A6700453768_u64),
+        (0x9AEC5E9353BCB7A2_u64, 0x228A85BA00A3B0B8_u64), (0x537EBAE14818D55E_u64, 0x70C16083860A7C6F_u64), (0xB8CAB9AFA584E9D9_u64, 0x2153F491949339BA_u64), (0x1A552D8B879019B8_u64, 0xEB0FAC4842CC1DFA_u64),
+        (0xC5C99DAA94C58289_u64, 0xDECEE4BE688D3B8A_u64), (0x53F54E51DC11E494_u64, 0x255D7F3EDFE36548_u64), (0x3D65B6F6B71E0CAB_u64, 0x65D5256D9563234A_u64), (0xAA2332AFC8E80002_u64, 0x35524F65B82BBF8D_u64),
+        (0xA5EED4B1A4FEAE9C_u64, 0xA8F2B06397C40B68_u64), (0x8D8011E3A2DE95D4_u64, 0xD7097A6B7814777D_u64), (0x0B3A71F23283B157_u64, 0xD0490A6C74361904_u64), (0x18AD89D1EA8855F9_u64, 0x1699FF346AB166D5_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO512PP_256: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x1CEB2E365100BADC_u64, 0xB20A196C50A0BF29_u64), (0x92428E53BFD32BDE_u64, 0x59846BDE1A4C64B5_u64), (0x7D2AD24F2205A4B0_u64, 0x406041CE4218F87C_u64), (0x051E23805497537C_u64, 0x8183A978739152B2_u64),
+        (0x3E6F4C0CE10FAA9D_u64, 0x5C76B4D0AA1C26C5_u64), (0x41A5576C1234FE62_u64, 0x8B9093B944596BD7_u64), (0xD19756CD55227BF2_u64, 0xB6A9C7367EE5D715_u64), (0x6DD207D5DC3F495D_u64, 0x936D07AF2B5F18C1_u64),
+        (0x3B6351B5374E1524_u64, 0x933A5C0C3BA192AD_u64), (0xF06A26A4B8884654_u64, 0x7CB6962F963CE897_u64), (0x248A976D225ED925_u64, 0xA90D114574F29D49_u64), (0x389E2CFF193A2E3F_u64, 0x39949384266D805B_u64),
+        (0xC8B2674C3107E034_u64, 0x6747DD540F632984_u64), (0x97B20BE4AD294935_u64, 0x4A5D6D9116C60F4E_u64), (0x298923C84B707606_u64, 0x0E48CB7754335F13_u64), (0x4AA37133D070C30F_u64, 0xFC195EE78E2C6551_u64),
+        (0x0EAE50899F86555E_u64, 0x19D1FDD825EF77DE_u64), (0x499C3F00BEB25AC5_u64, 0x1705F2AFCEBEABA7_u64), (0xE24F60EB35D1B711_u64, 0x4029FE1AEF7C836E_u64), (0xEC06E1B3A51C2335_u64, 0x9ED5FA2C24073FDB_u64),
+        (0x709C5472174F1D03_u64, 0x53EB2702737828CD_u64), (0x8DEFBF3466D68A58_u64, 0x8A8FACCF4DC30874_u64), (0xCA56EAD4CC7E56AD_u64, 0xF489340247881D7D_u64), (0xDE528E9E474C3ACF_u64, 0x5122B193DD218539_u64),
+        (0x44C6CD277479D2FB_u64, 0xCB1FCB068A8F83F2_u64), (0xEBF249081C0DB51A_u64, 0xA041A22BDF6B60D3_u64), (0x19334BD79D4360E0_u64, 0x4117EF8AEE438C06_u64), (0x82386A6BBD40B316_u64, 0x5FBE76B08F3BC1DB_u64),
+        (0x0E0F1F371300702C_u64, 0xD426820FF167FDE7_u64), (0x8198ED37B414B15B_u64, 0x03AD0608FBF44BCB_u64), (0xF939BD8665528801_u64, 0x6809C682E8FA393D_u64), (0x03AABCA906CAAF89_u64, 0x9CB2331986379BF0_u64),
+        (0x4F64425A0FE719A3_u64, 0xDF21CAF204EABEB0_u64), (0x686DD29ED6BB7866_u64, 0x7F2830E27C5A3AE7_u64), (0x80F1CB6807536389_u64, 0x227C03B0A3FB22A1_u64), (0x3C8AAABA89AF6755_u64, 0x31C736D60C1557CF_u64),
+        (0x78FBF4148A287547_u64, 0x74D3F415C5E9327F_u64), (0x8D961DE07D551EED_u64, 0x7BA04DBA4CED27B3_u64), (0x79CE463AD05709CE_u64, 0x1ACCCAF6C4486119_u64), (0xB06A6ECBA125E235_u64, 0x95F8FB7F46130EB3_u64),
+        (0xCC12C883126BE8B0_u64, 0xE03E3E0EC85387F3_u64), (0x6260447E7B60981A_u64, 0x1C73CA6CFA0C033A_u64), (0x47730CD07ACEB3E0_u64, 0x844AD3F887E1E70C_u64), (0x59A7986118203BE1_u64, 0x8DF5468D2F41D587_u64),
+        (0x6382679F18D111F5_u64, 0x3944FDB8203AFA9B_u64), (0x9A8274C8AB7F596A_u64, 0xD26BA787D0D6E8CB_u64), (0xCBFA43458B96D88C_u64, 0x9E649241B8ED960F_u64), (0x2779C93CB0BF6BA3_u64, 0xAF7A4BA21096700C_u64),
+        (0xC7DABF2F346D9267_u64, 0xACA96D65FE613BEC_u64), (0xF7C2227E09FB11D2_u64, 0xA1147462E5EEF924_u64), (0xD31E879E77D3180A_u64, 0x4619B5CC4B98D582_u64), (0xC6BE72360B95116B_u64, 0x845EB0D61042E5A0_u64),
+        (0x1B7BFF5EC52F9352_u64, 0xF5FFAB42465FC0A8_u64), (0x3F0EE33FDB7E6546_u64, 0x9413DEDB4E6D8B38_u64), (0xA9B9C32DFC26DCFC_u64, 0xB50CEE8403684BDE_u64), (0xF4F22E87DBACB19B_u64, 0x45A8F3A6D2587F75_u64),
+        (0x8727750362FA0903_u64, 0x9E097D93DB80D647_u64), (0xF86DD815FE441903_u64, 0x563B8FE5A019F423_u64), (0xB6C4D81271434B9B_u64, 0x46DA7270053EB1E2_u64), (0xD31C1245FD7098D6_u64, 0x172FA85E31C64565_u64),
+        (0x51A95D71238B5A0E_u64, 0x0074D3302D849857_u64), (0xA557A10514BA9B01_u64, 0x41F6A4B7E00EAB91_u64), (0xD4FA79D16BF61E3B_u64, 0xDFB90A040E6C0573_u64), (0x841AF256DD367B27_u64, 0x98118731E2C646F4_u64),
+        (0xF98EC65F491D57D5_u64, 0xDCC7362972E703B9_u64), (0x007DCBF2E5B97B9C_u64, 0xAAA87028316F2754_u64), (0x810CC3B11475031B_u64, 0x4F3ABE599B9243CB_u64), (0xD02D4EADF217C7CC_u64, 0xF51075A894155A06_u64),
+        (0xF212AC1B6BEB99A4_u64, 0x540497BD2754BED1_u64), (0x9CBCB40CF25C480F_u64, 0xB2CF476419A36D29_u64), (0x94F9C4DF7F48DEEC_u64, 0x6BB7A961B66A38BE_u64), (0x783401195C407453_u64, 0xA28B29E88D4EE546_u64),
+        (0x68FEAD47B9AA87B7_u64, 0x63683E0FD1D51D21_u64), (0x8FA161F76768F016_u64, 0x365756DC8F16E3E6_u64), (0x5379468E8912F17A_u64, 0xC4F8D760B842700A_u64), (0x63E2F12D8F7F47AA_u64, 0x7CD62D9204850C3A_u64),
+        (0x67BDADC9D10DDC18_u64, 0xF24A4869C60A1F0C_u64), (0x00CEBF2F6F7F7697_u64, 0xCBBF0865F16BC8B3_u64), (0x3B8C5AAC4D7196BD_u64, 0xEE655C5BF1FAA656_u64), (0x28C5C193B4D9E078_u64, 0xDCBE1A40B2AB0BEE_u64),
+        (0x7A920FF6B95515EE_u64, 0x43D8677200951F49_u64), (0xAFA1536F08BD905D_u64, 0x0D3607B87C62CC41_u64), (0xA76859AC87F9E778_u64, 0x8B415476077921DC_u64), (0xD2CDC4EB8116618D_u64, 0xD586B506E6C9A58F_u64),
+        (0xCA6218E4823A45EE_u64, 0xD10B0F4E86BD4F4D_u64), (0xCCC2E2F162F0470F_u64, 0x52C1B88ADF26F723_u64), (0x4ED7CF497B9380F3_u64, 0x4DFE7DCB5D1CEC3C_u64), (0xB895BB2BD3EB86DB_u64, 0x73FD39477B07A37B_u64),
+        (0xF0946322DD7DD0DB_u64, 0x425384F2A8C68A6A_u64), (0xEA0965FEEAC061D7_u64, 0xB03472FC965627BA_u64), (0x7D736E2427240A8E_u64, 0x753BB46E724E6192_u64), (0x171BB36B65DAB440_u64, 0x84166AFADBABAC92_u64),
+        (0x69D9CF79CD459C1C_u64, 0x72B24846428A03DB_u64), (0xFC1C642DD1EB8858_u64, 0xDD2B93A281A3E255_u64), (0xFB18223CE20469B5_u64, 0x672AC852ABD6E16A_u64), (0x34DF51580BEBDD1F_u64, 0x1F288D5976F603ED_u64),
+        (0xEE63A5C39314370B_u64, 0xD2626DC3240F0FE4_u64), (0x05B8FF529F8DB67A_u64, 0x11E8F3C0DD93EC83_u64), (0xC74E321201472620_u64, 0x61420288FD5C8C09_u64), (0x107891C956ECFEC9_u64, 0x97495AD3C89C7222_u64),
+        (0xB84A6D1971345893_u64, 0xF1E5E31064C792E6_u64), (0xACE7BC843DA4E0FC_u64, 0xBAA97D31B764A8F3_u64), (0xE38490F7DA8E7FAB_u64, 0x199254F6150C1ADA_u64), (0xEE0D306A7B94C7A8_u64, 0x756201C65B92AC46_u64),
+        (0x913E82B2AC4CD61F_u64, 0x3CB45F3A4F0DDED8_u64), (0x11CBEB003B11BADB_u64, 0x7D755796D041824A_u64), (0x24B3CAF80F90C6D6_u64, 0x1C7F7C651E2BD869_u64), (0x51DAE525B6F2BE44_u64, 0xF5B63ED5C91C77AE_u64),
+        (0x09F2C5D0B43BBAEB_u64, 0x377A30067029132B_u64), (0x1546843499B0B32F_u64, 0xFD2ECE30E91ADE03_u64), (0x5BC04982BB53940E_u64, 0xDE2B2C741B945987_u64), (0x805CD91965F351C8_u64, 0x69839C8B98607B93_u64),
+        (0xE01C13ADC410190C_u64, 0x4A24CDC69C2196BA_u64), (0x588C5966125E3C27_u64, 0xE406BF54B8B54A17_u64), (0xF4D653BAC7A5D694_u64, 0x48C51B93D94BA8C3_u64), (0x26F82C688C2485C7_u64, 0x66BDE31B66C5FF5E_u64),
+        (0x21A2DD8D5EDB9081_u64, 0x9FBBE27957ECA67D_u64), (0x94A2989C8499F6B1_u64, 0x69EE47BFB3802BA2_u64), (0x7F52D8EA12E4EBE5_u64, 0x7B2A9868F148B906_u64), (0xAA41F0688B630F1E_u64, 0x108ADD3C76B98202_u64),
+        (0x8DB526958F18ED41_u64, 0x56E8A95E6401D24E_u64), (0xD99FB14319AD4AAB_u64, 0x36E4A995DA932121_u64), (0x553B144767D315ED_u64, 0x1F8DB7725D710E76_u64), (0x0B49C630E2D09966_u64, 0x9668BFC30B0B6711_u64),
+        (0x1EDD5A3C4FBA3730_u64, 0xBE30268C2C02A9A9_u64), (0x1A48F5D84FFEC23C_u64, 0x60F542DD9DC20E9A_u64), (0xA009D5594EE156DE_u64, 0x2411C58A81188DCB_u64), (0x86A4B3741721C2FD_u64, 0x5D4B901AE836A062_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO512PP_384: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x5FADE33B046A9404_u64, 0x5F5FAB0F505A5A93_u64), (0x808668BFBCDA7503_u64, 0x0A052FEE6A53794D_u64), (0xEBBC9FD24CA1CA0C_u64, 0x2982B02E3EDB7574_u64), (0xA662B0A02E03A03E_u64, 0x768270C5E0E6E82C_u64),
+        (0x962EFC0042773B97_u64, 0xF0DCA048B13368DB_u64), (0x5019716BC5CD0E96_u64, 0xF701F54A20A82B69_u64), (0xCB23AEE5C0479CBC_u64, 0x020A8B263FAAB819_u64), (0xBB062831723FA307_u64, 0xA482E7BE6E77AF8B_u64),
+        (0x019FFC53B875DD32_u64, 0xCA1B8347297C71E2_u64), (0x4FBF62CFAA631FCC_u64, 0x633D82F38E3DA941_u64), (0x57D65901D06038C0_u64, 0x05FA275DFAC9288C_u64), (0x962A732DA8230FCE_u64, 0x9F04102C7D3C47CD_u64),
+        (0x4C66A90BD55C1A72_u64, 0x929D789A5FD97FC3_u64), (0xD51E3322E3C6E4D2_u64, 0xA9C3F4E317D4BB8A_u64), (0x3181391732A276C3_u64, 0x063D82E8999243CF_u64), (0x253E96310A34FBC3_u64, 0x93F56D4C06513362_u64),
+        (0xF80C5E9C6A3CD1AF_u64, 0x2F8AC0718FAC463C_u64), (0x3F0A85D995FBEFE4_u64, 0x0EEFBF0E196A8516_u64), (0xB159640D976543AC_u64, 0x58D070B1AAEC128B_u64), (0x938DBA407189B4BF_u64, 0xA6BA024809057427_u64),
+        (0xC166E4F60F72F45F_u64, 0x9A23A33268EF9360_u64), (0xD8EE2AEEBC3AC19E_u64, 0x1E927372A40943D1_u64), (0x4615F635FBE13213_u64, 0x22D3EDFA55766E0D_u64), (0x225E097DB2787D2E_u64, 0x123B9F653A0F9509_u64),
+        (0x04BFF9CAC0884366_u64, 0x434470EBBBA314E6_u64), (0x4B3BC0B545DE1AD8_u64, 0x102BDA9BA47C8A41_u64), (0xD85480BA2FF4AD51_u64, 0x56B8711A3CC5D7A0_u64), (0x7BAB426CDAFB7615_u64, 0x450C156D1C15A3D7_u64),
+        (0x096117BC4CF08DC2_u64, 0xEB5DC4F4FCEAF53C_u64), (0xFED9C159488DF4D9_u64, 0x299DD931F347F810_u64), (0xF84DAA38481E79FD_u64, 0xAE492CB06527F259_u64), (0xC2A6014FCEDEB955_u64, 0xE3DD36C7F8A1EB53_u64),
+        (0xC5ECE29D7DB298A9_u64, 0x61183295E5753ADA_u64), (0xB7ECCC26102E2E5E_u64, 0x121F968FD7DAA41F_u64), (0xF3D10DD7B73E897C_u64, 0x9A575ED4296EA641_u64), (0xF8EDE8A018A38950_u64, 0x82D4B59A095D0E5B_u64),
+        (0x15938BA0A34A7579_u64, 0x8F927621668AD58F_u64), (0x9DD7F614EB97FA8F_u64, 0x90F12881E8B7D4DF_u64), (0xA4E2610620C78279_u64, 0x142E18BA34AD108E_u64), (0x4632CC077114FE5F_u64, 0xD55B9A6309EC40FC_u64),
+        (0x86C8B196CD458A76_u64, 0x01DB193EB4478B8B_u64), (0x699D8C3F3612ACEC_u64, 0xA8CEA44F50122B9E_u64), (0xAEB80FBCBB6677E2_u64, 0xDBCCC17AC30B029F_u64), (0x9DE21EF272CB0F0A_u64, 0x439D97EA5B66D00F_u64),
+        (0x78C73BF851BA55FC_u64, 0x7E9BCDE25A683BB1_u64), (0x2E58D259D79FAE1F_u64, 0xBDD37A5CF6B053EC_u64), (0x94A481C80EF048D7_u64, 0xDA7A3AACBA084DA6_u64), (0xE26401AF18316FB0_u64, 0x0AFDF8A4589A26EB_u64),
+        (0x603C907C7D2E8F2E_u64, 0xC9B30FAEADA14F39_u64), (0xD7F7FAFF78569D42_u64, 0xEB691CD70E72CF6C_u64), (0x67213430DDD44C8B_u64, 0x96C40FECBAD9D85D_u64), (0x16008E12B29478E5_u64, 0x4C2073BF8187DD62_u64),
+        (0x93C6A56D1D56FEEF_u64, 0xDA21BAEC2A2495C7_u64), (0x8689FA71EA0ED234_u64, 0x5D996FB48F0682CD_u64), (0x61B344653CAFE104_u64, 0x20C28786CF1E18F0_u64), (0xA967C522AB9D7BEE_u64, 0xE38FF127D8F9C0C8_u64),
+        (0x4BE3E60E03FE701C_u64, 0x32202935F410E0B7_u64), (0x6CE45F8D65CB19CA_u64, 0x45E2B6145649D242_u64), (0xDA9519BDB8D96FE5_u64, 0x35D9971886C13B37_u64), (0x1DBD9A612EC33FFA_u64, 0x2AB7FED63C4A478C_u64),
+        (0x8067631C621EEC44_u64, 0x40EF48DD9E9F9C4A_u64), (0xB1B9BDAE2137F364_u64, 0xC99D61DD41F9651D_u64), (0xF855229B175E11FF_u64, 0xCF58777D08D2ACE3_u64), (0x9548DC6E7D7C99FB_u64, 0xB525F14A0437A28A_u64),
+        (0x0954B09CD8AB2386_u64, 0x0A3C14C88A9D8002_u64), (0xE53C257B36CC7D4E_u64, 0x61225DD226DD7575_u64), (0xD1EA1768E83D22E9_u64, 0x232106E7BC509A3E_u64), (0xE4164B96D9CF4C83_u64, 0x78AC91A986268C4B_u64),
+        (0xF70095318EC44CB9_u64, 0x66879F7C9CE4BE34_u64), (0x09A82C426BD3FC7C_u64, 0x2F41D8C84B84D0FE_u64), (0x091F764724EE72E6_u64, 0x53BE6A6F126C35D4_u64), (0xB14D953AC8534AE2_u64, 0xF6C10A46CCD570FA_u64),
+        (0x38B2E531D71E3040_u64, 0x6AAEFAAAA9034C07_u64), (0x392D8BA6AAB289AD_u64, 0x98834CFF5B0F4D0F_u64), (0x3546E88DFB26E281_u64, 0x4EC1A1E3B37DC580_u64), (0xFFF2A744FE50BEE6_u64, 0x764E3E5956A19119_u64),
+        (0x88001D1F28D022D0_u64, 0x322C4CE2BC13829C_u64), (0xA8B1119BAAA75DEF_u64, 0x1098FD34F53776F3_u64), (0x65F8A0D66BDAC5ED_u64, 0x9A427B74C87D9FF8_u64), (0x1E17CDEB1C14C28C_u64, 0x9D6F9A6109A5E965_u64),
+        (0xA0D1B05F7994B8C0_u64, 0x979DFDFD5EEEB285_u64), (0xFC73AE624B6B4AB2_u64, 0x77B8832EA752B308_u64), (0x40FEA65EB2672D6A_u64, 0x41AA6FA7A4B39010_u64), (0x288592A342D28541_u64, 0x2B63CC6351EE9FED_u64),
+        (0xC57C3AEE52954C1D_u64, 0xE4DB4CECBB0120D4_u64), (0xDF2B923E643550F5_u64, 0x28396150A2DD1642_u64), (0xD7E8912D3E301111_u64, 0x3BE80C2B8BA0A00A_u64), (0xC13F91CE6F5F8CEE_u64, 0x2B93AFF7EEC9BDCC_u64),
+        (0x9D584781056D22D9_u64, 0x8CC06D6DC865815E_u64), (0x7584EE6EF8D16087_u64, 0x2945015D19B48A06_u64), (0x1A5184CFE8AF686F_u64, 0x6D0E2371BE8AC1A6_u64), (0xB12B7299B80D7632_u64, 0x9AC086D29D5F14BE_u64),
+        (0xBB14D17BA3D2A479_u64, 0x076A30D5D0F517CA_u64), (0x22737650A9394AC0_u64, 0x8D49DBA202BF76A6_u64), (0x68054BBB365B1141_u64, 0x5764079D69151429_u64), (0x996E2FDF2EF76ACE_u64, 0x61EDE1936D8D82FE_u64),
+        (0xFD6CAE05DF1EDEB7_u64, 0x95A801955C464A5B_u64), (0xDFE85199F8409C21_u64, 0x5D39D78A0B768C8B_u64), (0xCB6A5761FC777D3E_u64, 0x192EC0C9917B1B89_u64), (0x006398BD337E89B3_u64, 0x2C520F0C3C21BF32_u64),
+        (0x87F038D1AE2ECE89_u64, 0xCFD80AD245AFEB7B_u64), (0xDCEA34D56DD1CCDC_u64, 0xAB855EA96A6883D9_u64), (0xEB190C0E1FCF044D_u64, 0xBFB4A29DC0842080_u64), (0x1AB601C40DC16647_u64, 0x53AE52DC8E214E86_u64),
+        (0x9E997B927FA2AE3D_u64, 0x35D801EAFB2F46B4_u64), (0x34A06EEE7A6DB9EC_u64, 0x9290EC71E54ECF0B_u64), (0x78B4B2BB4C4D2091_u64, 0x1D4B7FF0CE5B35EB_u64), (0x81C559BD9E54FCC8_u64, 0xBEFF46F72615D279_u64),
+        (0x3439B3FE3A03D675_u64, 0x5E5744BFD082D97C_u64), (0x16A7D87E3091A945_u64, 0xBA50309715EAA9B2_u64), (0xB6E745F60B1A1EB3_u64, 0x0D0A969538583CE7_u64), (0x7369A0CE6EF6161F_u64, 0x6C38024CF29CE17A_u64),
+        (0x710E6750B714E2C0_u64, 0x6CDEED29B381220A_u64), (0xAEF42D6AADAD6C12_u64, 0x26F2A821521B394E_u64), (0x403DC2B64D06CF0B_u64, 0x73F71DD63BF1BB4E_u64), (0x5AF49B02C3C13922_u64, 0x22A5B7F328E2B35C_u64),
+        (0x7879D00A90BC8BD7_u64, 0x15929717377AF363_u64), (0xD44A92DD3621097B_u64, 0x32F1C619A581BD0C_u64), (0x1C0D9F57079B9C43_u64, 0x63F40286D2DC4ADE_u64), (0xC9689B3CDC217027_u64, 0xA3D7A20C489C6EA1_u64),
+        (0xC38606D24DB52581_u64, 0x6550A53096E200F0_u64), (0x13A88CDD776DA0B0_u64, 0xBA250992C1883533_u64), (0x9CE9D15480FD9589_u64, 0x6747BE3C2D4DAB3B_u64), (0xC85671A7C4DC2F9B_u64, 0x0E98EBDBF394A2F6_u64),
+        (0x49AEAAAD9D9BB48D_u64, 0x0ABDED7B0BC4C4F1_u64), (0x35DE4F578FBC6B3D_u64, 0x948DCC3CDB104A23_u64), (0xF6432F539585D14F_u64, 0x725BD4241276136D_u64), (0x1B2979C1A08E24A4_u64, 0xEB067FA9E0436E7E_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO512SS_256: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x981E05FC1E35EA87_u64, 0xBF6246D87A2AC1DA_u64), (0x50BE13CE44F9C511_u64, 0x85EFD6EDB01BAE65_u64), (0xCF09E0C0A2A776B7_u64, 0xE5038B4E88759C5F_u64), (0x8B181723F2FD5995_u64, 0x363D609FAA9C1D0F_u64),
+        (0xCB97287E790B8CED_u64, 0xCE2CC8775FD98E39_u64), (0xA0F401FC6A81C63E_u64, 0x8E3D2B49CF7FB5D8_u64), (0x3CFF98BEC6C5D0B3_u64, 0x17DD06F1E2A965FA_u64), (0x475FFF54E383948E_u64, 0x4DCF06936D9CB00E_u64),
+        (0xD0C360F0EFF1438D_u64, 0x74349FE767E711F3_u64), (0xF36C14EFD7AE1DE2_u64, 0xB2F66B48A2BF4126_u64), (0x45D1E3E392788D85_u64, 0x8925FB3B637E0C3B_u64), (0xF3765F3C41C7757A_u64, 0x65E86F61AD6A24FB_u64),
+        (0x3A61BC69036D616B_u64, 0xF6710FAD08CF3C23_u64), (0xC957A5342B79EEE0_u64, 0xCBC7313806A1B5B1_u64), (0x0584F48D20F49DBA_u64, 0x466CFB86FE0B4429_u64), (0xA40D61ADE381C9D0_u64, 0xB0A80384EEE95FC1_u64),
+        (0x6757C3D593ACE303_u64, 0x46A94AB73E73A89D_u64), (0xC763F7310C2439FB_u64, 0xEA218DD1EA15C17A_u64), (0xCD3B419F8565DB94_u64, 0xD8E68F566D0A78AA_u64), (0x5815808C15B908EC_u64, 0x75F3D629C28A61E7_u64),
+        (0x70CC61BA5ED27B07_u64, 0xA44802ABFF1B6A0F_u64), (0x9305C9310CE86680_u64, 0x415936424E9E13A4_u64), (0x5AA3CB3202953362_u64, 0x58914E63E8DC8FBC_u64), (0xC041C4528D1503AA_u64, 0xD8A02E0BBA6B230D_u64),
+        (0xA5C1134CC5F3CA1E_u64, 0x4793974230D01709_u64), (0x18CE474BCD68E855_u64, 0x286C82D93DA3EE5D_u64), (0x3ABE3F107CCAE9C8_u64, 0x9B68AEB36598C097_u64), (0x465C7D935754B89D_u64, 0x4B5AA8234C533B7B_u64),
+        (0xDBD8E31349BA2E9E_u64, 0xAD89B9A819707A15_u64), (0x9BECC18FA43F3A89_u64, 0xCD4C1500BBBB5E35_u64), (0x0A717AF345A03238_u64, 0x6B88172EF85135A0_u64), (0xFA720A6DE3D1AA25_u64, 0x8049FDEE9E0D1C9E_u64),
+        (0xE9C9164466F64E89_u64, 0xF11AFAC28129439D_u64), (0xAEE3787EC233A295_u64, 0x059ACFACC571BA1D_u64), (0x4F1491884267E739_u64, 0x0E60B10190AAFE89_u64), (0x6C2B3B6D248BA3F5_u64, 0xD07CA7B7251FE767_u64),
+        (0xD906F0662FBE42E7_u64, 0xBE21F82037E4EBE8_u64), (0xC469E925EA146B0B_u64, 0xDC0CE5C5A62B0C91_u64), (0xC94DB4DC9711BAD2_u64, 0x652F6E7C46BDD8C1_u64), (0x1F94FC7829861A8B_u64, 0x217085170B292538_u64),
+        (0xA9F60B234A300637_u64, 0x3E10DA6596D58B00_u64), (0x40E4B04A5989DA17_u64, 0x3DA61A3F611E2960_u64), (0x8194037F5BEB7DE4_u64, 0x3E4C38495A24E230_u64), (0xA2A5D224724273F0_u64, 0xB3A1016D29421104_u64),
+        (0x9F7D84B72ABEB540_u64, 0x0618BA1B567AFA1C_u64), (0x7AD96A933E982CDE_u64, 0x902EC0F8E73451BF_u64), (0xF5D45E5DA5631AA0_u64, 0xF3B4A552B027D79E_u64), (0x387B8933600F3F2A_u64, 0xA4BFC5AEDF03CFDA_u64),
+        (0x9A8E1C53AC9FA611_u64, 0xF21C31989FD8E3C6_u64), (0x2E7E3B800A92AB04_u64, 0xACBD12B52A536C49_u64), (0x6EFB7FB40B9B67E1_u64, 0xE4BF68FDFFAC8E42_u64), (0xB3BBFBA7E473BBD4_u64, 0xCFBA39D514E5308C_u64),
+        (0xA657D6F2DA0437A3_u64, 0xA0C594CF9761647F_u64), (0xEAEF42CA36F03DB7_u64, 0x13B0287EBC6AC7C2_u64), (0x0570B8BCB707F202_u64, 0x1AFCB682D5CD0117_u64), (0xA69F2561500ADFE8_u64, 0xB224EF49334BBCE0_u64),
+        (0xED63E29EA93ED5AB_u64, 0x2B3B94FEFA77C73B_u64), (0x2B14719C58F5E07B_u64, 0xC63DED5C050ECF8F_u64), (0xC8EC2D06CE08B2C3_u64, 0x38A8E13EFD58FD11_u64), (0x8F3E67B40B89C37A_u64, 0x9FF981A35E153759_u64),
+        (0xC5B33C15AF853360_u64, 0xD49F632350DDF366_u64), (0x7BA07F75EE4DE9B7_u64, 0xC4C6997CCDC0B4B7_u64), (0x97406AFA178D338B_u64, 0x483B4885E67F83E6_u64), (0xA3E9C174F9D60A99_u64, 0x1D6ACA65CC79126F_u64),
+        (0x25BD5A8034A9EFB7_u64, 0x6ADBC92965410BF4_u64), (0x668C36C23790C05F_u64, 0x1580989604D94E56_u64), (0x88653DEA05741EA7_u64, 0x2EDFC9D16D100B8F_u64), (0x6227F5204A440B52_u64, 0x9BB7BD1A4E6EEE99_u64),
+        (0xD9EF5D83041B796E_u64, 0x6F62FAA7685445C1_u64), (0xA69E61683AB0DEEB_u64, 0x07CDA3FCC6F53EFC_u64), (0xA5873B3C186B5C37_u64, 0x726066B9A2AE4001_u64), (0x6C89AEDCE6495B2D_u64, 0x1EEFCF8C6AA76B81_u64),
+        (0x3E1C3E34BD19CE2D_u64, 0x315BD3DC32A8833E_u64), (0x13797775496F92AB_u64, 0x6B101891DD7614CD_u64), (0x20BA89D49A6968B4_u64, 0x9089790CF37A5F80_u64), (0x5F1CADF121919C0C_u64, 0x996ABAD52EC17789_u64),
+        (0x15521EED73C5D1DC_u64, 0x69D564298121B966_u64), (0x1417F268126997BC_u64, 0xCD34B1420E43E7B0_u64), (0x34A35E63393C9AD7_u64, 0x793E471F1A7A7FE5_u64), (0xCAFE14CD9197A9F9_u64, 0xCC07BA370039155A_u64),
+        (0x018C39445F9997AE_u64, 0xA8E3DAB989259F05_u64), (0x1DFB1D0C884D3E0C_u64, 0xE1798A3B08F00AAB_u64), (0x438E3AC4F01B9AC2_u64, 0x60B28DBB5A09440B_u64), (0x95C6E3F498DE3C19_u64, 0xCF1ABE77AEA3675D_u64),
+        (0xAF3004BC2050A135_u64, 0xF40F8089A0795713_u64), (0x025581BCD39A6286_u64, 0x060655C4D658C85F_u64), (0x50041E4CEABA9FC1_u64, 0x2484733EDCFEB341_u64), (0x8222321CB1465475_u64, 0x5F0D2EF12D8C2D7B_u64),
+        (0xADC2A25D56D92637_u64, 0xC46DA857B92348D6_u64), (0x8488A91E854F30DC_u64, 0x4348C95EE3906AA3_u64), (0xF9D0A88401D5D54A_u64, 0x7A656C20A9AA5971_u64), (0x9EDBDDFBF222BA97_u64, 0xBEEA2753E7BC6C94_u64),
+        (0xB4816396F2CF1C43_u64, 0xFEF5B211826AACA9_u64), (0x78D9452C37815C53_u64, 0xD2DA05E0B0CC6C6C_u64), (0xBDCAE6BFF5B62352_u64, 0x4779DCABF7D4B79C_u64), (0x8077DAECE5DD5176_u64, 0x5BDE0ECD114A429F_u64),
+        (0xD7B9E2B6C584B620_u64, 0xD9AC3D18DC367534_u64), (0x1BABAB079E53F3C6_u64, 0xC5758CE694F5E7A4_u64), (0x3F0E05F45D6F3AA8_u64, 0x0B5A22AA173DF4E6_u64), (0xD7239BA95F32B611_u64, 0x317496F42214F555_u64),
+        (0xBA341EFEEFD666B7_u64, 0x57F7E85A3F3D89B3_u64), (0x439B183FB2B496E3_u64, 0x79046DEE28194A96_u64), (0x560C777CA5FCB57D_u64, 0xE7B512FB245657C9_u64), (0xF786F7ADE1BE8E4E_u64, 0xC2F007619F8C5BC1_u64),
+        (0x4F4CF09605DF7B34_u64, 0xDB3C60616FA6B47F_u64), (0x5CA2F6C76E3D063D_u64, 0x62E8FCF3AE2B7751_u64), (0xA294E5A540352CBD_u64, 0xE48E0FAF60590254_u64), (0xE1A8C89391628DE7_u64, 0x0D8867659AE0D475_u64),
+        (0x1AD055408EA13CA2_u64, 0xF91DE5374E7C037F_u64), (0xDB8FBC85D6343E57_u64, 0xF19C200059ED0433_u64), (0xD646270A0C3F0C3F_u64, 0xE6C791966E612175_u64), (0x76D2F6C3506010C4_u64, 0x00EB640A12B57EDF_u64),
+        (0xB8109E2E1A51FC0E_u64, 0x43F249CF6F18C59D_u64), (0x633F7895EB0F504C_u64, 0x33F111E4AE9EC657_u64), (0xF68C13A9654F287A_u64, 0x1AAF9DCA8BF3C4B3_u64), (0x5E8F731BA800422F_u64, 0xB0A315C8102F5322_u64),
+        (0x5CF8259079FE9DDE_u64, 0x395F5EBBB2E65668_u64), (0x3264EBD62F0183F1_u64, 0x0E086AFC4CC5A23D_u64), (0x9AEE9B7996170E0C_u64, 0xF9AD1F6EEB3F5C70_u64), (0x27C5617FBB0EE6B1_u64, 0x08CDEE69782C890D_u64),
+        (0x508ABBBD009935DE_u64, 0x3D23B8F5B3C4D273_u64), (0x0D0BD3ED5FB7AD21_u64, 0xE0ACE588AF552AA8_u64), (0xD1E7ED3F13BD607A_u64, 0x08505BAC5FDC4446_u64), (0xEAD1E4FDA2468721_u64, 0xA7E93A07B2332D77_u64),
+        (0x2E7A897A66C2E8CE_u64, 0x374B979CF3689D42_u64), (0x284E95F511DB0A41_u64, 0x730C1916C11C6DD8_u64), (0xB95E6995DE1F647E_u64, 0xFC801911CFDD1543_u64), (0xA4575D6DD5CD8B0D_u64, 0x10AA69247E5B4CD3_u64),
+    ],
+};
+pub(crate) const JUMP_TABLE_XOSHIRO512SS_384: JumpConsistencyTestTable<u64, 128usize> = JumpConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 00:28:41",
+    table: [
+        (0x75854721EFF7AEA2_u64, 0xAE3C9D6EC35807D9_u64), (0x6912F1F2968E3BCF_u64, 0x731C2095790A553A_u64), (0x333A720065F1E81E_u64, 0xDC1222DF8C4010A5_u64), (0x26CE7F2E9B7FB59C_u64, 0xE3EC355ED16B9DBA_u64),
+        (0xFE1B4A69D8914770_u64, 0x02C84365C93A198C_u64), (0x2391CF2A896AC735_u64, 0xD4D92EEEAEF9C053_u64), (0x7AC320B5764BCBBA_u64, 0xF5553AED75561E09_u64), (0x0F27BC210AF60DFF_u64, 0x9DB5AAF6FAA0D422_u64),
+        (0xA169780370D921F1_u64, 0xB115D3DC2DA8CD51_u64), (0x4D075E4555C945AF_u64, 0xD965C05BBA3C91A5_u64), (0xB2FA94381259C45C_u64, 0x15ADD5990140A47C_u64), (0x50EF2047230F03EE_u64, 0x8517F00929660B52_u64),
+        (0xC78D6AC667E2D49B_u64, 0xD2ACEE5342314589_u64), (0xDCF316ACCC74D176_u64, 0xB855EB99BC35AB49_u64), (0x7D348870C3322DF7_u64, 0x6FF537D1DC089DB8_u64), (0x8E259A263B4526E5_u64, 0x8843492A7688537F_u64),
+        (0xE366FF55754499ED_u64, 0xF571068BD72BE29E_u64), (0x55F7C04A28B31198_u64, 0x50AF742DC8868060_u64), (0x3F13A7DFECAF8855_u64, 0x3D110A4ED67E136A_u64), (0x9A104E87612CB461_u64, 0xE9A915E2CF8F8E2A_u64),
+        (0xB079DCFB6D6FD293_u64, 0xD94E29908AB27A96_u64), (0x6B6093CDCFAE8445_u64, 0xCB299DA3EE89CFB3_u64), (0xA0FF52C20C96F410_u64, 0x49212E8CD78B238C_u64), (0x5F2893577159F84C_u64, 0xA6755CCF8572EFC0_u64),
+        (0x43EDBACBC8D88603_u64, 0xE65772DEB2AF5798_u64), (0xEB2D10BE98C94F22_u64, 0x7FECE6BF04C718D3_u64), (0xDADA3494355F2F22_u64, 0x4F13F37AF7664449_u64), (0x6FC6EA0A9218CFE7_u64, 0x477B2D5623C9259C_u64),
+        (0xF0F8A850EA231A85_u64, 0x1C55907A6A12679A_u64), (0x3764795A20840EF3_u64, 0xD5D7F167629A9967_u64), (0x6D5AD88B900398AF_u64, 0xA1CC5C4647E9B4D2_u64), (0x4C5A3B0D09C65657_u64, 0xC876C95DC2B48128_u64),
+        (0xCB5482AD0E43D35F_u64, 0x9E6EDEA4157F7E6A_u64), (0x338069977DCFCD52_u64, 0x42E7DE5A099B8CE8_u64), (0x017D8AF303538586_u64, 0x878D59D3DC1D7484_u64), (0x2D7CA6C002A470DB_u64, 0xAFA597423C0BC374_u64),
+        (0x07DD14076B21E30E_u64, 0x079FC2F0E9DF874E_u64), (0xE861023287F8E13A_u64, 0x0DD3C4808D9327E2_u64), (0xEF866F55D898343B_u64, 0xA61D0414560054DD_u64), (0x9F2CC3344D7DFF35_u64, 0xEACFDDA556400484_u64),
+        (0xE8C6148F046DCE3D_u64, 0x1A76E2B79A5DEDEC_u64), (0xE5DA78D34018D465_u64, 0xB448327869024C29_u64), (0x9B983261713DA76F_u64, 0x48D61E9B577F808B_u64), (0x3E72577C8DC3A1E2_u64, 0x557EFAF785A9FD65_u64),
+        (0xC2D75A535F34AC38_u64, 0xE19D9E960D4D8F47_u64), (0x1CD6D6E978F5BC04_u64, 0xA5EAC4CBA7D3EBBE_u64), (0x3A578CB148017BCD_u64, 0xD49DD7C727D2D8F4_u64), (0x4EA7E5AE7E5C1BDF_u64, 0x5F6E0928E5654B4D_u64),
+        (0x13B521EB1C3EDEAF_u64, 0x6508AEFE13123B99_u64), (0x5D82589AB45C23B8_u64, 0x241461019F1D0C8B_u64), (0x61CACAC73BF9C215_u64, 0x4E78C49AC9C02C1E_u64), (0x3306B6EEEEB8D47A_u64, 0x068155C5520ED51F_u64),
+        (0xB8E13FF104B7F637_u64, 0x7693DA7E3EF5A78F_u64), (0xF4AACD50CEAC3F10_u64, 0x7C322AEBDC0A9C62_u64), (0x604D73245DA54878_u64, 0x769EF526D216EBA1_u64), (0xC3B64C5A9AA89EE0_u64, 0xB352548B8B1129C5_u64),
+        (0x6C87AE5CB23D00F0_u64, 0x6CDCED56073D561E_u64), (0x601C1844BBD99350_u64, 0x899C8F42F4926D88_u64), (0xB110180D44B073A3_u64, 0xE923DE0499ADB3A3_u64), (0x016BDD4580A099BC_u64, 0x1F018E04450FF207_u64),
+        (0xC49D8B7AE6BB4D50_u64, 0x0E76E37AAA15F866_u64), (0x2709BFF41F8F7309_u64, 0xDA394971787763E1_u64), (0xF8105FFAD1EF921F_u64, 0xFCA9A6A9E87DADE1_u64), (0xE6FD403C637D3268_u64, 0xCDD75D3CFF295FCA_u64),
+        (0x132F9FC0ACAEE61B_u64, 0x35BBE43D6657E23E_u64), (0xB7E785E506A1F09B_u64, 0x82E737F52F24DC16_u64), (0x3643B0716EB91382_u64, 0xF3CEBA86EA6E9D8F_u64), (0x0D06EEA5D118A6B9_u64, 0x4874B25C2FE2384E_u64),
+        (0x44E0C77FC1BAB3F0_u64, 0x2C22A07EB45D1448_u64), (0x43C1F7E49C46EF0F_u64, 0xF60DC87B2517A827_u64), (0x302EBF3B17AC6169_u64, 0x61FE5074B4C231F5_u64), (0x9F9C7376B02154A8_u64, 0x02ADED8A8726D534_u64),
+        (0x00AC19075EE2D3FB_u64, 0x01279E44B77954A0_u64), (0x5C2934C9D7A8252D_u64, 0x8CF0218F79646A51_u64), (0x51545BB0A4428017_u64, 0x8D78FA7E60EF4256_u64), (0x9E877238D5E6299F_u64, 0x9A7D854768756B14_u64),
+        (0x625F0D9F349A78B7_u64, 0xED6FA50062933FF8_u64), (0xA7D00B4778D92325_u64, 0x485205A8BEE0B51A_u64), (0x110B5A81A5A37688_u64, 0x0B31C2D3FC41DC57_u64), (0xB2AFEE4A478D10B7_u64, 0xF2328F47447B1983_u64),
+        (0x4BA897535A4DDF59_u64, 0xE476F0A3DB29F0AD_u64), (0xA898D3998F6454F0_u64, 0xEA8B5D157321D647_u64), (0xC4F4C00AC6E99B0C_u64, 0x353791E45B38B3DB_u64), (0xB6A8B439281CC8C2_u64, 0xC50F64772A996C81_u64),
+        (0x187444EA53F1A476_u64, 0xF52445E9DEB3D895_u64), (0x18B69A171DF8F845_u64, 0x8D20D3E359CA1D5A_u64), (0xFBE2DB93E95208B2_u64, 0xA20CEDA5DD41520E_u64), (0x0DB4C59757A53804_u64, 0xC85286085308E9B3_u64),
+        (0x4728F3D91A0A3740_u64, 0x5591EB051B4BAC07_u64), (0x2852E6EB6B7EB32F_u64, 0x5134237CC0C9CF80_u64), (0xBF13E457F4947A6D_u64, 0x1250A83188798621_u64), (0x9D4E52B914B46592_u64, 0x4B327BA441783329_u64),
+        (0xFD7E47A35B8F2B08_u64, 0x85D202A5093458FE_u64), (0x2A6846F189262322_u64, 0x2A6CF2ECFAB8AC52_u64), (0x7FB332AED06E03ED_u64, 0x6E4BD23FF24195C5_u64), (0x470F1B61079277B3_u64, 0x59331D81B2741859_u64),
+        (0xCA81C0A1735E887A_u64, 0x3F51928C4DA1B8E1_u64), (0xEE6A905E285EDD8F_u64, 0x4D380F098F802B69_u64), (0x0E2130DC330D4026_u64, 0x8DAE95D2C290AC3F_u64), (0x282414391212CA01_u64, 0x9E19FB375A70C598_u64),
+        (0xFE240D728D549064_u64, 0xB5409B7026AE4153_u64), (0x649F30540BE217D7_u64, 0x1C0437AEED01B240_u64), (0xCAD7A932360D8B0D_u64, 0xB6C11924AB287BF6_u64), (0xBB2D862C8056E92E_u64, 0xA7E2D4AB7C26BFDE_u64),
+        (0xD30CED72D4BFB083_u64, 0x8631F4182B1B8346_u64), (0x921AE55A481C7B63_u64, 0x3EDFB51B470804F3_u64), (0xFEE861C1644E0165_u64, 0xA17C44CC11B7ABA7_u64), (0xC07FBDF9EFFCE018_u64, 0x193EFB9CDA0816A3_u64),
+        (0x9B4EF58EA1B9C3F5_u64, 0xAA1430FE271B2FBD_u64), (0xFDABE632F647ED3B_u64, 0x3E9E16B4D10C3355_u64), (0x5D99BED3037ED104_u64, 0x37AFE8922FC9C9C6_u64), (0xBF2CFC45D2AC45BA_u64, 0x4CBA584EDFFF177D_u64),
+        (0x99E510AE6375C8B6_u64, 0x3924A5056FDB294B_u64), (0x4F4EB1CEE2E2C9C0_u64, 0xD15B36C3E82CA672_u64), (0xDC3F1FB1FDC833D5_u64, 0x55C38648C08924ED_u64), (0x31D762A11AB58235_u64, 0x36AD82039754603C_u64),
+        (0xDD3A95614E5E5D0E_u64, 0x210B9880E1D802A1_u64), (0xA4CD8FB1641F0C41_u64, 0x9C22981F3B8298B9_u64), (0xD92923A4E0DE64D6_u64, 0x31FB59819E8596C5_u64), (0x53FFF76E7EF994FC_u64, 0x5098D0AED79EECA2_u64),
+        (0x89C1B4BF435AF737_u64, 0xEB08F3FA490F60CE_u64), (0x734EFCFAAA444CA2_u64, 0xB2E9ED4740EFA3A8_u64), (0xB58270C024E928D1_u64, 0x6FE3F4A53908CDA3_u64), (0x1E8B1277BD25BEF0_u64, 0xC5254B73A5864E8E_u64),
+        (0x0DFDDD2A83BDC910_u64, 0xD2167F8E9A0C6CE2_u64), (0xF3864612BAC024D9_u64, 0x897EDB550AF507BF_u64), (0xD0882390E215A515_u64, 0x7E30B3E39C75DE9B_u64), (0x6C9129C1BA1E4016_u64, 0x92A538B1CD9F2338_u64),
+    ],
+};
+pub(crate) const TABLE_MINSTD_RAND0_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0x00000000_u32,
+    table: [
+        0x000041A7_u32, 0x10D63AF1_u32, 0x60B7ACD9_u32, 0x3AB50C2A_u32, 0x4431B782_u32, 0x1C06DAC8_u32, 0x06058ED8_u32, 0x56E509FE_u32, 0x56F32F43_u32, 0x77A4044D_u32, 0x31169898_u32, 0x427C3C55_u32, 0x6A5D128C_u32, 0x046CDBE2_u32, 0x06D7D4B3_u32, 0x43CD3747_u32,
+        0x55E83917_u32, 0x00FC4111_u32, 0x3113C398_u32, 0x088E4954_u32, 0x35702E2F_u32, 0x57E82B11_u32, 0x4B63962D_u32, 0x77188B05_u32, 0x6C4F3258_u32, 0x437266F5_u32, 0x0BC5816B_u32, 0x53B39DD6_u32, 0x353F7788_u32, 0x59C09D07_u32, 0x74856499_u32, 0x618FB492_u32,
+        0x21980F48_u32, 0x06135133_u32, 0x5E37EE62_u32, 0x2A039441_u32, 0x50FA4CF3_u32, 0x60D20F0D_u32, 0x7EDA5023_u32, 0x2EC36AE5_u32, 0x1F9EF85F_u32, 0x7DC43530_u32, 0x5C8022D1_u32, 0x606DF6C8_u32, 0x5364F5ED_u32, 0x094DC561_u32, 0x50D9680C_u32, 0x713E154B_u32,
+        0x22E82902_u32, 0x37DC5435_u32, 0x621C8039_u32, 0x3D265081_u32, 0x1E6F6484_u32, 0x233025B8_u32, 0x2DFC6514_u32, 0x15501BA2_u32, 0x3E46339C_u32, 0x72E666B4_u32, 0x7460EE5A_u32, 0x07C09267_u32, 0x73CBAC2A_u32, 0x409434CA_u32, 0x4215DAE5_u32, 0x28D61248_u32,
+        0x7E4A49E9_u32, 0x3F3AA0C5_u32, 0x221105F1_u32, 0x0B9D22B0_u32, 0x795056C4_u32, 0x09709C15_u32, 0x40172B8A_u32, 0x312B91E5_u32, 0x23776A9B_u32, 0x74F7F84D_u32, 0x43CEC339_u32, 0x3B72F5F6_u32, 0x787207F7_u32, 0x066926E8_u32, 0x61794CA1_u32, 0x62961005_u32,
+        0x69F5EAD3_u32, 0x100BF8FE_u32, 0x0207F3ED_u32, 0x581F4DA5_u32, 0x6F22B8D5_u32, 0x5094E0F3_u32, 0x5E3C9AD9_u32, 0x5CDA4CE4_u32, 0x7FEE3C5B_u32, 0x71BCBBFA_u32, 0x1DD9546C_u32, 0x27358DC3_u32, 0x2CEE1151_u32, 0x41B2F3E2_u32, 0x4BA99C20_u32, 0x6C491FAE_u32,
+        0x34BF100C_u32, 0x6BB69EE1_u32, 0x2278FD06_u32, 0x352BA698_u32, 0x44C6606D_u32, 0x3BE4B761_u32, 0x24C35FFF_u32, 0x16D37134_u32, 0x13AD18A1_u32, 0x492BFC1E_u32, 0x66B53B19_u32, 0x043B1AFD_u32, 0x4468DA36_u32, 0x3FCE3250_u32, 0x7A4942E9_u32, 0x5FC80DB7_u32,
+        0x46FC9A81_u32, 0x7203AF8F_u32, 0x4FFB0FC3_u32, 0x6BC7F43A_u32, 0x1473431E_u32, 0x1B3B710F_u32, 0x5B7B9DC0_u32, 0x10B1D72C_u32, 0x0BA59244_u32, 0x2325B455_u32, 0x00624A7A_u32, 0x35078FC8_u32, 0x0370AEAB_u32, 0x5ADB5E50_u32, 0x780D04C9_u32, 0x1EB565B2_u32,
+    ],
+};
+pub(crate) const TABLE_MINSTD_RAND0_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x60D2AF1F_u32, 0x27E74DE2_u32, 0x42AA44E5_u32, 0x38913894_u32, 0x4619998F_u32, 0x3AB09F3D_u32, 0x21A67BE5_u32, 0x391008A5_u32, 0x4BA7A5E7_u32, 0x6B7D077E_u32, 0x5D771853_u32, 0x35DE2115_u32, 0x0A4E0554_u32, 0x083FD315_u32, 0x163B0BEE_u32, 0x7E8C41A8_u32,
+        0x2A2ABD80_u32, 0x5BFF3420_u32, 0x4FB7500F_u32, 0x0BE931AC_u32, 0x7ABC194F_u32, 0x5321D07C_u32, 0x50FEA787_u32, 0x7EA8B99B_u32, 0x7731B013_u32, 0x5F1EEC87_u32, 0x673AC3DA_u32, 0x43105228_u32, 0x6481E27D_u32, 0x0B3CB018_u32, 0x3D4CFD6B_u32, 0x09919B3E_u32,
+        0x3667085A_u32, 0x2655689D_u32, 0x31472F14_u32, 0x3863E352_u32, 0x25E1396A_u32, 0x65807193_u32, 0x53A09DF4_u32, 0x55E22910_u32, 0x72F6057C_u32, 0x75E24ADA_u32, 0x5BA06AAC_u32, 0x00BB7133_u32, 0x1204CBA5_u32, 0x78D5C6E0_u32, 0x1AF3DA1A_u32, 0x7F73EEC8_u32,
+        0x1441CDD7_u32, 0x6C30E8A4_u32, 0x7EF99E79_u32, 0x36135B0F_u32, 0x30BF5185_u32, 0x60810BC3_u32, 0x382B60B4_u32, 0x27D9E63B_u32, 0x529A3FED_u32, 0x0CDB0AF9_u32, 0x00AD6807_u32, 0x7888A3E9_u32, 0x52C153D1_u32, 0x1265E8C9_u32, 0x6095ED8E_u32, 0x131F372C_u32,
+        0x665F3382_u32, 0x742ED04F_u32, 0x2D6E2E20_u32, 0x1C964E2D_u32, 0x4FE67B04_u32, 0x24966C97_u32, 0x0FB34045_u32, 0x41407A10_u32, 0x700DD1E7_u32, 0x1B4FCE2A_u32, 0x14683568_u32, 0x458A494F_u32, 0x79D30533_u32, 0x0CFA95C1_u32, 0x177DB88F_u32, 0x3EDFC055_u32,
+        0x53CC2CB2_u32, 0x018A8519_u32, 0x2D282D19_u32, 0x28A8DB78_u32, 0x65E0B822_u32, 0x0458F46F_u32, 0x6C17A5A3_u32, 0x047BA7C6_u32, 0x5243BA76_u32, 0x5C86C32B_u32, 0x13776B82_u32, 0x05332DCA_u32, 0x63032B70_u32, 0x6518F8D8_u32, 0x4A7960C2_u32, 0x66BF86C0_u32,
+        0x2827DBF3_u32, 0x50D9431D_u32, 0x67C54E62_u32, 0x4A9D3927_u32, 0x181355B6_u32, 0x1D602C13_u32, 0x14EDA276_u32, 0x7D44FBB5_u32, 0x3BE96853_u32, 0x58C03FDF_u32, 0x35A176FE_u32, 0x7B8B3933_u32, 0x715882A2_u32, 0x69E893D0_u32, 0x24407302_u32, 0x033E9AE6_u32,
+        0x032B73B4_u32, 0x19B9300C_u32, 0x4D017105_u32, 0x19A326C2_u32, 0x264597B4_u32, 0x22EABE0D_u32, 0x61635963_u32, 0x4181A488_u32, 0x2658FE51_u32, 0x1CA08B82_u32, 0x7027127C_u32, 0x152EC46A_u32, 0x31611603_u32, 0x5CEC3848_u32, 0x18632EA1_u32, 0x178A5889_u32,
+    ],
+};
+pub(crate) const TABLE_MINSTD_RAND0_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0x19967810_u32, 0x65A46F8F_u32, 0x0E98496B_u32, 0x2FFC1749_u32, 0x4F5CD23B_u32, 0x56EE4831_u32, 0x35C5B58D_u32, 0x4712578F_u32, 0x053291BD_u32, 0x3AFE11F5_u32, 0x7E4D0A14_u32, 0x73D0E7D3_u32, 0x1824090C_u32, 0x65CDFF35_u32, 0x372E24CA_u32, 0x36716613_u32,
+        0x4EE48551_u32, 0x7CECB14D_u32, 0x176C764E_u32, 0x51CB06E5_u32, 0x6831CE56_u32, 0x1DE1A58B_u32, 0x493B5800_u32, 0x570E8D8F_u32, 0x7C6FD3EF_u32, 0x11C32FBC_u32, 0x2572E8C0_u32, 0x1B0EA875_u32, 0x5F55A733_u32, 0x7054362A_u32, 0x28B03903_u32, 0x496F08D3_u32,
+        0x18AC7A4F_u32, 0x6391E530_u32, 0x7F5DE561_u32, 0x6D7E849A_u32, 0x0D33D29F_u32, 0x4548CB7E_u32, 0x2E27E0BB_u32, 0x3C132CA9_u32, 0x0ED92A0F_u32, 0x53584666_u32, 0x4875FD49_u32, 0x3A47E8C9_u32, 0x47040203_u32, 0x58203761_u32, 0x2B13EE7A_u32, 0x298DA1AE_u32,
+        0x1171BBD2_u32, 0x41E1E0F0_u32, 0x5476CE5A_u32, 0x43E5A208_u32, 0x18F0DE0B_u32, 0x6D81B0F7_u32, 0x5D89604B_u32, 0x66110BE6_u32, 0x69245D63_u32, 0x526F4482_u32, 0x06FADD16_u32, 0x3FC8D2EE_u32, 0x199027F9_u32, 0x4730598B_u32, 0x3746D930_u32, 0x0C5CFAAA_u32,
+        0x2C4CB53D_u32, 0x600ECE83_u32, 0x6C182BB9_u32, 0x26DEB620_u32, 0x678702CF_u32, 0x50C99922_u32, 0x6364B09D_u32, 0x67874665_u32, 0x621EC5FC_u32, 0x525855B7_u32, 0x25638B9D_u32, 0x2A630398_u32, 0x4A8105E5_u32, 0x5CAA2399_u32, 0x26074156_u32, 0x2652889B_u32,
+        0x74868BC4_u32, 0x2D422CA0_u32, 0x537FD396_u32, 0x6D1C47AD_u32, 0x5BA5E6D1_u32, 0x68D4D258_u32, 0x6C3DC52C_u32, 0x4B5C0338_u32, 0x05D7782F_u32, 0x051358A8_u32, 0x39218032_u32, 0x46676FEB_u32, 0x30E4D169_u32, 0x7E705D92_u32, 0x030F5D18_u32, 0x65A8D439_u32,
+        0x2F051E53_u32, 0x7909F342_u32, 0x7C41B022_u32, 0x3C92C7E9_u32, 0x4882B110_u32, 0x7C36B6A0_u32, 0x6C100215_u32, 0x0EF8E820_u32, 0x774F948D_u32, 0x09A1F22D_u32, 0x67226D4B_u32, 0x05358BD3_u32, 0x7E6ECC50_u32, 0x1C27D909_u32, 0x7C19EC4F_u32, 0x09E97630_u32,
+        0x3E4E4E65_u32, 0x06FCE8D8_u32, 0x461AC07D_u32, 0x06537280_u32, 0x508234BE_u32, 0x1454CF3D_u32, 0x4BF1B838_u32, 0x6A758B7B_u32, 0x4B186ED7_u32, 0x31150BC5_u32, 0x5CB7CBAF_u32, 0x269D7DB7_u32, 0x2DA88D2F_u32, 0x14CD2014_u32, 0x26F50BB7_u32, 0x21D42F5C_u32,
+    ],
+};
+pub(crate) const TABLE_MINSTD_RAND_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0x00000000_u32,
+    table: [
+        0x0000BC8F_u32, 0x0AE257E2_u32, 0x4CF91F46_u32, 0x7220517D_u32, 0x7BE5F8F1_u32, 0x1847C123_u32, 0x41EABA51_u32, 0x32F1F059_u32, 0x21A6E8C3_u32, 0x5F2B6A7F_u32, 0x0B764D23_u32, 0x47BADB6F_u32, 0x4A8680AB_u32, 0x679FE14D_u32, 0x4BC3FEA9_u32, 0x417FCC03_u32,
+        0x70358A29_u32, 0x7F5EEE32_u32, 0x5CF4818F_u32, 0x7BB9EBCF_u32, 0x260772E4_u32, 0x3688D561_u32, 0x7322BD84_u32, 0x53957657_u32, 0x736579B9_u32, 0x7B138454_u32, 0x1515503A_u32, 0x6DD28774_u32, 0x74137B93_u32, 0x25A2A61B_u32, 0x77D6D485_u32, 0x351312D4_u32,
+        0x277B829B_u32, 0x41E802BE_u32, 0x329D7138_u32, 0x6517A8D7_u32, 0x5835C904_u32, 0x45AABB2D_u32, 0x4BD400C3_u32, 0x0BFC10A1_u32, 0x4E039796_u32, 0x375447B6_u32, 0x4CBE082B_u32, 0x6C269511_u32, 0x3F065FD0_u32, 0x62ECA207_u32, 0x092C37A3_u32, 0x188ED590_u32,
+        0x1C9F2F9D_u32, 0x63E30EDC_u32, 0x0ABF7009_u32, 0x173640DC_u32, 0x4EEFED15_u32, 0x4A214F03_u32, 0x5EA1C3E0_u32, 0x28357587_u32, 0x382C05A4_u32, 0x34BBE95B_u32, 0x74567B83_u32, 0x7F03DD8D_u32, 0x49DD08DE_u32, 0x14FB68D1_u32, 0x596927A7_u32, 0x2ADC4DFF_u32,
+        0x3A571494_u32, 0x09C1849D_u32, 0x107C6E12_u32, 0x1656CC57_u32, 0x388C2981_u32, 0x04C63E5C_u32, 0x40886C6C_u32, 0x3BD43F64_u32, 0x4A1D30FE_u32, 0x5649530F_u32, 0x0BF4E87D_u32, 0x0889BB70_u32, 0x6A960023_u32, 0x31E46490_u32, 0x186E35EF_u32, 0x152BC87E_u32,
+        0x7AB1A591_u32, 0x7ED2ACBC_u32, 0x0E8947D7_u32, 0x6F711A82_u32, 0x4FA5EAC8_u32, 0x56197B0C_u32, 0x4E990A89_u32, 0x4B39F24F_u32, 0x1350CCF2_u32, 0x20A44FA2_u32, 0x6643A393_u32, 0x4BE8E9C2_u32, 0x72C57B30_u32, 0x22C7B8E2_u32, 0x1956717A_u32, 0x229F2079_u32,
+        0x32BC3297_u32, 0x0C477116_u32, 0x52F46560_u32, 0x50F79AD3_u32, 0x1002E723_u32, 0x135CE823_u32, 0x0B5F7C13_u32, 0x7974535D_u32, 0x45338FDD_u32, 0x05740E64_u32, 0x466581E4_u32, 0x5E17660F_u32, 0x3E0590FC_u32, 0x3B956821_u32, 0x00EDBE35_u32, 0x1C7C2CF9_u32,
+        0x1A64250D_u32, 0x49266523_u32, 0x0EB89E4F_u32, 0x4D6287D0_u32, 0x15C70F2F_u32, 0x59581F55_u32, 0x1F3C7018_u32, 0x55146B6B_u32, 0x054B121A_u32, 0x163A3C52_u32, 0x2ED40A8C_u32, 0x6030F92F_u32, 0x325A42F4_u32, 0x6996E078_u32, 0x48201E93_u32, 0x6065725C_u32,
+    ],
+};
+pub(crate) const TABLE_MINSTD_RAND_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x7B467388_u32, 0x19331E91_u32, 0x1E00B41E_u32, 0x46AAD0F4_u32, 0x6ADE5065_u32, 0x7539B1D8_u32, 0x59D5A457_u32, 0x130734EF_u32, 0x6BE63188_u32, 0x51F629EA_u32, 0x0F49BA73_u32, 0x2F1BB0C2_u32, 0x264679C3_u32, 0x22C9704F_u32, 0x5CFFF35F_u32, 0x69B33F10_u32,
+        0x2D7095A5_u32, 0x0FC9061B_u32, 0x71C65455_u32, 0x33B82F15_u32, 0x267604EB_u32, 0x27898BED_u32, 0x18976FA1_u32, 0x7291BB28_u32, 0x04D9941E_u32, 0x7E4FCBE6_u32, 0x2850878C_u32, 0x2886C297_u32, 0x1A2DD20C_u32, 0x45D94B44_u32, 0x17A761E1_u32, 0x22610B87_u32,
+        0x78ACC70D_u32, 0x4AB16E07_u32, 0x05EA07F1_u32, 0x2B8F7455_u32, 0x2E90A7A6_u32, 0x3DEBD252_u32, 0x452F0F05_u32, 0x345175B5_u32, 0x0BF1F92D_u32, 0x5F274DBB_u32, 0x040A4BA1_u32, 0x514A80E2_u32, 0x1F446DFE_u32, 0x380026F1_u32, 0x64AF0F1D_u32, 0x4CE35184_u32,
+        0x62D7E9FF_u32, 0x46738B0C_u32, 0x34A7E37C_u32, 0x34D76FD5_u32, 0x3A7831D2_u32, 0x6DBA626F_u32, 0x2B6721A5_u32, 0x7B51371A_u32, 0x06D5952F_u32, 0x2ADCE352_u32, 0x285371F2_u32, 0x4E4A9995_u32, 0x0475A990_u32, 0x663F7E01_u32, 0x3E00B52E_u32, 0x27735808_u32,
+        0x3E144695_u32, 0x112934AE_u32, 0x58B44E79_u32, 0x76593442_u32, 0x1E355735_u32, 0x0BD6CF1B_u32, 0x5D159585_u32, 0x58DCBA6A_u32, 0x3C327C1D_u32, 0x3358F8E0_u32, 0x0577D0C4_u32, 0x0B3D958A_u32, 0x0137F0A5_u32, 0x42F899F6_u32, 0x79EF0B11_u32, 0x28A85E1E_u32,
+        0x5B2AD6A6_u32, 0x62905506_u32, 0x0D10778C_u32, 0x63FDAA72_u32, 0x23DE80FA_u32, 0x7001D87C_u32, 0x6C03A243_u32, 0x0131768B_u32, 0x7DAF4D66_u32, 0x71C3DD1F_u32, 0x62CBEFE7_u32, 0x74193792_u32, 0x5EE5ED94_u32, 0x5CE8F177_u32, 0x7772CE56_u32, 0x08A91A00_u32,
+        0x058D92C2_u32, 0x11DB7A8C_u32, 0x27985E82_u32, 0x03847CF2_u32, 0x42C3885C_u32, 0x635D1DBD_u32, 0x62DAFAF2_u32, 0x089C76CE_u32, 0x36A5B1C1_u32, 0x3518574F_u32, 0x08B71758_u32, 0x536AB6FE_u32, 0x76FF3AC3_u32, 0x67B9B638_u32, 0x528E8214_u32, 0x4521D2C9_u32,
+        0x74B0C01D_u32, 0x7BD14818_u32, 0x5AD99BCD_u32, 0x0DF91958_u32, 0x3CC1DCBD_u32, 0x4E657313_u32, 0x4B2DAF19_u32, 0x0B1B99B6_u32, 0x794C9506_u32, 0x7F385909_u32, 0x71DD136F_u32, 0x74C406BC_u32, 0x2E727F06_u32, 0x073DA0C6_u32, 0x4D7E4144_u32, 0x0174D724_u32,
+    ],
+};
+pub(crate) const TABLE_MINSTD_RAND_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0x756F032A_u32, 0x1F564974_u32, 0x5F2465F5_u32, 0x60356C02_u32, 0x79295AD7_u32, 0x0CD05495_u32, 0x267CBE1B_u32, 0x1B4A3DC7_u32, 0x43D6CE5C_u32, 0x24893953_u32, 0x2EC02F2F_u32, 0x4001241F_u32, 0x172A4F9A_u32, 0x0317B126_u32, 0x2053E0C8_u32, 0x27ED9F57_u32,
+        0x4EC0206A_u32, 0x15206738_u32, 0x18E6E767_u32, 0x7AEB0B37_u32, 0x45885CCB_u32, 0x7B514FD2_u32, 0x190A83F7_u32, 0x35C93FDC_u32, 0x56508B1F_u32, 0x5D28F977_u32, 0x211746B5_u32, 0x13ED9BDA_u32, 0x202E4421_u32, 0x73E079D6_u32, 0x13DDE13D_u32, 0x0A59BA57_u32,
+        0x2CF709D8_u32, 0x093961E5_u32, 0x4AF1E881_u32, 0x03E00A76_u32, 0x31D4859F_u32, 0x67D7B138_u32, 0x67A1B740_u32, 0x25E8F569_u32, 0x415A697F_u32, 0x66FA9236_u32, 0x0957EBDD_u32, 0x49531A36_u32, 0x7CABB82D_u32, 0x3F2DA4CA_u32, 0x4F83C1E7_u32, 0x2CFF692B_u32,
+        0x33E7954E_u32, 0x1001FB08_u32, 0x6575310C_u32, 0x3C7BCB29_u32, 0x3258F100_u32, 0x70AAE92A_u32, 0x72B3BC6E_u32, 0x10C7B26A_u32, 0x069299EE_u32, 0x54EECDA0_u32, 0x5066D97D_u32, 0x712BBF43_u32, 0x57E5B123_u32, 0x47642808_u32, 0x6E58A5A2_u32, 0x3D28200B_u32,
+        0x20F2543C_u32, 0x5D394E0C_u32, 0x2853F208_u32, 0x2CA24DE0_u32, 0x1FDA41E0_u32, 0x134B7B0C_u32, 0x3586A820_u32, 0x49AFB8B9_u32, 0x34D077E3_u32, 0x187DF99A_u32, 0x31ABA91A_u32, 0x4F12D6B1_u32, 0x01305D5B_u32, 0x2E92FB95_u32, 0x74DC38D6_u32, 0x00C193B0_u32,
+        0x1496C06D_u32, 0x3585A737_u32, 0x0C751A91_u32, 0x74EC6558_u32, 0x6A79E865_u32, 0x00C39945_u32, 0x11D14AAB_u32, 0x26BE63C4_u32, 0x759DE38E_u32, 0x264F1195_u32, 0x771476A9_u32, 0x0B9313D2_u32, 0x79B65B5A_u32, 0x67F3D291_u32, 0x1FD5B421_u32, 0x38A80954_u32,
+        0x0CB73962_u32, 0x287D1879_u32, 0x7BD9C33B_u32, 0x1A041867_u32, 0x0A356BDC_u32, 0x6F0CDEED_u32, 0x7BE735F9_u32, 0x01CAB89D_u32, 0x5FD06E56_u32, 0x1675572F_u32, 0x37925856_u32, 0x039ED1E7_u32, 0x27DCE95E_u32, 0x7FC79E3A_u32, 0x78B4B6A1_u32, 0x2310F1BF_u32,
+        0x07FF8159_u32, 0x1AB6A87F_u32, 0x0FC58A4B_u32, 0x60E76B20_u32, 0x13F0E5A0_u32, 0x0C31E1C0_u32, 0x71A62C36_u32, 0x044B0195_u32, 0x7B0F548D_u32, 0x7FA87C0A_u32, 0x0A355DA4_u32, 0x6493CDA5_u32, 0x39959F54_u32, 0x0B9604C0_u32, 0x2449B851_u32, 0x6889A6B3_u32,
+    ],
+};
+pub(crate) const TABLE_MT19937_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0x00000000_u32,
+    table: [
+        0x8C7F0AAC_u32, 0x97C4AA2F_u32, 0xB716A675_u32, 0xD821CCC0_u32, 0x9A4EB343_u32, 0xDBA252FB_u32, 0x8B7D76C3_u32, 0xD8E57D67_u32, 0x6C74A409_u32, 0x9FA1DED3_u32, 0xA5595115_u32, 0x6266D6F2_u32, 0x7005B724_u32, 0x4C2B3A57_u32, 0xE44B3C46_u32, 0x0E84BDD8_u32,
+        0xF6B29A58_u32, 0x45CCCD8C_u32, 0x6229393A_u32, 0x7A4842C1_u32, 0xCAAE7DE6_u32, 0xCFEA4A27_u32, 0x8765A857_u32, 0x7ADFC8AE_u32, 0x916B5E58_u32, 0x648D8B51_u32, 0xECF3E6A5_u32, 0xD6094219_u32, 0x122F6B4D_u32, 0x565F9848_u32, 0x164E1B09_u32, 0xA5EE9794_u32,
+        0x052D0873_u32, 0x5E4513D0_u32, 0xD52692F3_u32, 0xF5081EC5_u32, 0xC73547FE_u32, 0x23EE074F_u32, 0xDEB91DAF_u32, 0xDEBE09C0_u32, 0xFA86BB52_u32, 0x793E6063_u32, 0xCC95A7D8_u32, 0xCD087CB1_u32, 0x762382F3_u32, 0x853E031D_u32, 0xC7D0C293_u32, 0xADCB0C93_u32,
+        0x1E473B8E_u32, 0xB87B61A7_u32, 0xA3D1DD20_u32, 0x94FF3FC1_u32, 0x24B2CD09_u32, 0x89914AB9_u32, 0xF1D5D27F_u32, 0xC234A220_u32, 0x8597DA1F_u32, 0x1B1CC2CA_u32, 0x6A2748F4_u32, 0x793DE097_u32, 0x43B9EAA3_u32, 0x2FB379FE_u32, 0xC6342DCB_u32, 0xBCA6AB72_u32,
+        0x74C644B7_u32, 0x376FD81C_u32, 0x9184E322_u32, 0x229DA880_u32, 0x04CF6880_u32, 0x52FAE7A4_u32, 0x9E1D5C35_u32, 0x26511785_u32, 0x9CB24E26_u32, 0x38EA0DE8_u32, 0x9DEF62F4_u32, 0x62F0F111_u32, 0xF199794F_u32, 0xE710B184_u32, 0xAE8BC669_u32, 0x732FEC2A_u32,
+        0x5C08B5BA_u32, 0x9CF1BA1F_u32, 0x6FE15378_u32, 0xE7005101_u32, 0xB297F541_u32, 0x196A6FE7_u32, 0x0F6AEFA9_u32, 0xF8456839_u32, 0xAAB13923_u32, 0xA7342F66_u32, 0xABAEEC77_u32, 0x2BC0BB0B_u32, 0x35DBA1AE_u32, 0x5BAFDC52_u32, 0x2101505B_u32, 0xC02CF780_u32,
+        0x50BFE98E_u32, 0x9B9ACA63_u32, 0x5D1C2635_u32, 0x53364B8C_u32, 0x91F86A79_u32, 0x09D63FAA_u32, 0x70483054_u32, 0xA25FC8CB_u32, 0xFD061144_u32, 0xF57DB306_u32, 0x1A1F9BC4_u32, 0xA71D442F_u32, 0x3578F27F_u32, 0xA29337F4_u32, 0x294B9483_u32, 0xFECBF3CC_u32,
+        0xA7321B64_u32, 0x94F424B4_u32, 0x40D7B7E8_u32, 0x6A140F4E_u32, 0x7760248F_u32, 0x7985C694_u32, 0x3E92ACE3_u32, 0x9F9E5BBA_u32, 0x28B23B17_u32, 0x5687AACF_u32, 0x1C418B8D_u32, 0xACBC9175_u32, 0xA8053755_u32, 0x51342230_u32, 0x235FF531_u32, 0xC741A645_u32,
+    ],
+};
+pub(crate) const TABLE_MT19937_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x39037A7D_u32, 0xE5052ED8_u32, 0xC5DC5C6E_u32, 0x6DDCCBE1_u32, 0xA13AED6C_u32, 0x23839B39_u32, 0x37F0A862_u32, 0x63E92751_u32, 0xC0016042_u32, 0x6C6C241D_u32, 0x8F283C12_u32, 0x94A7DC55_u32, 0x2DBDAC59_u32, 0x5EC675B1_u32, 0xA4EB5FF7_u32, 0xEB813AE4_u32,
+        0x99114764_u32, 0x980D276A_u32, 0x9D7B14D9_u32, 0x21DECBC9_u32, 0x7699CCF0_u32, 0xD94B143B_u32, 0x5BB8E05A_u32, 0xB1A748CD_u32, 0xF5A4D1A7_u32, 0x4E9E983C_u32, 0x3C2CC2F7_u32, 0x4CFBA7C9_u32, 0x183008AF_u32, 0xD3CE2698_u32, 0x34B2311A_u32, 0x80B830F2_u32,
+        0x936B8400_u32, 0x20064868_u32, 0xD0DE8B3B_u32, 0xC6797364_u32, 0x9742E9B7_u32, 0xDEC03B48_u32, 0x2890832C_u32, 0x2B6FDDBC_u32, 0xEDF7CA88_u32, 0x693C836F_u32, 0x8B09A182_u32, 0x9A710689_u32, 0x1FE0D4B5_u32, 0xABA30B59_u32, 0x7385F29A_u32, 0x6BDC298B_u32,
+        0xD4C997D9_u32, 0x8528CC21_u32, 0xEC015E83_u32, 0x831A6866_u32, 0x5EDEBB49_u32, 0x6789B582_u32, 0x933C270B_u32, 0x69832C88_u32, 0x0ECAE417_u32, 0xC03673A1_u32, 0x0B758BDB_u32, 0x8B19E50C_u32, 0x6D96482C_u32, 0x65FD55C6_u32, 0x6CD353F3_u32, 0xD033E74B_u32,
+        0x93A24A77_u32, 0x1C4B3B11_u32, 0x7462E87D_u32, 0x516E1B4D_u32, 0x5DE046C2_u32, 0x2CC07E42_u32, 0xAE48ECBD_u32, 0xE974DD8E_u32, 0xA9AD92E3_u32, 0xA3CDDA5C_u32, 0xE8C71CB2_u32, 0x2A001779_u32, 0xE0749451_u32, 0x52B45B2B_u32, 0x120AFD1C_u32, 0xAAC7528D_u32,
+        0x57E434B0_u32, 0x68CE75F3_u32, 0x5504E012_u32, 0xD2B39EA9_u32, 0xDBF76C6D_u32, 0xA3F2B4FA_u32, 0x00AECD24_u32, 0x2BB5DDAD_u32, 0x3F6CBBC1_u32, 0xAFE20693_u32, 0xC0D19750_u32, 0x670C2518_u32, 0x0C6BF892_u32, 0x8293431A_u32, 0x25C1609C_u32, 0x92DD44D2_u32,
+        0x14F3A5A7_u32, 0x45206681_u32, 0x085FC99D_u32, 0x8117AD3C_u32, 0xFF54AB8E_u32, 0xBE7ACFA4_u32, 0xD624B154_u32, 0xEDD9642D_u32, 0xA90D2549_u32, 0xADEB767C_u32, 0x2F4FC57A_u32, 0x5F34C848_u32, 0x63A002B4_u32, 0xAC332A4F_u32, 0x7BB273EF_u32, 0x8CE1281D_u32,
+        0x609933AA_u32, 0x8B98F6EB_u32, 0x96E78C2C_u32, 0xD7B848E7_u32, 0x6D3652FF_u32, 0xF5CFD797_u32, 0xC5CBA7D8_u32, 0xEA67FD7E_u32, 0x5215FFEE_u32, 0x11751B5D_u32, 0xAA719D71_u32, 0xBDE22416_u32, 0x6EB13BD2_u32, 0x9FEF2AE6_u32, 0x974871E6_u32, 0x93807A95_u32,
+    ],
+};
+pub(crate) const TABLE_MT19937_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0x84A1A4E6_u32, 0x96260D3B_u32, 0xC3962A67_u32, 0x88630C55_u32, 0xC09A5FDA_u32, 0x43AE1554_u32, 0xB0800A3B_u32, 0x1C838C78_u32, 0xA43A510E_u32, 0xC2EC0283_u32, 0xEEA7D224_u32, 0x5F10C4CA_u32, 0xF6792B22_u32, 0x298CD0BE_u32, 0xD1C0048B_u32, 0xFA2EA272_u32,
+        0xD22CB0F3_u32, 0x23EA6A0E_u32, 0xA88A5A34_u32, 0x180A1A1F_u32, 0x7F32961B_u32, 0xA06148DC_u32, 0xFCE382B6_u32, 0x1B5786C9_u32, 0x64BCF6BC_u32, 0xFAA85C88_u32, 0x81496E94_u32, 0x6A64315D_u32, 0xB904C018_u32, 0xF20FBEC4_u32, 0x56C2995A_u32, 0x756E8002_u32,
+        0xDC228283_u32, 0x80DB3CD7_u32, 0x2C3C3AEE_u32, 0x310509BC_u32, 0xC1E5CCCA_u32, 0x73C49894_u32, 0x62D609EA_u32, 0xB506AA20_u32, 0x2165C08D_u32, 0x67D7E02C_u32, 0x26AAD31F_u32, 0x6109EC70_u32, 0x03E778C0_u32, 0xCF25E288_u32, 0xCEF0A0B2_u32, 0x1057AD99_u32,
+        0x95EAB107_u32, 0xBA3F7F6A_u32, 0xC780FC0E_u32, 0x47531CED_u32, 0x316D6814_u32, 0x2CC26D16_u32, 0xCC8EFA10_u32, 0x965FF32E_u32, 0xD4226766_u32, 0x217CB7DB_u32, 0x6E0FCBC2_u32, 0x01E5CB39_u32, 0xE90AB6DC_u32, 0x1B0F4F41_u32, 0xE1DBCE58_u32, 0x5B7CBE43_u32,
+        0x7549DB2F_u32, 0xA1894A1D_u32, 0x072C7E4E_u32, 0x21FD4A35_u32, 0x1A198E7D_u32, 0xBF47A89E_u32, 0x68BBEE29_u32, 0x3CB31F68_u32, 0x9A42EC2F_u32, 0x130EFD9D_u32, 0x8946A7CD_u32, 0x45035F7A_u32, 0x57920B05_u32, 0xDC6C04B9_u32, 0x3B2D9ED5_u32, 0x811212A7_u32,
+        0x11AC478F_u32, 0xE97BE580_u32, 0x4CFB926C_u32, 0xC971F33C_u32, 0xBDB34ADE_u32, 0xA64310E8_u32, 0xB59DE0E3_u32, 0x9818D2A7_u32, 0x44C1B71A_u32, 0xF4C8258B_u32, 0x9B0CE44B_u32, 0x22CF21DD_u32, 0x562D774B_u32, 0x303617B5_u32, 0x418F6302_u32, 0xAF13E8B4_u32,
+        0x83246887_u32, 0xD0527BC8_u32, 0x4D37691A_u32, 0x9AE86EDF_u32, 0x759467AE_u32, 0xC62CE9FC_u32, 0xD457C703_u32, 0xB44A1E41_u32, 0xFFC486B0_u32, 0x2E9CC7B1_u32, 0x26CD49EA_u32, 0xCB85FEB4_u32, 0x3BD2A51D_u32, 0x417439D0_u32, 0xE6F6A27A_u32, 0xBC97953C_u32,
+        0x640F1BBA_u32, 0x85AA5239_u32, 0x75011E0F_u32, 0x80B7D7DE_u32, 0x37B31411_u32, 0x01BDD45A_u32, 0xEFAD296A_u32, 0xCB12A57B_u32, 0x72FFB775_u32, 0x850BA5ED_u32, 0x7B70DB05_u32, 0x85872E72_u32, 0xAF779179_u32, 0x5FD36AE3_u32, 0x32127414_u32, 0x8EA84437_u32,
+    ],
+};
+pub(crate) const TABLE_MT19937_64_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x28E837C5CB41DC3E_u64, 0xFDFD3A7C3E40F98B_u64, 0x0A213217F032E8B9_u64, 0x98F56903CEE3FCEE_u64, 0x8AD330133B0725AC_u64, 0x0EA2062AFD5FE9EE_u64, 0xA1ABD7F3042AE12F_u64, 0x6C6F1E45458246E8_u64,
+        0xD79E62976F604366_u64, 0xE8035E3725DF4A19_u64, 0x6C53702C050D3513_u64, 0xA7D08AC58A5CF6F8_u64, 0xEE30BB851D47868E_u64, 0x5829A6E6164FF306_u64, 0x412E73C6D2C36438_u64, 0x71D5F3D8D6AF3241_u64,
+        0xCA49DA24D4B688F3_u64, 0xB207125F669EF749_u64, 0x8EC426BDAA4EC875_u64, 0x49950AA05A7F5E9C_u64, 0x25543020003D8F0F_u64, 0x944AA62C65F6A8F1_u64, 0x9086530E5E01A162_u64, 0x257BA86BC6240DCA_u64,
+        0x26713C398A95082C_u64, 0x051A63C496F8B52F_u64, 0x8DEDA8537FA6161A_u64, 0xAFF5006CF2DAB62A_u64, 0x635DFF948B1A77DF_u64, 0xEDC51A7F204C1569_u64, 0x2E06F8C09CDC1BB8_u64, 0x309DCDC1901A8C97_u64,
+        0x79E62EC29D25CF8B_u64, 0x41D09E962310A3B3_u64, 0xFAFBFA9D4CFDC407_u64, 0xFC47CBB307EE004F_u64, 0x28D7CF3C6A3011BF_u64, 0xAB8966E4AA3D91A2_u64, 0xC48CA8F37ED75121_u64, 0xD693285C6FB13EF0_u64,
+        0x2C2CBB3FA955C39F_u64, 0x2149A6C8751E6809_u64, 0x511533070D1522D0_u64, 0xE2032D7B74CF6419_u64, 0x66598A6E9C41167E_u64, 0x6AF9D6C9B3A12CA9_u64, 0x8414A3458A4B4603_u64, 0x7EBB3C72234AE251_u64,
+        0x1D8D5DAA93C5EB1C_u64, 0xC2041A9B355570B3_u64, 0x24D65589A8529AD0_u64, 0x36CD58E87D2C7639_u64, 0x31F926B221B7A011_u64, 0x988E0F3F2A3C9BAC_u64, 0xD7CC94D3E1D79751_u64, 0xDD2838951D112E5B_u64,
+        0x878DDF94F8B0C702_u64, 0x8A4FEEEC30677C20_u64, 0x8876F2F3752E6F84_u64, 0x1DB2EC6AFC40040F_u64, 0xF3453B11598AEFB9_u64, 0xCC2CA3620FDBDCA5_u64, 0x4741A63CBF4808A8_u64, 0x8D9868F90FA100DF_u64,
+        0x4D2BB4770253459A_u64, 0x00B381A3888D850F_u64, 0x11E281DE3D6303D9_u64, 0x32BD14B53C2E7764_u64, 0x0C1B5F00C4792840_u64, 0x69DD93B0E9ECABD8_u64, 0xE47005501E77E7ED_u64, 0x688775B7D3DC215C_u64,
+        0xC5E52AE91706F5E6_u64, 0xB1739FB195E42472_u64, 0x133AD1C998EC3AA7_u64, 0x01719CC56A2A4EB3_u64, 0x464939AA430658A5_u64, 0xAC6D9B82594653A8_u64, 0xE7454CD68FBFD8DB_u64, 0x54962A263735104D_u64,
+        0x3A04472074DA302B_u64, 0xCDAAA6D61C39D840_u64, 0x99D826930825DC42_u64, 0xBF7DD5D89B01508A_u64, 0xAA4EB5D34C3AF4E9_u64, 0x278F267C7CD81982_u64, 0x9AE07655B63A3D34_u64, 0x7C5332673C7213BF_u64,
+        0x0C8D28167D5CA7F1_u64, 0xAF4780BDAF47F9A6_u64, 0xB660568648184F63_u64, 0xBCD57ED3810BEE69_u64, 0x907391FA9451BD95_u64, 0x55C693DF78C77B26_u64, 0xFF3C8D4B69F84D1C_u64, 0xD6FA952966B72349_u64,
+        0xD9E9D64F2BA27E39_u64, 0xA9809224F05853FC_u64, 0xC911B240E03E6EDF_u64, 0xCE1384EA52C3250B_u64, 0x67B1C3358A70FF2D_u64, 0xCD28E564CD0B8897_u64, 0xA040F1F997A4B387_u64, 0x18BC3F6A48E8488C_u64,
+        0xD4CB942EC80277BD_u64, 0x91F0D2D36A1D2763_u64, 0x998897ECB33C1044_u64, 0x74247453187AE19C_u64, 0xBDAE4AA32D9ABDC4_u64, 0x5FDC43E5312CAF28_u64, 0x1A04B5751ECE4A98_u64, 0x97192FEF9E3B0819_u64,
+        0xF848BCE0F2AECA52_u64, 0x1A64FC597D072C4A_u64, 0x46A84945C2A61FDD_u64, 0xA53A103A2F0B464B_u64, 0x71D0D57FE4C139A8_u64, 0x932EC3F96EC7DC68_u64, 0xAFD74F85C77C06D8_u64, 0xB7AC7A86645365DB_u64,
+        0xF5D9BCEAB2E83E1A_u64, 0xC677DAB2492619B3_u64, 0x81C0A28156EAC3FF_u64, 0xF3BD7D600734374D_u64, 0x1A70AA6CB4CEACC2_u64, 0xD3BE9A7FD39A89CC_u64, 0x7CB7318958473B13_u64, 0x464BC804699954A2_u64,
+    ],
+};
+pub(crate) const TABLE_MT19937_64_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x4F3D0F0FC79C0BC7_u64, 0x9CD523493FF8D5DE_u64, 0x4C79CA8C9B3C8655_u64, 0xB15D0F48143ECC4F_u64, 0xB6664E86B4EE0EEF_u64, 0x1638C795FCE86A24_u64, 0x0A078F932F39BEFE_u64, 0xB92041DA6AA74526_u64,
+        0x8282F9AF8BEB651C_u64, 0x0978FA726A0B39BD_u64, 0x80762E51743B5E94_u64, 0x42F80E43E5F26F79_u64, 0x0FA73434C8BB5427_u64, 0x6562DD0D9F4DA8E6_u64, 0x0D7999B67AA69050_u64, 0xF485304385859AB3_u64,
+        0x86AC6DB1750A379A_u64, 0x532E7D236A840AFB_u64, 0x37D460936EC564AE_u64, 0x3DE3A8CAF3EB03F2_u64, 0xF6DAF14E00E422A7_u64, 0x97E716ACD15A163D_u64, 0xCA99A26C3808E5FD_u64, 0xC026EC408B59252F_u64,
+        0xE50C6E56AABCE0BB_u64, 0xC6ADCDE86DDB730F_u64, 0xA7FA298AD0C8F1A4_u64, 0x425C3AD41A9756C4_u64, 0xE840293B859E099C_u64, 0x075A6E20CA567562_u64, 0x1A26BC088B7EB7A4_u64, 0x8FC8E931B96ECD2F_u64,
+        0x192CF1C49794964F_u64, 0xBE4934AEE2D2128E_u64, 0x3C5ACEBC7ED80E9F_u64, 0xE3537A2D549974D7_u64, 0x1D876057DAFF641B_u64, 0xCC1EBF3095AD842D_u64, 0x611099DD891CABBA_u64, 0x95FE5226C1CEB9C1_u64,
+        0x536A562BC204C30B_u64, 0x1BD37EC7FB646E17_u64, 0x60680CFE5C5EB6EE_u64, 0x5E8B52472FA9AC1A_u64, 0xBC001FF789AA26C7_u64, 0xE3F335338E4EA743_u64, 0xDD0777B99CEDD4A1_u64, 0x9A30AA3B529E1B75_u64,
+        0xDB283796618D7A87_u64, 0x37296ADC68A23447_u64, 0x12A1D0CA7E899BBE_u64, 0xE4F33CE01ED0909A_u64, 0xE219366528593DC7_u64, 0x4C415C0D29B5A6CC_u64, 0x675C8C97BD699F0D_u64, 0x153EDA27582AE17E_u64,
+        0x5039CCB3C4D0F609_u64, 0x3ABD777D4D2BDDCE_u64, 0x485E93BC3A4A7EAE_u64, 0x420C946DABF76A53_u64, 0xB90AB18744D905B8_u64, 0x867AD84CE5CDC847_u64, 0xE9AE2A034D16AB6E_u64, 0xCD46C2A7B24555C2_u64,
+        0xAE34D028A0C76252_u64, 0x548B2AAA75A8738C_u64, 0xA04B64E8073A105A_u64, 0x15B7E1F8ED52F891_u64, 0x95D78410AEBD096E_u64, 0xFDCD4FC6320047CF_u64, 0x8D9D8516A411D2A9_u64, 0xD2B02F549A809A9C_u64,
+        0xBCF78EE2AE959AFD_u64, 0x61F0261D30292282_u64, 0x6CD97264F8FDEE77_u64, 0x1FF0B5381838B82A_u64, 0x0CF7C90DE7FFD13A_u64, 0x28CBFCEF28AA8F22_u64, 0xD283DC80C26C0F84_u64, 0x872B3402DEBD5B69_u64,
+        0x1D24650E28263354_u64, 0x761315560C07C633_u64, 0xE594206D041FDACA_u64, 0x89079CEB24BA61BB_u64, 0xBF35D1666C976886_u64, 0x38D4A21C5554C40B_u64, 0x99D72C577D1514EA_u64, 0xDC445D620C0EE1B0_u64,
+        0x162E8CBA1794392E_u64, 0x2ADC636ED9A497DE_u64, 0xD591677269DBE973_u64, 0x7FF5AC83B4FF6F97_u64, 0xD02B65FB445E04A5_u64, 0x36F86BDDBA4C25B0_u64, 0xCB38D4698E20A090_u64, 0x134A1BC21E6CBC33_u64,
+        0x1A5BDBDD7EBC18D6_u64, 0x9BEFB78C2CA8BAC1_u64, 0xE12B0C0653EBE303_u64, 0xBF733C0E2C088B48_u64, 0x35B0A3418D2D4705_u64, 0x664684A403956969_u64, 0x4506802B1AF5D37B_u64, 0x7EDE1F90321559DA_u64,
+        0xF6ED90C94F971252_u64, 0xA2F6EBFD27FC517D_u64, 0x63C9ACF3ECE2EC7D_u64, 0xD8D5C0425F5C3561_u64, 0xA16802CB61BA9BAE_u64, 0x1697589EDF997E7F_u64, 0x65EFD74C69AC1EFB_u64, 0xFAD37F50B9BCFDC6_u64,
+        0x6467BEBF7AC52D1C_u64, 0x6B534EC899106360_u64, 0x943B1F4315FBCEA2_u64, 0x08E6CBC50F0220AE_u64, 0x5DCB6735430DD859_u64, 0xB8654E3E2372023E_u64, 0xBB18E78F2B47FD48_u64, 0xF631D8A273FBB185_u64,
+        0xA1C07C268ECA30DD_u64, 0x5A339709BF3F5329_u64, 0xA5F33278E49435A6_u64, 0x0AA58BBEF9D636C3_u64, 0x347571D1CAD44F74_u64, 0xDB2B85B2C5F3185C_u64, 0xD4428602736FB223_u64, 0xE122475EBB4A9153_u64,
+    ],
+};
+pub(crate) const TABLE_MT19937_64_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0xB09E6ED44038BDCE_u64, 0x701F399762EDE03E_u64, 0x32A135B5A65223BF_u64, 0x5A9DD2A96A80176D_u64, 0xCB2200A06838FD51_u64, 0x4D949B73D2BCADEE_u64, 0x62C2B78F4F22FF56_u64, 0xB78A442DA34A75C5_u64,
+        0x33DCCCDF957E69B5_u64, 0x232BA36EF7BEF6BE_u64, 0xE7614ED1916BAC16_u64, 0x6BC71BFC598D51B6_u64, 0xCD0E81AEACBB0803_u64, 0xED0B4203E47F84B9_u64, 0x1A459A020E6DEA7B_u64, 0x982A42EE49A84899_u64,
+        0x2BBC113A37ED6A79_u64, 0x07C103853CB1BE57_u64, 0xA05AC0580C1F8C15_u64, 0x091D6076B09B6DE7_u64, 0xEAB22AD3835B16C3_u64, 0xCFE2A78676C3F00A_u64, 0xB350D4445651AFF9_u64, 0x6214A1E9480AFA57_u64,
+        0x091DF81FC06AB2D3_u64, 0x310FF2D6B9185662_u64, 0x5D4713B24DAC97FF_u64, 0x11754005A65DF38C_u64, 0xF10FC56BD5976E3A_u64, 0x0CA603B42321F6A3_u64, 0x6019AA0B67B57267_u64, 0x2B14883A76E2FA1A_u64,
+        0x2F37AA059EF26A46_u64, 0xFB7ACE1955ACB9B7_u64, 0x467F494CD82CA0C5_u64, 0x6D8533886074C3E0_u64, 0x89A2B87E6A6AC167_u64, 0x0F5770882CA10DD4_u64, 0x85CFB85236539AB7_u64, 0xDD1E8505BEF57BC3_u64,
+        0x52D52F1CD3199CD6_u64, 0x5FCC0178393756AA_u64, 0xE17D56890E46657F_u64, 0x5395FF747C1E26BC_u64, 0xA8FCF3EE9230EE85_u64, 0x0C387F1C5E6E87F8_u64, 0x21389157C0B22D68_u64, 0xD48A50AC5A80471A_u64,
+        0x4CC6CE2D75BD1DC1_u64, 0xFFB0B5DACF8705CD_u64, 0x6AD757FF8EF115C5_u64, 0x7E51C8021D221C5A_u64, 0x2E25F30677243BE5_u64, 0xB6560B25CF319AE5_u64, 0x76D5F31197E67373_u64, 0x2CB81C760D11AB1B_u64,
+        0xC8B8E1C23EEDDCDA_u64, 0x058BF9D664A7F354_u64, 0x88C79A0655DC42F4_u64, 0x1B8FD52D310DBD28_u64, 0xCEA17BB8B5C1CA18_u64, 0xDB557CE4397FFBC0_u64, 0xE2981C8455C29C48_u64, 0x5B39921E0ED163FC_u64,
+        0xA6576B64DE44B120_u64, 0x4EA50CA0345B6F28_u64, 0xB2EADA66649F5296_u64, 0x353084B5458DA13F_u64, 0x0741B91D4F3CA5B6_u64, 0x8B9631E3A4A9E0A1_u64, 0xF9EB36B5CEBD6BBA_u64, 0xC85C4D3CE67FA3C0_u64,
+        0x8DE5ACB11E5CDB33_u64, 0x8AD8BC2FD680C919_u64, 0x8175AA0C668B444E_u64, 0x754FB4CD90B2B3A0_u64, 0x9CCFA762DDB66104_u64, 0x43D9F2D09E1495B5_u64, 0xB1A29F4C337DE535_u64, 0xCAFF2AAC965C9D2A_u64,
+        0xCE58CA9BDEADE831_u64, 0xCB5C48C158D7AD03_u64, 0x78F44FD593F28560_u64, 0x5464205D551EF31C_u64, 0xF87781AB07378387_u64, 0xFC9134470ABBE2B3_u64, 0xF4C254EDDA361C46_u64, 0xFACC88BAAECB2F4B_u64,
+        0x6D0AAB308B8864B8_u64, 0xB5C1D055E4CC1F83_u64, 0xA84DCA8C7A200C2D_u64, 0x65DE919C73ED3AE9_u64, 0x908E82F4F64CBFAA_u64, 0xD30AD98D490A7FB7_u64, 0x0750A917B1C159FF_u64, 0x0BB1E93027A70EE4_u64,
+        0xFA6220203A8EE194_u64, 0x77813258928468FE_u64, 0x5DB541FD0A6FF426_u64, 0xEB3C1EEB6A480787_u64, 0x76F1EBA7839FC488_u64, 0x4095AF1C6AB11F75_u64, 0xEA25D1DB4080E8B9_u64, 0x958C2752147D2D14_u64,
+        0xB5BB1107AB8CDE6F_u64, 0x0A7A7718D28AB45C_u64, 0xA7B25427B4BE02E6_u64, 0x82B5044828F3F1C4_u64, 0x572FE7EEAC396C69_u64, 0x7C461B8395884C36_u64, 0x766472C95091914E_u64, 0x09046AF6C213C891_u64,
+        0xA63428CD2A2FA19B_u64, 0xEA53C9CE207D55AE_u64, 0x30BF244738D260CA_u64, 0x285A62D6E9CDB6E1_u64, 0x2BED2F433F67F766_u64, 0x8847ED9444734832_u64, 0x43F9ED40B3B9BE17_u64, 0x141E5E347E8B8A74_u64,
+        0xE6EFC50B1A231BEC_u64, 0x51083C80C3D335B5_u64, 0x76277C7F8C0F1E47_u64, 0x49114E800F2B0010_u64, 0xB94A309736DA6276_u64, 0xDA9070C9723A636A_u64, 0xB19E83FF4B388025_u64, 0x8E805DA56F79BB65_u64,
+    ],
+};
+pub(crate) const TABLE_RANLUX24_BASE_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0x00000000_u32,
+    table: [
+        0x00E57B2C_u32, 0x00F91555_u32, 0x00D9F2DE_u32, 0x006D1A0C_u32, 0x000109F9_u32, 0x0082FBCA_u32, 0x004B0B07_u32, 0x00AD771D_u32, 0x0083E7BB_u32, 0x007F4CB8_u32, 0x0034C3E0_u32, 0x0066BD4F_u32, 0x0080D522_u32, 0x00124168_u32, 0x0034989B_u32, 0x0006DC8E_u32,
+        0x004D4B24_u32, 0x0044E9DE_u32, 0x00961D27_u32, 0x006B3254_u32, 0x00F433F4_u32, 0x00DBFEFB_u32, 0x0029BF1D_u32, 0x00EE5181_u32, 0x004F1D6E_u32, 0x000DC738_u32, 0x00735845_u32, 0x00D7CFD1_u32, 0x0095132D_u32, 0x00E8368A_u32, 0x00A928EC_u32, 0x002E87DE_u32,
+        0x00A5D762_u32, 0x006F04C8_u32, 0x001A598E_u32, 0x00A709E9_u32, 0x00F28322_u32, 0x00C58E68_u32, 0x00607A92_u32, 0x00E159FC_u32, 0x005BDDC8_u32, 0x00E99E00_u32, 0x000FBA3A_u32, 0x0003D274_u32, 0x0026259A_u32, 0x00CB0AED_u32, 0x00C8C404_u32, 0x00D73CE7_u32,
+        0x00115D23_u32, 0x00D392C4_u32, 0x00E88583_u32, 0x0011CE2E_u32, 0x007AA70D_u32, 0x001B9BE9_u32, 0x007CFCAD_u32, 0x009C830E_u32, 0x0022ECA2_u32, 0x0068381F_u32, 0x00F70395_u32, 0x002C88DA_u32, 0x00F60261_u32, 0x004C3FC5_u32, 0x001A2C7A_u32, 0x003A41ED_u32,
+        0x00211EE4_u32, 0x00B2E50E_u32, 0x00133267_u32, 0x006465AB_u32, 0x00D0DDFB_u32, 0x00617DED_u32, 0x002D3E5C_u32, 0x007502DE_u32, 0x0008CF56_u32, 0x0066AF29_u32, 0x00389960_u32, 0x00A116DF_u32, 0x00988B5A_u32, 0x0048C9C1_u32, 0x0053E14E_u32, 0x00C4FADF_u32,
+        0x000A51B9_u32, 0x000CCABF_u32, 0x0011CBC1_u32, 0x003A264E_u32, 0x004296FF_u32, 0x0054D719_u32, 0x007E5EE0_u32, 0x000E87D4_u32, 0x0032C26A_u32, 0x001215D1_u32, 0x00F71F52_u32, 0x00A86513_u32, 0x0040EDC5_u32, 0x00D8A860_u32, 0x001558A2_u32, 0x00DFD43B_u32,
+        0x00758F89_u32, 0x00A7D8AB_u32, 0x00FA2909_u32, 0x0070FEF1_u32, 0x005E93F7_u32, 0x005F9B52_u32, 0x00ED0C77_u32, 0x0013AD80_u32, 0x000B06E9_u32, 0x00D3097C_u32, 0x0063C3C8_u32, 0x006DB25D_u32, 0x00B7920A_u32, 0x001C27D8_u32, 0x00E03517_u32, 0x0051137D_u32,
+        0x00BA4A0D_u32, 0x000197AF_u32, 0x0013E797_u32, 0x002AA468_u32, 0x0022D603_u32, 0x009509FD_u32, 0x00A23967_u32, 0x003C539D_u32, 0x006AA58D_u32, 0x00A93AD2_u32, 0x00C02103_u32, 0x009098BD_u32, 0x00B5539F_u32, 0x00CB0915_u32, 0x0035C98B_u32, 0x00815C7C_u32,
+    ],
+};
+pub(crate) const TABLE_RANLUX24_BASE_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x00A401B7_u32, 0x00F06E1B_u32, 0x00701350_u32, 0x00FA0483_u32, 0x0017290A_u32, 0x00098AB7_u32, 0x00606336_u32, 0x00ECD4A8_u32, 0x00C400FF_u32, 0x004F5850_u32, 0x00CE63FB_u32, 0x00B1F1A4_u32, 0x0089B045_u32, 0x006CC5DB_u32, 0x00CF0D7A_u32, 0x0041A19D_u32,
+        0x00503E96_u32, 0x0093F878_u32, 0x0050D80F_u32, 0x00232A73_u32, 0x00446DC2_u32, 0x006E8873_u32, 0x00BDF001_u32, 0x002CCF6B_u32, 0x002B0BC3_u32, 0x00513382_u32, 0x00E02B45_u32, 0x0099F3F4_u32, 0x0039AF04_u32, 0x00199FBC_u32, 0x00E40A8C_u32, 0x0081B3CA_u32,
+        0x00F9EF01_u32, 0x00DD771A_u32, 0x005CA7C7_u32, 0x009F41DD_u32, 0x00567AFF_u32, 0x002D2E19_u32, 0x006AA18A_u32, 0x00D7FE1E_u32, 0x0093CBF5_u32, 0x00EDBB52_u32, 0x00A916F1_u32, 0x00BA4CA7_u32, 0x00183A05_u32, 0x0030B96A_u32, 0x00988AFE_u32, 0x00005EAD_u32,
+        0x003F95C7_u32, 0x0086CA9C_u32, 0x00B3A0B0_u32, 0x0053C75D_u32, 0x006F67ED_u32, 0x00A0ACEB_u32, 0x00342F79_u32, 0x00AF059F_u32, 0x009E9BFC_u32, 0x0022E792_u32, 0x00E2EDFF_u32, 0x00E788BE_u32, 0x005D25B0_u32, 0x00269944_u32, 0x0004C663_u32, 0x00C8AECD_u32,
+        0x00A06383_u32, 0x00C14A4C_u32, 0x00F5850A_u32, 0x00689AEA_u32, 0x00CAB3F9_u32, 0x00B6CF54_u32, 0x00C49AB2_u32, 0x00263A96_u32, 0x00C5309C_u32, 0x0041E430_u32, 0x00ECC2D3_u32, 0x006D82EE_u32, 0x00861D1D_u32, 0x00C7EDFF_u32, 0x0096847F_u32, 0x0007C9B5_u32,
+        0x0025FEB6_u32, 0x00035304_u32, 0x00E2429D_u32, 0x005A5B71_u32, 0x008F9D22_u32, 0x0046E9AA_u32, 0x008156BA_u32, 0x00FF3F32_u32, 0x00F620FB_u32, 0x00467F68_u32, 0x003079AB_u32, 0x009AB819_u32, 0x00178EA3_u32, 0x00A38C1D_u32, 0x00CB026F_u32, 0x0020AF13_u32,
+        0x00BC261E_u32, 0x00BD5B01_u32, 0x00095E28_u32, 0x00D8FC7A_u32, 0x00AA5C8D_u32, 0x00D2CA19_u32, 0x00810A23_u32, 0x009BC267_u32, 0x00A503B9_u32, 0x001D5C0F_u32, 0x00D9E381_u32, 0x0062FF8F_u32, 0x0079C106_u32, 0x009212CF_u32, 0x002905D3_u32, 0x00D38AE7_u32,
+        0x008AE927_u32, 0x005542FE_u32, 0x00748A0E_u32, 0x0082A3F6_u32, 0x00C254DD_u32, 0x00BF7372_u32, 0x00AEBE96_u32, 0x007163BB_u32, 0x006CDFB5_u32, 0x00162FE5_u32, 0x00818AFF_u32, 0x007C4684_u32, 0x00CA2D80_u32, 0x00AFD9DC_u32, 0x00414AB9_u32, 0x0023B10B_u32,
+    ],
+};
+pub(crate) const TABLE_RANLUX24_BASE_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0x00912C8D_u32, 0x005EE33E_u32, 0x00642F4E_u32, 0x0072D5C7_u32, 0x005CEFA8_u32, 0x00878974_u32, 0x00FF2CB6_u32, 0x000607D6_u32, 0x00AFA8D9_u32, 0x005E9AE6_u32, 0x00587F15_u32, 0x00342A48_u32, 0x00986BEC_u32, 0x003A3509_u32, 0x0010C72D_u32, 0x00A2DC0E_u32,
+        0x007862F3_u32, 0x00E6265C_u32, 0x007151C4_u32, 0x0021290C_u32, 0x00345357_u32, 0x005996B4_u32, 0x00FB6F14_u32, 0x00475D5B_u32, 0x007F9A9F_u32, 0x0043F8CF_u32, 0x001433A5_u32, 0x00735095_u32, 0x0014621C_u32, 0x00999F98_u32, 0x003526A0_u32, 0x00538EDD_u32,
+        0x004BC63B_u32, 0x00E8C275_u32, 0x00271B89_u32, 0x000FCE87_u32, 0x007BC7B9_u32, 0x00391B8B_u32, 0x00039AEF_u32, 0x00F6C38A_u32, 0x00BCC3AC_u32, 0x006D6880_u32, 0x00DA7476_u32, 0x00C79968_u32, 0x00F2C832_u32, 0x00B637D2_u32, 0x008058A4_u32, 0x00F1BE2F_u32,
+        0x0084004F_u32, 0x00B2CABA_u32, 0x00A89007_u32, 0x00FA17EB_u32, 0x00C61259_u32, 0x002DF9D0_u32, 0x00BDA192_u32, 0x0062A8F5_u32, 0x00349269_u32, 0x0008FBBA_u32, 0x005CE4C6_u32, 0x00A2FC33_u32, 0x002CC84E_u32, 0x00C0FC60_u32, 0x00C2776A_u32, 0x00373646_u32,
+        0x0000DDE5_u32, 0x00F54075_u32, 0x005A1DF2_u32, 0x00416251_u32, 0x006A1C93_u32, 0x00ECC460_u32, 0x00AC6FA9_u32, 0x00CF3E30_u32, 0x003E771A_u32, 0x00846B8C_u32, 0x00584DDD_u32, 0x00FB2889_u32, 0x00940B98_u32, 0x00136880_u32, 0x00AC7B01_u32, 0x008A1B6A_u32,
+        0x0077DD40_u32, 0x00C64276_u32, 0x00E19254_u32, 0x00E16F58_u32, 0x002B858E_u32, 0x003A2C29_u32, 0x00D1942E_u32, 0x00DC3239_u32, 0x00AB9D1B_u32, 0x0094DAF5_u32, 0x001DBF4D_u32, 0x0084E025_u32, 0x007775C1_u32, 0x00F4AAF8_u32, 0x007F15E4_u32, 0x006AEDF8_u32,
+        0x00931D13_u32, 0x0057C6AD_u32, 0x00534F3E_u32, 0x0099B26C_u32, 0x0089B3B4_u32, 0x007177A4_u32, 0x00CAFAC0_u32, 0x006A8F8D_u32, 0x000738A4_u32, 0x00A4AB82_u32, 0x00B18ABE_u32, 0x00765754_u32, 0x0027C9AF_u32, 0x005F8643_u32, 0x00B81F86_u32, 0x0095456A_u32,
+        0x001F5DA4_u32, 0x00D5B498_u32, 0x00E97956_u32, 0x001FCB5C_u32, 0x003A14FD_u32, 0x0081AC5C_u32, 0x00A8B3CA_u32, 0x00F4984A_u32, 0x00250272_u32, 0x003D7EBD_u32, 0x00CC0E66_u32, 0x003C022B_u32, 0x005FC5A2_u32, 0x00AE53B8_u32, 0x006F1A3C_u32, 0x00171CCE_u32,
+    ],
+};
+pub(crate) const TABLE_RANLUX48_BASE_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x00001555FCE57B2C_u64, 0x00001A0C0CD9F2DF_u64, 0x0000FBCA490109FA_u64, 0x0000771E394B0B07_u64, 0x00004CB93B83E7BC_u64, 0x0000BD4FE734C3E1_u64, 0x00004167D880D522_u64, 0x0000DC8F0334989B_u64,
+        0x0000E9DFD24D4B24_u64, 0x00003255C9961D27_u64, 0x0000FEFC7CF433F5_u64, 0x000051806029BF1D_u64, 0x0000C739064F1D6E_u64, 0x0000CFD3C5735845_u64, 0x0000368B8095132D_u64, 0x000087DE43A928ED_u64,
+        0x000004C724A5D761_u64, 0x000009E91F1A598D_u64, 0x00008E6BECF28323_u64, 0x000059FC7D607A92_u64, 0x00009DFE715BDDC8_u64, 0x0000D2715B0FBA39_u64, 0x00000AECA2262597_u64, 0x00003CEB8CC8C405_u64,
+        0x000092C377115D24_u64, 0x0000CE2AABE88582_u64, 0x00009BE5DA7AA70B_u64, 0x0000830E5E7CFCAA_u64, 0x000038246822ECA3_u64, 0x000088DA57F70397_u64, 0x00003FBEBEF6025F_u64, 0x000041E95D1A2C79_u64,
+        0x0000E50FED211EE2_u64, 0x000065B30D133269_u64, 0x00007DEDB5D0DDFF_u64, 0x000002D3322D3E5A_u64, 0x0000AF25E608CF55_u64, 0x000016E54138995F_u64, 0x0000C9CD32988B5E_u64, 0x0000FADF5753E154_u64,
+        0x0000CAAECA0A51B6_u64, 0x0000264B8E11CBBD_u64, 0x0000D72682429700_u64, 0x000087E3D57E5EE4_u64, 0x000015CF6A32C272_u64, 0x000064FBBCF71F4D_u64, 0x0000A85DD840EDBE_u64, 0x0000D453501558A5_u64,
+        0x0000D8BDEF758F8F_u64, 0x0000FEEA28FA2912_u64, 0x00009B2E8A5E93EE_u64, 0x0000AD7E80ED0C69_u64, 0x000009A4860B06EE_u64, 0x0000B2726163C3D2_u64, 0x000027C3A6B79212_u64, 0x0000134AB4E0350A_u64,
+        0x000097AF16BA49F7_u64, 0x0000A4A8C913E7A1_u64, 0x00000A148922D613_u64, 0x0000537056A2396D_u64, 0x00003A8CC56AA57A_u64, 0x000098C4EDC020E4_u64, 0x0000097A3EB553B2_u64, 0x00005C960835C9AA_u64,
+        0x000049CBD097327E_u64, 0x0000881A6406E1A8_u64, 0x0000710147088ED1_u64, 0x0000F62F89D51EA8_u64, 0x0000C4E6F17B7FB2_u64, 0x0000A52307834ADC_u64, 0x00007E05DAE40B94_u64, 0x00001D90F0665564_u64,
+        0x0000BBA2C46A792E_u64, 0x00002C2203BB5ECE_u64, 0x00009BA8C8CDF72A_u64, 0x0000216FD2AE41EA_u64, 0x0000D3C51FCF22E6_u64, 0x0000338860639785_u64, 0x0000BB20BCB2CFFD_u64, 0x0000A5793EF8D881_u64,
+        0x00005C88E132C237_u64, 0x00002EA2184BD809_u64, 0x0000B582857F8BF1_u64, 0x00009D8FCC4C7A98_u64, 0x0000E9D67A8E5F53_u64, 0x00003066DD776368_u64, 0x000092F94F7DE0DF_u64, 0x00009412B2D14A06_u64,
+        0x0000C9CAAC7D57B2_u64, 0x0000B64E1A2AC7CD_u64, 0x0000754620C4936B_u64, 0x0000ED801085085D_u64, 0x00003789D19E87CE_u64, 0x00009B2894317FA9_u64, 0x000000CB94AB3BDC_u64, 0x0000D7B6547818D3_u64,
+        0x000003A995F6A909_u64, 0x00000722F4272466_u64, 0x0000082F44B39ECA_u64, 0x00006CB8E1D9F1D6_u64, 0x00000DEBA7FAC120_u64, 0x00004D5B7BCBE13C_u64, 0x000091DCD36290FA_u64, 0x00001AAF342E966C_u64,
+        0x0000352F103B6A07_u64, 0x000072C313C94177_u64, 0x00004C8FE720A55F_u64, 0x0000BA267EEA7827_u64, 0x000017059E37ED62_u64, 0x00002E0C1C1445A1_u64, 0x00006A93CF15A2AD_u64, 0x0000DFD70546B389_u64,
+        0x0000AC3AD6EFB706_u64, 0x0000C9AA226C0C26_u64, 0x00009C2F48B1B4A6_u64, 0x00004FE49AE70C40_u64, 0x0000AAA7F50B4982_u64, 0x00003977C326758F_u64, 0x00007D1A3B4B66C7_u64, 0x0000E208C9C73C7F_u64,
+        0x000038DEFCAF1EDD_u64, 0x00007C9BD8F703E1_u64, 0x0000CEE3F410D2E2_u64, 0x00009D433604B33D_u64, 0x000035CDF2D78578_u64, 0x00006F34DA4312B7_u64, 0x0000E06C90454F3A_u64, 0x00007EFF5929C6A1_u64,
+    ],
+};
+pub(crate) const TABLE_RANLUX48_BASE_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x00001969DC7A3AD3_u64, 0x000002976FD11308_u64, 0x0000960BDCBE28FF_u64, 0x000044B727012A76_u64, 0x00001FD244D84D34_u64, 0x0000B51CBC66C38E_u64, 0x0000B45C27078A02_u64, 0x0000FF7FA58FAEE7_u64,
+        0x0000F3E9B0C1EFE7_u64, 0x000015053F209FE6_u64, 0x00001BD43EA4E22A_u64, 0x00002906DD1CDE05_u64, 0x0000E615C9157414_u64, 0x0000F15240F0DCDF_u64, 0x00007EF9626276E7_u64, 0x0000D71D17A3B7B3_u64,
+        0x00000934984490D0_u64, 0x000030F90CAEB086_u64, 0x00003CF619E952DD_u64, 0x00007F79BCD2C800_u64, 0x0000E33366E1C7CB_u64, 0x0000F42F5923F0E9_u64, 0x00001524CE09CE5B_u64, 0x000013EF3CCC74D8_u64,
+        0x00009963F3BD53EC_u64, 0x0000F1E125F0EAEB_u64, 0x00007535F6C17A01_u64, 0x00003E07B66616A8_u64, 0x00000ABAA487E407_u64, 0x0000686AE70EA366_u64, 0x0000B4EB0C07980E_u64, 0x0000F5BC39EEB201_u64,
+        0x00005AD44F844EDC_u64, 0x0000168B4B63F31D_u64, 0x000053461904D50A_u64, 0x0000A0FBCF3B2336_u64, 0x00005C5846315E15_u64, 0x000068F3299363F1_u64, 0x0000A15554A2791B_u64, 0x0000153E629EBE61_u64,
+        0x000096412AB33F2F_u64, 0x0000F3ED5F22BAAF_u64, 0x0000B4081D8BCBE2_u64, 0x0000AB991AB3C719_u64, 0x0000BA6A131A6F84_u64, 0x00007FB5DF4F4C11_u64, 0x0000A0A7461DE5A5_u64, 0x0000130C4E50A8AC_u64,
+        0x00004F40D4826904_u64, 0x00005176E9870B93_u64, 0x0000DE608AACD2F6_u64, 0x00008B68E37F2743_u64, 0x00007CCB239D697D_u64, 0x00005B53755FAE54_u64, 0x00009D6ECBFB3FB0_u64, 0x000032C76FF90BDC_u64,
+        0x0000D0FED064B7BF_u64, 0x0000FD15444E1D6B_u64, 0x0000BAAC2F41C8AE_u64, 0x00008A627DAA9703_u64, 0x0000E3869B76A2D8_u64, 0x00007F87E6DDAC2B_u64, 0x00001EB4B9A14A75_u64, 0x00002F434BC2A16B_u64,
+        0x00000D975A0D2D86_u64, 0x000088332616F484_u64, 0x0000E2191AE26C7B_u64, 0x0000EBED49A83E98_u64, 0x00005E447B5DE9AB_u64, 0x0000108215BF101A_u64, 0x0000CD86F6D52BD5_u64, 0x000057B69D37D577_u64,
+        0x00000866AE319BC0_u64, 0x0000DEBC94803D80_u64, 0x0000F1CD5C1DC5A4_u64, 0x00009E43AB128A69_u64, 0x00004A1F432AA7F1_u64, 0x00008033881AA73C_u64, 0x0000FCA3799DD104_u64, 0x000005E01275870B_u64,
+        0x00003FFF2FB4A0BE_u64, 0x0000399D2D6B97D7_u64, 0x0000B2AC91457B67_u64, 0x0000A4ECDC65FB8C_u64, 0x0000FD796443EB4B_u64, 0x000061429B34633D_u64, 0x000047CFD14DD232_u64, 0x00001468E632F0FD_u64,
+        0x00005ACD993B539B_u64, 0x00007D45DC29440F_u64, 0x0000649F21969239_u64, 0x000041EFBED84B26_u64, 0x0000D469B67E503F_u64, 0x000021306BCFBBC3_u64, 0x0000CA994AE3C8A8_u64, 0x0000BFB2453096AC_u64,
+        0x000044765A945FDA_u64, 0x000073271B49ED01_u64, 0x0000D9609A81E991_u64, 0x0000B63064B0D7AA_u64, 0x000064E4ABF54311_u64, 0x0000C7307E6B1BCB_u64, 0x00000E87F9B35AC7_u64, 0x00009770DBA99E6B_u64,
+        0x0000E1C6AE32876B_u64, 0x000043B44025874D_u64, 0x0000FC9733875323_u64, 0x00004ED5B482C41A_u64, 0x000052FA81153E90_u64, 0x00006E9F92E89A6A_u64, 0x00006A53A5A39DBC_u64, 0x00004666CED67B78_u64,
+        0x0000E9F1088D8109_u64, 0x00008BCA02AA22C4_u64, 0x0000601799353FA2_u64, 0x0000D2E2C9F9FF51_u64, 0x000064A020A3F40C_u64, 0x0000A63CC867F9BB_u64, 0x00008F32CF22CFA1_u64, 0x00001141E4B27B87_u64,
+        0x00007FE848E4C0C1_u64, 0x0000F6008DBB59A2_u64, 0x00003BE922C45BFE_u64, 0x000048CC004C5429_u64, 0x00002750DC24FA7E_u64, 0x0000F41E463A9DFC_u64, 0x000095E8F48619FF_u64, 0x0000690658CA5CAD_u64,
+    ],
+};
+pub(crate) const TABLE_RANLUX48_BASE_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0x00005C98B782FA37_u64, 0x0000D510E9591291_u64, 0x0000110BFA567908_u64, 0x0000908141B54CE0_u64, 0x000006A026DAB6E4_u64, 0x0000C22E3806F936_u64, 0x00009CC98C9705D7_u64, 0x00008AB8C424D5A1_u64,
+        0x000032C736A6B7C4_u64, 0x000014B00E8A5424_u64, 0x00003A14C54E2D68_u64, 0x0000208C38C18F58_u64, 0x00002E200CA1DB6A_u64, 0x00005DB64D4DA533_u64, 0x000003A41433DB1B_u64, 0x0000A9938398E088_u64,
+        0x000019EC11E6D873_u64, 0x00006BF1D49AE234_u64, 0x0000C0ECC0B69F5B_u64, 0x000078EB500F0579_u64, 0x000076CC4CF228C3_u64, 0x0000053C035C844F_u64, 0x000031DD0F4CB4CC_u64, 0x0000A06087F51003_u64,
+        0x00004ACB436D2A0F_u64, 0x00001915FFA48390_u64, 0x00000197EF28A934_u64, 0x000088498BB3D444_u64, 0x00008674760E378F_u64, 0x0000DED96ED247DB_u64, 0x000058293EEDE434_u64, 0x000088AC9F19A3BA_u64,
+        0x0000117D3EC1AB80_u64, 0x0000813872B1B340_u64, 0x0000ACFC5F85930F_u64, 0x0000B7C8B6F8D431_u64, 0x00003DE15BAC79AA_u64, 0x0000F8673F1D27F0_u64, 0x00007FA083890A0B_u64, 0x000024B2D3D1BECB_u64,
+        0x0000315440EA9CA2_u64, 0x00005F07ECDA31CF_u64, 0x0000A03E002F43BB_u64, 0x0000F6F3E46F6651_u64, 0x000013359510134A_u64, 0x0000B01BCE38E962_u64, 0x0000B20B8D549EBF_u64, 0x0000E87549366F89_u64,
+        0x0000B91288C2ECA6_u64, 0x00001ACE55F2EB5A_u64, 0x0000307B4AAFDF56_u64, 0x00008D58B982DFF4_u64, 0x0000B721084BD2E7_u64, 0x00005A0A9BE8BAD7_u64, 0x00007A9055C3A79F_u64, 0x0000398766407904_u64,
+        0x00007A232472CCA9_u64, 0x000007053A12E985_u64, 0x0000A7FF0E941C18_u64, 0x0000921B0C8D3815_u64, 0x00008074DD7D8C5D_u64, 0x00005F54CE7FE14E_u64, 0x0000D689EF630A2F_u64, 0x00001AA655113C23_u64,
+        0x0000DAFA0441652E_u64, 0x0000266A4194D185_u64, 0x0000E4C478BC39AF_u64, 0x00009D028922912A_u64, 0x0000A083309E6F7A_u64, 0x0000D3F4CA2E7BA8_u64, 0x00007E6B3300B56D_u64, 0x000052A96C2F0199_u64,
+        0x00001C8DABA504CD_u64, 0x0000412E621E8E2C_u64, 0x0000FD6ADACB7179_u64, 0x000063C4DDEF7949_u64, 0x000077AF67ED9C6B_u64, 0x0000F6236A103347_u64, 0x00005C69E962547C_u64, 0x0000606851A8E04E_u64,
+        0x0000C341AD5109CF_u64, 0x0000A3BA9DBF20C2_u64, 0x000077B8370F7DD9_u64, 0x000009C07D3352E3_u64, 0x000043DAA603DB81_u64, 0x000082134B327BA3_u64, 0x0000A64FC2F3AF49_u64, 0x000013F35920048F_u64,
+        0x000092111545B678_u64, 0x00004DB73BF3A839_u64, 0x000025A961D02726_u64, 0x000045E7714ACEFB_u64, 0x000050B1ABCEFAC0_u64, 0x0000EE56778695B5_u64, 0x0000D5FF04E42A5F_u64, 0x00001BE8E49CD442_u64,
+        0x0000020CCB46F37A_u64, 0x0000CE9E609C7F1D_u64, 0x00004806B492E66B_u64, 0x0000C20BABC425D0_u64, 0x000089D7CF571DCA_u64, 0x0000B4558F534B40_u64, 0x0000A8F4FECC57F6_u64, 0x0000021F43481770_u64,
+        0x00007159FFF52B10_u64, 0x00009B8157D08815_u64, 0x0000DE568A6F20E0_u64, 0x00008D0C1A2F83B3_u64, 0x00000012780123F6_u64, 0x0000A2BB9F58ABF3_u64, 0x0000537AA33DA1A9_u64, 0x00001C4ADEAAFB10_u64,
+        0x000003344AD865E9_u64, 0x00004BBCE8ADD8B6_u64, 0x0000F9C6A08C53FC_u64, 0x0000515B5FF58A38_u64, 0x0000AAF0DEB5D000_u64, 0x000067B2F307DDD3_u64, 0x00006D665E3EB7D5_u64, 0x00006CBA865CD048_u64,
+        0x00005148E7F46642_u64, 0x000008353F5D240D_u64, 0x000014384FCA3C2A_u64, 0x0000511B7F93BCC5_u64, 0x000069863B846A5F_u64, 0x0000058BFF468D8C_u64, 0x00000E6E9ED0D011_u64, 0x0000C2DCEFD4B1F1_u64,
+    ],
+};
+pub(crate) const TABLE_RANLUX24_SEED_0: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0x00000000_u32,
+    table: [
+        0x00E57B2C_u32, 0x00F91555_u32, 0x00D9F2DE_u32, 0x006D1A0C_u32, 0x000109F9_u32, 0x0082FBCA_u32, 0x004B0B07_u32, 0x00AD771D_u32, 0x0083E7BB_u32, 0x007F4CB8_u32, 0x0034C3E0_u32, 0x0066BD4F_u32, 0x0080D522_u32, 0x00124168_u32, 0x0034989B_u32, 0x0006DC8E_u32,
+        0x004D4B24_u32, 0x0044E9DE_u32, 0x00961D27_u32, 0x006B3254_u32, 0x00F433F4_u32, 0x00DBFEFB_u32, 0x0029BF1D_u32, 0x00E5C921_u32, 0x00EFAED9_u32, 0x00B4A829_u32, 0x006C0D76_u32, 0x0043E1D5_u32, 0x00B1BD6F_u32, 0x0016AE6F_u32, 0x00E711C6_u32, 0x003640B4_u32,
+        0x000B4194_u32, 0x002E898E_u32, 0x0026699D_u32, 0x00133822_u32, 0x004B6ABC_u32, 0x0082A473_u32, 0x00C74AA7_u32, 0x00DCF8ED_u32, 0x00AF247C_u32, 0x008C1B5F_u32, 0x00F6F692_u32, 0x00E54D87_u32, 0x0010C22D_u32, 0x0038D4A2_u32, 0x00DA49AC_u32, 0x00607D3F_u32,
+        0x0076B0CC_u32, 0x0068280E_u32, 0x00B1926E_u32, 0x0010A9DA_u32, 0x00F22B35_u32, 0x008BE22B_u32, 0x002AD40C_u32, 0x00594E66_u32, 0x006CEA0A_u32, 0x00E4C3E2_u32, 0x0045AEDE_u32, 0x0073973F_u32, 0x006A32B8_u32, 0x001554C9_u32, 0x00B0C7BE_u32, 0x00DFC766_u32,
+        0x00937F95_u32, 0x0084A165_u32, 0x00C86E2E_u32, 0x00A75F70_u32, 0x002F309F_u32, 0x007C58C0_u32, 0x008AF462_u32, 0x007D6582_u32, 0x00914E96_u32, 0x0096C784_u32, 0x00146C73_u32, 0x00E05DB2_u32, 0x006C11BD_u32, 0x00F8D23E_u32, 0x00118421_u32, 0x006FA8E6_u32,
+        0x00FD562E_u32, 0x00E251EE_u32, 0x00AE5A37_u32, 0x004EF0C1_u32, 0x003534B0_u32, 0x00DBC66C_u32, 0x00FC78AB_u32, 0x0020CB0B_u32, 0x00940AFC_u32, 0x00027550_u32, 0x00286FA3_u32, 0x002F5953_u32, 0x00343E4C_u32, 0x00C5C5C3_u32, 0x00BC1CBB_u32, 0x00C0F29C_u32,
+        0x00F57C3E_u32, 0x00C55365_u32, 0x00521F8F_u32, 0x003E3300_u32, 0x001F7882_u32, 0x006C853A_u32, 0x001EB9FB_u32, 0x00DB82A4_u32, 0x007DE7C4_u32, 0x00C793F4_u32, 0x00B11ADF_u32, 0x00294351_u32, 0x00FA9F8D_u32, 0x00CEBC6A_u32, 0x0063B561_u32, 0x002D1542_u32,
+        0x00B0C8C0_u32, 0x00275841_u32, 0x0021500F_u32, 0x004AF4AF_u32, 0x001B0D38_u32, 0x008F0DF7_u32, 0x0010721B_u32, 0x002E15BF_u32, 0x00DEA1D3_u32, 0x0097802B_u32, 0x007B0D58_u32, 0x0038171A_u32, 0x00BE7C0F_u32, 0x00506755_u32, 0x00A1F057_u32, 0x0055C554_u32,
+    ],
+};
+pub(crate) const TABLE_RANLUX24_SEED_1: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xDEADBEEF_u32,
+    table: [
+        0x00A401B7_u32, 0x00F06E1B_u32, 0x00701350_u32, 0x00FA0483_u32, 0x0017290A_u32, 0x00098AB7_u32, 0x00606336_u32, 0x00ECD4A8_u32, 0x00C400FF_u32, 0x004F5850_u32, 0x00CE63FB_u32, 0x00B1F1A4_u32, 0x0089B045_u32, 0x006CC5DB_u32, 0x00CF0D7A_u32, 0x0041A19D_u32,
+        0x00503E96_u32, 0x0093F878_u32, 0x0050D80F_u32, 0x00232A73_u32, 0x00446DC2_u32, 0x006E8873_u32, 0x00BDF001_u32, 0x00B6866F_u32, 0x008B93D3_u32, 0x00702DE4_u32, 0x00CB217D_u32, 0x00E9A160_u32, 0x00C8D62E_u32, 0x00BBAB94_u32, 0x00622C90_u32, 0x00797384_u32,
+        0x00DB5728_u32, 0x00063964_u32, 0x0037BEDE_u32, 0x005921D0_u32, 0x00D35FC2_u32, 0x003545B0_u32, 0x001EDC68_u32, 0x00BD7AA7_u32, 0x001BA5DD_u32, 0x008E7B33_u32, 0x00C5708C_u32, 0x00F3FE4C_u32, 0x00BF5E7D_u32, 0x008731F1_u32, 0x0072C43D_u32, 0x001ED554_u32,
+        0x002EDFE0_u32, 0x00FA8E0D_u32, 0x002584D5_u32, 0x00E12C03_u32, 0x002CBB09_u32, 0x000FDA02_u32, 0x00FCF5DE_u32, 0x005A0653_u32, 0x003745D2_u32, 0x005AD0D1_u32, 0x00D6DB7E_u32, 0x009254D3_u32, 0x007BC1EB_u32, 0x00D357F4_u32, 0x00DD18A2_u32, 0x00D4DCEE_u32,
+        0x0011F991_u32, 0x00339E7D_u32, 0x00DF372F_u32, 0x00DDBCA2_u32, 0x008C09F2_u32, 0x0078F502_u32, 0x00875954_u32, 0x003F7FC7_u32, 0x00143B66_u32, 0x005415E7_u32, 0x003D43AD_u32, 0x004B18C6_u32, 0x0031753A_u32, 0x008AF8C8_u32, 0x00CAD2FD_u32, 0x0072D803_u32,
+        0x008EDC54_u32, 0x00B1BDB6_u32, 0x005485D3_u32, 0x00883746_u32, 0x0083E8C0_u32, 0x009816CC_u32, 0x007F01EF_u32, 0x00E00ECF_u32, 0x00492971_u32, 0x00D5A890_u32, 0x00FAD11F_u32, 0x00F92C37_u32, 0x00A67D9B_u32, 0x006C788B_u32, 0x004CE647_u32, 0x00C656A8_u32,
+        0x0035D38A_u32, 0x00207C38_u32, 0x00ACBCA8_u32, 0x002A2BF3_u32, 0x002BD961_u32, 0x0061AFBA_u32, 0x00B6913E_u32, 0x009F9BC8_u32, 0x00199BFB_u32, 0x005FDACB_u32, 0x00DA7C96_u32, 0x009EC6D8_u32, 0x00BBA2E6_u32, 0x00C8C772_u32, 0x00C82819_u32, 0x00120B09_u32,
+        0x00A4C03B_u32, 0x00EC64AE_u32, 0x0030E442_u32, 0x001313F4_u32, 0x00DEF428_u32, 0x00AC41DF_u32, 0x0004A024_u32, 0x0046D096_u32, 0x002F46F3_u32, 0x002FC5ED_u32, 0x0078CAFD_u32, 0x0089A9EB_u32, 0x00C7220D_u32, 0x00C8A50F_u32, 0x00E8FE95_u32, 0x00D867A7_u32,
+    ],
+};
+pub(crate) const TABLE_RANLUX24_SEED_2: ConsistencyTestTable<u32, 128usize> = ConsistencyTestTable::<u32, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xCAFEBABE_u32,
+    table: [
+        0x00912C8D_u32, 0x005EE33E_u32, 0x00642F4E_u32, 0x0072D5C7_u32, 0x005CEFA8_u32, 0x00878974_u32, 0x00FF2CB6_u32, 0x000607D6_u32, 0x00AFA8D9_u32, 0x005E9AE6_u32, 0x00587F15_u32, 0x00342A48_u32, 0x00986BEC_u32, 0x003A3509_u32, 0x0010C72D_u32, 0x00A2DC0E_u32,
+        0x007862F3_u32, 0x00E6265C_u32, 0x007151C4_u32, 0x0021290C_u32, 0x00345357_u32, 0x005996B4_u32, 0x00FB6F14_u32, 0x00C42A89_u32, 0x00A52E75_u32, 0x00BB33FE_u32, 0x004CBD21_u32, 0x007680F4_u32, 0x008FCD68_u32, 0x007C4704_u32, 0x001FBDB5_u32, 0x005A3897_u32,
+        0x001987A0_u32, 0x006E77AD_u32, 0x00E30469_u32, 0x0027DE9A_u32, 0x0049317B_u32, 0x00A90AB7_u32, 0x0040C614_u32, 0x00EC835D_u32, 0x008677B6_u32, 0x006C66D4_u32, 0x0007173D_u32, 0x00E4CDAA_u32, 0x00BEB164_u32, 0x00EFBBC5_u32, 0x008DAA01_u32, 0x001ABE98_u32,
+        0x00C57B60_u32, 0x006FD73F_u32, 0x0021DDE7_u32, 0x004F381D_u32, 0x00646E15_u32, 0x00D5183A_u32, 0x0004D96A_u32, 0x00AE57F2_u32, 0x0026824A_u32, 0x0091F7DD_u32, 0x00AF22BC_u32, 0x00E66025_u32, 0x00BE4FE6_u32, 0x0005C382_u32, 0x00756F6D_u32, 0x0060FDBC_u32,
+        0x00D85BF7_u32, 0x006EDFD6_u32, 0x00660C60_u32, 0x009831F2_u32, 0x006F2159_u32, 0x0006FA0F_u32, 0x004235B5_u32, 0x00C227B7_u32, 0x0091D6EC_u32, 0x00D15FBD_u32, 0x004D43D0_u32, 0x00C49DB2_u32, 0x0062B477_u32, 0x0038236F_u32, 0x00F031DD_u32, 0x009C4D75_u32,
+        0x00568A1E_u32, 0x00BEA16A_u32, 0x00DDCB9D_u32, 0x002180BE_u32, 0x00941BC9_u32, 0x00C0F47B_u32, 0x005A1AE0_u32, 0x003F785B_u32, 0x0035F825_u32, 0x00B9B493_u32, 0x000C1BC0_u32, 0x009B9C43_u32, 0x00D06F82_u32, 0x00E470B8_u32, 0x005F0783_u32, 0x00657BF6_u32,
+        0x00EC4087_u32, 0x00BD0667_u32, 0x00796615_u32, 0x0044EF33_u32, 0x00DEA76A_u32, 0x00BEFE54_u32, 0x00CF1FF4_u32, 0x00C37114_u32, 0x0073E72D_u32, 0x00765F84_u32, 0x004D676E_u32, 0x00C7C6D5_u32, 0x0093D6BD_u32, 0x000B2530_u32, 0x007AE7FC_u32, 0x00526AD6_u32,
+        0x00AB3D61_u32, 0x00A4573D_u32, 0x002296B1_u32, 0x00D97998_u32, 0x009EC209_u32, 0x00B88252_u32, 0x009CB903_u32, 0x00805F60_u32, 0x00090AF1_u32, 0x00A9BA00_u32, 0x005865D5_u32, 0x00C8FC70_u32, 0x00B361FC_u32, 0x00DC1B58_u32, 0x002EA7D6_u32, 0x005A1CCB_u32,
+    ],
+};
+pub(crate) const TABLE_RANLUX48_SEED_0: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0x0000000000000000_u64,
+    table: [
+        0x00001555FCE57B2C_u64, 0x00001A0C0CD9F2DF_u64, 0x0000FBCA490109FA_u64, 0x0000771E394B0B07_u64, 0x00004CB93B83E7BC_u64, 0x0000BD4FE734C3E1_u64, 0x00004167D880D522_u64, 0x0000DC8F0334989B_u64,
+        0x0000E9DFD24D4B24_u64, 0x00003255C9961D27_u64, 0x0000FEFC7CF433F5_u64, 0x0000F4F043ECC7EC_u64, 0x00001B2F5EA237FB_u64, 0x00007171DC842ACB_u64, 0x0000AF25E5E7DD1C_u64, 0x0000432823788512_u64,
+        0x0000437ED2D456E7_u64, 0x0000FAB0B847DC73_u64, 0x00001099063D3D3D_u64, 0x0000B7B54CD87BA3_u64, 0x000061ED43455370_u64, 0x0000E81688623EFB_u64, 0x0000B791E4E43481_u64, 0x0000BDA3ADB74FB8_u64,
+        0x00001749A4F12D74_u64, 0x000035F1B8500AC1_u64, 0x000055198A65B24C_u64, 0x00002D864CAED458_u64, 0x0000D4C124763116_u64, 0x000070E0C988DB7C_u64, 0x00002281FBC6C9DC_u64, 0x00006B0B0687D916_u64,
+        0x0000E192AAD1A260_u64, 0x0000CB5FD2C45294_u64, 0x00003E342A5A1BF9_u64, 0x00009342936D173B_u64, 0x00007F20FFF190AC_u64, 0x00005860F3569802_u64, 0x000052B978C783E3_u64, 0x0000D8DAB8BC5AB9_u64,
+        0x0000F7A068E91664_u64, 0x0000C541EAA7757C_u64, 0x00008AB70EEF01AF_u64, 0x00003BEE44C198EC_u64, 0x00009B9DECA76ECE_u64, 0x00001B39464E3C3A_u64, 0x00000C914ECBCD71_u64, 0x000026CE782A71EC_u64,
+        0x0000B9424DD665DF_u64, 0x00002EDDC670109D_u64, 0x0000E496EE8A72EB_u64, 0x0000305041F8F5EF_u64, 0x00006D45DE2863D5_u64, 0x00004D1428E29C62_u64, 0x00006DAC95759622_u64, 0x0000E7CBAF9BE2BB_u64,
+        0x000080DA10253097_u64, 0x0000D4965C588365_u64, 0x0000E49AFB8ED3C7_u64, 0x0000960307D59A1B_u64, 0x0000B06626A1ACCC_u64, 0x0000EC716038470E_u64, 0x0000F29EF2A59DF2_u64, 0x000035209A03682C_u64,
+        0x0000C96305A67B6E_u64, 0x0000F47CAFB70101_u64, 0x0000D0F0643D0AAC_u64, 0x000098A40C5E08B2_u64, 0x00008941CEBE50D8_u64, 0x00005EFA18E18329_u64, 0x0000966A72910504_u64, 0x0000024BD49150B9_u64,
+        0x00000D38294BDDED_u64, 0x000051FE59E2B895_u64, 0x00004582A994F25F_u64, 0x00007D3D8018805A_u64, 0x00007EBFAF7888BD_u64, 0x0000AA052F80BCBD_u64, 0x000083B306BA46FE_u64, 0x0000D5911395F171_u64,
+        0x0000B7278D1FA044_u64, 0x0000CCA65278E29C_u64, 0x00003AADB50C3013_u64, 0x000078EFB5071717_u64, 0x0000DD0799656FF0_u64, 0x0000F7C38D296EFC_u64, 0x00000B8092E8B686_u64, 0x0000CCA87ED2FB66_u64,
+        0x000010FA108DC3E1_u64, 0x00005EC92CC3096E_u64, 0x0000C8B6A0598EA5_u64, 0x00006EFF0E9030AE_u64, 0x0000E8AFC58467DC_u64, 0x0000107161DBC969_u64, 0x0000FF19D8765DEF_u64, 0x000037CD3BD52091_u64,
+        0x000016CC8B333E6A_u64, 0x00002EC34201EE75_u64, 0x00004B950ACEF598_u64, 0x0000C77D20499141_u64, 0x0000FB5BE491900A_u64, 0x000061A17781F004_u64, 0x0000B2884719C375_u64, 0x000023555A690C88_u64,
+        0x000081561412F326_u64, 0x0000E1E89EF1AEA9_u64, 0x00004C0FA59647B3_u64, 0x00002F5EBBD459BD_u64, 0x00004DB96070317D_u64, 0x0000A9CB3980F18A_u64, 0x0000E26D629F6DC3_u64, 0x00006DB68CACC82B_u64,
+        0x0000F75B44CFC578_u64, 0x0000D428FB678914_u64, 0x0000C609CC61F5F6_u64, 0x000012423AF19CEC_u64, 0x000021F5CB853861_u64, 0x00002B00E2DA2B34_u64, 0x0000C43ED5A445D3_u64, 0x0000804DF932F35A_u64,
+        0x0000E9DB2BD9B9B2_u64, 0x0000123194351EF2_u64, 0x000069F1C5A5E8E4_u64, 0x0000DADF9A053C4E_u64, 0x000013CBD949706F_u64, 0x000065F9FA02162D_u64, 0x000012BCF352B778_u64, 0x0000C4F654804753_u64,
+    ],
+};
+pub(crate) const TABLE_RANLUX48_SEED_1: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xDEADBEEFCAFEBABE_u64,
+    table: [
+        0x00001969DC7A3AD3_u64, 0x000002976FD11308_u64, 0x0000960BDCBE28FF_u64, 0x000044B727012A76_u64, 0x00001FD244D84D34_u64, 0x0000B51CBC66C38E_u64, 0x0000B45C27078A02_u64, 0x0000FF7FA58FAEE7_u64,
+        0x0000F3E9B0C1EFE7_u64, 0x000015053F209FE6_u64, 0x00001BD43EA4E22A_u64, 0x0000835341A46582_u64, 0x0000FD74C190ECFE_u64, 0x0000D87E729DD9AF_u64, 0x0000E1BB7BC59997_u64, 0x0000C8EB4791BBE1_u64,
+        0x00006C98F86260D4_u64, 0x0000AF00F639C575_u64, 0x0000E564C7E838B6_u64, 0x0000BC55C362739D_u64, 0x0000FF0BF9E86388_u64, 0x00004E0A9257E1EA_u64, 0x0000FFDACFD434CB_u64, 0x000039D843591B47_u64,
+        0x0000963640723333_u64, 0x0000F8E59F1E4BC4_u64, 0x000098AC4A5CC806_u64, 0x00003C580FDF117F_u64, 0x0000349ED6AB4FCB_u64, 0x0000F88931E8FDEC_u64, 0x0000D40E372D0A3E_u64, 0x00006C00DB55B562_u64,
+        0x00008BD80581DC78_u64, 0x0000AAB2ED111724_u64, 0x0000EDF7AA52E184_u64, 0x0000B9FF07E8BF68_u64, 0x0000A94E168D692A_u64, 0x0000F605F7E5F6B7_u64, 0x0000D01353954777_u64, 0x0000C49AA1844DCE_u64,
+        0x000098A2E9B14516_u64, 0x0000C7B0FAC22F34_u64, 0x0000265E0E93FA5B_u64, 0x00004639C855C20F_u64, 0x0000A6EC0DD1519F_u64, 0x0000E576FF4CD0EE_u64, 0x0000CC96F0ECEE3C_u64, 0x000080899E0B4046_u64,
+        0x0000359A74DCB548_u64, 0x0000C517BB029133_u64, 0x0000C6199E5D8C65_u64, 0x00005AE5E7AAEBE2_u64, 0x00006366038C7709_u64, 0x0000E73557FBABDE_u64, 0x0000BB58129AF4AB_u64, 0x00002BB865EE7723_u64,
+        0x000074D1EDFC7DA4_u64, 0x0000D11A38894FC3_u64, 0x000078F7BBB920A3_u64, 0x0000EB6549A1DAC8_u64, 0x00004B57FF733C1C_u64, 0x000087E3361BFABA_u64, 0x0000ED12829AE47A_u64, 0x0000A16BAFB69D90_u64,
+        0x000063C764B9D12A_u64, 0x00008F0A907700E4_u64, 0x0000D3324519DF8E_u64, 0x00009E7A79940485_u64, 0x0000C7EC526B4B64_u64, 0x000042E91FA2EC34_u64, 0x000055B4620D74F1_u64, 0x0000005ED34B7460_u64,
+        0x000025F3B4FBC575_u64, 0x000072AC49251A6A_u64, 0x000058803854F16D_u64, 0x0000189D5C9C6E1D_u64, 0x000068B82F192840_u64, 0x0000E8841D202427_u64, 0x00007F02B7D0CB96_u64, 0x0000EF9011B95EA2_u64,
+        0x0000B238148F0408_u64, 0x0000E3EC5E19DCAE_u64, 0x0000C2718CD3F737_u64, 0x0000C3C4FBC28D0C_u64, 0x000071078E3B7FC7_u64, 0x00007D023BEE9E4B_u64, 0x0000008449AA8762_u64, 0x00007789A48846A8_u64,
+        0x0000FC41C883FF91_u64, 0x0000A96B057D9A6D_u64, 0x0000114FC5EBCFD7_u64, 0x000001D195077470_u64, 0x000071F0A7A4D8F8_u64, 0x00004DF1EF93FDA3_u64, 0x000068E8252307EB_u64, 0x00001A5FC9CD1AF5_u64,
+        0x0000CD8BF9BA2BA8_u64, 0x00004C5ED187EC80_u64, 0x00004193522B3E4E_u64, 0x00006B6577FBC693_u64, 0x0000EE94DE4706E0_u64, 0x00009EE26C0B1C61_u64, 0x0000FB95436079F4_u64, 0x00006DE95418F69E_u64,
+        0x0000E80C8088EEE6_u64, 0x000096CDAF16ADBD_u64, 0x0000FF1C444CD9E0_u64, 0x00002F2D6C58B585_u64, 0x000034D6D53804BD_u64, 0x00003A648547CB12_u64, 0x0000A9A62DF435B2_u64, 0x00009607A6887631_u64,
+        0x0000B5C36F1AC5B4_u64, 0x0000A21D9BE594CE_u64, 0x00004869FAFCCBB6_u64, 0x0000926FDEC79696_u64, 0x00006E8E7E7F37E9_u64, 0x00005AE6C27D84DF_u64, 0x00006583EA904353_u64, 0x0000D72B74D784B9_u64,
+        0x0000AC1265B91A93_u64, 0x000090AE8E5F1BD7_u64, 0x00004744DB1CD56B_u64, 0x00007CBFC937179D_u64, 0x0000F887EC6E90DE_u64, 0x00005CE75E2CDF0A_u64, 0x00004E03E08AF8AA_u64, 0x00005DA5072E3CDA_u64,
+    ],
+};
+pub(crate) const TABLE_RANLUX48_SEED_2: ConsistencyTestTable<u64, 128usize> = ConsistencyTestTable::<u64, 128usize> {
+    date: "Oct 19 2026 02:27:55",
+    seed: 0xABADC0DECAFED00D_u64,
+    table: [
+        0x00005C98B782FA37_u64, 0x0000D510E9591291_u64, 0x0000110BFA567908_u64, 0x0000908141B54CE0_u64, 0x000006A026DAB6E4_u64, 0x0000C22E3806F936_u64, 0x00009CC98C9705D7_u64, 0x00008AB8C424D5A1_u64,
+        0x000032C736A6B7C4_u64, 0x000014B00E8A5424_u64, 0x00003A14C54E2D68_u64, 0x00009D880383DDC7_u64, 0x0000124759271280_u64, 0x00001B2D9A0E7C21_u64, 0x0000A15D8B4330BF_u64, 0x0000BCB060782A38_u64,
+        0x0000B009CE928426_u64, 0x0000DD40AC8073F5_u64, 0x0000A83E4C821048_u64, 0x000023A186E4DF9C_u64, 0x0000458865BA745E_u64, 0x00003E40B55E573E_u64, 0x000036E2B601F49D_u64, 0x000021EA77FB9A01_u64,
+        0x0000687FD9A3939B_u64, 0x000076A1CEEBAD8F_u64, 0x0000F7E45914B068_u64, 0x00002B5A0583AAEB_u64, 0x00001414EF95C69B_u64, 0x000091E924706783_u64, 0x0000CF50F7EEBFBD_u64, 0x0000FEA3E236BB63_u64,
+        0x00001A40E5772F3F_u64, 0x00003F1ECC34CF6A_u64, 0x00000DB25B9826B3_u64, 0x0000FDE4D55C3EBF_u64, 0x00003ECA8DE0D232_u64, 0x0000B16684E96BC2_u64, 0x0000DB308EAF925D_u64, 0x0000F9C27A6AB897_u64,
+        0x0000BF0CAEC56773_u64, 0x00000F702381C14C_u64, 0x0000D1D6CBFDF3D6_u64, 0x000087CF7F67A13C_u64, 0x0000A08229BF6C7B_u64, 0x0000D18FD486D510_u64, 0x0000FFD5EF34F91D_u64, 0x00005DF4E18ADE45_u64,
+        0x0000844FC4AD6C66_u64, 0x00009B581CEC6F12_u64, 0x0000AF43507EB8FC_u64, 0x0000C6C9BC55D407_u64, 0x0000E5575D246681_u64, 0x00006841564B503E_u64, 0x0000EAE863654570_u64, 0x0000D71D1371E6ED_u64,
+        0x0000BFEEF0705EB8_u64, 0x00003E233B342CBB_u64, 0x00005951B020434F_u64, 0x000055AF3F71F82A_u64, 0x000063F028CD04C7_u64, 0x000009D15C45A326_u64, 0x00002BA11B7A0637_u64, 0x000031EAFDB94F27_u64,
+        0x000079F99D6B7BD4_u64, 0x000015587D689078_u64, 0x0000DFBB5798511C_u64, 0x0000953F22CD81FD_u64, 0x0000082F49A89213_u64, 0x0000B09D416E7A17_u64, 0x0000E2B80B4A99A7_u64, 0x0000E6CC0927814E_u64,
+        0x00001307E238A4DC_u64, 0x0000D701E8439B9B_u64, 0x000073DF01CC0EEB_u64, 0x0000420534448C84_u64, 0x0000993D16090A39_u64, 0x0000DEBA4F0BBDF1_u64, 0x0000B743EEA88561_u64, 0x0000A4032478F4FA_u64,
+        0x0000ACFF73FBE16F_u64, 0x0000F3A0BC3A1E4D_u64, 0x0000174656B8685D_u64, 0x0000C40A3B3F6809_u64, 0x000076BB219F2CB6_u64, 0x000012D8C6DEB35F_u64, 0x0000E9ABEE3FF7DE_u64, 0x000077BCC91CDBF5_u64,
+        0x000053B9A82C1FA7_u64, 0x0000A9E9ACDB5E5F_u64, 0x000047BD51CEB2A4_u64, 0x000016756769129B_u64, 0x0000E16FC5524D4A_u64, 0x0000D7F97FAD5382_u64, 0x0000235920E2EDA3_u64, 0x0000B997593081D0_u64,
+        0x0000983D183EDE05_u64, 0x00000D2F020BF609_u64, 0x000081A1C3E4FCE5_u64, 0x0000193B0672858D_u64, 0x00004144DFB4B86E_u64, 0x0000B9F86FE866BB_u64, 0x0000C07744F31363_u64, 0x0000B427C391CF95_u64,
+        0x0000680DD7F1E17D_u64, 0x0000C09C7121A1AF_u64, 0x0000550CA00545BD_u64, 0x0000796532C704CE_u64, 0x0000899ECC8D04C8_u64, 0x000019E0C77FFFD2_u64, 0x00001E1730D5CD63_u64, 0x0000F95BF4EC934A_u64,
+        0x0000DD2FC4CF159A_u64, 0x000010DE74FC85B1_u64, 0x00007A7FF65FE290_u64, 0x0000BBF9CA7F86A0_u64, 0x000012E12BF7EE8D_u64, 0x00003C3D67F379A8_u64, 0x0000095E0E1F516F_u64, 0x0000E32E07F5D72F_u64,
+        0x00005E65C79B325F_u64, 0x000038C4377CD5A7_u64, 0x0000BBBCAC7A051B_u64, 0x0000C2707B7A02FE_u64, 0x000032E10B2C86FE_u64, 0x0000A9D5EC51DFC9_u64, 0x0000AC978843270D_u64, 0x0000299944978090_u64,
+    ],
+};
@@ -0,0 +1,78 @@
+use crate::rng::engines::counter_based::CounterBasedEngine;
+
+use super::*;
+
+/// Threefry2x64 with `R` rounds, see Salmon et al., "Parallel Random Numbers: As Easy as 1, 2, 3"
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Threefry2x64Bijection<const R: usize>;
+
+impl<const R: usize> Bijection<u64, 2, 2> for Threefry2x64Bijection<R> {
+    fn apply(counter: [u64; 2], key: [u64; 2]) -> [u64; 2] {
+        let schedule = [key[0], key[1], detail::SKEIN_KS_PARITY ^ key[0] ^ key[1]];
+
+        let mut x = [counter[0].wrapping_add(schedule[0]), counter[1].wrapping_add(schedule[1])];
+
+        for round in 0..R {
+            x[0] = x[0].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(detail::ROTATIONS[round % 8]);
+            x[1] ^= x[0];
+
+            if round % 4 == 3 {
+                let injection = (round + 1) / 4;
+
+                x[0] = x[0].wrapping_add(schedule[injection % 3]);
+                x[1] = x[1].wrapping_add(schedule[(injection + 1) % 3]).wrapping_add(injection as u64);
+            }
+        }
+
+        x
+    }
+
+    fn name() -> String { format!("threefry2x64-{}", R) }
+}
+
+/// Threefry2x64-20, the stateless form.
+pub fn threefry2x64_20(counter: [u64; 2], key: [u64; 2]) -> [u64; 2] { Threefry2x64Bijection::<20>::apply(counter, key) }
+
+pub type Threefry2x64 = CounterBasedEngine<u64, 2, 2, Threefry2x64Bijection<20>>;
+
+mod detail {
+    pub const SKEIN_KS_PARITY: u64 = 0x1BD11BDAA9FC1A22_u64;
+    pub const ROTATIONS: [u32; 8] = [16, 42, 12, 31, 16, 32, 24, 21];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::*;
+    use super::*;
+
+    // Random123 kat_vectors
+    #[test]
+    fn test_threefry2x64_20_known_answers() {
+        assert_eq!(threefry2x64_20([0, 0], [0, 0]), [0xc2b6e3a8c2c69865, 0x6f81ed42f350084d]);
+        assert_eq!(threefry2x64_20([u64::MAX; 2], [u64::MAX; 2]), [0xe02cb7c4d95d277a, 0xd06633d0893b8b68]);
+        assert_eq!(
+            threefry2x64_20([0x243f6a8885a308d3, 0x13198a2e03707344], [0xa4093822299f31d0, 0x082efa98ec4e6c89]),
+            [0x263c7d30bb0f0af1, 0x56be8361d3311526]
+        );
+
+        assert_eq!(Threefry2x64Bijection::<13>::apply([0, 0], [0, 0]), [0xf167b032c3b480bd, 0xe91f9fee4b7a6fb5]);
+    }
+
+    #[test]
+    fn test_threefry2x64_engine() {
+        let mut generator = Threefry2x64::new();
+        generator.seed_from_result(0xDEADBEEFCAFEBABE_u64);
+
+        let expected_0 = threefry2x64_20([0, 0], [0xDEADBEEFCAFEBABE, 0]);
+        let expected_1 = threefry2x64_20([1, 0], [0xDEADBEEFCAFEBABE, 0]);
+
+        assert_eq!(generator.generate(), expected_0[0]);
+        assert_eq!(generator.generate(), expected_0[1]);
+        assert_eq!(generator.generate(), expected_1[0]);
+
+        generator.discard(3);
+        assert_eq!(generator.counter(), [3, 0]);
+        assert_eq!(generator.generate(), threefry2x64_20([3, 0], [0xDEADBEEFCAFEBABE, 0])[0]);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::rng::engines::{Permuter, PolyDiscarder, Scrambler};
+use crate::rng::engines::{Permuter, PolyDiscarder, Scrambler, StateTag};
 use crate::rng::engines::generic::GenericLinearGenerator;
 
 macro_rules! easy_permuter {
@@ -6,6 +6,19 @@ macro_rules! easy_permuter {
         impl Permuter<u64, 2> for $name {
             fn permute(state: [u64; 2]) -> [u64; 2] { detail::permute_xoroshiro($a, $b, $c, state) }
         }
+
+        impl StateTag for $name {
+            fn tag() -> String { format!("xoroshiro128-{}-{}-{}", $a, $b, $c) }
+        }
+    };
+    ($name:ident, u32, $a:literal, $b:literal, $c:literal) => {
+        impl Permuter<u32, 2> for $name {
+            fn permute(state: [u32; 2]) -> [u32; 2] { detail::permute_xoroshiro_32($a, $b, $c, state) }
+        }
+
+        impl StateTag for $name {
+            fn tag() -> String { format!("xoroshiro64-{}-{}-{}", $a, $b, $c) }
+        }
     };
 }
 
@@ -18,6 +31,10 @@ impl Scrambler<u64, 2> for Xoroshiro128PScrambler {
     }
 }
 
+impl StateTag for Xoroshiro128PScrambler {
+    fn tag() -> String { "+".into() }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub struct Xoroshiro128PPScrambler;
 
@@ -27,6 +44,10 @@ impl Scrambler<u64, 2> for Xoroshiro128PPScrambler {
     }
 }
 
+impl StateTag for Xoroshiro128PPScrambler {
+    fn tag() -> String { "++".into() }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub struct Xoroshiro128SSScrambler;
 
@@ -40,6 +61,16 @@ mod detail {
 
         state
     }
+
+    pub fn permute_xoroshiro_32(a: u32, b: u32, c: u32, mut state: [u32; 2]) -> [u32; 2] {
+        let s_0 = state[0];
+        let s_1 = state[0] ^ state[1];
+
+        state[0] = s_0.rotate_left(a) ^ s_1 ^ (s_1.checked_shl(b).unwrap());
+        state[1] = s_1.rotate_left(c);
+
+        state
+    }
 }
 
 impl Scrambler<u64, 2> for Xoroshiro128SSScrambler {
@@ -48,6 +79,10 @@ impl Scrambler<u64, 2> for Xoroshiro128SSScrambler {
     }
 }
 
+impl StateTag for Xoroshiro128SSScrambler {
+    fn tag() -> String { "**".into() }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub struct Xoroshiro128PSPermuter;
 
@@ -80,6 +115,137 @@ pub type Xoroshiro128P = GenericLinearGenerator<u64, 2, Xoroshiro128PSPermuter,
 pub type Xoroshiro128SS = GenericLinearGenerator<u64, 2, Xoroshiro128PSPermuter, Xoroshiro128SSScrambler, Xoroshiro128PSPermuter>;
 pub type Xoroshiro128PP = GenericLinearGenerator<u64, 2, Xoroshiro128PPPermuter, Xoroshiro128PPScrambler, Xoroshiro128PPPermuter>;
 
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoroshiro64SScrambler;
+
+impl Scrambler<u32, 2> for Xoroshiro64SScrambler {
+    fn scramble(state: &[u32; 2]) -> u32 {
+        state[0].wrapping_mul(0x9E3779BB)
+    }
+}
+
+impl StateTag for Xoroshiro64SScrambler {
+    fn tag() -> String { "*".into() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoroshiro64SSScrambler;
+
+impl Scrambler<u32, 2> for Xoroshiro64SSScrambler {
+    fn scramble(state: &[u32; 2]) -> u32 {
+        state[0].wrapping_mul(0x9E3779BB).rotate_left(5).wrapping_mul(5)
+    }
+}
+
+impl StateTag for Xoroshiro64SSScrambler {
+    fn tag() -> String { "**".into() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoroshiro64Permuter;
+
+easy_permuter!(Xoroshiro64Permuter, u32, 26, 9, 13);
+
+impl PolyDiscarder<u32, 2> for Xoroshiro64Permuter {
+    /// The reference implementation has no jumps for xoroshiro64, so these are not taken from it: they are
+    /// `x^(2^48)` and `x^(2^32)` modulo the characteristic polynomial of the permuter, which is recovered with
+    /// Berlekamp–Massey by `util::characteristic_polynomial`. `test_jump_polynomials` recomputes them.
+    fn polynomials() -> &'static [(usize, [u32; 2])] {
+        &[
+            (48, [0x3f1f8b95_u32, 0xb4e7e463_u32]),
+            (32, [0x77fcd1a0_u32, 0x4cbf99bd_u32]),
+        ]
+    }
+}
+
+pub type Xoroshiro64S = GenericLinearGenerator<u32, 2, Xoroshiro64Permuter, Xoroshiro64SScrambler, Xoroshiro64Permuter>;
+pub type Xoroshiro64SS = GenericLinearGenerator<u32, 2, Xoroshiro64Permuter, Xoroshiro64SSScrambler, Xoroshiro64Permuter>;
+
+/// The reference implementation keeps the state in a ring buffer with a moving index `p`; here the state is
+/// rotated on every step instead so that `state[0]` is `s[p]` and `state[1]` is `s[p + 1]`.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoroshiro1024Permuter;
+
+impl Permuter<u64, 16> for Xoroshiro1024Permuter {
+    fn permute(state: [u64; 16]) -> [u64; 16] {
+        let s_0 = state[1];
+        let s_15 = state[0] ^ s_0;
+
+        let mut res = [0u64; 16];
+        res[0] = s_15.rotate_left(36);
+        res[1..15].copy_from_slice(&state[2..16]);
+        res[15] = s_0.rotate_left(25) ^ s_15 ^ (s_15 << 27);
+
+        return res;
+    }
+}
+
+impl StateTag for Xoroshiro1024Permuter {
+    fn tag() -> String { "xoroshiro1024".into() }
+}
+
+impl PolyDiscarder<u64, 16> for Xoroshiro1024Permuter {
+    fn polynomials() -> &'static [(usize, [u64; 16])] {
+        &[
+            (768, [
+                0x7374156360bbf00f_u64, 0x4630c2efa3b3c1f6_u64, 0x6654183a892786b1_u64, 0x94f7bfcbfb0f1661_u64,
+                0x27d8243d3d13eb2d_u64, 0x9701730f3dfb300f_u64, 0x2f293baae6f604ad_u64, 0xa661831cb60cd8b6_u64,
+                0x68280c77d9fe008c_u64, 0x50554160f5ba9459_u64, 0x2fc20b17ec7b2a9a_u64, 0x49189bbdc8ec9f8f_u64,
+                0x92a65bca41852cc1_u64, 0xf46820dd0509c12a_u64, 0x52b00c35fbf92185_u64, 0x1e5b3b7f589e03c1_u64,
+            ]),
+            (512, [
+                0x931197d8e3177f17_u64, 0xb59422e0b9138c5f_u64, 0xf06a6afb49d668bb_u64, 0xacb8a6412c8a1401_u64,
+                0x12304ec85f0b3468_u64, 0xb7dfe7079209891e_u64, 0x405b7eec77d9eb14_u64, 0x34ead68280c44e4a_u64,
+                0xe0e4ba3e0ac9e366_u64, 0x8f46eda8348905b7_u64, 0x328bf4dbad90d6ff_u64, 0xc8fd6fb31c9effc3_u64,
+                0xe899d452d4b67652_u64, 0x45f387286ade3205_u64, 0x03864f454a8920bd_u64, 0xa68fa28725b1b384_u64,
+            ]),
+        ]
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoroshiro1024SScrambler;
+
+impl Scrambler<u64, 16> for Xoroshiro1024SScrambler {
+    fn scramble(state: &[u64; 16]) -> u64 {
+        state[1].wrapping_mul(0x9e3779b97f4a7c13)
+    }
+}
+
+impl StateTag for Xoroshiro1024SScrambler {
+    fn tag() -> String { "*".into() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoroshiro1024PPScrambler;
+
+impl Scrambler<u64, 16> for Xoroshiro1024PPScrambler {
+    fn scramble(state: &[u64; 16]) -> u64 {
+        state[1].wrapping_add(state[0]).rotate_left(23).wrapping_add(state[0])
+    }
+}
+
+impl StateTag for Xoroshiro1024PPScrambler {
+    fn tag() -> String { "++".into() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoroshiro1024SSScrambler;
+
+impl Scrambler<u64, 16> for Xoroshiro1024SSScrambler {
+    fn scramble(state: &[u64; 16]) -> u64 {
+        state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+    }
+}
+
+impl StateTag for Xoroshiro1024SSScrambler {
+    fn tag() -> String { "**".into() }
+}
+
+pub type Xoroshiro1024S = GenericLinearGenerator<u64, 16, Xoroshiro1024Permuter, Xoroshiro1024SScrambler, Xoroshiro1024Permuter>;
+pub type Xoroshiro1024PP = GenericLinearGenerator<u64, 16, Xoroshiro1024Permuter, Xoroshiro1024PPScrambler, Xoroshiro1024Permuter>;
+pub type Xoroshiro1024SS = GenericLinearGenerator<u64, 16, Xoroshiro1024Permuter, Xoroshiro1024SSScrambler, Xoroshiro1024Permuter>;
+
 #[cfg(test)]
 mod tests {
     use super::super::test_tables;
@@ -131,8 +297,8 @@ mod tests {
     #[test]
     fn test_xoroshiro128p_jump_consistency() {
         let mut generator = Xoroshiro128P::new();
-        let discard_64 = |engine: &mut Xoroshiro128P| engine.discard(64);
-        let discard_96 = |engine: &mut Xoroshiro128P| engine.discard(96);
+        let discard_64 = |engine: &mut Xoroshiro128P| engine.discard(1u128 << 64);
+        let discard_96 = |engine: &mut Xoroshiro128P| engine.discard(1u128 << 96);
 
         test_tables::JUMP_TABLE_XOROSHIRO128P_64.test(&mut generator, discard_64);
         test_tables::JUMP_TABLE_XOROSHIRO128P_96.test(&mut generator, discard_96);
@@ -145,8 +311,8 @@ mod tests {
     #[test]
     fn test_xoroshiro128pp_jump_consistency() {
         let mut generator = Xoroshiro128PP::new();
-        let discard_64 = |engine: &mut Xoroshiro128PP| engine.discard(64);
-        let discard_96 = |engine: &mut Xoroshiro128PP| engine.discard(96);
+        let discard_64 = |engine: &mut Xoroshiro128PP| engine.discard(1u128 << 64);
+        let discard_96 = |engine: &mut Xoroshiro128PP| engine.discard(1u128 << 96);
 
         test_tables::JUMP_TABLE_XOROSHIRO128PP_64.test(&mut generator, discard_64);
         test_tables::JUMP_TABLE_XOROSHIRO128PP_96.test(&mut generator, discard_96);
@@ -159,8 +325,8 @@ mod tests {
     #[test]
     fn test_xoroshiro128ss_jump_consistency() {
         let mut generator = Xoroshiro128SS::new();
-        let discard_64 = |engine: &mut Xoroshiro128SS| engine.discard(64);
-        let discard_96 = |engine: &mut Xoroshiro128SS| engine.discard(96);
+        let discard_64 = |engine: &mut Xoroshiro128SS| engine.discard(1u128 << 64);
+        let discard_96 = |engine: &mut Xoroshiro128SS| engine.discard(1u128 << 96);
 
         test_tables::JUMP_TABLE_XOROSHIRO128SS_64.test(&mut generator, discard_64);
         test_tables::JUMP_TABLE_XOROSHIRO128SS_96.test(&mut generator, discard_96);
@@ -169,4 +335,144 @@ mod tests {
         test_tables::JUMP_TABLE_XOROSHIRO128SS_64.test(&mut generator, discard_64);
         test_tables::JUMP_TABLE_XOROSHIRO128SS_96.test(&mut generator, discard_96);
     }
+
+    #[test]
+    fn test_xoroshiro64s_consistency() {
+        let mut generator = Xoroshiro64S::new();
+        test_tables::TABLE_XOROSHIRO64S_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64S_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64S_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64S_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64S_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64S_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64S_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64S_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64S_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoroshiro64ss_consistency() {
+        let mut generator = Xoroshiro64SS::new();
+        test_tables::TABLE_XOROSHIRO64SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64SS_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64SS_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO64SS_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoroshiro64s_jump_consistency() {
+        let mut generator = Xoroshiro64S::new();
+        let discard_32 = |engine: &mut Xoroshiro64S| engine.discard(1u128 << 32);
+        let discard_48 = |engine: &mut Xoroshiro64S| engine.discard(1u128 << 48);
+
+        test_tables::JUMP_TABLE_XOROSHIRO64S_32.test(&mut generator, discard_32);
+        test_tables::JUMP_TABLE_XOROSHIRO64S_48.test(&mut generator, discard_48);
+        test_tables::JUMP_TABLE_XOROSHIRO64S_32.test(&mut generator, discard_32);
+        test_tables::JUMP_TABLE_XOROSHIRO64S_48.test(&mut generator, discard_48);
+        test_tables::JUMP_TABLE_XOROSHIRO64S_32.test(&mut generator, discard_32);
+        test_tables::JUMP_TABLE_XOROSHIRO64S_48.test(&mut generator, discard_48);
+    }
+
+    #[test]
+    fn test_xoroshiro64ss_jump_consistency() {
+        let mut generator = Xoroshiro64SS::new();
+        let discard_32 = |engine: &mut Xoroshiro64SS| engine.discard(1u128 << 32);
+        let discard_48 = |engine: &mut Xoroshiro64SS| engine.discard(1u128 << 48);
+
+        test_tables::JUMP_TABLE_XOROSHIRO64SS_32.test(&mut generator, discard_32);
+        test_tables::JUMP_TABLE_XOROSHIRO64SS_48.test(&mut generator, discard_48);
+        test_tables::JUMP_TABLE_XOROSHIRO64SS_32.test(&mut generator, discard_32);
+        test_tables::JUMP_TABLE_XOROSHIRO64SS_48.test(&mut generator, discard_48);
+        test_tables::JUMP_TABLE_XOROSHIRO64SS_32.test(&mut generator, discard_32);
+        test_tables::JUMP_TABLE_XOROSHIRO64SS_48.test(&mut generator, discard_48);
+    }
+
+    #[test]
+    fn test_xoroshiro1024s_consistency() {
+        let mut generator = Xoroshiro1024S::new();
+        test_tables::TABLE_XOROSHIRO1024S_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024S_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024S_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024S_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024S_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024S_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024S_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024S_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024S_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoroshiro1024pp_consistency() {
+        let mut generator = Xoroshiro1024PP::new();
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024PP_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoroshiro1024ss_consistency() {
+        let mut generator = Xoroshiro1024SS::new();
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOROSHIRO1024SS_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoroshiro1024s_jump_consistency() {
+        let mut generator = Xoroshiro1024S::new();
+        let discard_512 = |engine: &mut Xoroshiro1024S| engine.discard_pow2(512);
+        let discard_768 = |engine: &mut Xoroshiro1024S| engine.discard_pow2(768);
+
+        test_tables::JUMP_TABLE_XOROSHIRO1024S_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024S_768.test(&mut generator, discard_768);
+        test_tables::JUMP_TABLE_XOROSHIRO1024S_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024S_768.test(&mut generator, discard_768);
+        test_tables::JUMP_TABLE_XOROSHIRO1024S_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024S_768.test(&mut generator, discard_768);
+    }
+
+    #[test]
+    fn test_xoroshiro1024pp_jump_consistency() {
+        let mut generator = Xoroshiro1024PP::new();
+        let discard_512 = |engine: &mut Xoroshiro1024PP| engine.discard_pow2(512);
+        let discard_768 = |engine: &mut Xoroshiro1024PP| engine.discard_pow2(768);
+
+        test_tables::JUMP_TABLE_XOROSHIRO1024PP_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024PP_768.test(&mut generator, discard_768);
+        test_tables::JUMP_TABLE_XOROSHIRO1024PP_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024PP_768.test(&mut generator, discard_768);
+        test_tables::JUMP_TABLE_XOROSHIRO1024PP_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024PP_768.test(&mut generator, discard_768);
+    }
+
+    #[test]
+    fn test_xoroshiro1024ss_jump_consistency() {
+        let mut generator = Xoroshiro1024SS::new();
+        let discard_512 = |engine: &mut Xoroshiro1024SS| engine.discard_pow2(512);
+        let discard_768 = |engine: &mut Xoroshiro1024SS| engine.discard_pow2(768);
+
+        test_tables::JUMP_TABLE_XOROSHIRO1024SS_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024SS_768.test(&mut generator, discard_768);
+        test_tables::JUMP_TABLE_XOROSHIRO1024SS_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024SS_768.test(&mut generator, discard_768);
+        test_tables::JUMP_TABLE_XOROSHIRO1024SS_512.test(&mut generator, discard_512);
+        test_tables::JUMP_TABLE_XOROSHIRO1024SS_768.test(&mut generator, discard_768);
+    }
 }
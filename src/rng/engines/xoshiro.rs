@@ -22,6 +22,10 @@ impl Permuter<u64, 4> for Xoshiro256Permuter {
     }
 }
 
+impl StateTag for Xoshiro256Permuter {
+    fn tag() -> String { "xoshiro256".into() }
+}
+
 impl PolyDiscarder<u64, 4> for Xoshiro256Permuter {
     fn polynomials() -> &'static [(usize, [u64; 4])] {
         &[
@@ -40,6 +44,10 @@ impl Scrambler<u64, 4> for Xoshiro256PScrambler {
     }
 }
 
+impl StateTag for Xoshiro256PScrambler {
+    fn tag() -> String { "+".into() }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub struct Xoshiro256PPScrambler;
 
@@ -49,6 +57,10 @@ impl Scrambler<u64, 4> for Xoshiro256PPScrambler {
     }
 }
 
+impl StateTag for Xoshiro256PPScrambler {
+    fn tag() -> String { "++".into() }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub struct Xoshiro256SSScrambler;
 
@@ -58,10 +70,176 @@ impl Scrambler<u64, 4> for Xoshiro256SSScrambler {
     }
 }
 
+impl StateTag for Xoshiro256SSScrambler {
+    fn tag() -> String { "**".into() }
+}
+
 pub type Xoshiro256P = GenericLinearGenerator<u64, 4, Xoshiro256Permuter, Xoshiro256PScrambler, Xoshiro256Permuter>;
 pub type Xoshiro256PP = GenericLinearGenerator<u64, 4, Xoshiro256Permuter, Xoshiro256PPScrambler, Xoshiro256Permuter>;
 pub type Xoshiro256SS = GenericLinearGenerator<u64, 4, Xoshiro256Permuter, Xoshiro256SSScrambler, Xoshiro256Permuter>;
 
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro512Permuter;
+
+impl Permuter<u64, 8> for Xoshiro512Permuter {
+    fn permute(mut state: [u64; 8]) -> [u64; 8] {
+        let t = state[1] << 11;
+
+        state[2] ^= state[0];
+        state[5] ^= state[1];
+        state[1] ^= state[2];
+        state[7] ^= state[3];
+        state[3] ^= state[4];
+        state[4] ^= state[5];
+        state[0] ^= state[6];
+        state[6] ^= state[7];
+
+        state[6] ^= t;
+
+        state[7] = state[7].rotate_left(21);
+
+        return state;
+    }
+}
+
+impl StateTag for Xoshiro512Permuter {
+    fn tag() -> String { "xoshiro512".into() }
+}
+
+impl PolyDiscarder<u64, 8> for Xoshiro512Permuter {
+    fn polynomials() -> &'static [(usize, [u64; 8])] {
+        &[
+            (384, [
+                0x11467fef8f921d28_u64, 0xa2a819f2e79c8ea8_u64, 0xa8299fc284b3959a_u64, 0xb4d347340ca63ee1_u64,
+                0x1cb0940bedbff6ce_u64, 0xd956c5c4fa1f8e17_u64, 0x915e38fd4eda93bc_u64, 0x5b3ccdfa5d7daca5_u64,
+            ]),
+            (256, [
+                0x33ed89b6e7a353f9_u64, 0x760083d7955323be_u64, 0x2837f2fbb5f22fae_u64, 0x4b8c5674d309511c_u64,
+                0xb11ac47a7ba28c25_u64, 0xf1be7667092bcc1c_u64, 0x53851efdb6df0aaf_u64, 0x1ebbc8b23eaf25db_u64,
+            ]),
+        ]
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro512PScrambler;
+
+impl Scrambler<u64, 8> for Xoshiro512PScrambler {
+    fn scramble(state: &[u64; 8]) -> u64 {
+        state[0].wrapping_add(state[2])
+    }
+}
+
+impl StateTag for Xoshiro512PScrambler {
+    fn tag() -> String { "+".into() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro512PPScrambler;
+
+impl Scrambler<u64, 8> for Xoshiro512PPScrambler {
+    fn scramble(state: &[u64; 8]) -> u64 {
+        state[0].wrapping_add(state[2]).rotate_left(17).wrapping_add(state[2])
+    }
+}
+
+impl StateTag for Xoshiro512PPScrambler {
+    fn tag() -> String { "++".into() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro512SSScrambler;
+
+impl Scrambler<u64, 8> for Xoshiro512SSScrambler {
+    fn scramble(state: &[u64; 8]) -> u64 {
+        state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+    }
+}
+
+impl StateTag for Xoshiro512SSScrambler {
+    fn tag() -> String { "**".into() }
+}
+
+pub type Xoshiro512P = GenericLinearGenerator<u64, 8, Xoshiro512Permuter, Xoshiro512PScrambler, Xoshiro512Permuter>;
+pub type Xoshiro512PP = GenericLinearGenerator<u64, 8, Xoshiro512Permuter, Xoshiro512PPScrambler, Xoshiro512Permuter>;
+pub type Xoshiro512SS = GenericLinearGenerator<u64, 8, Xoshiro512Permuter, Xoshiro512SSScrambler, Xoshiro512Permuter>;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro128Permuter;
+
+impl Permuter<u32, 4> for Xoshiro128Permuter {
+    fn permute(mut state: [u32; 4]) -> [u32; 4] {
+        let t = state[1] << 9;
+
+        state[2] ^= state[0];
+        state[3] ^= state[1];
+        state[1] ^= state[2];
+        state[0] ^= state[3];
+
+        state[2] ^= t;
+
+        state[3] = state[3].rotate_left(11);
+
+        return state;
+    }
+}
+
+impl StateTag for Xoshiro128Permuter {
+    fn tag() -> String { "xoshiro128".into() }
+}
+
+impl PolyDiscarder<u32, 4> for Xoshiro128Permuter {
+    fn polynomials() -> &'static [(usize, [u32; 4])] {
+        &[
+            (96, [0xb523952e_u32, 0x0b6f099f_u32, 0xccf5a0ef_u32, 0x1c580662_u32]),
+            (64, [0x8764000b_u32, 0xf542d2d3_u32, 0x6fa035c3_u32, 0x77f2db5b_u32]),
+        ]
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro128PScrambler;
+
+impl Scrambler<u32, 4> for Xoshiro128PScrambler {
+    fn scramble(state: &[u32; 4]) -> u32 {
+        state[0].wrapping_add(state[3])
+    }
+}
+
+impl StateTag for Xoshiro128PScrambler {
+    fn tag() -> String { "+".into() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro128PPScrambler;
+
+impl Scrambler<u32, 4> for Xoshiro128PPScrambler {
+    fn scramble(state: &[u32; 4]) -> u32 {
+        state[0].wrapping_add(state[3]).rotate_left(7).wrapping_add(state[0])
+    }
+}
+
+impl StateTag for Xoshiro128PPScrambler {
+    fn tag() -> String { "++".into() }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Xoshiro128SSScrambler;
+
+impl Scrambler<u32, 4> for Xoshiro128SSScrambler {
+    fn scramble(state: &[u32; 4]) -> u32 {
+        state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+    }
+}
+
+impl StateTag for Xoshiro128SSScrambler {
+    fn tag() -> String { "**".into() }
+}
+
+pub type Xoshiro128P = GenericLinearGenerator<u32, 4, Xoshiro128Permuter, Xoshiro128PScrambler, Xoshiro128Permuter>;
+pub type Xoshiro128PP = GenericLinearGenerator<u32, 4, Xoshiro128Permuter, Xoshiro128PPScrambler, Xoshiro128Permuter>;
+pub type Xoshiro128SS = GenericLinearGenerator<u32, 4, Xoshiro128Permuter, Xoshiro128SSScrambler, Xoshiro128Permuter>;
+
 #[cfg(test)]
 mod tests {
     use super::super::test_tables;
@@ -113,8 +291,8 @@ mod tests {
     #[test]
     fn test_xoshiro256p_jump_consistency() {
         let mut generator = Xoshiro256P::new();
-        let discard_128 = |engine: &mut Xoshiro256P| engine.discard(128);
-        let discard_192 = |engine: &mut Xoshiro256P| engine.discard(192);
+        let discard_128 = |engine: &mut Xoshiro256P| engine.discard_pow2(128);
+        let discard_192 = |engine: &mut Xoshiro256P| engine.discard_pow2(192);
 
         test_tables::JUMP_TABLE_XOSHIRO256P_128.test(&mut generator, discard_128);
         test_tables::JUMP_TABLE_XOSHIRO256P_192.test(&mut generator, discard_192);
@@ -127,8 +305,8 @@ mod tests {
     #[test]
     fn test_xoshiro256pp_jump_consistency() {
         let mut generator = Xoshiro256PP::new();
-        let discard_128 = |engine: &mut Xoshiro256PP| engine.discard(128);
-        let discard_192 = |engine: &mut Xoshiro256PP| engine.discard(192);
+        let discard_128 = |engine: &mut Xoshiro256PP| engine.discard_pow2(128);
+        let discard_192 = |engine: &mut Xoshiro256PP| engine.discard_pow2(192);
 
         test_tables::JUMP_TABLE_XOSHIRO256PP_128.test(&mut generator, discard_128);
         test_tables::JUMP_TABLE_XOSHIRO256PP_192.test(&mut generator, discard_192);
@@ -141,8 +319,8 @@ mod tests {
     #[test]
     fn test_xoshiro256ss_jump_consistency() {
         let mut generator = Xoshiro256SS::new();
-        let discard_128 = |engine: &mut Xoshiro256SS| engine.discard(128);
-        let discard_192 = |engine: &mut Xoshiro256SS| engine.discard(192);
+        let discard_128 = |engine: &mut Xoshiro256SS| engine.discard_pow2(128);
+        let discard_192 = |engine: &mut Xoshiro256SS| engine.discard_pow2(192);
 
         test_tables::JUMP_TABLE_XOSHIRO256SS_128.test(&mut generator, discard_128);
         test_tables::JUMP_TABLE_XOSHIRO256SS_192.test(&mut generator, discard_192);
@@ -151,4 +329,172 @@ mod tests {
         test_tables::JUMP_TABLE_XOSHIRO256SS_128.test(&mut generator, discard_128);
         test_tables::JUMP_TABLE_XOSHIRO256SS_192.test(&mut generator, discard_192);
     }
+
+    #[test]
+    fn test_xoshiro512p_consistency() {
+        let mut generator = Xoshiro512P::new();
+        test_tables::TABLE_XOSHIRO512P_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512P_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512P_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512P_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512P_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512P_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512P_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512P_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512P_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoshiro512pp_consistency() {
+        let mut generator = Xoshiro512PP::new();
+        test_tables::TABLE_XOSHIRO512PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512PP_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512PP_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512PP_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoshiro512ss_consistency() {
+        let mut generator = Xoshiro512SS::new();
+        test_tables::TABLE_XOSHIRO512SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512SS_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512SS_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO512SS_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoshiro512p_jump_consistency() {
+        let mut generator = Xoshiro512P::new();
+        let discard_256 = |engine: &mut Xoshiro512P| engine.discard_pow2(256);
+        let discard_384 = |engine: &mut Xoshiro512P| engine.discard_pow2(384);
+
+        test_tables::JUMP_TABLE_XOSHIRO512P_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512P_384.test(&mut generator, discard_384);
+        test_tables::JUMP_TABLE_XOSHIRO512P_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512P_384.test(&mut generator, discard_384);
+        test_tables::JUMP_TABLE_XOSHIRO512P_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512P_384.test(&mut generator, discard_384);
+    }
+
+    #[test]
+    fn test_xoshiro512pp_jump_consistency() {
+        let mut generator = Xoshiro512PP::new();
+        let discard_256 = |engine: &mut Xoshiro512PP| engine.discard_pow2(256);
+        let discard_384 = |engine: &mut Xoshiro512PP| engine.discard_pow2(384);
+
+        test_tables::JUMP_TABLE_XOSHIRO512PP_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512PP_384.test(&mut generator, discard_384);
+        test_tables::JUMP_TABLE_XOSHIRO512PP_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512PP_384.test(&mut generator, discard_384);
+        test_tables::JUMP_TABLE_XOSHIRO512PP_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512PP_384.test(&mut generator, discard_384);
+    }
+
+    #[test]
+    fn test_xoshiro512ss_jump_consistency() {
+        let mut generator = Xoshiro512SS::new();
+        let discard_256 = |engine: &mut Xoshiro512SS| engine.discard_pow2(256);
+        let discard_384 = |engine: &mut Xoshiro512SS| engine.discard_pow2(384);
+
+        test_tables::JUMP_TABLE_XOSHIRO512SS_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512SS_384.test(&mut generator, discard_384);
+        test_tables::JUMP_TABLE_XOSHIRO512SS_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512SS_384.test(&mut generator, discard_384);
+        test_tables::JUMP_TABLE_XOSHIRO512SS_256.test(&mut generator, discard_256);
+        test_tables::JUMP_TABLE_XOSHIRO512SS_384.test(&mut generator, discard_384);
+    }
+
+    #[test]
+    fn test_xoshiro128p_consistency() {
+        let mut generator = Xoshiro128P::new();
+        test_tables::TABLE_XOSHIRO128P_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128P_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128P_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128P_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128P_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128P_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128P_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128P_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128P_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoshiro128pp_consistency() {
+        let mut generator = Xoshiro128PP::new();
+        test_tables::TABLE_XOSHIRO128PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128PP_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128PP_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128PP_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128PP_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128PP_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoshiro128ss_consistency() {
+        let mut generator = Xoshiro128SS::new();
+        test_tables::TABLE_XOSHIRO128SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128SS_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128SS_SEED_2.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128SS_SEED_0.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128SS_SEED_1.test(&mut generator);
+        test_tables::TABLE_XOSHIRO128SS_SEED_2.test(&mut generator);
+    }
+
+    #[test]
+    fn test_xoshiro128p_jump_consistency() {
+        let mut generator = Xoshiro128P::new();
+        let discard_64 = |engine: &mut Xoshiro128P| engine.discard(1u128 << 64);
+        let discard_96 = |engine: &mut Xoshiro128P| engine.discard(1u128 << 96);
+
+        test_tables::JUMP_TABLE_XOSHIRO128P_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128P_96.test(&mut generator, discard_96);
+        test_tables::JUMP_TABLE_XOSHIRO128P_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128P_96.test(&mut generator, discard_96);
+        test_tables::JUMP_TABLE_XOSHIRO128P_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128P_96.test(&mut generator, discard_96);
+    }
+
+    #[test]
+    fn test_xoshiro128pp_jump_consistency() {
+        let mut generator = Xoshiro128PP::new();
+        let discard_64 = |engine: &mut Xoshiro128PP| engine.discard(1u128 << 64);
+        let discard_96 = |engine: &mut Xoshiro128PP| engine.discard(1u128 << 96);
+
+        test_tables::JUMP_TABLE_XOSHIRO128PP_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128PP_96.test(&mut generator, discard_96);
+        test_tables::JUMP_TABLE_XOSHIRO128PP_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128PP_96.test(&mut generator, discard_96);
+        test_tables::JUMP_TABLE_XOSHIRO128PP_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128PP_96.test(&mut generator, discard_96);
+    }
+
+    #[test]
+    fn test_xoshiro128ss_jump_consistency() {
+        let mut generator = Xoshiro128SS::new();
+        let discard_64 = |engine: &mut Xoshiro128SS| engine.discard(1u128 << 64);
+        let discard_96 = |engine: &mut Xoshiro128SS| engine.discard(1u128 << 96);
+
+        test_tables::JUMP_TABLE_XOSHIRO128SS_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128SS_96.test(&mut generator, discard_96);
+        test_tables::JUMP_TABLE_XOSHIRO128SS_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128SS_96.test(&mut generator, discard_96);
+        test_tables::JUMP_TABLE_XOSHIRO128SS_64.test(&mut generator, discard_64);
+        test_tables::JUMP_TABLE_XOSHIRO128SS_96.test(&mut generator, discard_96);
+    }
 }
@@ -1,6 +1,11 @@
 pub mod distributions;
 pub mod engines;
 pub mod numbers;
+pub mod patterns;
+pub mod quality;
+pub mod selection;
+pub mod sequences;
+pub mod stats;
 #[cfg(test)] pub mod test;
 
 pub use numbers::*;
@@ -12,10 +17,44 @@ pub trait UniformRandomBitGenerator {
     fn max() -> Self::ResultType;
 
     fn generate(&mut self) -> Self::ResultType;
+
+    /// Fills `dest` with consecutive results of `generate`; engines that produce several values at once override it.
+    fn fill(&mut self, dest: &mut [Self::ResultType]) {
+        for v in dest {
+            *v = self.generate();
+        }
+    }
+
+    /// Fills `dest` with random bytes, the results of `generate` in little endian order for generators that cover
+    /// their whole `ResultType` and 8 bits at a time out of `naive_bits_per_call` otherwise.
+    fn fill_bytes(&mut self, dest: &mut [u8]) where Self: Sized {
+        if util::naive_bits_per_call::<Self>() != <Self::ResultType as crate::NumericLimits>::DIGITS {
+            for byte in dest {
+                *byte = util::fill_with_entropy::<u8, _>(self, 8);
+            }
+
+            return;
+        }
+
+        let size = std::mem::size_of::<Self::ResultType>();
+        let mut chunks = dest.chunks_exact_mut(size);
+
+        for chunk in &mut chunks {
+            let v: u128 = crate::ToScalar::<u128>::as_to(self.generate());
+            chunk.copy_from_slice(&v.to_le_bytes()[..size]);
+        }
+
+        let rest = chunks.into_remainder();
+
+        if !rest.is_empty() {
+            let v: u128 = crate::ToScalar::<u128>::as_to(self.generate());
+            rest.copy_from_slice(&v.to_le_bytes()[..rest.len()]);
+        }
+    }
 }
 
 pub trait RandomNumberEngine: UniformRandomBitGenerator + Eq + Clone {
-    fn discard(&mut self, z: usize);
+    fn discard(&mut self, z: u128);
 
     fn reset(&mut self);
 
@@ -66,11 +105,18 @@ mod util {
         return res;
     }
 
+    /// `ret << shift`, where shifting out all of the bits of a `T` gives zero instead of overflowing.
+    fn shift_in<T: UnsignedIntegral>(ret: T, shift: u32) -> T {
+        if shift >= T::DIGITS { T::zero() } else { ret << shift }
+    }
+
+    /// `wanted_bits` bits from as many calls to `generator` as needed, for any `T` that can hold them, be it
+    /// narrower or wider than the results of `generator`.
     pub fn fill_with_entropy<T: UnsignedIntegral + FromScalar<G::ResultType>, G: UniformRandomBitGenerator>(generator: &mut G, wanted_bits: u32) -> T {
         debug_assert!(wanted_bits <= T::DIGITS);
 
         if naive_bits_per_call::<G>() == wanted_bits {
-            return FromScalar::from_scalar(generator.generate()).unwrap();
+            return FromScalar::as_from(naively_get_bits_from_generator(generator));
         }
 
         let whole_calls = wanted_bits / naive_bits_per_call::<G>();
@@ -79,8 +125,8 @@ mod util {
         let mut ret = T::zero();
 
         for _ in 0..whole_calls {
-            ret = ret << naive_bits_per_call::<G>();
-            ret = ret | FromScalar::from_scalar(naively_get_bits_from_generator(generator)).unwrap();
+            ret = shift_in(ret, naive_bits_per_call::<G>());
+            ret = ret | FromScalar::as_from(naively_get_bits_from_generator(generator));
         }
 
         if bits_after_whole_calls != 0 {
@@ -89,8 +135,8 @@ mod util {
             let excess_entropy = naively_get_bits_from_generator(generator);
             let excess_entropy = excess_entropy >> (naive_bits_per_call::<G>() - bits_after_whole_calls);
 
-            ret = ret << bits_after_whole_calls;
-            ret = ret | FromScalar::from_scalar(excess_entropy).unwrap();
+            ret = shift_in(ret, bits_after_whole_calls);
+            ret = ret | FromScalar::as_from(excess_entropy);
         }
 
         return ret;
@@ -166,6 +212,34 @@ mod util {
             let mut generator = DummyGenerator2 {};
             let res = fill_with_entropy::<u32, _>(&mut generator, 7);
             assert_eq!(res, 127u32);
+
+            let res = fill_with_entropy::<u32, _>(&mut generator, 6);
+            assert_eq!(res, 63u32);
+
+            let mut bytes = [0u8; 3];
+            generator.fill_bytes(&mut bytes);
+            assert_eq!(bytes, [0xFFu8; 3]);
+
+            // results narrower and wider than those of the generator
+            let mut generator = SplitMix64::new();
+            generator.seed_from_result(17);
+
+            let mut reference = generator;
+            assert_eq!(fill_with_entropy::<u8, _>(&mut generator, 8), (reference.generate() >> 56) as u8);
+            assert_eq!(fill_with_entropy::<u16, _>(&mut generator, 16), (reference.generate() >> 48) as u16);
+            assert_eq!(fill_with_entropy::<u32, _>(&mut generator, 32), (reference.generate() >> 32) as u32);
+
+            let wide = ((reference.generate() as u128) << 64) | reference.generate() as u128;
+            assert_eq!(fill_with_entropy::<u128, _>(&mut generator, 128), wide);
+
+            let mut generator = SplitMix32::new();
+            generator.seed_from_result(17);
+
+            let mut reference = generator;
+            assert_eq!(fill_with_entropy::<u8, _>(&mut generator, 8), (reference.generate() >> 24) as u8);
+
+            let wide = (0..4).fold(0u128, |wide, _| (wide << 32) | reference.generate() as u128);
+            assert_eq!(fill_with_entropy::<u128, _>(&mut generator, 128), wide);
         }
 
         #[test]
@@ -0,0 +1,166 @@
+pub mod multi_jittered;
+pub mod poisson_disk;
+
+pub use multi_jittered::*;
+pub use poisson_disk::*;
+
+use crate::rng::distributions::GenerateCanonical;
+use crate::rng::*;
+use crate::smallvec::Vector;
+use crate::*;
+
+/// A fixed number of points in `[0, 1)^D` that are spread out as a set.
+///
+/// Unlike the points of a `SampleGenerator`, which are well spread for any prefix, a pattern only has its properties
+/// once all `count` points are taken, and the order of the points is unspecified.
+pub trait SamplePattern<T: FloatingPoint, const D: usize> {
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, count: usize) -> Vec<Vector<T, D>>;
+}
+
+/// Stratified or "jittered" sampling: the cube is split into the finest grid of `k^D` equal cells that has no more
+/// cells than points, and every cell holds one uniformly placed point.
+///
+/// Points past the `k^D` of the grid are uniform over the whole cube, so counts that are powers of `D` work best.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct JitteredPattern {}
+
+impl JitteredPattern {
+    pub fn new() -> Self { Self {} }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const D: usize> SamplePattern<T, D> for JitteredPattern {
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, count: usize) -> Vec<Vector<T, D>> {
+        let strata = detail::strata_per_axis(count, D);
+        let cells = strata.pow(D as u32);
+
+        (0..count)
+            .map(|i| {
+                let mut cell = i;
+
+                Vector(std::array::from_fn(|_| {
+                    let jitter = T::generate_canonical(generator).get();
+
+                    if i < cells {
+                        let stratum = cell % strata;
+                        cell /= strata;
+
+                        detail::stratum_point(stratum, strata, jitter)
+                    } else {
+                        jitter
+                    }
+                }))
+            })
+            .collect()
+    }
+}
+
+/// Latin hypercube sampling (McKay, Beckman and Conover, "A Comparison of Three Methods for Selecting Values of Input
+/// Variables in the Analysis of Output from a Computer Code", 1979): every axis is split into `count` strata and
+/// every stratum of every axis holds exactly one point, for any count and number of dimensions.
+///
+/// The strata of the axes are paired up at random, so the points can still clump in more than one dimension.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LatinHypercubePattern {}
+
+impl LatinHypercubePattern {
+    pub fn new() -> Self { Self {} }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const D: usize> SamplePattern<T, D> for LatinHypercubePattern {
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, count: usize) -> Vec<Vector<T, D>> {
+        let permutations: [Vec<usize>; D] = std::array::from_fn(|_| detail::random_permutation(count, generator));
+
+        (0..count)
+            .map(|i| Vector(std::array::from_fn(|d| detail::stratum_point(permutations[d][i], count, T::generate_canonical(generator).get()))))
+            .collect()
+    }
+}
+
+mod detail {
+    use crate::rng::*;
+    use crate::*;
+
+    /// The largest `k` with `k^dimensions <= count`.
+    pub fn strata_per_axis(count: usize, dimensions: usize) -> usize {
+        let fits = |k: usize| k.checked_pow(dimensions as u32).is_some_and(|cells| cells <= count);
+
+        let mut k = (count as f64).powf(1f64 / dimensions as f64).round() as usize;
+
+        while !fits(k) {
+            k -= 1;
+        }
+
+        while fits(k + 1) {
+            k += 1;
+        }
+
+        k
+    }
+
+    /// The point `jitter` of the way into stratum `stratum` of `strata`, which stays below 1 after rounding.
+    pub fn stratum_point<T: FloatingPoint>(stratum: usize, strata: usize, jitter: T) -> T {
+        let v = (T::as_from(stratum) + jitter) / T::as_from(strata);
+
+        if v < T::one() {
+            v
+        } else {
+            T::one().next_down()
+        }
+    }
+
+    /// A uniformly random permutation of `0..count` by a Fisher–Yates shuffle.
+    pub fn random_permutation<G: UniformRandomBitGenerator>(count: usize, generator: &mut G) -> Vec<usize> {
+        let mut permutation = (0..count).collect::<Vec<_>>();
+        crate::rng::selection::shuffle(&mut permutation, generator);
+
+        permutation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+
+    #[test]
+    fn test_strata_per_axis() {
+        assert_eq!(detail::strata_per_axis(0, 2), 0);
+        assert_eq!(detail::strata_per_axis(15, 2), 3);
+        assert_eq!(detail::strata_per_axis(16, 2), 4);
+        assert_eq!(detail::strata_per_axis(124, 3), 4);
+        assert_eq!(detail::strata_per_axis(125, 3), 5);
+        assert_eq!(detail::strata_per_axis(usize::MAX, 2), u32::MAX as usize);
+    }
+
+    #[test]
+    fn test_jittered_strata() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        let points: Vec<Vector<f64, 3>> = JitteredPattern::new().generate(&mut generator, 130);
+        assert_eq!(points.len(), 130);
+
+        // the first 125 points fill the 5 x 5 x 5 cells, one each
+        let mut cells = points[..125].iter().map(|p| p.0.iter().fold(0, |cell, &v| cell * 5 + (v * 5f64) as usize)).collect::<Vec<_>>();
+        cells.sort();
+        assert_eq!(cells, (0..125).collect::<Vec<_>>());
+
+        assert!(points.iter().all(|p| p.0.iter().all(|v| (0f64..1f64).contains(v))));
+    }
+
+    #[test]
+    fn test_latin_hypercube_strata() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        for count in [1, 2, 7, 100] {
+            let points: Vec<Vector<f32, 4>> = LatinHypercubePattern::new().generate(&mut generator, count);
+
+            for d in 0..4 {
+                let mut strata = points.iter().map(|p| (p[d] as f64 * count as f64) as usize).collect::<Vec<_>>();
+                strata.sort();
+                assert_eq!(strata, (0..count).collect::<Vec<_>>(), "axis {} of {} points", d, count);
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::rng::distributions::{GenerateCanonical, UniformIntDistribution};
+use crate::rng::patterns::{detail as patterns_detail, SamplePattern};
+use crate::rng::*;
+use crate::smallvec::Vector;
+use crate::*;
+
+/// Multi-jittered sampling (Chiu, Shirley and Wang, "Multi-Jittered Sampling", 1994): the points are jittered in an
+/// `m x n` grid of cells and at the same time form a Latin hypercube of `m n` strata along both axes.
+///
+/// Each cell starts out in the canonical arrangement, whose substrata are shuffled independently within each row and
+/// each column. Counts that are not a product `m n` with `m = floor(sqrt(count))` take the
+/// first cells of the enclosing grid in row order, the last row of which is partial.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MultiJitteredPattern {}
+
+impl MultiJitteredPattern {
+    pub fn new() -> Self { Self {} }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>> SamplePattern<T, 2> for MultiJitteredPattern {
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, count: usize) -> Vec<Vector<T, 2>> {
+        let (m, n) = detail::grid(count);
+
+        // the substratum along x of the cell in column i and row j starts out as j and that along y as i; shuffling the
+        // substrata along y within each row and those along x within each column keeps the Latin hypercube
+        let mut sub_x = (0..m * n).map(|c| c / m).collect::<Vec<_>>();
+        let mut sub_y = (0..m * n).map(|c| c % m).collect::<Vec<_>>();
+
+        for j in 0..n {
+            for i in (1..m).rev() {
+                let k = UniformIntDistribution::new(0, i).generate(generator);
+                sub_y.swap(j * m + i, j * m + k);
+            }
+        }
+
+        for i in 0..m {
+            for j in (1..n).rev() {
+                let k = UniformIntDistribution::new(0, j).generate(generator);
+                sub_x.swap(j * m + i, k * m + i);
+            }
+        }
+
+        patterns_detail::random_permutation(count, generator)
+            .into_iter()
+            .map(|c| {
+                let (i, j) = (c % m, c / m);
+                let x = (T::as_from(sub_x[c]) + T::generate_canonical(generator).get()) / T::as_from(n);
+                let y = (T::as_from(sub_y[c]) + T::generate_canonical(generator).get()) / T::as_from(m);
+
+                Vector([patterns_detail::stratum_point(i, m, x), patterns_detail::stratum_point(j, n, y)])
+            })
+            .collect()
+    }
+}
+
+/// Correlated multi-jittered sampling (Kensler, "Correlated Multi-Jittered Sampling", 2013): multi-jittered points
+/// whose substrata are shuffled the same way in every row and in every column, which spreads them more evenly.
+///
+/// The shuffles are hashes of the index, so that any point of a pattern is available on its own through `point`;
+/// `generate` only draws the pattern's seed.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CorrelatedMultiJitteredPattern {}
+
+impl CorrelatedMultiJitteredPattern {
+    pub fn new() -> Self { Self {} }
+
+    /// Point `index` of the `count` points of the pattern `seed`, which are in random order.
+    ///
+    /// This is the final variant of the paper: the points are stratified into `count` strata along y for any count,
+    /// into the cells and along x only if `count` fills the grid.
+    pub fn point<T: FloatingPoint>(index: u32, count: u32, seed: u32) -> Vector<T, 2> {
+        assert!(index < count, "the index must be below the count");
+
+        let (m, n) = detail::grid(count as usize);
+        let (m, n) = (m as u32, n as u32);
+
+        let s = detail::permute(index, count, seed.wrapping_mul(0x51633e2d));
+        let sx = detail::permute(s % m, m, seed.wrapping_mul(0x68bc21eb));
+        let sy = detail::permute(s / m, n, seed.wrapping_mul(0x02e5be93));
+        let jx = detail::random_fraction(s, seed.wrapping_mul(0x967a889b));
+        let jy = detail::random_fraction(s, seed.wrapping_mul(0x368cc8b7));
+
+        let x = (sx as f64 + (sy as f64 + jx) / n as f64) / m as f64;
+        let y = (s as f64 + jy) / count as f64;
+
+        Vector([x, y].map(|v| {
+            let v = T::as_from(v);
+            if v < T::one() { v } else { T::one().next_down() }
+        }))
+    }
+}
+
+impl<T: FloatingPoint> SamplePattern<T, 2> for CorrelatedMultiJitteredPattern {
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, count: usize) -> Vec<Vector<T, 2>> {
+        let count = u32::try_from(count).expect("the pattern has at most 2^32 - 1 points");
+        let seed = UniformIntDistribution::new(0, u32::MAX).generate(generator);
+
+        (0..count).map(|index| Self::point(index, count, seed)).collect()
+    }
+}
+
+mod detail {
+    /// The columns `m = floor(sqrt(count))` and the rows `n` of the smallest grid of `m n >= count` cells.
+    pub fn grid(count: usize) -> (usize, usize) {
+        let mut m = (count as f64).sqrt() as usize;
+
+        while m * m > count {
+            m -= 1;
+        }
+
+        while (m + 1) * (m + 1) <= count {
+            m += 1;
+        }
+
+        let m = std::cmp::max(m, 1);
+
+        (m, count.div_ceil(m))
+    }
+
+    /// A hashed permutation of `0..length` chosen by `seed`, from Kensler's paper.
+    ///
+    /// Every step is a bijection on the bits under the mask, the smallest covering `length`; values that land past
+    /// `length` are hashed again until they are in range ("cycle walking").
+    pub fn permute(mut i: u32, length: u32, seed: u32) -> u32 {
+        let mut w = length - 1;
+        w |= w >> 1;
+        w |= w >> 2;
+        w |= w >> 4;
+        w |= w >> 8;
+        w |= w >> 16;
+
+        loop {
+            i ^= seed;
+            i = i.wrapping_mul(0xe170893d);
+            i ^= seed >> 16;
+            i ^= (i & w) >> 4;
+            i ^= seed >> 8;
+            i = i.wrapping_mul(0x0929eb3f);
+            i ^= seed >> 23;
+            i ^= (i & w) >> 1;
+            i = i.wrapping_mul(1 | seed >> 27);
+            i = i.wrapping_mul(0x6935fa69);
+            i ^= (i & w) >> 11;
+            i = i.wrapping_mul(0x74dcb303);
+            i ^= (i & w) >> 2;
+            i = i.wrapping_mul(0x9e501cc3);
+            i ^= (i & w) >> 2;
+            i = i.wrapping_mul(0xc860a3df);
+            i &= w;
+            i ^= i >> 5;
+
+            if i < length {
+                break;
+            }
+        }
+
+        i.wrapping_add(seed) % length
+    }
+
+    /// A hash of `i` and `seed` as a fraction in `[0, 1)`, Kensler's `randfloat`.
+    pub fn random_fraction(mut i: u32, seed: u32) -> f64 {
+        i ^= seed;
+        i ^= i >> 17;
+        i ^= i >> 10;
+        i = i.wrapping_mul(0xb36534e5);
+        i ^= i >> 12;
+        i ^= i >> 21;
+        i = i.wrapping_mul(0x93fc4795);
+        i ^= 0xdf6e307f;
+        i ^= i >> 17;
+        i = i.wrapping_mul(1 | seed >> 18);
+
+        i as f64 / 4294967296f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+
+    fn strata(points: &[Vector<f64, 2>], axis: usize) -> Vec<usize> {
+        let mut strata = points.iter().map(|p| (p[axis] * points.len() as f64) as usize).collect::<Vec<_>>();
+        strata.sort();
+        strata
+    }
+
+    /// Checks that a full grid of `points` form a Latin hypercube along both axes with a point in each cell.
+    fn check_multi_jittered(points: &[Vector<f64, 2>]) {
+        let count = points.len();
+        let (m, n) = detail::grid(count);
+        assert_eq!(m * n, count);
+
+        let mut cells = points.iter().map(|p| (p[1] * n as f64) as usize * m + (p[0] * m as f64) as usize).collect::<Vec<_>>();
+        cells.sort();
+        assert_eq!(cells, (0..count).collect::<Vec<_>>(), "the cells of {} points", count);
+
+        for axis in 0..2 {
+            assert_eq!(strata(points, axis), (0..count).collect::<Vec<_>>(), "axis {} of {} points", axis, count);
+        }
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(detail::grid(1), (1, 1));
+        assert_eq!(detail::grid(16), (4, 4));
+        assert_eq!(detail::grid(20), (4, 5));
+        assert_eq!(detail::grid(24), (4, 6));
+        assert_eq!(detail::grid(25), (5, 5));
+    }
+
+    #[test]
+    fn test_permute() {
+        for length in [1u32, 2, 5, 16, 17, 100, 1000] {
+            for seed in [0u32, 1, 0xdeadbeef] {
+                let mut permutation = (0..length).map(|i| detail::permute(i, length, seed)).collect::<Vec<_>>();
+                permutation.sort();
+                assert_eq!(permutation, (0..length).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_jittered_strata() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        for count in [1, 2, 12, 16, 20, 99, 256] {
+            check_multi_jittered(&MultiJitteredPattern::new().generate(&mut generator, count));
+            check_multi_jittered(&CorrelatedMultiJitteredPattern::new().generate(&mut generator, count));
+        }
+
+        // the substrata are shuffled, in the canonical arrangement the substratum along y is the column in every cell
+        let points: Vec<Vector<f64, 2>> = MultiJitteredPattern::new().generate(&mut generator, 256);
+        assert!(points.iter().filter(|p| ((p[1] * 16f64).fract() * 16f64) as usize == (p[0] * 16f64) as usize).count() < 64);
+
+        // partial grids keep a point in each of the first cells and the correlated points stay stratified along y
+        for count in [3, 7, 10, 250] {
+            let (m, n) = detail::grid(count);
+
+            let points: Vec<Vector<f64, 2>> = MultiJitteredPattern::new().generate(&mut generator, count);
+            let mut cells = points.iter().map(|p| (p[1] * n as f64) as usize * m + (p[0] * m as f64) as usize).collect::<Vec<_>>();
+            cells.sort();
+            assert_eq!(cells, (0..count).collect::<Vec<_>>(), "the cells of {} points", count);
+
+            let points: Vec<Vector<f64, 2>> = CorrelatedMultiJitteredPattern::new().generate(&mut generator, count);
+            assert_eq!(strata(&points, 1), (0..count).collect::<Vec<_>>(), "{} correlated points", count);
+        }
+    }
+}
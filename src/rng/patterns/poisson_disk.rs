@@ -0,0 +1,167 @@
+use crate::rng::distributions::GenerateCanonical;
+use crate::rng::patterns::{detail as patterns_detail, SamplePattern};
+use crate::rng::*;
+use crate::smallvec::Vector;
+use crate::*;
+
+/// Blue noise by dart throwing (Cook, "Stochastic Sampling in Computer Graphics", 1986): uniformly random candidates
+/// are kept unless they fall within a radius of a point that was kept before. Distances wrap around the faces of the
+/// cube, so that the pattern tiles.
+///
+/// To always end up with `count` points, the radius starts out at the spacing of a grid of `count` cells and shrinks
+/// by `shrink` whenever `attempts` candidates in a row were rejected (McCool and Fiume, "Hierarchical Poisson Disk
+/// Sampling Distributions", 1992). The points are kept in a grid of about `count` cells so that a candidate is only
+/// compared against the points in the cells around it, which stays cheap for a few dimensions.
+#[derive(Copy, Clone, Debug)]
+pub struct PoissonDiskPattern {
+    attempts: usize,
+    shrink: f64,
+}
+
+impl PoissonDiskPattern {
+    pub fn new(attempts: usize, shrink: f64) -> Self {
+        assert!(attempts > 0, "at least one candidate has to be tried per radius");
+        assert!(0f64 < shrink && shrink < 1f64, "the radius has to shrink");
+
+        Self { attempts, shrink }
+    }
+
+    pub fn attempts(&self) -> usize { self.attempts }
+    pub fn shrink(&self) -> f64 { self.shrink }
+}
+
+impl Default for PoissonDiskPattern {
+    fn default() -> Self { Self::new(64, 0.95f64) }
+}
+
+impl<T: FloatingPoint + GenerateCanonical<T>, const D: usize> SamplePattern<T, D> for PoissonDiskPattern {
+    fn generate<G: UniformRandomBitGenerator>(&mut self, generator: &mut G, count: usize) -> Vec<Vector<T, D>> {
+        let resolution = std::cmp::max(patterns_detail::strata_per_axis(count, D), 1);
+        let mut grid = detail::Grid::<D>::new(resolution);
+
+        let mut points: Vec<Vector<T, D>> = Vec::with_capacity(count);
+        let mut radius = (1f64 / count as f64).powf(1f64 / D as f64);
+        let mut misses = 0;
+
+        while points.len() < count {
+            let candidate = Vector(std::array::from_fn(|_| T::generate_canonical(generator).get()));
+            let cell = grid.cell_of(&candidate);
+
+            if grid.neighbours(cell, radius).all(|i| detail::toroidal_distance_squared(&points[i], &candidate) >= radius * radius) {
+                grid.insert(cell, points.len());
+                points.push(candidate);
+                misses = 0;
+            } else {
+                misses += 1;
+
+                if misses == self.attempts {
+                    radius *= self.shrink;
+                    misses = 0;
+                }
+            }
+        }
+
+        points
+    }
+}
+
+mod detail {
+    use crate::smallvec::Vector;
+    use crate::*;
+
+    /// The indices of the points in each of `resolution^D` cells.
+    pub struct Grid<const D: usize> {
+        resolution: usize,
+        cells: Vec<Vec<usize>>,
+    }
+
+    impl<const D: usize> Grid<D> {
+        pub fn new(resolution: usize) -> Self { Self { resolution, cells: vec![Vec::new(); resolution.pow(D as u32)] } }
+
+        pub fn cell_of<T: FloatingPoint>(&self, point: &Vector<T, D>) -> [usize; D] {
+            std::array::from_fn(|d| {
+                let v: f64 = point[d].as_to();
+                std::cmp::min((v * self.resolution as f64) as usize, self.resolution - 1)
+            })
+        }
+
+        pub fn insert(&mut self, cell: [usize; D], index: usize) {
+            let flat = self.flatten(cell);
+            self.cells[flat].push(index);
+        }
+
+        /// The points in the cells that can hold points within `radius` of a point in `cell`, wrapping around.
+        pub fn neighbours(&self, cell: [usize; D], radius: f64) -> impl Iterator<Item = usize> + '_ {
+            let reach = (radius * self.resolution as f64).ceil() as usize;
+            // the neighbourhood must not wrap around onto itself
+            let span = std::cmp::min(2 * reach + 1, self.resolution);
+            let start = cell.map(|c| if span == self.resolution { 0 } else { c + self.resolution - reach });
+
+            (0..span.pow(D as u32)).flat_map(move |mut offset| {
+                let neighbour = std::array::from_fn(|d| {
+                    let coordinate = (start[d] + offset % span) % self.resolution;
+                    offset /= span;
+                    coordinate
+                });
+
+                self.cells[self.flatten(neighbour)].iter().copied()
+            })
+        }
+
+        fn flatten(&self, cell: [usize; D]) -> usize { cell.iter().rev().fold(0, |flat, &c| flat * self.resolution + c) }
+    }
+
+    pub fn toroidal_distance_squared<T: FloatingPoint, const D: usize>(a: &Vector<T, D>, b: &Vector<T, D>) -> f64 {
+        (0..D)
+            .map(|d| {
+                let delta: f64 = (a[d] - b[d]).as_to();
+                let delta = delta.abs();
+                let delta = delta.min(1f64 - delta);
+
+                delta * delta
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+
+    fn minimum_distance<const D: usize>(points: &[Vector<f64, D>]) -> f64 {
+        let mut res = f64::INFINITY;
+
+        for (i, a) in points.iter().enumerate() {
+            for b in &points[i + 1..] {
+                res = res.min(detail::toroidal_distance_squared(a, b).sqrt());
+            }
+        }
+
+        res
+    }
+
+    #[test]
+    fn test_poisson_disk_spacing() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        // random dart throwing fills the plane up to a radius of about 0.75 of the densest, hexagonal packing
+        let points: Vec<Vector<f64, 2>> = PoissonDiskPattern::default().generate(&mut generator, 400);
+        let densest = (2f64 / (3f64.sqrt() * 400f64)).sqrt();
+
+        assert_eq!(points.len(), 400);
+        assert!(minimum_distance(&points) > 0.6f64 * densest, "{} against {}", minimum_distance(&points), densest);
+
+        let points: Vec<Vector<f64, 3>> = PoissonDiskPattern::default().generate(&mut generator, 200);
+        assert_eq!(points.len(), 200);
+        assert!(minimum_distance(&points) > 0.6f64 * (1f64 / 200f64).powf(1f64 / 3f64));
+
+        // jittered points of the same count come much closer
+        let points: Vec<Vector<f64, 2>> = crate::rng::patterns::JitteredPattern::new().generate(&mut generator, 400);
+        assert!(minimum_distance(&points) < 0.2f64 * densest);
+
+        let points: Vec<Vector<f64, 2>> = PoissonDiskPattern::default().generate(&mut generator, 1);
+        assert_eq!(points.len(), 1);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::rng::stats::{regularized_gamma_p, TestResult};
+
+use super::*;
+
+/// Marsaglia's birthday spacings test: 4096 birthdays in a year of `2^32` days, the bits `shift..shift + 32` of
+/// words, are sorted and the number of repeated spacings between them counted.
+///
+/// The counts of `repetitions` years add up to a Poisson variable with the mean `repetitions * 4096^3 / (4 * 2^32)`;
+/// the p-value is the probability of at least as many repeats. Lattice structure, as of linear congruential
+/// generators, shows up as too many repeats.
+#[derive(Copy, Clone, Debug)]
+pub struct BirthdaySpacingsTest {
+    shift: u32,
+    repetitions: usize,
+}
+
+impl BirthdaySpacingsTest {
+    const BIRTHDAYS: usize = 4096;
+
+    pub fn new(shift: u32, repetitions: usize) -> Self {
+        assert!(shift <= 32, "the days take 32 bits of a word");
+        assert!(repetitions > 0, "at least one year has to be drawn");
+
+        Self { shift, repetitions }
+    }
+}
+
+impl QualityTest for BirthdaySpacingsTest {
+    fn run(&self, source: &mut dyn FnMut() -> u64) -> Vec<QualityResult> {
+        let repeats = (0..self.repetitions)
+            .map(|_| {
+                let mut days = detail::words(source, Self::BIRTHDAYS).into_iter().map(|word| (word >> self.shift) as u32).collect::<Vec<_>>();
+                days.sort_unstable();
+
+                let mut spacings = std::iter::once(days[0]).chain(days.windows(2).map(|pair| pair[1] - pair[0])).collect::<Vec<_>>();
+                spacings.sort_unstable();
+
+                spacings.windows(2).filter(|pair| pair[0] == pair[1]).count()
+            })
+            .sum::<usize>();
+
+        let mean = self.repetitions as f64 * (Self::BIRTHDAYS as f64).powi(3) / (4f64 * 2f64.powi(32));
+
+        // P(X >= k) = P(k, mean) for a Poisson variable X
+        let p_value = if repeats == 0 { 1f64 } else { regularized_gamma_p(repeats as f64, mean) };
+
+        vec![QualityResult::new(format!("birthday spacings, bits {}..{}", self.shift, self.shift + 32), TestResult::new(repeats as f64, p_value))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::RandomNumberEngine;
+
+    #[test]
+    fn test_birthday_spacings() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+        let mut source = || generator.generate();
+
+        let result = BirthdaySpacingsTest::new(0, 32).run(&mut source)[0].result();
+        assert!(result.p_value() > 1e-4 && result.p_value() < 1f64 - 1e-4, "{:?}", result);
+
+        // a multiplicative congruential generator puts its multiples on a few lines
+        let mut state = 1u64;
+        let mut source = || {
+            state = state.wrapping_mul(69069);
+            state << 32
+        };
+
+        assert!(BirthdaySpacingsTest::new(32, 32).run(&mut source)[0].result().p_value() < 1e-12);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::rng::stats;
+
+use super::*;
+
+/// Knuth's gap test (TAOCP vol. 2, 3.3.2 D): the lengths of the runs of uniform numbers outside of
+/// `[lower, upper)` between the numbers inside are geometrically distributed.
+///
+/// The numbers are the top 53 bits of words; the lengths of `gaps` gaps are compared by the chi-square test, with
+/// the long gaps in one bin.
+#[derive(Copy, Clone, Debug)]
+pub struct GapTest {
+    lower: f64,
+    upper: f64,
+    gaps: usize,
+}
+
+impl GapTest {
+    pub fn new(lower: f64, upper: f64, gaps: usize) -> Self {
+        assert!(0f64 <= lower && lower < upper && upper <= 1f64, "the interval must be a non-empty part of [0, 1)");
+        assert!(gaps > 0, "at least one gap has to be measured");
+
+        Self { lower, upper, gaps }
+    }
+}
+
+impl QualityTest for GapTest {
+    fn run(&self, source: &mut dyn FnMut() -> u64) -> Vec<QualityResult> {
+        let p = self.upper - self.lower;
+
+        // the longest gap with its own bin expects at least about 5 gaps in it
+        let longest = std::cmp::max(((5f64 / (self.gaps as f64 * p)).ln() / (1f64 - p).ln()).floor() as usize, 1);
+        let mut observed = vec![0usize; longest + 1];
+
+        for _ in 0..self.gaps {
+            let mut length = 0usize;
+
+            loop {
+                let u = (source() >> 11) as f64 / (1u64 << 53) as f64;
+
+                if self.lower <= u && u < self.upper {
+                    break;
+                }
+
+                length += 1;
+            }
+
+            observed[std::cmp::min(length, longest)] += 1;
+        }
+
+        let mut expected = (0..longest).map(|r| p * (1f64 - p).powi(r as i32) * self.gaps as f64).collect::<Vec<_>>();
+        expected.push((1f64 - p).powi(longest as i32) * self.gaps as f64);
+
+        vec![QualityResult::new(format!("gap, [{}, {})", self.lower, self.upper), stats::chi_square(&observed, &expected))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::RandomNumberEngine;
+
+    #[test]
+    fn test_gap() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+        let mut source = || generator.generate();
+
+        let result = GapTest::new(0.25f64, 0.5f64, 16384).run(&mut source)[0].result();
+        assert!(result.p_value() > 1e-4, "{:?}", result);
+
+        // an additive recurrence on the golden ratio spaces its visits to any interval far too evenly
+        let mut state = 0u64;
+        let mut source = || {
+            state = state.wrapping_add(0x9E3779B97F4A7C15);
+            state
+        };
+
+        let result = GapTest::new(0.25f64, 0.5f64, 16384).run(&mut source)[0].result();
+        assert!(result.p_value() < 1e-12, "{:?}", result);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::rng::stats;
+
+use super::*;
+
+/// The dependency of the Hamming weights of consecutive words: the weights of `tuples` non-overlapping tuples of
+/// `length` words are classified as low (below 30), medium or high (above 34), and the counts of the `3^length`
+/// combinations are compared with those of independent binomial weights by the chi-square test.
+#[derive(Copy, Clone, Debug)]
+pub struct HammingWeightTest {
+    length: u32,
+    tuples: usize,
+}
+
+impl HammingWeightTest {
+    pub fn new(length: u32, tuples: usize) -> Self {
+        assert!((1..=8).contains(&length), "the tuples must have 1 to 8 words");
+        assert!(tuples > 0, "at least one tuple has to be drawn");
+
+        Self { length, tuples }
+    }
+}
+
+impl QualityTest for HammingWeightTest {
+    fn run(&self, source: &mut dyn FnMut() -> u64) -> Vec<QualityResult> {
+        let classes = detail::class_probabilities();
+        let cells = 3usize.pow(self.length);
+
+        let mut observed = vec![0usize; cells];
+
+        for _ in 0..self.tuples {
+            let cell = (0..self.length).fold(0usize, |cell, _| cell * 3 + detail::class(source().count_ones()));
+            observed[cell] += 1;
+        }
+
+        let expected = (0..cells)
+            .map(|cell| {
+                let mut rest = cell;
+
+                (0..self.length)
+                    .map(|_| {
+                        let p = classes[rest % 3];
+                        rest /= 3;
+                        p
+                    })
+                    .product::<f64>()
+                    * self.tuples as f64
+            })
+            .collect::<Vec<_>>();
+
+        vec![QualityResult::new(format!("Hamming weight, {} words", self.length), stats::chi_square(&observed, &expected))]
+    }
+}
+
+mod detail {
+    pub fn class(weight: u32) -> usize {
+        match weight {
+            0..30 => 0,
+            30..=34 => 1,
+            _ => 2,
+        }
+    }
+
+    /// The probabilities of the classes for the Binomial(64, 1/2) weight of a random word.
+    pub fn class_probabilities() -> [f64; 3] {
+        let mut ret = [0f64; 3];
+        let mut binomial = 1f64;
+
+        for weight in 0..=64u32 {
+            ret[class(weight)] += binomial / 2f64.powi(64);
+            binomial = binomial * (64 - weight) as f64 / (weight + 1) as f64;
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::RandomNumberEngine;
+
+    #[test]
+    fn test_hamming_weight() {
+        let classes = detail::class_probabilities();
+        assert!((classes.iter().sum::<f64>() - 1f64).abs() < 1e-15);
+        assert!((classes[0] - classes[2]).abs() < 1e-15);
+
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+        let mut source = || generator.generate();
+
+        let result = HammingWeightTest::new(3, 65536).run(&mut source)[0].result();
+        assert!(result.p_value() > 1e-4, "{:?}", result);
+
+        // a word and its complement alternate between high and low weights
+        let mut state = 0u64;
+        let mut toggle = false;
+        let mut source = || {
+            toggle = !toggle;
+            if toggle {
+                state = generator.generate();
+                state
+            } else {
+                !state
+            }
+        };
+
+        assert!(HammingWeightTest::new(2, 65536).run(&mut source)[0].result().p_value() < 1e-12);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::rng::engines::F2Polynomial;
+use crate::rng::stats;
+
+use super::*;
+
+/// The linear complexity of single bits of the words (NIST SP 800-22, 2.10): the sequence of bit `b` of consecutive
+/// words is cut into `blocks` blocks of `block_length` bits, and the length of the shortest LFSR that generates each
+/// block is found by Berlekamp–Massey.
+///
+/// The lengths of random blocks are close to half the block length, their deviations are compared with the
+/// distribution of random blocks by the chi-square test. Bits that are linear in a state of fewer than
+/// `block_length / 2` bits, such as the lowest bit of generators with a + scrambler, fail.
+#[derive(Clone, Debug)]
+pub struct LinearComplexityTest {
+    bits: Vec<u32>,
+    block_length: usize,
+    blocks: usize,
+}
+
+impl LinearComplexityTest {
+    pub fn new(bits: Vec<u32>, block_length: usize, blocks: usize) -> Self {
+        assert!(bits.iter().all(|&bit| bit < 64), "the bits must be bits of a 64 bit word");
+        assert!(block_length >= 16, "the blocks must have at least 16 bits");
+        assert!(blocks > 0, "at least one block has to be drawn");
+
+        Self { bits, block_length, blocks }
+    }
+}
+
+impl QualityTest for LinearComplexityTest {
+    fn run(&self, source: &mut dyn FnMut() -> u64) -> Vec<QualityResult> {
+        let m = self.block_length as f64;
+        let sign = if self.block_length.is_multiple_of(2) { 1f64 } else { -1f64 };
+
+        let mean = m / 2f64 + (9f64 - sign) / 36f64 - (m / 3f64 + 2f64 / 9f64) / 2f64.powf(m);
+
+        // the deviations -3 and below, -2, ..., 3 and above
+        let probabilities = [1f64 / 96f64, 1f64 / 32f64, 1f64 / 8f64, 1f64 / 2f64, 1f64 / 4f64, 1f64 / 16f64, 1f64 / 48f64];
+        let expected = probabilities.map(|p| p * self.blocks as f64);
+
+        let mut observed = vec![[0usize; 7]; self.bits.len()];
+
+        for _ in 0..self.blocks {
+            let words = detail::words(source, self.block_length);
+
+            for (bit, observed) in self.bits.iter().zip(observed.iter_mut()) {
+                let sequence = words.iter().map(|word| (word >> bit) & 1 != 0).collect::<Vec<_>>();
+                let complexity = F2Polynomial::berlekamp_massey(&sequence).degree().unwrap_or(0) as f64;
+
+                let deviation = sign * (complexity - mean) + 2f64 / 9f64;
+                observed[(deviation.round().clamp(-3f64, 3f64) + 3f64) as usize] += 1;
+            }
+        }
+
+        self.bits
+            .iter()
+            .zip(observed.iter())
+            .map(|(bit, observed)| QualityResult::new(format!("linear complexity, bit {}", bit), stats::chi_square(observed, &expected)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::{Xoroshiro128P, Xoshiro256PP};
+    use crate::rng::RandomNumberEngine;
+
+    #[test]
+    fn test_linear_complexity() {
+        let test = LinearComplexityTest::new(vec![0, 1, 63], 500, 256);
+
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+        let mut source = || generator.generate();
+
+        for result in test.run(&mut source) {
+            assert!(result.result().p_value() > 1e-4, "{:?}", result);
+        }
+
+        // the lowest bit of xoroshiro128+ is the sum of the lowest bits of the state, which is linear in 128 bits
+        let mut generator = Xoroshiro128P::new();
+        generator.seed_from_result(17);
+        let mut source = || generator.generate();
+
+        let results = test.run(&mut source);
+        assert!(results[0].result().p_value() < 1e-12, "{:?}", results[0]);
+        assert!(results[1].result().p_value() > 1e-4 && results[2].result().p_value() > 1e-4, "{:?}", results);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::rng::stats;
+
+use super::*;
+
+/// The rank over GF(2) of `dimension x dimension` matrices whose rows are the top `dimension` bits of consecutive
+/// words (Marsaglia's binary rank test).
+///
+/// Random matrices are of full rank with a probability of about 0.289, of rank `dimension - 1` with 0.578 and of
+/// lower rank with the rest; the ranks of `matrices` matrices are compared by the chi-square test. The output of
+/// generators that are linear over GF(2) in a state of fewer than `dimension` words is never of full rank.
+#[derive(Copy, Clone, Debug)]
+pub struct MatrixRankTest {
+    dimension: usize,
+    matrices: usize,
+}
+
+impl MatrixRankTest {
+    pub fn new(dimension: usize, matrices: usize) -> Self {
+        assert!((3..=64).contains(&dimension), "the rows take at most 64 bits of a word");
+        assert!(matrices > 0, "at least one matrix has to be drawn");
+
+        Self { dimension, matrices }
+    }
+}
+
+impl QualityTest for MatrixRankTest {
+    fn run(&self, source: &mut dyn FnMut() -> u64) -> Vec<QualityResult> {
+        let n = self.dimension;
+
+        // the ranks n - 3 and below, n - 2, n - 1 and n
+        let mut observed = [0usize; 4];
+
+        for _ in 0..self.matrices {
+            let rows = super::detail::words(source, n).into_iter().map(|word| word >> (64 - n)).collect::<Vec<_>>();
+            let rank = detail::rank(rows);
+
+            observed[(rank + 3).saturating_sub(n)] += 1;
+        }
+
+        let probabilities = [n - 2, n - 1, n].map(|rank| detail::rank_probability(n, rank));
+        let expected = [1f64 - probabilities.iter().sum::<f64>(), probabilities[0], probabilities[1], probabilities[2]].map(|p| p * self.matrices as f64);
+
+        vec![QualityResult::new(format!("matrix rank, {0}x{0}", n), stats::chi_square(&observed, &expected))]
+    }
+}
+
+mod detail {
+    /// The rank over GF(2) of the matrix with the bits of `rows` as rows, by Gaussian elimination.
+    pub fn rank(mut rows: Vec<u64>) -> usize {
+        let mut rank = 0;
+
+        for bit in (0..64).rev() {
+            let mask = 1u64 << bit;
+
+            let Some(pivot) = (rank..rows.len()).find(|&i| rows[i] & mask != 0) else { continue };
+            rows.swap(rank, pivot);
+
+            for i in rank + 1..rows.len() {
+                if rows[i] & mask != 0 {
+                    rows[i] ^= rows[rank];
+                }
+            }
+
+            rank += 1;
+        }
+
+        rank
+    }
+
+    /// The probability that a random `n x n` matrix over GF(2) is of rank `rank`,
+    /// `2^(r (2n - r) - n^2) prod_(i < r) (1 - 2^(i - n))^2 / (1 - 2^(i - r))`.
+    pub fn rank_probability(n: usize, rank: usize) -> f64 {
+        let (n, r) = (n as i32, rank as i32);
+
+        let product = (0..r).map(|i| (1f64 - 2f64.powi(i - n)).powi(2) / (1f64 - 2f64.powi(i - r))).product::<f64>();
+
+        2f64.powi(r * (2 * n - r) - n * n) * product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::RandomNumberEngine;
+
+    #[test]
+    fn test_matrix_rank() {
+        assert_eq!(detail::rank(vec![0b100, 0b010, 0b110]), 2);
+        assert_eq!(detail::rank(vec![0b101, 0b011, 0b110, 0b001]), 3);
+
+        assert!((detail::rank_probability(32, 32) - 0.2887880951538411).abs() < 1e-12);
+        assert!((detail::rank_probability(32, 31) - 0.5775761901732).abs() < 1e-12);
+
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+        let mut source = || generator.generate();
+
+        let result = MatrixRankTest::new(32, 1024).run(&mut source)[0].result();
+        assert!(result.p_value() > 1e-4, "{:?}", result);
+
+        // a xorshift generator of 32 bits is linear in one word
+        let mut state = 1u32;
+        let mut source = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state as u64) << 32
+        };
+
+        assert!(MatrixRankTest::new(32, 256).run(&mut source)[0].result().p_value() < 1e-12);
+    }
+}
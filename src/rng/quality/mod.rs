@@ -0,0 +1,194 @@
+mod birthday_spacings;
+mod gap;
+mod hamming_weight;
+mod linear_complexity;
+mod matrix_rank;
+mod raw_output;
+
+pub use birthday_spacings::*;
+pub use gap::*;
+pub use hamming_weight::*;
+pub use linear_complexity::*;
+pub use matrix_rank::*;
+pub use raw_output::*;
+
+use crate::rng::stats::TestResult;
+use crate::rng::UniformRandomBitGenerator;
+
+/// An empirical test of the output of a generator, in the spirit of TestU01 and PractRand.
+///
+/// Tests see the output as 64 bit words, whatever the range of the generator; generators with fewer bits per call
+/// are called until a word is full, the earlier bits in the higher places.
+pub trait QualityTest {
+    /// The results of the test on the words of `source`, one for each of the statistics it looks at.
+    fn run(&self, source: &mut dyn FnMut() -> u64) -> Vec<QualityResult>;
+}
+
+/// The result of one statistic of a `QualityTest`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QualityResult {
+    name: String,
+    result: TestResult,
+}
+
+impl QualityResult {
+    pub fn new(name: String, result: TestResult) -> Self { Self { name, result } }
+
+    pub fn name(&self) -> &str { &self.name }
+    pub fn result(&self) -> TestResult { self.result }
+
+    /// Whether the p-value is within `significance` of 0 or of 1; a fit that is too good fails as well.
+    pub fn fails(&self, significance: f64) -> bool { self.result.p_value() < significance || self.result.p_value() > 1f64 - significance }
+}
+
+/// A set of `QualityTest`s that are run one after the other on the output of one generator.
+pub struct QualityBattery {
+    tests: Vec<Box<dyn QualityTest>>,
+    significance: f64,
+}
+
+impl QualityBattery {
+    /// An empty battery that flags the results with p-values within `significance` of 0 or 1.
+    pub fn new(significance: f64) -> Self {
+        assert!(0f64 < significance && significance < 0.5f64, "the significance must be in (0, 0.5)");
+
+        Self { tests: Vec::new(), significance }
+    }
+
+    /// Every test with parameters that take a few million words in total, flagging p-values within 1e-6 of 0 or 1.
+    pub fn standard() -> Self {
+        Self::new(1e-6f64)
+            .with(BirthdaySpacingsTest::new(0, 64))
+            .with(BirthdaySpacingsTest::new(32, 64))
+            .with(GapTest::new(0f64, 0.25f64, 65536))
+            .with(GapTest::new(0.5f64, 0.5f64 + 1f64 / 64f64, 16384))
+            .with(MatrixRankTest::new(32, 1024))
+            .with(MatrixRankTest::new(64, 512))
+            .with(HammingWeightTest::new(3, 262144))
+            .with(LinearComplexityTest::new(vec![0, 1, 31, 32, 63], 256, 512))
+    }
+
+    pub fn with<Test: QualityTest + 'static>(mut self, test: Test) -> Self {
+        self.tests.push(Box::new(test));
+        self
+    }
+
+    pub fn significance(&self) -> f64 { self.significance }
+
+    pub fn run<G: UniformRandomBitGenerator>(&self, generator: &mut G) -> QualityReport {
+        let mut source = || detail::next_word(generator);
+        let results = self.tests.iter().flat_map(|test| test.run(&mut source)).collect();
+
+        QualityReport { results, significance: self.significance }
+    }
+}
+
+/// The results of a `QualityBattery`; its `Display` is a table with the failures flagged.
+#[derive(Clone, Debug)]
+pub struct QualityReport {
+    results: Vec<QualityResult>,
+    significance: f64,
+}
+
+impl QualityReport {
+    pub fn results(&self) -> &[QualityResult] { &self.results }
+
+    pub fn failures(&self) -> impl Iterator<Item = &QualityResult> { self.results.iter().filter(|result| result.fails(self.significance)) }
+
+    pub fn passed(&self) -> bool { self.failures().next().is_none() }
+}
+
+impl std::fmt::Display for QualityReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<48} {:>14} {:>12}", "test", "statistic", "p-value")?;
+
+        for result in &self.results {
+            let flag = if result.fails(self.significance) { "  FAIL" } else { "" };
+            writeln!(f, "{:<48} {:>14.4} {:>12.4e}{}", result.name(), result.result().statistic(), result.result().p_value(), flag)?;
+        }
+
+        Ok(())
+    }
+}
+
+mod detail {
+    use crate::rng::util::{naive_bits_per_call, naively_get_bits_from_generator};
+    use crate::rng::UniformRandomBitGenerator;
+    use crate::ToScalar;
+
+    /// The next 64 bits of `generator`, the bits of earlier calls in the higher places; generators with more bits per
+    /// call give their lowest 64.
+    pub fn next_word<G: UniformRandomBitGenerator>(generator: &mut G) -> u64 {
+        let bits = std::cmp::min(naive_bits_per_call::<G>(), 64);
+
+        let mut word = 0u64;
+        let mut filled = 0u32;
+
+        while filled < 64 {
+            let value: u64 = naively_get_bits_from_generator(generator).as_to();
+            let take = std::cmp::min(bits, 64 - filled);
+
+            // the excess bits of the last call are dropped from the bottom
+            let value = (value & (u64::MAX >> (64 - bits))) >> (bits - take);
+            word = if take == 64 { value } else { (word << take) | value };
+            filled += take;
+        }
+
+        word
+    }
+
+    /// `count` words of `source`.
+    pub fn words(source: &mut dyn FnMut() -> u64, count: usize) -> Vec<u64> { (0..count).map(|_| source()).collect() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::{Xoroshiro128P, Xoroshiro128PP, Xoshiro256PP};
+    use crate::rng::RandomNumberEngine;
+
+    #[test]
+    fn test_next_word() {
+        struct Counter(u32);
+
+        impl UniformRandomBitGenerator for Counter {
+            type ResultType = u32;
+            fn min() -> Self::ResultType { 0 }
+            fn max() -> Self::ResultType { 0xFFFFF }
+            fn generate(&mut self) -> Self::ResultType {
+                self.0 += 1;
+                self.0
+            }
+        }
+
+        // three 20 bit calls and the top 4 bits of the fourth
+        assert_eq!(detail::next_word(&mut Counter(0)), (1u64 << 44) | (2u64 << 24) | (3u64 << 4));
+
+        let mut generator = Xoshiro256PP::new();
+        let mut reference = generator;
+        assert_eq!(detail::next_word(&mut generator), reference.generate());
+    }
+
+    #[test]
+    fn test_standard_battery() {
+        let battery = QualityBattery::standard();
+
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+        let report = battery.run(&mut generator);
+        assert!(report.passed(), "{}", report);
+
+        let mut generator = Xoroshiro128PP::new();
+        generator.seed_from_result(17);
+        let report = battery.run(&mut generator);
+        assert!(report.passed(), "{}", report);
+
+        // the lowest bit of xoroshiro128+ is an LFSR of degree 128, only the linear complexity of it fails
+        let mut generator = Xoroshiro128P::new();
+        generator.seed_from_result(17);
+        let report = battery.run(&mut generator);
+        let failures = report.failures().map(|result| result.name().to_string()).collect::<Vec<_>>();
+        assert_eq!(failures, ["linear complexity, bit 0"], "{}", report);
+        assert!(report.to_string().contains("FAIL"));
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+/// The output of a generator as a stream of bytes, the words of `QualityTest`s in little endian order, for external
+/// test suites that read random bits from a pipe.
+///
+/// PractRand reads them with `RNG_test stdin64`, for example from a program that runs
+/// `std::io::copy(&mut RawOutput::new(generator), &mut std::io::stdout().lock())` until the pipe is closed.
+pub struct RawOutput<G: UniformRandomBitGenerator> {
+    generator: G,
+    buffer: [u8; 8],
+    offset: usize,
+}
+
+impl<G: UniformRandomBitGenerator> RawOutput<G> {
+    pub fn new(generator: G) -> Self { Self { generator, buffer: [0; 8], offset: 8 } }
+
+    /// The generator, which has advanced past the bytes that were read and the rest of the last word.
+    pub fn into_inner(self) -> G { self.generator }
+}
+
+impl<G: UniformRandomBitGenerator> std::io::Read for RawOutput<G> {
+    /// Fills all of `buf`, the stream never ends.
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        for byte in buf.iter_mut() {
+            if self.offset == self.buffer.len() {
+                self.buffer = detail::next_word(&mut self.generator).to_le_bytes();
+                self.offset = 0;
+            }
+
+            *byte = self.buffer[self.offset];
+            self.offset += 1;
+        }
+
+        Ok(buf.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use std::io::Read;
+
+    #[test]
+    fn test_raw_output() {
+        let mut reference = Xoshiro256PP::new();
+        let mut output = RawOutput::new(reference);
+
+        // reads across the words
+        let mut bytes = [0u8; 20];
+        output.read_exact(&mut bytes[..3]).unwrap();
+        output.read_exact(&mut bytes[3..]).unwrap();
+
+        assert_eq!(bytes[..8], reference.generate().to_le_bytes());
+        assert_eq!(bytes[8..16], reference.generate().to_le_bytes());
+        assert_eq!(bytes[16..], reference.generate().to_le_bytes()[..4]);
+    }
+}
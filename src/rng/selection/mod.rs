@@ -0,0 +1,21 @@
+mod reservoir;
+mod shuffle;
+mod subset;
+mod weighted;
+
+pub use reservoir::*;
+pub use shuffle::*;
+pub use subset::*;
+pub use weighted::*;
+
+use crate::rng::*;
+
+mod detail {
+    use crate::rng::distributions::UniformIntDistribution;
+    use crate::rng::*;
+
+    /// An index uniform in `[low, high]`, without the bias of a modulo.
+    pub fn index_between<G: UniformRandomBitGenerator>(generator: &mut G, low: usize, high: usize) -> usize {
+        UniformIntDistribution::new(low, high).generate(generator)
+    }
+}
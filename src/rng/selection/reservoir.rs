@@ -0,0 +1,130 @@
+use crate::rng::distributions::GenerateCanonical;
+
+use super::*;
+
+/// A uniform sample of `capacity` items of a stream of unknown length, by Li's Algorithm L ("Reservoir-Sampling
+/// Algorithms of Time Complexity O(n(1 + log(N/n)))", 1994).
+///
+/// The first `capacity` items fill the reservoir. After that the number of items to skip until the next one that
+/// replaces a random item of the reservoir is drawn directly, so a stream of `n` items takes about
+/// `capacity * (1 + ln(n / capacity))` random numbers rather than one per item.
+#[derive(Clone, Debug)]
+pub struct ReservoirSampler<T> {
+    reservoir: Vec<T>,
+    capacity: usize,
+    seen: u64,
+
+    /// The largest key in the reservoir, were every item given a uniform key and the smallest ones kept.
+    w: f64,
+    /// The index in the stream of the next item that goes into the reservoir.
+    next: u64,
+}
+
+impl<T> ReservoirSampler<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "the reservoir must hold at least one item");
+
+        Self { reservoir: Vec::with_capacity(capacity), capacity, seen: 0, w: 1f64, next: 0 }
+    }
+
+    pub fn capacity(&self) -> usize { self.capacity }
+
+    /// The number of items that were pushed so far.
+    pub fn seen(&self) -> u64 { self.seen }
+
+    /// The sample of the items so far, all of them while there are at most `capacity`, in an unspecified order.
+    pub fn reservoir(&self) -> &[T] { &self.reservoir }
+
+    pub fn into_reservoir(self) -> Vec<T> { self.reservoir }
+
+    pub fn push<G: UniformRandomBitGenerator>(&mut self, item: T, generator: &mut G) {
+        let index = self.seen;
+        self.seen += 1;
+
+        if self.reservoir.len() < self.capacity {
+            self.reservoir.push(item);
+
+            if self.reservoir.len() == self.capacity {
+                self.advance(generator);
+            }
+
+            return;
+        }
+
+        if index == self.next {
+            let slot = detail::index_between(generator, 0, self.capacity - 1);
+            self.reservoir[slot] = item;
+
+            self.advance(generator);
+        }
+    }
+
+    /// Lowers the largest key and skips ahead to the first item with a smaller key.
+    fn advance<G: UniformRandomBitGenerator>(&mut self, generator: &mut G) {
+        let k = self.capacity as f64;
+
+        self.w *= (f64::generate_canonical_open(generator).get().ln() / k).exp();
+
+        // the skip is geometric with a success probability of `w`, counted from the item that was just pushed
+        let skip = f64::generate_canonical_open(generator).get().ln() / (-self.w).ln_1p();
+        self.next = if skip < u64::MAX as f64 { (self.seen - 1).saturating_add(skip as u64 + 1) } else { u64::MAX };
+    }
+}
+
+/// A uniform sample of `amount` items of `iter` by a `ReservoirSampler`, all of them if there are fewer.
+pub fn sample_reservoir<I: IntoIterator, G: UniformRandomBitGenerator>(iter: I, amount: usize, generator: &mut G) -> Vec<I::Item> {
+    if amount == 0 {
+        return Vec::new();
+    }
+
+    let mut sampler = ReservoirSampler::new(amount);
+
+    for item in iter {
+        sampler.push(item, generator);
+    }
+
+    sampler.into_reservoir()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::RandomNumberEngine;
+    use crate::rng::stats::chi_square;
+
+    #[test]
+    fn test_reservoir_sampler() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        assert!(sample_reservoir(0..10, 0, &mut generator).is_empty());
+        assert_eq!(sample_reservoir(0..3, 5, &mut generator), [0, 1, 2]);
+
+        let mut sampler = ReservoirSampler::new(4);
+        for i in 0..1000000 {
+            sampler.push(i, &mut generator);
+        }
+        assert_eq!((sampler.seen(), sampler.reservoir().len()), (1000000, 4));
+
+        // every item of the stream ends up in the reservoir with a probability of 3 / 20
+        let mut observed = vec![0usize; 20];
+        for _ in 0..20000 {
+            for i in sample_reservoir(0..20, 3, &mut generator) {
+                observed[i] += 1;
+            }
+        }
+
+        assert!(chi_square(&observed, &[3000f64; 20]).p_value() > 1e-4, "{:?}", observed);
+
+        // the same for a long stream, in coarse bins
+        let mut observed = vec![0usize; 10];
+        for _ in 0..2000 {
+            for i in sample_reservoir(0..100000, 5, &mut generator) {
+                observed[i / 10000] += 1;
+            }
+        }
+
+        assert!(chi_square(&observed, &[1000f64; 10]).p_value() > 1e-4, "{:?}", observed);
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+/// Puts `slice` in a uniformly random order by a Fisher–Yates shuffle.
+pub fn shuffle<T, G: UniformRandomBitGenerator>(slice: &mut [T], generator: &mut G) {
+    for i in (1..slice.len()).rev() {
+        slice.swap(i, detail::index_between(generator, 0, i));
+    }
+}
+
+/// Moves `amount` uniformly chosen elements of `slice`, in a uniformly random order, to its front and returns them
+/// together with the rest, whose order is unspecified.
+///
+/// This is the first `amount` steps of a Fisher–Yates shuffle, so it takes `amount` calls to the bounded integer
+/// primitive however long `slice` is; `amount` is clamped to the length of `slice`.
+pub fn partial_shuffle<'a, T, G: UniformRandomBitGenerator>(slice: &'a mut [T], amount: usize, generator: &mut G) -> (&'a mut [T], &'a mut [T]) {
+    let amount = std::cmp::min(amount, slice.len());
+
+    for i in 0..amount {
+        slice.swap(i, detail::index_between(generator, i, slice.len() - 1));
+    }
+
+    slice.split_at_mut(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::RandomNumberEngine;
+    use crate::rng::stats::chi_square;
+
+    /// The rank of a permutation of `0..n` in lexicographic order.
+    fn permutation_rank(permutation: &[usize]) -> usize {
+        let n = permutation.len();
+
+        (0..n).fold(0, |rank, i| {
+            let smaller = permutation[i + 1..].iter().filter(|&&v| v < permutation[i]).count();
+            rank * (n - i) + smaller
+        })
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        // all 24 orders of 4 elements are equally likely
+        let mut observed = vec![0usize; 24];
+        for _ in 0..48000 {
+            let mut values = [0, 1, 2, 3];
+            shuffle(&mut values, &mut generator);
+            observed[permutation_rank(&values)] += 1;
+        }
+
+        assert!(chi_square(&observed, &[2000f64; 24]).p_value() > 1e-4, "{:?}", observed);
+
+        let mut empty: [u8; 0] = [];
+        shuffle(&mut empty, &mut generator);
+
+        let mut values = (0..1000).collect::<Vec<_>>();
+        shuffle(&mut values, &mut generator);
+        assert_ne!(values, (0..1000).collect::<Vec<_>>());
+        values.sort();
+        assert_eq!(values, (0..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_partial_shuffle() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        // the ordered pairs drawn from 5 elements are equally likely
+        let mut observed = vec![0usize; 25];
+        for _ in 0..40000 {
+            let mut values = [0, 1, 2, 3, 4];
+            let (chosen, rest) = partial_shuffle(&mut values, 2, &mut generator);
+            assert_eq!((chosen.len(), rest.len()), (2, 3));
+            assert_ne!(chosen[0], chosen[1]);
+
+            observed[chosen[0] * 5 + chosen[1]] += 1;
+        }
+
+        let expected = (0..25).map(|i| if i / 5 == i % 5 { 0f64 } else { 2000f64 }).collect::<Vec<_>>();
+        assert!(chi_square(&observed, &expected).p_value() > 1e-4, "{:?}", observed);
+
+        let mut values = [0, 1, 2];
+        let (chosen, rest) = partial_shuffle(&mut values, 10, &mut generator);
+        assert_eq!((chosen.len(), rest.len()), (3, 0));
+    }
+}
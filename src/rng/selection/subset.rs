@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use super::*;
+
+/// `amount` distinct indices uniformly chosen from `0..length`, by Floyd's algorithm ("Programming Pearls: A Sample
+/// of Brilliance", 1987).
+///
+/// Takes `amount` calls to the bounded integer primitive and memory for `amount` indices however large `length` is.
+/// Every subset is equally likely but the order of the indices is not uniform, shuffle them if it matters.
+pub fn sample_indices<G: UniformRandomBitGenerator>(length: usize, amount: usize, generator: &mut G) -> Vec<usize> {
+    assert!(amount <= length, "cannot choose {} distinct indices out of {}", amount, length);
+
+    let mut chosen = HashSet::with_capacity(amount);
+    let mut indices = Vec::with_capacity(amount);
+
+    for j in length - amount..length {
+        let t = detail::index_between(generator, 0, j);
+
+        // `t` was chosen in an earlier round, which `j` never was
+        let index = if chosen.insert(t) { t } else { j };
+        chosen.insert(index);
+        indices.push(index);
+    }
+
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::RandomNumberEngine;
+    use crate::rng::stats::chi_square;
+
+    #[test]
+    fn test_sample_indices() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        assert!(sample_indices(10, 0, &mut generator).is_empty());
+
+        let mut all = sample_indices(10, 10, &mut generator);
+        all.sort();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+
+        let indices = sample_indices(usize::MAX, 100, &mut generator);
+        assert_eq!(indices.iter().collect::<HashSet<_>>().len(), 100);
+
+        // all 10 subsets of 2 out of 5 are equally likely
+        let mut observed = vec![0usize; 25];
+        for _ in 0..20000 {
+            let indices = sample_indices(5, 2, &mut generator);
+            let (a, b) = (std::cmp::min(indices[0], indices[1]), std::cmp::max(indices[0], indices[1]));
+            assert!(a < b && b < 5);
+
+            observed[a * 5 + b] += 1;
+        }
+
+        let expected = (0..25).map(|i| if i / 5 < i % 5 { 2000f64 } else { 0f64 }).collect::<Vec<_>>();
+        assert!(chi_square(&observed, &expected).p_value() > 1e-4, "{:?}", observed);
+    }
+}
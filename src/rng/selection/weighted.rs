@@ -0,0 +1,81 @@
+use crate::rng::distributions::GenerateCanonical;
+
+use super::*;
+
+/// `amount` distinct indices of `weights` chosen without replacement, each draw picking one of the remaining indices
+/// with a probability proportional to its weight, by the method of Efraimidis and Spirakis ("Weighted random sampling
+/// with a reservoir", 2006).
+///
+/// Every index gets the key `u^(1 / w)` for a uniform `u` and the `amount` largest keys win; the indices are returned
+/// by decreasing key, which is the order of the draws. Indices with a weight of zero are never chosen, so at least
+/// `amount` weights have to be positive.
+pub fn sample_weighted<G: UniformRandomBitGenerator>(weights: &[f64], amount: usize, generator: &mut G) -> Vec<usize> {
+    assert!(weights.iter().all(|&w| w >= 0f64 && w.is_finite()), "the weights must be finite and non-negative");
+    assert!(weights.iter().filter(|&&w| w > 0f64).count() >= amount, "cannot choose {} indices with a positive weight out of {:?}", amount, weights);
+
+    if amount == 0 {
+        return Vec::new();
+    }
+
+    // the logarithm of the key, which keeps small weights from underflowing to a key of 0
+    let mut keys = weights
+        .iter()
+        .enumerate()
+        .filter(|&(_, &w)| w > 0f64)
+        .map(|(i, &w)| (f64::generate_canonical_open(generator).get().ln() / w, i))
+        .collect::<Vec<_>>();
+
+    let by_key = |a: &(f64, usize), b: &(f64, usize)| b.0.partial_cmp(&a.0).unwrap();
+
+    if amount < keys.len() {
+        keys.select_nth_unstable_by(amount - 1, by_key);
+        keys.truncate(amount);
+    }
+
+    keys.sort_unstable_by(by_key);
+    keys.into_iter().map(|(_, i)| i).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::RandomNumberEngine;
+    use crate::rng::stats::chi_square;
+
+    #[test]
+    fn test_sample_weighted() {
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(17);
+
+        // choosing nothing takes nothing from the generator
+        let before = generator;
+        assert!(sample_weighted(&[1f64, 2f64], 0, &mut generator).is_empty());
+        assert!(generator == before);
+        assert_eq!(sample_weighted(&[0f64, 1f64, 0f64], 1, &mut generator), [1]);
+
+        let mut all = sample_weighted(&[1f64, 1e-300f64, 3f64], 3, &mut generator);
+        all.sort();
+        assert_eq!(all, [0, 1, 2]);
+
+        // the ordered pairs follow successive draws without replacement: P(i, j) = w_i / W * w_j / (W - w_i)
+        let weights = [1f64, 2f64, 3f64, 4f64];
+        let total = weights.iter().sum::<f64>();
+        let count = 100000;
+
+        let mut observed = vec![0usize; 16];
+        for _ in 0..count {
+            let indices = sample_weighted(&weights, 2, &mut generator);
+            observed[indices[0] * 4 + indices[1]] += 1;
+        }
+
+        let expected = (0..16)
+            .map(|k| {
+                let (i, j) = (k / 4, k % 4);
+                if i == j { 0f64 } else { weights[i] / total * weights[j] / (total - weights[i]) * count as f64 }
+            })
+            .collect::<Vec<_>>();
+
+        assert!(chi_square(&observed, &expected).p_value() > 1e-4, "{:?}", observed);
+    }
+}
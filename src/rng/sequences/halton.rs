@@ -0,0 +1,153 @@
+use crate::rng::engines::Xoroshiro128PP;
+use crate::rng::selection;
+use crate::rng::sequences::{detail as sequences_detail, SampleGenerator};
+use crate::rng::*;
+
+/// The number of prime bases, dimensions past it reuse the bases from the start with other permutations and shifts.
+pub const HALTON_DIMENSIONS: usize = 128;
+
+/// The Halton sequence: the radical inverses of the sample index in the first prime bases, one base per dimension.
+///
+/// Large bases are correlated with each other unless the digits are scrambled, so every base gets a random
+/// permutation of its digits (Braaten and Weller, "An Improved Low-Discrepancy Sequence for Multidimensional
+/// Quasi-Monte Carlo Integration", 1979). Pixels share the permutations but every dimension of every pixel is shifted
+/// by a random amount modulo 1 (a Cranley–Patterson rotation), which keeps the spacing of the points.
+#[derive(Clone)]
+pub struct HaltonSampleGenerator {
+    seed: u64,
+    bases: Vec<u64>,
+    permutations: Vec<Vec<u16>>,
+
+    pixel: u64,
+    index: u64,
+    dimension: u64,
+}
+
+impl HaltonSampleGenerator {
+    pub fn new(seed: u64) -> Self {
+        let bases = detail::primes(HALTON_DIMENSIONS);
+
+        let permutations = bases
+            .iter()
+            .enumerate()
+            .map(|(dimension, &base)| {
+                let mut generator = Xoroshiro128PP::new();
+                generator.seed_from_result(sequences_detail::hash(&[seed, dimension as u64]));
+
+                let mut permutation = (0..base as u16).collect::<Vec<_>>();
+                selection::shuffle(&mut permutation, &mut generator);
+
+                permutation
+            })
+            .collect();
+
+        Self { seed, bases, permutations, pixel: 0, index: 0, dimension: 0 }
+    }
+}
+
+impl SampleGenerator for HaltonSampleGenerator {
+    fn start_pixel_sample(&mut self, pixel: [u32; 2], index: u64) {
+        self.pixel = sequences_detail::pixel_key(pixel);
+        self.index = index;
+        self.dimension = 0;
+    }
+
+    fn next_fraction(&mut self) -> u64 {
+        let table = self.dimension as usize % HALTON_DIMENSIONS;
+        let x = detail::scrambled_radical_inverse(self.bases[table], &self.permutations[table], self.index);
+
+        let shift = sequences_detail::hash(&[self.seed, self.pixel, self.dimension]);
+        self.dimension += 1;
+
+        ((x * util::quick_exp2_f64(64)) as u64).wrapping_add(shift)
+    }
+}
+
+mod detail {
+    pub fn primes(count: usize) -> Vec<u64> {
+        let mut res: Vec<u64> = Vec::with_capacity(count);
+
+        for candidate in 2.. {
+            if res.len() == count {
+                break;
+            }
+
+            if res.iter().take_while(|&&p| p * p <= candidate).all(|&p| candidate % p != 0) {
+                res.push(candidate);
+            }
+        }
+
+        res
+    }
+
+    /// The digits of `index` in `base` mirrored around the radix point, each replaced by its image under
+    /// `permutation`.
+    ///
+    /// The infinitely many leading zeros of `index` become an infinite tail of `permutation[0]` digits, which adds up
+    /// to `permutation[0] / (base - 1)` units of the last digit.
+    pub fn scrambled_radical_inverse(base: u64, permutation: &[u16], mut index: u64) -> f64 {
+        let inv_base = 1f64 / base as f64;
+
+        let mut reversed = 0u64;
+        let mut inv_base_n = 1f64;
+
+        while index > 0 {
+            let next = index / base;
+            let digit = index - next * base;
+
+            reversed = reversed * base + permutation[digit as usize] as u64;
+            inv_base_n *= inv_base;
+            index = next;
+        }
+
+        let tail = permutation[0] as f64 / (base - 1) as f64;
+
+        (inv_base_n * (reversed as f64 + tail)).min(1f64.next_down())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radical_inverse() {
+        let identity = (0..5u16).collect::<Vec<_>>();
+
+        assert_eq!(detail::scrambled_radical_inverse(2, &[0, 1], 6), 0.375f64);
+        assert!((detail::scrambled_radical_inverse(5, &identity, 7) - 0.44f64).abs() < 1e-15);
+        // the permuted zeros of index 0 are 0.111..._2
+        assert_eq!(detail::scrambled_radical_inverse(2, &[1, 0], 0), 1f64.next_down());
+        assert_eq!(detail::scrambled_radical_inverse(2, &[1, 0], 1), 0.5f64);
+
+        assert_eq!(&detail::primes(8), &[2, 3, 5, 7, 11, 13, 17, 19]);
+    }
+
+    /// The largest distance between circularly neighbouring points, which is below `2 / n` if the points are spread
+    /// over `n` strata of any rotation of the unit interval.
+    fn largest_gap(mut points: Vec<f64>) -> f64 {
+        points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let wrap = points[0] + 1f64 - points.last().unwrap();
+        points.windows(2).map(|w| w[1] - w[0]).fold(wrap, f64::max)
+    }
+
+    #[test]
+    fn test_halton_stratification() {
+        let mut samples = HaltonSampleGenerator::new(0xDEADBEEF);
+
+        for (dimension, n) in [(0usize, 1024u64), (1, 729), (2, 625), (5, 169), (HALTON_DIMENSIONS + 1, 729)] {
+            let points = (0..n)
+                .map(|index| {
+                    samples.start_pixel_sample([17, 4], index);
+                    (0..dimension).for_each(|_| {
+                        samples.next_1d::<f64>();
+                    });
+                    samples.next_1d::<f64>()
+                })
+                .collect();
+
+            assert!(largest_gap(points) < 2f64 / n as f64, "dimension {} is not stratified", dimension);
+        }
+    }
+}
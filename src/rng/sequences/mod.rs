@@ -0,0 +1,124 @@
+pub mod halton;
+pub mod r2;
+pub mod sobol;
+
+pub use halton::*;
+pub use r2::*;
+pub use sobol::*;
+
+use crate::rng::engines::Xoroshiro128PP;
+use crate::rng::*;
+use crate::*;
+
+/// Points in `[0, 1)^d` for the samples of each pixel, handed out one dimension at a time.
+///
+/// Every sample of a pixel is a point of its own: `start_pixel_sample` rewinds to its first dimension, and the
+/// dimensions taken afterwards are the coordinates of the point in order. The same pixel, index and dimension always
+/// give the same value, regardless of which other samples were generated before.
+pub trait SampleGenerator {
+    fn start_pixel_sample(&mut self, pixel: [u32; 2], index: u64);
+
+    /// The next coordinate of the current point as a fraction of `2^64`.
+    fn next_fraction(&mut self) -> u64;
+
+    fn next_1d<T: FloatingPoint>(&mut self) -> T { detail::canonical_from_fraction(self.next_fraction()) }
+
+    fn next_2d<T: FloatingPoint>(&mut self) -> [T; 2] {
+        let u = self.next_1d();
+        [u, self.next_1d()]
+    }
+
+    fn fill<T: FloatingPoint>(&mut self, out: &mut [T]) {
+        for v in out {
+            *v = self.next_1d();
+        }
+    }
+}
+
+/// Uniformly random points, the baseline the low discrepancy sequences are measured against.
+///
+/// Each sample seeds a generator of its own from the seed, the pixel and the index.
+#[derive(Clone)]
+pub struct IndependentSampleGenerator {
+    seed: u64,
+    generator: Xoroshiro128PP,
+}
+
+impl IndependentSampleGenerator {
+    pub fn new(seed: u64) -> Self {
+        let mut res = Self { seed, generator: Xoroshiro128PP::new() };
+        res.reseed([0, 0], 0);
+        res
+    }
+
+    fn reseed(&mut self, pixel: [u32; 2], index: u64) {
+        self.generator.seed_from_result(detail::hash(&[self.seed, detail::pixel_key(pixel), index]));
+    }
+}
+
+impl SampleGenerator for IndependentSampleGenerator {
+    fn start_pixel_sample(&mut self, pixel: [u32; 2], index: u64) { self.reseed(pixel, index); }
+
+    fn next_fraction(&mut self) -> u64 { self.generator.generate() }
+}
+
+mod detail {
+    use crate::rng::util;
+    use crate::*;
+
+    /// The finaliser of SplitMix64, a bijection with good avalanche behaviour.
+    pub fn mix(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn hash(values: &[u64]) -> u64 { values.iter().fold(0x9e3779b97f4a7c15, |h, &v| mix(h ^ mix(v.wrapping_add(0x9e3779b97f4a7c15)))) }
+
+    pub fn pixel_key(pixel: [u32; 2]) -> u64 { (pixel[0] as u64) << 32 | pixel[1] as u64 }
+
+    /// The float just below the fixed point fraction `bits / 2^64`, which is in `[0, 1)` even after rounding.
+    pub fn canonical_from_fraction<T: FloatingPoint>(bits: u64) -> T {
+        let v = T::as_from((bits >> 11) as f64 * util::quick_exp2_f64(-53));
+
+        if v < T::one() {
+            v
+        } else {
+            T::one().next_down()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_independent_is_deterministic() {
+        let mut samples = IndependentSampleGenerator::new(1234);
+
+        let mut point = |pixel: [u32; 2], index: u64| -> [f64; 4] {
+            samples.start_pixel_sample(pixel, index);
+            let mut point = [0f64; 4];
+            samples.fill(&mut point);
+            point
+        };
+
+        let a = point([3, 4], 7);
+        let b = point([4, 3], 7);
+        let c = point([3, 4], 8);
+
+        assert_eq!(a, point([3, 4], 7));
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+        assert!(a.iter().all(|v| (0f64..1f64).contains(v)));
+    }
+
+    #[test]
+    fn test_canonical_from_fraction() {
+        assert_eq!(detail::canonical_from_fraction::<f64>(0), 0f64);
+        assert_eq!(detail::canonical_from_fraction::<f64>(1 << 63), 0.5f64);
+        assert_eq!(detail::canonical_from_fraction::<f64>(u64::MAX), 1f64.next_down());
+        assert_eq!(detail::canonical_from_fraction::<f32>(u64::MAX), 1f32.next_down());
+    }
+}
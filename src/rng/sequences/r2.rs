@@ -0,0 +1,82 @@
+use crate::rng::sequences::{detail as sequences_detail, SampleGenerator};
+
+/// Roberts' R2 sequence ("The Unreasonable Effectiveness of Quasirandom Sequences", 2018), the additive recurrence
+/// `x_n = x_0 + n (1 / g, 1 / g^2) mod 1` where `g` is the plastic number, the real root of `x^3 = x + 1`.
+///
+/// Dimensions are handed out in pairs, each pair of each pixel starting at a random `x_0`. There is no number of
+/// samples the sequence is tuned for, every prefix is about as evenly spread.
+#[derive(Copy, Clone, Debug)]
+pub struct R2SampleGenerator {
+    seed: u64,
+
+    pixel: u64,
+    index: u64,
+    dimension: u64,
+}
+
+impl R2SampleGenerator {
+    pub fn new(seed: u64) -> Self { Self { seed, pixel: 0, index: 0, dimension: 0 } }
+}
+
+impl SampleGenerator for R2SampleGenerator {
+    fn start_pixel_sample(&mut self, pixel: [u32; 2], index: u64) {
+        self.pixel = sequences_detail::pixel_key(pixel);
+        self.index = index;
+        self.dimension = 0;
+    }
+
+    fn next_fraction(&mut self) -> u64 {
+        // 1 / g and 1 / g^2 as fractions of 2^64, the recurrence is exact in fixed point
+        const ALPHA: [u64; 2] = [0xc13fa9a902a6328f, 0x91e10da5c79e7b1c];
+
+        let start = sequences_detail::hash(&[self.seed, self.pixel, self.dimension]);
+        let bits = start.wrapping_add(self.index.wrapping_mul(ALPHA[self.dimension as usize % 2]));
+        self.dimension += 1;
+
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_r2_spacing() {
+        let mut samples = R2SampleGenerator::new(42);
+
+        // the three gap theorem bounds the gaps of a Kronecker sequence by a multiple of 1 / n for badly approximable
+        // rotations like these
+        for n in [100u64, 1000u64, 4321u64] {
+            let mut points = [Vec::new(), Vec::new(), Vec::new()];
+
+            for index in 0..n {
+                samples.start_pixel_sample([1, 2], index);
+
+                for dimension in &mut points {
+                    dimension.push(samples.next_1d::<f64>());
+                }
+            }
+
+            for mut dimension in points {
+                dimension.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                let wrap = dimension[0] + 1f64 - dimension.last().unwrap();
+                let largest = dimension.windows(2).map(|w| w[1] - w[0]).fold(wrap, f64::max);
+
+                assert!(largest < 3f64 / n as f64, "a gap of {} between {} points", largest, n);
+            }
+        }
+
+        // the pairs are spread over the square, every cell of a 10 x 10 grid holds 10 of 1000 points give or take
+        let mut cells = [0usize; 100];
+
+        for index in 0..1000 {
+            samples.start_pixel_sample([1, 2], index);
+            let [u, v] = samples.next_2d::<f64>();
+            cells[(u * 10f64) as usize * 10 + (v * 10f64) as usize] += 1;
+        }
+
+        assert!(cells.iter().all(|&c| (6..=14).contains(&c)), "{:?}", cells);
+    }
+}
@@ -0,0 +1,271 @@
+use std::sync::OnceLock;
+
+use crate::rng::sequences::{detail as sequences_detail, SampleGenerator};
+
+/// The number of dimensions with direction numbers, dimensions past it reuse the matrices from the start with other
+/// scrambles.
+pub const SOBOL_DIMENSIONS: usize = 53;
+
+/// How the points of a `SobolSampleGenerator` are randomised per pixel and dimension.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SobolScrambling {
+    /// The plain sequence, the same points for every pixel.
+    None,
+    /// A random XOR of the bits, which flips the same halves at every level of the binary subdivision.
+    RandomDigit,
+    /// Nested uniform scrambling (Owen, "Randomly Permuted (t,m,s)-Nets and (t,s)-Sequences", 1995), which flips the
+    /// halves of every interval of the binary subdivision at random, decided by hashing the bits above it.
+    Owen,
+}
+
+/// The Sobol sequence with the direction numbers of Joe and Kuo ("Constructing Sobol Sequences with Better
+/// Two-Dimensional Projections", 2008), 32 bits deep.
+///
+/// The first `2^m` points of every dimension are stratified into `2^m` intervals and the first two dimensions form
+/// a (0, m, 2)-net; the scrambles keep both properties.
+#[derive(Copy, Clone, Debug)]
+pub struct SobolSampleGenerator {
+    seed: u64,
+    scrambling: SobolScrambling,
+
+    pixel: u64,
+    index: u64,
+    dimension: u64,
+}
+
+impl SobolSampleGenerator {
+    pub fn new(seed: u64, scrambling: SobolScrambling) -> Self { Self { seed, scrambling, pixel: 0, index: 0, dimension: 0 } }
+
+    pub fn scrambling(&self) -> SobolScrambling { self.scrambling }
+}
+
+impl SampleGenerator for SobolSampleGenerator {
+    fn start_pixel_sample(&mut self, pixel: [u32; 2], index: u64) {
+        self.pixel = sequences_detail::pixel_key(pixel);
+        self.index = index;
+        self.dimension = 0;
+    }
+
+    fn next_fraction(&mut self) -> u64 {
+        let matrix = &detail::matrices()[self.dimension as usize % SOBOL_DIMENSIONS];
+        let bits = detail::sample(matrix, self.index);
+
+        let seed = sequences_detail::hash(&[self.seed, self.pixel, self.dimension]);
+        self.dimension += 1;
+
+        let bits = match self.scrambling {
+            SobolScrambling::None => bits,
+            SobolScrambling::RandomDigit => bits ^ seed as u32,
+            SobolScrambling::Owen => detail::owen_scramble(bits, seed),
+        };
+
+        (bits as u64) << 32
+    }
+}
+
+mod detail {
+    use super::*;
+
+    /// Degree `s`, the inner coefficients `a` of the primitive polynomial and the initial direction numbers `m` of
+    /// every dimension but the first, from the `new-joe-kuo-6.21201` table.
+    #[rustfmt::skip]
+    const JOE_KUO: [(u32, u32, &[u32]); SOBOL_DIMENSIONS - 1] = [
+        (1, 0, &[1]),
+        (2, 1, &[1, 3]),
+        (3, 1, &[1, 3, 1]),
+        (3, 2, &[1, 1, 1]),
+        (4, 1, &[1, 1, 3, 3]),
+        (4, 4, &[1, 3, 5, 13]),
+        (5, 2, &[1, 1, 5, 5, 17]),
+        (5, 4, &[1, 1, 5, 5, 5]),
+        (5, 7, &[1, 1, 7, 11, 19]),
+        (5, 11, &[1, 1, 5, 1, 1]),
+        (5, 13, &[1, 1, 1, 3, 11]),
+        (5, 14, &[1, 3, 5, 5, 31]),
+        (6, 1, &[1, 3, 3, 9, 7, 49]),
+        (6, 13, &[1, 1, 1, 15, 21, 21]),
+        (6, 16, &[1, 3, 1, 13, 27, 49]),
+        (6, 19, &[1, 1, 1, 15, 7, 5]),
+        (6, 22, &[1, 3, 1, 15, 13, 25]),
+        (6, 25, &[1, 1, 5, 5, 19, 61]),
+        (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+        (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+        (7, 7, &[1, 1, 3, 13, 7, 35, 63]),
+        (7, 8, &[1, 3, 5, 9, 1, 25, 53]),
+        (7, 14, &[1, 3, 1, 13, 9, 35, 107]),
+        (7, 19, &[1, 3, 1, 5, 27, 61, 31]),
+        (7, 21, &[1, 1, 5, 11, 19, 41, 61]),
+        (7, 28, &[1, 3, 5, 3, 3, 13, 69]),
+        (7, 31, &[1, 1, 7, 13, 1, 19, 1]),
+        (7, 32, &[1, 3, 7, 5, 13, 19, 59]),
+        (7, 37, &[1, 1, 3, 9, 25, 29, 41]),
+        (7, 41, &[1, 3, 5, 13, 23, 1, 55]),
+        (7, 42, &[1, 3, 7, 3, 13, 59, 17]),
+        (7, 50, &[1, 3, 1, 3, 5, 53, 69]),
+        (7, 55, &[1, 1, 5, 5, 23, 33, 13]),
+        (7, 56, &[1, 1, 7, 7, 1, 61, 123]),
+        (7, 59, &[1, 1, 7, 9, 13, 61, 49]),
+        (7, 62, &[1, 3, 3, 5, 3, 55, 33]),
+        (8, 14, &[1, 3, 1, 15, 31, 13, 49, 245]),
+        (8, 21, &[1, 3, 5, 15, 31, 59, 63, 97]),
+        (8, 22, &[1, 3, 1, 11, 11, 11, 77, 249]),
+        (8, 38, &[1, 3, 1, 11, 27, 43, 71, 9]),
+        (8, 47, &[1, 1, 7, 15, 21, 11, 81, 45]),
+        (8, 49, &[1, 3, 7, 3, 25, 31, 65, 79]),
+        (8, 50, &[1, 3, 1, 1, 19, 11, 3, 205]),
+        (8, 52, &[1, 1, 5, 9, 19, 21, 29, 157]),
+        (8, 56, &[1, 3, 7, 11, 1, 33, 89, 185]),
+        (8, 67, &[1, 3, 3, 3, 15, 9, 79, 71]),
+        (8, 70, &[1, 3, 7, 11, 15, 39, 119, 27]),
+        (8, 84, &[1, 1, 3, 1, 11, 31, 97, 225]),
+        (8, 97, &[1, 1, 1, 3, 23, 43, 57, 177]),
+        (8, 103, &[1, 3, 7, 7, 17, 17, 37, 71]),
+        (8, 115, &[1, 3, 1, 5, 27, 63, 123, 213]),
+        (8, 122, &[1, 1, 3, 5, 11, 43, 53, 133]),
+    ];
+
+    /// The columns of the generator matrices, the first dimension is the van der Corput sequence.
+    pub fn matrices() -> &'static [[u32; 32]] {
+        static MATRICES: OnceLock<Vec<[u32; 32]>> = OnceLock::new();
+
+        MATRICES.get_or_init(|| {
+            let van_der_corput = std::array::from_fn(|i| 1u32 << (31 - i));
+
+            std::iter::once(van_der_corput).chain(JOE_KUO.iter().map(|&(s, a, m)| direction_numbers(s, a, m))).collect()
+        })
+    }
+
+    /// The recurrence of Bratley and Fox ("Algorithm 659: Implementing Sobol's Quasirandom Sequence Generator", 1988).
+    pub fn direction_numbers(s: u32, a: u32, m: &[u32]) -> [u32; 32] {
+        let s = s as usize;
+        let mut v = [0u32; 32];
+
+        for i in 0..s {
+            v[i] = m[i] << (31 - i);
+        }
+
+        for i in s..32 {
+            v[i] = v[i - s] ^ (v[i - s] >> s);
+
+            for k in 1..s {
+                if (a >> (s - 1 - k)) & 1 != 0 {
+                    v[i] ^= v[i - k];
+                }
+            }
+        }
+
+        v
+    }
+
+    /// The product of the matrix with the bits of the index; only the lowest 32 bits of the index matter.
+    pub fn sample(matrix: &[u32; 32], index: u64) -> u32 {
+        let mut res = 0u32;
+        let mut index = index as u32;
+        let mut column = 0;
+
+        while index != 0 {
+            if index & 1 != 0 {
+                res ^= matrix[column];
+            }
+
+            index >>= 1;
+            column += 1;
+        }
+
+        res
+    }
+
+    /// Flips every bit depending on a hash of the bits above it, after pbrt's `OwenScrambler`.
+    pub fn owen_scramble(mut v: u32, seed: u64) -> u32 {
+        if seed & 1 != 0 {
+            v ^= 1 << 31;
+        }
+
+        for b in 1..32 {
+            let prefix = v & (u32::MAX << (32 - b));
+
+            if (sequences_detail::mix(prefix as u64 ^ seed) >> b) & 1 != 0 {
+                v ^= 1 << (31 - b);
+            }
+        }
+
+        v
+    }
+
+    #[cfg(test)]
+    pub fn joe_kuo() -> &'static [(u32, u32, &'static [u32])] { &JOE_KUO }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(samples: &mut SobolSampleGenerator, pixel: [u32; 2], n: u64, dimensions: usize) -> Vec<Vec<f64>> {
+        (0..n)
+            .map(|index| {
+                samples.start_pixel_sample(pixel, index);
+                (0..dimensions).map(|_| samples.next_1d::<f64>()).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_joe_kuo_polynomials() {
+        // x^s + a_1 x^(s-1) + ... + 1 has to be primitive, i.e. x has an order of 2^s - 1 modulo the polynomial
+        for &(s, a, m) in detail::joe_kuo() {
+            let polynomial = (1u32 << s) | (a << 1) | 1;
+            let order = (1u32 << s) - 1;
+
+            let mut x = 1u32;
+            for i in 1..=order {
+                x <<= 1;
+                if x >> s != 0 {
+                    x ^= polynomial;
+                }
+
+                assert_eq!(x == 1, i == order, "the polynomial of degree {} with a = {} is not primitive", s, a);
+            }
+
+            assert_eq!(m.len(), s as usize);
+            assert!(m.iter().enumerate().all(|(i, &m)| m % 2 == 1 && m < 2 << i));
+        }
+    }
+
+    #[test]
+    fn test_sobol_first_points() {
+        let mut samples = SobolSampleGenerator::new(0, SobolScrambling::None);
+
+        let points = points(&mut samples, [0, 0], 4, 3);
+        assert_eq!(points, vec![vec![0f64, 0f64, 0f64], vec![0.5f64, 0.5f64, 0.5f64], vec![0.25f64, 0.75f64, 0.75f64], vec![0.75f64, 0.25f64, 0.25f64]]);
+
+        // the plain sequence is the same for every pixel, a scrambled one is not
+        assert_eq!(points, self::points(&mut samples, [5, 9], 4, 3));
+
+        let mut samples = SobolSampleGenerator::new(0, SobolScrambling::Owen);
+        assert_ne!(self::points(&mut samples, [0, 0], 4, 3), self::points(&mut samples, [5, 9], 4, 3));
+    }
+
+    #[test]
+    fn test_sobol_nets() {
+        for scrambling in [SobolScrambling::None, SobolScrambling::RandomDigit, SobolScrambling::Owen] {
+            let mut samples = SobolSampleGenerator::new(0xDEADBEEF, scrambling);
+            let points = points(&mut samples, [3, 1], 256, SOBOL_DIMENSIONS + 3);
+
+            // every dimension is a (0, 8, 1)-net
+            for dimension in 0..SOBOL_DIMENSIONS + 3 {
+                let mut strata = points.iter().map(|p| (p[dimension] * 256f64) as usize).collect::<Vec<_>>();
+                strata.sort();
+                assert_eq!(strata, (0..256).collect::<Vec<_>>(), "dimension {} with {:?}", dimension, scrambling);
+            }
+
+            // the first two dimensions are a (0, 8, 2)-net, every elementary interval of area 1/256 holds a point
+            for log2_width in 0..=8 {
+                let (columns, rows) = (1 << log2_width, 1 << (8 - log2_width));
+
+                let mut cells = points.iter().map(|p| (p[0] * columns as f64) as usize * rows + (p[1] * rows as f64) as usize).collect::<Vec<_>>();
+                cells.sort();
+                assert_eq!(cells, (0..256).collect::<Vec<_>>(), "{}x{} with {:?}", columns, rows, scrambling);
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use super::*;
+
+/// Pearson's chi-square test of the counts `observed` against the counts `expected` in the same bins.
+///
+/// Neighbouring bins are merged until each expects at least 5 samples, what is left over goes to the last bin; the
+/// p-value is the upper tail of the chi-square distribution with one degree of freedom less than there are bins.
+pub fn chi_square(observed: &[usize], expected: &[f64]) -> TestResult {
+    assert_eq!(observed.len(), expected.len(), "every bin needs an observed and an expected count");
+
+    let mut bins: Vec<(f64, usize)> = Vec::new();
+    let mut current = (0f64, 0usize);
+
+    for (&e, &o) in expected.iter().zip(observed.iter()) {
+        current = (current.0 + e, current.1 + o);
+
+        if current.0 >= 5f64 {
+            bins.push(current);
+            current = (0f64, 0usize);
+        }
+    }
+
+    match bins.last_mut() {
+        Some(last) => *last = (last.0 + current.0, last.1 + current.1),
+        None => bins.push(current),
+    }
+
+    if bins.len() < 2 {
+        return TestResult::new(0f64, 1f64);
+    }
+
+    let statistic = bins.iter().map(|&(e, o)| (o as f64 - e).powi(2) / e).sum::<f64>();
+    let degrees_of_freedom = (bins.len() - 1) as f64;
+
+    TestResult::new(statistic, regularized_gamma_q(degrees_of_freedom / 2f64, statistic / 2f64))
+}
+
+/// The chi-square test of `count` values of `generate_fn` in `0..` against `pmf`, where the values from
+/// `support_end` on share a bin.
+pub fn chi_square_discrete<G: FnMut() -> u64, Pmf: Fn(u64) -> f64>(generate_fn: &mut G, pmf: Pmf, count: usize, support_end: u64) -> TestResult {
+    let mut observed = vec![0usize; support_end as usize + 1];
+
+    for _ in 0..count {
+        observed[std::cmp::min(generate_fn(), support_end) as usize] += 1;
+    }
+
+    let mut expected = (0..support_end).map(|k| pmf(k) * count as f64).collect::<Vec<_>>();
+    expected.push((count as f64 - expected.iter().sum::<f64>()).max(0f64));
+
+    chi_square(&observed, &expected)
+}
+
+/// The Kolmogorov–Smirnov test of `samples` against the continuous `cdf`, by the largest distance between the
+/// empirical cdf and `cdf`; `samples` are sorted in place.
+///
+/// The p-value is that of the limiting Kolmogorov distribution with the correction of Stephens for finite counts.
+pub fn kolmogorov_smirnov<Cdf: Fn(f64) -> f64>(samples: &mut [f64], cdf: Cdf) -> TestResult {
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = samples.len() as f64;
+    let statistic = samples
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let f = cdf(x);
+            (f - i as f64 / n).max((i + 1) as f64 / n - f)
+        })
+        .fold(0f64, f64::max);
+
+    let root = n.sqrt();
+    let lambda = (root + 0.12f64 + 0.11f64 / root) * statistic;
+
+    TestResult::new(statistic, detail::kolmogorov_tail(lambda))
+}
+
+/// The Anderson–Darling test of `samples` against the continuous `cdf`, which weighs the tails more than the
+/// Kolmogorov–Smirnov test; `samples` are sorted in place.
+///
+/// The p-value is that of Marsaglia and Marsaglia ("Evaluating the Anderson-Darling Distribution", 2004) for a
+/// completely specified `cdf`.
+pub fn anderson_darling<Cdf: Fn(f64) -> f64>(samples: &mut [f64], cdf: Cdf) -> TestResult {
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let count = samples.len();
+    let n = count as f64;
+
+    // the logarithms of F and 1 - F, kept away from the ends of the support
+    let limit = 1e-300f64;
+    let sum = (0..count)
+        .map(|i| {
+            let lower = cdf(samples[i]).max(limit).ln();
+            let upper = (1f64 - cdf(samples[count - 1 - i])).max(limit).ln();
+
+            (2 * i + 1) as f64 * (lower + upper)
+        })
+        .sum::<f64>();
+
+    let statistic = -n - sum / n;
+
+    TestResult::new(statistic, 1f64 - detail::anderson_darling_cdf(count, statistic))
+}
+
+mod detail {
+    /// `P(K > lambda)` for the Kolmogorov distribution `K`.
+    pub fn kolmogorov_tail(lambda: f64) -> f64 {
+        // the alternating series does not converge in time below, where the tail is 1 to double precision
+        if lambda < 0.2f64 {
+            return 1f64;
+        }
+
+        let mut sum = 0f64;
+        let mut sign = 1f64;
+
+        for k in 1..=100 {
+            let term = (-2f64 * (k * k) as f64 * lambda * lambda).exp();
+            sum += sign * term;
+            sign = -sign;
+
+            if term < 1e-16 * sum.abs() {
+                break;
+            }
+        }
+
+        (2f64 * sum).clamp(0f64, 1f64)
+    }
+
+    /// The cdf of the Anderson–Darling statistic of `n` samples, the limiting distribution with a correction.
+    pub fn anderson_darling_cdf(n: usize, z: f64) -> f64 {
+        let x = anderson_darling_limit(z);
+
+        (x + anderson_darling_error(n as f64, x)).clamp(0f64, 1f64)
+    }
+
+    fn anderson_darling_limit(z: f64) -> f64 {
+        if z <= 0f64 {
+            return 0f64;
+        }
+
+        if z < 2f64 {
+            (-1.2337141f64 / z).exp() / z.sqrt() * (2.00012f64 + (0.247105f64 - (0.0649821f64 - (0.0347962f64 - (0.011672f64 - 0.00168691f64 * z) * z) * z) * z) * z)
+        } else {
+            (-(1.0776f64 - (2.30695f64 - (0.43424f64 - (0.082433f64 - (0.008056f64 - 0.0003146f64 * z) * z) * z) * z) * z).exp()).exp()
+        }
+    }
+
+    fn anderson_darling_error(n: f64, x: f64) -> f64 {
+        if x > 0.8f64 {
+            return (-130.2137f64 + (745.2337f64 - (1705.091f64 - (1950.646f64 - (1116.360f64 - 255.7844f64 * x) * x) * x) * x) * x) / n;
+        }
+
+        let c = 0.01265f64 + 0.1757f64 / n;
+
+        if x < c {
+            let t = x / c;
+            let t = t.sqrt() * (1f64 - t) * (49f64 * t - 102f64);
+
+            return t * (0.0037f64 / (n * n) + 0.00078f64 / n + 0.00006f64) / n;
+        }
+
+        let t = (x - c) / (0.8f64 - c);
+        let t = -0.00022633f64 + (6.54034f64 - (14.6538f64 - (14.458f64 - (8.259f64 - 1.91864f64 * t) * t) * t) * t) * t;
+
+        t * (0.04213f64 / n + 0.01365f64 / (n * n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_critical_values() {
+        // the 95% quantiles of the limiting distributions
+        assert!((detail::kolmogorov_tail(1.3581f64) - 0.05f64).abs() < 1e-5);
+        assert!((detail::anderson_darling_cdf(1000000, 2.4924f64) - 0.95f64).abs() < 1e-4);
+        assert!((detail::anderson_darling_cdf(1000000, 3.878f64) - 0.99f64).abs() < 1e-4);
+
+        // a perfect fit, and one that is off by half a bin everywhere
+        let result = chi_square(&[10, 20, 30], &[10f64, 20f64, 30f64]);
+        assert_eq!((result.statistic(), result.p_value()), (0f64, 1f64));
+
+        let result = chi_square(&[100, 0, 100, 0], &[50f64; 4]);
+        assert_eq!(result.statistic(), 200f64);
+        assert!(result.p_value() < 1e-40);
+    }
+
+    #[test]
+    fn test_empirical_cdf_statistics() {
+        // evenly spread points fit the uniform distribution better than chance, points in a half do not fit at all
+        let mut even = (0..1000).map(|i| (i as f64 + 0.5f64) / 1000f64).collect::<Vec<_>>();
+        assert!(kolmogorov_smirnov(&mut even, |x| x).p_value() > 0.999f64);
+        assert!(anderson_darling(&mut even, |x| x).p_value() > 0.999f64);
+
+        let mut half = even.iter().map(|x| x / 2f64).collect::<Vec<_>>();
+        assert!(kolmogorov_smirnov(&mut half, |x| x).p_value() < 1e-12);
+        assert!(anderson_darling(&mut half, |x| x).p_value() < 1e-6);
+
+        assert!((kolmogorov_smirnov(&mut half, |x| x).statistic() - 0.50025f64).abs() < 1e-12);
+    }
+}
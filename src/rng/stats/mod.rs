@@ -0,0 +1,27 @@
+mod goodness_of_fit;
+mod serial;
+mod special;
+mod spherical;
+
+pub use goodness_of_fit::*;
+pub use serial::*;
+pub use special::*;
+pub use spherical::*;
+
+/// The statistic of a test of a hypothesis and its p-value, the probability of a statistic at least as extreme if
+/// the hypothesis holds; small p-values reject the hypothesis.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TestResult {
+    statistic: f64,
+    p_value: f64,
+}
+
+impl TestResult {
+    pub fn new(statistic: f64, p_value: f64) -> Self { Self { statistic, p_value } }
+
+    pub fn statistic(&self) -> f64 { self.statistic }
+    pub fn p_value(&self) -> f64 { self.p_value }
+
+    /// Whether the hypothesis is rejected at the `significance` level.
+    pub fn rejects(&self, significance: f64) -> bool { self.p_value < significance }
+}
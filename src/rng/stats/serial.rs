@@ -0,0 +1,56 @@
+use super::*;
+
+/// The test of the circular serial correlation coefficient of `samples` at `lag` against independent values (Knuth,
+/// TAOCP vol. 2, 3.3.2 K), `sum (x_i - mean)(x_(i+lag) - mean) / sum (x_i - mean)^2` with the indices wrapping
+/// around.
+///
+/// Without correlation the coefficient is about normal with the mean `-1/(n-1)` and the variance
+/// `n^2 / ((n-1)^2 (n-2))`; the p-value is two-sided, the statistic is the coefficient.
+pub fn serial_correlation(samples: &[f64], lag: usize) -> TestResult {
+    let count = samples.len();
+    assert!(count > 2, "the correlation needs at least 3 samples");
+    assert!(0 < lag && lag < count, "the lag must be positive and below the count");
+
+    let n = count as f64;
+    let sum = samples.iter().sum::<f64>();
+    let squares = samples.iter().map(|x| x * x).sum::<f64>();
+    let products = (0..count).map(|i| samples[i] * samples[(i + lag) % count]).sum::<f64>();
+
+    let denominator = n * squares - sum * sum;
+    if denominator <= 0f64 {
+        // constant samples are perfectly correlated
+        return TestResult::new(1f64, 0f64);
+    }
+
+    let coefficient = (n * products - sum * sum) / denominator;
+
+    let mean = -1f64 / (n - 1f64);
+    let deviation = n / ((n - 1f64) * (n - 2f64).sqrt());
+    let z = (coefficient - mean) / deviation;
+
+    TestResult::new(coefficient, 2f64 * standard_normal_cdf(-z.abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serial_correlation() {
+        // a sawtooth follows itself closely and alternates at half its period
+        let sawtooth = (0..1000).map(|i| (i % 10) as f64).collect::<Vec<_>>();
+
+        let result = serial_correlation(&sawtooth, 1);
+        assert!(result.statistic() > 0.4f64 && result.p_value() < 1e-12);
+
+        let result = serial_correlation(&sawtooth, 10);
+        assert!((result.statistic() - 1f64).abs() < 1e-12);
+
+        let result = serial_correlation(&sawtooth, 5);
+        assert!(result.statistic() < -0.4f64 && result.p_value() < 1e-12);
+
+        // a permutation that scatters evenly spread values does not
+        let scattered = (0..1000u64).map(|i| ((i * 7919) % 1000) as f64 / 1000f64).collect::<Vec<_>>();
+        assert!(serial_correlation(&scattered, 3).statistic().abs() < 0.2f64);
+    }
+}
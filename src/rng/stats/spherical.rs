@@ -0,0 +1,117 @@
+use crate::rng::distributions::sphere::NDSampler;
+use crate::rng::UniformRandomBitGenerator;
+
+use super::*;
+
+/// The chi-square test of `count` directions in the plane from `sampler` against its `pdf` per unit angle, in `bins`
+/// bins of the angle; the expected counts integrate the density over each bin.
+pub fn circular_chi_square<Sampler, G>(sampler: &mut Sampler, generator: &mut G, count: usize, bins: usize) -> TestResult
+where
+    Sampler: NDSampler<f64, 2>,
+    G: UniformRandomBitGenerator,
+{
+    let width = 2f64 * std::f64::consts::PI / bins as f64;
+
+    let expected = (0..bins)
+        .map(|bin| {
+            let probability = (0..detail::STEPS)
+                .map(|k| {
+                    let φ = (bin as f64 + (k as f64 + 0.5f64) / detail::STEPS as f64) * width;
+                    sampler.pdf(&[φ.cos(), φ.sin()])
+                })
+                .sum::<f64>()
+                * width
+                / detail::STEPS as f64;
+
+            probability * count as f64
+        })
+        .collect::<Vec<_>>();
+
+    let mut observed = vec![0usize; bins];
+
+    for _ in 0..count {
+        let (direction, _) = sampler.sample(generator);
+        observed[detail::bin_of(direction[1].atan2(direction[0]).rem_euclid(2f64 * std::f64::consts::PI), width, bins)] += 1;
+    }
+
+    chi_square(&observed, &expected)
+}
+
+/// The chi-square test of `count` directions in space from `sampler` against its `pdf` per unit solid angle, in
+/// `resolution` bins of the polar angle times `2 resolution` bins of the azimuth; the expected counts integrate the
+/// density over each bin.
+pub fn spherical_chi_square<Sampler, G>(sampler: &mut Sampler, generator: &mut G, count: usize, resolution: usize) -> TestResult
+where
+    Sampler: NDSampler<f64, 3>,
+    G: UniformRandomBitGenerator,
+{
+    let (bins_θ, bins_φ) = (resolution, 2 * resolution);
+    let (d_θ, d_φ) = (std::f64::consts::PI / bins_θ as f64, 2f64 * std::f64::consts::PI / bins_φ as f64);
+
+    let expected = (0..bins_θ * bins_φ)
+        .map(|bin| {
+            let (i, j) = (bin / bins_φ, bin % bins_φ);
+            let mut probability = 0f64;
+
+            for k in 0..detail::STEPS * detail::STEPS {
+                let θ = (i as f64 + ((k / detail::STEPS) as f64 + 0.5f64) / detail::STEPS as f64) * d_θ;
+                let φ = (j as f64 + ((k % detail::STEPS) as f64 + 0.5f64) / detail::STEPS as f64) * d_φ;
+
+                probability += sampler.pdf(&[θ.sin() * φ.cos(), θ.sin() * φ.sin(), θ.cos()]) * θ.sin();
+            }
+
+            probability * d_θ * d_φ / (detail::STEPS * detail::STEPS) as f64 * count as f64
+        })
+        .collect::<Vec<_>>();
+
+    let mut observed = vec![0usize; bins_θ * bins_φ];
+
+    for _ in 0..count {
+        let (direction, _) = sampler.sample(generator);
+
+        let θ = direction[2].clamp(-1f64, 1f64).acos();
+        let φ = direction[1].atan2(direction[0]).rem_euclid(2f64 * std::f64::consts::PI);
+
+        observed[detail::bin_of(θ, d_θ, bins_θ) * bins_φ + detail::bin_of(φ, d_φ, bins_φ)] += 1;
+    }
+
+    chi_square(&observed, &expected)
+}
+
+mod detail {
+    /// The steps of the midpoint rule along each axis of a bin.
+    pub const STEPS: usize = 8;
+
+    pub fn bin_of(angle: f64, width: f64, bins: usize) -> usize { std::cmp::min((angle / width) as usize, bins - 1) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::distributions::sphere::*;
+    use crate::rng::engines::Xoroshiro128P;
+    use crate::rng::RandomNumberEngine;
+
+    #[test]
+    fn test_spherical_binning() {
+        let mut generator = Xoroshiro128P::new();
+        generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+        assert!(circular_chi_square(&mut UniformSphereSampler::<f64, 2>::new(), &mut generator, 65536, 64).p_value() > 1e-4);
+        assert!(spherical_chi_square(&mut UniformSphereSampler::<f64, 3>::new(), &mut generator, 65536, 16).p_value() > 1e-4);
+        assert!(spherical_chi_square(&mut CosineWeightedHemisphereSampler::<f64>::new(), &mut generator, 65536, 16).p_value() > 1e-4);
+
+        // the cosine lobe is not uniform
+        struct Mislabelled(CosineWeightedHemisphereSampler<f64>);
+
+        impl NDSampler<f64, 3> for Mislabelled {
+            const CANONICAL_DIMENSIONS: usize = 2;
+
+            fn sample<Gen: UniformRandomBitGenerator>(&mut self, gen: &mut Gen) -> ([f64; 3], f64) { self.0.sample(gen) }
+            fn sample_canonical(&mut self, u: &[f64]) -> ([f64; 3], f64) { self.0.sample_canonical(u) }
+            fn pdf(&self, point: &[f64; 3]) -> f64 { if point[2] > 0f64 { 0.5f64 * std::f64::consts::FRAC_1_PI } else { 0f64 } }
+        }
+
+        assert!(spherical_chi_square(&mut Mislabelled(CosineWeightedHemisphereSampler::new()), &mut generator, 65536, 16).p_value() < 1e-12);
+    }
+}
@@ -1,6 +1,17 @@
 use std::num::NonZeroUsize;
 
-use super::{distributions::NormalDistribution, RandomNumberEngine};
+use super::stats::{self, regularized_beta, regularized_gamma_p, standard_normal_cdf};
+use super::{distributions::NormalDistribution, RandomNumberEngine, UniformRandomBitGenerator};
+
+/// Like `DummyGenerator2` in `rng::util`, a generator with an odd range, but actually random.
+pub struct OddRangeGenerator<G: UniformRandomBitGenerator<ResultType = u64>>(pub G);
+
+impl<G: UniformRandomBitGenerator<ResultType = u64>> UniformRandomBitGenerator for OddRangeGenerator<G> {
+    type ResultType = u64;
+    fn min() -> Self::ResultType { 64 }
+    fn max() -> Self::ResultType { 127 }
+    fn generate(&mut self) -> Self::ResultType { 64 + self.0.generate() % 64 }
+}
 
 struct BinBuilder {
     start: f64,
@@ -112,47 +123,32 @@ fn test_bin_builder() {
     assert_eq!(bin_builder.insert(1.9998f64), (Bin::Regular(4), 2));
 }
 
-fn average_square_error<Discrete, Continuous>(range: Discrete, cont_fn: Continuous) -> f64
-where
-    Discrete: Iterator<Item = (f64, f64)>,
-    Continuous: Fn(f64) -> f64,
-{
-    let (sample_count, sum_of_errors) = range
-        .map(|(x, y)| {
-            let expected_y = cont_fn(x);
-            (expected_y - y).powi(2)
-        })
-        .enumerate()
-        .fold((0, 0f64), |acc, v| (v.0, acc.1 + v.1));
-
-    return sum_of_errors / (sample_count as f64);
-}
-
-fn test_bins<G: FnMut() -> f64, Continuous: Fn(f64) -> f64>(generate_fn: &mut G, reference_fn: Continuous, num_iters: usize, num_bins: usize, range_start: f64, range_end: f64) {
+/// Pearson's chi-square test of `num_iters` samples binned into `num_bins` bins between `range_start` and
+/// `range_end`, and an underflow and an overflow bin, against `cdf`, at a significance level of 0.01%.
+fn test_bins<G: FnMut() -> f64, Cdf: Fn(f64) -> f64>(generate_fn: &mut G, cdf: Cdf, num_iters: usize, num_bins: usize, range_start: f64, range_end: f64) {
     let mut bins = BinBuilder::new_range(range_start, range_end, num_bins.try_into().unwrap());
 
     for _ in 0..num_iters {
         bins.insert(generate_fn());
     }
 
-    let max_bin_height = bins.iter().fold(0usize, |a, b| std::cmp::max(a, *b.1));
-    let normalisation_multiplier = 1f64 / max_bin_height as f64;
+    let edge = |idx: usize| range_start + (range_end - range_start) * idx as f64 / num_bins as f64;
 
-    let samples = std::iter::repeat(std::iter::once(())) //
-        .take(num_bins)
-        .enumerate()
-        .map(|(idx, _)| {
-            let x_param = (2 * idx + 1) as f64 / (2 * num_bins) as f64;
-            let x = x_param * (range_end - range_start) + range_start;
+    let mut observed = vec![bins.get(Bin::Underflow)];
+    let mut expected = vec![cdf(range_start)];
 
-            (x, bins.get(Bin::Regular(idx)) as f64 * normalisation_multiplier)
-        });
+    for (idx, &count) in bins.iter() {
+        observed.push(count);
+        expected.push(cdf(edge(idx + 1)) - cdf(edge(idx)));
+    }
 
-    // bins.print(24);
+    observed.push(bins.get(Bin::Overflow));
+    expected.push(1f64 - cdf(range_end));
 
-    let mean_square_error = average_square_error(samples, reference_fn);
+    let expected = expected.iter().map(|p| p * num_iters as f64).collect::<Vec<_>>();
+    let result = stats::chi_square(&observed, &expected);
 
-    assert!(mean_square_error < 0.05f64);
+    assert!(!result.rejects(1e-4), "chi-square statistic {} has a p-value of {}", result.statistic(), result.p_value());
 }
 
 #[test]
@@ -164,7 +160,39 @@ fn test_canonical_bins() {
 
     let mut generate_fn = move || -> f64 { <f64 as GenerateCanonical<_>>::generate_canonical(&mut generator).get() };
 
-    test_bins(&mut generate_fn, |_| 1f64, 16384, 32, 0f64, 1f64);
+    test_bins(&mut generate_fn, |x| x, 16384, 32, 0f64, 1f64);
+}
+
+#[test]
+fn test_dense_canonical_bins() {
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    use crate::rng::distributions::GenerateCanonical;
+
+    let mut generate_fn = || -> f64 { <f64 as GenerateCanonical<_>>::generate_canonical_dense(&mut generator).get() };
+    test_bins(&mut generate_fn, |x| x, 16384, 32, 0f64, 1f64);
+
+    let mut generate_fn = || -> f64 { <f64 as GenerateCanonical<_>>::generate_canonical_dense_closed(&mut generator).get() };
+    test_bins(&mut generate_fn, |x| x, 16384, 32, 0f64, 1f64);
+
+    // the binades themselves, each should be half as likely as the previous one
+    let mut generate_fn = || -> f64 { -<f32 as GenerateCanonical<_>>::generate_canonical_dense(&mut generator).get().log2() as f64 };
+    test_bins(&mut generate_fn, |x| 1f64 - 0.5f64.powf(x), 16384, 8, 1f64, 9f64);
+}
+
+#[test]
+fn test_uniform_real_bins() {
+    use crate::rng::distributions::UniformRealDistribution;
+    use crate::rng::RandomNumberDistribution;
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    for mut distribution in [UniformRealDistribution::new(-3f64, 5f64), UniformRealDistribution::new_open(-3f64, 5f64).dense()] {
+        let mut generate_fn = || -> f64 { distribution.generate(&mut generator) };
+        test_bins(&mut generate_fn, |x| (x + 3f64) / 8f64, 16384, 32, -3f64, 5f64);
+    }
 }
 
 #[test]
@@ -174,11 +202,562 @@ fn test_normal_bins() {
     let mut generator = crate::rng::engines::Xoroshiro128P::new();
     generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
 
-    let mut distribution = NormalDistribution::<f64>::new();
+    let mut distribution = NormalDistribution::<f64>::default();
 
     let mut generate_fn = move || -> f64 { distribution.generate(&mut generator) };
 
-    let norm_dist_pdf = |x: f64| (1f64 / (2f64 * std::f64::consts::PI).sqrt()) * std::f64::consts::E.powf(-0.5f64 * x.powi(2)) / 0.398942280401f64;
+    test_bins(&mut generate_fn, standard_normal_cdf, 16384, 32, -2f64, 2f64);
+}
+
+fn test_uniform_int_bins_with<G: crate::rng::UniformRandomBitGenerator>(generator: &mut G, a: i32, b: i32) {
+    use crate::rng::distributions::UniformIntDistribution;
+    use crate::rng::RandomNumberDistribution;
+
+    let mut distribution = UniformIntDistribution::new(a, b);
+
+    // one bin per value, sampled at its centre
+    let mut generate_fn = || -> f64 { distribution.generate(generator) as f64 + 0.5f64 };
+
+    test_bins(&mut generate_fn, |x| (x - a as f64) / (b - a + 1) as f64, 16384, (b - a + 1) as usize, a as f64, (b + 1) as f64);
+}
+
+#[test]
+fn test_uniform_int_bins() {
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    test_uniform_int_bins_with(&mut generator, 0, 31);
+    test_uniform_int_bins_with(&mut generator, -7, 5);
+    test_uniform_int_bins_with(&mut generator, 1000, 1023);
+
+    let mut generator = OddRangeGenerator(generator);
+
+    test_uniform_int_bins_with(&mut generator, 0, 31);
+    test_uniform_int_bins_with(&mut generator, -7, 5);
+}
+
+/// Pearson's chi-square test of samples in `0..` against `pmf`, with the tail from `support_end` on in the last bin,
+/// at a significance level of 0.01%.
+fn test_chi_square_discrete<G: FnMut() -> u64, Pmf: Fn(u64) -> f64>(generate_fn: &mut G, pmf: Pmf, num_iters: usize, support_end: u64) {
+    let result = stats::chi_square_discrete(generate_fn, pmf, num_iters, support_end);
+
+    assert!(!result.rejects(1e-4), "chi-square statistic {} has a p-value of {}", result.statistic(), result.p_value());
+}
+
+fn ln_factorial(k: f64) -> f64 { (k + 1f64).ln_gamma().0 }
+
+#[test]
+fn test_bernoulli_chi_square() {
+    use crate::rng::distributions::BernoulliDistribution;
+    use crate::rng::RandomNumberDistribution;
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    for p in [0.001f64, 0.3f64, 0.5f64] {
+        let mut distribution = BernoulliDistribution::new(p);
+        let mut generate_fn = || -> u64 { distribution.generate(&mut generator) as u64 };
+
+        test_chi_square_discrete(&mut generate_fn, |k| if k == 0 { 1f64 - p } else { p }, 65536, 2);
+    }
+}
+
+#[test]
+fn test_binomial_chi_square() {
+    use crate::rng::distributions::BinomialDistribution;
+    use crate::rng::RandomNumberDistribution;
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    // inversion, BTPE and both of them mirrored
+    for (t, p) in [(20u64, 0.3f64), (100, 0.8), (1000, 0.5), (500, 0.1), (2000, 0.97)] {
+        let mut distribution = BinomialDistribution::new(t, p);
+        let mut generate_fn = || -> u64 { distribution.generate(&mut generator) };
+
+        let n = t as f64;
+        let pmf = |k: u64| {
+            let k = k as f64;
+            (ln_factorial(n) - ln_factorial(k) - ln_factorial(n - k) + k * p.ln() + (n - k) * (1f64 - p).ln()).exp()
+        };
+
+        test_chi_square_discrete(&mut generate_fn, pmf, 65536, t);
+    }
+}
+
+#[test]
+fn test_geometric_chi_square() {
+    use crate::rng::distributions::GeometricDistribution;
+    use crate::rng::RandomNumberDistribution;
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    for p in [0.9f64, 0.3f64, 0.01f64] {
+        let mut distribution = GeometricDistribution::<u64>::new(p);
+        let mut generate_fn = || -> u64 { distribution.generate(&mut generator) };
+
+        test_chi_square_discrete(&mut generate_fn, |k| p * (1f64 - p).powi(k as i32), 65536, (20f64 / p) as u64);
+    }
+}
+
+#[test]
+fn test_poisson_chi_square() {
+    use crate::rng::distributions::PoissonDistribution;
+    use crate::rng::RandomNumberDistribution;
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    // multiplication, then PTRS
+    for mean in [0.5f64, 7f64, 10f64, 30f64, 1000f64] {
+        let mut distribution = PoissonDistribution::<u64>::new(mean);
+        let mut generate_fn = || -> u64 { distribution.generate(&mut generator) };
+
+        let pmf = |k: u64| (k as f64 * mean.ln() - mean - ln_factorial(k as f64)).exp();
+
+        test_chi_square_discrete(&mut generate_fn, pmf, 65536, (mean + 10f64 * mean.sqrt() + 10f64) as u64);
+    }
+}
+
+#[test]
+fn test_negative_binomial_chi_square() {
+    use crate::rng::distributions::NegativeBinomialDistribution;
+    use crate::rng::RandomNumberDistribution;
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    for (k, p) in [(1u64, 0.5f64), (3, 0.2), (50, 0.7)] {
+        let mut distribution = NegativeBinomialDistribution::new(k, p);
+        let mut generate_fn = || -> u64 { distribution.generate(&mut generator) };
+
+        let r = k as f64;
+        let pmf = |x: u64| {
+            let x = x as f64;
+            (ln_factorial(x + r - 1f64) - ln_factorial(r - 1f64) - ln_factorial(x) + r * p.ln() + x * (1f64 - p).ln()).exp()
+        };
+
+        let mean = r * (1f64 - p) / p;
+        test_chi_square_discrete(&mut generate_fn, pmf, 65536, (mean + 10f64 * (mean / p).sqrt() + 10f64) as u64);
+    }
+}
+
+/// The Kolmogorov–Smirnov and Anderson–Darling tests of `num_iters` samples against `cdf`, at a significance level
+/// of 0.1%.
+fn test_kolmogorov_smirnov<G: FnMut() -> f64, Cdf: Fn(f64) -> f64>(generate_fn: &mut G, cdf: Cdf, num_iters: usize) {
+    let mut samples = (0..num_iters).map(|_| generate_fn()).collect::<Vec<_>>();
+
+    let result = stats::kolmogorov_smirnov(&mut samples, &cdf);
+    assert!(!result.rejects(1e-3), "Kolmogorov–Smirnov statistic {} has a p-value of {}", result.statistic(), result.p_value());
+
+    let result = stats::anderson_darling(&mut samples, &cdf);
+    assert!(!result.rejects(1e-3), "Anderson–Darling statistic {} has a p-value of {}", result.statistic(), result.p_value());
+}
+
+fn test_kolmogorov_smirnov_with<D, Cdf>(mut distribution: D, cdf: Cdf)
+where
+    D: crate::rng::RandomNumberDistribution<ResultType = f64>,
+    Cdf: Fn(f64) -> f64,
+{
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    let mut generate_fn = || -> f64 { distribution.generate(&mut generator) };
+    test_kolmogorov_smirnov(&mut generate_fn, cdf, 16384);
+}
+
+#[test]
+fn test_exponential_kolmogorov_smirnov() {
+    use crate::rng::distributions::ExponentialDistribution;
+
+    for lambda in [1f64, 0.1f64, 25f64] {
+        test_kolmogorov_smirnov_with(ExponentialDistribution::new(lambda), |x| 1f64 - (-lambda * x).exp());
+    }
+}
+
+#[test]
+fn test_gamma_kolmogorov_smirnov() {
+    use crate::rng::distributions::{ChiSquaredDistribution, GammaDistribution};
+
+    for (alpha, beta) in [(0.05f64, 1f64), (0.7, 2.0), (1.0, 1.0), (3.3, 0.5), (250.0, 1.0)] {
+        test_kolmogorov_smirnov_with(GammaDistribution::new(alpha, beta), |x| regularized_gamma_p(alpha, x / beta));
+    }
+
+    for n in [1f64, 2.5f64, 40f64] {
+        test_kolmogorov_smirnov_with(ChiSquaredDistribution::new(n), |x| regularized_gamma_p(n / 2f64, x / 2f64));
+    }
+}
+
+#[test]
+fn test_beta_kolmogorov_smirnov() {
+    use crate::rng::distributions::BetaDistribution;
+
+    for (alpha, beta) in [(0.3f64, 0.6f64), (1.0, 1.0), (2.0, 5.0), (0.5, 8.0), (40.0, 30.0)] {
+        test_kolmogorov_smirnov_with(BetaDistribution::new(alpha, beta), |x| regularized_beta(x, alpha, beta));
+    }
+}
+
+#[test]
+fn test_student_t_and_fisher_f_kolmogorov_smirnov() {
+    use crate::rng::distributions::{FisherFDistribution, StudentTDistribution};
+
+    for n in [1f64, 3.5f64, 30f64] {
+        let cdf = |t: f64| {
+            let tail = regularized_beta(n / (n + t * t), n / 2f64, 0.5f64) / 2f64;
+            if t < 0f64 { tail } else { 1f64 - tail }
+        };
+
+        test_kolmogorov_smirnov_with(StudentTDistribution::new(n), cdf);
+    }
 
-    test_bins(&mut generate_fn, norm_dist_pdf, 16384, 32, -2f64, 2f64);
+    for (m, n) in [(1f64, 1f64), (5.0, 2.0), (20.0, 30.0)] {
+        test_kolmogorov_smirnov_with(FisherFDistribution::new(m, n), |x| regularized_beta(m * x / (m * x + n), m / 2f64, n / 2f64));
+    }
+}
+
+#[test]
+fn test_location_scale_kolmogorov_smirnov() {
+    use crate::rng::distributions::{CauchyDistribution, ExtremeValueDistribution, LognormalDistribution, WeibullDistribution};
+
+    for (m, s) in [(0f64, 1f64), (2.0, 0.25)] {
+        test_kolmogorov_smirnov_with(LognormalDistribution::new(m, s), |x| standard_normal_cdf((x.ln() - m) / s));
+    }
+
+    for (a, b) in [(0f64, 1f64), (-3.0, 0.01)] {
+        test_kolmogorov_smirnov_with(CauchyDistribution::new(a, b), |x| 0.5f64 + ((x - a) / b).atan() / std::f64::consts::PI);
+    }
+
+    for (a, b) in [(0.5f64, 1f64), (1.0, 2.0), (5.0, 0.3)] {
+        test_kolmogorov_smirnov_with(WeibullDistribution::new(a, b), |x| 1f64 - (-(x / b).powf(a)).exp());
+    }
+
+    for (a, b) in [(0f64, 1f64), (10.0, 3.0)] {
+        test_kolmogorov_smirnov_with(ExtremeValueDistribution::new(a, b), |x| (-(-(x - a) / b).exp()).exp());
+    }
+}
+
+#[test]
+fn test_normal_kolmogorov_smirnov() {
+    use crate::rng::distributions::NormalMethod;
+
+    for method in [NormalMethod::MarsagliaPolar, NormalMethod::Ziggurat] {
+        for (mean, stdev) in [(0f64, 1f64), (5f64, 0.1f64), (-100f64, 30f64)] {
+            test_kolmogorov_smirnov_with(NormalDistribution::new_with(mean, stdev, method), |x| standard_normal_cdf((x - mean) / stdev));
+        }
+    }
+
+    // the tail of the ziggurat starts at 3.65
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    let mut distribution = NormalDistribution::<f64>::default().ziggurat();
+    let tail_cdf = |x: f64| (standard_normal_cdf(x) - standard_normal_cdf(3.7f64)) / (1f64 - standard_normal_cdf(3.7f64));
+
+    let mut generate_fn = || -> f64 {
+        use crate::rng::RandomNumberDistribution;
+
+        loop {
+            let x = distribution.generate(&mut generator).abs();
+            if x > 3.7f64 {
+                return x;
+            }
+        }
+    };
+
+    test_kolmogorov_smirnov(&mut generate_fn, tail_cdf, 4096);
+}
+
+#[test]
+fn test_discrete_chi_square() {
+    use crate::rng::distributions::DiscreteDistribution;
+    use crate::rng::RandomNumberDistribution;
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    let weights_list: [&[f64]; 3] = [&[1f64, 2f64, 3f64, 4f64], &[0.001f64, 0f64, 1000f64, 20f64, 0.5f64], &[1f64; 37]];
+
+    for weights in weights_list {
+        let sum = weights.iter().sum::<f64>();
+        let mut distribution = DiscreteDistribution::<u64>::new(weights);
+        let mut generate_fn = || -> u64 { distribution.generate(&mut generator) };
+
+        test_chi_square_discrete(&mut generate_fn, |k| weights[k as usize] / sum, 65536, weights.len() as u64);
+    }
+
+    // weights following a binomial pmf
+    let pmf = |k: f64| (ln_factorial(20f64) - ln_factorial(k) - ln_factorial(20f64 - k) + k * 0.3f64.ln() + (20f64 - k) * 0.7f64.ln()).exp();
+    let mut distribution = DiscreteDistribution::<u64>::from_fn(21, -0.5f64, 20.5f64, pmf);
+    let mut generate_fn = || -> u64 { distribution.generate(&mut generator) };
+
+    test_chi_square_discrete(&mut generate_fn, |k| pmf(k as f64), 65536, 21);
+}
+
+#[test]
+fn test_piecewise_kolmogorov_smirnov() {
+    use crate::rng::distributions::{PiecewiseConstantDistribution, PiecewiseLinearDistribution};
+
+    let intervals = [-2f64, -1f64, 0.5f64, 0.75f64, 3f64, 10f64];
+
+    let distribution = PiecewiseConstantDistribution::new(&intervals, &[1f64, 0f64, 5f64, 2f64, 0.1f64]);
+    let reference = distribution.clone();
+    test_kolmogorov_smirnov_with(distribution, |x| reference.cdf(x));
+
+    let distribution = PiecewiseLinearDistribution::new(&intervals, &[0f64, 3f64, 0f64, 1f64, 1f64, 0.5f64]);
+    let reference = distribution.clone();
+    test_kolmogorov_smirnov_with(distribution, |x| reference.cdf(x));
+
+    // a smooth density approximated by many intervals, against its exact cdf
+    let distribution = PiecewiseLinearDistribution::from_fn(1000, 0f64, 1f64, |x| x * x);
+    test_kolmogorov_smirnov_with(distribution, |x| x * x * x);
+}
+
+#[test]
+fn test_piecewise_constant_2d_chi_square() {
+    use crate::rng::distributions::{sphere::NDSampler, PiecewiseConstant2DDistribution};
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    let (width, height) = (7, 5);
+    let values = (0..width * height).map(|i| ((i * 37) % 11) as f64).collect::<Vec<_>>();
+    let sum = values.iter().sum::<f64>();
+    let mut distribution = PiecewiseConstant2DDistribution::new(&values, width);
+
+    let mut generate_fn = || -> u64 {
+        let (point, pdf) = distribution.sample(&mut generator);
+        let cell = (point[1] * height as f64) as usize * width + (point[0] * width as f64) as usize;
+
+        assert!((pdf - values[cell] / sum * (width * height) as f64).abs() < 1e-12);
+        cell as u64
+    };
+
+    test_chi_square_discrete(&mut generate_fn, |k| values[k as usize] / sum, 65536, (width * height) as u64);
+}
+
+/// The root mean square error of estimates of `reference` by `estimate`, over `pixels` pixels with `samples`
+/// points each.
+fn sample_generator_rmse<S: crate::rng::sequences::SampleGenerator, F: FnMut(&mut S) -> f64>(samples: &mut S, mut estimate: F, reference: f64, pixels: u32, n: u64) -> f64 {
+    let squared_error = (0..pixels)
+        .map(|pixel| {
+            let mean = (0..n)
+                .map(|index| {
+                    samples.start_pixel_sample([pixel, 7], index);
+                    estimate(samples)
+                })
+                .sum::<f64>()
+                / n as f64;
+
+            (mean - reference).powi(2)
+        })
+        .sum::<f64>();
+
+    (squared_error / pixels as f64).sqrt()
+}
+
+#[test]
+fn test_sample_generator_convergence() {
+    use crate::rng::distributions::sphere::*;
+    use crate::rng::sequences::*;
+
+    /// The errors of a hemispherical and a spherical integral with 256 samples per pixel.
+    fn errors<S: SampleGenerator>(mut samples: S) -> [f64; 2] {
+        // the irradiance from a hemisphere emitting x^2 + z is pi * 11 / 12
+        let irradiance = |samples: &mut S| -> f64 {
+            let (w, pdf) = CosineWeightedHemisphereSampler::<f64>::new().sample_from(samples);
+            (w[0] * w[0] + w[2]) * w[2] / pdf
+        };
+
+        // the mean of (x + y + z)^2 over the sphere is 1
+        let sphere = |samples: &mut S| -> f64 {
+            let (w, _) = UniformSphereSampler::<f64, 3>::new().sample_from(samples);
+            (w[0] + w[1] + w[2]).powi(2)
+        };
+
+        [
+            sample_generator_rmse(&mut samples, irradiance, std::f64::consts::PI * 11f64 / 12f64, 64, 256),
+            sample_generator_rmse(&mut samples, sphere, 1f64, 64, 256),
+        ]
+    }
+
+    let independent = errors(IndependentSampleGenerator::new(1));
+
+    let check = |name: &str, errors: [f64; 2], factor: f64| {
+        for (error, reference) in errors.iter().zip(independent.iter()) {
+            assert!(error * factor < *reference, "{} has an error of {} against {} of independent samples", name, error, reference);
+        }
+    };
+
+    check("Halton", errors(HaltonSampleGenerator::new(1)), 3f64);
+    check("R2", errors(R2SampleGenerator::new(1)), 3f64);
+    check("Sobol", errors(SobolSampleGenerator::new(1, SobolScrambling::RandomDigit)), 10f64);
+    check("Owen scrambled Sobol", errors(SobolSampleGenerator::new(1, SobolScrambling::Owen)), 10f64);
+}
+
+#[test]
+fn test_sample_pattern_convergence() {
+    use crate::rng::engines::Xoshiro256PP;
+    use crate::rng::patterns::*;
+
+    /// The root mean square error of 64 estimates of the mean of `exp(x y) + sin(3 x)` by 256 points each.
+    fn error<P: SamplePattern<f64, 2>>(mut pattern: P) -> f64 {
+        // the integral of exp(x y) over the unit square is Ei(1) - gamma
+        let reference = 1.3179021514544038f64 + (1f64 - 3f64.cos()) / 3f64;
+
+        let mut generator = Xoshiro256PP::new();
+        generator.seed_from_result(1);
+
+        let squared_error = (0..64)
+            .map(|_| {
+                let points = pattern.generate(&mut generator, 256);
+                let mean = points.iter().map(|p| (p[0] * p[1]).exp() + (3f64 * p[0]).sin()).sum::<f64>() / points.len() as f64;
+
+                (mean - reference).powi(2)
+            })
+            .sum::<f64>();
+
+        (squared_error / 64f64).sqrt()
+    }
+
+    struct IndependentPattern {}
+
+    impl SamplePattern<f64, 2> for IndependentPattern {
+        fn generate<G: crate::rng::UniformRandomBitGenerator>(&mut self, generator: &mut G, count: usize) -> Vec<crate::smallvec::Vector<f64, 2>> {
+            use crate::rng::distributions::GenerateCanonical;
+
+            (0..count).map(|_| crate::smallvec::Vector([f64::generate_canonical(generator).get(), f64::generate_canonical(generator).get()])).collect()
+        }
+    }
+
+    let independent = error(IndependentPattern {});
+
+    let check = |name: &str, error: f64, factor: f64| {
+        assert!(error * factor < independent, "{} has an error of {} against {} of independent points", name, error, independent);
+    };
+
+    check("jittered", error(JitteredPattern::new()), 5f64);
+    check("multi-jittered", error(MultiJitteredPattern::new()), 5f64);
+    check("correlated multi-jittered", error(CorrelatedMultiJitteredPattern::new()), 5f64);
+    check("Latin hypercube", error(LatinHypercubePattern::new()), 2f64);
+    check("Poisson disk", error(PoissonDiskPattern::default()), 1.5f64);
+}
+
+/// The Kolmogorov–Smirnov test of `project` applied to the samples of `sampler` against `cdf`, once with samples
+/// from a generator and once from canonical numbers.
+fn test_sampler_kolmogorov_smirnov<Sampler, Project, Cdf, const N: usize>(sampler: &mut Sampler, project: Project, cdf: Cdf)
+where
+    Sampler: crate::rng::distributions::sphere::NDSampler<f64, N>,
+    Project: Fn([f64; N]) -> f64,
+    Cdf: Fn(f64) -> f64,
+{
+    use crate::rng::distributions::GenerateCanonical;
+
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    test_kolmogorov_smirnov(&mut || project(sampler.sample(&mut generator).0), &cdf, 16384);
+
+    test_kolmogorov_smirnov(
+        &mut || {
+            let u = (0..Sampler::CANONICAL_DIMENSIONS).map(|_| f64::generate_canonical(&mut generator).get()).collect::<Vec<_>>();
+            project(sampler.sample_canonical(&u).0)
+        },
+        &cdf,
+        16384,
+    );
+}
+
+#[test]
+fn test_sphere_and_ball_kolmogorov_smirnov() {
+    use crate::rng::distributions::sphere::*;
+
+    // a coordinate of a uniform point on the sphere in n dimensions is distributed as 2 Beta((n-1)/2, (n-1)/2) - 1
+    fn coordinate_cdf(n: f64) -> impl Fn(f64) -> f64 { move |x| regularized_beta((x + 1f64) / 2f64, (n - 1f64) / 2f64, (n - 1f64) / 2f64) }
+
+    test_sampler_kolmogorov_smirnov(&mut UniformSphereSampler::<f64, 4>::new(), |v| v[0], coordinate_cdf(4f64));
+    test_sampler_kolmogorov_smirnov(&mut UniformSphereSampler::<f64, 4>::new(), |v| v[3], coordinate_cdf(4f64));
+    test_sampler_kolmogorov_smirnov(&mut UniformSphereSampler::<f64, 5>::new(), |v| v[2], coordinate_cdf(5f64));
+    test_sampler_kolmogorov_smirnov(&mut UniformSphereSampler::<f64, 7>::new(), |v| v[6], coordinate_cdf(7f64));
+
+    // the volume of the ball within a radius is a fraction of r^n
+    test_sampler_kolmogorov_smirnov(&mut UniformBallSampler::<f64, 3>::new(), |v| v.iter().map(|v| v * v).sum::<f64>().powf(1.5f64), |x| x);
+    test_sampler_kolmogorov_smirnov(&mut UniformBallSampler::<f64, 5>::new(), |v| v.iter().map(|v| v * v).sum::<f64>().powf(2.5f64), |x| x);
+    test_sampler_kolmogorov_smirnov(&mut UniformBallSampler::<f64, 5>::new(), |v| v[1] / v.iter().map(|v| v * v).sum::<f64>().sqrt(), coordinate_cdf(5f64));
+}
+
+#[test]
+fn test_planar_sampler_kolmogorov_smirnov() {
+    use crate::rng::distributions::sphere::*;
+
+    let angle = |v: [f64; 2]| v[1].atan2(v[0]).rem_euclid(2f64 * std::f64::consts::PI) / (2f64 * std::f64::consts::PI);
+
+    test_sampler_kolmogorov_smirnov(&mut ConcentricDiskSampler::<f64>::new(), |v| v[0] * v[0] + v[1] * v[1], |x| x);
+    test_sampler_kolmogorov_smirnov(&mut ConcentricDiskSampler::<f64>::new(), angle, |x| x);
+
+    // the density of x on the triangle (0, 0), (1, 0), (0, 1) falls linearly to zero
+    test_sampler_kolmogorov_smirnov(&mut UniformTriangleSampler::new([[0f64, 0f64], [1f64, 0f64], [0f64, 1f64]]), |v| v[0], |x| 1f64 - (1f64 - x).powi(2));
+    test_sampler_kolmogorov_smirnov(&mut UniformTriangleSampler::new([[0f64, 0f64], [1f64, 0f64], [0f64, 1f64]]), |v| v[1], |x| 1f64 - (1f64 - x).powi(2));
+    test_sampler_kolmogorov_smirnov(&mut UniformTriangleSampler::new([[1f64, 1f64, 0f64], [1f64, 1f64, 2f64], [1f64, 0f64, 0f64]]), |v| v[2] / 2f64, |x| 1f64 - (1f64 - x).powi(2));
+}
+
+#[test]
+fn test_spherical_sampler_kolmogorov_smirnov() {
+    use crate::rng::distributions::sphere::*;
+
+    // the cosine to the axis is uniform on the cone and the cap, as on the whole sphere
+    let axis = [0.48f64, -0.6f64, 0.64f64];
+    let cosine = move |v: [f64; 3]| v[0] * axis[0] + v[1] * axis[1] + v[2] * axis[2];
+
+    test_sampler_kolmogorov_smirnov(&mut UniformConeSampler::new(axis, 0.3f64), cosine, |x| (x - 0.3f64) / 0.7f64);
+    test_sampler_kolmogorov_smirnov(&mut UniformConeSampler::new(axis, -0.5f64), cosine, |x| (x + 0.5f64) / 1.5f64);
+
+    let mut cap = UniformSphericalCapSampler::visible_from([1f64, 2f64, 3f64], 2f64, [1f64, 2f64, -1f64]);
+    test_sampler_kolmogorov_smirnov(&mut cap, |v| (3f64 - v[2]) / 2f64, |x| (x - 0.5f64) / 0.5f64);
+
+    // Archimedes again: the height of a uniform point in the octant is uniform, as are the other coordinates
+    let mut octant = UniformSphericalTriangleSampler::new([[1f64, 0f64, 0f64], [0f64, 1f64, 0f64], [0f64, 0f64, 1f64]]);
+    assert!((octant.solid_angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+
+    for i in 0..3 {
+        test_sampler_kolmogorov_smirnov(&mut octant, |v| v[i], |x| x);
+    }
+
+    // the unit square at a distance of 1 with a corner below the viewpoint; the solid angle of [0, a] x [0, b] is
+    // atan(a b / sqrt(a^2 + b^2 + 1))
+    let solid_angle = |a: f64, b: f64| (a * b / (a * a + b * b + 1f64).sqrt()).atan();
+    let mut rectangle = UniformSphericalRectangleSampler::new([0f64, 0f64, 0f64], [0f64, 0f64, -1f64], [[1f64, 0f64, 0f64], [0f64, 1f64, 0f64]]);
+    assert!((rectangle.solid_angle() - solid_angle(1f64, 1f64)).abs() < 1e-12);
+
+    for i in 0..2 {
+        test_sampler_kolmogorov_smirnov(&mut rectangle, |v| -v[i] / v[2], |x| solid_angle(x, 1f64) / solid_angle(1f64, 1f64));
+    }
+}
+
+/// The chi-square test of the directions of `sampler` against its `pdf` over the sphere, at a significance level of
+/// 0.01%.
+fn test_spherical_chi_square<Sampler: crate::rng::distributions::sphere::NDSampler<f64, 3>>(sampler: &mut Sampler) {
+    let mut generator = crate::rng::engines::Xoroshiro128P::new();
+    generator.seed_from_result(0xDEADBEEF_CAFEBABE_u64);
+
+    let result = stats::spherical_chi_square(sampler, &mut generator, 262144, 32);
+    assert!(!result.rejects(1e-4), "chi-square statistic {} has a p-value of {}", result.statistic(), result.p_value());
+}
+
+#[test]
+fn test_microfacet_chi_square() {
+    use crate::rng::distributions::sphere::*;
+
+    let wos = [[0f64, 0f64, 1f64], [0.6f64, 0f64, 0.8f64], [-0.48f64, 0.64f64, 0.6f64], [0.2f64, -0.97f64, 0.14f64]];
+    let normalized = |w: [f64; 3]| crate::smallvec::Vector(w).normalized().0;
+
+    for (alpha_x, alpha_y) in [(0.5f64, 0.5f64), (0.2f64, 0.6f64), (1f64, 0.8f64)] {
+        test_spherical_chi_square(&mut GGXDistribution::new(alpha_x, alpha_y));
+        test_spherical_chi_square(&mut BeckmannDistribution::new(alpha_x, alpha_y));
+
+        for wo in wos {
+            test_spherical_chi_square(&mut VisibleNormalSampler::new(GGXDistribution::new(alpha_x, alpha_y), normalized(wo)));
+            test_spherical_chi_square(&mut VisibleNormalSampler::new(BeckmannDistribution::new(alpha_x, alpha_y), normalized(wo)));
+        }
+    }
 }